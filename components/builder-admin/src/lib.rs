@@ -26,6 +26,7 @@ extern crate iron;
 #[macro_use]
 extern crate log;
 extern crate mount;
+extern crate oauth_client;
 extern crate persistent;
 extern crate protobuf;
 #[macro_use]
@@ -21,6 +21,7 @@ use hab_net::privilege;
 use http_gateway;
 use http_gateway::app::prelude::*;
 use iron;
+use oauth_client;
 use persistent;
 use staticfile::Static;
 
@@ -37,16 +38,19 @@ impl HttpGateway for AdminSrv {
         chain.link(persistent::Read::<GitHubCli>::both(
             GitHubClient::new(config.github.clone()),
         ));
+        chain.link(persistent::Read::<OAuthCli>::both(
+            oauth_client::new_provider(&config.oauth),
+        ));
     }
 
-    fn mount(config: Arc<Self::Config>, chain: iron::Chain) -> Mount {
+    fn mount(config: Arc<Self::Config>, chain: iron::Chain) -> AppResult<Mount> {
         let mut mount = Mount::new();
         if let Some(ref path) = config.ui.root {
             debug!("Mounting UI at filepath {}", path);
             mount.mount("/", Static::new(path));
         }
         mount.mount("/v1", chain);
-        mount
+        Ok(mount)
     }
 
     fn router(config: Arc<Self::Config>) -> Router {
@@ -33,6 +33,8 @@ pub struct Config {
     /// List of net addresses for routing servers to connect to
     pub routers: Vec<RouterAddr>,
     pub github: GitHubCfg,
+    /// Identity provider users sign in with
+    pub oauth: OAuthCfg,
     pub segment: SegmentCfg,
     pub ui: UiCfg,
     /// Depot's configuration
@@ -51,6 +53,7 @@ impl Default for Config {
             http: HttpCfg::default(),
             routers: vec![RouterAddr::default()],
             github: GitHubCfg::default(),
+            oauth: OAuthCfg::default(),
             segment: SegmentCfg::default(),
             ui: UiCfg::default(),
             depot: depot::config::Config::default(),
@@ -127,6 +130,7 @@ pub struct UiCfg {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use oauth_client::ProviderKind;
 
     #[test]
     fn config_from_file() {
@@ -163,6 +167,13 @@ mod tests {
         url = "https://api.github.com"
         client_id = "0c2f738a7d0bd300de10"
         client_secret = "438223113eeb6e7edf2d2f91a232b72de72b9bdf"
+
+        [oauth]
+        provider = "oidc"
+
+        [oauth.oidc]
+        issuer = "https://sso.example.com"
+        client_id = "builder"
         "#;
 
         let config = Config::from_raw(&content).unwrap();
@@ -178,6 +189,10 @@ mod tests {
             config.github.client_secret,
             "438223113eeb6e7edf2d2f91a232b72de72b9bdf"
         );
+        assert_eq!(config.oauth.provider, ProviderKind::Oidc);
+        assert_eq!(config.oauth.oidc.issuer, "https://sso.example.com");
+        assert_eq!(config.oauth.oidc.client_id, "builder");
+        assert_eq!(config.oauth.oidc.username_claim, "preferred_username");
         assert_eq!(config.ui.root, Some("/some/path".to_string()));
         assert_eq!(config.segment.url, "https://api.segment.io");
     }
@@ -193,5 +208,6 @@ mod tests {
         assert_eq!(config.events_enabled, false);
         assert_eq!(config.non_core_builds_enabled, true);
         assert_eq!(config.http.port, 9000);
+        assert_eq!(config.oauth.provider, ProviderKind::GitHub);
     }
 }
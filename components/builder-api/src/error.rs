@@ -19,6 +19,7 @@ use std::result;
 
 use hab_core;
 use hab_net;
+use http_gateway::app::error::AppError;
 use depot;
 use hyper;
 use protobuf;
@@ -26,6 +27,7 @@ use zmq;
 
 #[derive(Debug)]
 pub enum Error {
+    App(AppError),
     BadPort(String),
     Depot(depot::Error),
     HabitatCore(hab_core::Error),
@@ -43,6 +45,7 @@ pub type Result<T> = result::Result<T, Error>;
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::App(ref e) => format!("{}", e),
             Error::BadPort(ref e) => format!("{} is an invalid port. Valid range 1-65535.", e),
             Error::Depot(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
@@ -63,6 +66,7 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::App(ref err) => err.description(),
             Error::BadPort(_) => "Received an invalid port or a number outside of the valid range.",
             Error::Depot(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
@@ -79,6 +83,12 @@ impl error::Error for Error {
     }
 }
 
+impl From<AppError> for Error {
+    fn from(err: AppError) -> Error {
+        Error::App(err)
+    }
+}
+
 impl From<hab_core::Error> for Error {
     fn from(err: hab_core::Error) -> Error {
         Error::HabitatCore(err)
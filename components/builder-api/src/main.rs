@@ -20,6 +20,7 @@ extern crate clap;
 extern crate env_logger;
 extern crate habitat_builder_api as api;
 extern crate habitat_core as hab_core;
+extern crate habitat_depot as depot;
 #[macro_use]
 extern crate log;
 
@@ -30,6 +31,8 @@ use std::str::FromStr;
 
 use hab_core::config::ConfigFile;
 use api::{Config, Error, Result};
+use depot::config::StorageBackend;
+use depot::doctor::Operation;
 
 const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
 const CFG_DEFAULT_PATH: &'static str = "/hab/svc/builder-api/config.toml";
@@ -42,7 +45,11 @@ fn main() {
         Ok(result) => result,
         Err(e) => return exit_with(e, 1),
     };
-    match api::server::run(config) {
+    let result = match matches.subcommand() {
+        ("migrate", Some(args)) => migrate(config, args),
+        _ => api::server::run(config).map_err(Error::from),
+    };
+    match result {
         Ok(_) => std::process::exit(0),
         Err(e) => exit_with(e, 1),
     }
@@ -62,6 +69,15 @@ fn app<'a, 'b>() -> clap::App<'a, 'b> {
                 "Filepath to store packages, keys, and other artifacts.")
             (@arg port: --port +takes_value "Listen port. [default: 9636]")
         )
+        (@subcommand migrate =>
+            (about: "Copy package archives from another storage backend into the configured one")
+            (@arg config: -c --config +takes_value
+                "Filepath to configuration file. [default: /hab/svc/builder-api/config.toml]")
+            (@arg path: -p --path +takes_value
+                "Filepath to store packages, keys, and other artifacts.")
+            (@arg from: --from +required +takes_value
+                "Storage backend to copy archives from (local, s3)")
+        )
     )
 }
 
@@ -72,8 +88,10 @@ fn config_from_args(matches: &clap::ArgMatches) -> Result<Config> {
         Some(cfg_path) => Config::from_file(cfg_path)?,
         None => Config::from_file(CFG_DEFAULT_PATH).unwrap_or(Config::default()),
     };
-    // Ensure GitHub config matches
+    // Ensure GitHub and identity provider config matches
+    config.oauth.github = config.github.clone();
     config.depot.github = config.github.clone();
+    config.depot.oauth = config.oauth.clone();
     if let Some(port) = args.value_of("port") {
         if u16::from_str(port).map(|p| config.http.port = p).is_err() {
             return Err(Error::BadPort(port.to_string()));
@@ -85,6 +103,29 @@ fn config_from_args(matches: &clap::ArgMatches) -> Result<Config> {
     Ok(config)
 }
 
+fn migrate(config: Config, args: &clap::ArgMatches) -> Result<()> {
+    let backend = StorageBackend::from_str(args.value_of("from").unwrap())?; // Required via clap
+    let from = depot::storage::new_store(&config.depot, backend)?;
+    let depot = depot::DepotUtil::new(config.depot)?;
+    let report = depot::doctor::migrate(&depot, &*from)?;
+    let mut copied = 0;
+    for operation in report.operations.iter() {
+        match *operation {
+            Operation::Success(_) => copied += 1,
+            Operation::Failure(ref op, ref reason) => println!("Failed {:?}: {:?}", op, reason),
+        }
+    }
+    println!(
+        "Migrated {} archives in {}ms",
+        copied,
+        report.duration() / 1_000_000
+    );
+    if !report.success {
+        process::exit(1);
+    }
+    Ok(())
+}
+
 fn exit_with<T>(err: T, code: i32)
 where
     T: fmt::Display,
@@ -18,22 +18,26 @@ use std::env;
 
 use bodyparser;
 use bldr_core::helpers::transition_visibility;
-use github_api_client::HubError;
 use hab_core::package::{Identifiable, Plan};
 use hab_core::event::*;
 use http_client::ApiClient;
 use http_gateway::http::controller::*;
-use http_gateway::http::helpers::{self, check_origin_access, get_param, validate_params};
+use http_gateway::http::helpers::{self, check_origin_access, check_origin_role,
+                                  dont_cache_response, get_param, validate_params};
 use hyper::header::{Accept, ContentType};
 use hyper::status::StatusCode;
 use iron::status;
+use oauth_client::OAuthError;
 use params::{FromValue, Params};
 use persistent;
+use protobuf::RepeatedField;
 use protocol::jobsrv::{Job, JobGet, JobLogGet, JobLog, JobState, ProjectJobsGet,
                        ProjectJobsGetResponse, JobGroupCancel, JobGroupGet, JobGroup};
 use protocol::jobsrv::{JobGraphPackageReverseDependenciesGet, JobGraphPackageReverseDependencies};
 use protocol::originsrv::*;
-use protocol::sessionsrv::{Account, AccountGetId, AccountInvitationListRequest,
+use protocol::sessionsrv::{AccessToken, AccessTokenCreate, AccessTokenListRequest,
+                           AccessTokenListResponse, AccessTokenRevoke, AccessTokenScope, Account,
+                           AccountGetId, AccountInvitationListRequest,
                            AccountInvitationListResponse, AccountOriginListRequest,
                            AccountOriginListResponse, AccountUpdate};
 use serde_json;
@@ -54,7 +58,7 @@ const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"))
 
 define_event_log!();
 
-pub fn github_authenticate(req: &mut Request) -> IronResult<Response> {
+pub fn oauth_authenticate(req: &mut Request) -> IronResult<Response> {
     let code = match get_param(req, "code") {
         Some(c) => c,
         None => return Ok(Response::with(status::BadRequest)),
@@ -67,13 +71,13 @@ pub fn github_authenticate(req: &mut Request) -> IronResult<Response> {
         return Ok(render_json(status::Ok, &session));
     }
 
-    let github = req.get::<persistent::Read<GitHubCli>>().unwrap();
+    let provider = req.get::<persistent::Read<OAuthCli>>().unwrap();
     let segment = req.get::<persistent::Read<SegmentCli>>().unwrap();
 
-    match github.authenticate(&code) {
+    match provider.authenticate(&code) {
         Ok(token) => {
             let session = {
-                session_create_github(req, token)?
+                session_create_oauth(req, token)?
             };
             log_event!(
                 req,
@@ -94,17 +98,17 @@ pub fn github_authenticate(req: &mut Request) -> IronResult<Response> {
 
             Ok(render_json(status::Ok, &session))
         }
-        Err(HubError::Auth(e)) => {
-            let err = NetError::new(ErrCode::ACCESS_DENIED, e.error);
+        Err(OAuthError::Auth(e)) => {
+            let err = NetError::new(ErrCode::ACCESS_DENIED, e);
             Ok(render_net_error(&err))
         }
-        Err(HubError::Serialization(e)) => {
-            warn!("bad reply from GitHub, {}", e);
+        Err(OAuthError::Serialization(e)) => {
+            warn!("bad reply from identity provider, {}", e);
             let err = NetError::new(ErrCode::BAD_REMOTE_REPLY, "rg:auth:1");
             Ok(render_net_error(&err))
         }
         Err(e) => {
-            warn!("unhandled github authentication error, {:?}", e);
+            warn!("unhandled authentication error, {:?}", e);
             let err = NetError::new(ErrCode::BUG, "rg:auth:2");
             Ok(render_net_error(&err))
         }
@@ -243,7 +247,16 @@ pub fn job_group_cancel(req: &mut Request) -> IronResult<Response> {
     jgc.set_group_id(group_id);
 
     match route_message::<JobGroupCancel, NetOk>(req, &jgc) {
-        Ok(_) => Ok(Response::with(status::NoContent)),
+        Ok(_) => {
+            helpers::audit(
+                req,
+                &name_split[0],
+                OriginAuditAction::JobGroupCancel,
+                group.get_project_name(),
+                &group_id.to_string(),
+            );
+            Ok(Response::with(status::NoContent))
+        }
         Err(err) => Ok(render_net_error(&err)),
     }
 }
@@ -340,7 +353,7 @@ pub fn project_privacy_toggle(req: &mut Request) -> IronResult<Response> {
         Err(_) => return Ok(Response::with(status::BadRequest)),
     };
 
-    if !check_origin_access(req, &origin).unwrap_or(false) {
+    if !check_origin_role(req, &origin, OriginMemberRole::Maintainer).unwrap_or(false) {
         return Ok(Response::with(status::Forbidden));
     }
 
@@ -355,7 +368,16 @@ pub fn project_privacy_toggle(req: &mut Request) -> IronResult<Response> {
             opu.set_project(project);
 
             match route_message::<OriginProjectUpdate, NetOk>(req, &opu) {
-                Ok(_) => Ok(Response::with(status::NoContent)),
+                Ok(_) => {
+                    helpers::audit(
+                        req,
+                        &origin,
+                        OriginAuditAction::ProjectVisibilityUpdate,
+                        project_get.get_name(),
+                        &real_visibility.to_string(),
+                    );
+                    Ok(Response::with(status::NoContent))
+                }
                 Err(err) => Ok(render_net_error(&err)),
             }
         }
@@ -537,6 +559,148 @@ pub fn list_user_origins(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+pub fn list_access_tokens(req: &mut Request) -> IronResult<Response> {
+    let mut request = AccessTokenListRequest::new();
+    {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        request.set_account_id(session.get_id());
+    }
+    match route_message::<AccessTokenListRequest, AccessTokenListResponse>(req, &request) {
+        Ok(tokens) => {
+            let mut response = render_json(status::Ok, &tokens);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn create_access_token(req: &mut Request) -> IronResult<Response> {
+    let mut request = AccessTokenCreate::new();
+    {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        // Tokens can't be used to mint further tokens
+        if session.has_access_token() {
+            return Ok(Response::with(status::Forbidden));
+        }
+        request.set_account_id(session.get_id());
+    }
+    let body = match req.get::<bodyparser::Struct<AccessTokenCreateReq>>() {
+        Ok(Some(body)) => body,
+        _ => return Ok(Response::with(status::UnprocessableEntity)),
+    };
+    if body.name.is_empty() {
+        return Ok(Response::with((
+            status::UnprocessableEntity,
+            "Missing value for field: `name`",
+        )));
+    }
+    let mut scopes = Vec::new();
+    for scope in body.scopes.iter() {
+        match scope.parse::<AccessTokenScope>() {
+            Ok(scope) => scopes.push(scope),
+            Err(_) => {
+                return Ok(Response::with((
+                    status::UnprocessableEntity,
+                    format!("Unknown scope: `{}`", scope),
+                )))
+            }
+        }
+    }
+    if scopes.is_empty() {
+        return Ok(Response::with((
+            status::UnprocessableEntity,
+            "Missing value for field: `scopes`",
+        )));
+    }
+    for origin in body.origins.iter() {
+        if !check_origin_access(req, origin).unwrap_or(false) {
+            return Ok(Response::with(status::Forbidden));
+        }
+    }
+    request.set_name(body.name);
+    request.set_origins(RepeatedField::from_vec(body.origins));
+    request.set_scopes(scopes);
+    request.set_expires_at(body.expires_at);
+
+    match route_message::<AccessTokenCreate, AccessToken>(req, &request) {
+        Ok(token) => {
+            audit_access_token(req, &token, OriginAuditAction::AccessTokenCreate);
+            let mut response = render_json(status::Created, &token);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn revoke_access_token(req: &mut Request) -> IronResult<Response> {
+    let mut request = AccessTokenRevoke::new();
+    match get_param(req, "id").and_then(|id| id.parse::<u64>().ok()) {
+        Some(id) => request.set_id(id),
+        None => return Ok(Response::with(status::BadRequest)),
+    }
+    {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        request.set_account_id(session.get_id());
+    }
+    let mut list_request = AccessTokenListRequest::new();
+    list_request.set_account_id(request.get_account_id());
+    let token = match route_message::<AccessTokenListRequest, AccessTokenListResponse>(
+        req,
+        &list_request,
+    ) {
+        Ok(tokens) => {
+            tokens
+                .get_tokens()
+                .iter()
+                .find(|token| token.get_id() == request.get_id())
+                .cloned()
+        }
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+    match route_message::<AccessTokenRevoke, NetOk>(req, &request) {
+        Ok(_) => {
+            if let Some(token) = token {
+                audit_access_token(req, &token, OriginAuditAction::AccessTokenRevoke);
+            }
+            Ok(Response::with(status::NoContent))
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Access tokens belong to an account rather than an origin, so changes to one are recorded in
+/// the log of every origin it can act on.
+fn audit_access_token(req: &mut Request, token: &AccessToken, action: OriginAuditAction) {
+    let origins = if token.get_origins().is_empty() {
+        let mut request = AccountOriginListRequest::new();
+        request.set_account_id(token.get_account_id());
+        match route_message::<AccountOriginListRequest, AccountOriginListResponse>(req, &request) {
+            Ok(response) => response.get_origins().to_vec(),
+            Err(err) => {
+                warn!(
+                    "Failed to list origins to audit access token, id={}, {}",
+                    token.get_id(),
+                    err
+                );
+                return;
+            }
+        }
+    } else {
+        token.get_origins().to_vec()
+    };
+    for origin in origins.iter() {
+        helpers::audit(
+            req,
+            origin,
+            action,
+            &token.get_id().to_string(),
+            token.get_name(),
+        );
+    }
+}
+
 /// Create a new project as the authenticated user and associated to
 /// the given origin.
 pub fn project_create(req: &mut Request) -> IronResult<Response> {
@@ -638,6 +802,13 @@ pub fn project_create(req: &mut Request) -> IronResult<Response> {
                     account: session.get_id().to_string(),
                 }
             );
+            helpers::audit(
+                req,
+                origin.get_name(),
+                OriginAuditAction::ProjectCreate,
+                response.get_name(),
+                "",
+            );
             Ok(render_json(status::Created, &response))
         }
         Err(err) => Ok(render_net_error(&err)),
@@ -665,13 +836,22 @@ pub fn project_delete(req: &mut Request) -> IronResult<Response> {
 
     project_del.set_name(format!("{}/{}", &origin, &name));
 
-    if !check_origin_access(req, origin).unwrap_or(false) {
+    if !check_origin_access(req, &origin).unwrap_or(false) {
         return Ok(Response::with(status::Forbidden));
     }
 
     project_del.set_requestor_id(session_id);
     match route_message::<OriginProjectDelete, NetOk>(req, &project_del) {
-        Ok(_) => Ok(Response::with(status::NoContent)),
+        Ok(_) => {
+            helpers::audit(
+                req,
+                &origin,
+                OriginAuditAction::ProjectDelete,
+                project_del.get_name(),
+                "",
+            );
+            Ok(Response::with(status::NoContent))
+        }
         Err(err) => Ok(render_net_error(&err)),
     }
 }
@@ -748,7 +928,7 @@ pub fn project_update(req: &mut Request) -> IronResult<Response> {
                             if plan.name != name {
                                 return Ok(Response::with((status::UnprocessableEntity, "rg:pu:2")));
                             }
-                            project.set_origin_name(String::from(origin));
+                            project.set_origin_name(origin.to_string());
                             project.set_package_name(String::from(name));
                         }
                         Err(e) => {
@@ -773,7 +953,16 @@ pub fn project_update(req: &mut Request) -> IronResult<Response> {
     request.set_requestor_id(session_id);
     request.set_project(project);
     match route_message::<OriginProjectUpdate, NetOk>(req, &request) {
-        Ok(_) => Ok(Response::with(status::NoContent)),
+        Ok(_) => {
+            helpers::audit(
+                req,
+                &origin,
+                OriginAuditAction::ProjectUpdate,
+                project_get.get_name(),
+                "",
+            );
+            Ok(Response::with(status::NoContent))
+        }
         Err(err) => Ok(render_net_error(&err)),
     }
 }
@@ -913,7 +1102,7 @@ pub fn create_project_integration(req: &mut Request) -> IronResult<Response> {
         }
     };
 
-    if !check_origin_access(req, &params["origin"]).unwrap_or(false) {
+    if !check_origin_role(req, &params["origin"], OriginMemberRole::Owner).unwrap_or(false) {
         return Ok(Response::with(status::Forbidden));
     }
 
@@ -931,7 +1120,16 @@ pub fn create_project_integration(req: &mut Request) -> IronResult<Response> {
     request.set_integration(opi);
 
     match route_message::<OriginProjectIntegrationCreate, NetOk>(req, &request) {
-        Ok(_) => Ok(Response::with(status::NoContent)),
+        Ok(_) => {
+            helpers::audit(
+                req,
+                &params["origin"],
+                OriginAuditAction::ProjectIntegrationCreate,
+                &format!("{}/{}", params["origin"], params["name"]),
+                &params["integration"],
+            );
+            Ok(Response::with(status::NoContent))
+        }
         Err(err) => {
             if err.get_code() == ErrCode::ENTITY_CONFLICT {
                 warn!("Failed to create integration as it already exists");
@@ -950,7 +1148,7 @@ pub fn delete_project_integration(req: &mut Request) -> IronResult<Response> {
         Err(st) => return Ok(Response::with(st)),
     };
 
-    if !check_origin_access(req, &params["origin"]).unwrap_or(false) {
+    if !check_origin_role(req, &params["origin"], OriginMemberRole::Owner).unwrap_or(false) {
         return Ok(Response::with(status::Forbidden));
     }
 
@@ -960,7 +1158,16 @@ pub fn delete_project_integration(req: &mut Request) -> IronResult<Response> {
     request.set_integration(params["integration"].clone());
 
     match route_message::<OriginProjectIntegrationDelete, NetOk>(req, &request) {
-        Ok(_) => Ok(Response::with(status::NoContent)),
+        Ok(_) => {
+            helpers::audit(
+                req,
+                &params["origin"],
+                OriginAuditAction::ProjectIntegrationDelete,
+                &format!("{}/{}", params["origin"], params["name"]),
+                &params["integration"],
+            );
+            Ok(Response::with(status::NoContent))
+        }
         Err(err) => {
             error!("delete_project_integration:1, err={:?}", err);
             Ok(Response::with(status::InternalServerError))
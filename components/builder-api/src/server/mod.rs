@@ -21,7 +21,9 @@ use http_gateway;
 use http_gateway::app::prelude::*;
 use iron;
 use mount::Mount;
+use oauth_client;
 use persistent::{self, Read};
+use protocol::sessionsrv::AccessTokenScope;
 use segment_api_client::SegmentClient;
 use staticfile::Static;
 
@@ -40,6 +42,9 @@ impl HttpGateway for ApiSrv {
         chain.link(persistent::Read::<GitHubCli>::both(
             GitHubClient::new(config.github.clone()),
         ));
+        chain.link(persistent::Read::<OAuthCli>::both(
+            oauth_client::new_provider(&config.oauth),
+        ));
         chain.link(persistent::Read::<SegmentCli>::both(
             SegmentClient::new(config.segment.clone()),
         ));
@@ -49,11 +54,16 @@ impl HttpGateway for ApiSrv {
         chain.link_after(Cors);
     }
 
-    fn mount(config: Arc<Self::Config>, chain: iron::Chain) -> Mount {
+    fn mount(config: Arc<Self::Config>, chain: iron::Chain) -> AppResult<Mount> {
         let mut depot_config = config.depot.clone();
         depot_config.segment = config.segment.clone();
-        let depot = depot::DepotUtil::new(depot_config);
-        let depot_chain = depot::server::router(depot).unwrap();
+        let depot = depot::DepotUtil::new(depot_config.clone()).map_err(
+            |e| AppError::Mount(format!("depot, {}", e)),
+        )?;
+        let depot_chain = depot::server::router(depot).map_err(|e| {
+            AppError::Mount(format!("depot, {}", e))
+        })?;
+        depot::upstream::start(depot_config);
         let mut mount = Mount::new();
         if let Some(ref path) = config.ui.root {
             debug!("Mounting UI at filepath {}", path);
@@ -61,15 +71,19 @@ impl HttpGateway for ApiSrv {
         }
         mount.mount("/v1", chain);
         mount.mount("/v1/depot", depot_chain);
-        mount
+        Ok(mount)
     }
 
     fn router(config: Arc<Self::Config>) -> Router {
         let basic = Authenticated::new(config.github.clone());
+        let group_promote = basic
+            .clone()
+            .allow_token(AccessTokenScope::Promote)
+            .origin_from_group();
 
         router!(
             status: get "/status" => status,
-            authenticate: get "/authenticate/:code" => github_authenticate,
+            authenticate: get "/authenticate/:code" => oauth_authenticate,
             notify: post "/notify" => notify,
             update_profile: patch "/profile" => XHandler::new(update_profile).before(basic.clone()),
             get_profile: get "/profile" => XHandler::new(get_profile).before(basic.clone()),
@@ -77,10 +91,10 @@ impl HttpGateway for ApiSrv {
             job: get "/jobs/:id" => XHandler::new(job_show).before(basic.clone()),
             job_log: get "/jobs/:id/log" => XHandler::new(job_log).before(basic.clone()),
             job_group_promote: post "/jobs/group/:id/promote/:channel" => {
-                XHandler::new(job_group_promote).before(basic.clone())
+                XHandler::new(job_group_promote).before(group_promote.clone())
             },
             job_group_demote: post "/jobs/group/:id/demote/:channel" => {
-                XHandler::new(job_group_demote).before(basic.clone())
+                XHandler::new(job_group_demote).before(group_promote.clone())
             },
             job_group_cancel: post "/jobs/group/:id/cancel" => {
                 XHandler::new(job_group_cancel).before(basic.clone())
@@ -93,6 +107,15 @@ impl HttpGateway for ApiSrv {
             user_origins: get "/user/origins" => {
                 XHandler::new(list_user_origins).before(basic.clone())
             },
+            user_tokens: get "/user/tokens" => {
+                XHandler::new(list_access_tokens).before(basic.clone())
+            },
+            user_token_create: post "/user/tokens" => {
+                XHandler::new(create_access_token).before(basic.clone())
+            },
+            user_token_revoke: delete "/user/tokens/:id" => {
+                XHandler::new(revoke_access_token).before(basic.clone())
+            },
 
             projects: post "/projects" => XHandler::new(project_create).before(basic.clone()),
             project: get "/projects/:origin/:name" => {
@@ -39,6 +39,17 @@ pub struct UserUpdateReq {
     pub email: String,
 }
 
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AccessTokenCreateReq {
+    pub name: String,
+    #[serde(default)]
+    pub origins: Vec<String>,
+    pub scopes: Vec<String>,
+    /// Seconds since the epoch, tokens without an expiry stay valid until revoked
+    #[serde(default)]
+    pub expires_at: u64,
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct GroupPromoteReq {
     pub idents: Vec<String>,
@@ -27,6 +27,7 @@ pub enum Error {
     EncryptError(String),
     FromUtf8Error(string::FromUtf8Error),
     HabitatCore(hab_core::Error),
+    WebhookDestination(String),
 }
 
 pub type Result<T> = result::Result<T, Error>;
@@ -39,6 +40,7 @@ impl fmt::Display for Error {
             Error::EncryptError(ref e) => format!("{}", e),
             Error::FromUtf8Error(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
+            Error::WebhookDestination(ref e) => format!("Webhook destination not allowed, {}", e),
         };
         write!(f, "{}", msg)
     }
@@ -52,6 +54,7 @@ impl error::Error for Error {
             Error::EncryptError(_) => "Error encrypting integration",
             Error::FromUtf8Error(ref e) => e.description(),
             Error::HabitatCore(ref err) => err.description(),
+            Error::WebhookDestination(_) => "Webhook destination not allowed",
         }
     }
 }
@@ -48,5 +48,6 @@ pub mod package_graph;
 pub mod rdeps;
 pub mod target_graph;
 pub mod job;
+pub mod webhooks;
 
 pub use error::Error;
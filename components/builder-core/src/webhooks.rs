@@ -0,0 +1,138 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks on where origin webhooks may deliver to. Webhooks are registered by origin owners, so
+//! they mustn't be able to point Builder at itself or at other hosts on its private network.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs};
+
+use error::{Error, Result};
+
+/// Resolves the host of a webhook's URL, failing if any of its addresses may not receive
+/// deliveries. Every address is checked since the one connected to is up to the resolver.
+pub fn check_destination(host: &str, port: u16) -> Result<()> {
+    resolve_destination(host, port).map(|_| ())
+}
+
+/// Resolves the host of a webhook's URL like `check_destination`, returning the address to
+/// deliver to. Deliveries must connect to exactly this address: resolving the host again when
+/// connecting could give a different answer, pointing the delivery at a private host after all.
+pub fn resolve_destination(host: &str, port: u16) -> Result<SocketAddr> {
+    let host = host.trim_left_matches('[').trim_right_matches(']');
+    let addrs = (host, port).to_socket_addrs().map_err(|e| {
+        Error::WebhookDestination(format!("unable to resolve {}, {}", host, e))
+    })?;
+    let mut destination = None;
+    for addr in addrs {
+        if !is_public(&addr.ip()) {
+            return Err(Error::WebhookDestination(
+                format!("{} resolves to {}", host, addr.ip()),
+            ));
+        }
+        destination = destination.or(Some(addr));
+    }
+    destination.ok_or(Error::WebhookDestination(
+        format!("{} has no addresses", host),
+    ))
+}
+
+/// Whether an address is routable on the public internet.
+pub fn is_public(addr: &IpAddr) -> bool {
+    match *addr {
+        IpAddr::V4(ref addr) => is_public_v4(addr),
+        IpAddr::V6(ref addr) => is_public_v6(addr),
+    }
+}
+
+fn is_public_v4(addr: &Ipv4Addr) -> bool {
+    let octets = addr.octets();
+    !(addr.is_unspecified() || addr.is_loopback() || addr.is_private() ||
+          addr.is_link_local() || addr.is_broadcast() || addr.is_multicast() ||
+          addr.is_documentation() || octets[0] == 0 ||
+          // Shared address space used by carrier-grade NAT
+          (octets[0] == 100 && (octets[1] & 0xc0) == 64))
+}
+
+fn is_public_v6(addr: &Ipv6Addr) -> bool {
+    let segments = addr.segments();
+    // Addresses of IPv4 hosts mapped into IPv6 are judged by the IPv4 address
+    if segments[..5].iter().all(|s| *s == 0) && segments[5] == 0xffff {
+        let octets = addr.octets();
+        return is_public_v4(&Ipv4Addr::new(
+            octets[12],
+            octets[13],
+            octets[14],
+            octets[15],
+        ));
+    }
+    !(addr.is_unspecified() || addr.is_loopback() || addr.is_multicast() ||
+          // Unique local addresses, fc00::/7
+          (segments[0] & 0xfe00) == 0xfc00 ||
+          // Link-local addresses, fe80::/10
+          (segments[0] & 0xffc0) == 0xfe80)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn public(addr: &str) -> bool {
+        is_public(&addr.parse().unwrap())
+    }
+
+    #[test]
+    fn private_addresses() {
+        for addr in &[
+            "0.0.0.0",
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "255.255.255.255",
+            "::",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "::ffff:169.254.169.254",
+        ]
+        {
+            assert!(!public(addr), "{} should not be public", addr);
+        }
+    }
+
+    #[test]
+    fn public_addresses() {
+        for addr in &["8.8.8.8", "100.128.0.1", "2001:4860:4860::8888", "::ffff:8.8.8.8"] {
+            assert!(public(addr), "{} should be public", addr);
+        }
+    }
+
+    #[test]
+    fn loopback_destinations() {
+        assert!(check_destination("localhost", 80).is_err());
+        assert!(check_destination("127.0.0.1", 8080).is_err());
+        assert!(check_destination("[::1]", 443).is_err());
+    }
+
+    #[test]
+    fn public_destinations_resolve_to_their_address() {
+        let addr = resolve_destination("8.8.8.8", 443).unwrap();
+        assert_eq!(addr, "8.8.8.8:443".parse().unwrap());
+        let addr = resolve_destination("[2001:4860:4860::8888]", 80).unwrap();
+        assert_eq!(addr, "[2001:4860:4860::8888]:80".parse().unwrap());
+    }
+}
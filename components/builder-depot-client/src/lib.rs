@@ -35,25 +35,28 @@ extern crate serde_derive;
 #[macro_use]
 extern crate serde_json;
 extern crate tee;
+#[cfg(test)]
+extern crate tempdir;
 extern crate url;
 
 pub mod error;
 pub use error::{Error, Result};
 
 use std::fmt;
-use std::fs::{self, File};
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::string::ToString;
 
 use broadcast::BroadcastWriter;
 use chrono::DateTime;
-use hab_core::package::{Identifiable, PackageArchive};
+use hab_core::package::{Identifiable, PackageArchive, PackageTarget};
 use hab_http::ApiClient;
 use hab_http::util::decoded_response;
 use hyper::client::{Body, IntoUrl, Response, RequestBuilder};
 use hyper::status::StatusCode;
-use hyper::header::{Authorization, Bearer};
+use hyper::header::{Authorization, Bearer, ByteRangeSpec, ContentRange, ContentRangeSpec,
+                    ContentType, Range};
 use hyper::Url;
 use protobuf::core::ProtobufEnum;
 use protocol::{originsrv, net};
@@ -207,6 +210,8 @@ pub struct Package {
     pub tdeps: Vec<PackageIdent>,
     pub exposes: Vec<u32>,
     pub config: String,
+    #[serde(default)]
+    pub target: String,
 }
 
 impl Into<originsrv::OriginPackage> for Package {
@@ -219,6 +224,7 @@ impl Into<originsrv::OriginPackage> for Package {
         out.set_tdeps(self.tdeps.into_iter().map(|m| m.into()).collect());
         out.set_exposes(self.exposes);
         out.set_config(self.config);
+        out.set_target(self.target);
         out
     }
 }
@@ -255,6 +261,17 @@ pub struct OriginChannelIdent {
     pub name: String,
 }
 
+#[derive(Clone, Deserialize)]
+pub struct OriginMember {
+    pub account_name: String,
+    pub role: String,
+}
+
+#[derive(Deserialize)]
+struct OriginMemberList {
+    roles: Vec<OriginMember>,
+}
+
 impl Into<originsrv::OriginChannelIdent> for OriginChannelIdent {
     fn into(self) -> originsrv::OriginChannelIdent {
         let mut out = originsrv::OriginChannelIdent::new();
@@ -612,6 +629,9 @@ impl Client {
     /// e.g. /pkgs/core/redis/latest but that only _shows_ you the details - it doesn't download
     /// the package.
     ///
+    /// An interrupted download leaves a partial file behind in `dst_path`, and the next call for
+    /// the same package picks up where it left off if the depot supports range requests.
+    ///
     /// # Failures
     ///
     /// * Package cannot be found
@@ -632,7 +652,57 @@ impl Client {
         // Given that the download URL requires a fully qualified package, the channel is
         // irrelevant, per https://github.com/habitat-sh/habitat/issues/2722. This function is fine
         // as is.
-        match self.download(&package_download(ident), dst_path.as_ref(), token, progress) {
+        self.fetch_package_impl(ident, None, token, dst_path.as_ref(), progress)
+    }
+
+    /// Downloads a release like `fetch_package`, but the one built for `target` instead of for
+    /// the platform we're running on.
+    ///
+    /// # Failures
+    ///
+    /// * Package cannot be found
+    /// * Remote Builder is not available
+    /// * File cannot be created and written to
+    pub fn fetch_package_for_target<D, I, P>(
+        &self,
+        ident: &I,
+        target: &PackageTarget,
+        token: Option<&str>,
+        dst_path: &P,
+        progress: Option<D>,
+    ) -> Result<PackageArchive>
+    where
+        P: AsRef<Path> + ?Sized,
+        I: Identifiable,
+        D: DisplayProgress + Sized,
+    {
+        self.fetch_package_impl(ident, Some(target), token, dst_path.as_ref(), progress)
+    }
+
+    fn fetch_package_impl<D, I>(
+        &self,
+        ident: &I,
+        target: Option<&PackageTarget>,
+        token: Option<&str>,
+        dst_path: &Path,
+        progress: Option<D>,
+    ) -> Result<PackageArchive>
+    where
+        I: Identifiable,
+        D: DisplayProgress + Sized,
+    {
+        let partial_name = match target {
+            Some(target) => format!("{}-{}.part", ident.to_string().replace("/", "-"), target),
+            None => format!("{}.part", ident.to_string().replace("/", "-")),
+        };
+        match self.download_resumable(
+            &package_download(ident),
+            dst_path,
+            &partial_name,
+            target,
+            token,
+            progress,
+        ) {
             Ok(file) => Ok(PackageArchive::new(PathBuf::from(file))),
             Err(e) => Err(e),
         }
@@ -653,6 +723,39 @@ impl Client {
         channel: Option<&str>,
         token: Option<&str>,
     ) -> Result<originsrv::OriginPackage>
+    where
+        I: Identifiable,
+    {
+        self.show_package_impl(package, channel, None, token)
+    }
+
+    /// Returns a package struct like `show_package`, but for packages built for `target` instead
+    /// of for the platform we're running on.
+    ///
+    /// # Failures
+    ///
+    /// * Package cannot be found
+    /// * Remote Builder is not available
+    pub fn show_package_for_target<I>(
+        &self,
+        package: &I,
+        channel: Option<&str>,
+        target: &PackageTarget,
+        token: Option<&str>,
+    ) -> Result<originsrv::OriginPackage>
+    where
+        I: Identifiable,
+    {
+        self.show_package_impl(package, channel, Some(target), token)
+    }
+
+    fn show_package_impl<I>(
+        &self,
+        package: &I,
+        channel: Option<&str>,
+        target: Option<&PackageTarget>,
+        token: Option<&str>,
+    ) -> Result<originsrv::OriginPackage>
     where
         I: Identifiable,
     {
@@ -668,7 +771,12 @@ impl Client {
             url.push_str("/latest");
         }
 
-        let mut res = self.maybe_add_authz(self.0.get(&url), token).send()?;
+        let mut req = self.maybe_add_authz(self.0.get(&url), token);
+        // Builder picks releases for the platform named in the User-Agent.
+        if let Some(target) = target {
+            req = req.header(self.0.user_agent_for_target(&target.to_string()));
+        }
+        let mut res = req.send()?;
         if res.status != StatusCode::Ok {
             return Err(err_from_response(res));
         }
@@ -876,7 +984,117 @@ impl Client {
         }
     }
 
-    /// Returns a vector of PackageIdent structs
+    /// Return the members of an origin along with their roles
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * Authorization token was not set on client
+    pub fn list_origin_members(&self, origin: &str, token: &str) -> Result<Vec<OriginMember>> {
+        let path = format!("depot/origins/{}/users", origin);
+        let mut res = self.add_authz(self.0.get(&path), token).send()?;
+
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        };
+
+        let mut encoded = String::new();
+        res.read_to_string(&mut encoded)?;
+        debug!("Response body: {:?}", encoded);
+        let list: OriginMemberList = serde_json::from_str(&encoded)?;
+        Ok(list.roles)
+    }
+
+    /// Change the role a member holds in an origin
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * Account is not a member of the origin
+    /// * Authorization token was not set on client
+    pub fn update_origin_member_role(
+        &self,
+        origin: &str,
+        member: &str,
+        role: &str,
+        token: &str,
+    ) -> Result<()> {
+        let path = format!("depot/origins/{}/users/{}/role", origin, member);
+        debug!("Updating origin member role, path: {:?}", path);
+        let body = json!({ "role": role }).to_string();
+
+        let res = self.add_authz(self.0.put(&path), token)
+            .header(ContentType::json())
+            .body(body.as_str())
+            .send()?;
+
+        if res.status != StatusCode::NoContent {
+            return Err(err_from_response(res));
+        };
+
+        Ok(())
+    }
+
+    /// Remove a member from an origin
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * Authorization token was not set on client
+    pub fn delete_origin_member(&self, origin: &str, member: &str, token: &str) -> Result<()> {
+        let path = format!("depot/origins/{}/users/{}", origin, member);
+        debug!("Deleting origin member, path: {:?}", path);
+
+        let res = self.add_authz(self.0.delete(&path), token).send()?;
+
+        if res.status != StatusCode::NoContent {
+            return Err(err_from_response(res));
+        };
+
+        Ok(())
+    }
+
+    /// Returns one page of the PackageIdent structs of every release of an origin's packages in a
+    /// channel, starting at the zero-based `range`, along with where the next page starts if there
+    /// are more.
+    ///
+    /// # Failures
+    ///
+    /// * Remote depot unavailable
+    /// * Channel does not exist
+    pub fn list_channel_packages(
+        &self,
+        origin: &str,
+        channel: &str,
+        range: usize,
+        token: Option<&str>,
+    ) -> Result<(Vec<hab_core::package::PackageIdent>, Option<usize>)> {
+        let path = format!("depot/channels/{}/{}/pkgs", origin, channel);
+        let custom = |url: &mut Url| {
+            url.query_pairs_mut().append_pair("range", &range.to_string());
+        };
+        let mut res = self.maybe_add_authz(self.0.get_with_custom_url(&path, custom), token)
+            .send()?;
+        match res.status {
+            StatusCode::Ok |
+            StatusCode::PartialContent => {
+                let mut encoded = String::new();
+                res.read_to_string(&mut encoded)?;
+                let package_results: PackageResults<hab_core::package::PackageIdent> =
+                    serde_json::from_str(&encoded)?;
+                let next = if res.status == StatusCode::PartialContent {
+                    Some((package_results.range_end + 1) as usize)
+                } else {
+                    None
+                };
+                Ok((package_results.data, next))
+            }
+            _ => Err(err_from_response(res)),
+        }
+    }
+
+    /// Returns one page of the PackageIdent structs matching a search, starting at the zero-based
+    /// `range`, along with where the next page starts if there are more results.
     ///
     /// # Failures
     ///
@@ -884,10 +1102,16 @@ impl Client {
     pub fn search_package(
         &self,
         search_term: &str,
+        range: usize,
         token: Option<&str>,
-    ) -> Result<(Vec<hab_core::package::PackageIdent>, bool)> {
-        let mut res = self.maybe_add_authz(self.0.get(&package_search(search_term)), token)
-            .send()?;
+    ) -> Result<(Vec<hab_core::package::PackageIdent>, Option<usize>)> {
+        let custom = |url: &mut Url| {
+            url.query_pairs_mut().append_pair("range", &range.to_string());
+        };
+        let mut res = self.maybe_add_authz(
+            self.0.get_with_custom_url(&package_search(search_term), custom),
+            token,
+        ).send()?;
         match res.status {
             StatusCode::Ok |
             StatusCode::PartialContent => {
@@ -895,8 +1119,14 @@ impl Client {
                 res.read_to_string(&mut encoded)?;
                 let package_results: PackageResults<hab_core::package::PackageIdent> =
                     serde_json::from_str(&encoded)?;
-                let packages: Vec<hab_core::package::PackageIdent> = package_results.data;
-                Ok((packages, res.status == StatusCode::PartialContent))
+                // The depot answers with Partial Content whenever there are results past this
+                // page.
+                let next = if res.status == StatusCode::PartialContent {
+                    Some((package_results.range_end + 1) as usize)
+                } else {
+                    None
+                };
+                Ok((package_results.data, next))
             }
             _ => Err(err_from_response(res)),
         }
@@ -970,6 +1200,105 @@ impl Client {
         Ok(dst_file_path)
     }
 
+    /// Like `download`, but writes to a partial file with a known name, and asks only for the
+    /// bytes which are missing from it if it is already there.
+    fn download_resumable<D>(
+        &self,
+        path: &str,
+        dst_path: &Path,
+        partial_name: &str,
+        target: Option<&PackageTarget>,
+        token: Option<&str>,
+        progress: Option<D>,
+    ) -> Result<PathBuf>
+    where
+        D: DisplayProgress + Sized,
+    {
+        let partial_file_path = dst_path.join(partial_name);
+        let offset = fs::metadata(&partial_file_path).map(|m| m.len()).unwrap_or(0);
+        let mut req = self.maybe_add_authz(self.0.get(path), token);
+        // Builder serves the archive for the platform named in the User-Agent.
+        if let Some(target) = target {
+            req = req.header(self.0.user_agent_for_target(&target.to_string()));
+        }
+        if offset > 0 {
+            debug!("Resuming download of {} from byte {}", path, offset);
+            req = req.header(Range::Bytes(vec![ByteRangeSpec::AllFrom(offset)]));
+        }
+        let mut res = req.send()?;
+
+        debug!("Response: {:?}", res);
+
+        let append = match res.status {
+            StatusCode::PartialContent if offset > 0 => true,
+            StatusCode::Ok => false,
+            StatusCode::RangeNotSatisfiable if offset > 0 => {
+                // Nothing is left to send when we already have the whole artifact.
+                let complete = match res.headers.get::<ContentRange>() {
+                    Some(&ContentRange(ContentRangeSpec::Bytes { instance_length, .. })) => {
+                        instance_length == Some(offset)
+                    }
+                    _ => false,
+                };
+                if let (true, Some(file_name)) = (complete, res.headers.get::<XFileName>()) {
+                    let dst_file_path = dst_path.join(format!("{}", file_name));
+                    debug!(
+                        "Moving complete {} to {}",
+                        &partial_file_path.display(),
+                        &dst_file_path.display()
+                    );
+                    fs::rename(&partial_file_path, &dst_file_path)?;
+                    return Ok(dst_file_path);
+                }
+                // Whatever we have doesn't line up with the artifact, so start over.
+                debug!("Discarding {} and starting over", &partial_file_path.display());
+                fs::remove_file(&partial_file_path)?;
+                drop(res);
+                return self.download_resumable(
+                    path,
+                    dst_path,
+                    partial_name,
+                    target,
+                    token,
+                    progress,
+                );
+            }
+            _ => return Err(err_from_response(res)),
+        };
+        fs::create_dir_all(&dst_path)?;
+
+        let file_name = match res.headers.get::<XFileName>() {
+            Some(filename) => format!("{}", filename),
+            None => return Err(Error::NoXFilename),
+        };
+        let dst_file_path = dst_path.join(file_name);
+        debug!("Writing to {}", &partial_file_path.display());
+        let mut f = if append {
+            OpenOptions::new().append(true).open(&partial_file_path)?
+        } else {
+            File::create(&partial_file_path)?
+        };
+        match progress {
+            Some(mut progress) => {
+                let size: u64 = res.headers.get::<hyper::header::ContentLength>().map_or(
+                    0,
+                    |v| **v,
+                );
+                progress.size(size);
+                let mut writer = BroadcastWriter::new(&mut f, progress);
+                io::copy(&mut res, &mut writer)?
+            }
+            None => io::copy(&mut res, &mut f)?,
+        };
+        debug!(
+            "Moving {} to {}",
+            &partial_file_path.display(),
+            &dst_file_path.display()
+        );
+        fs::rename(&partial_file_path, &dst_file_path)?;
+        Ok(dst_file_path)
+    }
+
     // TODO: Ideally we would have a single download function that can support
     // both progress and non-progress versions, however the Rust compiler cannot
     // infer the type for a None for a Display + Sized trait, and makes this task
@@ -1121,9 +1450,162 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::cmp;
+    use std::fs;
+    use std::io::Write;
+    use std::path::Path;
+
+    use hyper::header::{ByteRangeSpec, ContentRange, ContentRangeSpec, Range};
+    use hyper::server::{Listening, Request, Response, Server};
+    use hyper::status::StatusCode;
     use serde_json;
+    use tempdir::TempDir;
+
     use super::*;
 
+    const ARCHIVE: &'static [u8] = b"0123456789";
+    const PARTIAL: &'static str = "core-redis-3.2.4-20170514150022.part";
+
+    // Serves `ARCHIVE` the way Builder does, from wherever a `Range` header asks for if
+    // `honor_range` is set, and with `status` in place of the usual one if it's given. Ranges
+    // starting past the end aren't satisfiable.
+    fn start_depot(honor_range: bool, status: Option<StatusCode>) -> Listening {
+        Server::http("127.0.0.1:0")
+            .unwrap()
+            .handle(move |req: Request, mut res: Response| {
+                let offset = match req.headers.get::<Range>() {
+                    Some(&Range::Bytes(ref ranges)) if honor_range => {
+                        match ranges.first() {
+                            Some(&ByteRangeSpec::AllFrom(offset)) => offset as usize,
+                            _ => 0,
+                        }
+                    }
+                    _ => 0,
+                };
+                *res.status_mut() = match status {
+                    Some(status) => status,
+                    None if offset >= ARCHIVE.len() => StatusCode::RangeNotSatisfiable,
+                    None if offset > 0 => StatusCode::PartialContent,
+                    None => StatusCode::Ok,
+                };
+                res.headers_mut().set(XFileName(
+                    "core-redis-3.2.4-20170514150022-x86_64-linux.hart".to_string(),
+                ));
+                if *res.status_mut() == StatusCode::RangeNotSatisfiable {
+                    res.headers_mut().set(ContentRange(ContentRangeSpec::Bytes {
+                        range: None,
+                        instance_length: Some(ARCHIVE.len() as u64),
+                    }));
+                    res.send(b"").unwrap();
+                    return;
+                }
+                res.send(&ARCHIVE[cmp::min(offset, ARCHIVE.len())..])
+                    .unwrap();
+            })
+            .unwrap()
+    }
+
+    fn download(listening: &Listening, dst_path: &Path) -> Result<PathBuf> {
+        let client = Client::new(
+            format!("http://{}", listening.socket).as_str(),
+            "test",
+            "0.0.0",
+            None,
+        ).unwrap();
+        client.download_resumable(
+            "/pkgs/core/redis/3.2.4/20170514150022/download",
+            dst_path,
+            PARTIAL,
+            None,
+            None,
+            None::<NoProgress>,
+        )
+    }
+
+    fn write_partial(dst_path: &Path, body: &[u8]) {
+        File::create(dst_path.join(PARTIAL))
+            .unwrap()
+            .write_all(body)
+            .unwrap();
+    }
+
+    fn read(path: &Path) -> Vec<u8> {
+        let mut body = vec![];
+        File::open(path).unwrap().read_to_end(&mut body).unwrap();
+        body
+    }
+
+    struct NoProgress;
+
+    impl Write for NoProgress {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl DisplayProgress for NoProgress {
+        fn size(&mut self, _: u64) {}
+        fn finish(&mut self) {}
+    }
+
+    #[test]
+    fn download_resumes_a_partial_file() {
+        let mut listening = start_depot(true, None);
+        let tmp = TempDir::new("depot-client").unwrap();
+        write_partial(tmp.path(), &ARCHIVE[..4]);
+        let path = download(&listening, tmp.path()).unwrap();
+        assert_eq!(read(&path), ARCHIVE);
+        assert!(!tmp.path().join(PARTIAL).exists());
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn download_starts_over_when_the_range_is_ignored() {
+        let mut listening = start_depot(false, None);
+        let tmp = TempDir::new("depot-client").unwrap();
+        write_partial(tmp.path(), b"stale");
+        let path = download(&listening, tmp.path()).unwrap();
+        assert_eq!(read(&path), ARCHIVE);
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn download_starts_over_when_the_partial_file_does_not_line_up() {
+        let mut listening = start_depot(true, None);
+        let tmp = TempDir::new("depot-client").unwrap();
+        write_partial(tmp.path(), b"0123456789 and then some");
+        let path = download(&listening, tmp.path()).unwrap();
+        assert_eq!(read(&path), ARCHIVE);
+        assert!(!tmp.path().join(PARTIAL).exists());
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn download_finishes_a_partial_file_which_is_already_complete() {
+        let mut listening = start_depot(true, None);
+        let tmp = TempDir::new("depot-client").unwrap();
+        write_partial(tmp.path(), ARCHIVE);
+        let path = download(&listening, tmp.path()).unwrap();
+        assert_eq!(read(&path), ARCHIVE);
+        assert!(!tmp.path().join(PARTIAL).exists());
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn download_removes_a_partial_file_when_no_range_is_satisfiable() {
+        let mut listening = start_depot(true, Some(StatusCode::RangeNotSatisfiable));
+        let tmp = TempDir::new("depot-client").unwrap();
+        write_partial(tmp.path(), b"0123");
+        assert!(download(&listening, tmp.path()).is_err());
+        assert!(!tmp.path().join(PARTIAL).exists());
+        assert_eq!(fs::read_dir(tmp.path()).unwrap().count(), 0);
+        listening.close().unwrap();
+    }
+
     #[test]
     fn json_round_trip_u64_fields() {
         let pre = OriginSecretKey {
@@ -17,6 +17,7 @@ use std::io;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr, ToSocketAddrs};
 use std::option::IntoIter;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use hab_core::config::ConfigFile;
 use hab_core::os::system::{Architecture, Platform};
@@ -33,6 +34,8 @@ pub struct Config {
     /// List of net addresses for routing servers to connect to
     pub routers: Vec<RouterAddr>,
     pub github: GitHubCfg,
+    /// Identity provider users sign in with
+    pub oauth: OAuthCfg,
     pub segment: SegmentCfg,
     /// Filepath to location on disk to store entities
     pub path: PathBuf,
@@ -48,6 +51,10 @@ pub struct Config {
     pub key_dir: PathBuf,
     /// A list of package platform and architecture combinations which can be uploaded and hosted
     pub targets: Vec<PackageTarget>,
+    /// Where package archives are stored
+    pub storage: StorageCfg,
+    /// Another depot whose packages are mirrored into this one
+    pub upstream: UpstreamCfg,
 }
 
 impl ConfigFile for Config {
@@ -60,6 +67,7 @@ impl Default for Config {
             http: HttpCfg::default(),
             routers: vec![RouterAddr::default()],
             github: GitHubCfg::default(),
+            oauth: OAuthCfg::default(),
             segment: SegmentCfg::default(),
             path: PathBuf::from("/hab/svc/builder-api/data"),
             events_enabled: false, // TODO: change to default to true later
@@ -71,6 +79,8 @@ impl Default for Config {
                 PackageTarget::new(Platform::Linux, Architecture::X86_64),
                 PackageTarget::new(Platform::Windows, Architecture::X86_64),
             ],
+            storage: StorageCfg::default(),
+            upstream: UpstreamCfg::default(),
         }
     }
 }
@@ -116,6 +126,106 @@ impl ToSocketAddrs for HttpCfg {
     }
 }
 
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    /// Archives are kept under `path` on the local filesystem
+    Local,
+    /// Archives are kept in an S3 compatible object store
+    S3,
+}
+
+impl FromStr for StorageBackend {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "local" => Ok(StorageBackend::Local),
+            "s3" => Ok(StorageBackend::S3),
+            _ => Err(Error::UnknownStorageBackend(value.to_string())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct StorageCfg {
+    pub backend: StorageBackend,
+    pub s3: S3Cfg,
+}
+
+impl Default for StorageCfg {
+    fn default() -> Self {
+        StorageCfg {
+            backend: StorageBackend::Local,
+            s3: S3Cfg::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct S3Cfg {
+    /// URL of the object store, without the bucket
+    pub endpoint: String,
+    pub region: String,
+    pub bucket: String,
+    /// Path within the bucket under which archives are kept
+    pub prefix: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+impl Default for S3Cfg {
+    fn default() -> Self {
+        S3Cfg {
+            endpoint: "https://s3.amazonaws.com".to_string(),
+            region: "us-east-1".to_string(),
+            bucket: String::new(),
+            prefix: "pkgs".to_string(),
+            access_key_id: String::new(),
+            secret_access_key: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct UpstreamCfg {
+    /// URL of the depot to mirror. Nothing is mirrored when this is empty
+    pub url: String,
+    /// Authentication token for the upstream depot, needed to mirror private packages
+    pub auth_token: String,
+    /// Origins whose packages and public keys are mirrored
+    pub origins: Vec<String>,
+    /// Channels whose packages and memberships are mirrored
+    pub channels: Vec<String>,
+    /// Seconds between syncs. Packages are only fetched by pull-through when this is 0
+    pub sync_interval: u64,
+    /// Whether downloads and lookups of packages from mirrored origins are answered from upstream
+    /// when this depot doesn't have them yet, or is asked for the latest release
+    pub pull_through: bool,
+}
+
+impl UpstreamCfg {
+    pub fn enabled(&self) -> bool {
+        !self.url.is_empty()
+    }
+}
+
+impl Default for UpstreamCfg {
+    fn default() -> Self {
+        UpstreamCfg {
+            url: String::new(),
+            auth_token: String::new(),
+            origins: vec![],
+            channels: vec!["stable".to_string()],
+            sync_interval: 3600,
+            pull_through: false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,6 +260,20 @@ mod tests {
         url = "https://api.github.com"
         client_id = "0c2f738a7d0bd300de10"
         client_secret = "438223113eeb6e7edf2d2f91a232b72de72b9bdf"
+
+        [storage]
+        backend = "s3"
+
+        [storage.s3]
+        endpoint = "http://localhost:9000"
+        bucket = "habitat"
+        access_key_id = "minio"
+        secret_access_key = "minio123"
+
+        [upstream]
+        url = "https://bldr.habitat.sh"
+        origins = ["core"]
+        pull_through = true
         "#;
 
         let config = Config::from_raw(&content).unwrap();
@@ -173,6 +297,16 @@ mod tests {
         assert_eq!(config.targets[0].architecture, Architecture::X86_64);
         assert_eq!(config.targets[1].platform, Platform::Windows);
         assert_eq!(config.targets[1].architecture, Architecture::X86_64);
+        assert_eq!(config.storage.backend, StorageBackend::S3);
+        assert_eq!(config.storage.s3.endpoint, "http://localhost:9000");
+        assert_eq!(config.storage.s3.bucket, "habitat");
+        assert_eq!(config.storage.s3.region, "us-east-1");
+        assert_eq!(config.storage.s3.prefix, "pkgs");
+        assert!(config.upstream.enabled());
+        assert_eq!(config.upstream.origins, vec!["core"]);
+        assert_eq!(config.upstream.channels, vec!["stable"]);
+        assert_eq!(config.upstream.sync_interval, 3600);
+        assert!(config.upstream.pull_through);
     }
 
     #[test]
@@ -184,5 +318,7 @@ mod tests {
 
         let config = Config::from_raw(&content).unwrap();
         assert_eq!(config.http.port, 9000);
+        assert_eq!(config.storage.backend, StorageBackend::Local);
+        assert!(!config.upstream.enabled());
     }
 }
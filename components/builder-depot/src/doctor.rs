@@ -12,19 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fs;
+use std::fs::{self, File};
 use std::io;
-use std::path::PathBuf;
+use std::result;
 
 use hab_core;
-use hab_core::package::{FromArchive, PackageArchive};
+use hab_core::package::FromArchive;
 use http_gateway::conn::RouteBroker;
 use protocol::originsrv;
 use time;
-use walkdir::WalkDir;
+use uuid::Uuid;
 
 use super::DepotUtil;
-use error::Result;
+use error::{Error, Result};
+use storage::ArchiveStore;
 
 #[derive(Debug)]
 /// A struct containing the details of a repair run by `Doctor`.
@@ -102,12 +103,12 @@ impl Default for ReportBuilder {
 
 #[derive(Debug)]
 pub enum OperationType {
-    /// Record of an archive being re-inserted into the datastore. Contains the filepath to the
-    /// final location of the archive.
+    /// Record of an archive being re-inserted into the datastore. Contains the key the archive is
+    /// stored under.
     ArchiveInsert(String),
-    /// Record of cleaning up after the doctor has run. Contains the filepath of the trash which
-    /// was cleaned.
-    CleanupTrash(String),
+    /// Record of an archive being copied into the depot's store from another store. Contains the
+    /// key the archive is stored under.
+    ArchiveMigrate(String),
     /// Record of initializing the depot's datastore filesystem. Contains the filepath of the new
     /// filesystem.
     InitDepotFs(String),
@@ -121,9 +122,9 @@ pub enum Reason {
     BadArchive,
     BadMetadata(hab_core::Error),
     BadPermissions,
+    Depot(Error),
     IO(io::Error),
     FileExists,
-    NotEmpty,
 }
 
 #[derive(Debug)]
@@ -135,19 +136,13 @@ pub enum Operation {
 struct Doctor<'a> {
     report: ReportBuilder,
     depot: &'a DepotUtil,
-    packages_path: PathBuf,
 }
 
 impl<'a> Doctor<'a> {
     pub fn new(depot: &'a DepotUtil) -> Self {
-        let report = ReportBuilder::new();
-        let mut packages = depot.packages_path().clone();
-        packages.pop();
-        packages.push(format!("pkgs.{:?}", report.start));
         Doctor {
-            report: report,
+            report: ReportBuilder::new(),
             depot: depot,
-            packages_path: packages,
         }
     }
 
@@ -158,117 +153,120 @@ impl<'a> Doctor<'a> {
     }
 
     fn init_fs(&mut self) -> Result<()> {
-        match fs::metadata(&self.depot.config.path) {
-            Ok(meta) => {
-                if meta.is_file() {
-                    self.report.failure(
-                        OperationType::InitDepotFs(
-                            self.depot.config.path.to_string_lossy().into_owned(),
-                        ),
-                        Reason::FileExists,
-                    );
-                }
-                if meta.permissions().readonly() {
-                    self.report.failure(
-                        OperationType::InitDepotFs(
-                            self.depot.config.path.to_string_lossy().into_owned(),
-                        ),
-                        Reason::BadPermissions,
-                    );
-                }
-                fs::create_dir_all(&self.depot.packages_path())?;
+        if let Ok(meta) = fs::metadata(&self.depot.config.path) {
+            if meta.is_file() {
+                self.report.failure(
+                    OperationType::InitDepotFs(
+                        self.depot.config.path.to_string_lossy().into_owned(),
+                    ),
+                    Reason::FileExists,
+                );
+            }
+            if meta.permissions().readonly() {
+                self.report.failure(
+                    OperationType::InitDepotFs(
+                        self.depot.config.path.to_string_lossy().into_owned(),
+                    ),
+                    Reason::BadPermissions,
+                );
             }
-            Err(_) => fs::create_dir_all(&self.depot.packages_path())?,
         }
-        fs::rename(&self.depot.packages_path(), &self.packages_path)?;
-        fs::create_dir_all(&self.depot.packages_path())?;
+        fs::create_dir_all(&self.depot.staging_path())?;
         Ok(())
     }
 
     fn rebuild_metadata(&mut self) -> Result<()> {
-        let mut directories = vec![];
-        for entry in WalkDir::new(&self.packages_path).follow_links(false) {
-            let entry = entry.unwrap();
-            if entry.metadata().unwrap().is_dir() {
-                directories.push(entry);
-                continue;
-            }
-            let mut archive = PackageArchive::new(PathBuf::from(entry.path()));
-            match archive.ident() {
-                Ok(ident) => {
-                    match originsrv::OriginPackageCreate::from_archive(&mut archive) {
-                        Ok(package) => {
-                            let mut conn = RouteBroker::connect().unwrap();
-                            conn.route::<originsrv::OriginPackageCreate,
-                                originsrv::OriginPackage>(&package)?;
-                            let path = self.depot.archive_path(&ident, &archive.target()?);
-                            if let Some(e) = fs::create_dir_all(path.parent().unwrap()).err() {
-                                self.report.failure(
-                                    OperationType::ArchiveInsert(
-                                        entry.path().to_string_lossy().to_string(),
-                                    ),
-                                    Reason::IO(e),
-                                );
-                                break;
-                            }
-                            if let Some(e) = fs::rename(entry.path(), &path).err() {
-                                self.report.failure(
-                                    OperationType::ArchiveInsert(
-                                        entry.path().to_string_lossy().to_string(),
-                                    ),
-                                    Reason::IO(e),
-                                );
-                                break;
-                            }
-                            self.report.success(OperationType::ArchiveInsert(
-                                path.to_string_lossy().to_string(),
-                            ));
-                        }
-                        Err(e) => {
-                            // We should be moving this back to the garbage directory and recording
-                            // the path of it there in this failure
-                            self.report.failure(
-                                OperationType::ArchiveInsert(
-                                    entry.path().to_string_lossy().to_string(),
-                                ),
-                                Reason::BadMetadata(e),
-                            );
-                        }
-                    }
+        for key in self.depot.store.keys()? {
+            match self.reinsert(&key)? {
+                Ok(key) => {
+                    self.report.success(OperationType::ArchiveInsert(key));
                 }
-                Err(e) => {
-                    debug!("Error reading, archive={:?} error={:?}", &archive, &e);
-                    self.report.failure(
-                        OperationType::ArchiveInsert(
-                            entry.path().to_string_lossy().to_string(),
-                        ),
-                        Reason::BadArchive,
-                    );
+                Err(reason) => {
+                    self.report.failure(OperationType::ArchiveInsert(key), reason);
                 }
             }
         }
-        directories.reverse();
-        for dir in directories.iter() {
-            if let Some(e) = fs::remove_dir(dir.path()).err() {
-                debug!("Error deleting: {:?}", &e);
-                self.report.failure(
-                    OperationType::CleanupTrash(
-                        self.packages_path.to_string_lossy().to_string(),
-                    ),
-                    Reason::NotEmpty,
-                );
+        Ok(())
+    }
+
+    // Re-create the metadata for the archive stored under the given key, moving the archive to
+    // the key it belongs under if it's stored anywhere else. Returns the key it ends up under.
+    fn reinsert(&mut self, key: &str) -> Result<result::Result<String, Reason>> {
+        let mut local = match self.depot.fetch(key) {
+            Ok(local) => local,
+            Err(e) => return Ok(Err(Reason::Depot(e))),
+        };
+        let (ident, target) = match (local.archive.ident(), local.archive.target()) {
+            (Ok(ident), Ok(target)) => (ident, target),
+            (Err(e), _) | (_, Err(e)) => {
+                debug!("Error reading, archive={:?} error={:?}", &local.archive, &e);
+                return Ok(Err(Reason::BadArchive));
+            }
+        };
+        let package = match originsrv::OriginPackageCreate::from_archive(&mut local.archive) {
+            Ok(package) => package,
+            // We should be moving this to a garbage location and recording the location of it
+            // there in this failure
+            Err(e) => return Ok(Err(Reason::BadMetadata(e))),
+        };
+        let mut conn = RouteBroker::connect().unwrap();
+        conn.route::<originsrv::OriginPackageCreate, originsrv::OriginPackage>(
+            &package,
+        )?;
+
+        let canonical = self.depot.archive_key(&ident, &target);
+        if canonical != key {
+            if let Err(e) = self.depot.store.put(&canonical, &local.archive.path) {
+                return Ok(Err(Reason::Depot(e)));
+            }
+            // Stores which keep archives on local disk have moved it already.
+            match self.depot.store.exists(key) {
+                Ok(true) => {
+                    if let Err(e) = self.depot.store.delete(key) {
+                        return Ok(Err(Reason::Depot(e)));
+                    }
+                }
+                Ok(false) => {}
+                Err(e) => return Ok(Err(Reason::Depot(e))),
             }
         }
-        Ok(())
+        Ok(Ok(canonical))
     }
 }
 
 /// Runs the repair tool on the given Depot and returns a Report containing the results. A repair
-/// tool analyzes all packages found within Builder's metadata store and re-inserts them into
-/// the file system and re-builds all indices.
-///
-/// Any files found within the metastore which are not valid or readable archives are moved into a
-/// garbage directory for the user to examine.
+/// tool analyzes all packages found within Builder's archive store and re-inserts them into
+/// the metadata store, moving any archive not stored where it belongs to where it should be.
 pub fn repair(depot: &DepotUtil) -> Result<Report> {
     Doctor::new(depot).run()
 }
+
+/// Copies every archive in the given store into the depot's own store and returns a Report
+/// containing the results. Archives the depot's store already has are left alone, as is the store
+/// being migrated from, so a migration which fails part way through can simply be run again.
+pub fn migrate(depot: &DepotUtil, from: &ArchiveStore) -> Result<Report> {
+    let mut report = ReportBuilder::new();
+    fs::create_dir_all(&depot.staging_path())?;
+    for key in from.keys()? {
+        match migrate_archive(depot, from, &key) {
+            Ok(_) => report.success(OperationType::ArchiveMigrate(key)),
+            Err(e) => report.failure(OperationType::ArchiveMigrate(key), Reason::Depot(e)),
+        };
+    }
+    Ok(report.generate())
+}
+
+fn migrate_archive(depot: &DepotUtil, from: &ArchiveStore, key: &str) -> Result<()> {
+    if depot.store.exists(key)? {
+        return Ok(());
+    }
+    // Stores take the file they're given, so copy the archive rather than handing over the
+    // original.
+    let temp_path = depot.staging_path().join(format!("{}.tmp", Uuid::new_v4()));
+    io::copy(&mut from.get(key)?, &mut File::create(&temp_path)?)?;
+    let result = depot.store.put(key, &temp_path);
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
@@ -18,28 +18,38 @@ use std::io;
 use std::fmt;
 use std::result;
 
+use depot_client;
 use hab_core;
 use hab_core::package::{self, Identifiable};
+use hab_http;
 use hab_net;
 use hyper;
 
 #[derive(Debug)]
 pub enum Error {
+    ArchiveNotFound(String),
     BadPort(String),
     ChannelAlreadyExists(String),
     ChannelDoesNotExist(String),
+    DepotClient(depot_client::Error),
     HabitatCore(hab_core::Error),
     HabitatNet(hab_net::error::LibError),
     NetError(hab_net::NetError),
     HTTP(hyper::status::StatusCode),
+    HttpClient(hab_http::Error),
+    HyperError(hyper::error::Error),
     InvalidPackageIdent(String),
     IO(io::Error),
     MessageTypeNotFound,
+    Mirror(String),
     NoXFilename,
     NoFilePart,
     NulError(ffi::NulError),
+    ObjectStore(hyper::status::StatusCode, String),
+    ObjectStoreConfig(String),
     PackageIsAlreadyInChannel(String, String),
     RemotePackageNotFound(package::PackageIdent),
+    UnknownStorageBackend(String),
     WriteSyncFailed,
 }
 
@@ -48,12 +58,16 @@ pub type Result<T> = result::Result<T, Error>;
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::ArchiveNotFound(ref key) => format!("No archive is stored at {}", key),
             Error::BadPort(ref e) => format!("{} is an invalid port. Valid range 1-65535.", e),
             Error::ChannelAlreadyExists(ref e) => format!("{} already exists.", e),
             Error::ChannelDoesNotExist(ref e) => format!("{} does not exist.", e),
+            Error::DepotClient(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HabitatNet(ref e) => format!("{}", e),
             Error::HTTP(ref e) => format!("{}", e),
+            Error::HttpClient(ref e) => format!("{}", e),
+            Error::HyperError(ref e) => format!("{}", e),
             Error::InvalidPackageIdent(ref e) => {
                 format!(
                     "Invalid package identifier: {:?}. A valid identifier is in the form \
@@ -63,6 +77,7 @@ impl fmt::Display for Error {
             }
             Error::IO(ref e) => format!("{}", e),
             Error::MessageTypeNotFound => format!("Unable to find message for given type"),
+            Error::Mirror(ref e) => format!("Unable to mirror from upstream, {}", e),
             Error::NetError(ref e) => format!("{}", e),
             Error::NoXFilename => {
                 format!("Invalid download from Builder - missing X-Filename header")
@@ -74,6 +89,10 @@ impl fmt::Display for Error {
                 )
             }
             Error::NulError(ref e) => format!("{}", e),
+            Error::ObjectStore(ref code, ref msg) => {
+                format!("Object store request failed, [{}] {}", code, msg)
+            }
+            Error::ObjectStoreConfig(ref e) => format!("Invalid object store config, {}", e),
             Error::PackageIsAlreadyInChannel(ref p, ref c) => {
                 format!("{} is already in the {} channel.", p, c)
             }
@@ -84,6 +103,9 @@ impl fmt::Display for Error {
                     format!("Cannot find a release of package in any sources: {}", pkg)
                 }
             }
+            Error::UnknownStorageBackend(ref e) => {
+                format!("{} is not a storage backend, must be one of (local, s3)", e)
+            }
             Error::WriteSyncFailed => {
                 format!("Could not write to destination; perhaps the disk is full?")
             }
@@ -95,12 +117,16 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::ArchiveNotFound(_) => "No archive is stored under the given key",
             Error::BadPort(_) => "Received an invalid port or a number outside of the valid range.",
             Error::ChannelAlreadyExists(_) => "Channel already exists.",
             Error::ChannelDoesNotExist(_) => "Channel does not exist.",
+            Error::DepotClient(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
             Error::HabitatNet(ref err) => err.description(),
             Error::HTTP(_) => "Received an HTTP error",
+            Error::HttpClient(ref err) => err.description(),
+            Error::HyperError(ref err) => err.description(),
             Error::InvalidPackageIdent(_) => {
                 "Package identifiers must be in origin/name format (example: acme/redis)"
             }
@@ -109,6 +135,8 @@ impl error::Error for Error {
             Error::NulError(_) => {
                 "An attempt was made to build a CString with a null byte inside it"
             }
+            Error::ObjectStore(_, _) => "Object store request failed",
+            Error::ObjectStoreConfig(_) => "Invalid object store config",
             Error::PackageIsAlreadyInChannel(_, _) => "Package is already in channel",
             Error::RemotePackageNotFound(_) => "Cannot find a package in any sources",
             Error::NoXFilename => "Invalid download from Builder - missing X-Filename header",
@@ -116,6 +144,8 @@ impl error::Error for Error {
                 "An invalid path was passed - we needed a filename, and this path does not have one"
             }
             Error::MessageTypeNotFound => "Unable to find message for given type",
+            Error::Mirror(_) => "Unable to mirror from upstream",
+            Error::UnknownStorageBackend(_) => "Unknown storage backend",
             Error::WriteSyncFailed => {
                 "Could not write to destination; bytes written was 0 on a non-0 buffer"
             }
@@ -129,6 +159,24 @@ impl From<hab_core::Error> for Error {
     }
 }
 
+impl From<depot_client::Error> for Error {
+    fn from(err: depot_client::Error) -> Error {
+        Error::DepotClient(err)
+    }
+}
+
+impl From<hab_http::Error> for Error {
+    fn from(err: hab_http::Error) -> Error {
+        Error::HttpClient(err)
+    }
+}
+
+impl From<hyper::error::Error> for Error {
+    fn from(err: hyper::error::Error) -> Error {
+        Error::HyperError(err)
+    }
+}
+
 impl From<ffi::NulError> for Error {
     fn from(err: ffi::NulError) -> Error {
         Error::NulError(err)
@@ -0,0 +1,466 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Browsing the contents of stored releases, so they can be reviewed without downloading and
+//! unpacking them.
+//!
+//! What can be browsed of a release is read from its archive once, when it's uploaded or first
+//! browsed, and kept on the depot's disk from then on, so browsing doesn't fetch archives from
+//! the store on every request.
+
+use std::cmp;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs::{self, File};
+use std::io;
+use std::result;
+use std::str::FromStr;
+
+use hab_core::package::{ArchiveFile, Identifiable, PackageArchive, PackageTarget};
+use http_gateway::http::controller::*;
+use http_gateway::http::helpers::{self, dont_cache_response, visibility_for_optional_session};
+use hyper::mime::{Attr, Mime, SubLevel, TopLevel, Value};
+use persistent;
+use protocol::originsrv::*;
+use router::{Params, Router};
+use serde_json;
+use tempfile::NamedTempFile;
+
+use DepotUtil;
+use error::Result;
+
+/// Config files longer than this are reported as changed without a line by line diff.
+const MAX_DIFF_LINES: usize = 1000;
+
+/// The files in a release and the contents of its metadata files, config files included.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ArchiveContents {
+    files: Vec<ArchiveFile>,
+    metadata: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct FilesDiff<'a> {
+    added: Vec<&'a ArchiveFile>,
+    removed: Vec<&'a ArchiveFile>,
+    changed: Vec<FileChange<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct FileChange<'a> {
+    path: &'a str,
+    from: &'a ArchiveFile,
+    to: &'a ArchiveFile,
+}
+
+#[derive(Debug, Serialize)]
+struct ConfigChange {
+    path: String,
+    diff: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct PackageDiff<'a> {
+    from: &'a OriginPackageIdent,
+    to: &'a OriginPackageIdent,
+    files: FilesDiff<'a>,
+    config: Vec<ConfigChange>,
+}
+
+pub fn package_files(req: &mut Request) -> IronResult<Response> {
+    let ident = ident_from_params(req.extensions.get::<Router>().unwrap(), "version", "release");
+    let (package, contents) = match fetch_contents(req, ident) {
+        Ok(fetched) => fetched,
+        Err(response) => return Ok(response),
+    };
+    let mut response = render_json(status::Ok, &contents.files);
+    dont_cache_unless_public(&package, &mut response);
+    Ok(response)
+}
+
+pub fn package_metadata_file(req: &mut Request) -> IronResult<Response> {
+    let (ident, path) = {
+        let params = req.extensions.get::<Router>().unwrap();
+        (
+            ident_from_params(params, "version", "release"),
+            params.find("file").unwrap_or("").to_string(),
+        )
+    };
+    if !is_metadata_file(&path) {
+        return Ok(Response::with(status::NotFound));
+    }
+    let (package, mut contents) = match fetch_contents(req, ident) {
+        Ok(fetched) => fetched,
+        Err(response) => return Ok(response),
+    };
+    match contents.metadata.remove(&path) {
+        Some(body) => {
+            let mut response = Response::with((status::Ok, body));
+            response.headers.set(ContentType(Mime(
+                TopLevel::Text,
+                SubLevel::Plain,
+                vec![(Attr::Charset, Value::Utf8)],
+            )));
+            dont_cache_unless_public(&package, &mut response);
+            Ok(response)
+        }
+        None => Ok(Response::with(status::NotFound)),
+    }
+}
+
+pub fn package_diff(req: &mut Request) -> IronResult<Response> {
+    let (from, to) = {
+        let params = req.extensions.get::<Router>().unwrap();
+        (
+            ident_from_params(params, "version", "release"),
+            ident_from_params(params, "other_version", "other_release"),
+        )
+    };
+    let (from_package, from_contents) = match fetch_contents(req, from) {
+        Ok(fetched) => fetched,
+        Err(response) => return Ok(response),
+    };
+    let (to_package, to_contents) = match fetch_contents(req, to) {
+        Ok(fetched) => fetched,
+        Err(response) => return Ok(response),
+    };
+
+    let (from_config, to_config) = (from_contents.config(), to_contents.config());
+    let paths: BTreeSet<&str> = from_config.keys().chain(to_config.keys()).cloned().collect();
+    let mut config = vec![];
+    for path in paths {
+        let old = from_config.get(path);
+        let new = to_config.get(path);
+        if old != new {
+            config.push(ConfigChange {
+                path: path.to_string(),
+                diff: diff_lines(old.map_or("", |s| s), new.map_or("", |s| s)),
+            });
+        }
+    }
+
+    let diff = PackageDiff {
+        from: from_package.get_ident(),
+        to: to_package.get_ident(),
+        files: diff_files(&from_contents.files, &to_contents.files),
+        config: config,
+    };
+    let mut response = render_json(status::Ok, &diff);
+    dont_cache_unless_public(&from_package, &mut response);
+    dont_cache_unless_public(&to_package, &mut response);
+    Ok(response)
+}
+
+impl ArchiveContents {
+    /// Reads what can be browsed of a release from its archive.
+    pub fn from_archive(archive: &PackageArchive) -> Result<Self> {
+        let metadata = archive
+            .read_files(is_metadata_file)?
+            .into_iter()
+            .map(|(path, body)| (path, String::from_utf8_lossy(&body).into_owned()))
+            .collect();
+        Ok(ArchiveContents {
+            files: archive.files()?,
+            metadata: metadata,
+        })
+    }
+
+    fn config(&self) -> BTreeMap<&str, &str> {
+        self.metadata
+            .iter()
+            .filter(|&(path, _)| is_config_file(path))
+            .map(|(path, body)| (path.as_str(), body.as_str()))
+            .collect()
+    }
+}
+
+/// Keeps what can be browsed of a release on the depot's disk, replacing whatever was kept for a
+/// release of the same ident and target before.
+pub fn keep_contents<T>(
+    depot: &DepotUtil,
+    ident: &T,
+    target: &PackageTarget,
+    contents: &ArchiveContents,
+) -> Result<()>
+where
+    T: Identifiable,
+{
+    let path = depot.contents_path(ident, target);
+    let dir = path.parent().expect("contents are kept in a directory");
+    fs::create_dir_all(dir)?;
+    // Written aside and moved into place, so readers never see half a file.
+    let mut temp = NamedTempFile::new_in(dir)?;
+    serde_json::to_writer(&mut temp, contents).map_err(io::Error::from)?;
+    temp.persist(&path).map_err(|e| e.error)?;
+    Ok(())
+}
+
+/// Forgets what was kept of a release, once it's deleted.
+pub fn forget_contents<T>(depot: &DepotUtil, ident: &T, target: &PackageTarget)
+where
+    T: Identifiable,
+{
+    let path = depot.contents_path(ident, target);
+    if let Err(e) = fs::remove_file(&path) {
+        if e.kind() != io::ErrorKind::NotFound {
+            warn!("Unable to remove {}, err={}", path.display(), e);
+        }
+    }
+}
+
+// Look up a release the requester may see and read what can be browsed of it, from the depot's
+// disk if it's been kept there already and from its archive otherwise.
+fn fetch_contents(
+    req: &mut Request,
+    ident: OriginPackageIdent,
+) -> result::Result<(OriginPackage, ArchiveContents), Response> {
+    let session_id = helpers::get_optional_session_id(req);
+    let mut request = OriginPackageGet::new();
+    request.set_visibilities(visibility_for_optional_session(
+        req,
+        session_id,
+        ident.get_origin(),
+    ));
+    request.set_ident(ident);
+    let package = match route_message::<OriginPackageGet, OriginPackage>(req, &request) {
+        Ok(package) => package,
+        Err(err) => return Err(render_net_error(&err)),
+    };
+    let target = match PackageTarget::from_str(package.get_target()) {
+        Ok(target) => target,
+        Err(e) => {
+            error!(
+                "Invalid target for {}, target={}, err={}",
+                package.get_ident(),
+                package.get_target(),
+                e
+            );
+            return Err(Response::with(status::InternalServerError));
+        }
+    };
+    let lock = req.get::<persistent::State<DepotUtil>>().expect(
+        "depot not found",
+    );
+    let depot = lock.read().expect("depot read lock is poisoned");
+
+    if let Ok(file) = File::open(depot.contents_path(package.get_ident(), &target)) {
+        match serde_json::from_reader(file) {
+            Ok(contents) => return Ok((package, contents)),
+            Err(e) => warn!("Unable to read contents of {}, err={}", package.get_ident(), e),
+        }
+    }
+    let local = match depot.archive(package.get_ident(), &target) {
+        Ok(Some(local)) => local,
+        Ok(None) => return Err(Response::with(status::NotFound)),
+        Err(e) => {
+            error!(
+                "Unable to read archive for {} from the store, err={}",
+                package.get_ident(),
+                e
+            );
+            return Err(Response::with(status::InternalServerError));
+        }
+    };
+    let contents = match ArchiveContents::from_archive(&local.archive) {
+        Ok(contents) => contents,
+        Err(e) => {
+            error!("Unable to read contents of {}, err={}", package.get_ident(), e);
+            return Err(Response::with(status::InternalServerError));
+        }
+    };
+    if let Err(e) = keep_contents(&depot, package.get_ident(), &target, &contents) {
+        warn!("Unable to keep the contents of {}, err={}", package.get_ident(), e);
+    }
+    Ok((package, contents))
+}
+
+fn ident_from_params(params: &Params, version: &str, release: &str) -> OriginPackageIdent {
+    let mut ident = OriginPackageIdent::new();
+    ident.set_origin(params.find("origin").unwrap().to_string());
+    ident.set_name(params.find("pkg").unwrap().to_string());
+    ident.set_version(params.find(version).unwrap().to_string());
+    ident.set_release(params.find(release).unwrap().to_string());
+    ident
+}
+
+// Private releases may change visibility at any time, so nothing about them is cached.
+fn dont_cache_unless_public(package: &OriginPackage, response: &mut Response) {
+    if package.get_visibility() != OriginPackageVisibility::Public {
+        dont_cache_response(response);
+    }
+}
+
+// Only the plan, its config and hooks, and the metafiles written at build time may be browsed.
+fn is_metadata_file(path: &str) -> bool {
+    if path.split('/').any(|p| p.is_empty() || p == "." || p == "..") {
+        return false;
+    }
+    match path.find('/') {
+        Some(idx) => &path[..idx] == "hooks" || &path[..idx] == "config",
+        None => {
+            path == "plan.sh" ||
+                path.chars().all(|c| c.is_uppercase() || c == '_') || is_config_file(path)
+        }
+    }
+}
+
+fn is_config_file(path: &str) -> bool {
+    path == "default.toml" || path.starts_with("config/")
+}
+
+fn diff_files<'a>(from: &'a [ArchiveFile], to: &'a [ArchiveFile]) -> FilesDiff<'a> {
+    let old: HashMap<&str, &ArchiveFile> = from.iter().map(|f| (f.path.as_str(), f)).collect();
+    let new: HashMap<&str, &ArchiveFile> = to.iter().map(|f| (f.path.as_str(), f)).collect();
+    let mut diff = FilesDiff::default();
+    for file in from {
+        match new.get(file.path.as_str()) {
+            Some(other) => {
+                if file != *other {
+                    diff.changed.push(FileChange {
+                        path: &file.path,
+                        from: file,
+                        to: other,
+                    });
+                }
+            }
+            None => diff.removed.push(file),
+        }
+    }
+    diff.added = to.iter()
+        .filter(|f| !old.contains_key(f.path.as_str()))
+        .collect();
+    diff
+}
+
+// Diff two texts line by line, prefixing each line with "+ " if it was added, "- " if it was
+// removed or "  " if it was kept.
+fn diff_lines(from: &str, to: &str) -> Vec<String> {
+    let a: Vec<&str> = from.lines().collect();
+    let b: Vec<&str> = to.lines().collect();
+    if a.len() > MAX_DIFF_LINES || b.len() > MAX_DIFF_LINES {
+        return vec![];
+    }
+    // lcs[i][j] is the length of the longest common subsequence of a[i..] and b[j..].
+    let mut lcs = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                cmp::max(lcs[i + 1][j], lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut lines = vec![];
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            lines.push(format!("  {}", a[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(format!("- {}", a[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", b[j]));
+            j += 1;
+        }
+    }
+    lines.extend(a[i..].iter().map(|l| format!("- {}", l)));
+    lines.extend(b[j..].iter().map(|l| format!("+ {}", l)));
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use tempdir::TempDir;
+
+    use config::Config;
+    use super::*;
+
+    const HART: &'static str = "happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart";
+
+    fn file(path: &str, checksum: &str) -> ArchiveFile {
+        ArchiveFile {
+            path: path.to_string(),
+            size: 1,
+            link: None,
+            checksum: Some(checksum.to_string()),
+        }
+    }
+
+    #[test]
+    fn browsable_metadata_files() {
+        for path in &["plan.sh", "default.toml", "MANIFEST", "SVC_USER", "hooks/run",
+                      "config/redis.config"]
+        {
+            assert!(is_metadata_file(path), "{} should be browsable", path);
+        }
+        for path in &["bin/redis-server", "hooks/../bin/redis-server", "hooks/", "/plan.sh",
+                      "Readme.md"]
+        {
+            assert!(!is_metadata_file(path), "{} should not be browsable", path);
+        }
+    }
+
+    #[test]
+    fn diffing_files() {
+        let from = vec![file("IDENT", "a"), file("bin/old", "b"), file("hooks/run", "c")];
+        let to = vec![file("IDENT", "d"), file("bin/new", "e"), file("hooks/run", "c")];
+        let diff = diff_files(&from, &to);
+        assert_eq!(diff.added, vec![&to[1]]);
+        assert_eq!(diff.removed, vec![&from[1]]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].path, "IDENT");
+    }
+
+    #[test]
+    fn diffing_lines() {
+        assert_eq!(
+            diff_lines("port = 6379\nbind = []\n", "port = 6380\nbind = []\nmode = 1\n"),
+            vec!["- port = 6379", "+ port = 6380", "  bind = []", "+ mode = 1"]
+        );
+        assert_eq!(diff_lines("", "a"), vec!["+ a"]);
+        assert!(diff_lines("same", "same").iter().all(|l| l.starts_with("  ")));
+    }
+
+    #[test]
+    fn storing_an_archive_keeps_its_contents() {
+        let tmp = TempDir::new("depot").unwrap();
+        let mut config = Config::default();
+        config.path = tmp.path().to_path_buf();
+        let depot = DepotUtil::new(config).unwrap();
+        let upload = tmp.path().join("upload.hart");
+        fs::copy(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("../core/tests/fixtures")
+                .join(HART),
+            &upload,
+        ).unwrap();
+        let mut archive = PackageArchive::new(upload);
+        let ident = archive.ident().unwrap();
+        let target = archive.target().unwrap();
+        let files = archive.files().unwrap();
+
+        depot.put_archive(&ident, &target, &archive).unwrap();
+        assert!(!archive.path.exists());
+        assert!(depot.archive_exists(&ident, &target).unwrap());
+        let kept: ArchiveContents =
+            serde_json::from_reader(File::open(depot.contents_path(&ident, &target)).unwrap())
+                .unwrap();
+        assert_eq!(kept.files, files);
+        assert!(kept.metadata.contains_key("IDENT"));
+    }
+}
@@ -17,7 +17,7 @@ use std::collections::HashMap;
 use bldr_core;
 use bodyparser;
 use http_gateway::http::controller::*;
-use http_gateway::http::helpers::{self, check_origin_access};
+use http_gateway::http::helpers::{self, check_origin_role};
 use iron::status::{self, Status};
 
 use protocol::originsrv::*;
@@ -55,7 +55,8 @@ pub fn validate_params(
         }
     }
 
-    if !check_origin_access(req, &res["origin"]).unwrap_or(false) {
+    // Integrations hold credentials, so only owners may see or manage them
+    if !check_origin_role(req, &res["origin"], OriginMemberRole::Owner).unwrap_or(false) {
         debug!("Failed origin access check, origin: {}", &res["origin"]);
         return Err(status::Forbidden);
     }
@@ -143,7 +144,16 @@ pub fn create_origin_integration(req: &mut Request) -> IronResult<Response> {
     request.set_integration(oi);
 
     match route_message::<OriginIntegrationCreate, NetOk>(req, &request) {
-        Ok(_) => Ok(Response::with(status::NoContent)),
+        Ok(_) => {
+            helpers::audit(
+                req,
+                &params["origin"],
+                OriginAuditAction::IntegrationCreate,
+                &format!("{}/{}", params["integration"], params["name"]),
+                "",
+            );
+            Ok(Response::with(status::NoContent))
+        }
         Err(err) => Ok(render_net_error(&err)),
     }
 }
@@ -163,7 +173,16 @@ pub fn delete_origin_integration(req: &mut Request) -> IronResult<Response> {
     request.set_integration(oi);
 
     match route_message::<OriginIntegrationDelete, NetOk>(req, &request) {
-        Ok(_) => Ok(Response::with(status::NoContent)),
+        Ok(_) => {
+            helpers::audit(
+                req,
+                &params["origin"],
+                OriginAuditAction::IntegrationDelete,
+                &format!("{}/{}", params["integration"], params["name"]),
+                "",
+            );
+            Ok(Response::with(status::NoContent))
+        }
         Err(err) => Ok(render_net_error(&err)),
     }
 }
@@ -18,10 +18,13 @@
 extern crate habitat_builder_protocol as protocol;
 #[macro_use]
 extern crate habitat_core as hab_core;
+extern crate habitat_depot_client as depot_client;
+extern crate habitat_http_client as hab_http;
 extern crate habitat_net as hab_net;
 extern crate builder_core as bldr_core;
 extern crate builder_http_gateway as http_gateway;
 extern crate bodyparser;
+extern crate chrono;
 extern crate crypto;
 extern crate hyper;
 extern crate iron;
@@ -30,6 +33,7 @@ extern crate libc;
 #[macro_use]
 extern crate log;
 extern crate mount;
+extern crate oauth_client;
 extern crate persistent;
 extern crate protobuf;
 extern crate regex;
@@ -52,17 +56,20 @@ extern crate zmq;
 extern crate uuid;
 extern crate base64;
 extern crate github_api_client;
+#[cfg(test)]
+extern crate tempdir;
 
 pub mod config;
 pub mod error;
 pub mod doctor;
 pub mod server;
 pub mod handlers;
+pub mod storage;
+pub mod upstream;
 
 pub use self::config::Config;
 pub use self::error::{Error, Result};
 
-use std::fs;
 use std::path::{Path, PathBuf};
 
 use crypto::sha2::Sha256;
@@ -72,60 +79,115 @@ use iron::typemap;
 
 pub struct DepotUtil {
     pub config: Config,
+    pub store: Box<storage::ArchiveStore>,
 }
 
 impl DepotUtil {
-    pub fn new(config: Config) -> DepotUtil {
-        DepotUtil { config: config }
+    pub fn new(config: Config) -> Result<DepotUtil> {
+        let store = storage::new_store(&config, config.storage.backend)?;
+        Ok(DepotUtil {
+            config: config,
+            store: store,
+        })
     }
 
-    // Return a PackageArchive representing the given package. None is returned if Builder
-    // doesn't have an archive for the given package.
-    fn archive<T>(&self, ident: &T, target: &PackageTarget) -> Option<PackageArchive>
+    // Return whether Builder has an archive for the given package.
+    fn archive_exists<T>(&self, ident: &T, target: &PackageTarget) -> Result<bool>
     where
         T: Identifiable,
     {
-        let file = self.archive_path(ident, target);
-        match fs::metadata(&file) {
-            Ok(_) => Some(PackageArchive::new(file)),
-            Err(_) => None,
+        self.store.exists(&self.archive_key(ident, target))
+    }
+
+    // Return the archive for the given package, available on local disk for as long as the
+    // returned value lives. None is returned if Builder doesn't have an archive for the given
+    // package.
+    fn archive<T>(&self, ident: &T, target: &PackageTarget) -> Result<Option<storage::LocalArchive>>
+    where
+        T: Identifiable,
+    {
+        match self.fetch(&self.archive_key(ident, target)) {
+            Ok(archive) => Ok(Some(archive)),
+            Err(Error::ArchiveNotFound(_)) => Ok(None),
+            Err(e) => Err(e),
         }
     }
 
-    // Return a formatted string representing the filename of an archive for the given package
-    // identifier pieces.
-    fn archive_path<T: Identifiable>(&self, ident: &T, target: &PackageTarget) -> PathBuf {
-        let mut digest = Sha256::new();
-        let mut output = [0; 64];
-        digest.input_str(&ident.to_string());
-        digest.result(&mut output);
-        self.packages_path()
-            .join(format!("{:x}", output[0]))
-            .join(format!("{:x}", output[1]))
-            .join(format!(
-                "{}-{}-{}-{}-{}-{}.hart",
-                ident.origin(),
-                ident.name(),
-                ident.version().unwrap(),
-                ident.release().unwrap(),
-                target.architecture,
-                target.platform
-            ))
+    // Store the given archive for the package, along with what can be browsed of it. The
+    // archive's file is moved into the store.
+    fn put_archive<T>(
+        &self,
+        ident: &T,
+        target: &PackageTarget,
+        archive: &PackageArchive,
+    ) -> Result<()>
+    where
+        T: Identifiable,
+    {
+        // Browsing falls back to reading the archive from the store, so not being able to read
+        // its contents here needn't fail storing it. They have to be read before it's moved.
+        let contents = match handlers::files::ArchiveContents::from_archive(archive) {
+            Ok(contents) => Some(contents),
+            Err(e) => {
+                warn!("Unable to read the contents of {}, err={}", ident, e);
+                None
+            }
+        };
+        self.store.put(&self.archive_key(ident, target), &archive.path)?;
+        if let Some(contents) = contents {
+            if let Err(e) = handlers::files::keep_contents(self, ident, target, &contents) {
+                warn!("Unable to keep the contents of {}, err={}", ident, e);
+            }
+        }
+        Ok(())
     }
 
-    // Return a formatted string representing the folder location for an archive.
-    fn archive_parent<T: Identifiable>(&self, ident: &T) -> PathBuf {
+    // Make the archive stored under the given key available on local disk.
+    fn fetch(&self, key: &str) -> Result<storage::LocalArchive> {
+        storage::fetch(&*self.store, key, &self.staging_path())
+    }
+
+    // Return the key an archive for the given package is stored under.
+    fn archive_key<T: Identifiable>(&self, ident: &T, target: &PackageTarget) -> String {
         let mut digest = Sha256::new();
         let mut output = [0; 64];
         digest.input_str(&ident.to_string());
         digest.result(&mut output);
-        self.packages_path().join(format!("{:x}", output[0])).join(
-            format!("{:x}", output[1]),
+        format!(
+            "{:x}/{:x}/{}",
+            output[0],
+            output[1],
+            Self::archive_file_name(ident, target)
+        )
+    }
+
+    // Return where what can be browsed of the given package is kept, once it's been read from
+    // the package's archive.
+    fn contents_path<T: Identifiable>(&self, ident: &T, target: &PackageTarget) -> PathBuf {
+        Path::new(&self.config.path).join("contents").join(format!(
+            "{}.json",
+            self.archive_key(ident, target)
+        ))
+    }
+
+    // Return a formatted string representing the filename of an archive for the given package
+    // identifier pieces.
+    fn archive_file_name<T: Identifiable>(ident: &T, target: &PackageTarget) -> String {
+        format!(
+            "{}-{}-{}-{}-{}-{}.hart",
+            ident.origin(),
+            ident.name(),
+            ident.version().unwrap(),
+            ident.release().unwrap(),
+            target.architecture,
+            target.platform
         )
     }
 
-    fn packages_path(&self) -> PathBuf {
-        Path::new(&self.config.path).join("pkgs")
+    // Return the directory uploads and archives fetched from remote stores are kept in while
+    // they're being worked on.
+    fn staging_path(&self) -> PathBuf {
+        Path::new(&self.config.path).join("tmp")
     }
 }
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
 use std::fs::{self, File};
 use std::path::PathBuf;
 use std::io::{BufWriter, Read, Write};
@@ -22,6 +23,7 @@ use base64;
 use bldr_core;
 use bldr_core::helpers::transition_visibility;
 use bodyparser;
+use chrono::DateTime;
 use github_api_client::GitHubClient;
 use hab_core::package::{ident, FromArchive, Identifiable, PackageArchive, PackageIdent,
                         PackageTarget};
@@ -30,22 +32,27 @@ use hab_core::crypto::{BoxKeyPair, SigKeyPair};
 use hab_core::crypto::PUBLIC_BOX_KEY_VERSION;
 use hab_core::event::*;
 use http_gateway::http::controller::*;
-use http_gateway::http::helpers::{self, all_visibilities, check_origin_access, check_origin_owner,
+use http_gateway::http::helpers::{self, all_visibilities, check_origin_access, check_origin_role,
                                   dont_cache_response, get_param, visibility_for_optional_session};
 use http_gateway::http::middleware::{SegmentCli, XRouteClient};
-use hab_net::{privilege, ErrCode, NetOk, NetResult};
-use hyper::header::{Charset, ContentDisposition, DispositionParam, DispositionType};
+use hab_net::{privilege, ErrCode, NetError, NetOk, NetResult};
+use hyper::header::{AcceptRanges, ByteRangeSpec, Charset, ContentDisposition, ContentLength,
+                    ContentRange, ContentRangeSpec, DispositionParam, DispositionType, Range,
+                    RangeUnit};
 use hyper::mime::{Attr, Mime, SubLevel, TopLevel, Value};
 use iron::headers::{ContentType, UserAgent};
 use iron::middleware::BeforeMiddleware;
 use iron::request::Body;
+use iron::response::BodyReader;
+use oauth_client;
 use persistent;
 use protobuf;
+use protocol::SHARD_COUNT;
 use protocol::originsrv::*;
 use protocol::jobsrv::{JobGroup, JobGroupOriginGet, JobGroupOriginResponse, JobGroupSpec,
                        JobGroupGet, JobGraphPackageStatsGet, JobGraphPackageStats,
                        JobGraphPackagePreCreate, JobGroupAbort};
-use protocol::sessionsrv::{Account, AccountGet, AccountOriginRemove};
+use protocol::sessionsrv::{AccessTokenScope, Account, AccountGet, AccountOriginRemove};
 use regex::Regex;
 use router::{Params, Router};
 use segment_api_client::SegmentClient;
@@ -57,6 +64,7 @@ use uuid::Uuid;
 use super::DepotUtil;
 use error::{Error, Result};
 use handlers;
+use upstream;
 
 define_event_log!();
 
@@ -71,6 +79,25 @@ struct OriginUpdateReq {
     default_package_visibility: String,
 }
 
+#[derive(Clone, Serialize, Deserialize)]
+struct OriginMemberRoleReq {
+    role: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct OriginChannelPolicyReq {
+    promote_role: Option<String>,
+    prerequisite_channel: Option<String>,
+    require_job_success: Option<bool>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct OriginWebhookReq {
+    url: String,
+    events: Vec<String>,
+    secret: Option<String>,
+}
+
 const ONE_YEAR_IN_SECS: usize = 31536000;
 
 pub fn origin_update(req: &mut Request) -> IronResult<Response> {
@@ -80,7 +107,7 @@ pub fn origin_update(req: &mut Request) -> IronResult<Response> {
         None => return Ok(Response::with(status::BadRequest)),
     }
 
-    if !check_origin_access(req, request.get_name()).unwrap_or(false) {
+    if !check_origin_role(req, request.get_name(), OriginMemberRole::Owner).unwrap_or(false) {
         return Ok(Response::with(status::Forbidden));
     }
 
@@ -100,7 +127,16 @@ pub fn origin_update(req: &mut Request) -> IronResult<Response> {
         Err(err) => return Ok(render_net_error(&err)),
     }
     match route_message::<OriginUpdate, NetOk>(req, &request) {
-        Ok(_) => Ok(Response::with(status::NoContent)),
+        Ok(_) => {
+            helpers::audit(
+                req,
+                request.get_name(),
+                OriginAuditAction::OriginSettingsUpdate,
+                request.get_name(),
+                &request.get_default_package_visibility().to_string(),
+            );
+            Ok(Response::with(status::NoContent))
+        }
         Err(err) => Ok(render_net_error(&err)),
     }
 }
@@ -130,7 +166,16 @@ pub fn origin_create(req: &mut Request) -> IronResult<Response> {
     }
 
     match route_message::<OriginCreate, Origin>(req, &request) {
-        Ok(origin) => Ok(render_json(status::Created, &origin)),
+        Ok(origin) => {
+            helpers::audit(
+                req,
+                origin.get_name(),
+                OriginAuditAction::OriginRegister,
+                origin.get_name(),
+                "",
+            );
+            Ok(render_json(status::Created, &origin))
+        }
         Err(err) => Ok(render_net_error(&err)),
     }
 }
@@ -178,7 +223,16 @@ pub fn rescind_invitation(req: &mut Request) -> IronResult<Response> {
     );
 
     match route_message::<OriginInvitationRescindRequest, NetOk>(req, &request) {
-        Ok(_) => Ok(Response::with(status::NoContent)),
+        Ok(_) => {
+            helpers::audit(
+                req,
+                &origin,
+                OriginAuditAction::InvitationRescind,
+                &request.get_invitation_id().to_string(),
+                "",
+            );
+            Ok(Response::with(status::NoContent))
+        }
         Err(err) => Ok(render_net_error(&err)),
     }
 }
@@ -210,7 +264,16 @@ pub fn ignore_invitation(req: &mut Request) -> IronResult<Response> {
     );
 
     match route_message::<OriginInvitationIgnoreRequest, NetOk>(req, &request) {
-        Ok(_) => Ok(Response::with(status::NoContent)),
+        Ok(_) => {
+            helpers::audit(
+                req,
+                &origin,
+                OriginAuditAction::InvitationIgnore,
+                &request.get_invitation_id().to_string(),
+                "",
+            );
+            Ok(Response::with(status::NoContent))
+        }
         Err(err) => Ok(render_net_error(&err)),
     }
 }
@@ -250,6 +313,13 @@ pub fn accept_invitation(req: &mut Request) -> IronResult<Response> {
                     account: request.get_account_id().to_string(),
                 }
             );
+            helpers::audit(
+                req,
+                request.get_origin_name(),
+                OriginAuditAction::InvitationAccept,
+                &request.get_invite_id().to_string(),
+                "",
+            );
             Ok(Response::with(status::NoContent))
         }
         Err(err) => Ok(render_net_error(&err)),
@@ -275,7 +345,7 @@ pub fn invite_to_origin(req: &mut Request) -> IronResult<Response> {
         &origin
     );
 
-    if !check_origin_access(req, &origin).unwrap_or(false) {
+    if !check_origin_role(req, &origin, OriginMemberRole::Owner).unwrap_or(false) {
         return Ok(Response::with(status::Forbidden));
     }
 
@@ -313,6 +383,13 @@ pub fn invite_to_origin(req: &mut Request) -> IronResult<Response> {
                     account: session.get_id().to_string(),
                 }
             );
+            helpers::audit(
+                req,
+                &origin,
+                OriginAuditAction::MemberInvite,
+                &user_to_invite,
+                "",
+            );
             Ok(render_json(status::Created, &invitation))
         }
         Err(err) => {
@@ -386,7 +463,7 @@ pub fn origin_member_delete(req: &mut Request) -> IronResult<Response> {
         None => return Ok(Response::with(status::BadRequest)),
     };
 
-    if !check_origin_owner(req, session.get_id(), &origin).unwrap_or(false) {
+    if !check_origin_role(req, &origin, OriginMemberRole::Owner).unwrap_or(false) {
         return Ok(Response::with(status::Forbidden));
     }
 
@@ -410,8 +487,13 @@ pub fn origin_member_delete(req: &mut Request) -> IronResult<Response> {
     let mut session_request = AccountOriginRemove::new();
     let mut origin_request = OriginMemberRemove::new();
 
-    match helpers::get_origin(req, origin) {
+    match helpers::get_origin(req, &origin) {
         Ok(origin) => {
+            match is_origin_creator(req, &origin, &account_name) {
+                Ok(false) => (),
+                Ok(true) => return Ok(Response::with(status::BadRequest)),
+                Err(err) => return Ok(render_net_error(&err)),
+            }
             session_request.set_origin_id(origin.get_id());
             origin_request.set_origin_id(origin.get_id());
         }
@@ -425,11 +507,309 @@ pub fn origin_member_delete(req: &mut Request) -> IronResult<Response> {
     }
 
     match route_message::<OriginMemberRemove, NetOk>(req, &origin_request) {
-        Ok(_) => Ok(Response::with(status::NoContent)),
+        Ok(_) => {
+            helpers::audit(
+                req,
+                &origin,
+                OriginAuditAction::MemberRemove,
+                &account_name,
+                "",
+            );
+            Ok(Response::with(status::NoContent))
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn origin_member_role_update(req: &mut Request) -> IronResult<Response> {
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let account_name = match get_param(req, "username") {
+        Some(user) => user,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_role(req, &origin, OriginMemberRole::Owner).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let role = match req.get::<bodyparser::Struct<OriginMemberRoleReq>>() {
+        Ok(Some(body)) => {
+            match body.role.parse::<OriginMemberRole>() {
+                Ok(role) => role,
+                Err(_) => return Ok(Response::with(status::UnprocessableEntity)),
+            }
+        }
+        _ => return Ok(Response::with(status::UnprocessableEntity)),
+    };
+
+    let mut request = OriginMemberRoleUpdate::new();
+    match helpers::get_origin(req, &origin) {
+        Ok(origin) => {
+            // The origin's creator stays an owner so the origin can't be orphaned
+            match is_origin_creator(req, &origin, &account_name) {
+                Ok(false) => (),
+                Ok(true) => return Ok(Response::with(status::BadRequest)),
+                Err(err) => return Ok(render_net_error(&err)),
+            }
+            request.set_origin_id(origin.get_id());
+        }
+        Err(err) => return Ok(render_net_error(&err)),
+    }
+    request.set_account_name(account_name.to_string());
+    request.set_role(role);
+
+    match route_message::<OriginMemberRoleUpdate, NetOk>(req, &request) {
+        Ok(_) => {
+            helpers::audit(
+                req,
+                &origin,
+                OriginAuditAction::MemberRoleUpdate,
+                &account_name,
+                &role.to_string(),
+            );
+            Ok(Response::with(status::NoContent))
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn list_origin_audit_events(req: &mut Request) -> IronResult<Response> {
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_role(req, &origin, OriginMemberRole::Owner).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let (start, stop) = match helpers::extract_pagination(req) {
+        Ok(range) => range,
+        Err(response) => return Ok(response),
+    };
+
+    let mut request = OriginAuditEventListRequest::new();
+    request.set_origin_name(origin);
+    request.set_start(start as u64);
+    request.set_stop(stop as u64);
+    if let Some(action) = helpers::extract_query_value("action", req) {
+        match action.parse::<OriginAuditAction>() {
+            Ok(action) => request.set_action(action),
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    }
+    if let Some(account) = helpers::extract_query_value("account", req) {
+        request.set_account_name(account);
+    }
+    if let Some(target) = helpers::extract_query_value("target", req) {
+        request.set_target(target);
+    }
+    for &key in &["since", "until"] {
+        if let Some(value) = helpers::extract_query_value(key, req) {
+            if DateTime::parse_from_rfc3339(&value).is_err() {
+                return Ok(Response::with(status::BadRequest));
+            }
+            if key == "since" {
+                request.set_since(value);
+            } else {
+                request.set_until(value);
+            }
+        }
+    }
+
+    match route_message::<OriginAuditEventListRequest, OriginAuditEventListResponse>(
+        req,
+        &request,
+    ) {
+        Ok(list) => {
+            let mut response = helpers::paginated_response(
+                &list.get_events().to_vec(),
+                list.get_count() as isize,
+                list.get_start() as isize,
+                list.get_stop() as isize,
+            )?;
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn list_origin_webhooks(req: &mut Request) -> IronResult<Response> {
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_role(req, &origin, OriginMemberRole::Owner).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = OriginWebhookListRequest::new();
+    request.set_origin_name(origin);
+    match route_message::<OriginWebhookListRequest, OriginWebhookListResponse>(req, &request) {
+        Ok(list) => {
+            let mut response = render_json(status::Ok, &list);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn create_origin_webhook(req: &mut Request) -> IronResult<Response> {
+    let session = req.extensions.get::<Authenticated>().unwrap().clone();
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_role(req, &origin, OriginMemberRole::Owner).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut webhook = OriginWebhook::new();
+    match req.get::<bodyparser::Struct<OriginWebhookReq>>() {
+        Ok(Some(body)) => {
+            let (host, port) = match url::Url::parse(&body.url) {
+                Ok(ref url) if url.scheme() == "http" || url.scheme() == "https" => {
+                    match (url.host_str(), url.port_or_known_default()) {
+                        (Some(host), Some(port)) => (host.to_string(), port),
+                        _ => return Ok(Response::with(status::UnprocessableEntity)),
+                    }
+                }
+                _ => return Ok(Response::with(status::UnprocessableEntity)),
+            };
+            // Deliveries are made from inside Builder's network, so webhooks can't point at it.
+            // The check is made again at delivery time in case the host's addresses change.
+            if let Err(err) = bldr_core::webhooks::check_destination(&host, port) {
+                debug!("Rejecting webhook url {}, {}", body.url, err);
+                return Ok(Response::with(
+                    (status::UnprocessableEntity, err.to_string()),
+                ));
+            }
+            if body.events.is_empty() {
+                return Ok(Response::with(status::UnprocessableEntity));
+            }
+            let mut events = Vec::with_capacity(body.events.len());
+            for event in body.events.iter() {
+                match event.parse::<OriginWebhookEvent>() {
+                    Ok(event) => events.push(event),
+                    Err(_) => return Ok(Response::with(status::UnprocessableEntity)),
+                }
+            }
+            webhook.set_url(body.url);
+            webhook.set_events(events);
+            if let Some(secret) = body.secret {
+                webhook.set_secret(secret);
+            }
+        }
+        _ => return Ok(Response::with(status::UnprocessableEntity)),
+    }
+    webhook.set_origin_name(origin.clone());
+    webhook.set_owner_id(session.get_id());
+
+    let mut request = OriginWebhookCreate::new();
+    request.set_webhook(webhook);
+    match route_message::<OriginWebhookCreate, OriginWebhook>(req, &request) {
+        Ok(webhook) => {
+            helpers::audit(
+                req,
+                &origin,
+                OriginAuditAction::WebhookCreate,
+                &webhook.get_id().to_string(),
+                webhook.get_url(),
+            );
+            Ok(render_json(status::Created, &webhook))
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn delete_origin_webhook(req: &mut Request) -> IronResult<Response> {
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let id = match get_param(req, "id").and_then(|id| id.parse::<u64>().ok()) {
+        Some(id) => id,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_role(req, &origin, OriginMemberRole::Owner).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = OriginWebhookDelete::new();
+    request.set_origin_name(origin.clone());
+    request.set_id(id);
+    match route_message::<OriginWebhookDelete, NetOk>(req, &request) {
+        Ok(_) => {
+            helpers::audit(
+                req,
+                &origin,
+                OriginAuditAction::WebhookDelete,
+                &id.to_string(),
+                "",
+            );
+            Ok(Response::with(status::NoContent))
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn list_origin_webhook_deliveries(req: &mut Request) -> IronResult<Response> {
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let id = match get_param(req, "id").and_then(|id| id.parse::<u64>().ok()) {
+        Some(id) => id,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_role(req, &origin, OriginMemberRole::Owner).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let (start, stop) = match helpers::extract_pagination(req) {
+        Ok(range) => range,
+        Err(response) => return Ok(response),
+    };
+
+    let mut request = OriginWebhookDeliveryListRequest::new();
+    request.set_origin_name(origin);
+    request.set_webhook_id(id);
+    request.set_start(start as u64);
+    request.set_stop(stop as u64);
+    match route_message::<OriginWebhookDeliveryListRequest, OriginWebhookDeliveryListResponse>(
+        req,
+        &request,
+    ) {
+        Ok(list) => {
+            let mut response = helpers::paginated_response(
+                &list.get_deliveries().to_vec(),
+                list.get_count() as isize,
+                list.get_start() as isize,
+                list.get_stop() as isize,
+            )?;
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
         Err(err) => Ok(render_net_error(&err)),
     }
 }
 
+fn is_origin_creator(req: &mut Request, origin: &Origin, account_name: &str) -> NetResult<bool> {
+    let mut request = AccountGet::new();
+    request.set_name(account_name.to_string());
+    let account = route_message::<AccountGet, Account>(req, &request)?;
+    Ok(account.get_id() == origin.get_owner_id())
+}
+
 fn write_archive(filename: &PathBuf, body: &mut Body) -> Result<PackageArchive> {
     let file = File::create(&filename)?;
     let mut writer = BufWriter::new(file);
@@ -459,15 +839,18 @@ fn generate_origin_keys(req: &mut Request) -> IronResult<Response> {
     debug!("Generate Origin Keys {:?}", req);
     let session = req.extensions.get::<Authenticated>().unwrap().clone();
     match get_param(req, "origin") {
-        Some(origin) => {
-            if !check_origin_access(req, &origin).unwrap_or(false) {
+        Some(name) => {
+            if !check_origin_role(req, &name, OriginMemberRole::Owner).unwrap_or(false) {
                 return Ok(Response::with(status::Forbidden));
             }
 
-            match helpers::get_origin(req, origin) {
+            match helpers::get_origin(req, &name) {
                 Ok(origin) => {
                     match helpers::generate_origin_keys(req, session, origin) {
-                        Ok(_) => Ok(Response::with(status::Created)),
+                        Ok(_) => {
+                            helpers::audit(req, &name, OriginAuditAction::KeyGenerate, &name, "");
+                            Ok(Response::with(status::Created))
+                        }
                         Err(err) => Ok(render_net_error(&err)),
                     }
                 }
@@ -485,28 +868,27 @@ fn upload_origin_key(req: &mut Request) -> IronResult<Response> {
     let mut request = OriginPublicKeyCreate::new();
     request.set_owner_id(session.get_id());
 
-    let origin = match get_param(req, "origin") {
-        Some(origin) => {
-            if !check_origin_access(req, &origin).unwrap_or(false) {
-                return Ok(Response::with(status::Forbidden));
-            }
-
-            match helpers::get_origin(req, &origin) {
-                Ok(mut origin) => {
-                    request.set_name(origin.take_name());
-                    request.set_origin_id(origin.get_id());
-                }
-                Err(err) => return Ok(render_net_error(&err)),
-            }
-            origin
-        }
-        None => return Ok(Response::with(status::BadRequest)),
+    let (origin, revision) = match (get_param(req, "origin"), get_param(req, "revision")) {
+        (Some(origin), Some(revision)) => (origin, revision),
+        _ => return Ok(Response::with(status::BadRequest)),
     };
+    let refusal = refuse_key_upload(&mut KeyUploadRequest {
+        req: req,
+        origin: &origin,
+        revision: &revision,
+    });
+    if let Some(status) = refusal {
+        return Ok(Response::with(status));
+    }
 
-    match get_param(req, "revision") {
-        Some(revision) => request.set_revision(revision),
-        None => return Ok(Response::with(status::BadRequest)),
-    };
+    match helpers::get_origin(req, &origin) {
+        Ok(mut origin) => {
+            request.set_name(origin.take_name());
+            request.set_origin_id(origin.get_id());
+        }
+        Err(err) => return Ok(render_net_error(&err)),
+    }
+    request.set_revision(revision);
 
     let mut key_content = Vec::new();
     if let Err(e) = req.body.read_to_end(&mut key_content) {
@@ -548,6 +930,13 @@ fn upload_origin_key(req: &mut Request) -> IronResult<Response> {
                     account: session.get_id().to_string(),
                 }
             );
+            helpers::audit(
+                req,
+                &origin,
+                OriginAuditAction::PublicKeyUpload,
+                request.get_revision(),
+                "",
+            );
             let mut response = Response::with((
                 status::Created,
                 format!(
@@ -567,18 +956,59 @@ fn upload_origin_key(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+// What an upload of an origin public key is checked against: whether the revision exists already
+// and the uploader's role in the origin.
+trait KeyUpload {
+    fn exists(&mut self) -> bool;
+
+    fn has_role(&mut self, role: OriginMemberRole) -> bool;
+}
+
+struct KeyUploadRequest<'a, 'b: 'a, 'c: 'b> {
+    req: &'a mut Request<'b, 'c>,
+    origin: &'a str,
+    revision: &'a str,
+}
+
+impl<'a, 'b, 'c> KeyUpload for KeyUploadRequest<'a, 'b, 'c> {
+    fn exists(&mut self) -> bool {
+        let mut request = OriginPublicKeyGet::new();
+        request.set_origin(self.origin.to_string());
+        request.set_revision(self.revision.to_string());
+        route_message::<OriginPublicKeyGet, OriginPublicKey>(self.req, &request).is_ok()
+    }
+
+    fn has_role(&mut self, role: OriginMemberRole) -> bool {
+        check_origin_role(self.req, self.origin, role).unwrap_or(false)
+    }
+}
+
+// Return the status an upload of an origin public key is refused with, if it is. `hab pkg upload`
+// sends the public key of every signer ahead of the package and carries on when it's told the key
+// exists, so an existing revision is answered with a conflict whatever the uploader's role. Only
+// creating a new revision is held to owners.
+fn refuse_key_upload<T: KeyUpload>(upload: &mut T) -> Option<status::Status> {
+    if upload.exists() {
+        return Some(status::Conflict);
+    }
+    if !upload.has_role(OriginMemberRole::Owner) {
+        return Some(status::Forbidden);
+    }
+    None
+}
+
 fn download_latest_origin_secret_key(req: &mut Request) -> IronResult<Response> {
     let origin = match get_param(req, "origin") {
         Some(origin) => origin,
         None => return Ok(Response::with(status::BadRequest)),
     };
 
-    if !check_origin_access(req, &origin).unwrap_or(false) {
+    if !check_origin_role(req, &origin, OriginMemberRole::Owner).unwrap_or(false) {
         return Ok(Response::with(status::Forbidden));
     }
 
     let mut request = OriginSecretKeyGet::new();
-    match helpers::get_origin(req, origin) {
+    match helpers::get_origin(req, &origin) {
         Ok(mut origin) => {
             request.set_owner_id(origin.get_owner_id());
             request.set_origin(origin.take_name());
@@ -589,6 +1019,13 @@ fn download_latest_origin_secret_key(req: &mut Request) -> IronResult<Response>
         Ok(key) => key,
         Err(err) => return Ok(render_net_error(&err)),
     };
+    helpers::audit(
+        req,
+        &origin,
+        OriginAuditAction::SecretKeyDownload,
+        key.get_revision(),
+        "",
+    );
 
     let xfilename = format!("{}-{}.sig.key", key.get_name(), key.get_revision());
     download_content_as_file(key.get_body(), xfilename)
@@ -603,7 +1040,7 @@ fn upload_origin_secret_key(req: &mut Request) -> IronResult<Response> {
 
     let origin = match get_param(req, "origin") {
         Some(origin) => {
-            if !check_origin_access(req, &origin).unwrap_or(false) {
+            if !check_origin_role(req, &origin, OriginMemberRole::Owner).unwrap_or(false) {
                 return Ok(Response::with(status::Forbidden));
             }
 
@@ -656,6 +1093,13 @@ fn upload_origin_secret_key(req: &mut Request) -> IronResult<Response> {
     request.set_owner_id(0);
     match route_message::<OriginSecretKeyCreate, OriginSecretKey>(req, &request) {
         Ok(_) => {
+            helpers::audit(
+                req,
+                &origin,
+                OriginAuditAction::SecretKeyUpload,
+                request.get_revision(),
+                "",
+            );
             log_event!(
                 req,
                 Event::OriginSecretKeyUpload {
@@ -704,21 +1148,20 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
         ident
     );
 
-    // Find the path to folder where archive should be created, and
-    // create the folder if necessary
-    let parent_path = depot.archive_parent(&ident);
+    // Stage the upload on local disk so it can be inspected before it's stored, creating the
+    // staging folder if necessary
+    let staging_path = depot.staging_path();
 
-    match fs::create_dir_all(parent_path.clone()) {
+    match fs::create_dir_all(staging_path.clone()) {
         Ok(_) => {}
         Err(e) => {
-            error!("Unable to create archive directory, err={:?}", e);
+            error!("Unable to create staging directory, err={:?}", e);
             return Ok(Response::with(status::InternalServerError));
         }
     };
 
-    // Create a temp file at the archive location
     let temp_name = format!("{}.tmp", Uuid::new_v4());
-    let temp_path = parent_path.join(temp_name);
+    let temp_path = staging_path.join(temp_name);
 
     let mut archive = write_archive(&temp_path, &mut req.body)?;
     debug!("Package Archive: {:#?}", archive);
@@ -764,8 +1207,15 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
             }
         };
 
-    if origin_package_found && depot.archive(&ident, &target_from_artifact).is_some() {
-        return Ok(Response::with((status::Conflict)));
+    if origin_package_found {
+        match depot.archive_exists(&ident, &target_from_artifact) {
+            Ok(true) => return Ok(Response::with((status::Conflict))),
+            Ok(false) => (),
+            Err(e) => {
+                error!("Unable to check the store for {}, err={}", ident, e);
+                return Ok(Response::with(status::InternalServerError));
+            }
+        }
     };
 
     let checksum_from_artifact = match archive.checksum() {
@@ -819,30 +1269,30 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
         }
     }
 
-    let filename = depot.archive_path(&ident, &target_from_artifact);
+    let mut package = match OriginPackageCreate::from_archive(&mut archive) {
+        Ok(package) => package,
+        Err(e) => {
+            info!("Error building package from archive: {:#?}", e);
+            return Ok(Response::with((status::UnprocessableEntity, "ds:up:5")));
+        }
+    };
+
+    let key = depot.archive_key(&ident, &target_from_artifact);
 
-    match fs::rename(&temp_path, &filename) {
+    match depot.put_archive(&ident, &target_from_artifact, &archive) {
         Ok(_) => {}
         Err(e) => {
             error!(
-                "Unable to rename temp archive {:?} to {:?}, err={:?}",
+                "Unable to store temp archive {:?} at {}, err={}",
                 temp_path,
-                filename,
+                key,
                 e
             );
             return Ok(Response::with(status::InternalServerError));
         }
     }
 
-    info!("File added to Depot at {}", filename.to_string_lossy());
-    let mut archive = PackageArchive::new(filename);
-    let mut package = match OriginPackageCreate::from_archive(&mut archive) {
-        Ok(package) => package,
-        Err(e) => {
-            info!("Error building package from archive: {:#?}", e);
-            return Ok(Response::with((status::UnprocessableEntity, "ds:up:5")));
-        }
-    };
+    info!("File added to Depot at {}", key);
 
     if ident.satisfies(package.get_ident()) {
         {
@@ -898,6 +1348,11 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
                 return Ok(render_net_error(&err));
             }
 
+            let mut trigger =
+                OriginWebhookTrigger::for_package(OriginWebhookEvent::PackageUploaded, &ident);
+            trigger.set_target(target_from_artifact.to_string());
+            helpers::trigger_webhooks(req, &trigger);
+
             // Schedule re-build of dependent packages (if requested)
             // Don't schedule builds if the upload is being done by the builder
             if depot.config.builds_enabled &&
@@ -929,6 +1384,14 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
             }
         }
 
+        helpers::audit(
+            req,
+            ident.get_origin(),
+            OriginAuditAction::PackageUpload,
+            &ident.to_string(),
+            &target_from_artifact.to_string(),
+        );
+
         let mut response = Response::with((
             status::Created,
             format!("/pkgs/{}/download", package.get_ident()),
@@ -1050,6 +1513,13 @@ fn schedule(req: &mut Request) -> IronResult<Response> {
             if let Err(e) = segment.track(&account_name, &msg) {
                 warn!("Error tracking scheduling of job group in segment, {}", e);
             }
+            helpers::audit(
+                req,
+                request.get_origin(),
+                OriginAuditAction::JobSchedule,
+                &format!("{}/{}", request.get_origin(), request.get_package()),
+                &group.get_id().to_string(),
+            );
 
             let mut response = render_json(status::Ok, &group);
             dont_cache_response(&mut response);
@@ -1199,57 +1669,185 @@ fn package_channels(req: &mut Request) -> IronResult<Response> {
     }
 }
 
-fn download_package(req: &mut Request) -> IronResult<Response> {
+fn download_package(req: &mut Request) -> IronResult<Response> {
+    let lock = req.get::<persistent::State<DepotUtil>>().expect(
+        "depot not found",
+    );
+    let depot = lock.read().expect("depot read lock is poisoned");
+    let session_id = helpers::get_optional_session_id(req);
+    let mut ident_req = OriginPackageGet::new();
+    let ident = ident_from_req(req);
+    let mut vis = visibility_for_optional_session(req, session_id, &ident.get_origin());
+    vis.push(OriginPackageVisibility::Hidden);
+    ident_req.set_visibilities(vis);
+    ident_req.set_ident(ident);
+
+    let agent_target = target_from_headers(&req.headers.get::<UserAgent>().unwrap()).unwrap();
+    if !depot.config.targets.contains(&agent_target) {
+        return Ok(Response::with((
+            status::NotImplemented,
+            "Unsupported client platform ({}).",
+        )));
+    }
+
+    // Releases of mirrored origins which we don't have yet may be fetched from upstream.
+    let result = match route_message::<OriginPackageGet, OriginPackage>(req, &ident_req) {
+        Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND &&
+                            pull_from_upstream(&depot, ident_req.get_ident(), &agent_target) => {
+            route_message::<OriginPackageGet, OriginPackage>(req, &ident_req)
+        }
+        result => result,
+    };
+    match result {
+        Ok(package) => {
+            let key = depot.archive_key(package.get_ident(), &agent_target);
+            let file_name = DepotUtil::archive_file_name(package.get_ident(), &agent_target);
+            Ok(archive_response(
+                &depot,
+                &key,
+                file_name,
+                req.headers.get::<Range>(),
+            ))
+        }
+        Err(err) => return Ok(render_net_error(&err)),
+    }
+}
+
+// Where the single byte range a `Range` header asks for starts and ends, inclusively, within an
+// archive of the given size.
+#[derive(Debug, PartialEq)]
+enum ByteRange {
+    // No range we serve was asked for, so the whole archive is sent.
+    Whole,
+    Part(u64, u64),
+    // The range starts past the end of the archive.
+    Unsatisfiable,
+}
+
+impl ByteRange {
+    fn new(range: Option<&Range>, size: u64) -> Self {
+        let spec = match range {
+            // Several ranges would have to be sent as a multipart body, so the whole archive is
+            // sent instead, which clients have to cope with anyway.
+            Some(&Range::Bytes(ref specs)) if specs.len() == 1 => &specs[0],
+            _ => return ByteRange::Whole,
+        };
+        let (start, end) = match *spec {
+            ByteRangeSpec::FromTo(start, end) if start <= end => {
+                (start, cmp::min(end, size.saturating_sub(1)))
+            }
+            ByteRangeSpec::FromTo(_, _) => return ByteRange::Whole,
+            ByteRangeSpec::AllFrom(start) => (start, size.saturating_sub(1)),
+            ByteRangeSpec::Last(0) => return ByteRange::Unsatisfiable,
+            ByteRangeSpec::Last(len) => (size.saturating_sub(len), size.saturating_sub(1)),
+        };
+        if start >= size {
+            ByteRange::Unsatisfiable
+        } else {
+            ByteRange::Part(start, end)
+        }
+    }
+}
+
+// Send the archive stored under `key`, or the part of it a `Range` header asks for, so
+// interrupted downloads can pick up where they left off.
+fn archive_response(
+    depot: &DepotUtil,
+    key: &str,
+    file_name: String,
+    range: Option<&Range>,
+) -> Response {
+    let size = match depot.store.size(key) {
+        Ok(size) => size,
+        Err(Error::ArchiveNotFound(_)) => {
+            // This can happen if the package is not found in the store for some reason
+            error!("Inconsistentcy between metadata and archive store!");
+            return Response::with(status::InternalServerError);
+        }
+        Err(e) => {
+            error!("Unable to read archive {} from the store, err={}", key, e);
+            return Response::with(status::InternalServerError);
+        }
+    };
+    let (mut response, start, len) = match ByteRange::new(range, size) {
+        ByteRange::Whole => (Response::with(status::Ok), 0, size),
+        ByteRange::Part(start, end) => {
+            let mut response = Response::with(status::PartialContent);
+            response.headers.set(ContentRange(ContentRangeSpec::Bytes {
+                range: Some((start, end)),
+                instance_length: Some(size),
+            }));
+            (response, start, end - start + 1)
+        }
+        ByteRange::Unsatisfiable => {
+            let mut response = Response::with(status::RangeNotSatisfiable);
+            response.headers.set(ContentRange(ContentRangeSpec::Bytes {
+                range: None,
+                instance_length: Some(size),
+            }));
+            response.headers.set(XFileName(file_name));
+            return response;
+        }
+    };
+    let reader = match depot.store.get_from(key, start) {
+        Ok(reader) => reader,
+        Err(e) => {
+            error!("Unable to read archive {} from the store, err={}", key, e);
+            return Response::with(status::InternalServerError);
+        }
+    };
+    response.set_mut(BodyReader(reader.take(len)));
+    response.headers.set(ContentLength(len));
+    response.headers.set(ContentType(
+        Mime(TopLevel::Application, SubLevel::OctetStream, vec![]),
+    ));
+    response.headers.set(AcceptRanges(vec![RangeUnit::Bytes]));
+    do_cache_response(&mut response);
+    let disp = ContentDisposition {
+        disposition: DispositionType::Attachment,
+        parameters: vec![
+            DispositionParam::Filename(
+                Charset::Iso_8859_1,
+                None,
+                file_name.as_bytes().to_vec()
+            ),
+        ],
+    };
+    response.headers.set(disp);
+    response.headers.set(XFileName(file_name));
+    response
+}
+
+fn pull_latest_from_upstream(
+    req: &mut Request,
+    ident: &OriginPackageIdent,
+    channel: Option<&str>,
+    target: &PackageTarget,
+) -> Option<OriginPackageIdent> {
     let lock = req.get::<persistent::State<DepotUtil>>().expect(
         "depot not found",
     );
     let depot = lock.read().expect("depot read lock is poisoned");
-    let session_id = helpers::get_optional_session_id(req);
-    let mut ident_req = OriginPackageGet::new();
-    let ident = ident_from_req(req);
-    let mut vis = visibility_for_optional_session(req, session_id, &ident.get_origin());
-    vis.push(OriginPackageVisibility::Hidden);
-    ident_req.set_visibilities(vis);
-    ident_req.set_ident(ident);
-
-    let agent_target = target_from_headers(&req.headers.get::<UserAgent>().unwrap()).unwrap();
-    if !depot.config.targets.contains(&agent_target) {
-        return Ok(Response::with((
-            status::NotImplemented,
-            "Unsupported client platform ({}).",
-        )));
+    match upstream::pull_latest(&depot, ident, channel, target) {
+        Ok(latest) => latest,
+        Err(e) => {
+            warn!("Unable to look up {} upstream, err={}", ident, e);
+            None
+        }
     }
+}
 
-    match route_message::<OriginPackageGet, OriginPackage>(req, &ident_req) {
-        Ok(package) => {
-            if let Some(archive) = depot.archive(package.get_ident(), &agent_target) {
-                match fs::metadata(&archive.path) {
-                    Ok(_) => {
-                        let mut response = Response::with((status::Ok, archive.path.clone()));
-                        do_cache_response(&mut response);
-                        let disp = ContentDisposition {
-                            disposition: DispositionType::Attachment,
-                            parameters: vec![
-                                DispositionParam::Filename(
-                                    Charset::Iso_8859_1,
-                                    None,
-                                    archive.file_name().as_bytes().to_vec()
-                                ),
-                            ],
-                        };
-                        response.headers.set(disp);
-                        response.headers.set(XFileName(archive.file_name()));
-                        Ok(response)
-                    }
-                    Err(_) => Ok(Response::with(status::NotFound)),
-                }
-            } else {
-                // This can happen if the package is not found in the file system for some reason
-                error!("Inconsistentcy between metadata and filesystem!");
-                Ok(Response::with(status::InternalServerError))
-            }
+fn pull_from_upstream(
+    depot: &DepotUtil,
+    ident: &OriginPackageIdent,
+    target: &PackageTarget,
+) -> bool {
+    match upstream::pull(depot, ident, target) {
+        Ok(pulled) => pulled,
+        Err(e) => {
+            warn!("Unable to pull {} from upstream, err={}", ident, e);
+            false
         }
-        Err(err) => return Ok(render_net_error(&err)),
     }
 }
 
@@ -1412,12 +2010,12 @@ fn package_privacy_toggle(req: &mut Request) -> IronResult<Response> {
         Err(_) => return Ok(Response::with(status::BadRequest)),
     };
 
-    if !check_origin_access(req, &origin).unwrap_or(false) {
+    if !check_origin_role(req, &origin, OriginMemberRole::Maintainer).unwrap_or(false) {
         return Ok(Response::with(status::Forbidden));
     }
 
     let mut opg = OriginPackageGet::new();
-    opg.set_ident(ident);
+    opg.set_ident(ident.clone());
     opg.set_visibilities(all_visibilities());
 
     match route_message::<OriginPackageGet, OriginPackage>(req, &opg) {
@@ -1428,7 +2026,72 @@ fn package_privacy_toggle(req: &mut Request) -> IronResult<Response> {
             opu.set_pkg(package);
 
             match route_message::<OriginPackageUpdate, NetOk>(req, &opu) {
-                Ok(_) => Ok(Response::with(status::Ok)),
+                Ok(_) => {
+                    helpers::audit(
+                        req,
+                        &origin,
+                        OriginAuditAction::PackageVisibilityUpdate,
+                        &ident.to_string(),
+                        &real_visibility.to_string(),
+                    );
+                    Ok(Response::with(status::Ok))
+                }
+                Err(e) => Ok(render_net_error(&e)),
+            }
+        }
+        Err(e) => Ok(render_net_error(&e)),
+    }
+}
+
+fn yank_package(req: &mut Request) -> IronResult<Response> {
+    set_package_yanked(req, true)
+}
+
+fn unyank_package(req: &mut Request) -> IronResult<Response> {
+    set_package_yanked(req, false)
+}
+
+// Yanked releases are skipped when resolving the latest release of a package or listing a
+// channel, but can still be fetched by their fully qualified identifier.
+fn set_package_yanked(req: &mut Request, yanked: bool) -> IronResult<Response> {
+    let origin = match get_param(req, "origin") {
+        Some(o) => o,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let ident = ident_from_req(req);
+
+    if !ident.valid() || !ident.fully_qualified() {
+        info!(
+            "Invalid or not fully qualified package identifier: {}",
+            ident
+        );
+        return Ok(Response::with(status::BadRequest));
+    }
+
+    if !check_origin_role(req, &origin, OriginMemberRole::Maintainer).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut opg = OriginPackageGet::new();
+    opg.set_ident(ident.clone());
+    opg.set_visibilities(all_visibilities());
+
+    match route_message::<OriginPackageGet, OriginPackage>(req, &opg) {
+        Ok(mut package) => {
+            let mut opu = OriginPackageUpdate::new();
+            package.set_yanked(yanked);
+            opu.set_pkg(package);
+
+            match route_message::<OriginPackageUpdate, NetOk>(req, &opu) {
+                Ok(_) => {
+                    let action = if yanked {
+                        OriginAuditAction::PackageYank
+                    } else {
+                        OriginAuditAction::PackageUnyank
+                    };
+                    helpers::audit(req, &origin, action, &ident.to_string(), "");
+                    Ok(Response::with(status::Ok))
+                }
                 Err(e) => Ok(render_net_error(&e)),
             }
         }
@@ -1436,6 +2099,97 @@ fn package_privacy_toggle(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn delete_package(req: &mut Request) -> IronResult<Response> {
+    let origin = match get_param(req, "origin") {
+        Some(o) => o,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let ident = ident_from_req(req);
+
+    if !ident.valid() || !ident.fully_qualified() {
+        info!(
+            "Invalid or not fully qualified package identifier: {}",
+            ident
+        );
+        return Ok(Response::with(status::BadRequest));
+    }
+
+    if !check_origin_role(req, &origin, OriginMemberRole::Owner).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut opg = OriginPackageGet::new();
+    opg.set_ident(ident.clone());
+    opg.set_visibilities(all_visibilities());
+
+    let package = match route_message::<OriginPackageGet, OriginPackage>(req, &opg) {
+        Ok(package) => package,
+        Err(e) => return Ok(render_net_error(&e)),
+    };
+
+    // Releases which other releases still depend upon can only be yanked.
+    match package_dependents(req, &ident) {
+        Ok(ref dependents) if !dependents.get_idents().is_empty() => {
+            return Ok(render_json(status::Conflict, dependents));
+        }
+        Ok(_) => (),
+        Err(err) => return Ok(render_net_error(&err)),
+    }
+
+    let mut opd = OriginPackageDelete::new();
+    opd.set_id(package.get_id());
+    opd.set_ident(ident.clone());
+    if let Err(err) = route_message::<OriginPackageDelete, NetOk>(req, &opd) {
+        return Ok(render_net_error(&err));
+    }
+    helpers::audit(
+        req,
+        &origin,
+        OriginAuditAction::PackageDelete,
+        &ident.to_string(),
+        package.get_target(),
+    );
+
+    match PackageTarget::from_str(package.get_target()) {
+        Ok(target) => {
+            let lock = req.get::<persistent::State<DepotUtil>>().expect(
+                "depot not found",
+            );
+            let depot = lock.read().expect("depot read lock is poisoned");
+            let key = depot.archive_key(package.get_ident(), &target);
+            if let Err(e) = depot.store.delete(&key) {
+                warn!("Unable to delete archive {}, err={}", key, e);
+            }
+            handlers::files::forget_contents(&depot, package.get_ident(), &target);
+        }
+        Err(e) => warn!("Invalid target for {}, err={}", package.get_ident(), e),
+    }
+
+    Ok(Response::with(status::NoContent))
+}
+
+// Dependents can be in any origin, so every shard is asked for the ones it holds.
+fn package_dependents(
+    req: &mut Request,
+    ident: &OriginPackageIdent,
+) -> NetResult<OriginPackageDependentListResponse> {
+    let mut idents = Vec::new();
+    for shard in 0..SHARD_COUNT {
+        let mut request = OriginPackageDependentListRequest::new();
+        request.set_ident(ident.clone());
+        request.set_shard(shard);
+        let mut response = route_message::<
+            OriginPackageDependentListRequest,
+            OriginPackageDependentListResponse,
+        >(req, &request)?;
+        idents.extend(response.take_idents().into_vec());
+    }
+    idents.sort_by_key(|i| i.to_string());
+    let mut dependents = OriginPackageDependentListResponse::new();
+    dependents.set_idents(protobuf::RepeatedField::from_vec(idents));
+    Ok(dependents)
+}
+
 fn list_packages(req: &mut Request) -> IronResult<Response> {
     let session_id = helpers::get_optional_session_id(req);
     let mut distinct = false;
@@ -1619,8 +2373,110 @@ fn create_channel(req: &mut Request) -> IronResult<Response> {
         None => return Ok(Response::with(status::BadRequest)),
     };
 
+    if !check_origin_role(req, &origin, OriginMemberRole::Maintainer).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
     match helpers::create_channel(req, &origin, &channel) {
-        Ok(origin_channel) => Ok(render_json(status::Created, &origin_channel)),
+        Ok(origin_channel) => {
+            helpers::audit(req, &origin, OriginAuditAction::ChannelCreate, &channel, "");
+            Ok(render_json(status::Created, &origin_channel))
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn get_channel_policy(req: &mut Request) -> IronResult<Response> {
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let channel = match get_param(req, "channel") {
+        Some(channel) => channel,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_access(req, &origin).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = OriginChannelPolicyGet::new();
+    request.set_origin_name(origin);
+    request.set_channel_name(channel);
+    match route_message::<OriginChannelPolicyGet, OriginChannelPolicy>(req, &request) {
+        Ok(policy) => {
+            let mut response = render_json(status::Ok, &policy);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn update_channel_policy(req: &mut Request) -> IronResult<Response> {
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let channel = match get_param(req, "channel") {
+        Some(channel) => channel,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_role(req, &origin, OriginMemberRole::Owner).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut policy = OriginChannelPolicy::new();
+    policy.set_origin_name(origin.clone());
+    policy.set_channel_name(channel.clone());
+    match req.get::<bodyparser::Struct<OriginChannelPolicyReq>>() {
+        Ok(Some(body)) => {
+            if let Some(role) = body.promote_role {
+                match role.parse::<OriginMemberRole>() {
+                    // Policies can only tighten who may promote, never loosen it
+                    Ok(OriginMemberRole::Developer) |
+                    Err(_) => return Ok(Response::with(status::UnprocessableEntity)),
+                    Ok(role) => policy.set_promote_role(role),
+                }
+            }
+            if let Some(prerequisite) = body.prerequisite_channel {
+                if prerequisite == channel {
+                    return Ok(Response::with(status::UnprocessableEntity));
+                }
+                policy.set_prerequisite_channel(prerequisite);
+            }
+            policy.set_require_job_success(body.require_job_success.unwrap_or(false));
+        }
+        _ => return Ok(Response::with(status::UnprocessableEntity)),
+    }
+
+    if policy.has_prerequisite_channel() {
+        let mut channel_req = OriginChannelGet::new();
+        channel_req.set_origin_name(origin.clone());
+        channel_req.set_name(policy.get_prerequisite_channel().to_string());
+        match route_message::<OriginChannelGet, OriginChannel>(req, &channel_req) {
+            Ok(_) => (),
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => {
+                return Ok(Response::with(status::UnprocessableEntity))
+            }
+            Err(err) => return Ok(render_net_error(&err)),
+        }
+    }
+
+    let mut request = OriginChannelPolicyUpdate::new();
+    request.set_policy(policy);
+    match route_message::<OriginChannelPolicyUpdate, OriginChannelPolicy>(req, &request) {
+        Ok(policy) => {
+            helpers::audit(
+                req,
+                &origin,
+                OriginAuditAction::ChannelPolicyUpdate,
+                &channel,
+                &serde_json::to_string(&policy).unwrap(),
+            );
+            Ok(render_json(status::Ok, &policy))
+        }
         Err(err) => Ok(render_net_error(&err)),
     }
 }
@@ -1640,8 +2496,8 @@ fn delete_channel(req: &mut Request) -> IronResult<Response> {
     channel_req.set_name(channel.clone());
     match route_message::<OriginChannelGet, OriginChannel>(req, &channel_req) {
         Ok(origin_channel) => {
-            // make sure the person trying to create the channel has access to do so
-            if !check_origin_access(req, &origin).unwrap_or(false) {
+            // make sure the person trying to delete the channel has access to do so
+            if !check_origin_role(req, &origin, OriginMemberRole::Maintainer).unwrap_or(false) {
                 return Ok(Response::with(status::Forbidden));
             }
 
@@ -1650,11 +2506,34 @@ fn delete_channel(req: &mut Request) -> IronResult<Response> {
                 return Ok(Response::with(status::Forbidden));
             }
 
+            // Deleting and re-creating a protected channel would clear its policy, so it takes
+            // the same role as promoting into it.
+            let mut policy_req = OriginChannelPolicyGet::new();
+            policy_req.set_origin_name(origin.clone());
+            policy_req.set_channel_name(channel.clone());
+            match route_message::<OriginChannelPolicyGet, OriginChannelPolicy>(req, &policy_req) {
+                Ok(ref policy) if policy.has_promote_role() => {
+                    if !check_origin_role(req, &origin, policy.get_promote_role())
+                        .unwrap_or(false)
+                    {
+                        return Ok(render_net_error(&NetError::new(
+                            ErrCode::CHANNEL_PROTECTED,
+                            "dp:delete-channel:0",
+                        )));
+                    }
+                }
+                Ok(_) => (),
+                Err(err) => return Ok(render_net_error(&err)),
+            }
+
             let mut delete = OriginChannelDelete::new();
             delete.set_id(origin_channel.get_id());
             delete.set_origin_id(origin_channel.get_origin_id());
             match route_message::<OriginChannelDelete, NetOk>(req, &delete) {
-                Ok(_) => Ok(Response::with(status::Ok)),
+                Ok(_) => {
+                    helpers::audit(req, &origin, OriginAuditAction::ChannelDelete, &channel, "");
+                    Ok(Response::with(status::Ok))
+                }
                 Err(err) => return Ok(render_net_error(&err)),
             }
         }
@@ -1668,15 +2547,21 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
 
     let mut ident = ident_from_req(req);
     let qualified = ident.fully_qualified();
+    let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap()).unwrap();
+
+    // Upstream knows best which release is the latest one of a mirrored origin.
+    if !qualified {
+        let channel = channel.as_ref().map(|c| c.as_str());
+        if let Some(latest) = pull_latest_from_upstream(req, &ident, channel, &target) {
+            ident = latest;
+        }
+    }
 
     if let Some(channel) = channel {
-        if !qualified {
-            let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap())
-                .unwrap()
-                .to_string();
+        if !ident.fully_qualified() {
             let mut request = OriginChannelPackageLatestGet::new();
             request.set_name(channel.clone());
-            request.set_target(target);
+            request.set_target(target.to_string());
             request.set_visibilities(visibility_for_optional_session(
                 req,
                 session_id,
@@ -1694,25 +2579,34 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
         }
 
         let mut request = OriginChannelPackageGet::new();
-        request.set_name(channel);
+        request.set_name(channel.clone());
         request.set_visibilities(visibility_for_optional_session(
             req,
             session_id,
             &ident.get_origin(),
         ));
-        request.set_ident(ident);
+        request.set_ident(ident.clone());
 
-        match route_message::<OriginChannelPackageGet, OriginPackage>(req, &request) {
+        let result = match route_message::<OriginChannelPackageGet, OriginPackage>(req, &request) {
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND && qualified &&
+                                pull_latest_from_upstream(
+                                    req,
+                                    &ident,
+                                    Some(channel.as_str()),
+                                    &target,
+                                ).is_some() => {
+                route_message::<OriginChannelPackageGet, OriginPackage>(req, &request)
+            }
+            result => result,
+        };
+        match result {
             Ok(pkg) => render_package(req, &pkg, false),
             Err(err) => Ok(render_net_error(&err)),
         }
     } else {
-        if !qualified {
-            let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap())
-                .unwrap()
-                .to_string();
+        if !ident.fully_qualified() {
             let mut request = OriginPackageLatestGet::new();
-            request.set_target(target);
+            request.set_target(target.to_string());
             request.set_visibilities(visibility_for_optional_session(
                 req,
                 session_id,
@@ -1734,7 +2628,15 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
         ));
         request.set_ident(ident.clone());
 
-        match route_message::<OriginPackageGet, OriginPackage>(req, &request) {
+        let result = match route_message::<OriginPackageGet, OriginPackage>(req, &request) {
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND && qualified &&
+                                pull_latest_from_upstream(req, &ident, None, &target)
+                                    .is_some() => {
+                route_message::<OriginPackageGet, OriginPackage>(req, &request)
+            }
+            result => result,
+        };
+        match result {
             Ok(pkg) => {
                 let lock = req.get::<persistent::State<DepotUtil>>().expect(
                     "depot not found",
@@ -1743,11 +2645,14 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
                 let depot = lock.read().expect("depot read lock is poisoned");
 
                 // If we don't have a valid archive on disk, return NotFound
-                let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap()).unwrap();
-
-                if !depot.archive(&ident, &target).is_some() {
-                    return Ok(Response::with((status::NotFound)));
-                };
+                match depot.archive_exists(&ident, &target) {
+                    Ok(true) => (),
+                    Ok(false) => return Ok(Response::with((status::NotFound))),
+                    Err(e) => {
+                        error!("Unable to check the store for {}, err={}", ident, e);
+                        return Ok(Response::with(status::InternalServerError));
+                    }
+                }
 
                 // If the request was for a fully qualified ident, cache the response, otherwise do
                 // not cache
@@ -1873,7 +2778,13 @@ fn render_package(
     let mut pkg_json = serde_json::to_value(pkg.clone()).unwrap();
     let channels = helpers::channels_for_package_ident(req, pkg.get_ident());
     pkg_json["channels"] = json!(channels);
-    pkg_json["is_a_service"] = json!(is_a_service(req, pkg.get_ident()));
+    match is_a_service(req, pkg) {
+        Ok(is_service) => pkg_json["is_a_service"] = json!(is_service),
+        Err(e) => {
+            error!("Unable to read archive for {} from the store, err={}", pkg.get_ident(), e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    }
 
     let body = serde_json::to_string(&pkg_json).unwrap();
     let mut response = Response::with((status::Ok, body));
@@ -1917,7 +2828,20 @@ fn promote_package(req: &mut Request) -> IronResult<Response> {
     };
 
     match helpers::promote_package_to_channel(req, &ident, &channel) {
-        Ok(_) => Ok(Response::with(status::Ok)),
+        Ok(_) => {
+            helpers::audit(
+                req,
+                ident.get_origin(),
+                OriginAuditAction::PackagePromote,
+                &ident.to_string(),
+                &channel,
+            );
+            let mut trigger =
+                OriginWebhookTrigger::for_package(OriginWebhookEvent::PackagePromoted, &ident);
+            trigger.set_channel(channel);
+            helpers::trigger_webhooks(req, &trigger);
+            Ok(Response::with(status::Ok))
+        }
         Err(err) => Ok(render_net_error(&err)),
     }
 }
@@ -1950,7 +2874,9 @@ fn demote_package(req: &mut Request) -> IronResult<Response> {
         return Ok(Response::with(status::Forbidden));
     }
 
-    if !check_origin_access(req, &ident.get_origin()).unwrap_or(false) {
+    if !check_origin_role(req, &ident.get_origin(), OriginMemberRole::Maintainer)
+        .unwrap_or(false)
+    {
         return Ok(Response::with(status::Forbidden));
     }
 
@@ -1959,6 +2885,14 @@ fn demote_package(req: &mut Request) -> IronResult<Response> {
     channel_req.set_name(channel);
     match route_message::<OriginChannelGet, OriginChannel>(req, &channel_req) {
         Ok(origin_channel) => {
+            if let Err(err) = helpers::check_channel_demote_policy(
+                req,
+                ident.get_origin(),
+                origin_channel.get_name(),
+            )
+            {
+                return Ok(render_net_error(&err));
+            }
             let mut request = OriginPackageGet::new();
             request.set_ident(ident.clone());
             request.set_visibilities(all_visibilities());
@@ -1967,9 +2901,24 @@ fn demote_package(req: &mut Request) -> IronResult<Response> {
                     let mut demote = OriginPackageDemote::new();
                     demote.set_channel_id(origin_channel.get_id());
                     demote.set_package_id(package.get_id());
-                    demote.set_ident(ident);
+                    demote.set_ident(ident.clone());
                     match route_message::<OriginPackageDemote, NetOk>(req, &demote) {
-                        Ok(_) => Ok(Response::with(status::Ok)),
+                        Ok(_) => {
+                            helpers::audit(
+                                req,
+                                ident.get_origin(),
+                                OriginAuditAction::PackageDemote,
+                                &ident.to_string(),
+                                origin_channel.get_name(),
+                            );
+                            let mut trigger = OriginWebhookTrigger::for_package(
+                                OriginWebhookEvent::PackageDemoted,
+                                &ident,
+                            );
+                            trigger.set_channel(origin_channel.get_name().to_string());
+                            helpers::trigger_webhooks(req, &trigger);
+                            Ok(Response::with(status::Ok))
+                        }
                         Err(err) => return Ok(render_net_error(&err)),
                     }
                 }
@@ -2077,19 +3026,20 @@ fn target_from_headers(user_agent_header: &UserAgent) -> result::Result<PackageT
     }
 }
 
-fn is_a_service<T>(req: &mut Request, ident: &T) -> bool
-where
-    T: Identifiable,
-{
+fn is_a_service(req: &mut Request, pkg: &OriginPackage) -> Result<bool> {
+    if pkg.has_is_service() {
+        return Ok(pkg.get_is_service());
+    }
+    // Releases uploaded before this was recorded only have it in their archive
     let lock = req.get::<persistent::State<DepotUtil>>().expect(
         "depot not found",
     );
     let depot = lock.read().expect("depot read lock is poisoned");
     let agent_target = target_from_headers(&req.headers.get::<UserAgent>().unwrap()).unwrap();
 
-    match depot.archive(ident, &agent_target) {
-        Some(mut archive) => archive.is_a_service(),
-        None => false,
+    match depot.archive(pkg.get_ident(), &agent_target)? {
+        Some(mut local) => Ok(local.archive.is_a_service()),
+        None => Ok(false),
     }
 }
 
@@ -2104,6 +3054,9 @@ where
     M: BeforeMiddleware + Clone,
 {
     let opt = basic.clone().optional();
+    let upload = basic.clone().allow_token(AccessTokenScope::Upload);
+    let promote = basic.clone().allow_token(AccessTokenScope::Promote);
+    let key_download = basic.clone().allow_token(AccessTokenScope::KeyDownload);
 
     router!(
         channels: get "/channels/:origin" => list_channels,
@@ -2130,15 +3083,21 @@ where
         },
         channel_package_promote: put
             "/channels/:origin/:channel/pkgs/:pkg/:version/:release/promote" => {
-            XHandler::new(promote_package).before(basic.clone())
+            XHandler::new(promote_package).before(promote.clone())
         },
         channel_package_demote: put
             "/channels/:origin/:channel/pkgs/:pkg/:version/:release/demote" => {
-            XHandler::new(demote_package).before(basic.clone())
+            XHandler::new(demote_package).before(promote.clone())
         },
         channel_create: post "/channels/:origin/:channel" => {
             XHandler::new(create_channel).before(basic.clone())
         },
+        channel_policy: get "/channels/:origin/:channel/policy" => {
+            XHandler::new(get_channel_policy).before(basic.clone())
+        },
+        channel_policy_update: put "/channels/:origin/:channel/policy" => {
+            XHandler::new(update_channel_policy).before(basic.clone())
+        },
         channel_delete: delete "/channels/:origin/:channel" => {
             XHandler::new(delete_channel).before(basic.clone())
         },
@@ -2175,12 +3134,30 @@ where
         package_download: get "/pkgs/:origin/:pkg/:version/:release/download" => {
             XHandler::new(download_package).before(opt.clone())
         },
+        package_files: get "/pkgs/:origin/:pkg/:version/:release/files" => {
+            XHandler::new(handlers::files::package_files).before(opt.clone())
+        },
+        package_metadata_file: get "/pkgs/:origin/:pkg/:version/:release/metadata/*file" => {
+            XHandler::new(handlers::files::package_metadata_file).before(opt.clone())
+        },
+        package_diff: get "/pkgs/:origin/:pkg/:version/:release/diff/:other_version/:other_release" => {
+            XHandler::new(handlers::files::package_diff).before(opt.clone())
+        },
         package_upload: post "/pkgs/:origin/:pkg/:version/:release" => {
-            XHandler::new(upload_package).before(basic.clone())
+            XHandler::new(upload_package).before(upload.clone())
         },
         package_privacy_toggle: patch "/pkgs/:origin/:pkg/:version/:release/:visibility" => {
             XHandler::new(package_privacy_toggle).before(basic.clone())
         },
+        package_delete: delete "/pkgs/:origin/:pkg/:version/:release" => {
+            XHandler::new(delete_package).before(basic.clone())
+        },
+        package_yank: put "/pkgs/:origin/:pkg/:version/:release/yank" => {
+            XHandler::new(yank_package).before(basic.clone())
+        },
+        package_unyank: put "/pkgs/:origin/:pkg/:version/:release/unyank" => {
+            XHandler::new(unyank_package).before(basic.clone())
+        },
         packages_stats: get "/pkgs/origins/:origin/stats" => package_stats,
         schedule: post "/pkgs/schedule/:origin/:pkg" => {
             XHandler::new(schedule).before(basic.clone())
@@ -2205,13 +3182,13 @@ where
             XHandler::new(generate_origin_keys).before(basic.clone())
         },
         origin_key_create: post "/origins/:origin/keys/:revision" => {
-            XHandler::new(upload_origin_key).before(basic.clone())
+            XHandler::new(upload_origin_key).before(upload.clone())
         },
         origin_secret_key_create: post "/origins/:origin/secret_keys/:revision" => {
             XHandler::new(upload_origin_secret_key).before(basic.clone())
         },
         origin_secret_key_latest: get "/origins/:origin/secret_keys/latest" => {
-            XHandler::new(download_latest_origin_secret_key).before(basic.clone())
+            XHandler::new(download_latest_origin_secret_key).before(key_download.clone())
         },
 
         builder_key_latest: get "/builder/keys/latest" => download_latest_builder_key,
@@ -2253,6 +3230,24 @@ where
         origin_member_delete: delete "/origins/:origin/users/:username" => {
             XHandler::new(origin_member_delete).before(basic.clone())
         },
+        origin_member_role: put "/origins/:origin/users/:username/role" => {
+            XHandler::new(origin_member_role_update).before(basic.clone())
+        },
+        origin_audit_events: get "/origins/:origin/audit" => {
+            XHandler::new(list_origin_audit_events).before(basic.clone())
+        },
+        origin_webhooks: get "/origins/:origin/webhooks" => {
+            XHandler::new(list_origin_webhooks).before(basic.clone())
+        },
+        origin_webhook_create: post "/origins/:origin/webhooks" => {
+            XHandler::new(create_origin_webhook).before(basic.clone())
+        },
+        origin_webhook_delete: delete "/origins/:origin/webhooks/:id" => {
+            XHandler::new(delete_origin_webhook).before(basic.clone())
+        },
+        origin_webhook_deliveries: get "/origins/:origin/webhooks/:id/deliveries" => {
+            XHandler::new(list_origin_webhook_deliveries).before(basic.clone())
+        },
     )
 }
 
@@ -2268,6 +3263,9 @@ pub fn router(depot: DepotUtil) -> Result<Chain> {
     chain.link(persistent::Read::<GitHubCli>::both(
         GitHubClient::new(depot.config.github.clone()),
     ));
+    chain.link(persistent::Read::<OAuthCli>::both(
+        oauth_client::new_provider(&depot.config.oauth),
+    ));
     chain.link(persistent::Read::<SegmentCli>::both(
         SegmentClient::new(depot.config.segment.clone()),
     ));
@@ -2285,3 +3283,158 @@ impl From<Error> for IronError {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+    use std::path::Path;
+
+    use iron::Headers;
+    use iron_test::{request, response};
+    use tempdir::TempDir;
+
+    use config::Config;
+    use super::*;
+
+    const KEY: &'static str = "a4/3f/core-redis-3.2.4-20170514150022-x86_64-linux.hart";
+    const FILE_NAME: &'static str = "core-redis-3.2.4-20170514150022-x86_64-linux.hart";
+    const ARCHIVE: &'static [u8] = b"0123456789";
+
+    // Serves `ARCHIVE` from a depot kept in `path`, the way downloads are.
+    fn downloads(path: &Path) -> Box<Fn(&mut Request) -> IronResult<Response> + Send + Sync> {
+        let mut config = Config::default();
+        config.path = path.to_path_buf();
+        let depot = DepotUtil::new(config).unwrap();
+        let staged = path.join("upload.hart");
+        File::create(&staged).unwrap().write_all(ARCHIVE).unwrap();
+        depot.store.put(KEY, &staged).unwrap();
+        Box::new(move |req: &mut Request| {
+            Ok(archive_response(
+                &depot,
+                KEY,
+                FILE_NAME.to_string(),
+                req.headers.get::<Range>(),
+            ))
+        })
+    }
+
+    fn download(path: &Path, range: Option<Range>) -> Response {
+        let mut headers = Headers::new();
+        if let Some(range) = range {
+            headers.set(range);
+        }
+        request::get("http://localhost/download", headers, &downloads(path)).unwrap()
+    }
+
+    #[test]
+    fn whole_archive_is_sent_without_a_range() {
+        let tmp = TempDir::new("depot").unwrap();
+        let res = download(tmp.path(), None);
+        assert_eq!(res.status, Some(status::Ok));
+        assert_eq!(res.headers.get::<ContentLength>(), Some(&ContentLength(10)));
+        assert_eq!(
+            res.headers.get::<XFileName>(),
+            Some(&XFileName(FILE_NAME.to_string()))
+        );
+        assert_eq!(response::extract_body_to_bytes(res), ARCHIVE);
+    }
+
+    #[test]
+    fn download_resumes_from_a_range() {
+        let tmp = TempDir::new("depot").unwrap();
+        let res = download(
+            tmp.path(),
+            Some(Range::Bytes(vec![ByteRangeSpec::AllFrom(4)])),
+        );
+        assert_eq!(res.status, Some(status::PartialContent));
+        assert_eq!(
+            res.headers.get::<ContentRange>(),
+            Some(&ContentRange(ContentRangeSpec::Bytes {
+                range: Some((4, 9)),
+                instance_length: Some(10),
+            }))
+        );
+        assert_eq!(response::extract_body_to_bytes(res), &ARCHIVE[4..]);
+    }
+
+    #[test]
+    fn range_past_the_end_is_not_satisfiable() {
+        let tmp = TempDir::new("depot").unwrap();
+        let res = download(
+            tmp.path(),
+            Some(Range::Bytes(vec![ByteRangeSpec::AllFrom(10)])),
+        );
+        assert_eq!(res.status, Some(status::RangeNotSatisfiable));
+        assert_eq!(
+            res.headers.get::<ContentRange>(),
+            Some(&ContentRange(ContentRangeSpec::Bytes {
+                range: None,
+                instance_length: Some(10),
+            }))
+        );
+    }
+
+    struct Upload {
+        exists: bool,
+        role: OriginMemberRole,
+    }
+
+    impl KeyUpload for Upload {
+        fn exists(&mut self) -> bool {
+            self.exists
+        }
+
+        fn has_role(&mut self, role: OriginMemberRole) -> bool {
+            self.role.includes(role)
+        }
+    }
+
+    #[test]
+    fn developers_may_upload_packages_signed_with_existing_keys() {
+        // The conflict tells `hab pkg upload` the key is there, and it goes on to the package.
+        let mut upload = Upload {
+            exists: true,
+            role: OriginMemberRole::Developer,
+        };
+        assert_eq!(refuse_key_upload(&mut upload), Some(status::Conflict));
+    }
+
+    #[test]
+    fn only_owners_may_upload_new_key_revisions() {
+        let mut upload = Upload {
+            exists: false,
+            role: OriginMemberRole::Maintainer,
+        };
+        assert_eq!(refuse_key_upload(&mut upload), Some(status::Forbidden));
+        upload.role = OriginMemberRole::Owner;
+        assert_eq!(refuse_key_upload(&mut upload), None);
+    }
+
+    #[test]
+    fn byte_ranges_are_clamped_to_the_archive() {
+        let range = |spec| Range::Bytes(vec![spec]);
+        assert_eq!(ByteRange::new(None, 10), ByteRange::Whole);
+        assert_eq!(
+            ByteRange::new(Some(&range(ByteRangeSpec::FromTo(2, 20))), 10),
+            ByteRange::Part(2, 9)
+        );
+        assert_eq!(
+            ByteRange::new(Some(&range(ByteRangeSpec::Last(3))), 10),
+            ByteRange::Part(7, 9)
+        );
+        assert_eq!(
+            ByteRange::new(Some(&range(ByteRangeSpec::Last(30))), 10),
+            ByteRange::Part(0, 9)
+        );
+        assert_eq!(
+            ByteRange::new(
+                Some(&Range::Bytes(vec![
+                    ByteRangeSpec::FromTo(0, 1),
+                    ByteRangeSpec::FromTo(4, 5),
+                ])),
+                10,
+            ),
+            ByteRange::Whole
+        );
+    }
+}
@@ -0,0 +1,169 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use walkdir::WalkDir;
+
+use error::{Error, Result};
+use super::{move_file, ArchiveStore};
+
+/// Keeps archives in a directory on the local filesystem.
+pub struct LocalStore {
+    root: PathBuf,
+}
+
+impl LocalStore {
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        LocalStore { root: root.into() }
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        self.root.join(key)
+    }
+}
+
+impl ArchiveStore for LocalStore {
+    fn exists(&self, key: &str) -> Result<bool> {
+        Ok(self.path_for(key).is_file())
+    }
+
+    fn put(&self, key: &str, src: &Path) -> Result<()> {
+        let path = self.path_for(key);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        move_file(src, &path)
+    }
+
+    fn get(&self, key: &str) -> Result<Box<Read + Send>> {
+        match File::open(self.path_for(key)) {
+            Ok(file) => Ok(Box::new(file)),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => {
+                Err(Error::ArchiveNotFound(key.to_string()))
+            }
+            Err(e) => Err(Error::IO(e)),
+        }
+    }
+
+    fn get_from(&self, key: &str, offset: u64) -> Result<Box<Read + Send>> {
+        match File::open(self.path_for(key)) {
+            Ok(mut file) => {
+                file.seek(SeekFrom::Start(offset))?;
+                Ok(Box::new(file))
+            }
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => {
+                Err(Error::ArchiveNotFound(key.to_string()))
+            }
+            Err(e) => Err(Error::IO(e)),
+        }
+    }
+
+    fn size(&self, key: &str) -> Result<u64> {
+        match fs::metadata(self.path_for(key)) {
+            Ok(ref m) if m.is_file() => Ok(m.len()),
+            Ok(_) => Err(Error::ArchiveNotFound(key.to_string())),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => {
+                Err(Error::ArchiveNotFound(key.to_string()))
+            }
+            Err(e) => Err(Error::IO(e)),
+        }
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        match fs::remove_file(self.path_for(key)) {
+            Ok(_) => Ok(()),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => {
+                Err(Error::ArchiveNotFound(key.to_string()))
+            }
+            Err(e) => Err(Error::IO(e)),
+        }
+    }
+
+    fn keys(&self) -> Result<Vec<String>> {
+        let mut keys = Vec::new();
+        if !self.root.is_dir() {
+            return Ok(keys);
+        }
+        for entry in WalkDir::new(&self.root).follow_links(false) {
+            let entry = entry.map_err(|e| {
+                Error::IO(io::Error::new(io::ErrorKind::Other, e.to_string()))
+            })?;
+            if !entry.file_type().is_file() ||
+                entry.path().extension().map_or(true, |e| e != "hart")
+            {
+                continue;
+            }
+            // Keys always use forward slashes, whatever the platform's separator is.
+            let relative = entry.path().strip_prefix(&self.root).unwrap();
+            let parts: Vec<String> = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            keys.push(parts.join("/"));
+        }
+        keys.sort();
+        Ok(keys)
+    }
+
+    fn local_path(&self, key: &str) -> Option<PathBuf> {
+        Some(self.path_for(key))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::{Read, Write};
+
+    use tempdir::TempDir;
+
+    use error::Error;
+    use storage::ArchiveStore;
+    use super::LocalStore;
+
+    #[test]
+    fn archives_round_trip() {
+        let root = TempDir::new("depot").unwrap();
+        let staging = TempDir::new("staging").unwrap();
+        let store = LocalStore::new(root.path().join("pkgs"));
+        let key = "a4/3f/core-redis-3.2.4-20170514150022-x86_64-linux.hart";
+        let src = staging.path().join("upload.tmp");
+        File::create(&src).unwrap().write_all(b"HART-1").unwrap();
+
+        assert!(!store.exists(key).unwrap());
+        assert_eq!(store.keys().unwrap(), Vec::<String>::new());
+        store.put(key, &src).unwrap();
+        assert!(!src.exists());
+        assert!(store.exists(key).unwrap());
+        assert_eq!(store.keys().unwrap(), vec![key.to_string()]);
+
+        let mut body = String::new();
+        store.get(key).unwrap().read_to_string(&mut body).unwrap();
+        assert_eq!(body, "HART-1");
+
+        let mut rest = String::new();
+        store.get_from(key, 4).unwrap().read_to_string(&mut rest).unwrap();
+        assert_eq!(rest, "-1");
+        assert_eq!(store.size(key).unwrap(), 6);
+
+        store.delete(key).unwrap();
+        match store.get(key) {
+            Err(Error::ArchiveNotFound(ref k)) => assert_eq!(k, key),
+            _ => panic!("Expected the archive to be gone"),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Where the depot keeps package archives.
+//!
+//! Archives are addressed by a key, which is their path relative to the root of the store (for
+//! example `a4/3f/core-redis-3.2.4-20170514150022-x86_64-linux.hart`). The `[storage]` section of
+//! the config picks the backend holding them:
+//!
+//! * `local` keeps them in the `pkgs` directory under the depot's `path`, as it always has.
+//! * `s3` keeps them in a bucket of an S3 compatible object store, such as Amazon S3 or MinIO.
+//!
+//! Uploads are always staged on local disk first so they can be inspected before being stored.
+
+pub mod local;
+pub mod s3;
+
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use hab_core::package::PackageArchive;
+use tempfile::NamedTempFile;
+
+use config::{Config, StorageBackend};
+use error::{Error, Result};
+
+pub use self::local::LocalStore;
+pub use self::s3::S3Store;
+
+/// A place package archives can be stored and read back from.
+pub trait ArchiveStore: Send + Sync {
+    /// Returns whether an archive is stored under the given key.
+    fn exists(&self, key: &str) -> Result<bool>;
+
+    /// Stores the file at `src` under the given key, replacing whatever was there. The file at
+    /// `src` is moved into the store and will not be there afterwards.
+    fn put(&self, key: &str, src: &Path) -> Result<()>;
+
+    /// Opens the archive stored under the given key for reading.
+    ///
+    /// # Failures
+    ///
+    /// * `Error::ArchiveNotFound` if nothing is stored under the key
+    fn get(&self, key: &str) -> Result<Box<Read + Send>>;
+
+    /// Opens the archive stored under the given key for reading from byte `offset` on, so an
+    /// interrupted download can pick up where it left off.
+    ///
+    /// # Failures
+    ///
+    /// * `Error::ArchiveNotFound` if nothing is stored under the key
+    fn get_from(&self, key: &str, offset: u64) -> Result<Box<Read + Send>>;
+
+    /// Returns the length in bytes of the archive stored under the given key.
+    ///
+    /// # Failures
+    ///
+    /// * `Error::ArchiveNotFound` if nothing is stored under the key
+    fn size(&self, key: &str) -> Result<u64>;
+
+    /// Removes the archive stored under the given key.
+    fn delete(&self, key: &str) -> Result<()>;
+
+    /// Returns the keys of every stored archive.
+    fn keys(&self) -> Result<Vec<String>>;
+
+    /// Returns where the archive stored under the given key lives on local disk, for stores which
+    /// keep archives there, so they can be read without copying them first.
+    fn local_path(&self, _key: &str) -> Option<PathBuf> {
+        None
+    }
+}
+
+/// Returns the store for the given backend, configured from the depot's config.
+pub fn new_store(config: &Config, backend: StorageBackend) -> Result<Box<ArchiveStore>> {
+    match backend {
+        StorageBackend::Local => Ok(Box::new(LocalStore::new(config.path.join("pkgs")))),
+        StorageBackend::S3 => Ok(Box::new(S3Store::new(&config.storage.s3)?)),
+    }
+}
+
+/// A stored archive made available on local disk. Archives fetched from a remote store are
+/// removed again when this is dropped.
+pub struct LocalArchive {
+    pub archive: PackageArchive,
+    _temp: Option<NamedTempFile>,
+}
+
+/// Makes the archive stored under the given key available on local disk, downloading it into
+/// `staging_path` if the store keeps it elsewhere.
+pub fn fetch(store: &ArchiveStore, key: &str, staging_path: &Path) -> Result<LocalArchive> {
+    if let Some(path) = store.local_path(key) {
+        if !path.is_file() {
+            return Err(Error::ArchiveNotFound(key.to_string()));
+        }
+        return Ok(LocalArchive {
+            archive: PackageArchive::new(path),
+            _temp: None,
+        });
+    }
+    fs::create_dir_all(staging_path)?;
+    let mut temp = NamedTempFile::new_in(staging_path)?;
+    io::copy(&mut store.get(key)?, &mut temp)?;
+    Ok(LocalArchive {
+        archive: PackageArchive::new(temp.path().to_path_buf()),
+        _temp: Some(temp),
+    })
+}
+
+/// Moves a file, copying it when it can't simply be renamed, as when the store lives on another
+/// filesystem than the staging directory.
+fn move_file(src: &Path, dst: &Path) -> Result<()> {
+    if fs::rename(src, dst).is_ok() {
+        return Ok(());
+    }
+    io::copy(&mut File::open(src)?, &mut File::create(dst)?)?;
+    fs::remove_file(src)?;
+    Ok(())
+}
@@ -0,0 +1,1043 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mirroring packages from an upstream depot.
+//!
+//! An on-prem or air-gapped depot can keep a copy of some origins of another depot, configured in
+//! its `[upstream]` section:
+//!
+//! ```toml
+//! [upstream]
+//! url = "https://bldr.habitat.sh"
+//! origins = ["core"]
+//! channels = ["stable"]
+//! sync_interval = 3600
+//! pull_through = true
+//! ```
+//!
+//! Every sync imports the origins' public keys, then every release in each channel, along with
+//! its membership of that channel. Releases this depot already has are skipped, so syncs are
+//! incremental and one which is interrupted picks up where it left off. Every artifact has its
+//! signatures verified against the mirrored keys before it is added. The origins themselves must
+//! already exist on this depot.
+//!
+//! Channel membership is copied exactly as upstream has it: releases are promoted into a channel
+//! when upstream lists them there, and demoted from it once upstream no longer does. Nothing is
+//! demoted from `unstable`, as on any other depot. This depot's channel policies don't apply to
+//! the mirror: it acts on promotions and demotions upstream has already allowed, and has no user
+//! whose role a policy could be checked against.
+//!
+//! With `pull_through` on, downloads of releases from mirrored origins which this depot doesn't
+//! have yet are fetched from upstream on the spot, in the build for the platform of the client
+//! asking for them. Lookups of the latest release of a mirrored package are answered by upstream
+//! too, fetching that release if need be, so they don't lag behind until the next sync.
+
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+use depot_client::{Client, DisplayProgress};
+use hab_core::crypto::artifact;
+use hab_core::crypto::keys::parse_name_with_rev;
+use hab_core::package::{FromArchive, Identifiable, PackageTarget};
+use hab_net::{ErrCode, NetOk, NetResult};
+use hab_net::conn::RouteClient;
+use http_gateway::conn::RouteBroker;
+use http_gateway::http::helpers::all_visibilities;
+use protobuf;
+use protocol::Routable;
+use protocol::originsrv::*;
+use uuid::Uuid;
+
+use config::Config;
+use error::{Error, Result};
+use super::DepotUtil;
+
+const PRODUCT: &'static str = "builder-depot";
+const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+/// How long to wait after starting before the first sync, giving the route broker time to start.
+const STARTUP_DELAY_SECS: u64 = 10;
+/// How many of a channel's releases to ask this depot's services for at once.
+const CHANNEL_PAGE_SIZE: u64 = 50;
+
+/// Syncs from the upstream depot in the background every `sync_interval` seconds, if one is
+/// configured.
+pub fn start(config: Config) {
+    if !config.upstream.enabled() || config.upstream.sync_interval == 0 {
+        return;
+    }
+    thread::Builder::new()
+        .name("upstream-sync".to_string())
+        .spawn(move || {
+            let depot = match DepotUtil::new(config) {
+                Ok(depot) => depot,
+                Err(e) => {
+                    error!("Unable to start upstream sync, err={}", e);
+                    return;
+                }
+            };
+            thread::sleep(Duration::from_secs(STARTUP_DELAY_SECS));
+            loop {
+                match sync(&depot) {
+                    Ok(added) => info!("Upstream sync added {} packages", added),
+                    Err(e) => error!("Upstream sync failed, err={}", e),
+                }
+                thread::sleep(Duration::from_secs(depot.config.upstream.sync_interval));
+            }
+        })
+        .unwrap();
+}
+
+/// Mirrors every configured origin and channel from upstream once, returning how many packages
+/// were added.
+pub fn sync(depot: &DepotUtil) -> Result<usize> {
+    Mirror::new(depot, connect()?)?.sync()
+}
+
+/// Fetches a release this depot doesn't have from upstream, in the build for the given target.
+/// Returns `false` without fetching anything unless pull-through is on and the release belongs to
+/// a mirrored origin.
+pub fn pull(depot: &DepotUtil, ident: &OriginPackageIdent, target: &PackageTarget) -> Result<bool> {
+    if !pullable(depot, ident) {
+        return Ok(false);
+    }
+    Mirror::new(depot, connect()?)?.pull(ident, target)
+}
+
+/// Asks upstream which release a possibly partial ident stands for, in the given channel if any,
+/// and fetches it in the build for the given target unless this depot has it already. Returns
+/// `None` without asking upstream unless pull-through is on and the release belongs to a mirrored
+/// origin.
+pub fn pull_latest(
+    depot: &DepotUtil,
+    ident: &OriginPackageIdent,
+    channel: Option<&str>,
+    target: &PackageTarget,
+) -> Result<Option<OriginPackageIdent>> {
+    if !pulls_through(depot, ident) {
+        return Ok(None);
+    }
+    let package = Mirror::new(depot, connect()?)?.pull_latest(
+        ident,
+        channel,
+        target,
+    )?;
+    Ok(Some(package.get_ident().clone()))
+}
+
+fn pullable(depot: &DepotUtil, ident: &OriginPackageIdent) -> bool {
+    ident.fully_qualified() && pulls_through(depot, ident)
+}
+
+fn pulls_through(depot: &DepotUtil, ident: &OriginPackageIdent) -> bool {
+    let cfg = &depot.config.upstream;
+    cfg.enabled() && cfg.pull_through && cfg.origins.iter().any(|o| o == ident.get_origin())
+}
+
+fn connect() -> Result<RouteClient> {
+    RouteBroker::connect().map_err(|e| {
+        Error::Mirror(format!("unable to connect to the route broker, {}", e))
+    })
+}
+
+/// Carries the mirror's requests to this depot's services. The route broker does in production,
+/// and the tests stand in for it.
+trait Services {
+    fn route<M, T>(&mut self, msg: &M) -> NetResult<T>
+    where
+        M: Routable,
+        T: protobuf::MessageStatic;
+}
+
+impl Services for RouteClient {
+    fn route<M, T>(&mut self, msg: &M) -> NetResult<T>
+    where
+        M: Routable,
+        T: protobuf::MessageStatic,
+    {
+        RouteClient::route(self, msg)
+    }
+}
+
+struct Mirror<'a, S: Services> {
+    depot: &'a DepotUtil,
+    client: Client,
+    conn: S,
+}
+
+impl<'a, S: Services> Mirror<'a, S> {
+    fn new(depot: &'a DepotUtil, conn: S) -> Result<Self> {
+        let client = Client::new(&depot.config.upstream.url, PRODUCT, VERSION, None)?;
+        Ok(Mirror {
+            depot: depot,
+            client: client,
+            conn: conn,
+        })
+    }
+
+    fn sync(&mut self) -> Result<usize> {
+        let depot = self.depot;
+        let mut added = 0;
+        for origin in depot.config.upstream.origins.iter() {
+            self.sync_keys(origin)?;
+            for channel in depot.config.upstream.channels.iter() {
+                added += self.sync_channel(origin, channel)?;
+            }
+        }
+        Ok(added)
+    }
+
+    fn pull(&mut self, ident: &OriginPackageIdent, target: &PackageTarget) -> Result<bool> {
+        if !pullable(self.depot, ident) {
+            return Ok(false);
+        }
+        self.ingest(ident, target)?;
+        Ok(true)
+    }
+
+    fn pull_latest(
+        &mut self,
+        ident: &OriginPackageIdent,
+        channel: Option<&str>,
+        target: &PackageTarget,
+    ) -> Result<OriginPackage> {
+        let latest: OriginPackageIdent = self.client
+            .show_package_for_target(ident, channel, target, self.token())?
+            .get_ident()
+            .clone();
+        let package = match self.local_package(&latest)? {
+            Some(package) => package,
+            None => self.ingest(&latest, target)?,
+        };
+        if let Some(channel) = channel {
+            self.add_to_channel(&package, channel)?;
+        }
+        Ok(package)
+    }
+
+    fn token(&self) -> Option<&str> {
+        let token = &self.depot.config.upstream.auth_token;
+        if token.is_empty() { None } else { Some(token) }
+    }
+
+    // The key cache holding upstream's public keys, which artifacts are verified against.
+    fn keys_path(&self) -> PathBuf {
+        self.depot.staging_path().join("upstream-keys")
+    }
+
+    fn sync_keys(&mut self, origin: &str) -> Result<()> {
+        for key in self.client.show_origin_keys(origin)? {
+            self.import_key(origin, key.get_revision())?;
+        }
+        Ok(())
+    }
+
+    // Fetch a public key from upstream unless we have it already, and add it to this depot.
+    fn import_key(&mut self, origin: &str, revision: &str) -> Result<()> {
+        let path = self.keys_path().join(format!("{}-{}.pub", origin, revision));
+        if !path.is_file() {
+            fs::create_dir_all(self.keys_path())?;
+            self.client.fetch_origin_key(
+                origin,
+                revision,
+                &self.keys_path(),
+                None::<NoProgress>,
+            )?;
+        }
+
+        let mut get = OriginPublicKeyGet::new();
+        get.set_origin(origin.to_string());
+        get.set_revision(revision.to_string());
+        match self.conn.route::<OriginPublicKeyGet, OriginPublicKey>(&get) {
+            Ok(_) => return Ok(()),
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => (),
+            Err(err) => return Err(Error::NetError(err)),
+        }
+        let mut body = Vec::new();
+        File::open(&path)?.read_to_end(&mut body)?;
+        let mut create = OriginPublicKeyCreate::new();
+        create.set_origin_id(self.origin(origin)?.get_id());
+        create.set_name(origin.to_string());
+        create.set_revision(revision.to_string());
+        create.set_body(body);
+        create.set_owner_id(0);
+        self.conn.route::<OriginPublicKeyCreate, OriginPublicKey>(
+            &create,
+        )?;
+        debug!("Imported public key {}-{} from upstream", origin, revision);
+        Ok(())
+    }
+
+    // Mirror every release in a channel, and demote the ones upstream no longer has in it,
+    // returning how many were added.
+    fn sync_channel(&mut self, origin: &str, channel: &str) -> Result<usize> {
+        let mut added = 0;
+        let mut range = 0;
+        let mut listed = HashSet::new();
+        loop {
+            let (idents, next) = self.client.list_channel_packages(
+                origin,
+                channel,
+                range,
+                self.token(),
+            )?;
+            for ident in idents {
+                let ident: OriginPackageIdent = ident.into();
+                listed.insert(ident.to_string());
+                let package = match self.local_package(&ident)? {
+                    Some(package) => package,
+                    None => {
+                        match self.ingest_listed(&ident) {
+                            Ok(package) => {
+                                added += 1;
+                                package
+                            }
+                            // One bad release shouldn't hold up the rest.
+                            Err(e) => {
+                                warn!("Unable to mirror {}, err={}", ident, e);
+                                continue;
+                            }
+                        }
+                    }
+                };
+                self.add_to_channel(&package, channel)?;
+            }
+            match next {
+                Some(next) => range = next,
+                None => break,
+            }
+        }
+        // Only a complete listing tells which releases upstream has demoted.
+        self.demote_unlisted(origin, channel, &listed)?;
+        Ok(added)
+    }
+
+    fn demote_unlisted(
+        &mut self,
+        origin: &str,
+        channel: &str,
+        listed: &HashSet<String>,
+    ) -> Result<()> {
+        if channel == "unstable" {
+            return Ok(());
+        }
+        for ident in self.channel_packages(origin, channel)? {
+            if listed.contains(&ident.to_string()) {
+                continue;
+            }
+            let package = match self.package_metadata(&ident)? {
+                Some(package) => package,
+                None => continue,
+            };
+            let origin_channel = self.channel(origin, channel)?;
+            let mut demote = OriginPackageDemote::new();
+            demote.set_channel_id(origin_channel.get_id());
+            demote.set_package_id(package.get_id());
+            demote.set_ident(ident.clone());
+            self.conn.route::<OriginPackageDemote, NetOk>(&demote)?;
+            info!("Demoted {} from {}, as upstream has", ident, channel);
+        }
+        Ok(())
+    }
+
+    // Return every release of an origin which is in one of this depot's channels.
+    fn channel_packages(&mut self, origin: &str, channel: &str) -> Result<Vec<OriginPackageIdent>> {
+        let mut ident = OriginPackageIdent::new();
+        ident.set_origin(origin.to_string());
+        let mut idents = Vec::new();
+        let mut start = 0;
+        loop {
+            let mut list = OriginChannelPackageListRequest::new();
+            list.set_name(channel.to_string());
+            list.set_ident(ident.clone());
+            list.set_visibilities(all_visibilities());
+            list.set_start(start);
+            list.set_stop(start + CHANNEL_PAGE_SIZE - 1);
+            let page = match self.conn
+                .route::<OriginChannelPackageListRequest, OriginPackageListResponse>(&list) {
+                Ok(page) => page,
+                Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => break,
+                Err(err) => return Err(Error::NetError(err)),
+            };
+            let count = page.get_idents().len() as u64;
+            idents.extend(page.get_idents().iter().cloned());
+            start += count;
+            if count < CHANNEL_PAGE_SIZE || start >= page.get_count() {
+                break;
+            }
+        }
+        Ok(idents)
+    }
+
+    // Return this depot's metadata for a release, if it has both that and the archive.
+    fn local_package(&mut self, ident: &OriginPackageIdent) -> Result<Option<OriginPackage>> {
+        let package = match self.package_metadata(ident)? {
+            Some(package) => package,
+            None => return Ok(None),
+        };
+        let target = PackageTarget::from_str(package.get_target())?;
+        if self.depot.archive_exists(ident, &target)? {
+            Ok(Some(package))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn package_metadata(&mut self, ident: &OriginPackageIdent) -> Result<Option<OriginPackage>> {
+        let mut get = OriginPackageGet::new();
+        get.set_ident(ident.clone());
+        get.set_visibilities(all_visibilities());
+        match self.conn.route::<OriginPackageGet, OriginPackage>(&get) {
+            Ok(package) => Ok(Some(package)),
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => Ok(None),
+            Err(err) => Err(Error::NetError(err)),
+        }
+    }
+
+    // Mirror a release which was found in a channel, in the build for the target upstream has.
+    fn ingest_listed(&mut self, ident: &OriginPackageIdent) -> Result<OriginPackage> {
+        let package = self.client.show_package(ident, None, self.token())?;
+        let target = PackageTarget::from_str(package.get_target())?;
+        self.ingest(ident, &target)
+    }
+
+    // Download the build of a release for the given target from upstream, verify it, and add it
+    // to this depot.
+    fn ingest(
+        &mut self,
+        ident: &OriginPackageIdent,
+        target: &PackageTarget,
+    ) -> Result<OriginPackage> {
+        if !self.depot.config.targets.contains(target) {
+            return Err(Error::Mirror(
+                format!("{} is built for unsupported target {}", ident, target),
+            ));
+        }
+        // Every download gets a directory of its own, which goes away whether or not the release
+        // makes it into the store.
+        let staging_path = self.depot.staging_path().join("upstream").join(
+            Uuid::new_v4().to_string(),
+        );
+        fs::create_dir_all(&staging_path)?;
+        let result = self.ingest_to(ident, target, &staging_path);
+        if let Err(e) = fs::remove_dir_all(&staging_path) {
+            warn!(
+                "Unable to remove upstream download {}, err={}",
+                staging_path.display(),
+                e
+            );
+        }
+        result
+    }
+
+    fn ingest_to(
+        &mut self,
+        ident: &OriginPackageIdent,
+        target: &PackageTarget,
+        staging_path: &Path,
+    ) -> Result<OriginPackage> {
+        let mut archive = self.client.fetch_package_for_target(
+            ident,
+            target,
+            self.token(),
+            staging_path,
+            None::<NoProgress>,
+        )?;
+
+        // Every signer's key has to be known before the signatures can be checked.
+        for signer in artifact::artifact_signers(&archive.path)? {
+            let (name, revision) = parse_name_with_rev(&signer)?;
+            self.import_key(&name, &revision)?;
+        }
+        artifact::verify(&archive.path, &self.keys_path())?;
+
+        // The archive is stored under the ident asked for, while its metadata is read from the
+        // archive, so the two have to agree.
+        let archive_ident = archive.ident()?;
+        if archive_ident.to_string() != ident.to_string() {
+            return Err(Error::Mirror(format!(
+                "upstream sent {} when asked for {}",
+                archive_ident,
+                ident
+            )));
+        }
+        let archive_target = archive.target()?;
+        if archive_target != *target {
+            return Err(Error::Mirror(format!(
+                "upstream sent the {} build of {} when asked for {}",
+                archive_target,
+                ident,
+                target
+            )));
+        }
+        let origin = self.origin(ident.get_origin())?;
+        let mut package = OriginPackageCreate::from_archive(&mut archive)?;
+        package.set_origin_id(origin.get_id());
+        package.set_owner_id(0);
+        package.set_visibility(origin.get_default_package_visibility());
+
+        self.depot.put_archive(ident, target, &archive)?;
+        info!("Mirrored {} from upstream", ident);
+        match self.package_metadata(ident)? {
+            Some(existing) => Ok(existing),
+            None => Ok(self.conn.route::<OriginPackageCreate, OriginPackage>(
+                &package,
+            )?),
+        }
+    }
+
+    // Channel policies are deliberately not checked here, see the module documentation.
+    fn add_to_channel(&mut self, package: &OriginPackage, channel: &str) -> Result<()> {
+        let mut list = OriginPackageChannelListRequest::new();
+        list.set_ident(package.get_ident().clone());
+        list.set_visibilities(all_visibilities());
+        let channels = self.conn
+            .route::<OriginPackageChannelListRequest, OriginPackageChannelListResponse>(&list)?;
+        if channels.get_channels().iter().any(|c| c.get_name() == channel) {
+            return Ok(());
+        }
+        let origin_channel = self.channel(package.get_ident().get_origin(), channel)?;
+        let mut promote = OriginPackagePromote::new();
+        promote.set_channel_id(origin_channel.get_id());
+        promote.set_package_id(package.get_id());
+        promote.set_ident(package.get_ident().clone());
+        self.conn.route::<OriginPackagePromote, NetOk>(&promote)?;
+        Ok(())
+    }
+
+    // Return this depot's channel of an origin, creating it if it doesn't exist yet.
+    fn channel(&mut self, origin: &str, name: &str) -> Result<OriginChannel> {
+        let mut get = OriginChannelGet::new();
+        get.set_origin_name(origin.to_string());
+        get.set_name(name.to_string());
+        match self.conn.route::<OriginChannelGet, OriginChannel>(&get) {
+            Ok(channel) => return Ok(channel),
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => (),
+            Err(err) => return Err(Error::NetError(err)),
+        }
+        let mut create = OriginChannelCreate::new();
+        create.set_origin_id(self.origin(origin)?.get_id());
+        create.set_origin_name(origin.to_string());
+        create.set_name(name.to_string());
+        create.set_owner_id(0);
+        Ok(self.conn.route::<OriginChannelCreate, OriginChannel>(
+            &create,
+        )?)
+    }
+
+    fn origin(&mut self, name: &str) -> Result<Origin> {
+        let mut get = OriginGet::new();
+        get.set_name(name.to_string());
+        match self.conn.route::<OriginGet, Origin>(&get) {
+            Ok(origin) => Ok(origin),
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => {
+                Err(Error::Mirror(format!(
+                    "origin {} must be created on this depot before it can be mirrored",
+                    name
+                )))
+            }
+            Err(err) => Err(Error::NetError(err)),
+        }
+    }
+}
+
+/// Downloads happen in the background, so there's no one to show progress to.
+struct NoProgress;
+
+impl Write for NoProgress {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl DisplayProgress for NoProgress {
+    fn size(&mut self, _size: u64) {}
+    fn finish(&mut self) {}
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use hab_net::NetError;
+    use hyper::header::UserAgent;
+    use hyper::server::{Handler, Listening, Request, Response, Server};
+    use hyper::status::StatusCode;
+    use hyper::uri::RequestUri;
+    use protobuf::Message;
+    use tempdir::TempDir;
+
+    use super::*;
+
+    const IDENT: &'static str = "happyhumans/possums/8.1.4/20160427165340";
+    const HART: &'static str = "happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart";
+    const KEY: &'static str = "happyhumans-20160424223347.pub";
+    const PACKAGE: &'static str = r#"{"ident": {"origin": "happyhumans", "name": "possums",
+        "version": "8.1.4", "release": "20160427165340"},
+        "checksum": "", "manifest": "", "deps": [], "tdeps": [],
+        "exposes": [], "config": "", "target": "x86_64-linux"}"#;
+
+    fn fixtures() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../core/tests/fixtures")
+    }
+
+    fn read_fixture(name: &str) -> Vec<u8> {
+        let mut body = Vec::new();
+        File::open(fixtures().join(name))
+            .unwrap()
+            .read_to_end(&mut body)
+            .unwrap();
+        body
+    }
+
+    /// An upstream depot with one release, of the happyhumans origin, in its stable channel. It
+    /// only has the Linux build.
+    struct Upstream {
+        hart: Vec<u8>,
+        downloads: Arc<AtomicUsize>,
+    }
+
+    impl Handler for Upstream {
+        fn handle(&self, req: Request, mut res: Response) {
+            let path = match req.uri {
+                RequestUri::AbsolutePath(ref path) => path.split('?').next().unwrap().to_string(),
+                _ => String::new(),
+            };
+            let linux = req.headers.get::<UserAgent>().map_or(false, |ua| {
+                ua.contains("(x86_64-linux;")
+            });
+            let (status, file_name, body) = match path.as_str() {
+                "/v1/depot/origins/happyhumans/keys" => {
+                    (
+                        StatusCode::Ok,
+                        None,
+                        r#"[{"origin": "happyhumans", "revision": "20160424223347",
+                        "location": "/origins/happyhumans/keys/20160424223347"}]"#
+                            .as_bytes()
+                            .to_vec(),
+                    )
+                }
+                "/v1/depot/origins/happyhumans/keys/20160424223347" => {
+                    (StatusCode::Ok, Some(KEY), read_fixture(KEY))
+                }
+                "/v1/depot/channels/happyhumans/stable/pkgs" => {
+                    (
+                        StatusCode::Ok,
+                        None,
+                        r#"{"range_start": 0, "range_end": 0, "total_count": 1, "data": [
+                        {"origin": "happyhumans", "name": "possums", "version": "8.1.4",
+                         "release": "20160427165340"}]}"#
+                            .as_bytes()
+                            .to_vec(),
+                    )
+                }
+                "/v1/depot/pkgs/happyhumans/possums/8.1.4/20160427165340" => {
+                    (StatusCode::Ok, None, PACKAGE.as_bytes().to_vec())
+                }
+                "/v1/depot/channels/happyhumans/stable/pkgs/possums/latest" if linux => {
+                    (StatusCode::Ok, None, PACKAGE.as_bytes().to_vec())
+                }
+                "/v1/depot/pkgs/happyhumans/possums/8.1.4/20160427165340/download" if linux => {
+                    self.downloads.fetch_add(1, Ordering::SeqCst);
+                    (StatusCode::Ok, Some(HART), self.hart.clone())
+                }
+                // A misbehaving upstream, sending another release than the one asked for.
+                "/v1/depot/pkgs/happyhumans/possums/8.1.4/20170101000000/download" if linux => {
+                    self.downloads.fetch_add(1, Ordering::SeqCst);
+                    (StatusCode::Ok, Some(HART), self.hart.clone())
+                }
+                _ => (StatusCode::NotFound, None, vec![]),
+            };
+            *res.status_mut() = status;
+            if let Some(file_name) = file_name {
+                res.headers_mut().set_raw(
+                    "X-Filename",
+                    vec![file_name.as_bytes().to_vec()],
+                );
+            }
+            res.send(&body).unwrap();
+        }
+    }
+
+    /// This depot's services, holding just what the mirror asks them for.
+    #[derive(Default)]
+    struct FakeServices {
+        keys: Vec<String>,
+        packages: Vec<OriginPackage>,
+        channels: Vec<OriginChannel>,
+        // Pairs of channel and package ids
+        members: Vec<(u64, u64)>,
+        policies: Vec<OriginChannelPolicy>,
+    }
+
+    fn not_found() -> NetError {
+        NetError::new(ErrCode::ENTITY_NOT_FOUND, "upstream:test")
+    }
+
+    fn parse<M: protobuf::MessageStatic>(bytes: &[u8]) -> M {
+        protobuf::parse_from_bytes(bytes).unwrap()
+    }
+
+    impl Services for FakeServices {
+        fn route<M, T>(&mut self, msg: &M) -> NetResult<T>
+        where
+            M: Routable,
+            T: protobuf::MessageStatic,
+        {
+            let request = msg.write_to_bytes().unwrap();
+            let reply = match M::descriptor_static(None).name() {
+                "OriginGet" => {
+                    let get: OriginGet = parse(&request);
+                    if get.get_name() != "happyhumans" {
+                        return Err(not_found());
+                    }
+                    let mut origin = Origin::new();
+                    origin.set_id(1);
+                    origin.set_name(get.get_name().to_string());
+                    origin.set_default_package_visibility(OriginPackageVisibility::Public);
+                    origin.write_to_bytes()
+                }
+                "OriginPublicKeyGet" => {
+                    let get: OriginPublicKeyGet = parse(&request);
+                    let name = format!("{}-{}", get.get_origin(), get.get_revision());
+                    if !self.keys.contains(&name) {
+                        return Err(not_found());
+                    }
+                    OriginPublicKey::new().write_to_bytes()
+                }
+                "OriginPublicKeyCreate" => {
+                    let create: OriginPublicKeyCreate = parse(&request);
+                    self.keys.push(
+                        format!("{}-{}", create.get_name(), create.get_revision()),
+                    );
+                    OriginPublicKey::new().write_to_bytes()
+                }
+                "OriginPackageGet" => {
+                    let get: OriginPackageGet = parse(&request);
+                    match self.packages.iter().find(|p| {
+                        p.get_ident().to_string() == get.get_ident().to_string()
+                    }) {
+                        Some(package) => package.write_to_bytes(),
+                        None => return Err(not_found()),
+                    }
+                }
+                "OriginPackageCreate" => {
+                    let create: OriginPackageCreate = parse(&request);
+                    let mut package = OriginPackage::new();
+                    package.set_id(self.packages.len() as u64 + 1);
+                    package.set_origin_id(create.get_origin_id());
+                    package.set_ident(create.get_ident().clone());
+                    package.set_target(create.get_target().to_string());
+                    package.set_is_service(create.get_is_service());
+                    self.packages.push(package.clone());
+                    package.write_to_bytes()
+                }
+                "OriginPackageChannelListRequest" => {
+                    let list: OriginPackageChannelListRequest = parse(&request);
+                    let id = self.packages
+                        .iter()
+                        .find(|p| p.get_ident().to_string() == list.get_ident().to_string())
+                        .map(|p| p.get_id());
+                    let mut res = OriginPackageChannelListResponse::new();
+                    for channel in self.channels.iter() {
+                        if self.members.iter().any(|&(c, p)| {
+                            c == channel.get_id() && Some(p) == id
+                        })
+                        {
+                            res.mut_channels().push(channel.clone());
+                        }
+                    }
+                    res.write_to_bytes()
+                }
+                "OriginChannelPackageListRequest" => {
+                    let list: OriginChannelPackageListRequest = parse(&request);
+                    let channel = match self.channels.iter().find(
+                        |c| c.get_name() == list.get_name(),
+                    ) {
+                        Some(channel) => channel.get_id(),
+                        None => return Err(not_found()),
+                    };
+                    let idents: Vec<OriginPackageIdent> = self.members
+                        .iter()
+                        .filter(|&&(c, _)| c == channel)
+                        .filter_map(|&(_, p)| self.packages.iter().find(|pkg| pkg.get_id() == p))
+                        .map(|pkg| pkg.get_ident().clone())
+                        .collect();
+                    let mut res = OriginPackageListResponse::new();
+                    res.set_count(idents.len() as u64);
+                    res.set_idents(protobuf::RepeatedField::from_vec(
+                        idents
+                            .into_iter()
+                            .skip(list.get_start() as usize)
+                            .take((list.get_stop() - list.get_start() + 1) as usize)
+                            .collect(),
+                    ));
+                    res.write_to_bytes()
+                }
+                "OriginPackageDemote" => {
+                    let demote: OriginPackageDemote = parse(&request);
+                    self.members.retain(|&(c, p)| {
+                        (c, p) != (demote.get_channel_id(), demote.get_package_id())
+                    });
+                    NetOk::new().write_to_bytes()
+                }
+                "OriginPackagePromote" => {
+                    let promote: OriginPackagePromote = parse(&request);
+                    self.members.push(
+                        (promote.get_channel_id(), promote.get_package_id()),
+                    );
+                    NetOk::new().write_to_bytes()
+                }
+                "OriginChannelGet" => {
+                    let get: OriginChannelGet = parse(&request);
+                    match self.channels.iter().find(|c| c.get_name() == get.get_name()) {
+                        Some(channel) => channel.write_to_bytes(),
+                        None => return Err(not_found()),
+                    }
+                }
+                "OriginChannelCreate" => {
+                    let create: OriginChannelCreate = parse(&request);
+                    let mut channel = OriginChannel::new();
+                    channel.set_id(self.channels.len() as u64 + 1);
+                    channel.set_origin_id(create.get_origin_id());
+                    channel.set_name(create.get_name().to_string());
+                    self.channels.push(channel.clone());
+                    channel.write_to_bytes()
+                }
+                "OriginChannelPolicyGet" => {
+                    let get: OriginChannelPolicyGet = parse(&request);
+                    match self.policies.iter().find(|p| {
+                        p.get_channel_name() == get.get_channel_name()
+                    }) {
+                        Some(policy) => policy.write_to_bytes(),
+                        None => OriginChannelPolicy::new().write_to_bytes(),
+                    }
+                }
+                name => panic!("Unexpected {} sent to this depot's services", name),
+            };
+            Ok(parse(&reply.unwrap()))
+        }
+    }
+
+    fn depot(upstream: &Listening, path: &Path) -> DepotUtil {
+        let mut config = Config::default();
+        config.path = path.to_path_buf();
+        config.upstream.url = format!("http://{}", upstream.socket);
+        config.upstream.origins = vec!["happyhumans".to_string()];
+        config.upstream.pull_through = true;
+        DepotUtil::new(config).unwrap()
+    }
+
+    fn start_upstream(hart: Vec<u8>) -> (Listening, Arc<AtomicUsize>) {
+        let downloads = Arc::new(AtomicUsize::new(0));
+        let upstream = Upstream {
+            hart: hart,
+            downloads: downloads.clone(),
+        };
+        let listening = Server::http("127.0.0.1:0").unwrap().handle(upstream).unwrap();
+        (listening, downloads)
+    }
+
+    fn ident() -> OriginPackageIdent {
+        OriginPackageIdent::from_str(IDENT).unwrap()
+    }
+
+    fn linux() -> PackageTarget {
+        PackageTarget::from_str("x86_64-linux").unwrap()
+    }
+
+    fn upstream_downloads(depot: &DepotUtil) -> usize {
+        fs::read_dir(depot.staging_path().join("upstream"))
+            .map(|entries| entries.count())
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn sync_skips_releases_it_already_has() {
+        let (mut listening, downloads) = start_upstream(read_fixture(HART));
+        let tmp = TempDir::new("upstream").unwrap();
+        let depot = depot(&listening, tmp.path());
+        let mut mirror = Mirror::new(&depot, FakeServices::default()).unwrap();
+
+        assert_eq!(mirror.sync().unwrap(), 1);
+        assert_eq!(mirror.conn.keys, vec!["happyhumans-20160424223347"]);
+        assert_eq!(mirror.conn.packages.len(), 1);
+        assert!(depot.archive_exists(&ident(), &linux()).unwrap());
+        assert!(depot.contents_path(&ident(), &linux()).is_file());
+
+        assert_eq!(mirror.sync().unwrap(), 0);
+        assert_eq!(downloads.load(Ordering::SeqCst), 1);
+        assert_eq!(mirror.conn.packages.len(), 1);
+        assert_eq!(upstream_downloads(&depot), 0);
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn sync_mirrors_channel_membership() {
+        let (mut listening, _) = start_upstream(read_fixture(HART));
+        let tmp = TempDir::new("upstream").unwrap();
+        let depot = depot(&listening, tmp.path());
+        let mut mirror = Mirror::new(&depot, FakeServices::default()).unwrap();
+
+        mirror.sync().unwrap();
+        mirror.sync().unwrap();
+        let channels = mirror.conn.channels.clone();
+        assert_eq!(channels.len(), 1);
+        assert_eq!(channels[0].get_name(), "stable");
+        let package_id = mirror.conn.packages[0].get_id();
+        assert_eq!(mirror.conn.members, vec![(channels[0].get_id(), package_id)]);
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn sync_demotes_releases_upstream_no_longer_lists() {
+        let (mut listening, _) = start_upstream(read_fixture(HART));
+        let tmp = TempDir::new("upstream").unwrap();
+        let depot = depot(&listening, tmp.path());
+        let mut services = FakeServices::default();
+        let mut stable = OriginChannel::new();
+        stable.set_id(1);
+        stable.set_name("stable".to_string());
+        services.channels.push(stable);
+        let mut demoted = OriginPackage::new();
+        demoted.set_id(1);
+        demoted.set_ident(
+            OriginPackageIdent::from_str("happyhumans/possums/8.1.3/20160427165339").unwrap(),
+        );
+        services.packages.push(demoted);
+        services.members.push((1, 1));
+        let mut mirror = Mirror::new(&depot, services).unwrap();
+
+        assert_eq!(mirror.sync().unwrap(), 1);
+        let package_id = mirror.conn.packages[1].get_id();
+        assert_eq!(mirror.conn.members, vec![(1, package_id)]);
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn sync_mirrors_into_protected_channels() {
+        let (mut listening, _) = start_upstream(read_fixture(HART));
+        let tmp = TempDir::new("upstream").unwrap();
+        let depot = depot(&listening, tmp.path());
+        let mut services = FakeServices::default();
+        let mut policy = OriginChannelPolicy::new();
+        policy.set_origin_name("happyhumans".to_string());
+        policy.set_channel_name("stable".to_string());
+        policy.set_promote_role(OriginMemberRole::Owner);
+        policy.set_prerequisite_channel("unstable".to_string());
+        policy.set_require_job_success(true);
+        services.policies.push(policy);
+        let mut mirror = Mirror::new(&depot, services).unwrap();
+
+        // None of the policy's rules hold for the mirrored release, and it's mirrored regardless
+        assert_eq!(mirror.sync().unwrap(), 1);
+        let channels = mirror.conn.channels.clone();
+        assert_eq!(channels.len(), 1);
+        assert_eq!(channels[0].get_name(), "stable");
+        let package_id = mirror.conn.packages[0].get_id();
+        assert_eq!(mirror.conn.members, vec![(channels[0].get_id(), package_id)]);
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn releases_with_bad_signatures_are_rejected() {
+        let mut hart = read_fixture(HART);
+        let last = hart.len() - 1;
+        hart[last] ^= 0xff;
+        let (mut listening, downloads) = start_upstream(hart);
+        let tmp = TempDir::new("upstream").unwrap();
+        let depot = depot(&listening, tmp.path());
+        let mut mirror = Mirror::new(&depot, FakeServices::default()).unwrap();
+
+        assert!(mirror.pull(&ident(), &linux()).is_err());
+        assert_eq!(downloads.load(Ordering::SeqCst), 1);
+        assert!(mirror.conn.packages.is_empty());
+        assert!(!depot.archive_exists(&ident(), &linux()).unwrap());
+        assert_eq!(upstream_downloads(&depot), 0);
+
+        assert_eq!(mirror.sync().unwrap(), 0);
+        assert!(mirror.conn.members.is_empty());
+        assert_eq!(upstream_downloads(&depot), 0);
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn releases_other_than_the_one_asked_for_are_rejected() {
+        let (mut listening, downloads) = start_upstream(read_fixture(HART));
+        let tmp = TempDir::new("upstream").unwrap();
+        let depot = depot(&listening, tmp.path());
+        let mut mirror = Mirror::new(&depot, FakeServices::default()).unwrap();
+        let other = OriginPackageIdent::from_str("happyhumans/possums/8.1.4/20170101000000")
+            .unwrap();
+
+        assert!(mirror.pull(&other, &linux()).is_err());
+        assert_eq!(downloads.load(Ordering::SeqCst), 1);
+        assert!(mirror.conn.packages.is_empty());
+        assert!(!depot.archive_exists(&other, &linux()).unwrap());
+        assert!(!depot.archive_exists(&ident(), &linux()).unwrap());
+        assert_eq!(upstream_downloads(&depot), 0);
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn pull_fetches_the_build_for_the_requested_target() {
+        let (mut listening, downloads) = start_upstream(read_fixture(HART));
+        let tmp = TempDir::new("upstream").unwrap();
+        let depot = depot(&listening, tmp.path());
+        let mut mirror = Mirror::new(&depot, FakeServices::default()).unwrap();
+        let windows = PackageTarget::from_str("x86_64-windows").unwrap();
+
+        // Upstream only has the Linux build, so asking for Windows must not fetch it.
+        assert!(mirror.pull(&ident(), &windows).is_err());
+        assert_eq!(downloads.load(Ordering::SeqCst), 0);
+        assert!(!depot.archive_exists(&ident(), &windows).unwrap());
+        assert_eq!(upstream_downloads(&depot), 0);
+
+        assert!(mirror.pull(&ident(), &linux()).unwrap());
+        assert!(depot.archive_exists(&ident(), &linux()).unwrap());
+        assert_eq!(mirror.conn.packages[0].get_target(), "x86_64-linux");
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn pull_latest_fetches_upstreams_latest_release_into_the_channel() {
+        let (mut listening, downloads) = start_upstream(read_fixture(HART));
+        let tmp = TempDir::new("upstream").unwrap();
+        let depot = depot(&listening, tmp.path());
+        let mut mirror = Mirror::new(&depot, FakeServices::default()).unwrap();
+        let partial = OriginPackageIdent::from_str("happyhumans/possums").unwrap();
+
+        let package = mirror.pull_latest(&partial, Some("stable"), &linux()).unwrap();
+        assert_eq!(package.get_ident().to_string(), IDENT);
+        assert!(depot.archive_exists(&ident(), &linux()).unwrap());
+        let channels = mirror.conn.channels.clone();
+        assert_eq!(channels[0].get_name(), "stable");
+        assert_eq!(mirror.conn.members, vec![(channels[0].get_id(), package.get_id())]);
+
+        // Once it's here, it isn't fetched again.
+        mirror.pull_latest(&partial, Some("stable"), &linux()).unwrap();
+        assert_eq!(downloads.load(Ordering::SeqCst), 1);
+        assert_eq!(mirror.conn.packages.len(), 1);
+        listening.close().unwrap();
+    }
+}
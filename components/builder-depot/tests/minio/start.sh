@@ -0,0 +1,31 @@
+#/bin/bash
+#
+# Starts a MinIO server on 127.0.0.1:9000 for the object store tests, with the credentials and
+# bucket they expect. Like the data store tests' postgres, it is brought down when the test run
+# that started us goes away.
+
+if [ ! -f /bin/hab ]; then
+  useradd -r -U hab
+  curl https://raw.githubusercontent.com/habitat-sh/habitat/master/components/hab/install.sh | bash
+fi
+
+hab pkg install core/minio
+
+# MinIO serves every top level directory of its data directory as a bucket
+rm -rf /tmp/builder-depot-minio
+mkdir -p /tmp/builder-depot-minio/builder-depot-test
+MINIO_ACCESS_KEY=depot-test MINIO_SECRET_KEY=depot-test-secret \
+  hab pkg exec core/minio minio server --address 127.0.0.1:9000 /tmp/builder-depot-minio &
+minio_pid=$!
+
+sudo_ppid=$(ps -p $$ -o 'ppid=')
+original_gpid=$(ps -p $sudo_ppid -o 'ppid=')
+while true; do
+  current_gpid=$(ps -p $sudo_ppid -o 'ppid=')
+  if [ "$original_gpid" != "$current_gpid" ]; then
+    echo "Stopping core/minio"
+    kill $minio_pid
+    exit 0
+  fi
+  sleep 1
+done
@@ -0,0 +1,144 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::{Read, Write};
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+
+use depot::config::S3Cfg;
+use depot::error::Error;
+use depot::storage::{ArchiveStore, S3Store};
+use tempdir::TempDir;
+
+static TEST_COUNT: AtomicUsize = ATOMIC_USIZE_INIT;
+
+mod minio {
+    use std::net::TcpStream;
+    use std::path::PathBuf;
+    use std::process::{Child, Command, Stdio};
+    use std::sync::{Once, ONCE_INIT};
+    use std::thread;
+    use std::time::Duration;
+
+    pub const ENDPOINT: &'static str = "http://127.0.0.1:9000";
+    pub const BUCKET: &'static str = "builder-depot-test";
+    pub const ACCESS_KEY_ID: &'static str = "depot-test";
+    pub const SECRET_ACCESS_KEY: &'static str = "depot-test-secret";
+
+    struct Minio {
+        inner: Child,
+    }
+
+    static MINIO: Once = ONCE_INIT;
+
+    /// Starts the object store the first time it's called, and waits for it to accept
+    /// connections.
+    pub fn start() {
+        MINIO.call_once(|| {
+            thread::spawn(move || {
+                let mut minio = Minio::new();
+                let _ = minio.inner.wait();
+            });
+        });
+        for _ in 0..120 {
+            if TcpStream::connect("127.0.0.1:9000").is_ok() {
+                return;
+            }
+            thread::sleep(Duration::from_millis(500));
+        }
+        panic!("Timed out waiting for core/minio to start");
+    }
+
+    impl Minio {
+        fn new() -> Minio {
+            let start_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("minio")
+                .join("start.sh");
+            let child = Command::new("sudo")
+                .arg("-E")
+                .arg(start_path)
+                .stdin(Stdio::inherit())
+                .stdout(Stdio::inherit())
+                .stderr(Stdio::inherit())
+                .current_dir("/tmp")
+                .spawn()
+                .expect("Failed to launch core/minio");
+            Minio { inner: child }
+        }
+    }
+}
+
+/// Returns a store on the test object store. Every store gets a prefix of its own, so tests
+/// don't see each other's archives.
+fn s3_store() -> S3Store {
+    minio::start();
+    let mut config = S3Cfg::default();
+    config.endpoint = minio::ENDPOINT.to_string();
+    config.bucket = minio::BUCKET.to_string();
+    config.prefix = format!("test-{}", TEST_COUNT.fetch_add(1, Ordering::SeqCst));
+    config.access_key_id = minio::ACCESS_KEY_ID.to_string();
+    config.secret_access_key = minio::SECRET_ACCESS_KEY.to_string();
+    S3Store::new(&config).expect("Failed to create S3 store")
+}
+
+#[test]
+fn s3_store_puts_gets_and_deletes_archives() {
+    let store = s3_store();
+    let tmp = TempDir::new("s3-store").expect("Failed to create temp dir");
+    let key = "a4/3f/core-redis-3.2.4-20170514150022-x86_64-linux.hart";
+    let src = tmp.path().join("upload.hart");
+    File::create(&src)
+        .and_then(|mut f| f.write_all(b"not really an archive"))
+        .expect("Failed to write archive");
+
+    assert!(!store.exists(key).expect("Failed to check for archive"));
+    store.put(key, &src).expect("Failed to put archive");
+    assert!(!src.exists(), "put should consume the source file");
+    assert!(store.exists(key).expect("Failed to check for archive"));
+    assert_eq!(store.keys().expect("Failed to list archives"), vec![key]);
+
+    let mut contents = String::new();
+    store
+        .get(key)
+        .expect("Failed to get archive")
+        .read_to_string(&mut contents)
+        .expect("Failed to read archive");
+    assert_eq!(contents, "not really an archive");
+
+    let mut rest = String::new();
+    store
+        .get_from(key, 11)
+        .expect("Failed to get the rest of the archive")
+        .read_to_string(&mut rest)
+        .expect("Failed to read the rest of the archive");
+    assert_eq!(rest, "an archive");
+    assert_eq!(store.size(key).expect("Failed to size archive"), 21);
+
+    store.delete(key).expect("Failed to delete archive");
+    assert!(!store.exists(key).expect("Failed to check for archive"));
+    assert!(store.keys().expect("Failed to list archives").is_empty());
+}
+
+#[test]
+fn s3_store_reports_missing_archives() {
+    let store = s3_store();
+    let key = "00/00/core-missing-1.0.0-20170101000000-x86_64-linux.hart";
+    match store.get(key) {
+        Err(Error::ArchiveNotFound(ref missing)) => assert_eq!(missing, key),
+        Err(e) => panic!("Unexpected error getting a missing archive, {}", e),
+        Ok(_) => panic!("Got an archive that was never put"),
+    }
+    assert!(!store.exists(key).expect("Failed to check for archive"));
+}
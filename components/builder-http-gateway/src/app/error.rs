@@ -24,6 +24,7 @@ pub type AppResult<T> = Result<T, AppError>;
 #[derive(Debug)]
 pub enum AppError {
     Connection(conn::ConnErr),
+    Mount(String),
     Protocol(protocol::ProtocolError),
 }
 
@@ -31,6 +32,7 @@ impl fmt::Display for AppError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
             AppError::Connection(ref e) => format!("{}", e),
+            AppError::Mount(ref e) => format!("Unable to mount router, {}", e),
             AppError::Protocol(ref e) => format!("{}", e),
         };
         write!(f, "{}", msg)
@@ -41,6 +43,7 @@ impl error::Error for AppError {
     fn description(&self) -> &str {
         match *self {
             AppError::Connection(ref err) => err.description(),
+            AppError::Mount(_) => "Unable to mount an additional router.",
             AppError::Protocol(ref err) => err.description(),
         }
     }
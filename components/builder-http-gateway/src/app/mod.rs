@@ -125,10 +125,14 @@ pub trait HttpGateway {
     }
 
     /// Callback for mounting additional Iron Routers before server start.
-    fn mount(Arc<Self::Config>, chain: iron::Chain) -> Mount {
+    ///
+    /// # Errors
+    ///
+    /// * An additional router could not be built from the configuration
+    fn mount(Arc<Self::Config>, chain: iron::Chain) -> AppResult<Mount> {
         let mut mount = Mount::new();
         mount.mount("/", chain);
-        mount
+        Ok(mount)
     }
 
     /// Returns the Iron Router used when starting the server.
@@ -140,6 +144,7 @@ pub trait HttpGateway {
 ///
 /// # Errors
 ///
+/// * An additional router could not be mounted
 /// * HTTP server could not start
 pub fn start<T>(cfg: T::Config) -> AppResult<()>
 where
@@ -150,7 +155,7 @@ where
     T::add_middleware(cfg.clone(), &mut chain);
     chain.link_before(XRouteClient);
     chain.link_after(Cors);
-    let mount = T::mount(cfg.clone(), chain);
+    let mount = T::mount(cfg.clone(), chain)?;
     let mut server = Iron::new(mount);
     server.threads = cfg.handler_count();
     let http_listen_addr = (cfg.listen_addr().clone(), cfg.listen_port());
@@ -24,14 +24,19 @@ use http::controller::*;
 use iron::status::{self, Status};
 use protocol::originsrv::{CheckOriginOwnerRequest, CheckOriginOwnerResponse,
                           CheckOriginAccessRequest, CheckOriginAccessResponse, Origin,
-                          OriginChannel, OriginChannelCreate, OriginChannelGet, OriginGet,
+                          OriginAuditAction, OriginAuditEvent, OriginAuditEventCreate,
+                          OriginChannel, OriginChannelCreate, OriginChannelGet,
+                          OriginChannelPolicy, OriginChannelPolicyGet, OriginGet,
+                          OriginMemberRole,
                           OriginPackage, OriginPackageChannelListRequest,
                           OriginPackageChannelListResponse, OriginPackageGet,
                           OriginPackageGroupPromote, OriginPackageGroupDemote, OriginPackageIdent,
                           OriginPackagePlatformListRequest, OriginPackagePlatformListResponse,
                           OriginPackagePromote, OriginPackageVisibility, OriginPublicKeyCreate,
-                          OriginPublicKey, OriginSecretKey, OriginSecretKeyCreate};
-use protocol::jobsrv::{JobGroup, JobGroupGet, JobGroupProject, JobGroupProjectState};
+                          OriginPublicKey, OriginSecretKey, OriginSecretKeyCreate,
+                          OriginWebhookEvent, OriginWebhookTrigger};
+use protocol::jobsrv::{Job, JobGroup, JobGroupGet, JobGroupProject, JobGroupProjectState,
+                       JobState, PackageJobGet};
 use protocol::sessionsrv::Session;
 use serde::Serialize;
 use serde_json;
@@ -255,6 +260,18 @@ pub fn get_param(req: &mut Request, name: &str) -> Option<String> {
 }
 
 pub fn check_origin_access<T>(req: &mut Request, origin: T) -> IronResult<bool>
+where
+    T: ToString,
+{
+    check_origin_role(req, origin, OriginMemberRole::Developer)
+}
+
+/// Check whether the session's account is a member of the origin holding at least the given role.
+pub fn check_origin_role<T>(
+    req: &mut Request,
+    origin: T,
+    role: OriginMemberRole,
+) -> IronResult<bool>
 where
     T: ToString,
 {
@@ -267,6 +284,7 @@ where
     let mut request = CheckOriginAccessRequest::new();
     request.set_account_id(session_id);
     request.set_origin_name(origin.to_string());
+    request.set_role(role);
     match route_message::<CheckOriginAccessRequest, CheckOriginAccessResponse>(req, &request) {
         Ok(response) => Ok(response.get_has_access()),
         Err(err) => {
@@ -314,7 +332,9 @@ pub fn promote_package_to_channel(
     ident: &OriginPackageIdent,
     channel: &str,
 ) -> NetResult<NetOk> {
-    if !check_origin_access(req, ident.get_origin()).unwrap_or(false) {
+    if !check_origin_role(req, ident.get_origin(), OriginMemberRole::Maintainer)
+        .unwrap_or(false)
+    {
         return Err(NetError::new(
             ErrCode::ACCESS_DENIED,
             "core:promote-package-to-channel:0",
@@ -326,6 +346,8 @@ pub fn promote_package_to_channel(
     channel_req.set_name(channel.to_string());
 
     let origin_channel = route_message::<OriginChannelGet, OriginChannel>(req, &channel_req)?;
+    check_channel_policy(req, ident, channel)?;
+
     let mut request = OriginPackageGet::new();
     request.set_ident(ident.clone());
     request.set_visibilities(all_visibilities());
@@ -338,6 +360,137 @@ pub fn promote_package_to_channel(
     route_message::<OriginPackagePromote, NetOk>(req, &promote)
 }
 
+/// Checks a promotion of a package into a channel against the channel's promotion policy,
+/// returning an error naming the rule the promotion breaks.
+pub fn check_channel_policy(
+    req: &mut Request,
+    ident: &OriginPackageIdent,
+    channel: &str,
+) -> NetResult<()> {
+    let policy = channel_policy(req, ident.get_origin(), channel)?;
+    enforce_channel_policy(
+        &policy,
+        &mut Promotion {
+            req: req,
+            ident: ident,
+        },
+    )
+}
+
+/// Checks a demotion of a package out of a channel against the channel's promotion policy. Only
+/// those who may promote into a protected channel may demote out of it.
+pub fn check_channel_demote_policy(
+    req: &mut Request,
+    origin: &str,
+    channel: &str,
+) -> NetResult<()> {
+    let policy = channel_policy(req, origin, channel)?;
+    if policy.has_promote_role() &&
+        !check_origin_role(req, origin, policy.get_promote_role()).unwrap_or(false)
+    {
+        return Err(NetError::new(
+            ErrCode::CHANNEL_PROTECTED,
+            "hg:check-channel-policy:4",
+        ));
+    }
+    Ok(())
+}
+
+fn channel_policy(
+    req: &mut Request,
+    origin: &str,
+    channel: &str,
+) -> NetResult<OriginChannelPolicy> {
+    let mut policy_get = OriginChannelPolicyGet::new();
+    policy_get.set_origin_name(origin.to_string());
+    policy_get.set_channel_name(channel.to_string());
+    route_message::<OriginChannelPolicyGet, OriginChannelPolicy>(req, &policy_get)
+}
+
+/// What a channel's promotion policy is checked against: the user asking for a promotion and the
+/// package they want promoted.
+trait PolicySubject {
+    fn has_role(&mut self, role: OriginMemberRole) -> bool;
+
+    fn channels(&mut self) -> NetResult<OriginPackageChannelListResponse>;
+
+    fn job(&mut self) -> NetResult<Job>;
+}
+
+struct Promotion<'a, 'b: 'a, 'c: 'b> {
+    req: &'a mut Request<'b, 'c>,
+    ident: &'a OriginPackageIdent,
+}
+
+impl<'a, 'b, 'c> PolicySubject for Promotion<'a, 'b, 'c> {
+    fn has_role(&mut self, role: OriginMemberRole) -> bool {
+        check_origin_role(self.req, self.ident.get_origin(), role).unwrap_or(false)
+    }
+
+    fn channels(&mut self) -> NetResult<OriginPackageChannelListResponse> {
+        let mut list = OriginPackageChannelListRequest::new();
+        list.set_ident(self.ident.clone());
+        list.set_visibilities(all_visibilities());
+        route_message::<OriginPackageChannelListRequest, OriginPackageChannelListResponse>(
+            self.req,
+            &list,
+        )
+    }
+
+    fn job(&mut self) -> NetResult<Job> {
+        let mut job_get = PackageJobGet::new();
+        job_get.set_ident(self.ident.clone());
+        route_message::<PackageJobGet, Job>(self.req, &job_get)
+    }
+}
+
+fn enforce_channel_policy<T: PolicySubject>(
+    policy: &OriginChannelPolicy,
+    subject: &mut T,
+) -> NetResult<()> {
+    if policy.has_promote_role() && !subject.has_role(policy.get_promote_role()) {
+        return Err(NetError::new(
+            ErrCode::CHANNEL_PROTECTED,
+            "hg:check-channel-policy:0",
+        ));
+    }
+
+    if policy.has_prerequisite_channel() {
+        let channels = subject.channels()?;
+        if !channels.get_channels().iter().any(|c| {
+            c.get_name() == policy.get_prerequisite_channel()
+        })
+        {
+            return Err(NetError::new(
+                ErrCode::CHANNEL_PREREQUISITE_UNMET,
+                "hg:check-channel-policy:1",
+            ));
+        }
+    }
+
+    if policy.get_require_job_success() {
+        match subject.job() {
+            Ok(ref job) if job.get_state() == JobState::Complete => (),
+            Ok(_) => {
+                return Err(NetError::new(
+                    ErrCode::CHANNEL_JOB_REQUIRED,
+                    "hg:check-channel-policy:2",
+                ))
+            }
+            // Uploaded by hand rather than built by Builder
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => {
+                return Err(NetError::new(
+                    ErrCode::CHANNEL_JOB_REQUIRED,
+                    "hg:check-channel-policy:3",
+                ))
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(())
+}
+
 pub fn promote_or_demote_job_group(
     req: &mut Request,
     group_id: u64,
@@ -400,7 +553,26 @@ pub fn promote_or_demote_job_group(
 
     for (origin, projects) in origin_map.iter() {
         match do_group_promotion_or_demotion(req, channel, projects.to_vec(), &origin, promote) {
-            Ok(_) => (),
+            Ok(_) => {
+                let action = if promote {
+                    OriginAuditAction::JobGroupPromote
+                } else {
+                    OriginAuditAction::JobGroupDemote
+                };
+                audit(req, &origin, action, &group_id.to_string(), channel);
+                let event = if promote {
+                    OriginWebhookEvent::PackagePromoted
+                } else {
+                    OriginWebhookEvent::PackageDemoted
+                };
+                for project in projects.iter() {
+                    let ident = OriginPackageIdent::from_str(project.get_ident()).unwrap();
+                    let mut trigger = OriginWebhookTrigger::for_package(event, &ident);
+                    trigger.set_channel(channel.to_string());
+                    trigger.set_group_id(group_id);
+                    trigger_webhooks(req, &trigger);
+                }
+            }
             Err(e) => {
                 if e.get_code() != ErrCode::ACCESS_DENIED {
                     info!("Failed to promote group, err: {:?}", e);
@@ -420,6 +592,89 @@ pub fn get_optional_session_id(req: &mut Request) -> Option<u64> {
     }
 }
 
+/// Records a mutating operation in the origin's audit log. The operation has already been
+/// carried out by the time this is called, so a failure to record it is logged rather than
+/// returned to the caller.
+pub fn audit(
+    req: &mut Request,
+    origin: &str,
+    action: OriginAuditAction,
+    target: &str,
+    detail: &str,
+) {
+    let mut event = OriginAuditEvent::new();
+    match req.extensions.get::<Authenticated>() {
+        Some(session) => {
+            event.set_account_id(session.get_id());
+            event.set_account_name(session.get_name().to_string());
+            if session.has_access_token() {
+                event.set_access_token_id(session.get_access_token().get_id());
+            }
+        }
+        None => {
+            warn!(
+                "Unable to record audit event without a session, origin={}, action={}, target={}",
+                origin,
+                action,
+                target
+            );
+            return;
+        }
+    }
+    event.set_origin_name(origin.to_string());
+    event.set_action(action);
+    event.set_target(target.to_string());
+    event.set_detail(detail.to_string());
+    event.set_remote_addr(req.remote_addr.ip().to_string());
+    if let Some(forwarded) = forwarded_for(req) {
+        event.set_forwarded_for(forwarded);
+    }
+    if let Some(&UserAgent(ref agent)) = req.headers.get::<UserAgent>() {
+        event.set_user_agent(agent.to_string());
+    }
+
+    let mut request = OriginAuditEventCreate::new();
+    request.set_event(event);
+    if let Err(err) = route_message::<OriginAuditEventCreate, NetOk>(req, &request) {
+        warn!(
+            "Failed to record audit event, origin={}, action={}, target={}, {}",
+            origin,
+            action,
+            target,
+            err
+        );
+    }
+}
+
+/// Queues deliveries of an event to the origin's webhooks. Like auditing, this happens after the
+/// operation has succeeded, so a failure is only logged.
+pub fn trigger_webhooks(req: &mut Request, trigger: &OriginWebhookTrigger) {
+    if let Err(err) = route_message::<OriginWebhookTrigger, NetOk>(req, trigger) {
+        warn!(
+            "Failed to trigger webhooks, origin={}, event={}, {}",
+            trigger.get_origin_name(),
+            trigger.get_event(),
+            err
+        );
+    }
+}
+
+/// The X-Forwarded-For chain sent with the request, if any. Clients can send anything here, so
+/// it's only ever recorded next to the peer address and never in place of it.
+fn forwarded_for(req: &Request) -> Option<String> {
+    let hops: Vec<String> = req.headers
+        .get_raw("X-Forwarded-For")
+        .unwrap_or(&[])
+        .iter()
+        .filter_map(|value| String::from_utf8(value.clone()).ok())
+        .collect();
+    if hops.is_empty() {
+        None
+    } else {
+        Some(hops.join(", "))
+    }
+}
+
 pub fn generate_origin_keys(req: &mut Request, session: Session, origin: Origin) -> NetResult<()> {
     let mut public_request = OriginPublicKeyCreate::new();
     let mut secret_request = OriginSecretKeyCreate::new();
@@ -458,7 +713,7 @@ fn do_group_promotion_or_demotion(
     origin: &str,
     promote: bool,
 ) -> NetResult<NetOk> {
-    if !check_origin_access(req, origin).unwrap_or(false) {
+    if !check_origin_role(req, origin, OriginMemberRole::Maintainer).unwrap_or(false) {
         return Err(NetError::new(
             ErrCode::ACCESS_DENIED,
             "hg:promote-demote-job-group:0",
@@ -486,10 +741,17 @@ fn do_group_promotion_or_demotion(
         }
     };
 
+    if !promote {
+        check_channel_demote_policy(req, origin, channel.get_name())?;
+    }
+
     let mut package_ids = Vec::new();
 
     for project in projects {
         let opi = OriginPackageIdent::from_str(project.get_ident()).unwrap();
+        if promote {
+            check_channel_policy(req, &opi, channel.get_name())?;
+        }
         let mut opg = OriginPackageGet::new();
         opg.set_ident(opi);
         opg.set_visibilities(all_visibilities());
@@ -529,3 +791,131 @@ fn get_session_id(req: &mut Request) -> u64 {
     let session = req.extensions.get::<Authenticated>().unwrap();
     session.get_id()
 }
+
+#[cfg(test)]
+mod test {
+    use protocol::originsrv::OriginChannel;
+
+    use super::*;
+
+    struct Subject {
+        role: Option<OriginMemberRole>,
+        channels: Vec<&'static str>,
+        job: Option<JobState>,
+    }
+
+    impl PolicySubject for Subject {
+        fn has_role(&mut self, role: OriginMemberRole) -> bool {
+            self.role.map_or(false, |r| r.includes(role))
+        }
+
+        fn channels(&mut self) -> NetResult<OriginPackageChannelListResponse> {
+            let mut response = OriginPackageChannelListResponse::new();
+            for name in self.channels.iter() {
+                let mut channel = OriginChannel::new();
+                channel.set_name(name.to_string());
+                response.mut_channels().push(channel);
+            }
+            Ok(response)
+        }
+
+        fn job(&mut self) -> NetResult<Job> {
+            match self.job {
+                Some(state) => {
+                    let mut job = Job::new();
+                    job.set_state(state);
+                    Ok(job)
+                }
+                None => Err(NetError::new(ErrCode::ENTITY_NOT_FOUND, "hg:test")),
+            }
+        }
+    }
+
+    fn built_by_maintainer() -> Subject {
+        Subject {
+            role: Some(OriginMemberRole::Maintainer),
+            channels: vec!["unstable"],
+            job: Some(JobState::Complete),
+        }
+    }
+
+    fn rejection(policy: &OriginChannelPolicy, subject: &mut Subject) -> ErrCode {
+        enforce_channel_policy(policy, subject)
+            .expect_err("promotion should be rejected")
+            .get_code()
+    }
+
+    #[test]
+    fn unrestricted_channel() {
+        let policy = OriginChannelPolicy::new();
+        let mut subject = built_by_maintainer();
+        subject.channels.clear();
+        subject.job = None;
+        assert!(enforce_channel_policy(&policy, &mut subject).is_ok());
+    }
+
+    #[test]
+    fn protected_channel() {
+        let mut policy = OriginChannelPolicy::new();
+        policy.set_promote_role(OriginMemberRole::Owner);
+        assert_eq!(
+            rejection(&policy, &mut built_by_maintainer()),
+            ErrCode::CHANNEL_PROTECTED
+        );
+
+        let mut subject = built_by_maintainer();
+        subject.role = Some(OriginMemberRole::Owner);
+        assert!(enforce_channel_policy(&policy, &mut subject).is_ok());
+    }
+
+    #[test]
+    fn higher_roles_may_promote_into_protected_channels() {
+        let mut policy = OriginChannelPolicy::new();
+        policy.set_promote_role(OriginMemberRole::Maintainer);
+        assert!(enforce_channel_policy(&policy, &mut built_by_maintainer()).is_ok());
+
+        let mut subject = built_by_maintainer();
+        subject.role = Some(OriginMemberRole::Owner);
+        assert!(enforce_channel_policy(&policy, &mut subject).is_ok());
+
+        subject.role = Some(OriginMemberRole::Developer);
+        assert_eq!(
+            rejection(&policy, &mut subject),
+            ErrCode::CHANNEL_PROTECTED
+        );
+    }
+
+    #[test]
+    fn prerequisite_channel() {
+        let mut policy = OriginChannelPolicy::new();
+        policy.set_prerequisite_channel("staging".to_string());
+        assert_eq!(
+            rejection(&policy, &mut built_by_maintainer()),
+            ErrCode::CHANNEL_PREREQUISITE_UNMET
+        );
+
+        let mut subject = built_by_maintainer();
+        subject.channels.push("staging");
+        assert!(enforce_channel_policy(&policy, &mut subject).is_ok());
+    }
+
+    #[test]
+    fn job_required() {
+        let mut policy = OriginChannelPolicy::new();
+        policy.set_require_job_success(true);
+        assert!(enforce_channel_policy(&policy, &mut built_by_maintainer()).is_ok());
+
+        let mut subject = built_by_maintainer();
+        subject.job = Some(JobState::Failed);
+        assert_eq!(
+            rejection(&policy, &mut subject),
+            ErrCode::CHANNEL_JOB_REQUIRED
+        );
+
+        subject.job = None;
+        assert_eq!(
+            rejection(&policy, &mut subject),
+            ErrCode::CHANNEL_JOB_REQUIRED
+        );
+    }
+}
@@ -13,11 +13,11 @@
 // limitations under the License.
 
 use base64;
-use github_api_client::{GitHubCfg, GitHubClient, HubError};
+use github_api_client::{GitHubCfg, GitHubClient};
 use hab_net::{ErrCode, NetError};
 use hab_net::conn::RouteClient;
 use hab_net::privilege::FeatureFlags;
-use hyper;
+use oauth_client::{IdentityProvider, OAuthError, ProviderKind};
 use iron::Handler;
 use iron::headers::{self, Authorization, Bearer};
 use iron::method::Method;
@@ -26,8 +26,10 @@ use iron::prelude::*;
 use iron::status::Status;
 use iron::typemap::Key;
 use persistent;
+use protocol::jobsrv::{JobGroup, JobGroupGet};
 use protocol::message;
 use protocol::sessionsrv::*;
+use router::Router;
 use segment_api_client::SegmentClient;
 use serde_json;
 use unicase::UniCase;
@@ -87,6 +89,13 @@ impl Key for GitHubCli {
     type Value = GitHubClient;
 }
 
+/// Identity provider users sign in with
+pub struct OAuthCli;
+
+impl Key for OAuthCli {
+    type Value = Box<IdentityProvider>;
+}
+
 pub struct SegmentCli;
 
 impl Key for SegmentCli {
@@ -111,6 +120,8 @@ pub struct Authenticated {
     github: GitHubClient,
     features: FeatureFlags,
     optional: bool,
+    token_scope: Option<AccessTokenScope>,
+    group_origin: bool,
 }
 
 impl Authenticated {
@@ -120,6 +131,8 @@ impl Authenticated {
             github: github,
             features: FeatureFlags::empty(),
             optional: false,
+            token_scope: None,
+            group_origin: false,
         }
     }
 
@@ -133,6 +146,20 @@ impl Authenticated {
         self
     }
 
+    /// Accept personal access tokens carrying the given scope. Requests authenticated with a
+    /// personal access token are rejected by routes which don't opt in.
+    pub fn allow_token(mut self, scope: AccessTokenScope) -> Self {
+        self.token_scope = Some(scope);
+        self
+    }
+
+    /// Take the origin which personal access tokens are checked against from the job group named
+    /// by the route's `:id`, for routes which don't name an origin themselves.
+    pub fn origin_from_group(mut self) -> Self {
+        self.group_origin = true;
+        self
+    }
+
     fn authenticate(&self, req: &mut Request, token: SessionToken) -> IronResult<Session> {
         let mut request = SessionGet::new();
         request.set_token(token);
@@ -150,6 +177,90 @@ impl Authenticated {
         }
     }
 
+    fn authenticate_access_token(&self, req: &mut Request, token: &str) -> IronResult<Session> {
+        let key = match base64::decode(token) {
+            Ok(decoded) => {
+                match message::decode::<AccessTokenKey>(&decoded) {
+                    Ok(key) => key,
+                    Err(_) => {
+                        let err = NetError::new(ErrCode::BAD_TOKEN, "net:auth:4");
+                        return Err(IronError::new(err, Status::Forbidden));
+                    }
+                }
+            }
+            Err(_) => {
+                let err = NetError::new(ErrCode::BAD_TOKEN, "net:auth:4");
+                return Err(IronError::new(err, Status::Forbidden));
+            }
+        };
+        let mut request = AccessTokenValidate::new();
+        request.set_key(key);
+        let session = {
+            let conn = req.extensions.get_mut::<XRouteClient>().unwrap();
+            match conn.route::<AccessTokenValidate, Session>(&request) {
+                Ok(session) => session,
+                Err(err) => {
+                    let status = net_err_to_http(err.get_code());
+                    let body = itry!(serde_json::to_string(&err));
+                    return Err(IronError::new(err, (body, status)));
+                }
+            }
+        };
+        self.validate_session(&session)?;
+        self.validate_access_token(req, session.get_access_token())?;
+        Ok(session)
+    }
+
+    fn validate_access_token(&self, req: &mut Request, token: &AccessToken) -> IronResult<()> {
+        let origin = self.request_origin(req)?;
+        let origin = origin.as_ref().map(String::as_str);
+        // Optional authentication only ever guards reads, which a token of any scope may perform
+        // within its origins.
+        let permitted = if self.optional {
+            token.permits_origin(origin)
+        } else {
+            match self.token_scope {
+                Some(scope) => token.permits(scope, origin),
+                None => false,
+            }
+        };
+        if !permitted {
+            let err = NetError::new(ErrCode::ACCESS_DENIED, "net:auth:5");
+            return Err(IronError::new(err, Status::Forbidden));
+        }
+        Ok(())
+    }
+
+    // The origin a request is about, if it is about a single one.
+    fn request_origin(&self, req: &mut Request) -> IronResult<Option<String>> {
+        let (origin, id) = match req.extensions.get::<Router>() {
+            Some(params) => {
+                (
+                    params.find("origin").map(str::to_string),
+                    params.find("id").map(str::to_string),
+                )
+            }
+            None => (None, None),
+        };
+        if origin.is_some() || !self.group_origin {
+            return Ok(origin);
+        }
+        let mut request = JobGroupGet::new();
+        match id.and_then(|id| id.parse::<u64>().ok()) {
+            Some(id) => request.set_group_id(id),
+            None => return Ok(None),
+        }
+        let conn = req.extensions.get_mut::<XRouteClient>().unwrap();
+        match conn.route::<JobGroupGet, JobGroup>(&request) {
+            Ok(group) => Ok(group.get_project_name().split('/').next().map(str::to_string)),
+            Err(err) => {
+                let status = net_err_to_http(err.get_code());
+                let body = itry!(serde_json::to_string(&err));
+                Err(IronError::new(err, (body, status)))
+            }
+        }
+    }
+
     fn validate_session(&self, session: &Session) -> IronResult<()> {
         let flags = FeatureFlags::from_bits(session.get_flags()).unwrap();
         if !flags.contains(self.features) {
@@ -179,16 +290,18 @@ impl BeforeMiddleware for Authenticated {
         };
 
         let session = {
-            if let Ok(decoded_token) = base64::decode(&token) {
+            if token.starts_with(ACCESS_TOKEN_PREFIX) {
+                self.authenticate_access_token(req, &token[ACCESS_TOKEN_PREFIX.len()..])?
+            } else if let Ok(decoded_token) = base64::decode(&token) {
                 if let Ok(token) = message::decode(&decoded_token) {
                     self.authenticate(req, token)?
                 } else {
                     // TODO: Replace temporary auth workaround
                     // We got a bearer token that is not a valid session token.
-                    // Check to see if this is a valid github token, and create (or
-                    // update) a session. This is a temporary fix until we can roll out
+                    // Check to see if this is a valid token of our identity provider, and create
+                    // (or update) a session. This is a temporary fix until we can roll out
                     // and migrate clients to our own personal access tokens.
-                    session_create_github(req, token)?
+                    session_create_oauth(req, token)?
                 }
             } else {
                 let err = NetError::new(ErrCode::BAD_TOKEN, "net:auth:3");
@@ -220,20 +333,25 @@ impl AfterMiddleware for Cors {
     }
 }
 
-pub fn session_create_github(req: &mut Request, token: String) -> IronResult<Session> {
-    let github = req.get::<persistent::Read<GitHubCli>>().unwrap();
+pub fn session_create_oauth(req: &mut Request, token: String) -> IronResult<Session> {
+    let provider = req.get::<persistent::Read<OAuthCli>>().unwrap();
     let conn = req.extensions.get_mut::<XRouteClient>().expect(
         "no XRouteClient extension in request",
     );
-    match github.user(&token) {
-        Ok(user) => {
+    match provider.user(&token) {
+        Ok(identity) => {
             let mut request = SessionCreate::new();
             request.set_token(token);
-            request.set_extern_id(user.id);
-            request.set_name(user.login);
-            request.set_provider(OAuthProvider::GitHub);
-            if let Some(email) = user.email {
+            request.set_extern_id(identity.extern_id());
+            request.set_subject(identity.id);
+            request.set_name(identity.username);
+            request.set_provider(match provider.kind() {
+                ProviderKind::GitHub => OAuthProvider::GitHub,
+                ProviderKind::Oidc => OAuthProvider::OIDC,
+            });
+            if let Some(email) = identity.email {
                 request.set_email(email);
+                request.set_email_verified(identity.email_verified);
             }
             match conn.route::<SessionCreate, Session>(&request) {
                 Ok(session) => Ok(session),
@@ -244,21 +362,22 @@ pub fn session_create_github(req: &mut Request, token: String) -> IronResult<Ses
                 }
             }
         }
-        Err(HubError::ApiError(hyper::status::StatusCode::Unauthorized, _)) => {
+        Err(OAuthError::Unauthorized) => {
             let err = NetError::new(ErrCode::ACCESS_DENIED, "net:session-create:1");
             let status = net_err_to_http(err.get_code());
             let body = itry!(serde_json::to_string(&err));
             Err(IronError::new(err, (body, status)))
         }
-        Err(e @ HubError::ApiError(_, _)) => {
-            warn!("Unexpected response from GitHub, {:?}", e);
+        Err(e @ OAuthError::HttpResponse(_, _)) |
+        Err(e @ OAuthError::MissingClaim(_)) => {
+            warn!("Unexpected response from identity provider, {:?}", e);
             let err = NetError::new(ErrCode::BAD_REMOTE_REPLY, "net:session-create:2");
             let status = net_err_to_http(err.get_code());
             let body = itry!(serde_json::to_string(&err));
             Err(IronError::new(err, (body, status)))
         }
-        Err(e @ HubError::Serialization(_)) => {
-            warn!("Bad response body from GitHub, {:?}", e);
+        Err(e @ OAuthError::Serialization(_)) => {
+            warn!("Bad response body from identity provider, {:?}", e);
             let err = NetError::new(ErrCode::BAD_REMOTE_REPLY, "net:session-create:3");
             let status = net_err_to_http(err.get_code());
             let body = itry!(serde_json::to_string(&err));
@@ -41,8 +41,12 @@ pub fn net_err_to_http(err: ErrCode) -> Status {
         ErrCode::SOCK |
         ErrCode::REMOTE_UNAVAILABLE => Status::ServiceUnavailable,
 
-        ErrCode::BAD_TOKEN => Status::Forbidden,
-        ErrCode::GROUP_NOT_COMPLETE => Status::UnprocessableEntity,
+        ErrCode::BAD_TOKEN |
+        ErrCode::CHANNEL_PROTECTED => Status::Forbidden,
+
+        ErrCode::GROUP_NOT_COMPLETE |
+        ErrCode::CHANNEL_PREREQUISITE_UNMET |
+        ErrCode::CHANNEL_JOB_REQUIRED => Status::UnprocessableEntity,
         ErrCode::PARTIAL_JOB_GROUP_PROMOTE => Status::PartialContent,
 
         ErrCode::BUG |
@@ -30,6 +30,7 @@ extern crate iron;
 extern crate log;
 extern crate mount;
 extern crate num_cpus;
+extern crate oauth_client;
 extern crate params;
 extern crate persistent;
 extern crate protobuf;
@@ -137,6 +137,26 @@ impl DataStore {
         Ok(None)
     }
 
+    /// Get the most recent job which built the given package. Packages which weren't built by
+    /// Builder have no job, so we'll get a None result.
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the job cannot be selected from the database
+    pub fn get_job_for_package(&self, msg: &jobsrv::PackageJobGet) -> Result<Option<jobsrv::Job>> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query(
+            "SELECT * FROM get_job_for_package_v1($1)",
+            &[&msg.get_ident().to_string()],
+        ).map_err(Error::PackageJobGet)?;
+        for row in rows {
+            let job = row_to_job(&row)?;
+            return Ok(Some(job));
+        }
+        Ok(None)
+    }
+
     /// Get the 50 most recently-created jobs for a given project
     /// (specified as an origin-qualified name, e.g., "core/nginx").
     ///
@@ -69,6 +69,7 @@ pub enum Error {
     LogDirIsNotDir(PathBuf),
     LogDirNotWritable(PathBuf),
     NetError(hab_net::NetError),
+    PackageJobGet(postgres::error::Error),
     ParseVCSInstallationId(num::ParseIntError),
     ProjectJobsGet(postgres::error::Error),
     Protobuf(protobuf::ProtobufError),
@@ -167,6 +168,9 @@ impl fmt::Display for Error {
             }
             Error::Protobuf(ref e) => format!("{}", e),
             Error::Protocol(ref e) => format!("{}", e),
+            Error::PackageJobGet(ref e) => {
+                format!("Database error getting job for package, {}", e)
+            }
             Error::ProjectJobsGet(ref e) => {
                 format!("Database error getting jobs for project, {}", e)
             }
@@ -224,6 +228,7 @@ impl error::Error for Error {
             Error::LogDirNotWritable(_) => "Build log directory is not writable",
             Error::NetError(ref err) => err.description(),
             Error::ParseVCSInstallationId(_) => "VCS installation id could not be parsed as u64",
+            Error::PackageJobGet(ref err) => err.description(),
             Error::ProjectJobsGet(ref err) => err.description(),
             Error::Protobuf(ref err) => err.description(),
             Error::Protocol(ref err) => err.description(),
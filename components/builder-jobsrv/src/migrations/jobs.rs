@@ -438,5 +438,23 @@ pub fn migrate(migrator: &mut Migrator) -> Result<()> {
                        WHERE job_state = 'Dispatched'
                      $$"#,
     )?;
+
+    // Get the most recent job which built a package, by its fully qualified ident
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE INDEX IF NOT EXISTS jobs_package_ident_index_v1 on jobs(package_ident)"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_job_for_package_v1(p_package_ident text)
+                     RETURNS SETOF jobs
+                     LANGUAGE SQL STABLE AS $$
+                       SELECT *
+                       FROM jobs
+                       WHERE package_ident = p_package_ident
+                       ORDER BY created_at DESC
+                       LIMIT 1
+                     $$"#,
+    )?;
     Ok(())
 }
@@ -59,6 +59,27 @@ pub fn job_get(req: &mut Message, conn: &mut RouteConn, state: &mut ServerState)
     Ok(())
 }
 
+pub fn package_job_get(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg = req.parse::<jobsrv::PackageJobGet>()?;
+    match state.datastore.get_job_for_package(&msg) {
+        Ok(Some(ref job)) => conn.route_reply(req, job)?,
+        Ok(None) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "jb:package-job-get:1");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "jb:package-job-get:2");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn project_jobs_get(
     req: &mut Message,
     conn: &mut RouteConn,
@@ -41,6 +41,7 @@ lazy_static! {
         let mut map = DispatchTable::new();
         map.register(JobSpec::descriptor_static(None), handlers::job_create);
         map.register(JobGet::descriptor_static(None), handlers::job_get);
+        map.register(PackageJobGet::descriptor_static(None), handlers::package_job_get);
         map.register(ProjectJobsGet::descriptor_static(None), handlers::project_jobs_get);
         map.register(JobLogGet::descriptor_static(None), handlers::job_log_get);
         map.register(JobGroupSpec::descriptor_static(None), handlers::job_group_create);
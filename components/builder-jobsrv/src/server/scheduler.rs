@@ -17,7 +17,7 @@ use std::path::Path;
 use std::sync::{mpsc, Arc};
 use std::thread::{self, JoinHandle};
 
-use hab_net::ErrCode;
+use hab_net::{ErrCode, NetOk};
 use hab_net::conn::RouteClient;
 use hab_net::socket::DEFAULT_CONTEXT;
 use zmq;
@@ -158,6 +158,20 @@ impl ScheduleMgr {
         self.logger.log(&msg);
     }
 
+    fn trigger_webhooks(&mut self, trigger: &originsrv::OriginWebhookTrigger) {
+        if let Err(err) = self.route_conn.route::<originsrv::OriginWebhookTrigger, NetOk>(
+            trigger,
+        )
+        {
+            self.log_error(format!(
+                "Failed to trigger webhooks for {} (group: {}): {:?}",
+                trigger.get_origin_name(),
+                trigger.get_group_id(),
+                err
+            ));
+        }
+    }
+
     fn process_queue(&mut self) -> Result<()> {
         let groups = self.datastore.get_queued_job_groups()?;
 
@@ -486,6 +500,15 @@ impl ScheduleMgr {
                                 ));
                             }
                         };
+
+                        let mut trigger = originsrv::OriginWebhookTrigger::new();
+                        trigger.set_origin_name(job.get_project().get_origin_name().to_string());
+                        trigger.set_event(originsrv::OriginWebhookEvent::JobFailed);
+                        trigger.set_ident(job.get_project().get_name().to_string());
+                        trigger.set_group_id(job.get_owner_id());
+                        trigger.set_job_id(job.get_id());
+                        trigger.set_state(String::from("failed"));
+                        self.trigger_webhooks(&trigger);
                     }
 
                     match job.get_state() {
@@ -566,6 +589,29 @@ impl ScheduleMgr {
 
             self.datastore.set_job_group_state(group_id, new_state)?;
 
+            if new_state == jobsrv::JobGroupState::GroupComplete {
+                // A group can span several origins, each of which gets to hear about it.
+                let mut origins: Vec<&str> = group
+                    .get_projects()
+                    .iter()
+                    .filter_map(|p| p.get_name().split("/").next())
+                    .collect();
+                origins.sort();
+                origins.dedup();
+                for origin in origins {
+                    let mut trigger = originsrv::OriginWebhookTrigger::new();
+                    trigger.set_origin_name(origin.to_string());
+                    trigger.set_event(originsrv::OriginWebhookEvent::JobGroupCompleted);
+                    trigger.set_group_id(group_id);
+                    trigger.set_state(if failed > 0 {
+                        String::from("failed")
+                    } else {
+                        String::from("complete")
+                    });
+                    self.trigger_webhooks(&trigger);
+                }
+            }
+
             if new_state == jobsrv::JobGroupState::GroupPending {
                 self.schedule_cli.notify()?;
             } else {
@@ -18,6 +18,7 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use bldr_core::helpers::transition_visibility;
+use chrono::{DateTime, UTC};
 use db::async::{AsyncServer, EventOutcome};
 use db::config::{DataStoreCfg, ShardId};
 use db::error::{Error as DbError, Result as DbResult};
@@ -35,6 +36,8 @@ use protobuf;
 
 use error::{SrvError, SrvResult};
 use migrations;
+use serde_json;
+use webhooks;
 
 #[derive(Debug, Clone)]
 pub struct DataStore {
@@ -84,6 +87,9 @@ impl DataStore {
         migrations::origin_projects::migrate(&mut migrator)?;
         migrations::origin_packages::migrate(&mut migrator)?;
         migrations::origin_channels::migrate(&mut migrator)?;
+        migrations::origin_audit_events::migrate(&mut migrator)?;
+        migrations::origin_webhooks::migrate(&mut migrator)?;
+        migrations::origin_channel_policies::migrate(&mut migrator)?;
 
         migrator.finish()?;
 
@@ -116,7 +122,7 @@ impl DataStore {
         let ident = pkg.get_ident();
 
         conn.execute(
-            "SELECT update_origin_package_v1($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)",
+            "SELECT update_origin_package_v2($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)",
             &[
                 &(pkg.get_id() as i64),
                 &(pkg.get_owner_id() as i64),
@@ -130,12 +136,43 @@ impl DataStore {
                 &self.into_delimited(pkg.get_tdeps().to_vec()),
                 &self.into_delimited(pkg.get_exposes().to_vec()),
                 &pkg.get_visibility().to_string(),
+                &pkg.get_yanked(),
             ],
         ).map_err(SrvError::OriginPackageUpdate)?;
         self.async.schedule("sync_packages")?;
         Ok(())
     }
 
+    pub fn delete_origin_package(&self, opd: &originsrv::OriginPackageDelete) -> SrvResult<()> {
+        let conn = self.pool.get(opd)?;
+        conn.execute(
+            "SELECT delete_origin_package_v1($1)",
+            &[&(opd.get_id() as i64)],
+        ).map_err(SrvError::OriginPackageDelete)?;
+        Ok(())
+    }
+
+    /// Returns every release in the requested shard which has the given release among its
+    /// dependencies, direct or transitive. Those can be in any origin, so callers ask every shard.
+    pub fn list_origin_package_dependents(
+        &self,
+        opdl: &originsrv::OriginPackageDependentListRequest,
+    ) -> SrvResult<originsrv::OriginPackageDependentListResponse> {
+        let conn = self.pool.get_shard(opdl.get_shard())?;
+        let rows = conn.query(
+            "SELECT * FROM get_origin_package_dependents_v1($1)",
+            &[&opdl.get_ident().to_string()],
+        ).map_err(SrvError::OriginPackageDependentList)?;
+        let mut idents = protobuf::RepeatedField::new();
+        for row in rows.iter() {
+            let dependent: String = row.get("ident");
+            idents.push(originsrv::OriginPackageIdent::from_str(&dependent)?);
+        }
+        let mut response = originsrv::OriginPackageDependentListResponse::new();
+        response.set_idents(idents);
+        Ok(response)
+    }
+
     pub fn update_origin_project(&self, opc: &originsrv::OriginProjectUpdate) -> SrvResult<()> {
         let conn = self.pool.get(opc)?;
         let project = opc.get_project();
@@ -389,10 +426,18 @@ impl DataStore {
     ) -> SrvResult<bool> {
         let conn = self.pool.get(coar)?;
         let rows = &conn.query(
-            "SELECT * FROM check_account_in_origin_members_v1($1, $2)",
+            "SELECT * FROM get_origin_member_role_v1($1, $2)",
             &[&coar.get_origin_name(), &(coar.get_account_id() as i64)],
         ).map_err(SrvError::OriginAccountInOrigin)?;
-        if rows.len() != 0 { Ok(true) } else { Ok(false) }
+        match rows.iter().nth(0) {
+            Some(row) => {
+                let role: String = row.get("role");
+                let role: originsrv::OriginMemberRole =
+                    role.parse().map_err(SrvError::UnknownOriginMemberRole)?;
+                Ok(role.includes(coar.get_role()))
+            }
+            None => Ok(false),
+        }
     }
 
     pub fn my_origins(
@@ -423,7 +468,7 @@ impl DataStore {
     ) -> SrvResult<originsrv::OriginMemberListResponse> {
         let conn = self.pool.get(omlr)?;
         let rows = &conn.query(
-            "SELECT * FROM list_origin_members_v1($1)",
+            "SELECT * FROM list_origin_members_v2($1)",
             &[&(omlr.get_origin_id() as i64)],
         ).map_err(SrvError::OriginMemberList)?;
 
@@ -431,11 +476,20 @@ impl DataStore {
         response.set_origin_id(omlr.get_origin_id());
 
         let mut members = protobuf::RepeatedField::new();
+        let mut roles = protobuf::RepeatedField::new();
         for row in rows {
-            members.push(row.get("account_name"));
+            let mut member = originsrv::OriginMember::new();
+            let account_id: i64 = row.get("account_id");
+            member.set_account_id(account_id as u64);
+            member.set_account_name(row.get("account_name"));
+            let role: String = row.get("role");
+            member.set_role(role.parse().map_err(SrvError::UnknownOriginMemberRole)?);
+            members.push(member.get_account_name().to_string());
+            roles.push(member);
         }
 
         response.set_members(members);
+        response.set_roles(roles);
         Ok(response)
     }
 
@@ -803,7 +857,7 @@ impl DataStore {
         }
 
         let rows = conn.query(
-            "SELECT * FROM insert_origin_v2($1, $2, $3, $4)",
+            "SELECT * FROM insert_origin_v3($1, $2, $3, $4)",
             &[
                 &origin.get_name(),
                 &(origin.get_owner_id() as i64),
@@ -884,7 +938,7 @@ impl DataStore {
         let ident = opc.get_ident();
 
         let rows = conn.query(
-            "SELECT * FROM insert_origin_package_v3($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)",
+            "SELECT * FROM insert_origin_package_v4($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)",
             &[
                 &(opc.get_origin_id() as i64),
                 &(opc.get_owner_id() as i64),
@@ -897,7 +951,8 @@ impl DataStore {
                 &self.into_delimited(opc.get_deps().to_vec()),
                 &self.into_delimited(opc.get_tdeps().to_vec()),
                 &self.into_delimited(opc.get_exposes().to_vec()),
-                &opc.get_visibility().to_string()
+                &opc.get_visibility().to_string(),
+                &opc.get_is_service(),
             ],
         ).map_err(SrvError::OriginPackageCreate)?;
 
@@ -983,7 +1038,7 @@ impl DataStore {
     ) -> SrvResult<Option<originsrv::OriginPackageIdent>> {
         let conn = self.pool.get(opc)?;
         let rows = conn.query(
-            "SELECT * FROM get_origin_package_latest_v5($1, $2, $3)",
+            "SELECT * FROM get_origin_package_latest_v6($1, $2, $3)",
             &[
                 &self.searchable_ident(opc.get_ident()),
                 &opc.get_target(),
@@ -1004,7 +1059,7 @@ impl DataStore {
     ) -> SrvResult<Option<originsrv::OriginPackageIdent>> {
         let conn = self.pool.get(ocpg)?;
         let rows = conn.query(
-            "SELECT * FROM get_origin_channel_package_latest_v5($1, $2, $3, $4, $5)",
+            "SELECT * FROM get_origin_channel_package_latest_v6($1, $2, $3, $4, $5)",
             &[
                 &ocpg.get_ident().get_origin(),
                 &ocpg.get_name(),
@@ -1183,7 +1238,7 @@ impl DataStore {
         let conn = self.pool.get(opl)?;
 
         let rows = conn.query(
-            "SELECT * FROM get_origin_channel_packages_for_channel_v3($1, $2, $3, $4, $5, $6)",
+            "SELECT * FROM get_origin_channel_packages_for_channel_v4($1, $2, $3, $4, $5, $6)",
             &[
                 &opl.get_ident().get_origin(),
                 &opl.get_name(),
@@ -1358,6 +1413,11 @@ impl DataStore {
         let pv2: originsrv::OriginPackageVisibility =
             pv.parse().map_err(SrvError::UnknownOriginPackageVisibility)?;
         package.set_visibility(pv2);
+        package.set_yanked(row.get("yanked"));
+        let is_service: Option<bool> = row.get("is_service");
+        if let Some(is_service) = is_service {
+            package.set_is_service(is_service);
+        }
 
         Ok(package)
     }
@@ -1446,6 +1506,82 @@ impl DataStore {
         }
     }
 
+    pub fn get_origin_channel_policy(
+        &self,
+        ocpg: &originsrv::OriginChannelPolicyGet,
+    ) -> SrvResult<Option<originsrv::OriginChannelPolicy>> {
+        let conn = self.pool.get(ocpg)?;
+        let rows = &conn.query(
+            "SELECT * FROM get_origin_channel_policy_v1($1, $2)",
+            &[&ocpg.get_origin_name(), &ocpg.get_channel_name()],
+        ).map_err(SrvError::OriginChannelPolicyGet)?;
+
+        if rows.len() != 0 {
+            let row = rows.get(0);
+            Ok(Some(self.row_to_origin_channel_policy(&row)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn update_origin_channel_policy(
+        &self,
+        ocpu: &originsrv::OriginChannelPolicyUpdate,
+    ) -> SrvResult<Option<originsrv::OriginChannelPolicy>> {
+        let conn = self.pool.get(ocpu)?;
+        let policy = ocpu.get_policy();
+        let promote_role = if policy.has_promote_role() {
+            Some(policy.get_promote_role().to_string())
+        } else {
+            None
+        };
+        let prerequisite_channel = if policy.has_prerequisite_channel() {
+            Some(policy.get_prerequisite_channel().to_string())
+        } else {
+            None
+        };
+
+        let rows = conn.query(
+            "SELECT * FROM upsert_origin_channel_policy_v1($1, $2, $3, $4, $5)",
+            &[
+                &policy.get_origin_name(),
+                &policy.get_channel_name(),
+                &promote_role,
+                &prerequisite_channel,
+                &policy.get_require_job_success(),
+            ],
+        ).map_err(SrvError::OriginChannelPolicyUpdate)?;
+
+        if rows.len() == 0 {
+            return Ok(None);
+        }
+        let mut updated = policy.clone();
+        let channel_id: i64 = rows.get(0).get("channel_id");
+        updated.set_channel_id(channel_id as u64);
+        Ok(Some(updated))
+    }
+
+    fn row_to_origin_channel_policy(
+        &self,
+        row: &postgres::rows::Row,
+    ) -> SrvResult<originsrv::OriginChannelPolicy> {
+        let mut policy = originsrv::OriginChannelPolicy::new();
+        let channel_id: i64 = row.get("channel_id");
+        policy.set_channel_id(channel_id as u64);
+        policy.set_origin_name(row.get("origin_name"));
+        policy.set_channel_name(row.get("channel_name"));
+        if let Some(role) = row.get::<&str, Option<String>>("promote_role") {
+            policy.set_promote_role(role.parse().map_err(
+                SrvError::UnknownOriginMemberRole,
+            )?);
+        }
+        if let Some(channel) = row.get::<&str, Option<String>>("prerequisite_channel") {
+            policy.set_prerequisite_channel(channel);
+        }
+        policy.set_require_job_success(row.get("require_job_success"));
+        Ok(policy)
+    }
+
     pub fn promote_origin_package_group(
         &self,
         opp: &originsrv::OriginPackageGroupPromote,
@@ -1617,6 +1753,305 @@ impl DataStore {
         Ok(())
     }
 
+    /// Returns whether the account was a member of the origin.
+    pub fn update_origin_member_role(
+        &self,
+        omru: &originsrv::OriginMemberRoleUpdate,
+    ) -> SrvResult<bool> {
+        let conn = self.pool.get(omru)?;
+        let rows = conn.query(
+            "SELECT * FROM update_origin_member_role_v1($1, $2, $3)",
+            &[
+                &(omru.get_origin_id() as i64),
+                &omru.get_account_name(),
+                &omru.get_role().to_string(),
+            ],
+        ).map_err(SrvError::OriginMemberRoleUpdate)?;
+        Ok(rows.len() != 0)
+    }
+
+    pub fn create_origin_audit_event(
+        &self,
+        oaec: &originsrv::OriginAuditEventCreate,
+    ) -> SrvResult<()> {
+        let conn = self.pool.get(oaec)?;
+        let event = oaec.get_event();
+        let access_token_id = if event.has_access_token_id() {
+            Some(event.get_access_token_id() as i64)
+        } else {
+            None
+        };
+        let forwarded_for = if event.has_forwarded_for() {
+            Some(event.get_forwarded_for())
+        } else {
+            None
+        };
+
+        conn.execute(
+            "SELECT * FROM insert_origin_audit_event_v2($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+            &[
+                &event.get_origin_name(),
+                &(event.get_account_id() as i64),
+                &event.get_account_name(),
+                &event.get_action().to_string(),
+                &event.get_target(),
+                &event.get_detail(),
+                &event.get_remote_addr(),
+                &event.get_user_agent(),
+                &access_token_id,
+                &forwarded_for,
+            ],
+        ).map_err(SrvError::OriginAuditEventCreate)?;
+        Ok(())
+    }
+
+    pub fn list_origin_audit_events(
+        &self,
+        oael: &originsrv::OriginAuditEventListRequest,
+    ) -> SrvResult<originsrv::OriginAuditEventListResponse> {
+        let conn = self.pool.get(oael)?;
+        let action = if oael.has_action() {
+            oael.get_action().to_string()
+        } else {
+            String::new()
+        };
+
+        let rows = conn.query(
+            "SELECT * FROM get_origin_audit_events_v2($1, $2, $3, $4, $5, $6, $7, $8)",
+            &[
+                &oael.get_origin_name(),
+                &action,
+                &oael.get_account_name(),
+                &oael.get_target(),
+                &oael.get_since(),
+                &oael.get_until(),
+                &oael.limit(),
+                &(oael.get_start() as i64),
+            ],
+        ).map_err(SrvError::OriginAuditEventList)?;
+
+        let mut response = originsrv::OriginAuditEventListResponse::new();
+        response.set_origin_name(oael.get_origin_name().to_string());
+        response.set_start(oael.get_start());
+        response.set_stop(self.last_index(oael, &rows));
+        let mut events = protobuf::RepeatedField::new();
+        for row in rows.iter() {
+            let count: i64 = row.get("total_count");
+            response.set_count(count as u64);
+            events.push(self.row_to_origin_audit_event(&row)?);
+        }
+        response.set_events(events);
+        Ok(response)
+    }
+
+    fn row_to_origin_audit_event(
+        &self,
+        row: &postgres::rows::Row,
+    ) -> SrvResult<originsrv::OriginAuditEvent> {
+        let mut event = originsrv::OriginAuditEvent::new();
+        let id: i64 = row.get("id");
+        event.set_id(id as u64);
+        let origin_id: i64 = row.get("origin_id");
+        event.set_origin_id(origin_id as u64);
+        event.set_origin_name(row.get("origin_name"));
+        let account_id: i64 = row.get("account_id");
+        event.set_account_id(account_id as u64);
+        event.set_account_name(row.get("account_name"));
+        let action: String = row.get("action");
+        event.set_action(action.parse().map_err(
+            SrvError::UnknownOriginAuditAction,
+        )?);
+        event.set_target(row.get("target"));
+        event.set_detail(row.get("detail"));
+        event.set_remote_addr(row.get("remote_addr"));
+        event.set_user_agent(row.get("user_agent"));
+        if let Some(forwarded_for) = row.get::<&str, Option<String>>("forwarded_for") {
+            event.set_forwarded_for(forwarded_for);
+        }
+        if let Some(access_token_id) = row.get::<&str, Option<i64>>("access_token_id") {
+            event.set_access_token_id(access_token_id as u64);
+        }
+        event.set_created_at(
+            row.get::<&str, DateTime<UTC>>("created_at")
+                .to_rfc3339(),
+        );
+        Ok(event)
+    }
+
+    pub fn create_origin_webhook(
+        &self,
+        owc: &originsrv::OriginWebhookCreate,
+    ) -> SrvResult<Option<originsrv::OriginWebhook>> {
+        let conn = self.pool.get(owc)?;
+        let webhook = owc.get_webhook();
+        let secret = if webhook.get_secret().is_empty() {
+            webhooks::generate_secret()
+        } else {
+            webhook.get_secret().to_string()
+        };
+        let events: Vec<String> = webhook
+            .get_events()
+            .iter()
+            .map(|e| e.to_string())
+            .collect();
+
+        let rows = conn.query(
+            "SELECT * FROM insert_origin_webhook_v1($1, $2, $3, $4, $5)",
+            &[
+                &webhook.get_origin_name(),
+                &webhook.get_url(),
+                &secret,
+                &events,
+                &(webhook.get_owner_id() as i64),
+            ],
+        ).map_err(SrvError::OriginWebhookCreate)?;
+
+        if rows.len() == 0 {
+            return Ok(None);
+        }
+        let mut created = self.row_to_origin_webhook(&rows.get(0))?;
+        // The secret is only ever handed back once, when the webhook is created.
+        created.set_secret(secret);
+        Ok(Some(created))
+    }
+
+    pub fn list_origin_webhooks(
+        &self,
+        owl: &originsrv::OriginWebhookListRequest,
+    ) -> SrvResult<originsrv::OriginWebhookListResponse> {
+        let conn = self.pool.get(owl)?;
+        let rows = conn.query(
+            "SELECT * FROM get_origin_webhooks_v1($1)",
+            &[&owl.get_origin_name()],
+        ).map_err(SrvError::OriginWebhookList)?;
+
+        let mut response = originsrv::OriginWebhookListResponse::new();
+        response.set_origin_name(owl.get_origin_name().to_string());
+        let mut webhooks = protobuf::RepeatedField::new();
+        for row in rows.iter() {
+            webhooks.push(self.row_to_origin_webhook(&row)?);
+        }
+        response.set_webhooks(webhooks);
+        Ok(response)
+    }
+
+    pub fn delete_origin_webhook(&self, owd: &originsrv::OriginWebhookDelete) -> SrvResult<bool> {
+        let conn = self.pool.get(owd)?;
+        let rows = conn.query(
+            "SELECT * FROM delete_origin_webhook_v1($1, $2)",
+            &[&owd.get_origin_name(), &(owd.get_id() as i64)],
+        ).map_err(SrvError::OriginWebhookDelete)?;
+        Ok(rows.len() != 0)
+    }
+
+    /// Queues a delivery of the event to each of the origin's webhooks subscribed to it,
+    /// returning how many were queued. The deliveries themselves are made by the webhook
+    /// delivery thread.
+    pub fn trigger_origin_webhooks(&self, owt: &originsrv::OriginWebhookTrigger) -> SrvResult<usize> {
+        let conn = self.pool.get(owt)?;
+        let payload = serde_json::to_string(owt).unwrap();
+        let rows = conn.query(
+            "SELECT * FROM insert_origin_webhook_deliveries_v1($1, $2, $3)",
+            &[
+                &owt.get_origin_name(),
+                &owt.get_event().to_string(),
+                &payload,
+            ],
+        ).map_err(SrvError::OriginWebhookTrigger)?;
+        Ok(rows.len())
+    }
+
+    pub fn list_origin_webhook_deliveries(
+        &self,
+        owdl: &originsrv::OriginWebhookDeliveryListRequest,
+    ) -> SrvResult<originsrv::OriginWebhookDeliveryListResponse> {
+        let conn = self.pool.get(owdl)?;
+        let rows = conn.query(
+            "SELECT * FROM get_origin_webhook_deliveries_v1($1, $2, $3, $4)",
+            &[
+                &owdl.get_origin_name(),
+                &(owdl.get_webhook_id() as i64),
+                &owdl.limit(),
+                &(owdl.get_start() as i64),
+            ],
+        ).map_err(SrvError::OriginWebhookDeliveryList)?;
+
+        let mut response = originsrv::OriginWebhookDeliveryListResponse::new();
+        response.set_webhook_id(owdl.get_webhook_id());
+        response.set_start(owdl.get_start());
+        response.set_stop(self.last_index(owdl, &rows));
+        let mut deliveries = protobuf::RepeatedField::new();
+        for row in rows.iter() {
+            let count: i64 = row.get("total_count");
+            response.set_count(count as u64);
+            deliveries.push(self.row_to_origin_webhook_delivery(&row)?);
+        }
+        response.set_deliveries(deliveries);
+        Ok(response)
+    }
+
+    fn row_to_origin_webhook(
+        &self,
+        row: &postgres::rows::Row,
+    ) -> SrvResult<originsrv::OriginWebhook> {
+        let mut webhook = originsrv::OriginWebhook::new();
+        let id: i64 = row.get("id");
+        webhook.set_id(id as u64);
+        let origin_id: i64 = row.get("origin_id");
+        webhook.set_origin_id(origin_id as u64);
+        webhook.set_origin_name(row.get("origin_name"));
+        webhook.set_url(row.get("url"));
+        let events: Vec<String> = row.get("events");
+        let mut parsed = Vec::with_capacity(events.len());
+        for event in events.iter() {
+            parsed.push(event.parse().map_err(
+                SrvError::UnknownOriginWebhookEvent,
+            )?);
+        }
+        webhook.set_events(parsed);
+        let owner_id: i64 = row.get("owner_id");
+        webhook.set_owner_id(owner_id as u64);
+        webhook.set_created_at(
+            row.get::<&str, DateTime<UTC>>("created_at")
+                .to_rfc3339(),
+        );
+        Ok(webhook)
+    }
+
+    fn row_to_origin_webhook_delivery(
+        &self,
+        row: &postgres::rows::Row,
+    ) -> SrvResult<originsrv::OriginWebhookDelivery> {
+        let mut delivery = originsrv::OriginWebhookDelivery::new();
+        let id: i64 = row.get("id");
+        delivery.set_id(id as u64);
+        let webhook_id: i64 = row.get("webhook_id");
+        delivery.set_webhook_id(webhook_id as u64);
+        let event: String = row.get("event");
+        delivery.set_event(event.parse().map_err(
+            SrvError::UnknownOriginWebhookEvent,
+        )?);
+        delivery.set_payload(row.get("payload"));
+        let state: String = row.get("state");
+        delivery.set_state(state.parse().map_err(
+            SrvError::UnknownOriginWebhookDeliveryState,
+        )?);
+        let attempts: i32 = row.get("attempts");
+        delivery.set_attempts(attempts as u32);
+        let response_code: i32 = row.get("response_code");
+        delivery.set_response_code(response_code as u32);
+        delivery.set_error(row.get("error"));
+        delivery.set_created_at(
+            row.get::<&str, DateTime<UTC>>("created_at")
+                .to_rfc3339(),
+        );
+        delivery.set_updated_at(
+            row.get::<&str, DateTime<UTC>>("updated_at")
+                .to_rfc3339(),
+        );
+        Ok(delivery)
+    }
+
     fn rows_to_origin_integration_names(
         &self,
         rows: &postgres::rows::Rows,
@@ -1764,3 +2199,53 @@ fn sync_invitations(pool: Pool, mut route_conn: RouteClient) -> DbResult<EventOu
     }
     Ok(result)
 }
+
+/// Makes an attempt at each webhook delivery which is due, on every shard. Deliveries are claimed
+/// one at a time and leased for long enough that another originsrv polling the same shards won't
+/// pick them up while they're in flight.
+pub fn deliver_origin_webhooks(pool: &Pool) -> SrvResult<()> {
+    for shard in pool.shards.iter() {
+        let conn = pool.get_shard(*shard)?;
+        for _ in 0..webhooks::BATCH_SIZE {
+            let rows = conn.query(
+                "SELECT * FROM claim_origin_webhook_deliveries_v1($1, $2)",
+                &[&1i64, &webhooks::LEASE_SECS],
+            ).map_err(SrvError::OriginWebhookDeliveryClaim)?;
+            if rows.is_empty() {
+                break;
+            }
+            let row = rows.get(0);
+            let id: i64 = row.get("id");
+            let event: String = row.get("event");
+            let payload: String = row.get("payload");
+            let url: String = row.get("url");
+            let secret: String = row.get("secret");
+            let attempts: i32 = row.get::<&str, i32>("attempts") + 1;
+
+            let (state, response_code, error, retry_in) =
+                match webhooks::deliver(&url, &event, id as u64, &secret, &payload) {
+                    Ok(status) => ("delivered", status, String::new(), 0),
+                    Err((status, err)) => {
+                        if attempts >= webhooks::MAX_ATTEMPTS {
+                            warn!(
+                                "Giving up on webhook delivery {} to {} after {} attempts, {}",
+                                id,
+                                url,
+                                attempts,
+                                err
+                            );
+                            ("failed", status, err, 0)
+                        } else {
+                            debug!("Webhook delivery {} to {} failed, {}", id, url, err);
+                            ("pending", status, err, webhooks::backoff(attempts))
+                        }
+                    }
+                };
+            conn.execute(
+                "SELECT * FROM update_origin_webhook_delivery_v1($1, $2, $3, $4, $5)",
+                &[&id, &state, &(response_code as i32), &error, &retry_in],
+            ).map_err(SrvError::OriginWebhookDeliveryUpdate)?;
+        }
+    }
+    Ok(())
+}
@@ -40,9 +40,13 @@ pub enum SrvError {
     HabitatCore(hab_core::Error),
     MyOrigins(postgres::error::Error),
     NetError(hab_net::NetError),
+    OriginAuditEventCreate(postgres::error::Error),
+    OriginAuditEventList(postgres::error::Error),
     OriginCreate(postgres::error::Error),
     OriginChannelCreate(postgres::error::Error),
     OriginChannelGet(postgres::error::Error),
+    OriginChannelPolicyGet(postgres::error::Error),
+    OriginChannelPolicyUpdate(postgres::error::Error),
     OriginChannelList(postgres::error::Error),
     OriginChannelDelete(postgres::error::Error),
     OriginChannelPackageGet(postgres::error::Error),
@@ -64,6 +68,7 @@ pub enum SrvError {
     OriginInvitationListForAccount(postgres::error::Error),
     OriginInvitationValidate(postgres::error::Error),
     OriginMemberDelete(postgres::error::Error),
+    OriginMemberRoleUpdate(postgres::error::Error),
     OriginPackageCreate(postgres::error::Error),
     OriginPackageGet(postgres::error::Error),
     OriginPackageLatestGet(postgres::error::Error),
@@ -72,6 +77,8 @@ pub enum SrvError {
     OriginPackageList(postgres::error::Error),
     OriginPackageVersionList(postgres::error::Error),
     OriginPackageGroupDemote(postgres::error::Error),
+    OriginPackageDelete(postgres::error::Error),
+    OriginPackageDependentList(postgres::error::Error),
     OriginPackageDemote(postgres::error::Error),
     OriginPackageGroupPromote(postgres::error::Error),
     OriginPackagePromote(postgres::error::Error),
@@ -94,13 +101,24 @@ pub enum SrvError {
     OriginPublicKeyLatestGet(postgres::error::Error),
     OriginPublicKeyListForOrigin(postgres::error::Error),
     OriginUpdate(postgres::error::Error),
+    OriginWebhookCreate(postgres::error::Error),
+    OriginWebhookDelete(postgres::error::Error),
+    OriginWebhookDeliveryClaim(postgres::error::Error),
+    OriginWebhookDeliveryList(postgres::error::Error),
+    OriginWebhookDeliveryUpdate(postgres::error::Error),
+    OriginWebhookList(postgres::error::Error),
+    OriginWebhookTrigger(postgres::error::Error),
     OriginAccountList(postgres::error::Error),
     OriginAccountInOrigin(postgres::error::Error),
     Protocol(protocol::ProtocolError),
     SyncInvitations(postgres::error::Error),
     SyncInvitationsUpdate(postgres::error::Error),
     Protobuf(protobuf::ProtobufError),
+    UnknownOriginAuditAction(protocol::originsrv::Error),
+    UnknownOriginMemberRole(protocol::originsrv::Error),
     UnknownOriginPackageVisibility(protocol::originsrv::Error),
+    UnknownOriginWebhookDeliveryState(protocol::originsrv::Error),
+    UnknownOriginWebhookEvent(protocol::originsrv::Error),
     VisibilityCascade(postgres::error::Error),
 }
 
@@ -126,6 +144,12 @@ impl fmt::Display for SrvError {
             SrvError::HabitatCore(ref e) => format!("{}", e),
             SrvError::MyOrigins(ref e) => format!("Error looking up my origins, {}", e),
             SrvError::NetError(ref e) => format!("{}", e),
+            SrvError::OriginAuditEventCreate(ref e) => {
+                format!("Error recording origin audit event in database, {}", e)
+            }
+            SrvError::OriginAuditEventList(ref e) => {
+                format!("Error listing origin audit events from database, {}", e)
+            }
             SrvError::OriginCreate(ref e) => format!("Error creating origin in database, {}", e),
             SrvError::OriginChannelCreate(ref e) => {
                 format!("Error creating channel in database, {}", e)
@@ -133,6 +157,12 @@ impl fmt::Display for SrvError {
             SrvError::OriginChannelGet(ref e) => {
                 format!("Error getting channel from database, {}", e)
             }
+            SrvError::OriginChannelPolicyGet(ref e) => {
+                format!("Error retrieving channel policy from database, {}", e)
+            }
+            SrvError::OriginChannelPolicyUpdate(ref e) => {
+                format!("Error updating channel policy in database, {}", e)
+            }
             SrvError::OriginChannelList(ref e) => {
                 format!("Error listing channels for an origin from database, {}", e)
             }
@@ -209,6 +239,9 @@ impl fmt::Display for SrvError {
             SrvError::OriginMemberDelete(ref e) => {
                 format!("Error deleting member of origin in database, {}", e)
             }
+            SrvError::OriginMemberRoleUpdate(ref e) => {
+                format!("Error updating role of origin member in database, {}", e)
+            }
             SrvError::OriginPackageGet(ref e) => {
                 format!("Error getting package in database, {}", e)
             }
@@ -233,6 +266,12 @@ impl fmt::Display for SrvError {
             SrvError::OriginPackageGroupDemote(ref e) => {
                 format!("Error demoting package group from channel, {}", e)
             }
+            SrvError::OriginPackageDelete(ref e) => {
+                format!("Error deleting a package in this origin, {}", e)
+            }
+            SrvError::OriginPackageDependentList(ref e) => {
+                format!("Error listing the dependents of a package, {}", e)
+            }
             SrvError::OriginPackageDemote(ref e) => {
                 format!("Error demoting package from channel, {}", e)
             }
@@ -322,8 +361,33 @@ impl fmt::Display for SrvError {
                 format!("Error update invitation sync for account, {}", e)
             }
             SrvError::OriginUpdate(ref e) => format!("Error updating origin, {}", e),
+            SrvError::OriginWebhookCreate(ref e) => {
+                format!("Error creating origin webhook in database, {}", e)
+            }
+            SrvError::OriginWebhookDelete(ref e) => {
+                format!("Error deleting origin webhook from database, {}", e)
+            }
+            SrvError::OriginWebhookDeliveryClaim(ref e) => {
+                format!("Error claiming due webhook deliveries from database, {}", e)
+            }
+            SrvError::OriginWebhookDeliveryList(ref e) => {
+                format!("Error listing webhook deliveries from database, {}", e)
+            }
+            SrvError::OriginWebhookDeliveryUpdate(ref e) => {
+                format!("Error updating webhook delivery in database, {}", e)
+            }
+            SrvError::OriginWebhookList(ref e) => {
+                format!("Error listing origin webhooks from database, {}", e)
+            }
+            SrvError::OriginWebhookTrigger(ref e) => {
+                format!("Error queueing webhook deliveries in database, {}", e)
+            }
             SrvError::Protobuf(ref e) => format!("{}", e),
+            SrvError::UnknownOriginAuditAction(ref e) => format!("{}", e),
+            SrvError::UnknownOriginMemberRole(ref e) => format!("{}", e),
             SrvError::UnknownOriginPackageVisibility(ref e) => format!("{}", e),
+            SrvError::UnknownOriginWebhookDeliveryState(ref e) => format!("{}", e),
+            SrvError::UnknownOriginWebhookEvent(ref e) => format!("{}", e),
             SrvError::VisibilityCascade(ref e) => format!("{}", e),
         };
         write!(f, "{}", msg)
@@ -346,9 +410,13 @@ impl error::Error for SrvError {
             SrvError::HabitatCore(ref err) => err.description(),
             SrvError::MyOrigins(ref err) => err.description(),
             SrvError::NetError(ref err) => err.description(),
+            SrvError::OriginAuditEventCreate(ref err) => err.description(),
+            SrvError::OriginAuditEventList(ref err) => err.description(),
             SrvError::OriginCreate(ref err) => err.description(),
             SrvError::OriginChannelCreate(ref err) => err.description(),
             SrvError::OriginChannelGet(ref err) => err.description(),
+            SrvError::OriginChannelPolicyGet(ref err) => err.description(),
+            SrvError::OriginChannelPolicyUpdate(ref err) => err.description(),
             SrvError::OriginChannelList(ref err) => err.description(),
             SrvError::OriginChannelPackageGet(ref err) => err.description(),
             SrvError::OriginChannelPackageLatestGet(ref err) => err.description(),
@@ -370,6 +438,7 @@ impl error::Error for SrvError {
             SrvError::OriginInvitationListForAccount(ref err) => err.description(),
             SrvError::OriginInvitationValidate(ref err) => err.description(),
             SrvError::OriginMemberDelete(ref err) => err.description(),
+            SrvError::OriginMemberRoleUpdate(ref err) => err.description(),
             SrvError::OriginPackageCreate(ref err) => err.description(),
             SrvError::OriginPackageGet(ref err) => err.description(),
             SrvError::OriginPackageLatestGet(ref err) => err.description(),
@@ -378,6 +447,8 @@ impl error::Error for SrvError {
             SrvError::OriginPackageList(ref err) => err.description(),
             SrvError::OriginPackageVersionList(ref err) => err.description(),
             SrvError::OriginPackageGroupDemote(ref err) => err.description(),
+            SrvError::OriginPackageDelete(ref err) => err.description(),
+            SrvError::OriginPackageDependentList(ref err) => err.description(),
             SrvError::OriginPackageDemote(ref err) => err.description(),
             SrvError::OriginPackageGroupPromote(ref err) => err.description(),
             SrvError::OriginPackagePromote(ref err) => err.description(),
@@ -402,11 +473,22 @@ impl error::Error for SrvError {
             SrvError::OriginAccountList(ref err) => err.description(),
             SrvError::OriginAccountInOrigin(ref err) => err.description(),
             SrvError::OriginUpdate(ref err) => err.description(),
+            SrvError::OriginWebhookCreate(ref err) => err.description(),
+            SrvError::OriginWebhookDelete(ref err) => err.description(),
+            SrvError::OriginWebhookDeliveryClaim(ref err) => err.description(),
+            SrvError::OriginWebhookDeliveryList(ref err) => err.description(),
+            SrvError::OriginWebhookDeliveryUpdate(ref err) => err.description(),
+            SrvError::OriginWebhookList(ref err) => err.description(),
+            SrvError::OriginWebhookTrigger(ref err) => err.description(),
             SrvError::Protocol(ref err) => err.description(),
             SrvError::SyncInvitations(ref err) => err.description(),
             SrvError::SyncInvitationsUpdate(ref err) => err.description(),
             SrvError::Protobuf(ref err) => err.description(),
+            SrvError::UnknownOriginAuditAction(ref err) => err.description(),
+            SrvError::UnknownOriginMemberRole(ref err) => err.description(),
             SrvError::UnknownOriginPackageVisibility(ref err) => err.description(),
+            SrvError::UnknownOriginWebhookDeliveryState(ref err) => err.description(),
+            SrvError::UnknownOriginWebhookEvent(ref err) => err.description(),
             SrvError::VisibilityCascade(ref err) => err.description(),
         }
     }
@@ -16,20 +16,27 @@
 #![cfg_attr(feature="clippy", plugin(clippy))]
 
 extern crate builder_core as bldr_core;
+extern crate chrono;
 extern crate habitat_builder_db as db;
 extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hab_core;
+extern crate habitat_http_client as http_client;
 extern crate habitat_net as hab_net;
+extern crate hex;
+#[macro_use]
+extern crate hyper;
 #[macro_use]
 extern crate lazy_static;
 #[macro_use]
 extern crate log;
+extern crate openssl;
 extern crate postgres;
 extern crate protobuf;
 extern crate r2d2;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+extern crate serde_json;
 extern crate toml;
 extern crate zmq;
 
@@ -38,6 +45,7 @@ pub mod data_store;
 pub mod error;
 pub mod migrations;
 pub mod server;
+pub mod webhooks;
 
 pub use self::config::Config;
 pub use self::error::{SrvError, SrvResult};
@@ -21,3 +21,6 @@ pub mod origin_integrations;
 pub mod origin_projects;
 pub mod origin_packages;
 pub mod origin_channels;
+pub mod origin_channel_policies;
+pub mod origin_audit_events;
+pub mod origin_webhooks;
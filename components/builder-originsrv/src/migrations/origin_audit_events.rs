@@ -0,0 +1,217 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::SrvResult;
+
+pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE SEQUENCE IF NOT EXISTS origin_audit_event_id_seq;"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE TABLE IF NOT EXISTS origin_audit_events (
+                        id bigint PRIMARY KEY DEFAULT next_id_v1('origin_audit_event_id_seq'),
+                        origin_id bigint REFERENCES origins(id),
+                        origin_name text,
+                        account_id bigint,
+                        account_name text,
+                        action text,
+                        target text,
+                        detail text,
+                        remote_addr text,
+                        user_agent text,
+                        access_token_id bigint,
+                        created_at timestamptz DEFAULT now()
+                        )"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE INDEX IF NOT EXISTS origin_audit_events_origin_name_created_at_idx
+                        ON origin_audit_events (origin_name, created_at DESC)"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_origin_audit_event_v1 (
+                        oae_origin_name text,
+                        oae_account_id bigint,
+                        oae_account_name text,
+                        oae_action text,
+                        oae_target text,
+                        oae_detail text,
+                        oae_remote_addr text,
+                        oae_user_agent text,
+                        oae_access_token_id bigint
+                 ) RETURNS SETOF origin_audit_events AS $$
+                        INSERT INTO origin_audit_events (
+                                      origin_id,
+                                      origin_name,
+                                      account_id,
+                                      account_name,
+                                      action,
+                                      target,
+                                      detail,
+                                      remote_addr,
+                                      user_agent,
+                                      access_token_id)
+                        SELECT id,
+                               name,
+                               oae_account_id,
+                               oae_account_name,
+                               oae_action,
+                               oae_target,
+                               oae_detail,
+                               oae_remote_addr,
+                               oae_user_agent,
+                               oae_access_token_id
+                        FROM origins WHERE name = oae_origin_name
+                        RETURNING *
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_audit_events_v1 (
+                        oae_origin_name text,
+                        oae_action text,
+                        oae_account_name text,
+                        oae_target text,
+                        oae_since text,
+                        oae_until text,
+                        oae_limit bigint,
+                        oae_offset bigint
+                 ) RETURNS TABLE(total_count bigint,
+                                 id bigint,
+                                 origin_id bigint,
+                                 origin_name text,
+                                 account_id bigint,
+                                 account_name text,
+                                 action text,
+                                 target text,
+                                 detail text,
+                                 remote_addr text,
+                                 user_agent text,
+                                 access_token_id bigint,
+                                 created_at timestamptz) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, e.id, e.origin_id,
+                               e.origin_name, e.account_id, e.account_name, e.action, e.target,
+                               e.detail, e.remote_addr, e.user_agent, e.access_token_id,
+                               e.created_at
+                          FROM origin_audit_events e
+                          WHERE e.origin_name = oae_origin_name
+                          AND (oae_action = '' OR e.action = oae_action)
+                          AND (oae_account_name = '' OR e.account_name = oae_account_name)
+                          AND (oae_target = '' OR e.target LIKE (oae_target || '%'))
+                          AND (oae_since = '' OR e.created_at >= oae_since::timestamptz)
+                          AND (oae_until = '' OR e.created_at < oae_until::timestamptz)
+                          ORDER BY e.created_at DESC, e.id DESC
+                          LIMIT oae_limit
+                          OFFSET oae_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE origin_audit_events ADD COLUMN IF NOT EXISTS forwarded_for text"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_origin_audit_event_v2 (
+                        oae_origin_name text,
+                        oae_account_id bigint,
+                        oae_account_name text,
+                        oae_action text,
+                        oae_target text,
+                        oae_detail text,
+                        oae_remote_addr text,
+                        oae_user_agent text,
+                        oae_access_token_id bigint,
+                        oae_forwarded_for text
+                 ) RETURNS SETOF origin_audit_events AS $$
+                        INSERT INTO origin_audit_events (
+                                      origin_id,
+                                      origin_name,
+                                      account_id,
+                                      account_name,
+                                      action,
+                                      target,
+                                      detail,
+                                      remote_addr,
+                                      user_agent,
+                                      access_token_id,
+                                      forwarded_for)
+                        SELECT id,
+                               name,
+                               oae_account_id,
+                               oae_account_name,
+                               oae_action,
+                               oae_target,
+                               oae_detail,
+                               oae_remote_addr,
+                               oae_user_agent,
+                               oae_access_token_id,
+                               oae_forwarded_for
+                        FROM origins WHERE name = oae_origin_name
+                        RETURNING *
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_audit_events_v2 (
+                        oae_origin_name text,
+                        oae_action text,
+                        oae_account_name text,
+                        oae_target text,
+                        oae_since text,
+                        oae_until text,
+                        oae_limit bigint,
+                        oae_offset bigint
+                 ) RETURNS TABLE(total_count bigint,
+                                 id bigint,
+                                 origin_id bigint,
+                                 origin_name text,
+                                 account_id bigint,
+                                 account_name text,
+                                 action text,
+                                 target text,
+                                 detail text,
+                                 remote_addr text,
+                                 user_agent text,
+                                 access_token_id bigint,
+                                 forwarded_for text,
+                                 created_at timestamptz) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, e.id, e.origin_id,
+                               e.origin_name, e.account_id, e.account_name, e.action, e.target,
+                               e.detail, e.remote_addr, e.user_agent, e.access_token_id,
+                               e.forwarded_for, e.created_at
+                          FROM origin_audit_events e
+                          WHERE e.origin_name = oae_origin_name
+                          AND (oae_action = '' OR e.action = oae_action)
+                          AND (oae_account_name = '' OR e.account_name = oae_account_name)
+                          AND (oae_target = '' OR e.target LIKE (oae_target || '%'))
+                          AND (oae_since = '' OR e.created_at >= oae_since::timestamptz)
+                          AND (oae_until = '' OR e.created_at < oae_until::timestamptz)
+                          ORDER BY e.created_at DESC, e.id DESC
+                          LIMIT oae_limit
+                          OFFSET oae_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,77 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::SrvResult;
+
+pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE TABLE IF NOT EXISTS origin_channel_policies (
+                        channel_id bigint PRIMARY KEY REFERENCES origin_channels(id) ON DELETE CASCADE,
+                        promote_role text,
+                        prerequisite_channel text,
+                        require_job_success bool DEFAULT false,
+                        created_at timestamptz DEFAULT now(),
+                        updated_at timestamptz DEFAULT now()
+                        )"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_channel_policy_v1 (
+                        ocp_origin_name text,
+                        ocp_channel_name text
+                 ) RETURNS TABLE(channel_id bigint,
+                                 origin_name text,
+                                 channel_name text,
+                                 promote_role text,
+                                 prerequisite_channel text,
+                                 require_job_success bool) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT c.id, o.name, c.name, p.promote_role,
+                               p.prerequisite_channel, COALESCE(p.require_job_success, false)
+                          FROM origins o
+                          INNER JOIN origin_channels c ON c.origin_id = o.id
+                          LEFT JOIN origin_channel_policies p ON p.channel_id = c.id
+                          WHERE o.name = ocp_origin_name AND c.name = ocp_channel_name;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION upsert_origin_channel_policy_v1 (
+                        ocp_origin_name text,
+                        ocp_channel_name text,
+                        ocp_promote_role text,
+                        ocp_prerequisite_channel text,
+                        ocp_require_job_success bool
+                 ) RETURNS SETOF origin_channel_policies AS $$
+                        INSERT INTO origin_channel_policies (channel_id, promote_role,
+                                                             prerequisite_channel, require_job_success)
+                        SELECT c.id, ocp_promote_role, ocp_prerequisite_channel, ocp_require_job_success
+                          FROM origins o
+                          INNER JOIN origin_channels c ON c.origin_id = o.id
+                          WHERE o.name = ocp_origin_name AND c.name = ocp_channel_name
+                        ON CONFLICT (channel_id) DO UPDATE SET
+                            promote_role = EXCLUDED.promote_role,
+                            prerequisite_channel = EXCLUDED.prerequisite_channel,
+                            require_job_success = EXCLUDED.require_job_success,
+                            updated_at = now()
+                        RETURNING *
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    Ok(())
+}
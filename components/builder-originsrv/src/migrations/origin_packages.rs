@@ -734,5 +734,133 @@ pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
         "originsrv",
         r#"UPDATE origin_packages SET scheduler_sync = false "#,
     )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE IF EXISTS origin_packages ADD COLUMN IF NOT EXISTS yanked bool NOT NULL DEFAULT false"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION update_origin_package_v2 (
+                op_id bigint,
+                op_owner_id bigint,
+                op_name text,
+                op_ident text,
+                op_checksum text,
+                op_manifest text,
+                op_config text,
+                op_target text,
+                op_deps text,
+                op_tdeps text,
+                op_exposes text,
+                op_visibility text,
+                op_yanked bool
+                 ) RETURNS void AS $$
+                    UPDATE origin_packages SET
+                        owner_id = op_owner_id,
+                        name = op_name,
+                        ident = op_ident,
+                        checksum = op_checksum,
+                        manifest = op_manifest,
+                        config = op_config,
+                        target = op_target,
+                        deps = op_deps,
+                        tdeps = op_tdeps,
+                        exposes = op_exposes,
+                        visibility = op_visibility,
+                        yanked = op_yanked,
+                        scheduler_sync = false,
+                        updated_at = now()
+                        WHERE id = op_id;
+                    $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"DROP FUNCTION IF EXISTS update_origin_package_v1(bigint, bigint, text, text, text, text, text, text, text, text, text, text)"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_package_latest_v6 (
+                    op_ident text,
+                    op_target text,
+                    op_visibilities text
+                 ) RETURNS SETOF origin_packages AS $$
+                    BEGIN
+                        RETURN QUERY SELECT *
+                        FROM origin_packages
+                        WHERE ident LIKE (op_ident  || '%')
+                        AND target = op_target
+                        AND visibility = ANY(STRING_TO_ARRAY(op_visibilities, ','))
+                        AND yanked = false;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"DROP FUNCTION IF EXISTS get_origin_package_latest_v5(text, text, text)"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION delete_origin_package_v1 (
+                    op_id bigint
+                 ) RETURNS void AS $$
+                    DELETE FROM origin_channel_packages WHERE package_id = op_id;
+                    DELETE FROM origin_packages WHERE id = op_id;
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_package_dependents_v1 (
+                    op_ident text
+                 ) RETURNS TABLE(ident text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT origin_packages.ident
+                        FROM origin_packages
+                        WHERE op_ident = ANY(STRING_TO_ARRAY(deps, ':'))
+                        OR op_ident = ANY(STRING_TO_ARRAY(tdeps, ':'))
+                        ORDER BY origin_packages.ident ASC;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE IF EXISTS origin_packages ADD COLUMN IF NOT EXISTS is_service bool"#,
+    )?;
+    migrator.migrate("originsrv",
+                 r#"CREATE OR REPLACE FUNCTION insert_origin_package_v4 (
+                    op_origin_id bigint,
+                    op_owner_id bigint,
+                    op_name text,
+                    op_ident text,
+                    op_checksum text,
+                    op_manifest text,
+                    op_config text,
+                    op_target text,
+                    op_deps text,
+                    op_tdeps text,
+                    op_exposes text,
+                    op_visibility text,
+                    op_is_service bool
+                 ) RETURNS SETOF origin_packages AS $$
+                     DECLARE
+                        inserted_package origin_packages;
+                        channel_id bigint;
+                     BEGIN
+                         INSERT INTO origin_packages (origin_id, owner_id, name, ident, checksum, manifest, config, target, deps, tdeps, exposes, visibility, is_service)
+                                VALUES (op_origin_id, op_owner_id, op_name, op_ident, op_checksum, op_manifest, op_config, op_target, op_deps, op_tdeps, op_exposes, op_visibility, op_is_service)
+                                RETURNING * into inserted_package;
+
+                         SELECT id FROM origin_channels WHERE origin_id = op_origin_id AND name = 'unstable' INTO channel_id;
+                         PERFORM promote_origin_package_v1(channel_id, inserted_package.id);
+
+                         RETURN NEXT inserted_package;
+                         RETURN;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator.migrate(
+        "originsrv",
+        r#"DROP FUNCTION IF EXISTS insert_origin_package_v3(bigint, bigint, text, text, text, text, text, text, text, text, text, text)"#,
+    )?;
     Ok(())
 }
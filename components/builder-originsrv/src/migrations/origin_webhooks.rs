@@ -0,0 +1,196 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::SrvResult;
+
+pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE SEQUENCE IF NOT EXISTS origin_webhook_id_seq;"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE TABLE IF NOT EXISTS origin_webhooks (
+                        id bigint PRIMARY KEY DEFAULT next_id_v1('origin_webhook_id_seq'),
+                        origin_id bigint REFERENCES origins(id),
+                        origin_name text,
+                        url text,
+                        secret text,
+                        events text[],
+                        owner_id bigint,
+                        created_at timestamptz DEFAULT now(),
+                        updated_at timestamptz
+                        )"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE SEQUENCE IF NOT EXISTS origin_webhook_delivery_id_seq;"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE TABLE IF NOT EXISTS origin_webhook_deliveries (
+                        id bigint PRIMARY KEY DEFAULT next_id_v1('origin_webhook_delivery_id_seq'),
+                        webhook_id bigint REFERENCES origin_webhooks(id) ON DELETE CASCADE,
+                        event text,
+                        payload text,
+                        state text DEFAULT 'pending',
+                        attempts integer DEFAULT 0,
+                        response_code integer DEFAULT 0,
+                        error text DEFAULT '',
+                        next_attempt_at timestamptz DEFAULT now(),
+                        created_at timestamptz DEFAULT now(),
+                        updated_at timestamptz DEFAULT now()
+                        )"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE INDEX IF NOT EXISTS origin_webhook_deliveries_pending_idx
+                        ON origin_webhook_deliveries (next_attempt_at) WHERE state = 'pending'"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_origin_webhook_v1 (
+                        ow_origin_name text,
+                        ow_url text,
+                        ow_secret text,
+                        ow_events text[],
+                        ow_owner_id bigint
+                 ) RETURNS SETOF origin_webhooks AS $$
+                        INSERT INTO origin_webhooks (origin_id, origin_name, url, secret, events, owner_id)
+                        SELECT id, name, ow_url, ow_secret, ow_events, ow_owner_id
+                        FROM origins WHERE name = ow_origin_name
+                        RETURNING *
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_webhooks_v1 (
+                        ow_origin_name text
+                 ) RETURNS SETOF origin_webhooks AS $$
+                        SELECT * FROM origin_webhooks
+                        WHERE origin_name = ow_origin_name
+                        ORDER BY id
+                 $$ LANGUAGE SQL STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION delete_origin_webhook_v1 (
+                        ow_origin_name text,
+                        ow_id bigint
+                 ) RETURNS SETOF origin_webhooks AS $$
+                        DELETE FROM origin_webhooks
+                        WHERE origin_name = ow_origin_name AND id = ow_id
+                        RETURNING *
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_origin_webhook_deliveries_v1 (
+                        owd_origin_name text,
+                        owd_event text,
+                        owd_payload text
+                 ) RETURNS SETOF origin_webhook_deliveries AS $$
+                        INSERT INTO origin_webhook_deliveries (webhook_id, event, payload)
+                        SELECT id, owd_event, owd_payload
+                        FROM origin_webhooks
+                        WHERE origin_name = owd_origin_name AND owd_event = ANY(events)
+                        RETURNING *
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_webhook_deliveries_v1 (
+                        owd_origin_name text,
+                        owd_webhook_id bigint,
+                        owd_limit bigint,
+                        owd_offset bigint
+                 ) RETURNS TABLE(total_count bigint,
+                                 id bigint,
+                                 webhook_id bigint,
+                                 event text,
+                                 payload text,
+                                 state text,
+                                 attempts integer,
+                                 response_code integer,
+                                 error text,
+                                 created_at timestamptz,
+                                 updated_at timestamptz) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, d.id, d.webhook_id,
+                               d.event, d.payload, d.state, d.attempts, d.response_code, d.error,
+                               d.created_at, d.updated_at
+                          FROM origin_webhook_deliveries d
+                          INNER JOIN origin_webhooks w ON w.id = d.webhook_id
+                          WHERE w.origin_name = owd_origin_name AND d.webhook_id = owd_webhook_id
+                          ORDER BY d.created_at DESC, d.id DESC
+                          LIMIT owd_limit
+                          OFFSET owd_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION claim_origin_webhook_deliveries_v1 (
+                        owd_limit bigint,
+                        owd_lease_secs bigint
+                 ) RETURNS TABLE(id bigint,
+                                 event text,
+                                 payload text,
+                                 attempts integer,
+                                 url text,
+                                 secret text) AS $$
+                    BEGIN
+                        RETURN QUERY WITH due AS (
+                            SELECT d.id FROM origin_webhook_deliveries d
+                            WHERE d.state = 'pending' AND d.next_attempt_at <= now()
+                            ORDER BY d.next_attempt_at
+                            LIMIT owd_limit
+                            FOR UPDATE SKIP LOCKED
+                        ), claimed AS (
+                            UPDATE origin_webhook_deliveries d
+                            SET next_attempt_at = now() + (owd_lease_secs * interval '1 second')
+                            FROM due WHERE d.id = due.id
+                            RETURNING d.id, d.webhook_id, d.event, d.payload, d.attempts
+                        )
+                        SELECT c.id, c.event, c.payload, c.attempts, w.url, w.secret
+                          FROM claimed c
+                          INNER JOIN origin_webhooks w ON w.id = c.webhook_id;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION update_origin_webhook_delivery_v1 (
+                        owd_id bigint,
+                        owd_state text,
+                        owd_response_code integer,
+                        owd_error text,
+                        owd_retry_in_secs bigint
+                 ) RETURNS void AS $$
+                        UPDATE origin_webhook_deliveries SET
+                            state = owd_state,
+                            attempts = attempts + 1,
+                            response_code = owd_response_code,
+                            error = owd_error,
+                            next_attempt_at = now() + (owd_retry_in_secs * interval '1 second'),
+                            updated_at = now()
+                        WHERE id = owd_id
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    Ok(())
+}
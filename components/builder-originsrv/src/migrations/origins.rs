@@ -178,5 +178,97 @@ pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
                     END;
                  $$ LANGUAGE plpgsql STABLE"#,
     )?;
+    // Members which joined before roles existed keep the rights they had, except for managing
+    // keys, integrations and members which is left to the origin's owner. Anyone joining from
+    // now on starts out as a developer.
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE IF EXISTS origin_members ADD COLUMN IF NOT EXISTS role text NOT NULL DEFAULT 'maintainer'"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"UPDATE origin_members SET role = 'owner' FROM origins
+                 WHERE origins.id = origin_members.origin_id AND origins.owner_id = origin_members.account_id"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE IF EXISTS origin_members ALTER COLUMN role SET DEFAULT 'developer'"#,
+    )?;
+    migrator.migrate("originsrv",
+                     r#"CREATE OR REPLACE FUNCTION insert_origin_member_v2 (
+                     om_origin_id bigint,
+                     om_origin_name text,
+                     om_account_id bigint,
+                     om_account_name text,
+                     om_role text
+                 ) RETURNS void AS $$
+                     BEGIN
+                         INSERT INTO origin_members (origin_id, origin_name, account_id, account_name, role)
+                                VALUES (om_origin_id, om_origin_name, om_account_id, om_account_name, om_role);
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator.migrate("originsrv",
+                     r#"CREATE OR REPLACE FUNCTION insert_origin_v3 (
+                     origin_name text,
+                     origin_owner_id bigint,
+                     origin_owner_name text,
+                     origin_default_package_visibility text
+                 ) RETURNS SETOF origins AS $$
+                     DECLARE
+                       inserted_origin origins;
+                     BEGIN
+                         INSERT INTO origins (name, owner_id, default_package_visibility)
+                                VALUES (origin_name, origin_owner_id, origin_default_package_visibility) RETURNING * into inserted_origin;
+                         PERFORM insert_origin_member_v2(inserted_origin.id, origin_name, origin_owner_id, origin_owner_name, 'owner');
+                         PERFORM insert_origin_channel_v1(inserted_origin.id, origin_owner_id, 'unstable');
+                         PERFORM insert_origin_channel_v1(inserted_origin.id, origin_owner_id, 'stable');
+                         RETURN NEXT inserted_origin;
+                         RETURN;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator.migrate(
+        "originsrv",
+        r#"DROP FUNCTION IF EXISTS insert_origin_v2(text, bigint, text, text)"#,
+    )?;
+    migrator.migrate("originsrv",
+                     r#"CREATE OR REPLACE FUNCTION list_origin_members_v2 (
+                   om_origin_id bigint
+                 ) RETURNS TABLE(account_id bigint, account_name text, role text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT origin_members.account_id, origin_members.account_name, origin_members.role
+                          FROM origin_members WHERE origin_id = om_origin_id
+                          ORDER BY account_name ASC;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate(
+        "originsrv",
+        r#"DROP FUNCTION IF EXISTS list_origin_members_v1(bigint)"#,
+    )?;
+    migrator.migrate("originsrv",
+                     r#"CREATE OR REPLACE FUNCTION get_origin_member_role_v1 (
+                   om_origin_name text,
+                   om_account_id bigint
+                 ) RETURNS TABLE(role text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT origin_members.role FROM origin_members
+                          WHERE origin_name = om_origin_name AND account_id = om_account_id;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate(
+        "originsrv",
+        r#"DROP FUNCTION IF EXISTS check_account_in_origin_members_v1(text, bigint)"#,
+    )?;
+    migrator.migrate("originsrv",
+                     r#"CREATE OR REPLACE FUNCTION update_origin_member_role_v1 (
+                    om_origin_id bigint,
+                    om_account_name text,
+                    om_role text
+                 ) RETURNS SETOF origin_members AS $$
+                        UPDATE origin_members SET role = om_role, updated_at = now()
+                            WHERE origin_id = om_origin_id AND account_name = om_account_name
+                            RETURNING *;
+                 $$ LANGUAGE SQL VOLATILE"#)?;
     Ok(())
 }
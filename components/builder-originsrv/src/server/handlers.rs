@@ -95,6 +95,40 @@ pub fn origin_package_update(
     Ok(())
 }
 
+pub fn origin_package_delete(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginPackageDelete>()?;
+    match state.datastore.delete_origin_package(&msg) {
+        Ok(()) => conn.route_reply(req, &NetOk::new())?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-package-delete:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_package_dependent_list(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginPackageDependentListRequest>()?;
+    match state.datastore.list_origin_package_dependents(&msg) {
+        Ok(ref opdl) => conn.route_reply(req, opdl)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-package-dependent-list:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn origin_create(
     req: &mut Message,
     conn: &mut RouteConn,
@@ -717,6 +751,51 @@ pub fn origin_channel_get(
     Ok(())
 }
 
+pub fn origin_channel_policy_get(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginChannelPolicyGet>()?;
+    match state.datastore.get_origin_channel_policy(&msg) {
+        Ok(Some(ref policy)) => conn.route_reply(req, policy)?,
+        Ok(None) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "vt:origin-channel-policy-get:0");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-channel-policy-get:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_channel_policy_update(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginChannelPolicyUpdate>()?;
+    match state.datastore.update_origin_channel_policy(&msg) {
+        Ok(Some(ref policy)) => conn.route_reply(req, policy)?,
+        Ok(None) => {
+            let err = NetError::new(
+                ErrCode::ENTITY_NOT_FOUND,
+                "vt:origin-channel-policy-update:0",
+            );
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-channel-policy-update:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn origin_channel_list(
     req: &mut Message,
     conn: &mut RouteConn,
@@ -1060,3 +1139,154 @@ pub fn origin_member_delete(
     }
     Ok(())
 }
+
+pub fn origin_member_role_update(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginMemberRoleUpdate>()?;
+    match state.datastore.update_origin_member_role(&msg) {
+        Ok(true) => conn.route_reply(req, &NetOk::new())?,
+        Ok(false) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "vt:origin-member-role-update:0");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-member-role-update:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_audit_event_create(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginAuditEventCreate>()?;
+    match state.datastore.create_origin_audit_event(&msg) {
+        Ok(()) => conn.route_reply(req, &NetOk::new())?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-audit-event-create:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_audit_event_list(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginAuditEventListRequest>()?;
+    match state.datastore.list_origin_audit_events(&msg) {
+        Ok(ref oaelr) => conn.route_reply(req, oaelr)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-audit-event-list:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_webhook_create(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginWebhookCreate>()?;
+    match state.datastore.create_origin_webhook(&msg) {
+        Ok(Some(ref webhook)) => conn.route_reply(req, webhook)?,
+        Ok(None) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "vt:origin-webhook-create:0");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-webhook-create:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_webhook_delete(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginWebhookDelete>()?;
+    match state.datastore.delete_origin_webhook(&msg) {
+        Ok(true) => conn.route_reply(req, &NetOk::new())?,
+        Ok(false) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "vt:origin-webhook-delete:0");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-webhook-delete:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_webhook_list(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginWebhookListRequest>()?;
+    match state.datastore.list_origin_webhooks(&msg) {
+        Ok(ref owlr) => conn.route_reply(req, owlr)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-webhook-list:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_webhook_trigger(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginWebhookTrigger>()?;
+    match state.datastore.trigger_origin_webhooks(&msg) {
+        Ok(queued) => {
+            debug!("Queued {} webhook deliveries, {:?}", queued, msg);
+            conn.route_reply(req, &NetOk::new())?
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-webhook-trigger:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_webhook_delivery_list(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginWebhookDeliveryListRequest>()?;
+    match state.datastore.list_origin_webhook_deliveries(&msg) {
+        Ok(ref owdlr) => conn.route_reply(req, owdlr)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-webhook-delivery-list:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
@@ -20,6 +20,7 @@ use protocol::originsrv::*;
 use config::Config;
 use data_store::DataStore;
 use error::{SrvError, SrvResult};
+use webhooks;
 
 lazy_static! {
     static ref DISPATCH_TABLE: DispatchTable<OriginSrv> = {
@@ -53,6 +54,10 @@ lazy_static! {
             handlers::origin_member_list);
         map.register(OriginPackageUpdate::descriptor_static(None),
             handlers::origin_package_update);
+        map.register(OriginPackageDelete::descriptor_static(None),
+            handlers::origin_package_delete);
+        map.register(OriginPackageDependentListRequest::descriptor_static(None),
+            handlers::origin_package_dependent_list);
         map.register(OriginSecretKeyCreate::descriptor_static(None),
             handlers::origin_secret_key_create);
         map.register(OriginSecretKeyGet::descriptor_static(None),
@@ -104,6 +109,10 @@ lazy_static! {
         map.register(OriginChannelCreate::descriptor_static(None), handlers::origin_channel_create);
         map.register(OriginChannelDelete::descriptor_static(None), handlers::origin_channel_delete);
         map.register(OriginChannelGet::descriptor_static(None), handlers::origin_channel_get);
+        map.register(OriginChannelPolicyGet::descriptor_static(None),
+            handlers::origin_channel_policy_get);
+        map.register(OriginChannelPolicyUpdate::descriptor_static(None),
+            handlers::origin_channel_policy_update);
         map.register(OriginChannelListRequest::descriptor_static(None),
             handlers::origin_channel_list);
         map.register(OriginChannelPackageGet::descriptor_static(None),
@@ -114,8 +123,24 @@ lazy_static! {
             handlers::origin_channel_package_list);
         map.register(OriginMemberRemove::descriptor_static(None),
             handlers::origin_member_delete);
+        map.register(OriginMemberRoleUpdate::descriptor_static(None),
+            handlers::origin_member_role_update);
         map.register(MyOriginsRequest::descriptor_static(None),
             handlers::my_origins);
+        map.register(OriginAuditEventCreate::descriptor_static(None),
+            handlers::origin_audit_event_create);
+        map.register(OriginAuditEventListRequest::descriptor_static(None),
+            handlers::origin_audit_event_list);
+        map.register(OriginWebhookCreate::descriptor_static(None),
+            handlers::origin_webhook_create);
+        map.register(OriginWebhookDelete::descriptor_static(None),
+            handlers::origin_webhook_delete);
+        map.register(OriginWebhookListRequest::descriptor_static(None),
+            handlers::origin_webhook_list);
+        map.register(OriginWebhookTrigger::descriptor_static(None),
+            handlers::origin_webhook_trigger);
+        map.register(OriginWebhookDeliveryListRequest::descriptor_static(None),
+            handlers::origin_webhook_delivery_list);
         map
     };
 }
@@ -158,6 +183,7 @@ impl Dispatcher for OriginSrv {
         let state = ServerState::new(config, router_pipe)?;
         state.datastore.register_async_events();
         state.datastore.start_async();
+        webhooks::start(state.datastore.pool.clone());
         Ok(state)
     }
 
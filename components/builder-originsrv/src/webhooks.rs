@@ -0,0 +1,178 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Delivery of origin webhooks. Each delivery POSTs the JSON payload of an event to the webhook's
+//! URL, signed with the webhook's secret so the receiver can verify it came from Builder.
+
+use std::io;
+use std::thread;
+use std::time::Duration;
+
+use bldr_core::webhooks::resolve_destination;
+use db::pool::Pool;
+use hex::ToHex;
+use http_client::ApiClient;
+use hyper::client::RedirectPolicy;
+use hyper::header::ContentType;
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::rand::rand_bytes;
+use openssl::sign::Signer;
+
+use data_store;
+
+header! { (XHabitatEvent, "X-Habitat-Event") => [String] }
+header! { (XHabitatDelivery, "X-Habitat-Delivery") => [String] }
+header! { (XHabitatSignature, "X-Habitat-Signature") => [String] }
+
+const PRODUCT: &'static str = "builder-originsrv";
+const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
+
+/// A delivery is given up on after this many failed attempts.
+pub const MAX_ATTEMPTS: i32 = 8;
+
+const BACKOFF_BASE_SECS: i64 = 30;
+
+/// Most deliveries attempted on a shard in a single poll.
+pub const BATCH_SIZE: i64 = 50;
+
+/// How long a claimed delivery is hidden from other pollers while it's being attempted.
+/// Deliveries are claimed one at a time, so this only has to outlast a single attempt, connect
+/// timeout included.
+pub const LEASE_SECS: i64 = 300;
+
+/// How long a receiver has to read a delivery and respond.
+const TIMEOUT_SECS: u64 = 10;
+
+const POLL_INTERVAL_SECS: u64 = 5;
+
+/// Polls for due webhook deliveries in the background every `POLL_INTERVAL_SECS` seconds.
+pub fn start(pool: Pool) {
+    thread::Builder::new()
+        .name("webhook-delivery".to_string())
+        .spawn(move || loop {
+            if let Err(e) = data_store::deliver_origin_webhooks(&pool) {
+                error!("Webhook delivery failed, err={}", e);
+            }
+            thread::sleep(Duration::from_secs(POLL_INTERVAL_SECS));
+        })
+        .unwrap();
+}
+
+/// Returns a random secret for a webhook registered without one.
+pub fn generate_secret() -> String {
+    let mut buf = [0u8; 32];
+    rand_bytes(&mut buf).expect("Unable to generate webhook secret");
+    buf.to_hex()
+}
+
+/// Signs a payload the same way GitHub signs its webhooks, returning the value of the
+/// `X-Habitat-Signature` header.
+pub fn sign(secret: &str, payload: &str) -> String {
+    let key = PKey::hmac(secret.as_bytes()).unwrap();
+    let mut signer = Signer::new(MessageDigest::sha256(), &key).unwrap();
+    signer.update(payload.as_bytes()).unwrap();
+    format!("sha256={}", signer.finish().unwrap().to_hex())
+}
+
+/// Seconds to wait before retrying a delivery which has failed `attempts` times, doubling with
+/// each failure.
+pub fn backoff(attempts: i32) -> i64 {
+    BACKOFF_BASE_SECS * 2i64.pow((attempts.max(1) - 1) as u32)
+}
+
+/// Makes a single attempt at a delivery. On failure the returned status is 0 if the receiver
+/// couldn't be reached at all. Redirects aren't followed, as they could lead anywhere.
+pub fn deliver(
+    url: &str,
+    event: &str,
+    delivery_id: u64,
+    secret: &str,
+    payload: &str,
+) -> Result<u16, (u16, String)> {
+    // The host's addresses may have changed since the webhook was registered, so they're
+    // checked again on every attempt, and the delivery connects to the address which was checked.
+    let mut client = ApiClient::with_resolver(url, PRODUCT, VERSION, None, |host, port| {
+        resolve_destination(host, port).map_err(|e| {
+            io::Error::new(io::ErrorKind::PermissionDenied, e.to_string())
+        })
+    }).map_err(|e| (0, e.to_string()))?;
+    client.set_timeout(Duration::from_secs(TIMEOUT_SECS));
+    client.set_redirect_policy(RedirectPolicy::FollowNone);
+    let response = client
+        .post("")
+        .header(ContentType::json())
+        .header(XHabitatEvent(event.to_string()))
+        .header(XHabitatDelivery(delivery_id.to_string()))
+        .header(XHabitatSignature(sign(secret, payload)))
+        .body(payload)
+        .send()
+        .map_err(|e| (0, e.to_string()))?;
+    let status = response.status.to_u16();
+    if response.status.is_success() {
+        Ok(status)
+    } else {
+        Err((status, format!("Receiver responded with {}", response.status)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use hyper::server::{Request, Response, Server};
+
+    use super::*;
+
+    #[test]
+    fn sign_payload() {
+        assert_eq!(
+            sign("key", "The quick brown fox jumps over the lazy dog"),
+            "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+
+    #[test]
+    fn backoff_doubles() {
+        assert_eq!(backoff(1), 30);
+        assert_eq!(backoff(2), 60);
+        assert_eq!(backoff(MAX_ATTEMPTS), 3840);
+    }
+
+    #[test]
+    fn secrets_are_unique() {
+        let secret = generate_secret();
+        assert_eq!(secret.len(), 64);
+        assert_ne!(secret, generate_secret());
+    }
+
+    #[test]
+    fn deliveries_never_reach_private_addresses() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let mut listening = Server::http("127.0.0.1:0")
+            .unwrap()
+            .handle(move |_: Request, _: Response| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            })
+            .unwrap();
+        let url = format!("http://{}/", listening.socket);
+
+        let (status, _) = deliver(&url, "package-uploaded", 1, "key", "{}").unwrap_err();
+        assert_eq!(status, 0);
+        listening.close().unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 0);
+    }
+}
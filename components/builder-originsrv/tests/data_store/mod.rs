@@ -14,7 +14,8 @@
 
 use protobuf;
 use protocol::originsrv;
-use originsrv::data_store::DataStore;
+use originsrv::data_store::{self, DataStore};
+use originsrv::webhooks;
 
 use std::str::FromStr;
 
@@ -732,6 +733,390 @@ fn delete_origin_member_by_name() {
     );
 }
 
+#[test]
+fn origin_member_roles() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    ds.create_origin(&origin).expect("Should create origin");
+
+    let neurosis = ds.get_origin_by_name("neurosis")
+        .expect("Could not retrieve origin")
+        .expect("Origin does not exist");
+
+    let mut oic = originsrv::OriginInvitationCreate::new();
+    oic.set_origin_id(neurosis.get_id());
+    oic.set_origin_name(neurosis.get_name().to_string());
+    oic.set_account_id(2);
+    oic.set_account_name(String::from("steve"));
+    oic.set_owner_id(1);
+    let invite = ds.create_origin_invitation(&oic)
+        .expect("Failed to create invitation")
+        .expect("No invitation created");
+    let conn = ds.pool.get(&oic).expect("Cannot get connection from pool");
+    conn.execute(
+        "SELECT accept_origin_invitation_v1($1, $2)",
+        &[&(invite.get_id() as i64), &false],
+    ).expect("Failed to accept invitation");
+
+    let mut omlr = originsrv::OriginMemberListRequest::new();
+    omlr.set_origin_id(neurosis.get_id());
+    let members = ds.list_origin_members(&omlr).expect(
+        "Error getting origin members from database",
+    );
+    assert_eq!(members.get_members(), &["scottkelly", "steve"]);
+    assert_eq!(
+        members.get_roles()[0].get_role(),
+        originsrv::OriginMemberRole::Owner
+    );
+    assert_eq!(
+        members.get_roles()[1].get_role(),
+        originsrv::OriginMemberRole::Developer
+    );
+
+    let mut coar = originsrv::CheckOriginAccessRequest::new();
+    coar.set_origin_name(String::from("neurosis"));
+    coar.set_account_id(2);
+    assert!(ds.check_account_in_origin(&coar).unwrap());
+    coar.set_role(originsrv::OriginMemberRole::Maintainer);
+    assert!(!ds.check_account_in_origin(&coar).unwrap());
+
+    let mut omru = originsrv::OriginMemberRoleUpdate::new();
+    omru.set_origin_id(neurosis.get_id());
+    omru.set_account_name(String::from("steve"));
+    omru.set_role(originsrv::OriginMemberRole::Maintainer);
+    assert!(ds.update_origin_member_role(&omru).unwrap());
+    assert!(ds.check_account_in_origin(&coar).unwrap());
+    coar.set_role(originsrv::OriginMemberRole::Owner);
+    assert!(!ds.check_account_in_origin(&coar).unwrap());
+
+    omru.set_account_name(String::from("nobody"));
+    assert!(!ds.update_origin_member_role(&omru).unwrap());
+    coar.set_account_id(3);
+    coar.set_role(originsrv::OriginMemberRole::Developer);
+    assert!(!ds.check_account_in_origin(&coar).unwrap());
+}
+
+#[test]
+fn origin_audit_events() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let neurosis = ds.create_origin(&origin).expect("Should create origin").expect(
+        "Origin was not created",
+    );
+
+    let mut event = originsrv::OriginAuditEvent::new();
+    event.set_origin_name(String::from("neurosis"));
+    event.set_account_id(1);
+    event.set_account_name(String::from("scottkelly"));
+    event.set_action(originsrv::OriginAuditAction::PackageUpload);
+    event.set_target(String::from("neurosis/zeal/1.0.0/20170101000000"));
+    event.set_remote_addr(String::from("10.0.0.1"));
+    event.set_user_agent(String::from("hab/0.30.0"));
+    let mut oaec = originsrv::OriginAuditEventCreate::new();
+    oaec.set_event(event.clone());
+    ds.create_origin_audit_event(&oaec).expect(
+        "Failed to record audit event",
+    );
+
+    event.set_account_id(2);
+    event.set_account_name(String::from("steve"));
+    event.set_action(originsrv::OriginAuditAction::PackagePromote);
+    event.set_detail(String::from("stable"));
+    event.set_access_token_id(42);
+    event.set_forwarded_for(String::from("203.0.113.7, 10.0.0.2"));
+    oaec.set_event(event.clone());
+    ds.create_origin_audit_event(&oaec).expect(
+        "Failed to record audit event",
+    );
+
+    // Events for unknown origins are dropped
+    event.set_origin_name(String::from("nope"));
+    oaec.set_event(event);
+    ds.create_origin_audit_event(&oaec).expect(
+        "Failed to record audit event",
+    );
+
+    let mut oael = originsrv::OriginAuditEventListRequest::new();
+    oael.set_origin_name(String::from("neurosis"));
+    oael.set_start(0);
+    oael.set_stop(49);
+    let events = ds.list_origin_audit_events(&oael).expect(
+        "Failed to list audit events",
+    );
+    assert_eq!(events.get_count(), 2);
+    assert_eq!(events.get_stop(), 1);
+    let latest = &events.get_events()[0];
+    assert_eq!(latest.get_origin_id(), neurosis.get_id());
+    assert_eq!(latest.get_account_name(), "steve");
+    assert_eq!(
+        latest.get_action(),
+        originsrv::OriginAuditAction::PackagePromote
+    );
+    assert_eq!(latest.get_detail(), "stable");
+    assert_eq!(latest.get_access_token_id(), 42);
+    assert_eq!(latest.get_forwarded_for(), "203.0.113.7, 10.0.0.2");
+    assert!(!events.get_events()[1].has_access_token_id());
+    assert!(!events.get_events()[1].has_forwarded_for());
+
+    oael.set_action(originsrv::OriginAuditAction::PackageUpload);
+    let events = ds.list_origin_audit_events(&oael).expect(
+        "Failed to list audit events",
+    );
+    assert_eq!(events.get_count(), 1);
+    assert_eq!(events.get_events()[0].get_account_name(), "scottkelly");
+    assert_eq!(events.get_events()[0].get_remote_addr(), "10.0.0.1");
+
+    oael.clear_action();
+    oael.set_account_name(String::from("steve"));
+    oael.set_target(String::from("neurosis/zeal"));
+    let events = ds.list_origin_audit_events(&oael).expect(
+        "Failed to list audit events",
+    );
+    assert_eq!(events.get_count(), 1);
+
+    oael.clear_account_name();
+    oael.set_target(String::from("neurosis/other"));
+    let events = ds.list_origin_audit_events(&oael).expect(
+        "Failed to list audit events",
+    );
+    assert_eq!(events.get_count(), 0);
+
+    oael.clear_target();
+    oael.set_since(String::from("2999-01-01T00:00:00Z"));
+    let events = ds.list_origin_audit_events(&oael).expect(
+        "Failed to list audit events",
+    );
+    assert_eq!(events.get_count(), 0);
+
+    oael.clear_since();
+    oael.set_until(String::from("2999-01-01T00:00:00Z"));
+    oael.set_stop(0);
+    let events = ds.list_origin_audit_events(&oael).expect(
+        "Failed to list audit events",
+    );
+    assert_eq!(events.get_count(), 2);
+    assert_eq!(events.get_events().len(), 1);
+}
+
+#[test]
+fn origin_webhooks() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let neurosis = ds.create_origin(&origin).expect("Should create origin").expect(
+        "Origin was not created",
+    );
+
+    let mut webhook = originsrv::OriginWebhook::new();
+    webhook.set_origin_name(String::from("neurosis"));
+    webhook.set_url(String::from("https://ci.example.com/hooks/habitat"));
+    webhook.set_events(vec![
+        originsrv::OriginWebhookEvent::PackageUploaded,
+        originsrv::OriginWebhookEvent::PackagePromoted,
+    ]);
+    webhook.set_owner_id(1);
+    let mut owc = originsrv::OriginWebhookCreate::new();
+    owc.set_webhook(webhook.clone());
+    let created = ds.create_origin_webhook(&owc)
+        .expect("Failed to create webhook")
+        .expect("Webhook was not created");
+    assert_eq!(created.get_origin_id(), neurosis.get_id());
+    assert_eq!(created.get_secret().len(), 64);
+    assert_eq!(created.get_events().len(), 2);
+
+    webhook.set_url(String::from("https://chat.example.com/hooks/habitat"));
+    webhook.set_events(vec![originsrv::OriginWebhookEvent::JobFailed]);
+    webhook.set_secret(String::from("hunter2"));
+    owc.set_webhook(webhook.clone());
+    let second = ds.create_origin_webhook(&owc)
+        .expect("Failed to create webhook")
+        .expect("Webhook was not created");
+    assert_eq!(second.get_secret(), "hunter2");
+
+    // Webhooks can't be registered against unknown origins
+    webhook.set_origin_name(String::from("nope"));
+    owc.set_webhook(webhook);
+    assert!(
+        ds.create_origin_webhook(&owc)
+            .expect("Failed to create webhook")
+            .is_none()
+    );
+
+    let mut owl = originsrv::OriginWebhookListRequest::new();
+    owl.set_origin_name(String::from("neurosis"));
+    let webhooks = ds.list_origin_webhooks(&owl).expect(
+        "Failed to list webhooks",
+    );
+    assert_eq!(webhooks.get_webhooks().len(), 2);
+    assert_eq!(webhooks.get_webhooks()[0].get_id(), created.get_id());
+    assert!(!webhooks.get_webhooks()[0].has_secret());
+
+    let ident = originsrv::OriginPackageIdent::from_str("neurosis/zeal/1.0.0/20170101000000")
+        .unwrap();
+    let mut owt = originsrv::OriginWebhookTrigger::for_package(
+        originsrv::OriginWebhookEvent::PackagePromoted,
+        &ident,
+    );
+    owt.set_channel(String::from("stable"));
+    let queued = ds.trigger_origin_webhooks(&owt).expect(
+        "Failed to trigger webhooks",
+    );
+    assert_eq!(queued, 1);
+
+    // Nobody is subscribed to demotions
+    owt.set_event(originsrv::OriginWebhookEvent::PackageDemoted);
+    let queued = ds.trigger_origin_webhooks(&owt).expect(
+        "Failed to trigger webhooks",
+    );
+    assert_eq!(queued, 0);
+
+    let mut owdl = originsrv::OriginWebhookDeliveryListRequest::new();
+    owdl.set_origin_name(String::from("neurosis"));
+    owdl.set_webhook_id(created.get_id());
+    owdl.set_start(0);
+    owdl.set_stop(49);
+    let deliveries = ds.list_origin_webhook_deliveries(&owdl).expect(
+        "Failed to list deliveries",
+    );
+    assert_eq!(deliveries.get_count(), 1);
+    let delivery = &deliveries.get_deliveries()[0];
+    assert_eq!(
+        delivery.get_event(),
+        originsrv::OriginWebhookEvent::PackagePromoted
+    );
+    assert_eq!(
+        delivery.get_state(),
+        originsrv::OriginWebhookDeliveryState::Pending
+    );
+    assert_eq!(delivery.get_attempts(), 0);
+    assert!(delivery.get_payload().contains("\"channel\":\"stable\""));
+
+    // Deliveries can only be listed through the webhook's own origin
+    owdl.set_origin_name(String::from("nope"));
+    let deliveries = ds.list_origin_webhook_deliveries(&owdl).expect(
+        "Failed to list deliveries",
+    );
+    assert_eq!(deliveries.get_count(), 0);
+
+    let mut owd = originsrv::OriginWebhookDelete::new();
+    owd.set_origin_name(String::from("neurosis"));
+    owd.set_id(created.get_id());
+    assert!(ds.delete_origin_webhook(&owd).expect(
+        "Failed to delete webhook",
+    ));
+    assert!(!ds.delete_origin_webhook(&owd).expect(
+        "Failed to delete webhook",
+    ));
+    let webhooks = ds.list_origin_webhooks(&owl).expect(
+        "Failed to list webhooks",
+    );
+    assert_eq!(webhooks.get_webhooks().len(), 1);
+    assert_eq!(webhooks.get_webhooks()[0].get_id(), second.get_id());
+}
+
+/// Makes every pending webhook delivery due now, as though its retry backoff had passed.
+fn expire_webhook_backoff(ds: &DataStore) {
+    for shard in ds.pool.shards.iter() {
+        let conn = ds.pool.get_shard(*shard).expect("Failed to get shard");
+        conn.execute(
+            "UPDATE origin_webhook_deliveries SET next_attempt_at = now() WHERE state = 'pending'",
+            &[],
+        ).expect("Failed to expire backoff");
+    }
+}
+
+#[test]
+fn origin_webhook_delivery_retries() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    ds.create_origin(&origin).expect("Should create origin").expect(
+        "Origin was not created",
+    );
+
+    // Loopback destinations are refused at delivery time, so every attempt fails without
+    // anything being sent.
+    let mut webhook = originsrv::OriginWebhook::new();
+    webhook.set_origin_name(String::from("neurosis"));
+    webhook.set_url(String::from("http://127.0.0.1:1/hooks/habitat"));
+    webhook.set_events(vec![originsrv::OriginWebhookEvent::PackageUploaded]);
+    webhook.set_owner_id(1);
+    let mut owc = originsrv::OriginWebhookCreate::new();
+    owc.set_webhook(webhook);
+    let created = ds.create_origin_webhook(&owc)
+        .expect("Failed to create webhook")
+        .expect("Webhook was not created");
+
+    let ident = originsrv::OriginPackageIdent::from_str("neurosis/zeal/1.0.0/20170101000000")
+        .unwrap();
+    let owt = originsrv::OriginWebhookTrigger::for_package(
+        originsrv::OriginWebhookEvent::PackageUploaded,
+        &ident,
+    );
+    assert_eq!(ds.trigger_origin_webhooks(&owt).unwrap(), 1);
+
+    let mut owdl = originsrv::OriginWebhookDeliveryListRequest::new();
+    owdl.set_origin_name(String::from("neurosis"));
+    owdl.set_webhook_id(created.get_id());
+    owdl.set_start(0);
+    owdl.set_stop(49);
+    let delivery = || {
+        ds.list_origin_webhook_deliveries(&owdl)
+            .expect("Failed to list deliveries")
+            .get_deliveries()[0]
+            .clone()
+    };
+
+    data_store::deliver_origin_webhooks(&ds.pool).expect("Failed to deliver webhooks");
+    let first = delivery();
+    assert_eq!(
+        first.get_state(),
+        originsrv::OriginWebhookDeliveryState::Pending
+    );
+    assert_eq!(first.get_attempts(), 1);
+    assert_eq!(first.get_response_code(), 0);
+    assert!(!first.get_error().is_empty());
+
+    // A failed delivery isn't attempted again until its backoff has passed
+    data_store::deliver_origin_webhooks(&ds.pool).expect("Failed to deliver webhooks");
+    assert_eq!(delivery().get_attempts(), 1);
+
+    for attempt in 2..webhooks::MAX_ATTEMPTS {
+        expire_webhook_backoff(&ds);
+        data_store::deliver_origin_webhooks(&ds.pool).expect("Failed to deliver webhooks");
+        let retried = delivery();
+        assert_eq!(
+            retried.get_state(),
+            originsrv::OriginWebhookDeliveryState::Pending
+        );
+        assert_eq!(retried.get_attempts(), attempt as u32);
+    }
+
+    expire_webhook_backoff(&ds);
+    data_store::deliver_origin_webhooks(&ds.pool).expect("Failed to deliver webhooks");
+    let failed = delivery();
+    assert_eq!(
+        failed.get_state(),
+        originsrv::OriginWebhookDeliveryState::Failed
+    );
+    assert_eq!(failed.get_attempts(), webhooks::MAX_ATTEMPTS as u32);
+
+    // Failed deliveries are never claimed again
+    expire_webhook_backoff(&ds);
+    data_store::deliver_origin_webhooks(&ds.pool).expect("Failed to deliver webhooks");
+    assert_eq!(delivery().get_attempts(), webhooks::MAX_ATTEMPTS as u32);
+}
+
 #[test]
 fn update_origin_package() {
     let ds = datastore_test!(DataStore);
@@ -1187,6 +1572,188 @@ fn get_latest_package() {
     assert!(result3.is_none());
 }
 
+#[test]
+fn get_latest_package_skips_yanked() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let origin = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut ident1 = originsrv::OriginPackageIdent::new();
+    ident1.set_origin("core".to_string());
+    ident1.set_name("cacerts".to_string());
+    ident1.set_version("2017.01.17".to_string());
+    ident1.set_release("20170209064044".to_string());
+
+    let mut ident2 = originsrv::OriginPackageIdent::new();
+    ident2.set_origin("core".to_string());
+    ident2.set_name("cacerts".to_string());
+    ident2.set_version("2017.01.18".to_string());
+    ident2.set_release("20170209064044".to_string());
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(origin.get_id());
+    package.set_ident(ident1.clone());
+    package.set_checksum("checksum".to_string());
+    package.set_manifest("manifest".to_string());
+    package.set_config("config".to_string());
+    package.set_target("x86_64-linux".to_string());
+    package.set_exposes(vec![1, 2]);
+    ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+
+    package.set_ident(ident2.clone());
+    let mut yanked = ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+    yanked.set_yanked(true);
+    let mut upd = originsrv::OriginPackageUpdate::new();
+    upd.set_pkg(yanked);
+    ds.update_origin_package(&upd).expect(
+        "Failed to update origin package",
+    );
+
+    let mut package_get = originsrv::OriginPackageLatestGet::new();
+    let mut search_ident = originsrv::OriginPackageIdent::new();
+    search_ident.set_origin("core".to_string());
+    search_ident.set_name("cacerts".to_string());
+    package_get.set_ident(search_ident);
+    package_get.set_target("x86_64-linux".to_string());
+    package_get.set_visibilities(vec![originsrv::OriginPackageVisibility::Public]);
+    let latest = ds.get_origin_package_latest(&package_get).unwrap();
+    assert_eq!(latest.unwrap().to_string(), ident1.to_string());
+
+    // An explicit lookup still finds the yanked release
+    let mut explicit_get = originsrv::OriginPackageGet::new();
+    explicit_get.set_ident(ident2.clone());
+    explicit_get.set_visibilities(vec![originsrv::OriginPackageVisibility::Public]);
+    let result = ds.get_origin_package(&explicit_get)
+        .expect("Failed to get origin package")
+        .unwrap();
+    assert!(result.get_yanked());
+}
+
+#[test]
+fn delete_origin_package() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let origin = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut ident = originsrv::OriginPackageIdent::new();
+    ident.set_origin("core".to_string());
+    ident.set_name("cacerts".to_string());
+    ident.set_version("2017.01.17".to_string());
+    ident.set_release("20170209064044".to_string());
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(origin.get_id());
+    package.set_ident(ident.clone());
+    package.set_checksum("checksum".to_string());
+    package.set_manifest("manifest".to_string());
+    package.set_config("config".to_string());
+    package.set_target("x86_64-linux".to_string());
+    package.set_exposes(vec![1, 2]);
+    let created = ds.create_origin_package(&package).expect(
+        "Failed to create origin package",
+    );
+
+    let mut delete = originsrv::OriginPackageDelete::new();
+    delete.set_id(created.get_id());
+    delete.set_ident(ident.clone());
+    ds.delete_origin_package(&delete).expect(
+        "Failed to delete origin package",
+    );
+
+    let mut package_get = originsrv::OriginPackageGet::new();
+    package_get.set_ident(ident);
+    package_get.set_visibilities(vec![originsrv::OriginPackageVisibility::Public]);
+    let result = ds.get_origin_package(&package_get).expect(
+        "Failed to get origin package",
+    );
+    assert!(result.is_none());
+}
+
+#[test]
+fn list_origin_package_dependents() {
+    let ds = datastore_test!(DataStore);
+    let mut origins = Vec::new();
+    for name in vec!["core", "neurosis"] {
+        let mut origin = originsrv::OriginCreate::new();
+        origin.set_name(String::from(name));
+        origin.set_owner_id(1);
+        origin.set_owner_name(String::from("scottkelly"));
+        origins.push(ds.create_origin(&origin).expect("Should create origin").unwrap());
+    }
+
+    let create = |origin: &originsrv::Origin,
+                  ident: &str,
+                  deps: Vec<&str>,
+                  tdeps: Vec<&str>| {
+        let parse = |s: &str| originsrv::OriginPackageIdent::from_str(s).unwrap();
+        let mut package = originsrv::OriginPackageCreate::new();
+        package.set_owner_id(1);
+        package.set_origin_id(origin.get_id());
+        package.set_ident(parse(ident));
+        package.set_checksum("checksum".to_string());
+        package.set_manifest("manifest".to_string());
+        package.set_config("config".to_string());
+        package.set_target("x86_64-linux".to_string());
+        package.set_deps(deps.into_iter().map(&parse).collect());
+        package.set_tdeps(tdeps.into_iter().map(&parse).collect());
+        ds.create_origin_package(&package).expect(
+            "Failed to create origin package",
+        );
+    };
+    let cacerts = "core/cacerts/2017.01.17/20170209064044";
+    let newer_cacerts = "core/cacerts/2017.01.17/20170301000000";
+    create(&origins[0], cacerts, vec![], vec![]);
+    create(&origins[0], newer_cacerts, vec![], vec![]);
+    create(
+        &origins[1],
+        "neurosis/app/1.0.0/20170301000001",
+        vec![cacerts],
+        vec![cacerts],
+    );
+    create(
+        &origins[0],
+        "core/openssl/1.0.2l/20170301000002",
+        vec![],
+        vec![newer_cacerts],
+    );
+
+    let dependents = |ident: &str| {
+        let mut dependents = Vec::new();
+        for shard in ds.pool.shards.iter() {
+            let mut list = originsrv::OriginPackageDependentListRequest::new();
+            list.set_ident(originsrv::OriginPackageIdent::from_str(ident).unwrap());
+            list.set_shard(*shard);
+            let response = ds.list_origin_package_dependents(&list).expect(
+                "Failed to list dependents",
+            );
+            dependents.extend(response.get_idents().iter().map(|i| i.to_string()));
+        }
+        dependents
+    };
+    assert_eq!(dependents(cacerts), vec!["neurosis/app/1.0.0/20170301000001"]);
+    assert_eq!(
+        dependents(newer_cacerts),
+        vec!["core/openssl/1.0.2l/20170301000002"]
+    );
+    assert!(dependents("core/openssl/1.0.2l/20170301000002").is_empty());
+}
+
 #[test]
 fn list_origin_package_versions_for_origin() {
     let ds = datastore_test!(DataStore);
@@ -1774,6 +2341,77 @@ fn create_origin_channel_handles_unique_constraint_violations_correctly() {
     assert!(resp.is_err(), "Insertion should've triggered an error");
 }
 
+#[test]
+fn origin_channel_policies() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    ds.create_origin(&origin).expect("Should create origin");
+
+    // Channels without a policy have an empty one
+    let mut ocpg = originsrv::OriginChannelPolicyGet::new();
+    ocpg.set_origin_name(String::from("neurosis"));
+    ocpg.set_channel_name(String::from("stable"));
+    let policy = ds.get_origin_channel_policy(&ocpg)
+        .expect("Failed to get channel policy")
+        .expect("Channel does not exist");
+    assert_eq!(policy.get_channel_name(), "stable");
+    assert!(policy.get_channel_id() > 0);
+    assert!(!policy.has_promote_role());
+    assert!(!policy.has_prerequisite_channel());
+    assert!(!policy.get_require_job_success());
+
+    let mut update = originsrv::OriginChannelPolicy::new();
+    update.set_origin_name(String::from("neurosis"));
+    update.set_channel_name(String::from("stable"));
+    update.set_promote_role(originsrv::OriginMemberRole::Owner);
+    update.set_prerequisite_channel(String::from("unstable"));
+    update.set_require_job_success(true);
+    let mut ocpu = originsrv::OriginChannelPolicyUpdate::new();
+    ocpu.set_policy(update.clone());
+    let updated = ds.update_origin_channel_policy(&ocpu)
+        .expect("Failed to update channel policy")
+        .expect("Channel does not exist");
+    assert_eq!(updated.get_channel_id(), policy.get_channel_id());
+
+    let policy = ds.get_origin_channel_policy(&ocpg)
+        .expect("Failed to get channel policy")
+        .expect("Channel does not exist");
+    assert_eq!(policy.get_promote_role(), originsrv::OriginMemberRole::Owner);
+    assert_eq!(policy.get_prerequisite_channel(), "unstable");
+    assert!(policy.get_require_job_success());
+
+    // Updating replaces the whole policy
+    update.clear_promote_role();
+    update.clear_prerequisite_channel();
+    ocpu.set_policy(update.clone());
+    ds.update_origin_channel_policy(&ocpu)
+        .expect("Failed to update channel policy")
+        .expect("Channel does not exist");
+    let policy = ds.get_origin_channel_policy(&ocpg)
+        .expect("Failed to get channel policy")
+        .expect("Channel does not exist");
+    assert!(!policy.has_promote_role());
+    assert!(!policy.has_prerequisite_channel());
+    assert!(policy.get_require_job_success());
+
+    update.set_channel_name(String::from("nope"));
+    ocpu.set_policy(update);
+    assert!(
+        ds.update_origin_channel_policy(&ocpu)
+            .expect("Failed to update channel policy")
+            .is_none()
+    );
+    ocpg.set_channel_name(String::from("nope"));
+    assert!(
+        ds.get_origin_channel_policy(&ocpg)
+            .expect("Failed to get channel policy")
+            .is_none()
+    );
+}
+
 #[test]
 fn list_origin_channel() {
     let ds = datastore_test!(DataStore);
@@ -88,6 +88,14 @@ impl Routable for ProjectJobsGet {
     }
 }
 
+impl Routable for PackageJobGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_ident().to_string())
+    }
+}
+
 impl Pageable for ProjectJobsGet {
     fn get_range(&self) -> [u64; 2] {
         [self.get_start(), self.get_stop()]
@@ -7812,6 +7812,197 @@ impl ::protobuf::reflect::ProtobufValue for JobGraphPackageStats {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct PackageJobGet {
+    // message fields
+    ident: ::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for PackageJobGet {}
+
+impl PackageJobGet {
+    pub fn new() -> PackageJobGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static PackageJobGet {
+        static mut instance: ::protobuf::lazy::Lazy<PackageJobGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PackageJobGet,
+        };
+        unsafe {
+            instance.get(PackageJobGet::new)
+        }
+    }
+
+    // optional .originsrv.OriginPackageIdent ident = 1;
+
+    pub fn clear_ident(&mut self) {
+        self.ident.clear();
+    }
+
+    pub fn has_ident(&self) -> bool {
+        self.ident.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ident(&mut self, v: super::originsrv::OriginPackageIdent) {
+        self.ident = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ident(&mut self) -> &mut super::originsrv::OriginPackageIdent {
+        if self.ident.is_none() {
+            self.ident.set_default();
+        }
+        self.ident.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ident(&mut self) -> super::originsrv::OriginPackageIdent {
+        self.ident.take().unwrap_or_else(|| super::originsrv::OriginPackageIdent::new())
+    }
+
+    pub fn get_ident(&self) -> &super::originsrv::OriginPackageIdent {
+        self.ident.as_ref().unwrap_or_else(|| super::originsrv::OriginPackageIdent::default_instance())
+    }
+
+    fn get_ident_for_reflect(&self) -> &::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent> {
+        &self.ident
+    }
+
+    fn mut_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent> {
+        &mut self.ident
+    }
+}
+
+impl ::protobuf::Message for PackageJobGet {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ident {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ident)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.ident.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.ident.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for PackageJobGet {
+    fn new() -> PackageJobGet {
+        PackageJobGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<PackageJobGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginPackageIdent>>(
+                    "ident",
+                    PackageJobGet::get_ident_for_reflect,
+                    PackageJobGet::mut_ident_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PackageJobGet>(
+                    "PackageJobGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for PackageJobGet {
+    fn clear(&mut self) {
+        self.clear_ident();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PackageJobGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PackageJobGet {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum Os {
     Linux = 1,
@@ -8231,273 +8422,274 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x20\x01(\tR\x06origin\"m\n\x14JobGraphPackageStats\x12\x14\n\x05plans\
     \x18\x01\x20\x01(\x04R\x05plans\x12\x16\n\x06builds\x18\x02\x20\x01(\x04\
     R\x06builds\x12'\n\x0funique_packages\x18\x03\x20\x01(\x04R\x0euniquePac\
-    kages*(\n\x02Os\x12\t\n\x05Linux\x10\x01\x12\n\n\x06Darwin\x10\x02\x12\
-    \x0b\n\x07Windows\x10\x03*\"\n\x0bWorkerState\x12\t\n\x05Ready\x10\0\x12\
-    \x08\n\x04Busy\x10\x01*.\n\x0fWorkerOperation\x12\x0c\n\x08StartJob\x10\
-    \0\x12\r\n\tCancelJob\x10\x01*\x9c\x01\n\x08JobState\x12\x0b\n\x07Pendin\
-    g\x10\0\x12\x0e\n\nProcessing\x10\x01\x12\x0c\n\x08Complete\x10\x02\x12\
-    \x0c\n\x08Rejected\x10\x03\x12\n\n\x06Failed\x10\x04\x12\x0e\n\nDispatch\
-    ed\x10\x05\x12\x11\n\rCancelPending\x10\x06\x12\x14\n\x10CancelProcessin\
-    g\x10\x07\x12\x12\n\x0eCancelComplete\x10\x08*k\n\x14JobGroupProjectStat\
-    e\x12\x0e\n\nNotStarted\x10\0\x12\x0e\n\nInProgress\x10\x01\x12\x0b\n\
-    \x07Success\x10\x02\x12\x0b\n\x07Failure\x10\x03\x12\x0b\n\x07Skipped\
-    \x10\x04\x12\x0c\n\x08Canceled\x10\x05*\x7f\n\rJobGroupState\x12\x10\n\
-    \x0cGroupPending\x10\0\x12\x14\n\x10GroupDispatching\x10\x01\x12\x11\n\r\
-    GroupComplete\x10\x02\x12\x0f\n\x0bGroupFailed\x10\x03\x12\x0f\n\x0bGrou\
-    pQueued\x10\x04\x12\x11\n\rGroupCanceled\x10\x05J\xff?\n\x07\x12\x05\0\0\
-    \xd6\x01\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\
-    \x08\x0e\n\t\n\x02\x03\0\x12\x03\x02\x07\x1c\n\t\n\x02\x03\x01\x12\x03\
-    \x03\x07\"\n\n\n\x02\x05\0\x12\x04\x05\0\t\x01\n\n\n\x03\x05\0\x01\x12\
-    \x03\x05\x05\x07\n\x0b\n\x04\x05\0\x02\0\x12\x03\x06\x02\x0c\n\x0c\n\x05\
-    \x05\0\x02\0\x01\x12\x03\x06\x02\x07\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\
-    \x06\n\x0b\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x07\x02\r\n\x0c\n\x05\x05\0\
-    \x02\x01\x01\x12\x03\x07\x02\x08\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\
-    \x07\x0b\x0c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x08\x02\x0e\n\x0c\n\x05\
-    \x05\0\x02\x02\x01\x12\x03\x08\x02\t\n\x0c\n\x05\x05\0\x02\x02\x02\x12\
-    \x03\x08\x0c\r\n\n\n\x02\x05\x01\x12\x04\x0b\0\x0e\x01\n\n\n\x03\x05\x01\
-    \x01\x12\x03\x0b\x05\x10\n\x0b\n\x04\x05\x01\x02\0\x12\x03\x0c\x02\x0c\n\
-    \x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x0c\x02\x07\n\x0c\n\x05\x05\x01\x02\
-    \0\x02\x12\x03\x0c\n\x0b\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\r\x02\x0b\n\
-    \x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\r\x02\x06\n\x0c\n\x05\x05\x01\x02\
-    \x01\x02\x12\x03\r\t\n\n\n\n\x02\x05\x02\x12\x04\x10\0\x13\x01\n\n\n\x03\
-    \x05\x02\x01\x12\x03\x10\x05\x14\n\x0b\n\x04\x05\x02\x02\0\x12\x03\x11\
-    \x02\x0f\n\x0c\n\x05\x05\x02\x02\0\x01\x12\x03\x11\x02\n\n\x0c\n\x05\x05\
-    \x02\x02\0\x02\x12\x03\x11\r\x0e\n\x0b\n\x04\x05\x02\x02\x01\x12\x03\x12\
-    \x02\x10\n\x0c\n\x05\x05\x02\x02\x01\x01\x12\x03\x12\x02\x0b\n\x0c\n\x05\
-    \x05\x02\x02\x01\x02\x12\x03\x12\x0e\x0f\n\n\n\x02\x05\x03\x12\x04\x15\0\
-    \x1f\x01\n\n\n\x03\x05\x03\x01\x12\x03\x15\x05\r\n\x0b\n\x04\x05\x03\x02\
-    \0\x12\x03\x16\x02\x0e\n\x0c\n\x05\x05\x03\x02\0\x01\x12\x03\x16\x02\t\n\
-    \x0c\n\x05\x05\x03\x02\0\x02\x12\x03\x16\x0c\r\n\x0b\n\x04\x05\x03\x02\
-    \x01\x12\x03\x17\x02\x11\n\x0c\n\x05\x05\x03\x02\x01\x01\x12\x03\x17\x02\
-    \x0c\n\x0c\n\x05\x05\x03\x02\x01\x02\x12\x03\x17\x0f\x10\n\x0b\n\x04\x05\
-    \x03\x02\x02\x12\x03\x18\x02\x0f\n\x0c\n\x05\x05\x03\x02\x02\x01\x12\x03\
-    \x18\x02\n\n\x0c\n\x05\x05\x03\x02\x02\x02\x12\x03\x18\r\x0e\n\x0b\n\x04\
-    \x05\x03\x02\x03\x12\x03\x19\x02\x0f\n\x0c\n\x05\x05\x03\x02\x03\x01\x12\
-    \x03\x19\x02\n\n\x0c\n\x05\x05\x03\x02\x03\x02\x12\x03\x19\r\x0e\n\x0b\n\
-    \x04\x05\x03\x02\x04\x12\x03\x1a\x02\r\n\x0c\n\x05\x05\x03\x02\x04\x01\
-    \x12\x03\x1a\x02\x08\n\x0c\n\x05\x05\x03\x02\x04\x02\x12\x03\x1a\x0b\x0c\
-    \n\x0b\n\x04\x05\x03\x02\x05\x12\x03\x1b\x02\x11\n\x0c\n\x05\x05\x03\x02\
-    \x05\x01\x12\x03\x1b\x02\x0c\n\x0c\n\x05\x05\x03\x02\x05\x02\x12\x03\x1b\
-    \x0f\x10\n\x0b\n\x04\x05\x03\x02\x06\x12\x03\x1c\x02\x14\n\x0c\n\x05\x05\
-    \x03\x02\x06\x01\x12\x03\x1c\x02\x0f\n\x0c\n\x05\x05\x03\x02\x06\x02\x12\
-    \x03\x1c\x12\x13\n\x0b\n\x04\x05\x03\x02\x07\x12\x03\x1d\x02\x17\n\x0c\n\
-    \x05\x05\x03\x02\x07\x01\x12\x03\x1d\x02\x12\n\x0c\n\x05\x05\x03\x02\x07\
-    \x02\x12\x03\x1d\x15\x16\n\x0b\n\x04\x05\x03\x02\x08\x12\x03\x1e\x02\x15\
-    \n\x0c\n\x05\x05\x03\x02\x08\x01\x12\x03\x1e\x02\x10\n\x0c\n\x05\x05\x03\
-    \x02\x08\x02\x12\x03\x1e\x13\x14\n\n\n\x02\x04\0\x12\x04!\0#\x01\n\n\n\
-    \x03\x04\0\x01\x12\x03!\x08\x15\n\x0b\n\x04\x04\0\x02\0\x12\x03\"\x02\"\
-    \n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\"\x02\n\n\x0c\n\x05\x04\0\x02\0\x06\
-    \x12\x03\"\x0b\x1a\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\"\x1b\x1d\n\x0c\n\
-    \x05\x04\0\x02\0\x03\x12\x03\"\x20!\n\n\n\x02\x04\x01\x12\x04%\0)\x01\n\
-    \n\n\x03\x04\x01\x01\x12\x03%\x08\x11\n\x0b\n\x04\x04\x01\x02\0\x12\x03&\
-    \x02\x1f\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03&\x02\n\n\x0c\n\x05\x04\
-    \x01\x02\0\x05\x12\x03&\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03&\
-    \x12\x1a\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03&\x1d\x1e\n\x0b\n\x04\x04\
-    \x01\x02\x01\x12\x03'\x02\x15\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03'\
-    \x02\n\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03'\x0b\r\n\x0c\n\x05\x04\
-    \x01\x02\x01\x01\x12\x03'\x0e\x10\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\
-    \x03'\x13\x14\n\x0b\n\x04\x04\x01\x02\x02\x12\x03(\x02!\n\x0c\n\x05\x04\
-    \x01\x02\x02\x04\x12\x03(\x02\n\n\x0c\n\x05\x04\x01\x02\x02\x06\x12\x03(\
-    \x0b\x16\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03(\x17\x1c\n\x0c\n\x05\
-    \x04\x01\x02\x02\x03\x12\x03(\x1f\x20\n\n\n\x02\x04\x02\x12\x04+\0/\x01\
-    \n\n\n\x03\x04\x02\x01\x12\x03+\x08\x12\n\x0b\n\x04\x04\x02\x02\0\x12\
-    \x03,\x02\x1c\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03,\x02\n\n\x0c\n\x05\
-    \x04\x02\x02\0\x05\x12\x03,\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\
-    \x03,\x12\x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03,\x1a\x1b\n\x0b\n\x04\
-    \x04\x02\x02\x01\x12\x03-\x02\x1d\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\
-    \x03-\x02\n\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03-\x0b\x11\n\x0c\n\x05\
-    \x04\x02\x02\x01\x01\x12\x03-\x12\x18\n\x0c\n\x05\x04\x02\x02\x01\x03\
-    \x12\x03-\x1b\x1c\n\x0b\n\x04\x04\x02\x02\x02\x12\x03.\x02\x20\n\x0c\n\
-    \x05\x04\x02\x02\x02\x04\x12\x03.\x02\n\n\x0c\n\x05\x04\x02\x02\x02\x05\
-    \x12\x03.\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03.\x10\x1b\n\x0c\
-    \n\x05\x04\x02\x02\x02\x03\x12\x03.\x1e\x1f\n\n\n\x02\x04\x03\x12\x041\0\
-    B\x01\n\n\n\x03\x04\x03\x01\x12\x031\x08\x0b\n\n\n\x03\x04\x03\t\x12\x03\
-    2\x0b\x0e\n\x0b\n\x04\x04\x03\t\0\x12\x032\x0b\r\n\x0c\n\x05\x04\x03\t\0\
-    \x01\x12\x032\x0b\r\n\x0c\n\x05\x04\x03\t\0\x02\x12\x032\x0b\r\n\n\n\x03\
-    \x04\x03\n\x12\x033\x0b\x15\n\x0b\n\x04\x04\x03\n\0\x12\x033\x0b\x14\n\
-    \x0b\n\x04\x04\x03\x02\0\x12\x034\x02\x19\n\x0c\n\x05\x04\x03\x02\0\x04\
-    \x12\x034\x02\n\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x034\x0b\x11\n\x0c\n\
-    \x05\x04\x03\x02\0\x01\x12\x034\x12\x14\n\x0c\n\x05\x04\x03\x02\0\x03\
-    \x12\x034\x17\x18\n\x0b\n\x04\x04\x03\x02\x01\x12\x035\x02\x1f\n\x0c\n\
-    \x05\x04\x03\x02\x01\x04\x12\x035\x02\n\n\x0c\n\x05\x04\x03\x02\x01\x05\
-    \x12\x035\x0b\x11\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x035\x12\x1a\n\x0c\
-    \n\x05\x04\x03\x02\x01\x03\x12\x035\x1d\x1e\n\x0b\n\x04\x04\x03\x02\x02\
-    \x12\x036\x02\x1e\n\x0c\n\x05\x04\x03\x02\x02\x04\x12\x036\x02\n\n\x0c\n\
-    \x05\x04\x03\x02\x02\x06\x12\x036\x0b\x13\n\x0c\n\x05\x04\x03\x02\x02\
-    \x01\x12\x036\x14\x19\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x036\x1c\x1d\n\
-    \x0b\n\x04\x04\x03\x02\x03\x12\x037\x02/\n\x0c\n\x05\x04\x03\x02\x03\x04\
-    \x12\x037\x02\n\n\x0c\n\x05\x04\x03\x02\x03\x06\x12\x037\x0b\"\n\x0c\n\
-    \x05\x04\x03\x02\x03\x01\x12\x037#*\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\
-    \x037-.\n\x0b\n\x04\x04\x03\x02\x04\x12\x038\x02\"\n\x0c\n\x05\x04\x03\
-    \x02\x04\x04\x12\x038\x02\n\n\x0c\n\x05\x04\x03\x02\x04\x06\x12\x038\x0b\
-    \x17\n\x0c\n\x05\x04\x03\x02\x04\x01\x12\x038\x18\x1d\n\x0c\n\x05\x04\
-    \x03\x02\x04\x03\x12\x038\x20!\n%\n\x04\x04\x03\x02\x05\x12\x039\x02!\"\
-    \x18\x20RFC3339-formatted\x20time\n\n\x0c\n\x05\x04\x03\x02\x05\x04\x12\
-    \x039\x02\n\n\x0c\n\x05\x04\x03\x02\x05\x05\x12\x039\x0b\x11\n\x0c\n\x05\
-    \x04\x03\x02\x05\x01\x12\x039\x12\x1c\n\x0c\n\x05\x04\x03\x02\x05\x03\
-    \x12\x039\x1f\x20\n%\n\x04\x04\x03\x02\x06\x12\x03:\x02'\"\x18\x20RFC333\
-    9-formatted\x20time\n\n\x0c\n\x05\x04\x03\x02\x06\x04\x12\x03:\x02\n\n\
-    \x0c\n\x05\x04\x03\x02\x06\x05\x12\x03:\x0b\x11\n\x0c\n\x05\x04\x03\x02\
-    \x06\x01\x12\x03:\x12\"\n\x0c\n\x05\x04\x03\x02\x06\x03\x12\x03:%&\n\x0b\
-    \n\x04\x04\x03\x02\x07\x12\x03;\x02(\n\x0c\n\x05\x04\x03\x02\x07\x04\x12\
-    \x03;\x02\n\n\x0c\n\x05\x04\x03\x02\x07\x05\x12\x03;\x0b\x11\n\x0c\n\x05\
-    \x04\x03\x02\x07\x01\x12\x03;\x12#\n\x0c\n\x05\x04\x03\x02\x07\x03\x12\
-    \x03;&'\n\x0b\n\x04\x04\x03\x02\x08\x12\x03<\x02:\n\x0c\n\x05\x04\x03\
-    \x02\x08\x04\x12\x03<\x02\n\n\x0c\n\x05\x04\x03\x02\x08\x06\x12\x03<\x0b\
-    '\n\x0c\n\x05\x04\x03\x02\x08\x01\x12\x03<(5\n\x0c\n\x05\x04\x03\x02\x08\
-    \x03\x12\x03<89\n\x0b\n\x04\x04\x03\x02\t\x12\x03=\x02!\n\x0c\n\x05\x04\
-    \x03\x02\t\x04\x12\x03=\x02\n\n\x0c\n\x05\x04\x03\x02\t\x05\x12\x03=\x0b\
-    \x0f\n\x0c\n\x05\x04\x03\x02\t\x01\x12\x03=\x10\x1b\n\x0c\n\x05\x04\x03\
-    \x02\t\x03\x12\x03=\x1e\x20\n\x0b\n\x04\x04\x03\x02\n\x12\x03>\x029\n\
-    \x0c\n\x05\x04\x03\x02\n\x04\x12\x03>\x02\n\n\x0c\n\x05\x04\x03\x02\n\
-    \x06\x12\x03>\x0b&\n\x0c\n\x05\x04\x03\x02\n\x01\x12\x03>'3\n\x0c\n\x05\
-    \x04\x03\x02\n\x03\x12\x03>68\n\x0b\n\x04\x04\x03\x02\x0b\x12\x03?\x02\
-    \x1f\n\x0c\n\x05\x04\x03\x02\x0b\x04\x12\x03?\x02\n\n\x0c\n\x05\x04\x03\
-    \x02\x0b\x05\x12\x03?\x0b\x11\n\x0c\n\x05\x04\x03\x02\x0b\x01\x12\x03?\
-    \x12\x19\n\x0c\n\x05\x04\x03\x02\x0b\x03\x12\x03?\x1c\x1e\n\x0b\n\x04\
-    \x04\x03\x02\x0c\x12\x03@\x02H\n\x0c\n\x05\x04\x03\x02\x0c\x04\x12\x03@\
-    \x02\n\n\x0c\n\x05\x04\x03\x02\x0c\x06\x12\x03@\x0b-\n\x0c\n\x05\x04\x03\
-    \x02\x0c\x01\x12\x03@.B\n\x0c\n\x05\x04\x03\x02\x0c\x03\x12\x03@EG\n\x0b\
-    \n\x04\x04\x03\x02\r\x12\x03A\x02\x1e\n\x0c\n\x05\x04\x03\x02\r\x04\x12\
-    \x03A\x02\n\n\x0c\n\x05\x04\x03\x02\r\x05\x12\x03A\x0b\x11\n\x0c\n\x05\
-    \x04\x03\x02\r\x01\x12\x03A\x12\x18\n\x0c\n\x05\x04\x03\x02\r\x03\x12\
-    \x03A\x1b\x1d\n\n\n\x02\x04\x04\x12\x04D\0F\x01\n\n\n\x03\x04\x04\x01\
-    \x12\x03D\x08\x0e\n\x0b\n\x04\x04\x04\x02\0\x12\x03E\x02\x19\n\x0c\n\x05\
-    \x04\x04\x02\0\x04\x12\x03E\x02\n\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03E\
-    \x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03E\x12\x14\n\x0c\n\x05\x04\
-    \x04\x02\0\x03\x12\x03E\x17\x18\n\n\n\x02\x04\x05\x12\x04H\0L\x01\n\n\n\
-    \x03\x04\x05\x01\x12\x03H\x08\x0f\n\x0b\n\x04\x04\x05\x02\0\x12\x03I\x02\
-    \x1f\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03I\x02\n\n\x0c\n\x05\x04\x05\
-    \x02\0\x05\x12\x03I\x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03I\x12\
-    \x1a\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03I\x1d\x1e\n\x0b\n\x04\x04\x05\
-    \x02\x01\x12\x03J\x02/\n\x0c\n\x05\x04\x05\x02\x01\x04\x12\x03J\x02\n\n\
-    \x0c\n\x05\x04\x05\x02\x01\x06\x12\x03J\x0b\"\n\x0c\n\x05\x04\x05\x02\
-    \x01\x01\x12\x03J#*\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03J-.\n\x0b\n\
-    \x04\x04\x05\x02\x02\x12\x03K\x02\x1e\n\x0c\n\x05\x04\x05\x02\x02\x04\
-    \x12\x03K\x02\n\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03K\x0b\x11\n\x0c\n\
-    \x05\x04\x05\x02\x02\x01\x12\x03K\x12\x19\n\x0c\n\x05\x04\x05\x02\x02\
-    \x03\x12\x03K\x1c\x1d\n\n\n\x02\x04\x06\x12\x04N\0R\x01\n\n\n\x03\x04\
-    \x06\x01\x12\x03N\x08\x16\n\x0b\n\x04\x04\x06\x02\0\x12\x03O\x02\x1b\n\
-    \x0c\n\x05\x04\x06\x02\0\x04\x12\x03O\x02\n\n\x0c\n\x05\x04\x06\x02\0\
-    \x05\x12\x03O\x0b\x11\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03O\x12\x16\n\
-    \x0c\n\x05\x04\x06\x02\0\x03\x12\x03O\x19\x1a\n\x0b\n\x04\x04\x06\x02\
-    \x01\x12\x03P\x02\x1c\n\x0c\n\x05\x04\x06\x02\x01\x04\x12\x03P\x02\n\n\
-    \x0c\n\x05\x04\x06\x02\x01\x05\x12\x03P\x0b\x11\n\x0c\n\x05\x04\x06\x02\
-    \x01\x01\x12\x03P\x12\x17\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03P\x1a\
-    \x1b\n\x0b\n\x04\x04\x06\x02\x02\x12\x03Q\x02\x1b\n\x0c\n\x05\x04\x06\
-    \x02\x02\x04\x12\x03Q\x02\n\n\x0c\n\x05\x04\x06\x02\x02\x05\x12\x03Q\x0b\
-    \x11\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03Q\x12\x16\n\x0c\n\x05\x04\
-    \x06\x02\x02\x03\x12\x03Q\x19\x1a\n\n\n\x02\x04\x07\x12\x04T\0Y\x01\n\n\
-    \n\x03\x04\x07\x01\x12\x03T\x08\x1e\n\x0b\n\x04\x04\x07\x02\0\x12\x03U\
-    \x02\x18\n\x0c\n\x05\x04\x07\x02\0\x04\x12\x03U\x02\n\n\x0c\n\x05\x04\
-    \x07\x02\0\x06\x12\x03U\x0b\x0e\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03U\
-    \x0f\x13\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03U\x16\x17\n\x0b\n\x04\x04\
-    \x07\x02\x01\x12\x03V\x02\x1c\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x03V\
-    \x02\n\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03V\x0b\x11\n\x0c\n\x05\x04\
-    \x07\x02\x01\x01\x12\x03V\x12\x17\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\
-    \x03V\x1a\x1b\n\x0b\n\x04\x04\x07\x02\x02\x12\x03W\x02\x1b\n\x0c\n\x05\
-    \x04\x07\x02\x02\x04\x12\x03W\x02\n\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\
-    \x03W\x0b\x11\n\x0c\n\x05\x04\x07\x02\x02\x01\x12\x03W\x12\x16\n\x0c\n\
-    \x05\x04\x07\x02\x02\x03\x12\x03W\x19\x1a\n\x0b\n\x04\x04\x07\x02\x03\
-    \x12\x03X\x02\x1c\n\x0c\n\x05\x04\x07\x02\x03\x04\x12\x03X\x02\n\n\x0c\n\
-    \x05\x04\x07\x02\x03\x05\x12\x03X\x0b\x11\n\x0c\n\x05\x04\x07\x02\x03\
-    \x01\x12\x03X\x12\x17\n\x0c\n\x05\x04\x07\x02\x03\x03\x12\x03X\x1a\x1b\n\
-    \n\n\x02\x04\x08\x12\x04[\0_\x01\n\n\n\x03\x04\x08\x01\x12\x03[\x08\x13\
-    \n\x0b\n\x04\x04\x08\x02\0\x12\x03\\\x02\x1d\n\x0c\n\x05\x04\x08\x02\0\
-    \x04\x12\x03\\\x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03\\\x0b\x11\n\
-    \x0c\n\x05\x04\x08\x02\0\x01\x12\x03\\\x12\x18\n\x0c\n\x05\x04\x08\x02\0\
-    \x03\x12\x03\\\x1b\x1c\n+\n\x04\x04\x08\x02\x01\x12\x03]\x02\x1a\"\x1e\
-    \x20Chunk\x20ordering\x20(line\x20number)\n\n\x0c\n\x05\x04\x08\x02\x01\
-    \x04\x12\x03]\x02\n\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03]\x0b\x11\n\
-    \x0c\n\x05\x04\x08\x02\x01\x01\x12\x03]\x12\x15\n\x0c\n\x05\x04\x08\x02\
-    \x01\x03\x12\x03]\x18\x19\n5\n\x04\x04\x08\x02\x02\x12\x03^\x02\x1e\"(\
-    \x20Log\x20content\x20(TODO:\x20Make\x20repeatedfield)\n\n\x0c\n\x05\x04\
-    \x08\x02\x02\x04\x12\x03^\x02\n\n\x0c\n\x05\x04\x08\x02\x02\x05\x12\x03^\
-    \x0b\x11\n\x0c\n\x05\x04\x08\x02\x02\x01\x12\x03^\x12\x19\n\x0c\n\x05\
-    \x04\x08\x02\x02\x03\x12\x03^\x1c\x1d\n\n\n\x02\x04\t\x12\x04a\0c\x01\n\
-    \n\n\x03\x04\t\x01\x12\x03a\x08\x16\n\x0b\n\x04\x04\t\x02\0\x12\x03b\x02\
-    \x1d\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03b\x02\n\n\x0c\n\x05\x04\t\x02\0\
-    \x05\x12\x03b\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03b\x12\x18\n\x0c\
-    \n\x05\x04\t\x02\0\x03\x12\x03b\x1b\x1c\n\n\n\x02\x04\n\x12\x04e\0h\x01\
-    \n\n\n\x03\x04\n\x01\x12\x03e\x08\x11\n\x0b\n\x04\x04\n\x02\0\x12\x03f\
-    \x02\x19\n\x0c\n\x05\x04\n\x02\0\x04\x12\x03f\x02\n\n\x0c\n\x05\x04\n\
-    \x02\0\x05\x12\x03f\x0b\x11\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03f\x12\x14\
-    \n\x0c\n\x05\x04\n\x02\0\x03\x12\x03f\x17\x18\n.\n\x04\x04\n\x02\x01\x12\
-    \x03g\x02\x1c\"!\x20Zero-indexed\x20line\x20of\x20log\x20output\n\n\x0c\
-    \n\x05\x04\n\x02\x01\x04\x12\x03g\x02\n\n\x0c\n\x05\x04\n\x02\x01\x05\
-    \x12\x03g\x0b\x11\n\x0c\n\x05\x04\n\x02\x01\x01\x12\x03g\x12\x17\n\x0c\n\
-    \x05\x04\n\x02\x01\x03\x12\x03g\x1a\x1b\n\n\n\x02\x04\x0b\x12\x04j\0o\
-    \x01\n\n\n\x03\x04\x0b\x01\x12\x03j\x08\x0e\n,\n\x04\x04\x0b\x02\0\x12\
-    \x03k\x02\x1c\"\x1f\x20Zero-indexed\x20(inclusive)\x20line\n\n\x0c\n\x05\
-    \x04\x0b\x02\0\x04\x12\x03k\x02\n\n\x0c\n\x05\x04\x0b\x02\0\x05\x12\x03k\
-    \x0b\x11\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03k\x12\x17\n\x0c\n\x05\x04\
-    \x0b\x02\0\x03\x12\x03k\x1a\x1b\n,\n\x04\x04\x0b\x02\x01\x12\x03l\x02\
-    \x1b\"\x1f\x20Zero-indexed\x20(exclusive)\x20line\n\n\x0c\n\x05\x04\x0b\
-    \x02\x01\x04\x12\x03l\x02\n\n\x0c\n\x05\x04\x0b\x02\x01\x05\x12\x03l\x0b\
-    \x11\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03l\x12\x16\n\x0c\n\x05\x04\
-    \x0b\x02\x01\x03\x12\x03l\x19\x1a\n\x0b\n\x04\x04\x0b\x02\x02\x12\x03m\
-    \x02\x1e\n\x0c\n\x05\x04\x0b\x02\x02\x04\x12\x03m\x02\n\n\x0c\n\x05\x04\
-    \x0b\x02\x02\x05\x12\x03m\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x02\x01\x12\
-    \x03m\x12\x19\n\x0c\n\x05\x04\x0b\x02\x02\x03\x12\x03m\x1c\x1d\n\x0b\n\
-    \x04\x04\x0b\x02\x03\x12\x03n\x02\x20\n\x0c\n\x05\x04\x0b\x02\x03\x04\
-    \x12\x03n\x02\n\n\x0c\n\x05\x04\x0b\x02\x03\x05\x12\x03n\x0b\x0f\n\x0c\n\
-    \x05\x04\x0b\x02\x03\x01\x12\x03n\x10\x1b\n\x0c\n\x05\x04\x0b\x02\x03\
-    \x03\x12\x03n\x1e\x1f\n\n\n\x02\x04\x0c\x12\x04q\0x\x01\n\n\n\x03\x04\
-    \x0c\x01\x12\x03q\x08\x14\n\x0b\n\x04\x04\x0c\x02\0\x12\x03r\x02\x1d\n\
-    \x0c\n\x05\x04\x0c\x02\0\x04\x12\x03r\x02\n\n\x0c\n\x05\x04\x0c\x02\0\
-    \x05\x12\x03r\x0b\x11\n\x0c\n\x05\x04\x0c\x02\0\x01\x12\x03r\x12\x18\n\
-    \x0c\n\x05\x04\x0c\x02\0\x03\x12\x03r\x1b\x1c\n\x0b\n\x04\x04\x0c\x02\
-    \x01\x12\x03s\x02\x1e\n\x0c\n\x05\x04\x0c\x02\x01\x04\x12\x03s\x02\n\n\
-    \x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03s\x0b\x11\n\x0c\n\x05\x04\x0c\x02\
-    \x01\x01\x12\x03s\x12\x19\n\x0c\n\x05\x04\x0c\x02\x01\x03\x12\x03s\x1c\
-    \x1d\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03t\x02\x1e\n\x0c\n\x05\x04\x0c\
-    \x02\x02\x04\x12\x03t\x02\n\n\x0c\n\x05\x04\x0c\x02\x02\x05\x12\x03t\x0b\
-    \x0f\n\x0c\n\x05\x04\x0c\x02\x02\x01\x12\x03t\x10\x19\n\x0c\n\x05\x04\
-    \x0c\x02\x02\x03\x12\x03t\x1c\x1d\n\x0b\n\x04\x04\x0c\x02\x03\x12\x03u\
-    \x02\x1d\n\x0c\n\x05\x04\x0c\x02\x03\x04\x12\x03u\x02\n\n\x0c\n\x05\x04\
-    \x0c\x02\x03\x05\x12\x03u\x0b\x11\n\x0c\n\x05\x04\x0c\x02\x03\x01\x12\
-    \x03u\x12\x18\n\x0c\n\x05\x04\x0c\x02\x03\x03\x12\x03u\x1b\x1c\n\x0b\n\
-    \x04\x04\x0c\x02\x04\x12\x03v\x02\x20\n\x0c\n\x05\x04\x0c\x02\x04\x04\
-    \x12\x03v\x02\n\n\x0c\n\x05\x04\x0c\x02\x04\x05\x12\x03v\x0b\x0f\n\x0c\n\
-    \x05\x04\x0c\x02\x04\x01\x12\x03v\x10\x1b\n\x0c\n\x05\x04\x0c\x02\x04\
-    \x03\x12\x03v\x1e\x1f\n\x0b\n\x04\x04\x0c\x02\x05\x12\x03w\x02!\n\x0c\n\
-    \x05\x04\x0c\x02\x05\x04\x12\x03w\x02\n\n\x0c\n\x05\x04\x0c\x02\x05\x05\
-    \x12\x03w\x0b\x0f\n\x0c\n\x05\x04\x0c\x02\x05\x01\x12\x03w\x10\x1c\n\x0c\
-    \n\x05\x04\x0c\x02\x05\x03\x12\x03w\x1f\x20\n\x0b\n\x02\x05\x04\x12\x05z\
-    \0\x81\x01\x01\n\n\n\x03\x05\x04\x01\x12\x03z\x05\x19\n\x0b\n\x04\x05\
-    \x04\x02\0\x12\x03{\x02\x11\n\x0c\n\x05\x05\x04\x02\0\x01\x12\x03{\x02\
-    \x0c\n\x0c\n\x05\x05\x04\x02\0\x02\x12\x03{\x0f\x10\n\x0b\n\x04\x05\x04\
-    \x02\x01\x12\x03|\x02\x11\n\x0c\n\x05\x05\x04\x02\x01\x01\x12\x03|\x02\
-    \x0c\n\x0c\n\x05\x05\x04\x02\x01\x02\x12\x03|\x0f\x10\n\x0b\n\x04\x05\
-    \x04\x02\x02\x12\x03}\x02\x0e\n\x0c\n\x05\x05\x04\x02\x02\x01\x12\x03}\
-    \x02\t\n\x0c\n\x05\x05\x04\x02\x02\x02\x12\x03}\x0c\r\n\x0b\n\x04\x05\
-    \x04\x02\x03\x12\x03~\x02\x0e\n\x0c\n\x05\x05\x04\x02\x03\x01\x12\x03~\
-    \x02\t\n\x0c\n\x05\x05\x04\x02\x03\x02\x12\x03~\x0c\r\n\x0b\n\x04\x05\
-    \x04\x02\x04\x12\x03\x7f\x02\x0e\n\x0c\n\x05\x05\x04\x02\x04\x01\x12\x03\
-    \x7f\x02\t\n\x0c\n\x05\x05\x04\x02\x04\x02\x12\x03\x7f\x0c\r\n\x0c\n\x04\
-    \x05\x04\x02\x05\x12\x04\x80\x01\x02\x0f\n\r\n\x05\x05\x04\x02\x05\x01\
-    \x12\x04\x80\x01\x02\n\n\r\n\x05\x05\x04\x02\x05\x02\x12\x04\x80\x01\r\
-    \x0e\n\x0c\n\x02\x04\r\x12\x06\x83\x01\0\x89\x01\x01\n\x0b\n\x03\x04\r\
-    \x01\x12\x04\x83\x01\x08\x17\n\x0c\n\x04\x04\r\x02\0\x12\x04\x84\x01\x02\
-    \x1b\n\r\n\x05\x04\r\x02\0\x04\x12\x04\x84\x01\x02\n\n\r\n\x05\x04\r\x02\
-    \0\x05\x12\x04\x84\x01\x0b\x11\n\r\n\x05\x04\r\x02\0\x01\x12\x04\x84\x01\
-    \x12\x16\n\r\n\x05\x04\r\x02\0\x03\x12\x04\x84\x01\x19\x1a\n\x0c\n\x04\
-    \x04\r\x02\x01\x12\x04\x85\x01\x02\x1c\n\r\n\x05\x04\r\x02\x01\x04\x12\
-    \x04\x85\x01\x02\n\n\r\n\x05\x04\r\x02\x01\x05\x12\x04\x85\x01\x0b\x11\n\
-    \r\n\x05\x04\r\x02\x01\x01\x12\x04\x85\x01\x12\x17\n\r\n\x05\x04\r\x02\
-    \x01\x03\x12\x04\x85\x01\x1a\x1b\n\x0c\n\x04\x04\r\x02\x02\x12\x04\x86\
-    \x01\x02*\n\r\n\x05\x04\r\x02\x02\x04\x12\x04\x86\x01\x02\n\n\r\n\x05\
-    \x04\r\x02\x02\x06\x12\x04\x86\x01\x0b\x1f\n\r\n\x05\x04\r\x02\x02\x01\
-    \x12\x04\x86\x01\x20%\n\r\n\x05\x04\r\x02\x02\x03\x12\x04\x86\x01()\n\
-    \x0c\n\x04\x04\r\x02\x03\x12\x04\x87\x01\x02\x1d\n\r\n\x05\x04\r\x02\x03\
-    \x04\x12\x04\x87\x01\x02\n\n\r\n\x05\x04\r\x02\x03\x05\x12\x04\x87\x01\
-    \x0b\x11\n\r\n\x05\x04\r\x02\x03\x01\x12\x04\x87\x01\x12\x18\n\r\n\x05\
-    \x04\r\x02\x03\x03\x12\x04\x87\x01\x1b\x1c\n\x0c\n\x04\x04\r\x02\x04\x12\
-    \x04\x88\x01\x02\x1d\n\r\n\x05\x04\r\x02\x04\x04\x12\x04\x88\x01\x02\n\n\
-    \r\n\x05\x04\r\x02\x04\x05\x12\x04\x88\x01\x0b\x11\n\r\n\x05\x04\r\x02\
-    \x04\x01\x12\x04\x88\x01\x12\x18\n\r\n\x05\x04\r\x02\x04\x03\x12\x04\x88\
-    \x01\x1b\x1c\n\x0c\n\x02\x05\x05\x12\x06\x8b\x01\0\x92\x01\x01\n\x0b\n\
-    \x03\x05\x05\x01\x12\x04\x8b\x01\x05\x12\n\x0c\n\x04\x05\x05\x02\0\x12\
+    kages\"D\n\rPackageJobGet\x123\n\x05ident\x18\x01\x20\x01(\x0b2\x1d.orig\
+    insrv.OriginPackageIdentR\x05ident*(\n\x02Os\x12\t\n\x05Linux\x10\x01\
+    \x12\n\n\x06Darwin\x10\x02\x12\x0b\n\x07Windows\x10\x03*\"\n\x0bWorkerSt\
+    ate\x12\t\n\x05Ready\x10\0\x12\x08\n\x04Busy\x10\x01*.\n\x0fWorkerOperat\
+    ion\x12\x0c\n\x08StartJob\x10\0\x12\r\n\tCancelJob\x10\x01*\x9c\x01\n\
+    \x08JobState\x12\x0b\n\x07Pending\x10\0\x12\x0e\n\nProcessing\x10\x01\
+    \x12\x0c\n\x08Complete\x10\x02\x12\x0c\n\x08Rejected\x10\x03\x12\n\n\x06\
+    Failed\x10\x04\x12\x0e\n\nDispatched\x10\x05\x12\x11\n\rCancelPending\
+    \x10\x06\x12\x14\n\x10CancelProcessing\x10\x07\x12\x12\n\x0eCancelComple\
+    te\x10\x08*k\n\x14JobGroupProjectState\x12\x0e\n\nNotStarted\x10\0\x12\
+    \x0e\n\nInProgress\x10\x01\x12\x0b\n\x07Success\x10\x02\x12\x0b\n\x07Fai\
+    lure\x10\x03\x12\x0b\n\x07Skipped\x10\x04\x12\x0c\n\x08Canceled\x10\x05*\
+    \x7f\n\rJobGroupState\x12\x10\n\x0cGroupPending\x10\0\x12\x14\n\x10Group\
+    Dispatching\x10\x01\x12\x11\n\rGroupComplete\x10\x02\x12\x0f\n\x0bGroupF\
+    ailed\x10\x03\x12\x0f\n\x0bGroupQueued\x10\x04\x12\x11\n\rGroupCanceled\
+    \x10\x05J\x8aA\n\x07\x12\x05\0\0\xdb\x01\x01\n\x08\n\x01\x0c\x12\x03\0\0\
+    \x12\n\x08\n\x01\x02\x12\x03\x01\x08\x0e\n\t\n\x02\x03\0\x12\x03\x02\x07\
+    \x1c\n\t\n\x02\x03\x01\x12\x03\x03\x07\"\n\n\n\x02\x05\0\x12\x04\x05\0\t\
+    \x01\n\n\n\x03\x05\0\x01\x12\x03\x05\x05\x07\n\x0b\n\x04\x05\0\x02\0\x12\
+    \x03\x06\x02\x0c\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x06\x02\x07\n\x0c\n\
+    \x05\x05\0\x02\0\x02\x12\x03\x06\n\x0b\n\x0b\n\x04\x05\0\x02\x01\x12\x03\
+    \x07\x02\r\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x07\x02\x08\n\x0c\n\x05\
+    \x05\0\x02\x01\x02\x12\x03\x07\x0b\x0c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\
+    \x08\x02\x0e\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x08\x02\t\n\x0c\n\x05\
+    \x05\0\x02\x02\x02\x12\x03\x08\x0c\r\n\n\n\x02\x05\x01\x12\x04\x0b\0\x0e\
+    \x01\n\n\n\x03\x05\x01\x01\x12\x03\x0b\x05\x10\n\x0b\n\x04\x05\x01\x02\0\
+    \x12\x03\x0c\x02\x0c\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x0c\x02\x07\n\
+    \x0c\n\x05\x05\x01\x02\0\x02\x12\x03\x0c\n\x0b\n\x0b\n\x04\x05\x01\x02\
+    \x01\x12\x03\r\x02\x0b\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\r\x02\x06\
+    \n\x0c\n\x05\x05\x01\x02\x01\x02\x12\x03\r\t\n\n\n\n\x02\x05\x02\x12\x04\
+    \x10\0\x13\x01\n\n\n\x03\x05\x02\x01\x12\x03\x10\x05\x14\n\x0b\n\x04\x05\
+    \x02\x02\0\x12\x03\x11\x02\x0f\n\x0c\n\x05\x05\x02\x02\0\x01\x12\x03\x11\
+    \x02\n\n\x0c\n\x05\x05\x02\x02\0\x02\x12\x03\x11\r\x0e\n\x0b\n\x04\x05\
+    \x02\x02\x01\x12\x03\x12\x02\x10\n\x0c\n\x05\x05\x02\x02\x01\x01\x12\x03\
+    \x12\x02\x0b\n\x0c\n\x05\x05\x02\x02\x01\x02\x12\x03\x12\x0e\x0f\n\n\n\
+    \x02\x05\x03\x12\x04\x15\0\x1f\x01\n\n\n\x03\x05\x03\x01\x12\x03\x15\x05\
+    \r\n\x0b\n\x04\x05\x03\x02\0\x12\x03\x16\x02\x0e\n\x0c\n\x05\x05\x03\x02\
+    \0\x01\x12\x03\x16\x02\t\n\x0c\n\x05\x05\x03\x02\0\x02\x12\x03\x16\x0c\r\
+    \n\x0b\n\x04\x05\x03\x02\x01\x12\x03\x17\x02\x11\n\x0c\n\x05\x05\x03\x02\
+    \x01\x01\x12\x03\x17\x02\x0c\n\x0c\n\x05\x05\x03\x02\x01\x02\x12\x03\x17\
+    \x0f\x10\n\x0b\n\x04\x05\x03\x02\x02\x12\x03\x18\x02\x0f\n\x0c\n\x05\x05\
+    \x03\x02\x02\x01\x12\x03\x18\x02\n\n\x0c\n\x05\x05\x03\x02\x02\x02\x12\
+    \x03\x18\r\x0e\n\x0b\n\x04\x05\x03\x02\x03\x12\x03\x19\x02\x0f\n\x0c\n\
+    \x05\x05\x03\x02\x03\x01\x12\x03\x19\x02\n\n\x0c\n\x05\x05\x03\x02\x03\
+    \x02\x12\x03\x19\r\x0e\n\x0b\n\x04\x05\x03\x02\x04\x12\x03\x1a\x02\r\n\
+    \x0c\n\x05\x05\x03\x02\x04\x01\x12\x03\x1a\x02\x08\n\x0c\n\x05\x05\x03\
+    \x02\x04\x02\x12\x03\x1a\x0b\x0c\n\x0b\n\x04\x05\x03\x02\x05\x12\x03\x1b\
+    \x02\x11\n\x0c\n\x05\x05\x03\x02\x05\x01\x12\x03\x1b\x02\x0c\n\x0c\n\x05\
+    \x05\x03\x02\x05\x02\x12\x03\x1b\x0f\x10\n\x0b\n\x04\x05\x03\x02\x06\x12\
+    \x03\x1c\x02\x14\n\x0c\n\x05\x05\x03\x02\x06\x01\x12\x03\x1c\x02\x0f\n\
+    \x0c\n\x05\x05\x03\x02\x06\x02\x12\x03\x1c\x12\x13\n\x0b\n\x04\x05\x03\
+    \x02\x07\x12\x03\x1d\x02\x17\n\x0c\n\x05\x05\x03\x02\x07\x01\x12\x03\x1d\
+    \x02\x12\n\x0c\n\x05\x05\x03\x02\x07\x02\x12\x03\x1d\x15\x16\n\x0b\n\x04\
+    \x05\x03\x02\x08\x12\x03\x1e\x02\x15\n\x0c\n\x05\x05\x03\x02\x08\x01\x12\
+    \x03\x1e\x02\x10\n\x0c\n\x05\x05\x03\x02\x08\x02\x12\x03\x1e\x13\x14\n\n\
+    \n\x02\x04\0\x12\x04!\0#\x01\n\n\n\x03\x04\0\x01\x12\x03!\x08\x15\n\x0b\
+    \n\x04\x04\0\x02\0\x12\x03\"\x02\"\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\"\
+    \x02\n\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\"\x0b\x1a\n\x0c\n\x05\x04\0\
+    \x02\0\x01\x12\x03\"\x1b\x1d\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\"\x20!\
+    \n\n\n\x02\x04\x01\x12\x04%\0)\x01\n\n\n\x03\x04\x01\x01\x12\x03%\x08\
+    \x11\n\x0b\n\x04\x04\x01\x02\0\x12\x03&\x02\x1f\n\x0c\n\x05\x04\x01\x02\
+    \0\x04\x12\x03&\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03&\x0b\x11\n\
+    \x0c\n\x05\x04\x01\x02\0\x01\x12\x03&\x12\x1a\n\x0c\n\x05\x04\x01\x02\0\
+    \x03\x12\x03&\x1d\x1e\n\x0b\n\x04\x04\x01\x02\x01\x12\x03'\x02\x15\n\x0c\
+    \n\x05\x04\x01\x02\x01\x04\x12\x03'\x02\n\n\x0c\n\x05\x04\x01\x02\x01\
+    \x06\x12\x03'\x0b\r\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03'\x0e\x10\n\
+    \x0c\n\x05\x04\x01\x02\x01\x03\x12\x03'\x13\x14\n\x0b\n\x04\x04\x01\x02\
+    \x02\x12\x03(\x02!\n\x0c\n\x05\x04\x01\x02\x02\x04\x12\x03(\x02\n\n\x0c\
+    \n\x05\x04\x01\x02\x02\x06\x12\x03(\x0b\x16\n\x0c\n\x05\x04\x01\x02\x02\
+    \x01\x12\x03(\x17\x1c\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03(\x1f\x20\n\
+    \n\n\x02\x04\x02\x12\x04+\0/\x01\n\n\n\x03\x04\x02\x01\x12\x03+\x08\x12\
+    \n\x0b\n\x04\x04\x02\x02\0\x12\x03,\x02\x1c\n\x0c\n\x05\x04\x02\x02\0\
+    \x04\x12\x03,\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03,\x0b\x11\n\x0c\
+    \n\x05\x04\x02\x02\0\x01\x12\x03,\x12\x17\n\x0c\n\x05\x04\x02\x02\0\x03\
+    \x12\x03,\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x01\x12\x03-\x02\x1d\n\x0c\n\
+    \x05\x04\x02\x02\x01\x04\x12\x03-\x02\n\n\x0c\n\x05\x04\x02\x02\x01\x05\
+    \x12\x03-\x0b\x11\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03-\x12\x18\n\x0c\
+    \n\x05\x04\x02\x02\x01\x03\x12\x03-\x1b\x1c\n\x0b\n\x04\x04\x02\x02\x02\
+    \x12\x03.\x02\x20\n\x0c\n\x05\x04\x02\x02\x02\x04\x12\x03.\x02\n\n\x0c\n\
+    \x05\x04\x02\x02\x02\x05\x12\x03.\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\
+    \x01\x12\x03.\x10\x1b\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03.\x1e\x1f\n\
+    \n\n\x02\x04\x03\x12\x041\0B\x01\n\n\n\x03\x04\x03\x01\x12\x031\x08\x0b\
+    \n\n\n\x03\x04\x03\t\x12\x032\x0b\x0e\n\x0b\n\x04\x04\x03\t\0\x12\x032\
+    \x0b\r\n\x0c\n\x05\x04\x03\t\0\x01\x12\x032\x0b\r\n\x0c\n\x05\x04\x03\t\
+    \0\x02\x12\x032\x0b\r\n\n\n\x03\x04\x03\n\x12\x033\x0b\x15\n\x0b\n\x04\
+    \x04\x03\n\0\x12\x033\x0b\x14\n\x0b\n\x04\x04\x03\x02\0\x12\x034\x02\x19\
+    \n\x0c\n\x05\x04\x03\x02\0\x04\x12\x034\x02\n\n\x0c\n\x05\x04\x03\x02\0\
+    \x05\x12\x034\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x034\x12\x14\n\
+    \x0c\n\x05\x04\x03\x02\0\x03\x12\x034\x17\x18\n\x0b\n\x04\x04\x03\x02\
+    \x01\x12\x035\x02\x1f\n\x0c\n\x05\x04\x03\x02\x01\x04\x12\x035\x02\n\n\
+    \x0c\n\x05\x04\x03\x02\x01\x05\x12\x035\x0b\x11\n\x0c\n\x05\x04\x03\x02\
+    \x01\x01\x12\x035\x12\x1a\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x035\x1d\
+    \x1e\n\x0b\n\x04\x04\x03\x02\x02\x12\x036\x02\x1e\n\x0c\n\x05\x04\x03\
+    \x02\x02\x04\x12\x036\x02\n\n\x0c\n\x05\x04\x03\x02\x02\x06\x12\x036\x0b\
+    \x13\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x036\x14\x19\n\x0c\n\x05\x04\
+    \x03\x02\x02\x03\x12\x036\x1c\x1d\n\x0b\n\x04\x04\x03\x02\x03\x12\x037\
+    \x02/\n\x0c\n\x05\x04\x03\x02\x03\x04\x12\x037\x02\n\n\x0c\n\x05\x04\x03\
+    \x02\x03\x06\x12\x037\x0b\"\n\x0c\n\x05\x04\x03\x02\x03\x01\x12\x037#*\n\
+    \x0c\n\x05\x04\x03\x02\x03\x03\x12\x037-.\n\x0b\n\x04\x04\x03\x02\x04\
+    \x12\x038\x02\"\n\x0c\n\x05\x04\x03\x02\x04\x04\x12\x038\x02\n\n\x0c\n\
+    \x05\x04\x03\x02\x04\x06\x12\x038\x0b\x17\n\x0c\n\x05\x04\x03\x02\x04\
+    \x01\x12\x038\x18\x1d\n\x0c\n\x05\x04\x03\x02\x04\x03\x12\x038\x20!\n%\n\
+    \x04\x04\x03\x02\x05\x12\x039\x02!\"\x18\x20RFC3339-formatted\x20time\n\
+    \n\x0c\n\x05\x04\x03\x02\x05\x04\x12\x039\x02\n\n\x0c\n\x05\x04\x03\x02\
+    \x05\x05\x12\x039\x0b\x11\n\x0c\n\x05\x04\x03\x02\x05\x01\x12\x039\x12\
+    \x1c\n\x0c\n\x05\x04\x03\x02\x05\x03\x12\x039\x1f\x20\n%\n\x04\x04\x03\
+    \x02\x06\x12\x03:\x02'\"\x18\x20RFC3339-formatted\x20time\n\n\x0c\n\x05\
+    \x04\x03\x02\x06\x04\x12\x03:\x02\n\n\x0c\n\x05\x04\x03\x02\x06\x05\x12\
+    \x03:\x0b\x11\n\x0c\n\x05\x04\x03\x02\x06\x01\x12\x03:\x12\"\n\x0c\n\x05\
+    \x04\x03\x02\x06\x03\x12\x03:%&\n\x0b\n\x04\x04\x03\x02\x07\x12\x03;\x02\
+    (\n\x0c\n\x05\x04\x03\x02\x07\x04\x12\x03;\x02\n\n\x0c\n\x05\x04\x03\x02\
+    \x07\x05\x12\x03;\x0b\x11\n\x0c\n\x05\x04\x03\x02\x07\x01\x12\x03;\x12#\
+    \n\x0c\n\x05\x04\x03\x02\x07\x03\x12\x03;&'\n\x0b\n\x04\x04\x03\x02\x08\
+    \x12\x03<\x02:\n\x0c\n\x05\x04\x03\x02\x08\x04\x12\x03<\x02\n\n\x0c\n\
+    \x05\x04\x03\x02\x08\x06\x12\x03<\x0b'\n\x0c\n\x05\x04\x03\x02\x08\x01\
+    \x12\x03<(5\n\x0c\n\x05\x04\x03\x02\x08\x03\x12\x03<89\n\x0b\n\x04\x04\
+    \x03\x02\t\x12\x03=\x02!\n\x0c\n\x05\x04\x03\x02\t\x04\x12\x03=\x02\n\n\
+    \x0c\n\x05\x04\x03\x02\t\x05\x12\x03=\x0b\x0f\n\x0c\n\x05\x04\x03\x02\t\
+    \x01\x12\x03=\x10\x1b\n\x0c\n\x05\x04\x03\x02\t\x03\x12\x03=\x1e\x20\n\
+    \x0b\n\x04\x04\x03\x02\n\x12\x03>\x029\n\x0c\n\x05\x04\x03\x02\n\x04\x12\
+    \x03>\x02\n\n\x0c\n\x05\x04\x03\x02\n\x06\x12\x03>\x0b&\n\x0c\n\x05\x04\
+    \x03\x02\n\x01\x12\x03>'3\n\x0c\n\x05\x04\x03\x02\n\x03\x12\x03>68\n\x0b\
+    \n\x04\x04\x03\x02\x0b\x12\x03?\x02\x1f\n\x0c\n\x05\x04\x03\x02\x0b\x04\
+    \x12\x03?\x02\n\n\x0c\n\x05\x04\x03\x02\x0b\x05\x12\x03?\x0b\x11\n\x0c\n\
+    \x05\x04\x03\x02\x0b\x01\x12\x03?\x12\x19\n\x0c\n\x05\x04\x03\x02\x0b\
+    \x03\x12\x03?\x1c\x1e\n\x0b\n\x04\x04\x03\x02\x0c\x12\x03@\x02H\n\x0c\n\
+    \x05\x04\x03\x02\x0c\x04\x12\x03@\x02\n\n\x0c\n\x05\x04\x03\x02\x0c\x06\
+    \x12\x03@\x0b-\n\x0c\n\x05\x04\x03\x02\x0c\x01\x12\x03@.B\n\x0c\n\x05\
+    \x04\x03\x02\x0c\x03\x12\x03@EG\n\x0b\n\x04\x04\x03\x02\r\x12\x03A\x02\
+    \x1e\n\x0c\n\x05\x04\x03\x02\r\x04\x12\x03A\x02\n\n\x0c\n\x05\x04\x03\
+    \x02\r\x05\x12\x03A\x0b\x11\n\x0c\n\x05\x04\x03\x02\r\x01\x12\x03A\x12\
+    \x18\n\x0c\n\x05\x04\x03\x02\r\x03\x12\x03A\x1b\x1d\n\n\n\x02\x04\x04\
+    \x12\x04D\0F\x01\n\n\n\x03\x04\x04\x01\x12\x03D\x08\x0e\n\x0b\n\x04\x04\
+    \x04\x02\0\x12\x03E\x02\x19\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x03E\x02\n\
+    \n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03E\x0b\x11\n\x0c\n\x05\x04\x04\x02\
+    \0\x01\x12\x03E\x12\x14\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03E\x17\x18\n\
+    \n\n\x02\x04\x05\x12\x04H\0L\x01\n\n\n\x03\x04\x05\x01\x12\x03H\x08\x0f\
+    \n\x0b\n\x04\x04\x05\x02\0\x12\x03I\x02\x1f\n\x0c\n\x05\x04\x05\x02\0\
+    \x04\x12\x03I\x02\n\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03I\x0b\x11\n\x0c\
+    \n\x05\x04\x05\x02\0\x01\x12\x03I\x12\x1a\n\x0c\n\x05\x04\x05\x02\0\x03\
+    \x12\x03I\x1d\x1e\n\x0b\n\x04\x04\x05\x02\x01\x12\x03J\x02/\n\x0c\n\x05\
+    \x04\x05\x02\x01\x04\x12\x03J\x02\n\n\x0c\n\x05\x04\x05\x02\x01\x06\x12\
+    \x03J\x0b\"\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03J#*\n\x0c\n\x05\x04\
+    \x05\x02\x01\x03\x12\x03J-.\n\x0b\n\x04\x04\x05\x02\x02\x12\x03K\x02\x1e\
+    \n\x0c\n\x05\x04\x05\x02\x02\x04\x12\x03K\x02\n\n\x0c\n\x05\x04\x05\x02\
+    \x02\x05\x12\x03K\x0b\x11\n\x0c\n\x05\x04\x05\x02\x02\x01\x12\x03K\x12\
+    \x19\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03K\x1c\x1d\n\n\n\x02\x04\x06\
+    \x12\x04N\0R\x01\n\n\n\x03\x04\x06\x01\x12\x03N\x08\x16\n\x0b\n\x04\x04\
+    \x06\x02\0\x12\x03O\x02\x1b\n\x0c\n\x05\x04\x06\x02\0\x04\x12\x03O\x02\n\
+    \n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03O\x0b\x11\n\x0c\n\x05\x04\x06\x02\
+    \0\x01\x12\x03O\x12\x16\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03O\x19\x1a\n\
+    \x0b\n\x04\x04\x06\x02\x01\x12\x03P\x02\x1c\n\x0c\n\x05\x04\x06\x02\x01\
+    \x04\x12\x03P\x02\n\n\x0c\n\x05\x04\x06\x02\x01\x05\x12\x03P\x0b\x11\n\
+    \x0c\n\x05\x04\x06\x02\x01\x01\x12\x03P\x12\x17\n\x0c\n\x05\x04\x06\x02\
+    \x01\x03\x12\x03P\x1a\x1b\n\x0b\n\x04\x04\x06\x02\x02\x12\x03Q\x02\x1b\n\
+    \x0c\n\x05\x04\x06\x02\x02\x04\x12\x03Q\x02\n\n\x0c\n\x05\x04\x06\x02\
+    \x02\x05\x12\x03Q\x0b\x11\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03Q\x12\
+    \x16\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03Q\x19\x1a\n\n\n\x02\x04\x07\
+    \x12\x04T\0Y\x01\n\n\n\x03\x04\x07\x01\x12\x03T\x08\x1e\n\x0b\n\x04\x04\
+    \x07\x02\0\x12\x03U\x02\x18\n\x0c\n\x05\x04\x07\x02\0\x04\x12\x03U\x02\n\
+    \n\x0c\n\x05\x04\x07\x02\0\x06\x12\x03U\x0b\x0e\n\x0c\n\x05\x04\x07\x02\
+    \0\x01\x12\x03U\x0f\x13\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03U\x16\x17\n\
+    \x0b\n\x04\x04\x07\x02\x01\x12\x03V\x02\x1c\n\x0c\n\x05\x04\x07\x02\x01\
+    \x04\x12\x03V\x02\n\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03V\x0b\x11\n\
+    \x0c\n\x05\x04\x07\x02\x01\x01\x12\x03V\x12\x17\n\x0c\n\x05\x04\x07\x02\
+    \x01\x03\x12\x03V\x1a\x1b\n\x0b\n\x04\x04\x07\x02\x02\x12\x03W\x02\x1b\n\
+    \x0c\n\x05\x04\x07\x02\x02\x04\x12\x03W\x02\n\n\x0c\n\x05\x04\x07\x02\
+    \x02\x05\x12\x03W\x0b\x11\n\x0c\n\x05\x04\x07\x02\x02\x01\x12\x03W\x12\
+    \x16\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x03W\x19\x1a\n\x0b\n\x04\x04\
+    \x07\x02\x03\x12\x03X\x02\x1c\n\x0c\n\x05\x04\x07\x02\x03\x04\x12\x03X\
+    \x02\n\n\x0c\n\x05\x04\x07\x02\x03\x05\x12\x03X\x0b\x11\n\x0c\n\x05\x04\
+    \x07\x02\x03\x01\x12\x03X\x12\x17\n\x0c\n\x05\x04\x07\x02\x03\x03\x12\
+    \x03X\x1a\x1b\n\n\n\x02\x04\x08\x12\x04[\0_\x01\n\n\n\x03\x04\x08\x01\
+    \x12\x03[\x08\x13\n\x0b\n\x04\x04\x08\x02\0\x12\x03\\\x02\x1d\n\x0c\n\
+    \x05\x04\x08\x02\0\x04\x12\x03\\\x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\
+    \x03\\\x0b\x11\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x03\\\x12\x18\n\x0c\n\
+    \x05\x04\x08\x02\0\x03\x12\x03\\\x1b\x1c\n+\n\x04\x04\x08\x02\x01\x12\
+    \x03]\x02\x1a\"\x1e\x20Chunk\x20ordering\x20(line\x20number)\n\n\x0c\n\
+    \x05\x04\x08\x02\x01\x04\x12\x03]\x02\n\n\x0c\n\x05\x04\x08\x02\x01\x05\
+    \x12\x03]\x0b\x11\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\x03]\x12\x15\n\x0c\
+    \n\x05\x04\x08\x02\x01\x03\x12\x03]\x18\x19\n5\n\x04\x04\x08\x02\x02\x12\
+    \x03^\x02\x1e\"(\x20Log\x20content\x20(TODO:\x20Make\x20repeatedfield)\n\
+    \n\x0c\n\x05\x04\x08\x02\x02\x04\x12\x03^\x02\n\n\x0c\n\x05\x04\x08\x02\
+    \x02\x05\x12\x03^\x0b\x11\n\x0c\n\x05\x04\x08\x02\x02\x01\x12\x03^\x12\
+    \x19\n\x0c\n\x05\x04\x08\x02\x02\x03\x12\x03^\x1c\x1d\n\n\n\x02\x04\t\
+    \x12\x04a\0c\x01\n\n\n\x03\x04\t\x01\x12\x03a\x08\x16\n\x0b\n\x04\x04\t\
+    \x02\0\x12\x03b\x02\x1d\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03b\x02\n\n\x0c\
+    \n\x05\x04\t\x02\0\x05\x12\x03b\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\
+    \x03b\x12\x18\n\x0c\n\x05\x04\t\x02\0\x03\x12\x03b\x1b\x1c\n\n\n\x02\x04\
+    \n\x12\x04e\0h\x01\n\n\n\x03\x04\n\x01\x12\x03e\x08\x11\n\x0b\n\x04\x04\
+    \n\x02\0\x12\x03f\x02\x19\n\x0c\n\x05\x04\n\x02\0\x04\x12\x03f\x02\n\n\
+    \x0c\n\x05\x04\n\x02\0\x05\x12\x03f\x0b\x11\n\x0c\n\x05\x04\n\x02\0\x01\
+    \x12\x03f\x12\x14\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03f\x17\x18\n.\n\x04\
+    \x04\n\x02\x01\x12\x03g\x02\x1c\"!\x20Zero-indexed\x20line\x20of\x20log\
+    \x20output\n\n\x0c\n\x05\x04\n\x02\x01\x04\x12\x03g\x02\n\n\x0c\n\x05\
+    \x04\n\x02\x01\x05\x12\x03g\x0b\x11\n\x0c\n\x05\x04\n\x02\x01\x01\x12\
+    \x03g\x12\x17\n\x0c\n\x05\x04\n\x02\x01\x03\x12\x03g\x1a\x1b\n\n\n\x02\
+    \x04\x0b\x12\x04j\0o\x01\n\n\n\x03\x04\x0b\x01\x12\x03j\x08\x0e\n,\n\x04\
+    \x04\x0b\x02\0\x12\x03k\x02\x1c\"\x1f\x20Zero-indexed\x20(inclusive)\x20\
+    line\n\n\x0c\n\x05\x04\x0b\x02\0\x04\x12\x03k\x02\n\n\x0c\n\x05\x04\x0b\
+    \x02\0\x05\x12\x03k\x0b\x11\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03k\x12\
+    \x17\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03k\x1a\x1b\n,\n\x04\x04\x0b\x02\
+    \x01\x12\x03l\x02\x1b\"\x1f\x20Zero-indexed\x20(exclusive)\x20line\n\n\
+    \x0c\n\x05\x04\x0b\x02\x01\x04\x12\x03l\x02\n\n\x0c\n\x05\x04\x0b\x02\
+    \x01\x05\x12\x03l\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03l\x12\
+    \x16\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\x03l\x19\x1a\n\x0b\n\x04\x04\
+    \x0b\x02\x02\x12\x03m\x02\x1e\n\x0c\n\x05\x04\x0b\x02\x02\x04\x12\x03m\
+    \x02\n\n\x0c\n\x05\x04\x0b\x02\x02\x05\x12\x03m\x0b\x11\n\x0c\n\x05\x04\
+    \x0b\x02\x02\x01\x12\x03m\x12\x19\n\x0c\n\x05\x04\x0b\x02\x02\x03\x12\
+    \x03m\x1c\x1d\n\x0b\n\x04\x04\x0b\x02\x03\x12\x03n\x02\x20\n\x0c\n\x05\
+    \x04\x0b\x02\x03\x04\x12\x03n\x02\n\n\x0c\n\x05\x04\x0b\x02\x03\x05\x12\
+    \x03n\x0b\x0f\n\x0c\n\x05\x04\x0b\x02\x03\x01\x12\x03n\x10\x1b\n\x0c\n\
+    \x05\x04\x0b\x02\x03\x03\x12\x03n\x1e\x1f\n\n\n\x02\x04\x0c\x12\x04q\0x\
+    \x01\n\n\n\x03\x04\x0c\x01\x12\x03q\x08\x14\n\x0b\n\x04\x04\x0c\x02\0\
+    \x12\x03r\x02\x1d\n\x0c\n\x05\x04\x0c\x02\0\x04\x12\x03r\x02\n\n\x0c\n\
+    \x05\x04\x0c\x02\0\x05\x12\x03r\x0b\x11\n\x0c\n\x05\x04\x0c\x02\0\x01\
+    \x12\x03r\x12\x18\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03r\x1b\x1c\n\x0b\n\
+    \x04\x04\x0c\x02\x01\x12\x03s\x02\x1e\n\x0c\n\x05\x04\x0c\x02\x01\x04\
+    \x12\x03s\x02\n\n\x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03s\x0b\x11\n\x0c\n\
+    \x05\x04\x0c\x02\x01\x01\x12\x03s\x12\x19\n\x0c\n\x05\x04\x0c\x02\x01\
+    \x03\x12\x03s\x1c\x1d\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03t\x02\x1e\n\x0c\
+    \n\x05\x04\x0c\x02\x02\x04\x12\x03t\x02\n\n\x0c\n\x05\x04\x0c\x02\x02\
+    \x05\x12\x03t\x0b\x0f\n\x0c\n\x05\x04\x0c\x02\x02\x01\x12\x03t\x10\x19\n\
+    \x0c\n\x05\x04\x0c\x02\x02\x03\x12\x03t\x1c\x1d\n\x0b\n\x04\x04\x0c\x02\
+    \x03\x12\x03u\x02\x1d\n\x0c\n\x05\x04\x0c\x02\x03\x04\x12\x03u\x02\n\n\
+    \x0c\n\x05\x04\x0c\x02\x03\x05\x12\x03u\x0b\x11\n\x0c\n\x05\x04\x0c\x02\
+    \x03\x01\x12\x03u\x12\x18\n\x0c\n\x05\x04\x0c\x02\x03\x03\x12\x03u\x1b\
+    \x1c\n\x0b\n\x04\x04\x0c\x02\x04\x12\x03v\x02\x20\n\x0c\n\x05\x04\x0c\
+    \x02\x04\x04\x12\x03v\x02\n\n\x0c\n\x05\x04\x0c\x02\x04\x05\x12\x03v\x0b\
+    \x0f\n\x0c\n\x05\x04\x0c\x02\x04\x01\x12\x03v\x10\x1b\n\x0c\n\x05\x04\
+    \x0c\x02\x04\x03\x12\x03v\x1e\x1f\n\x0b\n\x04\x04\x0c\x02\x05\x12\x03w\
+    \x02!\n\x0c\n\x05\x04\x0c\x02\x05\x04\x12\x03w\x02\n\n\x0c\n\x05\x04\x0c\
+    \x02\x05\x05\x12\x03w\x0b\x0f\n\x0c\n\x05\x04\x0c\x02\x05\x01\x12\x03w\
+    \x10\x1c\n\x0c\n\x05\x04\x0c\x02\x05\x03\x12\x03w\x1f\x20\n\x0b\n\x02\
+    \x05\x04\x12\x05z\0\x81\x01\x01\n\n\n\x03\x05\x04\x01\x12\x03z\x05\x19\n\
+    \x0b\n\x04\x05\x04\x02\0\x12\x03{\x02\x11\n\x0c\n\x05\x05\x04\x02\0\x01\
+    \x12\x03{\x02\x0c\n\x0c\n\x05\x05\x04\x02\0\x02\x12\x03{\x0f\x10\n\x0b\n\
+    \x04\x05\x04\x02\x01\x12\x03|\x02\x11\n\x0c\n\x05\x05\x04\x02\x01\x01\
+    \x12\x03|\x02\x0c\n\x0c\n\x05\x05\x04\x02\x01\x02\x12\x03|\x0f\x10\n\x0b\
+    \n\x04\x05\x04\x02\x02\x12\x03}\x02\x0e\n\x0c\n\x05\x05\x04\x02\x02\x01\
+    \x12\x03}\x02\t\n\x0c\n\x05\x05\x04\x02\x02\x02\x12\x03}\x0c\r\n\x0b\n\
+    \x04\x05\x04\x02\x03\x12\x03~\x02\x0e\n\x0c\n\x05\x05\x04\x02\x03\x01\
+    \x12\x03~\x02\t\n\x0c\n\x05\x05\x04\x02\x03\x02\x12\x03~\x0c\r\n\x0b\n\
+    \x04\x05\x04\x02\x04\x12\x03\x7f\x02\x0e\n\x0c\n\x05\x05\x04\x02\x04\x01\
+    \x12\x03\x7f\x02\t\n\x0c\n\x05\x05\x04\x02\x04\x02\x12\x03\x7f\x0c\r\n\
+    \x0c\n\x04\x05\x04\x02\x05\x12\x04\x80\x01\x02\x0f\n\r\n\x05\x05\x04\x02\
+    \x05\x01\x12\x04\x80\x01\x02\n\n\r\n\x05\x05\x04\x02\x05\x02\x12\x04\x80\
+    \x01\r\x0e\n\x0c\n\x02\x04\r\x12\x06\x83\x01\0\x89\x01\x01\n\x0b\n\x03\
+    \x04\r\x01\x12\x04\x83\x01\x08\x17\n\x0c\n\x04\x04\r\x02\0\x12\x04\x84\
+    \x01\x02\x1b\n\r\n\x05\x04\r\x02\0\x04\x12\x04\x84\x01\x02\n\n\r\n\x05\
+    \x04\r\x02\0\x05\x12\x04\x84\x01\x0b\x11\n\r\n\x05\x04\r\x02\0\x01\x12\
+    \x04\x84\x01\x12\x16\n\r\n\x05\x04\r\x02\0\x03\x12\x04\x84\x01\x19\x1a\n\
+    \x0c\n\x04\x04\r\x02\x01\x12\x04\x85\x01\x02\x1c\n\r\n\x05\x04\r\x02\x01\
+    \x04\x12\x04\x85\x01\x02\n\n\r\n\x05\x04\r\x02\x01\x05\x12\x04\x85\x01\
+    \x0b\x11\n\r\n\x05\x04\r\x02\x01\x01\x12\x04\x85\x01\x12\x17\n\r\n\x05\
+    \x04\r\x02\x01\x03\x12\x04\x85\x01\x1a\x1b\n\x0c\n\x04\x04\r\x02\x02\x12\
+    \x04\x86\x01\x02*\n\r\n\x05\x04\r\x02\x02\x04\x12\x04\x86\x01\x02\n\n\r\
+    \n\x05\x04\r\x02\x02\x06\x12\x04\x86\x01\x0b\x1f\n\r\n\x05\x04\r\x02\x02\
+    \x01\x12\x04\x86\x01\x20%\n\r\n\x05\x04\r\x02\x02\x03\x12\x04\x86\x01()\
+    \n\x0c\n\x04\x04\r\x02\x03\x12\x04\x87\x01\x02\x1d\n\r\n\x05\x04\r\x02\
+    \x03\x04\x12\x04\x87\x01\x02\n\n\r\n\x05\x04\r\x02\x03\x05\x12\x04\x87\
+    \x01\x0b\x11\n\r\n\x05\x04\r\x02\x03\x01\x12\x04\x87\x01\x12\x18\n\r\n\
+    \x05\x04\r\x02\x03\x03\x12\x04\x87\x01\x1b\x1c\n\x0c\n\x04\x04\r\x02\x04\
+    \x12\x04\x88\x01\x02\x1d\n\r\n\x05\x04\r\x02\x04\x04\x12\x04\x88\x01\x02\
+    \n\n\r\n\x05\x04\r\x02\x04\x05\x12\x04\x88\x01\x0b\x11\n\r\n\x05\x04\r\
+    \x02\x04\x01\x12\x04\x88\x01\x12\x18\n\r\n\x05\x04\r\x02\x04\x03\x12\x04\
+    \x88\x01\x1b\x1c\n\x0c\n\x02\x05\x05\x12\x06\x8b\x01\0\x92\x01\x01\n\x0b\
+    \n\x03\x05\x05\x01\x12\x04\x8b\x01\x05\x12\n\x0c\n\x04\x05\x05\x02\0\x12\
     \x04\x8c\x01\x02\x13\n\r\n\x05\x05\x05\x02\0\x01\x12\x04\x8c\x01\x02\x0e\
     \n\r\n\x05\x05\x05\x02\0\x02\x12\x04\x8c\x01\x11\x12\n\x0c\n\x04\x05\x05\
     \x02\x01\x12\x04\x8d\x01\x02\x17\n\r\n\x05\x05\x05\x02\x01\x01\x12\x04\
@@ -8634,7 +8826,13 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x03\x12\x04\xd4\x01\x1b\x1c\n\x0c\n\x04\x04\x1a\x02\x02\x12\x04\xd5\x01\
     \x02&\n\r\n\x05\x04\x1a\x02\x02\x04\x12\x04\xd5\x01\x02\n\n\r\n\x05\x04\
     \x1a\x02\x02\x05\x12\x04\xd5\x01\x0b\x11\n\r\n\x05\x04\x1a\x02\x02\x01\
-    \x12\x04\xd5\x01\x12!\n\r\n\x05\x04\x1a\x02\x02\x03\x12\x04\xd5\x01$%\
+    \x12\x04\xd5\x01\x12!\n\r\n\x05\x04\x1a\x02\x02\x03\x12\x04\xd5\x01$%\n2\
+    \n\x02\x04\x1b\x12\x06\xd9\x01\0\xdb\x01\x01\x1a$\x20Gets\x20the\x20job\
+    \x20which\x20built\x20a\x20package\n\n\x0b\n\x03\x04\x1b\x01\x12\x04\xd9\
+    \x01\x08\x15\n\x0c\n\x04\x04\x1b\x02\0\x12\x04\xda\x01\x022\n\r\n\x05\
+    \x04\x1b\x02\0\x04\x12\x04\xda\x01\x02\n\n\r\n\x05\x04\x1b\x02\0\x06\x12\
+    \x04\xda\x01\x0b'\n\r\n\x05\x04\x1b\x02\0\x01\x12\x04\xda\x01(-\n\r\n\
+    \x05\x04\x1b\x02\0\x03\x12\x04\xda\x0101\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -1184,6 +1184,9 @@ pub enum ErrCode {
     REG_NOT_FOUND = 2001,
     GROUP_NOT_COMPLETE = 3000,
     PARTIAL_JOB_GROUP_PROMOTE = 3001,
+    CHANNEL_PROTECTED = 4000,
+    CHANNEL_PREREQUISITE_UNMET = 4001,
+    CHANNEL_JOB_REQUIRED = 4002,
 }
 
 impl ::protobuf::ProtobufEnum for ErrCode {
@@ -1218,6 +1221,9 @@ impl ::protobuf::ProtobufEnum for ErrCode {
             2001 => ::std::option::Option::Some(ErrCode::REG_NOT_FOUND),
             3000 => ::std::option::Option::Some(ErrCode::GROUP_NOT_COMPLETE),
             3001 => ::std::option::Option::Some(ErrCode::PARTIAL_JOB_GROUP_PROMOTE),
+            4000 => ::std::option::Option::Some(ErrCode::CHANNEL_PROTECTED),
+            4001 => ::std::option::Option::Some(ErrCode::CHANNEL_PREREQUISITE_UNMET),
+            4002 => ::std::option::Option::Some(ErrCode::CHANNEL_JOB_REQUIRED),
             _ => ::std::option::Option::None
         }
     }
@@ -1249,6 +1255,9 @@ impl ::protobuf::ProtobufEnum for ErrCode {
             ErrCode::REG_NOT_FOUND,
             ErrCode::GROUP_NOT_COMPLETE,
             ErrCode::PARTIAL_JOB_GROUP_PROMOTE,
+            ErrCode::CHANNEL_PROTECTED,
+            ErrCode::CHANNEL_PREREQUISITE_UNMET,
+            ErrCode::CHANNEL_JOB_REQUIRED,
         ];
         values
     }
@@ -1286,7 +1295,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x01(\x0e2\x0c.net.ErrCodeR\x04code\x12\x10\n\x03msg\x18\x02\x20\x01(\tR\
     \x03msg\"\x07\n\x05NetOk*L\n\x08Protocol\x12\x07\n\x03Net\x10\0\x12\x0c\
     \n\x08RouteSrv\x10\x01\x12\x0e\n\nSessionSrv\x10\x02\x12\r\n\tOriginSrv\
-    \x10\x03\x12\n\n\x06JobSrv\x10\x04*\xe6\x03\n\x07ErrCode\x12\x07\n\x03BU\
+    \x10\x03\x12\n\n\x06JobSrv\x10\x04*\xba\x04\n\x07ErrCode\x12\x07\n\x03BU\
     G\x10\0\x12\x0b\n\x07TIMEOUT\x10\x01\x12\x13\n\x0fREMOTE_REJECTED\x10\
     \x02\x12\x14\n\x10BAD_REMOTE_REPLY\x10\x03\x12\x14\n\x10ENTITY_NOT_FOUND\
     \x10\x04\x12\x0c\n\x08NO_SHARD\x10\x06\x12\x11\n\rACCESS_DENIED\x10\x07\
@@ -1299,28 +1308,30 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     R\x10\xed\x07\x12\x19\n\x14INVALID_INTEGRATIONS\x10\xee\x07\x12\x11\n\
     \x0cREG_CONFLICT\x10\xd0\x0f\x12\x12\n\rREG_NOT_FOUND\x10\xd1\x0f\x12\
     \x17\n\x12GROUP_NOT_COMPLETE\x10\xb8\x17\x12\x1e\n\x19PARTIAL_JOB_GROUP_\
-    PROMOTE\x10\xb9\x17J\xac\x10\n\x06\x12\x04\0\0C\x10\n\x08\n\x01\x0c\x12\
-    \x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\x08\x0b\n\n\n\x02\x05\0\x12\x04\
-    \x03\0\t\x01\n\n\n\x03\x05\0\x01\x12\x03\x03\x05\r\n\x0b\n\x04\x05\0\x02\
-    \0\x12\x03\x04\x02\n\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x04\x02\x05\n\
-    \x0c\n\x05\x05\0\x02\0\x02\x12\x03\x04\x08\t\n\x0b\n\x04\x05\0\x02\x01\
-    \x12\x03\x05\x02\x0f\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x05\x02\n\n\
-    \x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x05\r\x0e\n\x0b\n\x04\x05\0\x02\x02\
-    \x12\x03\x06\x02\x11\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x06\x02\x0c\n\
-    \x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x06\x0f\x10\n\x0b\n\x04\x05\0\x02\
-    \x03\x12\x03\x07\x02\x10\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x07\x02\
-    \x0b\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x07\x0e\x0f\n\x0b\n\x04\x05\0\
-    \x02\x04\x12\x03\x08\x02\r\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x08\x02\
-    \x08\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x08\x0b\x0c\n\n\n\x02\x05\x01\
-    \x12\x04\x0b\0,\x01\n\n\n\x03\x05\x01\x01\x12\x03\x0b\x05\x0c\n\x16\n\
-    \x04\x05\x01\x02\0\x12\x03\r\x02\n\x1a\t\x20Generic\n\n\x0c\n\x05\x05\
-    \x01\x02\0\x01\x12\x03\r\x02\x05\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x03\r\
-    \x08\t\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\x0e\x02\x0e\n\x0c\n\x05\x05\
-    \x01\x02\x01\x01\x12\x03\x0e\x02\t\n\x0c\n\x05\x05\x01\x02\x01\x02\x12\
-    \x03\x0e\x0c\r\n\x0b\n\x04\x05\x01\x02\x02\x12\x03\x0f\x02\x16\n\x0c\n\
-    \x05\x05\x01\x02\x02\x01\x12\x03\x0f\x02\x11\n\x0c\n\x05\x05\x01\x02\x02\
-    \x02\x12\x03\x0f\x14\x15\n\x0b\n\x04\x05\x01\x02\x03\x12\x03\x10\x02\x17\
-    \n\x0c\n\x05\x05\x01\x02\x03\x01\x12\x03\x10\x02\x12\n\x0c\n\x05\x05\x01\
+    PROMOTE\x10\xb9\x17\x12\x16\n\x11CHANNEL_PROTECTED\x10\xa0\x1f\x12\x1f\n\
+    \x1aCHANNEL_PREREQUISITE_UNMET\x10\xa1\x1f\x12\x19\n\x14CHANNEL_JOB_REQU\
+    IRED\x10\xa2\x1fJ\xc5\x11\n\x06\x12\x04\0\0H\x10\n\x08\n\x01\x0c\x12\x03\
+    \0\0\x12\n\x08\n\x01\x02\x12\x03\x01\x08\x0b\n\n\n\x02\x05\0\x12\x04\x03\
+    \0\t\x01\n\n\n\x03\x05\0\x01\x12\x03\x03\x05\r\n\x0b\n\x04\x05\0\x02\0\
+    \x12\x03\x04\x02\n\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x04\x02\x05\n\x0c\
+    \n\x05\x05\0\x02\0\x02\x12\x03\x04\x08\t\n\x0b\n\x04\x05\0\x02\x01\x12\
+    \x03\x05\x02\x0f\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x05\x02\n\n\x0c\n\
+    \x05\x05\0\x02\x01\x02\x12\x03\x05\r\x0e\n\x0b\n\x04\x05\0\x02\x02\x12\
+    \x03\x06\x02\x11\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x06\x02\x0c\n\x0c\
+    \n\x05\x05\0\x02\x02\x02\x12\x03\x06\x0f\x10\n\x0b\n\x04\x05\0\x02\x03\
+    \x12\x03\x07\x02\x10\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x07\x02\x0b\n\
+    \x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x07\x0e\x0f\n\x0b\n\x04\x05\0\x02\
+    \x04\x12\x03\x08\x02\r\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x08\x02\x08\
+    \n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x08\x0b\x0c\n\n\n\x02\x05\x01\x12\
+    \x04\x0b\01\x01\n\n\n\x03\x05\x01\x01\x12\x03\x0b\x05\x0c\n\x16\n\x04\
+    \x05\x01\x02\0\x12\x03\r\x02\n\x1a\t\x20Generic\n\n\x0c\n\x05\x05\x01\
+    \x02\0\x01\x12\x03\r\x02\x05\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x03\r\x08\
+    \t\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\x0e\x02\x0e\n\x0c\n\x05\x05\x01\
+    \x02\x01\x01\x12\x03\x0e\x02\t\n\x0c\n\x05\x05\x01\x02\x01\x02\x12\x03\
+    \x0e\x0c\r\n\x0b\n\x04\x05\x01\x02\x02\x12\x03\x0f\x02\x16\n\x0c\n\x05\
+    \x05\x01\x02\x02\x01\x12\x03\x0f\x02\x11\n\x0c\n\x05\x05\x01\x02\x02\x02\
+    \x12\x03\x0f\x14\x15\n\x0b\n\x04\x05\x01\x02\x03\x12\x03\x10\x02\x17\n\
+    \x0c\n\x05\x05\x01\x02\x03\x01\x12\x03\x10\x02\x12\n\x0c\n\x05\x05\x01\
     \x02\x03\x02\x12\x03\x10\x15\x16\n\x0b\n\x04\x05\x01\x02\x04\x12\x03\x11\
     \x02\x17\n\x0c\n\x05\x05\x01\x02\x04\x01\x12\x03\x11\x02\x12\n\x0c\n\x05\
     \x05\x01\x02\x04\x02\x12\x03\x11\x15\x16\n\x0b\n\x04\x05\x01\x02\x05\x12\
@@ -1365,42 +1376,49 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x12\x03*\x02\x1c\x1a\x0b\x20Scheduler\n\n\x0c\n\x05\x05\x01\x02\x17\x01\
     \x12\x03*\x02\x14\n\x0c\n\x05\x05\x01\x02\x17\x02\x12\x03*\x17\x1b\n\x0b\
     \n\x04\x05\x01\x02\x18\x12\x03+\x02#\n\x0c\n\x05\x05\x01\x02\x18\x01\x12\
-    \x03+\x02\x1b\n\x0c\n\x05\x05\x01\x02\x18\x02\x12\x03+\x1e\"\n\n\n\x02\
-    \x04\0\x12\x04.\02\x01\n\n\n\x03\x04\0\x01\x12\x03.\x08\x0e\n\x0b\n\x04\
-    \x04\0\x02\0\x12\x03/\x02!\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03/\x02\n\n\
-    \x0c\n\x05\x04\0\x02\0\x05\x12\x03/\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\
-    \x12\x03/\x12\x1c\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03/\x1f\x20\n\x0b\n\
-    \x04\x04\0\x02\x01\x12\x030\x02\x1f\n\x0c\n\x05\x04\0\x02\x01\x04\x12\
-    \x030\x02\n\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x030\x0b\x0f\n\x0c\n\x05\
-    \x04\0\x02\x01\x01\x12\x030\x10\x1a\n\x0c\n\x05\x04\0\x02\x01\x03\x12\
-    \x030\x1d\x1e\n\x0b\n\x04\x04\0\x02\x02\x12\x031\x02\x18\n\x0c\n\x05\x04\
-    \0\x02\x02\x04\x12\x031\x02\n\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x031\x0b\
-    \x0f\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x031\x10\x13\n\x0c\n\x05\x04\0\
-    \x02\x02\x03\x12\x031\x16\x17\n\n\n\x02\x04\x01\x12\x044\07\x01\n\n\n\
-    \x03\x04\x01\x01\x12\x034\x08\x11\n\x0b\n\x04\x04\x01\x02\0\x12\x035\x02\
-    !\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x035\x02\n\n\x0c\n\x05\x04\x01\x02\0\
-    \x06\x12\x035\x0b\x13\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x035\x14\x1c\n\
-    \x0c\n\x05\x04\x01\x02\0\x03\x12\x035\x1f\x20\n\x0b\n\x04\x04\x01\x02\
-    \x01\x12\x036\x02\x1b\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x036\x02\n\n\
-    \x0c\n\x05\x04\x01\x02\x01\x05\x12\x036\x0b\x11\n\x0c\n\x05\x04\x01\x02\
-    \x01\x01\x12\x036\x12\x16\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x036\x19\
-    \x1a\n\n\n\x02\x04\x02\x12\x049\0<\x01\n\n\n\x03\x04\x02\x01\x12\x039\
-    \x08\x0b\n\x0b\n\x04\x04\x02\x02\0\x12\x03:\x02\x19\n\x0c\n\x05\x04\x02\
-    \x02\0\x04\x12\x03:\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03:\x0b\x11\
-    \n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03:\x12\x14\n\x0c\n\x05\x04\x02\x02\
-    \0\x03\x12\x03:\x17\x18\n\x0b\n\x04\x04\x02\x02\x01\x12\x03;\x02\x1d\n\
-    \x0c\n\x05\x04\x02\x02\x01\x04\x12\x03;\x02\n\n\x0c\n\x05\x04\x02\x02\
-    \x01\x05\x12\x03;\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03;\x10\
-    \x18\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03;\x1b\x1c\n\n\n\x02\x04\x03\
-    \x12\x04>\0A\x01\n\n\n\x03\x04\x03\x01\x12\x03>\x08\x10\n\x0b\n\x04\x04\
-    \x03\x02\0\x12\x03?\x02\x1c\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03?\x02\n\
-    \n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03?\x0b\x12\n\x0c\n\x05\x04\x03\x02\
-    \0\x01\x12\x03?\x13\x17\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03?\x1a\x1b\n\
-    \x0b\n\x04\x04\x03\x02\x01\x12\x03@\x02\x1a\n\x0c\n\x05\x04\x03\x02\x01\
-    \x04\x12\x03@\x02\n\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03@\x0b\x11\n\
-    \x0c\n\x05\x04\x03\x02\x01\x01\x12\x03@\x12\x15\n\x0c\n\x05\x04\x03\x02\
-    \x01\x03\x12\x03@\x18\x19\n\t\n\x02\x04\x04\x12\x03C\0\x10\n\n\n\x03\x04\
-    \x04\x01\x12\x03C\x08\r\
+    \x03+\x02\x1b\n\x0c\n\x05\x05\x01\x02\x18\x02\x12\x03+\x1e\"\n)\n\x04\
+    \x05\x01\x02\x19\x12\x03.\x02\x1b\x1a\x1c\x20Channel\x20promotion\x20pol\
+    icies\n\n\x0c\n\x05\x05\x01\x02\x19\x01\x12\x03.\x02\x13\n\x0c\n\x05\x05\
+    \x01\x02\x19\x02\x12\x03.\x16\x1a\n\x0b\n\x04\x05\x01\x02\x1a\x12\x03/\
+    \x02$\n\x0c\n\x05\x05\x01\x02\x1a\x01\x12\x03/\x02\x1c\n\x0c\n\x05\x05\
+    \x01\x02\x1a\x02\x12\x03/\x1f#\n\x0b\n\x04\x05\x01\x02\x1b\x12\x030\x02\
+    \x1e\n\x0c\n\x05\x05\x01\x02\x1b\x01\x12\x030\x02\x16\n\x0c\n\x05\x05\
+    \x01\x02\x1b\x02\x12\x030\x19\x1d\n\n\n\x02\x04\0\x12\x043\07\x01\n\n\n\
+    \x03\x04\0\x01\x12\x033\x08\x0e\n\x0b\n\x04\x04\0\x02\0\x12\x034\x02!\n\
+    \x0c\n\x05\x04\0\x02\0\x04\x12\x034\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\
+    \x12\x034\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x034\x12\x1c\n\x0c\n\
+    \x05\x04\0\x02\0\x03\x12\x034\x1f\x20\n\x0b\n\x04\x04\0\x02\x01\x12\x035\
+    \x02\x1f\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x035\x02\n\n\x0c\n\x05\x04\0\
+    \x02\x01\x05\x12\x035\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x035\x10\
+    \x1a\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x035\x1d\x1e\n\x0b\n\x04\x04\0\
+    \x02\x02\x12\x036\x02\x18\n\x0c\n\x05\x04\0\x02\x02\x04\x12\x036\x02\n\n\
+    \x0c\n\x05\x04\0\x02\x02\x05\x12\x036\x0b\x0f\n\x0c\n\x05\x04\0\x02\x02\
+    \x01\x12\x036\x10\x13\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x036\x16\x17\n\n\
+    \n\x02\x04\x01\x12\x049\0<\x01\n\n\n\x03\x04\x01\x01\x12\x039\x08\x11\n\
+    \x0b\n\x04\x04\x01\x02\0\x12\x03:\x02!\n\x0c\n\x05\x04\x01\x02\0\x04\x12\
+    \x03:\x02\n\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03:\x0b\x13\n\x0c\n\x05\
+    \x04\x01\x02\0\x01\x12\x03:\x14\x1c\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
+    \x03:\x1f\x20\n\x0b\n\x04\x04\x01\x02\x01\x12\x03;\x02\x1b\n\x0c\n\x05\
+    \x04\x01\x02\x01\x04\x12\x03;\x02\n\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\
+    \x03;\x0b\x11\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03;\x12\x16\n\x0c\n\
+    \x05\x04\x01\x02\x01\x03\x12\x03;\x19\x1a\n\n\n\x02\x04\x02\x12\x04>\0A\
+    \x01\n\n\n\x03\x04\x02\x01\x12\x03>\x08\x0b\n\x0b\n\x04\x04\x02\x02\0\
+    \x12\x03?\x02\x19\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03?\x02\n\n\x0c\n\
+    \x05\x04\x02\x02\0\x05\x12\x03?\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\
+    \x12\x03?\x12\x14\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03?\x17\x18\n\x0b\n\
+    \x04\x04\x02\x02\x01\x12\x03@\x02\x1d\n\x0c\n\x05\x04\x02\x02\x01\x04\
+    \x12\x03@\x02\n\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03@\x0b\x0f\n\x0c\n\
+    \x05\x04\x02\x02\x01\x01\x12\x03@\x10\x18\n\x0c\n\x05\x04\x02\x02\x01\
+    \x03\x12\x03@\x1b\x1c\n\n\n\x02\x04\x03\x12\x04C\0F\x01\n\n\n\x03\x04\
+    \x03\x01\x12\x03C\x08\x10\n\x0b\n\x04\x04\x03\x02\0\x12\x03D\x02\x1c\n\
+    \x0c\n\x05\x04\x03\x02\0\x04\x12\x03D\x02\n\n\x0c\n\x05\x04\x03\x02\0\
+    \x06\x12\x03D\x0b\x12\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03D\x13\x17\n\
+    \x0c\n\x05\x04\x03\x02\0\x03\x12\x03D\x1a\x1b\n\x0b\n\x04\x04\x03\x02\
+    \x01\x12\x03E\x02\x1a\n\x0c\n\x05\x04\x03\x02\x01\x04\x12\x03E\x02\n\n\
+    \x0c\n\x05\x04\x03\x02\x01\x05\x12\x03E\x0b\x11\n\x0c\n\x05\x04\x03\x02\
+    \x01\x01\x12\x03E\x12\x15\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03E\x18\
+    \x19\n\t\n\x02\x04\x04\x12\x03H\0\x10\n\n\n\x03\x04\x04\x01\x12\x03H\x08\
+    \r\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
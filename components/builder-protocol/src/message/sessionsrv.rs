@@ -1399,90 +1399,103 @@ impl ::protobuf::reflect::ProtobufValue for AccountUpdate {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct AccountOriginInvitation {
+pub struct AccountIdentity {
     // message fields
-    id: ::std::option::Option<u64>,
-    origin_invitation_id: ::std::option::Option<u64>,
+    provider: ::std::option::Option<OAuthProvider>,
+    subject: ::protobuf::SingularField<::std::string::String>,
     account_id: ::std::option::Option<u64>,
-    account_name: ::protobuf::SingularField<::std::string::String>,
-    origin_id: ::std::option::Option<u64>,
-    origin_name: ::protobuf::SingularField<::std::string::String>,
-    owner_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountOriginInvitation {}
+unsafe impl ::std::marker::Sync for AccountIdentity {}
 
-impl AccountOriginInvitation {
-    pub fn new() -> AccountOriginInvitation {
+impl AccountIdentity {
+    pub fn new() -> AccountIdentity {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountOriginInvitation {
-        static mut instance: ::protobuf::lazy::Lazy<AccountOriginInvitation> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountIdentity {
+        static mut instance: ::protobuf::lazy::Lazy<AccountIdentity> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountOriginInvitation,
+            ptr: 0 as *const AccountIdentity,
         };
         unsafe {
-            instance.get(AccountOriginInvitation::new)
+            instance.get(AccountIdentity::new)
         }
     }
 
-    // optional uint64 id = 1;
+    // optional .sessionsrv.OAuthProvider provider = 1;
 
-    pub fn clear_id(&mut self) {
-        self.id = ::std::option::Option::None;
+    pub fn clear_provider(&mut self) {
+        self.provider = ::std::option::Option::None;
     }
 
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
+    pub fn has_provider(&self) -> bool {
+        self.provider.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = ::std::option::Option::Some(v);
+    pub fn set_provider(&mut self, v: OAuthProvider) {
+        self.provider = ::std::option::Option::Some(v);
     }
 
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
+    pub fn get_provider(&self) -> OAuthProvider {
+        self.provider.unwrap_or(OAuthProvider::GitHub)
     }
 
-    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.id
+    fn get_provider_for_reflect(&self) -> &::std::option::Option<OAuthProvider> {
+        &self.provider
     }
 
-    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.id
+    fn mut_provider_for_reflect(&mut self) -> &mut ::std::option::Option<OAuthProvider> {
+        &mut self.provider
     }
 
-    // optional uint64 origin_invitation_id = 2;
+    // optional string subject = 2;
 
-    pub fn clear_origin_invitation_id(&mut self) {
-        self.origin_invitation_id = ::std::option::Option::None;
+    pub fn clear_subject(&mut self) {
+        self.subject.clear();
     }
 
-    pub fn has_origin_invitation_id(&self) -> bool {
-        self.origin_invitation_id.is_some()
+    pub fn has_subject(&self) -> bool {
+        self.subject.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_invitation_id(&mut self, v: u64) {
-        self.origin_invitation_id = ::std::option::Option::Some(v);
+    pub fn set_subject(&mut self, v: ::std::string::String) {
+        self.subject = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_origin_invitation_id(&self) -> u64 {
-        self.origin_invitation_id.unwrap_or(0)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_subject(&mut self) -> &mut ::std::string::String {
+        if self.subject.is_none() {
+            self.subject.set_default();
+        }
+        self.subject.as_mut().unwrap()
     }
 
-    fn get_origin_invitation_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.origin_invitation_id
+    // Take field
+    pub fn take_subject(&mut self) -> ::std::string::String {
+        self.subject.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn mut_origin_invitation_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.origin_invitation_id
+    pub fn get_subject(&self) -> &str {
+        match self.subject.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_subject_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.subject
+    }
+
+    fn mut_subject_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.subject
     }
 
     // optional uint64 account_id = 3;
@@ -1511,151 +1524,9 @@ impl AccountOriginInvitation {
     fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
         &mut self.account_id
     }
-
-    // optional string account_name = 4;
-
-    pub fn clear_account_name(&mut self) {
-        self.account_name.clear();
-    }
-
-    pub fn has_account_name(&self) -> bool {
-        self.account_name.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_account_name(&mut self, v: ::std::string::String) {
-        self.account_name = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
-        if self.account_name.is_none() {
-            self.account_name.set_default();
-        }
-        self.account_name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_account_name(&mut self) -> ::std::string::String {
-        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_account_name(&self) -> &str {
-        match self.account_name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
-
-    fn get_account_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.account_name
-    }
-
-    fn mut_account_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.account_name
-    }
-
-    // optional uint64 origin_id = 5;
-
-    pub fn clear_origin_id(&mut self) {
-        self.origin_id = ::std::option::Option::None;
-    }
-
-    pub fn has_origin_id(&self) -> bool {
-        self.origin_id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_origin_id(&self) -> u64 {
-        self.origin_id.unwrap_or(0)
-    }
-
-    fn get_origin_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.origin_id
-    }
-
-    fn mut_origin_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.origin_id
-    }
-
-    // optional string origin_name = 6;
-
-    pub fn clear_origin_name(&mut self) {
-        self.origin_name.clear();
-    }
-
-    pub fn has_origin_name(&self) -> bool {
-        self.origin_name.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_origin_name(&mut self, v: ::std::string::String) {
-        self.origin_name = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
-        if self.origin_name.is_none() {
-            self.origin_name.set_default();
-        }
-        self.origin_name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_origin_name(&mut self) -> ::std::string::String {
-        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_origin_name(&self) -> &str {
-        match self.origin_name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
-
-    fn get_origin_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.origin_name
-    }
-
-    fn mut_origin_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.origin_name
-    }
-
-    // optional uint64 owner_id = 7;
-
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
-    }
-
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
-    }
-
-    fn get_owner_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.owner_id
-    }
-
-    fn mut_owner_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.owner_id
-    }
 }
 
-impl ::protobuf::Message for AccountOriginInvitation {
+impl ::protobuf::Message for AccountIdentity {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1668,15 +1539,11 @@ impl ::protobuf::Message for AccountOriginInvitation {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_uint64()?;
-                    self.id = ::std::option::Option::Some(tmp);
+                    let tmp = is.read_enum()?;
+                    self.provider = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.origin_invitation_id = ::std::option::Option::Some(tmp);
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.subject)?;
                 },
                 3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
@@ -1685,26 +1552,6 @@ impl ::protobuf::Message for AccountOriginInvitation {
                     let tmp = is.read_uint64()?;
                     self.account_id = ::std::option::Option::Some(tmp);
                 },
-                4 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name)?;
-                },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.origin_id = ::std::option::Option::Some(tmp);
-                },
-                6 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name)?;
-                },
-                7 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.owner_id = ::std::option::Option::Some(tmp);
-                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1717,54 +1564,30 @@ impl ::protobuf::Message for AccountOriginInvitation {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.id {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(v) = self.provider {
+            my_size += ::protobuf::rt::enum_size(1, v);
         }
-        if let Some(v) = self.origin_invitation_id {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(ref v) = self.subject.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
         }
         if let Some(v) = self.account_id {
             my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(ref v) = self.account_name.as_ref() {
-            my_size += ::protobuf::rt::string_size(4, &v);
-        }
-        if let Some(v) = self.origin_id {
-            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(ref v) = self.origin_name.as_ref() {
-            my_size += ::protobuf::rt::string_size(6, &v);
-        }
-        if let Some(v) = self.owner_id {
-            my_size += ::protobuf::rt::value_size(7, v, ::protobuf::wire_format::WireTypeVarint);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id {
-            os.write_uint64(1, v)?;
+        if let Some(v) = self.provider {
+            os.write_enum(1, v.value())?;
         }
-        if let Some(v) = self.origin_invitation_id {
-            os.write_uint64(2, v)?;
+        if let Some(ref v) = self.subject.as_ref() {
+            os.write_string(2, &v)?;
         }
         if let Some(v) = self.account_id {
             os.write_uint64(3, v)?;
         }
-        if let Some(ref v) = self.account_name.as_ref() {
-            os.write_string(4, &v)?;
-        }
-        if let Some(v) = self.origin_id {
-            os.write_uint64(5, v)?;
-        }
-        if let Some(ref v) = self.origin_name.as_ref() {
-            os.write_string(6, &v)?;
-        }
-        if let Some(v) = self.owner_id {
-            os.write_uint64(7, v)?;
-        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1796,12 +1619,12 @@ impl ::protobuf::Message for AccountOriginInvitation {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountOriginInvitation {
-    fn new() -> AccountOriginInvitation {
-        AccountOriginInvitation::new()
+impl ::protobuf::MessageStatic for AccountIdentity {
+    fn new() -> AccountIdentity {
+        AccountIdentity::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountOriginInvitation>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountIdentity>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1809,43 +1632,23 @@ impl ::protobuf::MessageStatic for AccountOriginInvitation {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "id",
-                    AccountOriginInvitation::get_id_for_reflect,
-                    AccountOriginInvitation::mut_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "origin_invitation_id",
-                    AccountOriginInvitation::get_origin_invitation_id_for_reflect,
-                    AccountOriginInvitation::mut_origin_invitation_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "account_id",
-                    AccountOriginInvitation::get_account_id_for_reflect,
-                    AccountOriginInvitation::mut_account_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "account_name",
-                    AccountOriginInvitation::get_account_name_for_reflect,
-                    AccountOriginInvitation::mut_account_name_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "origin_id",
-                    AccountOriginInvitation::get_origin_id_for_reflect,
-                    AccountOriginInvitation::mut_origin_id_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<OAuthProvider>>(
+                    "provider",
+                    AccountIdentity::get_provider_for_reflect,
+                    AccountIdentity::mut_provider_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "origin_name",
-                    AccountOriginInvitation::get_origin_name_for_reflect,
-                    AccountOriginInvitation::mut_origin_name_for_reflect,
+                    "subject",
+                    AccountIdentity::get_subject_for_reflect,
+                    AccountIdentity::mut_subject_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "owner_id",
-                    AccountOriginInvitation::get_owner_id_for_reflect,
-                    AccountOriginInvitation::mut_owner_id_for_reflect,
+                    "account_id",
+                    AccountIdentity::get_account_id_for_reflect,
+                    AccountIdentity::mut_account_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginInvitation>(
-                    "AccountOriginInvitation",
+                ::protobuf::reflect::MessageDescriptor::new::<AccountIdentity>(
+                    "AccountIdentity",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1854,261 +1657,156 @@ impl ::protobuf::MessageStatic for AccountOriginInvitation {
     }
 }
 
-impl ::protobuf::Clear for AccountOriginInvitation {
+impl ::protobuf::Clear for AccountIdentity {
     fn clear(&mut self) {
-        self.clear_id();
-        self.clear_origin_invitation_id();
+        self.clear_provider();
+        self.clear_subject();
         self.clear_account_id();
-        self.clear_account_name();
-        self.clear_origin_id();
-        self.clear_origin_name();
-        self.clear_owner_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for AccountOriginInvitation {
+impl ::std::fmt::Debug for AccountIdentity {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AccountOriginInvitation {
+impl ::protobuf::reflect::ProtobufValue for AccountIdentity {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct AccountOriginInvitationCreate {
+pub struct AccountIdentityCreate {
     // message fields
-    origin_invitation_id: ::std::option::Option<u64>,
+    provider: ::std::option::Option<OAuthProvider>,
+    subject: ::protobuf::SingularField<::std::string::String>,
     account_id: ::std::option::Option<u64>,
-    account_name: ::protobuf::SingularField<::std::string::String>,
-    origin_id: ::std::option::Option<u64>,
-    origin_name: ::protobuf::SingularField<::std::string::String>,
-    owner_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountOriginInvitationCreate {}
+unsafe impl ::std::marker::Sync for AccountIdentityCreate {}
 
-impl AccountOriginInvitationCreate {
-    pub fn new() -> AccountOriginInvitationCreate {
+impl AccountIdentityCreate {
+    pub fn new() -> AccountIdentityCreate {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountOriginInvitationCreate {
-        static mut instance: ::protobuf::lazy::Lazy<AccountOriginInvitationCreate> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountIdentityCreate {
+        static mut instance: ::protobuf::lazy::Lazy<AccountIdentityCreate> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountOriginInvitationCreate,
+            ptr: 0 as *const AccountIdentityCreate,
         };
         unsafe {
-            instance.get(AccountOriginInvitationCreate::new)
-        }
-    }
-
-    // optional uint64 origin_invitation_id = 1;
-
-    pub fn clear_origin_invitation_id(&mut self) {
-        self.origin_invitation_id = ::std::option::Option::None;
-    }
-
-    pub fn has_origin_invitation_id(&self) -> bool {
-        self.origin_invitation_id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_origin_invitation_id(&mut self, v: u64) {
-        self.origin_invitation_id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_origin_invitation_id(&self) -> u64 {
-        self.origin_invitation_id.unwrap_or(0)
-    }
-
-    fn get_origin_invitation_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.origin_invitation_id
-    }
-
-    fn mut_origin_invitation_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.origin_invitation_id
-    }
-
-    // optional uint64 account_id = 2;
-
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
-    }
-
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
-    }
-
-    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.account_id
-    }
-
-    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.account_id
-    }
-
-    // optional string account_name = 3;
-
-    pub fn clear_account_name(&mut self) {
-        self.account_name.clear();
-    }
-
-    pub fn has_account_name(&self) -> bool {
-        self.account_name.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_account_name(&mut self, v: ::std::string::String) {
-        self.account_name = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
-        if self.account_name.is_none() {
-            self.account_name.set_default();
-        }
-        self.account_name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_account_name(&mut self) -> ::std::string::String {
-        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_account_name(&self) -> &str {
-        match self.account_name.as_ref() {
-            Some(v) => &v,
-            None => "",
+            instance.get(AccountIdentityCreate::new)
         }
     }
 
-    fn get_account_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.account_name
-    }
-
-    fn mut_account_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.account_name
-    }
-
-    // optional uint64 origin_id = 4;
+    // optional .sessionsrv.OAuthProvider provider = 1;
 
-    pub fn clear_origin_id(&mut self) {
-        self.origin_id = ::std::option::Option::None;
+    pub fn clear_provider(&mut self) {
+        self.provider = ::std::option::Option::None;
     }
 
-    pub fn has_origin_id(&self) -> bool {
-        self.origin_id.is_some()
+    pub fn has_provider(&self) -> bool {
+        self.provider.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_id = ::std::option::Option::Some(v);
+    pub fn set_provider(&mut self, v: OAuthProvider) {
+        self.provider = ::std::option::Option::Some(v);
     }
 
-    pub fn get_origin_id(&self) -> u64 {
-        self.origin_id.unwrap_or(0)
+    pub fn get_provider(&self) -> OAuthProvider {
+        self.provider.unwrap_or(OAuthProvider::GitHub)
     }
 
-    fn get_origin_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.origin_id
+    fn get_provider_for_reflect(&self) -> &::std::option::Option<OAuthProvider> {
+        &self.provider
     }
 
-    fn mut_origin_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.origin_id
+    fn mut_provider_for_reflect(&mut self) -> &mut ::std::option::Option<OAuthProvider> {
+        &mut self.provider
     }
 
-    // optional string origin_name = 5;
+    // optional string subject = 2;
 
-    pub fn clear_origin_name(&mut self) {
-        self.origin_name.clear();
+    pub fn clear_subject(&mut self) {
+        self.subject.clear();
     }
 
-    pub fn has_origin_name(&self) -> bool {
-        self.origin_name.is_some()
+    pub fn has_subject(&self) -> bool {
+        self.subject.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_name(&mut self, v: ::std::string::String) {
-        self.origin_name = ::protobuf::SingularField::some(v);
+    pub fn set_subject(&mut self, v: ::std::string::String) {
+        self.subject = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
-        if self.origin_name.is_none() {
-            self.origin_name.set_default();
+    pub fn mut_subject(&mut self) -> &mut ::std::string::String {
+        if self.subject.is_none() {
+            self.subject.set_default();
         }
-        self.origin_name.as_mut().unwrap()
+        self.subject.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_origin_name(&mut self) -> ::std::string::String {
-        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_subject(&mut self) -> ::std::string::String {
+        self.subject.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_origin_name(&self) -> &str {
-        match self.origin_name.as_ref() {
+    pub fn get_subject(&self) -> &str {
+        match self.subject.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    fn get_origin_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.origin_name
+    fn get_subject_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.subject
     }
 
-    fn mut_origin_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.origin_name
+    fn mut_subject_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.subject
     }
 
-    // optional uint64 owner_id = 6;
+    // optional uint64 account_id = 3;
 
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
     }
 
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
     }
 
-    fn get_owner_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.owner_id
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
     }
 
-    fn mut_owner_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.owner_id
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
     }
 }
 
-impl ::protobuf::Message for AccountOriginInvitationCreate {
+impl ::protobuf::Message for AccountIdentityCreate {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2121,36 +1819,19 @@ impl ::protobuf::Message for AccountOriginInvitationCreate {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_uint64()?;
-                    self.origin_invitation_id = ::std::option::Option::Some(tmp);
+                    let tmp = is.read_enum()?;
+                    self.provider = ::std::option::Option::Some(tmp);
                 },
                 2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.subject)?;
+                },
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
                     self.account_id = ::std::option::Option::Some(tmp);
                 },
-                3 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name)?;
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.origin_id = ::std::option::Option::Some(tmp);
-                },
-                5 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name)?;
-                },
-                6 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.owner_id = ::std::option::Option::Some(tmp);
-                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2163,23 +1844,14 @@ impl ::protobuf::Message for AccountOriginInvitationCreate {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.origin_invitation_id {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(v) = self.account_id {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(ref v) = self.account_name.as_ref() {
-            my_size += ::protobuf::rt::string_size(3, &v);
-        }
-        if let Some(v) = self.origin_id {
-            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(v) = self.provider {
+            my_size += ::protobuf::rt::enum_size(1, v);
         }
-        if let Some(ref v) = self.origin_name.as_ref() {
-            my_size += ::protobuf::rt::string_size(5, &v);
+        if let Some(ref v) = self.subject.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
         }
-        if let Some(v) = self.owner_id {
-            my_size += ::protobuf::rt::value_size(6, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2187,23 +1859,14 @@ impl ::protobuf::Message for AccountOriginInvitationCreate {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.origin_invitation_id {
-            os.write_uint64(1, v)?;
-        }
-        if let Some(v) = self.account_id {
-            os.write_uint64(2, v)?;
-        }
-        if let Some(ref v) = self.account_name.as_ref() {
-            os.write_string(3, &v)?;
-        }
-        if let Some(v) = self.origin_id {
-            os.write_uint64(4, v)?;
+        if let Some(v) = self.provider {
+            os.write_enum(1, v.value())?;
         }
-        if let Some(ref v) = self.origin_name.as_ref() {
-            os.write_string(5, &v)?;
+        if let Some(ref v) = self.subject.as_ref() {
+            os.write_string(2, &v)?;
         }
-        if let Some(v) = self.owner_id {
-            os.write_uint64(6, v)?;
+        if let Some(v) = self.account_id {
+            os.write_uint64(3, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2236,12 +1899,12 @@ impl ::protobuf::Message for AccountOriginInvitationCreate {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountOriginInvitationCreate {
-    fn new() -> AccountOriginInvitationCreate {
-        AccountOriginInvitationCreate::new()
+impl ::protobuf::MessageStatic for AccountIdentityCreate {
+    fn new() -> AccountIdentityCreate {
+        AccountIdentityCreate::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountOriginInvitationCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountIdentityCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2249,38 +1912,23 @@ impl ::protobuf::MessageStatic for AccountOriginInvitationCreate {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "origin_invitation_id",
-                    AccountOriginInvitationCreate::get_origin_invitation_id_for_reflect,
-                    AccountOriginInvitationCreate::mut_origin_invitation_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "account_id",
-                    AccountOriginInvitationCreate::get_account_id_for_reflect,
-                    AccountOriginInvitationCreate::mut_account_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "account_name",
-                    AccountOriginInvitationCreate::get_account_name_for_reflect,
-                    AccountOriginInvitationCreate::mut_account_name_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "origin_id",
-                    AccountOriginInvitationCreate::get_origin_id_for_reflect,
-                    AccountOriginInvitationCreate::mut_origin_id_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<OAuthProvider>>(
+                    "provider",
+                    AccountIdentityCreate::get_provider_for_reflect,
+                    AccountIdentityCreate::mut_provider_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "origin_name",
-                    AccountOriginInvitationCreate::get_origin_name_for_reflect,
-                    AccountOriginInvitationCreate::mut_origin_name_for_reflect,
+                    "subject",
+                    AccountIdentityCreate::get_subject_for_reflect,
+                    AccountIdentityCreate::mut_subject_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "owner_id",
-                    AccountOriginInvitationCreate::get_owner_id_for_reflect,
-                    AccountOriginInvitationCreate::mut_owner_id_for_reflect,
+                    "account_id",
+                    AccountIdentityCreate::get_account_id_for_reflect,
+                    AccountIdentityCreate::mut_account_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginInvitationCreate>(
-                    "AccountOriginInvitationCreate",
+                ::protobuf::reflect::MessageDescriptor::new::<AccountIdentityCreate>(
+                    "AccountIdentityCreate",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2289,187 +1937,128 @@ impl ::protobuf::MessageStatic for AccountOriginInvitationCreate {
     }
 }
 
-impl ::protobuf::Clear for AccountOriginInvitationCreate {
+impl ::protobuf::Clear for AccountIdentityCreate {
     fn clear(&mut self) {
-        self.clear_origin_invitation_id();
+        self.clear_provider();
+        self.clear_subject();
         self.clear_account_id();
-        self.clear_account_name();
-        self.clear_origin_id();
-        self.clear_origin_name();
-        self.clear_owner_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for AccountOriginInvitationCreate {
+impl ::std::fmt::Debug for AccountIdentityCreate {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AccountOriginInvitationCreate {
+impl ::protobuf::reflect::ProtobufValue for AccountIdentityCreate {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct AccountOriginInvitationAcceptRequest {
+pub struct AccountIdentityGet {
     // message fields
-    account_id: ::std::option::Option<u64>,
-    invite_id: ::std::option::Option<u64>,
-    origin_name: ::protobuf::SingularField<::std::string::String>,
-    ignore: ::std::option::Option<bool>,
+    provider: ::std::option::Option<OAuthProvider>,
+    subject: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountOriginInvitationAcceptRequest {}
+unsafe impl ::std::marker::Sync for AccountIdentityGet {}
 
-impl AccountOriginInvitationAcceptRequest {
-    pub fn new() -> AccountOriginInvitationAcceptRequest {
+impl AccountIdentityGet {
+    pub fn new() -> AccountIdentityGet {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountOriginInvitationAcceptRequest {
-        static mut instance: ::protobuf::lazy::Lazy<AccountOriginInvitationAcceptRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountIdentityGet {
+        static mut instance: ::protobuf::lazy::Lazy<AccountIdentityGet> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountOriginInvitationAcceptRequest,
+            ptr: 0 as *const AccountIdentityGet,
         };
         unsafe {
-            instance.get(AccountOriginInvitationAcceptRequest::new)
+            instance.get(AccountIdentityGet::new)
         }
     }
 
-    // optional uint64 account_id = 1;
-
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
-    }
-
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
-    }
-
-    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.account_id
-    }
-
-    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.account_id
-    }
-
-    // optional uint64 invite_id = 2;
+    // optional .sessionsrv.OAuthProvider provider = 1;
 
-    pub fn clear_invite_id(&mut self) {
-        self.invite_id = ::std::option::Option::None;
+    pub fn clear_provider(&mut self) {
+        self.provider = ::std::option::Option::None;
     }
 
-    pub fn has_invite_id(&self) -> bool {
-        self.invite_id.is_some()
+    pub fn has_provider(&self) -> bool {
+        self.provider.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_invite_id(&mut self, v: u64) {
-        self.invite_id = ::std::option::Option::Some(v);
+    pub fn set_provider(&mut self, v: OAuthProvider) {
+        self.provider = ::std::option::Option::Some(v);
     }
 
-    pub fn get_invite_id(&self) -> u64 {
-        self.invite_id.unwrap_or(0)
+    pub fn get_provider(&self) -> OAuthProvider {
+        self.provider.unwrap_or(OAuthProvider::GitHub)
     }
 
-    fn get_invite_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.invite_id
+    fn get_provider_for_reflect(&self) -> &::std::option::Option<OAuthProvider> {
+        &self.provider
     }
 
-    fn mut_invite_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.invite_id
+    fn mut_provider_for_reflect(&mut self) -> &mut ::std::option::Option<OAuthProvider> {
+        &mut self.provider
     }
 
-    // optional string origin_name = 3;
+    // optional string subject = 2;
 
-    pub fn clear_origin_name(&mut self) {
-        self.origin_name.clear();
+    pub fn clear_subject(&mut self) {
+        self.subject.clear();
     }
 
-    pub fn has_origin_name(&self) -> bool {
-        self.origin_name.is_some()
+    pub fn has_subject(&self) -> bool {
+        self.subject.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_name(&mut self, v: ::std::string::String) {
-        self.origin_name = ::protobuf::SingularField::some(v);
+    pub fn set_subject(&mut self, v: ::std::string::String) {
+        self.subject = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
-        if self.origin_name.is_none() {
-            self.origin_name.set_default();
+    pub fn mut_subject(&mut self) -> &mut ::std::string::String {
+        if self.subject.is_none() {
+            self.subject.set_default();
         }
-        self.origin_name.as_mut().unwrap()
+        self.subject.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_origin_name(&mut self) -> ::std::string::String {
-        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_subject(&mut self) -> ::std::string::String {
+        self.subject.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_origin_name(&self) -> &str {
-        match self.origin_name.as_ref() {
+    pub fn get_subject(&self) -> &str {
+        match self.subject.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    fn get_origin_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.origin_name
-    }
-
-    fn mut_origin_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.origin_name
-    }
-
-    // optional bool ignore = 4;
-
-    pub fn clear_ignore(&mut self) {
-        self.ignore = ::std::option::Option::None;
-    }
-
-    pub fn has_ignore(&self) -> bool {
-        self.ignore.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_ignore(&mut self, v: bool) {
-        self.ignore = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_ignore(&self) -> bool {
-        self.ignore.unwrap_or(false)
-    }
-
-    fn get_ignore_for_reflect(&self) -> &::std::option::Option<bool> {
-        &self.ignore
+    fn get_subject_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.subject
     }
 
-    fn mut_ignore_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
-        &mut self.ignore
+    fn mut_subject_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.subject
     }
 }
 
-impl ::protobuf::Message for AccountOriginInvitationAcceptRequest {
+impl ::protobuf::Message for AccountIdentityGet {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2482,25 +2071,11 @@ impl ::protobuf::Message for AccountOriginInvitationAcceptRequest {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_uint64()?;
-                    self.account_id = ::std::option::Option::Some(tmp);
+                    let tmp = is.read_enum()?;
+                    self.provider = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.invite_id = ::std::option::Option::Some(tmp);
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name)?;
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.ignore = ::std::option::Option::Some(tmp);
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.subject)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2514,17 +2089,11 @@ impl ::protobuf::Message for AccountOriginInvitationAcceptRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.account_id {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(v) = self.invite_id {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(ref v) = self.origin_name.as_ref() {
-            my_size += ::protobuf::rt::string_size(3, &v);
+        if let Some(v) = self.provider {
+            my_size += ::protobuf::rt::enum_size(1, v);
         }
-        if let Some(v) = self.ignore {
-            my_size += 2;
+        if let Some(ref v) = self.subject.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2532,17 +2101,11 @@ impl ::protobuf::Message for AccountOriginInvitationAcceptRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_id {
-            os.write_uint64(1, v)?;
-        }
-        if let Some(v) = self.invite_id {
-            os.write_uint64(2, v)?;
-        }
-        if let Some(ref v) = self.origin_name.as_ref() {
-            os.write_string(3, &v)?;
+        if let Some(v) = self.provider {
+            os.write_enum(1, v.value())?;
         }
-        if let Some(v) = self.ignore {
-            os.write_bool(4, v)?;
+        if let Some(ref v) = self.subject.as_ref() {
+            os.write_string(2, &v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2575,12 +2138,12 @@ impl ::protobuf::Message for AccountOriginInvitationAcceptRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountOriginInvitationAcceptRequest {
-    fn new() -> AccountOriginInvitationAcceptRequest {
-        AccountOriginInvitationAcceptRequest::new()
+impl ::protobuf::MessageStatic for AccountIdentityGet {
+    fn new() -> AccountIdentityGet {
+        AccountIdentityGet::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountOriginInvitationAcceptRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountIdentityGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2588,28 +2151,18 @@ impl ::protobuf::MessageStatic for AccountOriginInvitationAcceptRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "account_id",
-                    AccountOriginInvitationAcceptRequest::get_account_id_for_reflect,
-                    AccountOriginInvitationAcceptRequest::mut_account_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "invite_id",
-                    AccountOriginInvitationAcceptRequest::get_invite_id_for_reflect,
-                    AccountOriginInvitationAcceptRequest::mut_invite_id_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<OAuthProvider>>(
+                    "provider",
+                    AccountIdentityGet::get_provider_for_reflect,
+                    AccountIdentityGet::mut_provider_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "origin_name",
-                    AccountOriginInvitationAcceptRequest::get_origin_name_for_reflect,
-                    AccountOriginInvitationAcceptRequest::mut_origin_name_for_reflect,
+                    "subject",
+                    AccountIdentityGet::get_subject_for_reflect,
+                    AccountIdentityGet::mut_subject_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "ignore",
-                    AccountOriginInvitationAcceptRequest::get_ignore_for_reflect,
-                    AccountOriginInvitationAcceptRequest::mut_ignore_for_reflect,
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginInvitationAcceptRequest>(
-                    "AccountOriginInvitationAcceptRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<AccountIdentityGet>(
+                    "AccountIdentityGet",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2618,57 +2171,114 @@ impl ::protobuf::MessageStatic for AccountOriginInvitationAcceptRequest {
     }
 }
 
-impl ::protobuf::Clear for AccountOriginInvitationAcceptRequest {
+impl ::protobuf::Clear for AccountIdentityGet {
     fn clear(&mut self) {
-        self.clear_account_id();
-        self.clear_invite_id();
-        self.clear_origin_name();
-        self.clear_ignore();
+        self.clear_provider();
+        self.clear_subject();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for AccountOriginInvitationAcceptRequest {
+impl ::std::fmt::Debug for AccountIdentityGet {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AccountOriginInvitationAcceptRequest {
+impl ::protobuf::reflect::ProtobufValue for AccountIdentityGet {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct AccountOriginInvitationIgnoreRequest {
+pub struct AccountOriginInvitation {
     // message fields
+    id: ::std::option::Option<u64>,
+    origin_invitation_id: ::std::option::Option<u64>,
     account_id: ::std::option::Option<u64>,
-    invitation_id: ::std::option::Option<u64>,
+    account_name: ::protobuf::SingularField<::std::string::String>,
+    origin_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountOriginInvitationIgnoreRequest {}
+unsafe impl ::std::marker::Sync for AccountOriginInvitation {}
 
-impl AccountOriginInvitationIgnoreRequest {
-    pub fn new() -> AccountOriginInvitationIgnoreRequest {
+impl AccountOriginInvitation {
+    pub fn new() -> AccountOriginInvitation {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountOriginInvitationIgnoreRequest {
-        static mut instance: ::protobuf::lazy::Lazy<AccountOriginInvitationIgnoreRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountOriginInvitation {
+        static mut instance: ::protobuf::lazy::Lazy<AccountOriginInvitation> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountOriginInvitationIgnoreRequest,
+            ptr: 0 as *const AccountOriginInvitation,
         };
         unsafe {
-            instance.get(AccountOriginInvitationIgnoreRequest::new)
+            instance.get(AccountOriginInvitation::new)
         }
     }
 
-    // optional uint64 account_id = 1;
+    // optional uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+
+    // optional uint64 origin_invitation_id = 2;
+
+    pub fn clear_origin_invitation_id(&mut self) {
+        self.origin_invitation_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_invitation_id(&self) -> bool {
+        self.origin_invitation_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_invitation_id(&mut self, v: u64) {
+        self.origin_invitation_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_invitation_id(&self) -> u64 {
+        self.origin_invitation_id.unwrap_or(0)
+    }
+
+    fn get_origin_invitation_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.origin_invitation_id
+    }
+
+    fn mut_origin_invitation_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.origin_invitation_id
+    }
+
+    // optional uint64 account_id = 3;
 
     pub fn clear_account_id(&mut self) {
         self.account_id = ::std::option::Option::None;
@@ -2695,255 +2305,150 @@ impl AccountOriginInvitationIgnoreRequest {
         &mut self.account_id
     }
 
-    // optional uint64 invitation_id = 2;
+    // optional string account_name = 4;
 
-    pub fn clear_invitation_id(&mut self) {
-        self.invitation_id = ::std::option::Option::None;
+    pub fn clear_account_name(&mut self) {
+        self.account_name.clear();
     }
 
-    pub fn has_invitation_id(&self) -> bool {
-        self.invitation_id.is_some()
+    pub fn has_account_name(&self) -> bool {
+        self.account_name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_invitation_id(&mut self, v: u64) {
-        self.invitation_id = ::std::option::Option::Some(v);
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_name = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_invitation_id(&self) -> u64 {
-        self.invitation_id.unwrap_or(0)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if self.account_name.is_none() {
+            self.account_name.set_default();
+        }
+        self.account_name.as_mut().unwrap()
     }
 
-    fn get_invitation_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.invitation_id
+    // Take field
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn mut_invitation_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.invitation_id
+    pub fn get_account_name(&self) -> &str {
+        match self.account_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
-}
 
-impl ::protobuf::Message for AccountOriginInvitationIgnoreRequest {
-    fn is_initialized(&self) -> bool {
-        true
+    fn get_account_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.account_name
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.account_id = ::std::option::Option::Some(tmp);
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.invitation_id = ::std::option::Option::Some(tmp);
-                },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
+    fn mut_account_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.account_name
     }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if let Some(v) = self.account_id {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(v) = self.invitation_id {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+    // optional uint64 origin_id = 5;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_id {
-            os.write_uint64(1, v)?;
-        }
-        if let Some(v) = self.invitation_id {
-            os.write_uint64(2, v)?;
-        }
-        os.write_unknown_fields(self.get_unknown_fields())?;
-        ::std::result::Result::Ok(())
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
-        &self.unknown_fields
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
 
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
+    fn get_origin_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.origin_id
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
+    fn mut_origin_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.origin_id
     }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
+
+    // optional string origin_name = 6;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
     }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
-        self
+
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
     }
-}
 
-impl ::protobuf::MessageStatic for AccountOriginInvitationIgnoreRequest {
-    fn new() -> AccountOriginInvitationIgnoreRequest {
-        AccountOriginInvitationIgnoreRequest::new()
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
+        }
+        self.origin_name.as_mut().unwrap()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountOriginInvitationIgnoreRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "account_id",
-                    AccountOriginInvitationIgnoreRequest::get_account_id_for_reflect,
-                    AccountOriginInvitationIgnoreRequest::mut_account_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "invitation_id",
-                    AccountOriginInvitationIgnoreRequest::get_invitation_id_for_reflect,
-                    AccountOriginInvitationIgnoreRequest::mut_invitation_id_for_reflect,
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginInvitationIgnoreRequest>(
-                    "AccountOriginInvitationIgnoreRequest",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
-    }
-}
-
-impl ::protobuf::Clear for AccountOriginInvitationIgnoreRequest {
-    fn clear(&mut self) {
-        self.clear_account_id();
-        self.clear_invitation_id();
-        self.unknown_fields.clear();
-    }
-}
-
-impl ::std::fmt::Debug for AccountOriginInvitationIgnoreRequest {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
-    }
-}
-
-impl ::protobuf::reflect::ProtobufValue for AccountOriginInvitationIgnoreRequest {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
-    }
-}
-
-#[derive(PartialEq,Clone,Default)]
-pub struct AccountOriginInvitationRescindRequest {
-    // message fields
-    account_id: ::std::option::Option<u64>,
-    invitation_id: ::std::option::Option<u64>,
-    // special fields
-    unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::protobuf::CachedSize,
-}
-
-// see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountOriginInvitationRescindRequest {}
-
-impl AccountOriginInvitationRescindRequest {
-    pub fn new() -> AccountOriginInvitationRescindRequest {
-        ::std::default::Default::default()
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn default_instance() -> &'static AccountOriginInvitationRescindRequest {
-        static mut instance: ::protobuf::lazy::Lazy<AccountOriginInvitationRescindRequest> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountOriginInvitationRescindRequest,
-        };
-        unsafe {
-            instance.get(AccountOriginInvitationRescindRequest::new)
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
     }
 
-    // optional uint64 account_id = 1;
-
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
-    }
-
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
-    }
-
-    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.account_id
+    fn get_origin_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.origin_name
     }
 
-    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.account_id
+    fn mut_origin_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.origin_name
     }
 
-    // optional uint64 invitation_id = 2;
+    // optional uint64 owner_id = 7;
 
-    pub fn clear_invitation_id(&mut self) {
-        self.invitation_id = ::std::option::Option::None;
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
     }
 
-    pub fn has_invitation_id(&self) -> bool {
-        self.invitation_id.is_some()
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_invitation_id(&mut self, v: u64) {
-        self.invitation_id = ::std::option::Option::Some(v);
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_invitation_id(&self) -> u64 {
-        self.invitation_id.unwrap_or(0)
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
     }
 
-    fn get_invitation_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.invitation_id
+    fn get_owner_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.owner_id
     }
 
-    fn mut_invitation_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.invitation_id
+    fn mut_owner_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.owner_id
     }
 }
 
-impl ::protobuf::Message for AccountOriginInvitationRescindRequest {
+impl ::protobuf::Message for AccountOriginInvitation {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2957,14 +2462,41 @@ impl ::protobuf::Message for AccountOriginInvitationRescindRequest {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.account_id = ::std::option::Option::Some(tmp);
+                    self.id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.invitation_id = ::std::option::Option::Some(tmp);
+                    self.origin_invitation_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.owner_id = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2978,24 +2510,54 @@ impl ::protobuf::Message for AccountOriginInvitationRescindRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.account_id {
+        if let Some(v) = self.id {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(v) = self.invitation_id {
+        if let Some(v) = self.origin_invitation_id {
             my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.account_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(v) = self.origin_id {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.origin_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
+        if let Some(v) = self.owner_id {
+            my_size += ::protobuf::rt::value_size(7, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_id {
+        if let Some(v) = self.id {
             os.write_uint64(1, v)?;
         }
-        if let Some(v) = self.invitation_id {
+        if let Some(v) = self.origin_invitation_id {
             os.write_uint64(2, v)?;
         }
+        if let Some(v) = self.account_id {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(ref v) = self.account_name.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        if let Some(v) = self.origin_id {
+            os.write_uint64(5, v)?;
+        }
+        if let Some(ref v) = self.origin_name.as_ref() {
+            os.write_string(6, &v)?;
+        }
+        if let Some(v) = self.owner_id {
+            os.write_uint64(7, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3027,12 +2589,12 @@ impl ::protobuf::Message for AccountOriginInvitationRescindRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountOriginInvitationRescindRequest {
-    fn new() -> AccountOriginInvitationRescindRequest {
-        AccountOriginInvitationRescindRequest::new()
+impl ::protobuf::MessageStatic for AccountOriginInvitation {
+    fn new() -> AccountOriginInvitation {
+        AccountOriginInvitation::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountOriginInvitationRescindRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountOriginInvitation>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3040,18 +2602,43 @@ impl ::protobuf::MessageStatic for AccountOriginInvitationRescindRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    AccountOriginInvitation::get_id_for_reflect,
+                    AccountOriginInvitation::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "origin_invitation_id",
+                    AccountOriginInvitation::get_origin_invitation_id_for_reflect,
+                    AccountOriginInvitation::mut_origin_invitation_id_for_reflect,
+                ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "account_id",
-                    AccountOriginInvitationRescindRequest::get_account_id_for_reflect,
-                    AccountOriginInvitationRescindRequest::mut_account_id_for_reflect,
+                    AccountOriginInvitation::get_account_id_for_reflect,
+                    AccountOriginInvitation::mut_account_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "account_name",
+                    AccountOriginInvitation::get_account_name_for_reflect,
+                    AccountOriginInvitation::mut_account_name_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "invitation_id",
-                    AccountOriginInvitationRescindRequest::get_invitation_id_for_reflect,
-                    AccountOriginInvitationRescindRequest::mut_invitation_id_for_reflect,
+                    "origin_id",
+                    AccountOriginInvitation::get_origin_id_for_reflect,
+                    AccountOriginInvitation::mut_origin_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginInvitationRescindRequest>(
-                    "AccountOriginInvitationRescindRequest",
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origin_name",
+                    AccountOriginInvitation::get_origin_name_for_reflect,
+                    AccountOriginInvitation::mut_origin_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "owner_id",
+                    AccountOriginInvitation::get_owner_id_for_reflect,
+                    AccountOriginInvitation::mut_owner_id_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginInvitation>(
+                    "AccountOriginInvitation",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3060,54 +2647,91 @@ impl ::protobuf::MessageStatic for AccountOriginInvitationRescindRequest {
     }
 }
 
-impl ::protobuf::Clear for AccountOriginInvitationRescindRequest {
+impl ::protobuf::Clear for AccountOriginInvitation {
     fn clear(&mut self) {
+        self.clear_id();
+        self.clear_origin_invitation_id();
         self.clear_account_id();
-        self.clear_invitation_id();
+        self.clear_account_name();
+        self.clear_origin_id();
+        self.clear_origin_name();
+        self.clear_owner_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for AccountOriginInvitationRescindRequest {
+impl ::std::fmt::Debug for AccountOriginInvitation {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AccountOriginInvitationRescindRequest {
+impl ::protobuf::reflect::ProtobufValue for AccountOriginInvitation {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct AccountInvitationListRequest {
+pub struct AccountOriginInvitationCreate {
     // message fields
+    origin_invitation_id: ::std::option::Option<u64>,
     account_id: ::std::option::Option<u64>,
+    account_name: ::protobuf::SingularField<::std::string::String>,
+    origin_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountInvitationListRequest {}
+unsafe impl ::std::marker::Sync for AccountOriginInvitationCreate {}
 
-impl AccountInvitationListRequest {
-    pub fn new() -> AccountInvitationListRequest {
+impl AccountOriginInvitationCreate {
+    pub fn new() -> AccountOriginInvitationCreate {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountInvitationListRequest {
-        static mut instance: ::protobuf::lazy::Lazy<AccountInvitationListRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountOriginInvitationCreate {
+        static mut instance: ::protobuf::lazy::Lazy<AccountOriginInvitationCreate> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountInvitationListRequest,
+            ptr: 0 as *const AccountOriginInvitationCreate,
         };
         unsafe {
-            instance.get(AccountInvitationListRequest::new)
+            instance.get(AccountOriginInvitationCreate::new)
         }
     }
 
-    // optional uint64 account_id = 1;
+    // optional uint64 origin_invitation_id = 1;
+
+    pub fn clear_origin_invitation_id(&mut self) {
+        self.origin_invitation_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_invitation_id(&self) -> bool {
+        self.origin_invitation_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_invitation_id(&mut self, v: u64) {
+        self.origin_invitation_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_invitation_id(&self) -> u64 {
+        self.origin_invitation_id.unwrap_or(0)
+    }
+
+    fn get_origin_invitation_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.origin_invitation_id
+    }
+
+    fn mut_origin_invitation_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.origin_invitation_id
+    }
+
+    // optional uint64 account_id = 2;
 
     pub fn clear_account_id(&mut self) {
         self.account_id = ::std::option::Option::None;
@@ -3133,9 +2757,151 @@ impl AccountInvitationListRequest {
     fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
         &mut self.account_id
     }
+
+    // optional string account_name = 3;
+
+    pub fn clear_account_name(&mut self) {
+        self.account_name.clear();
+    }
+
+    pub fn has_account_name(&self) -> bool {
+        self.account_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if self.account_name.is_none() {
+            self.account_name.set_default();
+        }
+        self.account_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_account_name(&self) -> &str {
+        match self.account_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_account_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.account_name
+    }
+
+    fn mut_account_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.account_name
+    }
+
+    // optional uint64 origin_id = 4;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    fn get_origin_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.origin_id
+    }
+
+    fn mut_origin_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.origin_id
+    }
+
+    // optional string origin_name = 5;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
+        }
+        self.origin_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_origin_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.origin_name
+    }
+
+    fn mut_origin_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.origin_name
+    }
+
+    // optional uint64 owner_id = 6;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    fn get_owner_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.owner_id
+    }
+
+    fn mut_owner_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.owner_id
+    }
 }
 
-impl ::protobuf::Message for AccountInvitationListRequest {
+impl ::protobuf::Message for AccountOriginInvitationCreate {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3145,12 +2911,39 @@ impl ::protobuf::Message for AccountInvitationListRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.origin_invitation_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
                     self.account_id = ::std::option::Option::Some(tmp);
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -3163,18 +2956,48 @@ impl ::protobuf::Message for AccountInvitationListRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.account_id {
+        if let Some(v) = self.origin_invitation_id {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.account_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.origin_id {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.origin_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        if let Some(v) = self.owner_id {
+            my_size += ::protobuf::rt::value_size(6, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_id {
+        if let Some(v) = self.origin_invitation_id {
             os.write_uint64(1, v)?;
         }
+        if let Some(v) = self.account_id {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(ref v) = self.account_name.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(v) = self.origin_id {
+            os.write_uint64(4, v)?;
+        }
+        if let Some(ref v) = self.origin_name.as_ref() {
+            os.write_string(5, &v)?;
+        }
+        if let Some(v) = self.owner_id {
+            os.write_uint64(6, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3206,12 +3029,12 @@ impl ::protobuf::Message for AccountInvitationListRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountInvitationListRequest {
-    fn new() -> AccountInvitationListRequest {
-        AccountInvitationListRequest::new()
+impl ::protobuf::MessageStatic for AccountOriginInvitationCreate {
+    fn new() -> AccountOriginInvitationCreate {
+        AccountOriginInvitationCreate::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountInvitationListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountOriginInvitationCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3219,13 +3042,38 @@ impl ::protobuf::MessageStatic for AccountInvitationListRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "origin_invitation_id",
+                    AccountOriginInvitationCreate::get_origin_invitation_id_for_reflect,
+                    AccountOriginInvitationCreate::mut_origin_invitation_id_for_reflect,
+                ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "account_id",
-                    AccountInvitationListRequest::get_account_id_for_reflect,
-                    AccountInvitationListRequest::mut_account_id_for_reflect,
+                    AccountOriginInvitationCreate::get_account_id_for_reflect,
+                    AccountOriginInvitationCreate::mut_account_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountInvitationListRequest>(
-                    "AccountInvitationListRequest",
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "account_name",
+                    AccountOriginInvitationCreate::get_account_name_for_reflect,
+                    AccountOriginInvitationCreate::mut_account_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "origin_id",
+                    AccountOriginInvitationCreate::get_origin_id_for_reflect,
+                    AccountOriginInvitationCreate::mut_origin_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origin_name",
+                    AccountOriginInvitationCreate::get_origin_name_for_reflect,
+                    AccountOriginInvitationCreate::mut_origin_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "owner_id",
+                    AccountOriginInvitationCreate::get_owner_id_for_reflect,
+                    AccountOriginInvitationCreate::mut_owner_id_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginInvitationCreate>(
+                    "AccountOriginInvitationCreate",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3234,50 +3082,57 @@ impl ::protobuf::MessageStatic for AccountInvitationListRequest {
     }
 }
 
-impl ::protobuf::Clear for AccountInvitationListRequest {
+impl ::protobuf::Clear for AccountOriginInvitationCreate {
     fn clear(&mut self) {
+        self.clear_origin_invitation_id();
         self.clear_account_id();
+        self.clear_account_name();
+        self.clear_origin_id();
+        self.clear_origin_name();
+        self.clear_owner_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for AccountInvitationListRequest {
+impl ::std::fmt::Debug for AccountOriginInvitationCreate {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AccountInvitationListRequest {
+impl ::protobuf::reflect::ProtobufValue for AccountOriginInvitationCreate {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct AccountInvitationListResponse {
+pub struct AccountOriginInvitationAcceptRequest {
     // message fields
     account_id: ::std::option::Option<u64>,
-    invitations: ::protobuf::RepeatedField<AccountOriginInvitation>,
+    invite_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
+    ignore: ::std::option::Option<bool>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountInvitationListResponse {}
+unsafe impl ::std::marker::Sync for AccountOriginInvitationAcceptRequest {}
 
-impl AccountInvitationListResponse {
-    pub fn new() -> AccountInvitationListResponse {
+impl AccountOriginInvitationAcceptRequest {
+    pub fn new() -> AccountOriginInvitationAcceptRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountInvitationListResponse {
-        static mut instance: ::protobuf::lazy::Lazy<AccountInvitationListResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountOriginInvitationAcceptRequest {
+        static mut instance: ::protobuf::lazy::Lazy<AccountOriginInvitationAcceptRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountInvitationListResponse,
+            ptr: 0 as *const AccountOriginInvitationAcceptRequest,
         };
         unsafe {
-            instance.get(AccountInvitationListResponse::new)
+            instance.get(AccountOriginInvitationAcceptRequest::new)
         }
     }
 
@@ -3308,47 +3163,107 @@ impl AccountInvitationListResponse {
         &mut self.account_id
     }
 
-    // repeated .sessionsrv.AccountOriginInvitation invitations = 2;
+    // optional uint64 invite_id = 2;
 
-    pub fn clear_invitations(&mut self) {
-        self.invitations.clear();
+    pub fn clear_invite_id(&mut self) {
+        self.invite_id = ::std::option::Option::None;
+    }
+
+    pub fn has_invite_id(&self) -> bool {
+        self.invite_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_invitations(&mut self, v: ::protobuf::RepeatedField<AccountOriginInvitation>) {
-        self.invitations = v;
+    pub fn set_invite_id(&mut self, v: u64) {
+        self.invite_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_invite_id(&self) -> u64 {
+        self.invite_id.unwrap_or(0)
+    }
+
+    fn get_invite_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.invite_id
+    }
+
+    fn mut_invite_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.invite_id
+    }
+
+    // optional string origin_name = 3;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_invitations(&mut self) -> &mut ::protobuf::RepeatedField<AccountOriginInvitation> {
-        &mut self.invitations
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
+        }
+        self.origin_name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_invitations(&mut self) -> ::protobuf::RepeatedField<AccountOriginInvitation> {
-        ::std::mem::replace(&mut self.invitations, ::protobuf::RepeatedField::new())
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_invitations(&self) -> &[AccountOriginInvitation] {
-        &self.invitations
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    fn get_invitations_for_reflect(&self) -> &::protobuf::RepeatedField<AccountOriginInvitation> {
-        &self.invitations
+    fn get_origin_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.origin_name
     }
 
-    fn mut_invitations_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<AccountOriginInvitation> {
-        &mut self.invitations
+    fn mut_origin_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.origin_name
+    }
+
+    // optional bool ignore = 4;
+
+    pub fn clear_ignore(&mut self) {
+        self.ignore = ::std::option::Option::None;
+    }
+
+    pub fn has_ignore(&self) -> bool {
+        self.ignore.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ignore(&mut self, v: bool) {
+        self.ignore = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_ignore(&self) -> bool {
+        self.ignore.unwrap_or(false)
+    }
+
+    fn get_ignore_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.ignore
+    }
+
+    fn mut_ignore_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.ignore
     }
 }
 
-impl ::protobuf::Message for AccountInvitationListResponse {
+impl ::protobuf::Message for AccountOriginInvitationAcceptRequest {
     fn is_initialized(&self) -> bool {
-        for v in &self.invitations {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -3364,7 +3279,21 @@ impl ::protobuf::Message for AccountInvitationListResponse {
                     self.account_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.invitations)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.invite_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.ignore = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -3381,10 +3310,15 @@ impl ::protobuf::Message for AccountInvitationListResponse {
         if let Some(v) = self.account_id {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        for value in &self.invitations {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
+        if let Some(v) = self.invite_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.origin_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.ignore {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3394,11 +3328,15 @@ impl ::protobuf::Message for AccountInvitationListResponse {
         if let Some(v) = self.account_id {
             os.write_uint64(1, v)?;
         }
-        for v in &self.invitations {
-            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
+        if let Some(v) = self.invite_id {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(ref v) = self.origin_name.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(v) = self.ignore {
+            os.write_bool(4, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3430,12 +3368,12 @@ impl ::protobuf::Message for AccountInvitationListResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountInvitationListResponse {
-    fn new() -> AccountInvitationListResponse {
-        AccountInvitationListResponse::new()
+impl ::protobuf::MessageStatic for AccountOriginInvitationAcceptRequest {
+    fn new() -> AccountOriginInvitationAcceptRequest {
+        AccountOriginInvitationAcceptRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountInvitationListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountOriginInvitationAcceptRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3445,16 +3383,26 @@ impl ::protobuf::MessageStatic for AccountInvitationListResponse {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "account_id",
-                    AccountInvitationListResponse::get_account_id_for_reflect,
-                    AccountInvitationListResponse::mut_account_id_for_reflect,
+                    AccountOriginInvitationAcceptRequest::get_account_id_for_reflect,
+                    AccountOriginInvitationAcceptRequest::mut_account_id_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AccountOriginInvitation>>(
-                    "invitations",
-                    AccountInvitationListResponse::get_invitations_for_reflect,
-                    AccountInvitationListResponse::mut_invitations_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "invite_id",
+                    AccountOriginInvitationAcceptRequest::get_invite_id_for_reflect,
+                    AccountOriginInvitationAcceptRequest::mut_invite_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountInvitationListResponse>(
-                    "AccountInvitationListResponse",
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origin_name",
+                    AccountOriginInvitationAcceptRequest::get_origin_name_for_reflect,
+                    AccountOriginInvitationAcceptRequest::mut_origin_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "ignore",
+                    AccountOriginInvitationAcceptRequest::get_ignore_for_reflect,
+                    AccountOriginInvitationAcceptRequest::mut_ignore_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginInvitationAcceptRequest>(
+                    "AccountOriginInvitationAcceptRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3463,53 +3411,53 @@ impl ::protobuf::MessageStatic for AccountInvitationListResponse {
     }
 }
 
-impl ::protobuf::Clear for AccountInvitationListResponse {
+impl ::protobuf::Clear for AccountOriginInvitationAcceptRequest {
     fn clear(&mut self) {
         self.clear_account_id();
-        self.clear_invitations();
+        self.clear_invite_id();
+        self.clear_origin_name();
+        self.clear_ignore();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for AccountInvitationListResponse {
+impl ::std::fmt::Debug for AccountOriginInvitationAcceptRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AccountInvitationListResponse {
+impl ::protobuf::reflect::ProtobufValue for AccountOriginInvitationAcceptRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct AccountOriginCreate {
+pub struct AccountOriginInvitationIgnoreRequest {
     // message fields
     account_id: ::std::option::Option<u64>,
-    account_name: ::protobuf::SingularField<::std::string::String>,
-    origin_id: ::std::option::Option<u64>,
-    origin_name: ::protobuf::SingularField<::std::string::String>,
+    invitation_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountOriginCreate {}
+unsafe impl ::std::marker::Sync for AccountOriginInvitationIgnoreRequest {}
 
-impl AccountOriginCreate {
-    pub fn new() -> AccountOriginCreate {
+impl AccountOriginInvitationIgnoreRequest {
+    pub fn new() -> AccountOriginInvitationIgnoreRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountOriginCreate {
-        static mut instance: ::protobuf::lazy::Lazy<AccountOriginCreate> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountOriginInvitationIgnoreRequest {
+        static mut instance: ::protobuf::lazy::Lazy<AccountOriginInvitationIgnoreRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountOriginCreate,
+            ptr: 0 as *const AccountOriginInvitationIgnoreRequest,
         };
         unsafe {
-            instance.get(AccountOriginCreate::new)
+            instance.get(AccountOriginInvitationIgnoreRequest::new)
         }
     }
 
@@ -3540,123 +3488,35 @@ impl AccountOriginCreate {
         &mut self.account_id
     }
 
-    // optional string account_name = 2;
-
-    pub fn clear_account_name(&mut self) {
-        self.account_name.clear();
-    }
-
-    pub fn has_account_name(&self) -> bool {
-        self.account_name.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_account_name(&mut self, v: ::std::string::String) {
-        self.account_name = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
-        if self.account_name.is_none() {
-            self.account_name.set_default();
-        }
-        self.account_name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_account_name(&mut self) -> ::std::string::String {
-        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_account_name(&self) -> &str {
-        match self.account_name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
-
-    fn get_account_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.account_name
-    }
-
-    fn mut_account_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.account_name
-    }
-
-    // optional uint64 origin_id = 3;
-
-    pub fn clear_origin_id(&mut self) {
-        self.origin_id = ::std::option::Option::None;
-    }
-
-    pub fn has_origin_id(&self) -> bool {
-        self.origin_id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_origin_id(&self) -> u64 {
-        self.origin_id.unwrap_or(0)
-    }
-
-    fn get_origin_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.origin_id
-    }
-
-    fn mut_origin_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.origin_id
-    }
-
-    // optional string origin_name = 4;
+    // optional uint64 invitation_id = 2;
 
-    pub fn clear_origin_name(&mut self) {
-        self.origin_name.clear();
+    pub fn clear_invitation_id(&mut self) {
+        self.invitation_id = ::std::option::Option::None;
     }
 
-    pub fn has_origin_name(&self) -> bool {
-        self.origin_name.is_some()
+    pub fn has_invitation_id(&self) -> bool {
+        self.invitation_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_name(&mut self, v: ::std::string::String) {
-        self.origin_name = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
-        if self.origin_name.is_none() {
-            self.origin_name.set_default();
-        }
-        self.origin_name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_origin_name(&mut self) -> ::std::string::String {
-        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn set_invitation_id(&mut self, v: u64) {
+        self.invitation_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_origin_name(&self) -> &str {
-        match self.origin_name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_invitation_id(&self) -> u64 {
+        self.invitation_id.unwrap_or(0)
     }
 
-    fn get_origin_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.origin_name
+    fn get_invitation_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.invitation_id
     }
 
-    fn mut_origin_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.origin_name
+    fn mut_invitation_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.invitation_id
     }
 }
 
-impl ::protobuf::Message for AccountOriginCreate {
+impl ::protobuf::Message for AccountOriginInvitationIgnoreRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3673,17 +3533,11 @@ impl ::protobuf::Message for AccountOriginCreate {
                     self.account_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name)?;
-                },
-                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.origin_id = ::std::option::Option::Some(tmp);
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name)?;
+                    self.invitation_id = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -3700,14 +3554,8 @@ impl ::protobuf::Message for AccountOriginCreate {
         if let Some(v) = self.account_id {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(ref v) = self.account_name.as_ref() {
-            my_size += ::protobuf::rt::string_size(2, &v);
-        }
-        if let Some(v) = self.origin_id {
-            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(ref v) = self.origin_name.as_ref() {
-            my_size += ::protobuf::rt::string_size(4, &v);
+        if let Some(v) = self.invitation_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -3718,14 +3566,8 @@ impl ::protobuf::Message for AccountOriginCreate {
         if let Some(v) = self.account_id {
             os.write_uint64(1, v)?;
         }
-        if let Some(ref v) = self.account_name.as_ref() {
-            os.write_string(2, &v)?;
-        }
-        if let Some(v) = self.origin_id {
-            os.write_uint64(3, v)?;
-        }
-        if let Some(ref v) = self.origin_name.as_ref() {
-            os.write_string(4, &v)?;
+        if let Some(v) = self.invitation_id {
+            os.write_uint64(2, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -3758,12 +3600,12 @@ impl ::protobuf::Message for AccountOriginCreate {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountOriginCreate {
-    fn new() -> AccountOriginCreate {
-        AccountOriginCreate::new()
+impl ::protobuf::MessageStatic for AccountOriginInvitationIgnoreRequest {
+    fn new() -> AccountOriginInvitationIgnoreRequest {
+        AccountOriginInvitationIgnoreRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountOriginCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountOriginInvitationIgnoreRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3773,26 +3615,16 @@ impl ::protobuf::MessageStatic for AccountOriginCreate {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "account_id",
-                    AccountOriginCreate::get_account_id_for_reflect,
-                    AccountOriginCreate::mut_account_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "account_name",
-                    AccountOriginCreate::get_account_name_for_reflect,
-                    AccountOriginCreate::mut_account_name_for_reflect,
+                    AccountOriginInvitationIgnoreRequest::get_account_id_for_reflect,
+                    AccountOriginInvitationIgnoreRequest::mut_account_id_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "origin_id",
-                    AccountOriginCreate::get_origin_id_for_reflect,
-                    AccountOriginCreate::mut_origin_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "origin_name",
-                    AccountOriginCreate::get_origin_name_for_reflect,
-                    AccountOriginCreate::mut_origin_name_for_reflect,
+                    "invitation_id",
+                    AccountOriginInvitationIgnoreRequest::get_invitation_id_for_reflect,
+                    AccountOriginInvitationIgnoreRequest::mut_invitation_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginCreate>(
-                    "AccountOriginCreate",
+                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginInvitationIgnoreRequest>(
+                    "AccountOriginInvitationIgnoreRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3801,52 +3633,51 @@ impl ::protobuf::MessageStatic for AccountOriginCreate {
     }
 }
 
-impl ::protobuf::Clear for AccountOriginCreate {
+impl ::protobuf::Clear for AccountOriginInvitationIgnoreRequest {
     fn clear(&mut self) {
         self.clear_account_id();
-        self.clear_account_name();
-        self.clear_origin_id();
-        self.clear_origin_name();
+        self.clear_invitation_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for AccountOriginCreate {
+impl ::std::fmt::Debug for AccountOriginInvitationIgnoreRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AccountOriginCreate {
+impl ::protobuf::reflect::ProtobufValue for AccountOriginInvitationIgnoreRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct AccountOriginListRequest {
+pub struct AccountOriginInvitationRescindRequest {
     // message fields
     account_id: ::std::option::Option<u64>,
+    invitation_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountOriginListRequest {}
+unsafe impl ::std::marker::Sync for AccountOriginInvitationRescindRequest {}
 
-impl AccountOriginListRequest {
-    pub fn new() -> AccountOriginListRequest {
+impl AccountOriginInvitationRescindRequest {
+    pub fn new() -> AccountOriginInvitationRescindRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountOriginListRequest {
-        static mut instance: ::protobuf::lazy::Lazy<AccountOriginListRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountOriginInvitationRescindRequest {
+        static mut instance: ::protobuf::lazy::Lazy<AccountOriginInvitationRescindRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountOriginListRequest,
+            ptr: 0 as *const AccountOriginInvitationRescindRequest,
         };
         unsafe {
-            instance.get(AccountOriginListRequest::new)
+            instance.get(AccountOriginInvitationRescindRequest::new)
         }
     }
 
@@ -3876,9 +3707,36 @@ impl AccountOriginListRequest {
     fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
         &mut self.account_id
     }
+
+    // optional uint64 invitation_id = 2;
+
+    pub fn clear_invitation_id(&mut self) {
+        self.invitation_id = ::std::option::Option::None;
+    }
+
+    pub fn has_invitation_id(&self) -> bool {
+        self.invitation_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invitation_id(&mut self, v: u64) {
+        self.invitation_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_invitation_id(&self) -> u64 {
+        self.invitation_id.unwrap_or(0)
+    }
+
+    fn get_invitation_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.invitation_id
+    }
+
+    fn mut_invitation_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.invitation_id
+    }
 }
 
-impl ::protobuf::Message for AccountOriginListRequest {
+impl ::protobuf::Message for AccountOriginInvitationRescindRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3894,6 +3752,13 @@ impl ::protobuf::Message for AccountOriginListRequest {
                     let tmp = is.read_uint64()?;
                     self.account_id = ::std::option::Option::Some(tmp);
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.invitation_id = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -3909,6 +3774,9 @@ impl ::protobuf::Message for AccountOriginListRequest {
         if let Some(v) = self.account_id {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let Some(v) = self.invitation_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3918,6 +3786,9 @@ impl ::protobuf::Message for AccountOriginListRequest {
         if let Some(v) = self.account_id {
             os.write_uint64(1, v)?;
         }
+        if let Some(v) = self.invitation_id {
+            os.write_uint64(2, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3949,12 +3820,12 @@ impl ::protobuf::Message for AccountOriginListRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountOriginListRequest {
-    fn new() -> AccountOriginListRequest {
-        AccountOriginListRequest::new()
+impl ::protobuf::MessageStatic for AccountOriginInvitationRescindRequest {
+    fn new() -> AccountOriginInvitationRescindRequest {
+        AccountOriginInvitationRescindRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountOriginListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountOriginInvitationRescindRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3964,11 +3835,16 @@ impl ::protobuf::MessageStatic for AccountOriginListRequest {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "account_id",
-                    AccountOriginListRequest::get_account_id_for_reflect,
-                    AccountOriginListRequest::mut_account_id_for_reflect,
+                    AccountOriginInvitationRescindRequest::get_account_id_for_reflect,
+                    AccountOriginInvitationRescindRequest::mut_account_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginListRequest>(
-                    "AccountOriginListRequest",
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "invitation_id",
+                    AccountOriginInvitationRescindRequest::get_invitation_id_for_reflect,
+                    AccountOriginInvitationRescindRequest::mut_invitation_id_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginInvitationRescindRequest>(
+                    "AccountOriginInvitationRescindRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3977,50 +3853,50 @@ impl ::protobuf::MessageStatic for AccountOriginListRequest {
     }
 }
 
-impl ::protobuf::Clear for AccountOriginListRequest {
+impl ::protobuf::Clear for AccountOriginInvitationRescindRequest {
     fn clear(&mut self) {
         self.clear_account_id();
+        self.clear_invitation_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for AccountOriginListRequest {
+impl ::std::fmt::Debug for AccountOriginInvitationRescindRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AccountOriginListRequest {
+impl ::protobuf::reflect::ProtobufValue for AccountOriginInvitationRescindRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct AccountOriginListResponse {
+pub struct AccountInvitationListRequest {
     // message fields
     account_id: ::std::option::Option<u64>,
-    origins: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountOriginListResponse {}
+unsafe impl ::std::marker::Sync for AccountInvitationListRequest {}
 
-impl AccountOriginListResponse {
-    pub fn new() -> AccountOriginListResponse {
+impl AccountInvitationListRequest {
+    pub fn new() -> AccountInvitationListRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountOriginListResponse {
-        static mut instance: ::protobuf::lazy::Lazy<AccountOriginListResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountInvitationListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<AccountInvitationListRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountOriginListResponse,
+            ptr: 0 as *const AccountInvitationListRequest,
         };
         unsafe {
-            instance.get(AccountOriginListResponse::new)
+            instance.get(AccountInvitationListRequest::new)
         }
     }
 
@@ -4050,42 +3926,9 @@ impl AccountOriginListResponse {
     fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
         &mut self.account_id
     }
-
-    // repeated string origins = 2;
-
-    pub fn clear_origins(&mut self) {
-        self.origins.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_origins(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.origins = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_origins(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.origins
-    }
-
-    // Take field
-    pub fn take_origins(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.origins, ::protobuf::RepeatedField::new())
-    }
-
-    pub fn get_origins(&self) -> &[::std::string::String] {
-        &self.origins
-    }
-
-    fn get_origins_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
-        &self.origins
-    }
-
-    fn mut_origins_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.origins
-    }
 }
 
-impl ::protobuf::Message for AccountOriginListResponse {
+impl ::protobuf::Message for AccountInvitationListRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4101,9 +3944,6 @@ impl ::protobuf::Message for AccountOriginListResponse {
                     let tmp = is.read_uint64()?;
                     self.account_id = ::std::option::Option::Some(tmp);
                 },
-                2 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.origins)?;
-                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -4119,9 +3959,6 @@ impl ::protobuf::Message for AccountOriginListResponse {
         if let Some(v) = self.account_id {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        for value in &self.origins {
-            my_size += ::protobuf::rt::string_size(2, &value);
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -4131,9 +3968,6 @@ impl ::protobuf::Message for AccountOriginListResponse {
         if let Some(v) = self.account_id {
             os.write_uint64(1, v)?;
         }
-        for v in &self.origins {
-            os.write_string(2, &v)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4165,12 +3999,12 @@ impl ::protobuf::Message for AccountOriginListResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountOriginListResponse {
-    fn new() -> AccountOriginListResponse {
-        AccountOriginListResponse::new()
+impl ::protobuf::MessageStatic for AccountInvitationListRequest {
+    fn new() -> AccountInvitationListRequest {
+        AccountInvitationListRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountOriginListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountInvitationListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -4180,16 +4014,11 @@ impl ::protobuf::MessageStatic for AccountOriginListResponse {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "account_id",
-                    AccountOriginListResponse::get_account_id_for_reflect,
-                    AccountOriginListResponse::mut_account_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "origins",
-                    AccountOriginListResponse::get_origins_for_reflect,
-                    AccountOriginListResponse::mut_origins_for_reflect,
+                    AccountInvitationListRequest::get_account_id_for_reflect,
+                    AccountInvitationListRequest::mut_account_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginListResponse>(
-                    "AccountOriginListResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<AccountInvitationListRequest>(
+                    "AccountInvitationListRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4198,128 +4027,121 @@ impl ::protobuf::MessageStatic for AccountOriginListResponse {
     }
 }
 
-impl ::protobuf::Clear for AccountOriginListResponse {
+impl ::protobuf::Clear for AccountInvitationListRequest {
     fn clear(&mut self) {
         self.clear_account_id();
-        self.clear_origins();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for AccountOriginListResponse {
+impl ::std::fmt::Debug for AccountInvitationListRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AccountOriginListResponse {
+impl ::protobuf::reflect::ProtobufValue for AccountInvitationListRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct AccountOriginRemove {
+pub struct AccountInvitationListResponse {
     // message fields
-    account_name: ::protobuf::SingularField<::std::string::String>,
-    origin_id: ::std::option::Option<u64>,
+    account_id: ::std::option::Option<u64>,
+    invitations: ::protobuf::RepeatedField<AccountOriginInvitation>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountOriginRemove {}
+unsafe impl ::std::marker::Sync for AccountInvitationListResponse {}
 
-impl AccountOriginRemove {
-    pub fn new() -> AccountOriginRemove {
+impl AccountInvitationListResponse {
+    pub fn new() -> AccountInvitationListResponse {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountOriginRemove {
-        static mut instance: ::protobuf::lazy::Lazy<AccountOriginRemove> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountInvitationListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<AccountInvitationListResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountOriginRemove,
+            ptr: 0 as *const AccountInvitationListResponse,
         };
         unsafe {
-            instance.get(AccountOriginRemove::new)
+            instance.get(AccountInvitationListResponse::new)
         }
     }
 
-    // optional string account_name = 1;
+    // optional uint64 account_id = 1;
 
-    pub fn clear_account_name(&mut self) {
-        self.account_name.clear();
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
     }
 
-    pub fn has_account_name(&self) -> bool {
-        self.account_name.is_some()
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_account_name(&mut self, v: ::std::string::String) {
-        self.account_name = ::protobuf::SingularField::some(v);
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
-        if self.account_name.is_none() {
-            self.account_name.set_default();
-        }
-        self.account_name.as_mut().unwrap()
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
     }
 
-    // Take field
-    pub fn take_account_name(&mut self) -> ::std::string::String {
-        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
     }
 
-    pub fn get_account_name(&self) -> &str {
-        match self.account_name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
     }
 
-    fn get_account_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.account_name
-    }
+    // repeated .sessionsrv.AccountOriginInvitation invitations = 2;
 
-    fn mut_account_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.account_name
+    pub fn clear_invitations(&mut self) {
+        self.invitations.clear();
     }
 
-    // optional uint64 origin_id = 2;
-
-    pub fn clear_origin_id(&mut self) {
-        self.origin_id = ::std::option::Option::None;
+    // Param is passed by value, moved
+    pub fn set_invitations(&mut self, v: ::protobuf::RepeatedField<AccountOriginInvitation>) {
+        self.invitations = v;
     }
 
-    pub fn has_origin_id(&self) -> bool {
-        self.origin_id.is_some()
+    // Mutable pointer to the field.
+    pub fn mut_invitations(&mut self) -> &mut ::protobuf::RepeatedField<AccountOriginInvitation> {
+        &mut self.invitations
     }
 
-    // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_id = ::std::option::Option::Some(v);
+    // Take field
+    pub fn take_invitations(&mut self) -> ::protobuf::RepeatedField<AccountOriginInvitation> {
+        ::std::mem::replace(&mut self.invitations, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_origin_id(&self) -> u64 {
-        self.origin_id.unwrap_or(0)
+    pub fn get_invitations(&self) -> &[AccountOriginInvitation] {
+        &self.invitations
     }
 
-    fn get_origin_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.origin_id
+    fn get_invitations_for_reflect(&self) -> &::protobuf::RepeatedField<AccountOriginInvitation> {
+        &self.invitations
     }
 
-    fn mut_origin_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.origin_id
+    fn mut_invitations_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<AccountOriginInvitation> {
+        &mut self.invitations
     }
 }
 
-impl ::protobuf::Message for AccountOriginRemove {
+impl ::protobuf::Message for AccountInvitationListResponse {
     fn is_initialized(&self) -> bool {
+        for v in &self.invitations {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -4328,14 +4150,14 @@ impl ::protobuf::Message for AccountOriginRemove {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name)?;
-                },
-                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.origin_id = ::std::option::Option::Some(tmp);
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.invitations)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -4349,24 +4171,27 @@ impl ::protobuf::Message for AccountOriginRemove {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.account_name.as_ref() {
-            my_size += ::protobuf::rt::string_size(1, &v);
-        }
-        if let Some(v) = self.origin_id {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
+        for value in &self.invitations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.account_name.as_ref() {
-            os.write_string(1, &v)?;
-        }
-        if let Some(v) = self.origin_id {
-            os.write_uint64(2, v)?;
+        if let Some(v) = self.account_id {
+            os.write_uint64(1, v)?;
         }
+        for v in &self.invitations {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4398,12 +4223,12 @@ impl ::protobuf::Message for AccountOriginRemove {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountOriginRemove {
-    fn new() -> AccountOriginRemove {
-        AccountOriginRemove::new()
+impl ::protobuf::MessageStatic for AccountInvitationListResponse {
+    fn new() -> AccountInvitationListResponse {
+        AccountInvitationListResponse::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountOriginRemove>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountInvitationListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -4411,18 +4236,18 @@ impl ::protobuf::MessageStatic for AccountOriginRemove {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "account_name",
-                    AccountOriginRemove::get_account_name_for_reflect,
-                    AccountOriginRemove::mut_account_name_for_reflect,
-                ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "origin_id",
-                    AccountOriginRemove::get_origin_id_for_reflect,
-                    AccountOriginRemove::mut_origin_id_for_reflect,
+                    "account_id",
+                    AccountInvitationListResponse::get_account_id_for_reflect,
+                    AccountInvitationListResponse::mut_account_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginRemove>(
-                    "AccountOriginRemove",
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AccountOriginInvitation>>(
+                    "invitations",
+                    AccountInvitationListResponse::get_invitations_for_reflect,
+                    AccountInvitationListResponse::mut_invitations_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountInvitationListResponse>(
+                    "AccountInvitationListResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4431,378 +4256,270 @@ impl ::protobuf::MessageStatic for AccountOriginRemove {
     }
 }
 
-impl ::protobuf::Clear for AccountOriginRemove {
+impl ::protobuf::Clear for AccountInvitationListResponse {
     fn clear(&mut self) {
-        self.clear_account_name();
-        self.clear_origin_id();
+        self.clear_account_id();
+        self.clear_invitations();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for AccountOriginRemove {
+impl ::std::fmt::Debug for AccountInvitationListResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AccountOriginRemove {
+impl ::protobuf::reflect::ProtobufValue for AccountInvitationListResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Session {
+pub struct AccountOriginCreate {
     // message fields
-    id: ::std::option::Option<u64>,
-    email: ::protobuf::SingularField<::std::string::String>,
-    name: ::protobuf::SingularField<::std::string::String>,
-    token: ::protobuf::SingularField<::std::string::String>,
-    flags: ::std::option::Option<u32>,
-    oauth_token: ::protobuf::SingularField<::std::string::String>,
+    account_id: ::std::option::Option<u64>,
+    account_name: ::protobuf::SingularField<::std::string::String>,
+    origin_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Session {}
+unsafe impl ::std::marker::Sync for AccountOriginCreate {}
 
-impl Session {
-    pub fn new() -> Session {
+impl AccountOriginCreate {
+    pub fn new() -> AccountOriginCreate {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Session {
-        static mut instance: ::protobuf::lazy::Lazy<Session> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountOriginCreate {
+        static mut instance: ::protobuf::lazy::Lazy<AccountOriginCreate> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Session,
+            ptr: 0 as *const AccountOriginCreate,
         };
         unsafe {
-            instance.get(Session::new)
+            instance.get(AccountOriginCreate::new)
         }
     }
 
-    // optional uint64 id = 1;
+    // optional uint64 account_id = 1;
 
-    pub fn clear_id(&mut self) {
-        self.id = ::std::option::Option::None;
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
     }
 
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = ::std::option::Option::Some(v);
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
     }
 
-    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.id
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
     }
 
-    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.id
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
     }
 
-    // optional string email = 2;
+    // optional string account_name = 2;
 
-    pub fn clear_email(&mut self) {
-        self.email.clear();
+    pub fn clear_account_name(&mut self) {
+        self.account_name.clear();
     }
 
-    pub fn has_email(&self) -> bool {
-        self.email.is_some()
+    pub fn has_account_name(&self) -> bool {
+        self.account_name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_email(&mut self, v: ::std::string::String) {
-        self.email = ::protobuf::SingularField::some(v);
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_name = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_email(&mut self) -> &mut ::std::string::String {
-        if self.email.is_none() {
-            self.email.set_default();
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if self.account_name.is_none() {
+            self.account_name.set_default();
         }
-        self.email.as_mut().unwrap()
+        self.account_name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_email(&mut self) -> ::std::string::String {
-        self.email.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_email(&self) -> &str {
-        match self.email.as_ref() {
+    pub fn get_account_name(&self) -> &str {
+        match self.account_name.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    fn get_email_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.email
+    fn get_account_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.account_name
     }
 
-    fn mut_email_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.email
+    fn mut_account_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.account_name
     }
 
-    // optional string name = 3;
+    // optional uint64 origin_id = 3;
 
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
-        }
-        self.name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
 
-    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.name
+    fn get_origin_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.origin_id
     }
 
-    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.name
+    fn mut_origin_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.origin_id
     }
 
-    // optional string token = 4;
+    // optional string origin_name = 4;
 
-    pub fn clear_token(&mut self) {
-        self.token.clear();
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
     }
 
-    pub fn has_token(&self) -> bool {
-        self.token.is_some()
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_token(&mut self, v: ::std::string::String) {
-        self.token = ::protobuf::SingularField::some(v);
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_token(&mut self) -> &mut ::std::string::String {
-        if self.token.is_none() {
-            self.token.set_default();
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
         }
-        self.token.as_mut().unwrap()
+        self.origin_name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_token(&mut self) -> ::std::string::String {
-        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_token(&self) -> &str {
-        match self.token.as_ref() {
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    fn get_token_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.token
+    fn get_origin_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.origin_name
     }
 
-    fn mut_token_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.token
+    fn mut_origin_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.origin_name
     }
+}
 
-    // optional uint32 flags = 5;
-
-    pub fn clear_flags(&mut self) {
-        self.flags = ::std::option::Option::None;
+impl ::protobuf::Message for AccountOriginCreate {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    pub fn has_flags(&self) -> bool {
-        self.flags.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_flags(&mut self, v: u32) {
-        self.flags = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_flags(&self) -> u32 {
-        self.flags.unwrap_or(0)
-    }
-
-    fn get_flags_for_reflect(&self) -> &::std::option::Option<u32> {
-        &self.flags
-    }
-
-    fn mut_flags_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
-        &mut self.flags
-    }
-
-    // optional string oauth_token = 6;
-
-    pub fn clear_oauth_token(&mut self) {
-        self.oauth_token.clear();
-    }
-
-    pub fn has_oauth_token(&self) -> bool {
-        self.oauth_token.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_oauth_token(&mut self, v: ::std::string::String) {
-        self.oauth_token = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_oauth_token(&mut self) -> &mut ::std::string::String {
-        if self.oauth_token.is_none() {
-            self.oauth_token.set_default();
-        }
-        self.oauth_token.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_oauth_token(&mut self) -> ::std::string::String {
-        self.oauth_token.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_oauth_token(&self) -> &str {
-        match self.oauth_token.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
-
-    fn get_oauth_token_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.oauth_token
-    }
-
-    fn mut_oauth_token_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.oauth_token
-    }
-}
-
-impl ::protobuf::Message for Session {
-    fn is_initialized(&self) -> bool {
-        true
-    }
-
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.id = ::std::option::Option::Some(tmp);
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.email)?;
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token)?;
-                },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.flags = ::std::option::Option::Some(tmp);
-                },
-                6 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.oauth_token)?;
-                },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.id {
+        if let Some(v) = self.account_id {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(ref v) = self.email.as_ref() {
+        if let Some(ref v) = self.account_name.as_ref() {
             my_size += ::protobuf::rt::string_size(2, &v);
         }
-        if let Some(ref v) = self.name.as_ref() {
-            my_size += ::protobuf::rt::string_size(3, &v);
+        if let Some(v) = self.origin_id {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(ref v) = self.token.as_ref() {
+        if let Some(ref v) = self.origin_name.as_ref() {
             my_size += ::protobuf::rt::string_size(4, &v);
         }
-        if let Some(v) = self.flags {
-            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(ref v) = self.oauth_token.as_ref() {
-            my_size += ::protobuf::rt::string_size(6, &v);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id {
+        if let Some(v) = self.account_id {
             os.write_uint64(1, v)?;
         }
-        if let Some(ref v) = self.email.as_ref() {
+        if let Some(ref v) = self.account_name.as_ref() {
             os.write_string(2, &v)?;
         }
-        if let Some(ref v) = self.name.as_ref() {
-            os.write_string(3, &v)?;
+        if let Some(v) = self.origin_id {
+            os.write_uint64(3, v)?;
         }
-        if let Some(ref v) = self.token.as_ref() {
+        if let Some(ref v) = self.origin_name.as_ref() {
             os.write_string(4, &v)?;
         }
-        if let Some(v) = self.flags {
-            os.write_uint32(5, v)?;
-        }
-        if let Some(ref v) = self.oauth_token.as_ref() {
-            os.write_string(6, &v)?;
-        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4834,12 +4551,12 @@ impl ::protobuf::Message for Session {
     }
 }
 
-impl ::protobuf::MessageStatic for Session {
-    fn new() -> Session {
-        Session::new()
+impl ::protobuf::MessageStatic for AccountOriginCreate {
+    fn new() -> AccountOriginCreate {
+        AccountOriginCreate::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Session>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountOriginCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -4848,37 +4565,27 @@ impl ::protobuf::MessageStatic for Session {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "id",
-                    Session::get_id_for_reflect,
-                    Session::mut_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "email",
-                    Session::get_email_for_reflect,
-                    Session::mut_email_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    Session::get_name_for_reflect,
-                    Session::mut_name_for_reflect,
+                    "account_id",
+                    AccountOriginCreate::get_account_id_for_reflect,
+                    AccountOriginCreate::mut_account_id_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "token",
-                    Session::get_token_for_reflect,
-                    Session::mut_token_for_reflect,
+                    "account_name",
+                    AccountOriginCreate::get_account_name_for_reflect,
+                    AccountOriginCreate::mut_account_name_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "flags",
-                    Session::get_flags_for_reflect,
-                    Session::mut_flags_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "origin_id",
+                    AccountOriginCreate::get_origin_id_for_reflect,
+                    AccountOriginCreate::mut_origin_id_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "oauth_token",
-                    Session::get_oauth_token_for_reflect,
-                    Session::mut_oauth_token_for_reflect,
+                    "origin_name",
+                    AccountOriginCreate::get_origin_name_for_reflect,
+                    AccountOriginCreate::mut_origin_name_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Session>(
-                    "Session",
+                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginCreate>(
+                    "AccountOriginCreate",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4887,294 +4594,3663 @@ impl ::protobuf::MessageStatic for Session {
     }
 }
 
-impl ::protobuf::Clear for Session {
+impl ::protobuf::Clear for AccountOriginCreate {
     fn clear(&mut self) {
-        self.clear_id();
-        self.clear_email();
-        self.clear_name();
-        self.clear_token();
-        self.clear_flags();
-        self.clear_oauth_token();
+        self.clear_account_id();
+        self.clear_account_name();
+        self.clear_origin_id();
+        self.clear_origin_name();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Session {
+impl ::std::fmt::Debug for AccountOriginCreate {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Session {
+impl ::protobuf::reflect::ProtobufValue for AccountOriginCreate {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct SessionCreate {
+pub struct AccountOriginListRequest {
     // message fields
-    token: ::protobuf::SingularField<::std::string::String>,
-    extern_id: ::std::option::Option<u32>,
-    email: ::protobuf::SingularField<::std::string::String>,
-    name: ::protobuf::SingularField<::std::string::String>,
-    provider: ::std::option::Option<OAuthProvider>,
-    app_token: ::protobuf::SingularField<::std::string::String>,
+    account_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for SessionCreate {}
+unsafe impl ::std::marker::Sync for AccountOriginListRequest {}
 
-impl SessionCreate {
-    pub fn new() -> SessionCreate {
+impl AccountOriginListRequest {
+    pub fn new() -> AccountOriginListRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static SessionCreate {
-        static mut instance: ::protobuf::lazy::Lazy<SessionCreate> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountOriginListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<AccountOriginListRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SessionCreate,
+            ptr: 0 as *const AccountOriginListRequest,
         };
         unsafe {
-            instance.get(SessionCreate::new)
+            instance.get(AccountOriginListRequest::new)
         }
     }
 
-    // optional string token = 1;
+    // optional uint64 account_id = 1;
 
-    pub fn clear_token(&mut self) {
-        self.token.clear();
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
     }
 
-    pub fn has_token(&self) -> bool {
-        self.token.is_some()
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_token(&mut self, v: ::std::string::String) {
-        self.token = ::protobuf::SingularField::some(v);
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_token(&mut self) -> &mut ::std::string::String {
-        if self.token.is_none() {
-            self.token.set_default();
-        }
-        self.token.as_mut().unwrap()
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
     }
 
-    // Take field
-    pub fn take_token(&mut self) -> ::std::string::String {
-        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
     }
 
-    pub fn get_token(&self) -> &str {
-        match self.token.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
+    }
+}
+
+impl ::protobuf::Message for AccountOriginListRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccountOriginListRequest {
+    fn new() -> AccountOriginListRequest {
+        AccountOriginListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccountOriginListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "account_id",
+                    AccountOriginListRequest::get_account_id_for_reflect,
+                    AccountOriginListRequest::mut_account_id_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginListRequest>(
+                    "AccountOriginListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccountOriginListRequest {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AccountOriginListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccountOriginListRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AccountOriginListResponse {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    origins: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccountOriginListResponse {}
+
+impl AccountOriginListResponse {
+    pub fn new() -> AccountOriginListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccountOriginListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<AccountOriginListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccountOriginListResponse,
+        };
+        unsafe {
+            instance.get(AccountOriginListResponse::new)
+        }
+    }
+
+    // optional uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
+    }
+
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
+    }
+
+    // repeated string origins = 2;
+
+    pub fn clear_origins(&mut self) {
+        self.origins.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origins(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.origins = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_origins(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.origins
+    }
+
+    // Take field
+    pub fn take_origins(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.origins, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_origins(&self) -> &[::std::string::String] {
+        &self.origins
+    }
+
+    fn get_origins_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.origins
+    }
+
+    fn mut_origins_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.origins
+    }
+}
+
+impl ::protobuf::Message for AccountOriginListResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.origins)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.origins {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            os.write_uint64(1, v)?;
+        }
+        for v in &self.origins {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccountOriginListResponse {
+    fn new() -> AccountOriginListResponse {
+        AccountOriginListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccountOriginListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "account_id",
+                    AccountOriginListResponse::get_account_id_for_reflect,
+                    AccountOriginListResponse::mut_account_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origins",
+                    AccountOriginListResponse::get_origins_for_reflect,
+                    AccountOriginListResponse::mut_origins_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginListResponse>(
+                    "AccountOriginListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccountOriginListResponse {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_origins();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AccountOriginListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccountOriginListResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AccountOriginRemove {
+    // message fields
+    account_name: ::protobuf::SingularField<::std::string::String>,
+    origin_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccountOriginRemove {}
+
+impl AccountOriginRemove {
+    pub fn new() -> AccountOriginRemove {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccountOriginRemove {
+        static mut instance: ::protobuf::lazy::Lazy<AccountOriginRemove> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccountOriginRemove,
+        };
+        unsafe {
+            instance.get(AccountOriginRemove::new)
+        }
+    }
+
+    // optional string account_name = 1;
+
+    pub fn clear_account_name(&mut self) {
+        self.account_name.clear();
+    }
+
+    pub fn has_account_name(&self) -> bool {
+        self.account_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if self.account_name.is_none() {
+            self.account_name.set_default();
+        }
+        self.account_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_account_name(&self) -> &str {
+        match self.account_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_account_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.account_name
+    }
+
+    fn mut_account_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.account_name
+    }
+
+    // optional uint64 origin_id = 2;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    fn get_origin_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.origin_id
+    }
+
+    fn mut_origin_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.origin_id
+    }
+}
+
+impl ::protobuf::Message for AccountOriginRemove {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.account_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.origin_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.account_name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.origin_id {
+            os.write_uint64(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccountOriginRemove {
+    fn new() -> AccountOriginRemove {
+        AccountOriginRemove::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccountOriginRemove>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "account_name",
+                    AccountOriginRemove::get_account_name_for_reflect,
+                    AccountOriginRemove::mut_account_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "origin_id",
+                    AccountOriginRemove::get_origin_id_for_reflect,
+                    AccountOriginRemove::mut_origin_id_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginRemove>(
+                    "AccountOriginRemove",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccountOriginRemove {
+    fn clear(&mut self) {
+        self.clear_account_name();
+        self.clear_origin_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AccountOriginRemove {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccountOriginRemove {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Session {
+    // message fields
+    id: ::std::option::Option<u64>,
+    email: ::protobuf::SingularField<::std::string::String>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    token: ::protobuf::SingularField<::std::string::String>,
+    flags: ::std::option::Option<u32>,
+    oauth_token: ::protobuf::SingularField<::std::string::String>,
+    access_token: ::protobuf::SingularPtrField<AccessToken>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Session {}
+
+impl Session {
+    pub fn new() -> Session {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Session {
+        static mut instance: ::protobuf::lazy::Lazy<Session> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Session,
+        };
+        unsafe {
+            instance.get(Session::new)
+        }
+    }
+
+    // optional uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+
+    // optional string email = 2;
+
+    pub fn clear_email(&mut self) {
+        self.email.clear();
+    }
+
+    pub fn has_email(&self) -> bool {
+        self.email.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.email = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        if self.email.is_none() {
+            self.email.set_default();
+        }
+        self.email.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        self.email.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_email(&self) -> &str {
+        match self.email.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_email_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.email
+    }
+
+    fn mut_email_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.email
+    }
+
+    // optional string name = 3;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
+    }
+
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
+    }
+
+    // optional string token = 4;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        if self.token.is_none() {
+            self.token.set_default();
+        }
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        match self.token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_token_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.token
+    }
+
+    fn mut_token_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.token
+    }
+
+    // optional uint32 flags = 5;
+
+    pub fn clear_flags(&mut self) {
+        self.flags = ::std::option::Option::None;
+    }
+
+    pub fn has_flags(&self) -> bool {
+        self.flags.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_flags(&mut self, v: u32) {
+        self.flags = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_flags(&self) -> u32 {
+        self.flags.unwrap_or(0)
+    }
+
+    fn get_flags_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.flags
+    }
+
+    fn mut_flags_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.flags
+    }
+
+    // optional string oauth_token = 6;
+
+    pub fn clear_oauth_token(&mut self) {
+        self.oauth_token.clear();
+    }
+
+    pub fn has_oauth_token(&self) -> bool {
+        self.oauth_token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_oauth_token(&mut self, v: ::std::string::String) {
+        self.oauth_token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_oauth_token(&mut self) -> &mut ::std::string::String {
+        if self.oauth_token.is_none() {
+            self.oauth_token.set_default();
+        }
+        self.oauth_token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_oauth_token(&mut self) -> ::std::string::String {
+        self.oauth_token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_oauth_token(&self) -> &str {
+        match self.oauth_token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_oauth_token_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.oauth_token
+    }
+
+    fn mut_oauth_token_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.oauth_token
+    }
+
+    // optional .sessionsrv.AccessToken access_token = 7;
+
+    pub fn clear_access_token(&mut self) {
+        self.access_token.clear();
+    }
+
+    pub fn has_access_token(&self) -> bool {
+        self.access_token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_access_token(&mut self, v: AccessToken) {
+        self.access_token = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_access_token(&mut self) -> &mut AccessToken {
+        if self.access_token.is_none() {
+            self.access_token.set_default();
+        }
+        self.access_token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_access_token(&mut self) -> AccessToken {
+        self.access_token.take().unwrap_or_else(|| AccessToken::new())
+    }
+
+    pub fn get_access_token(&self) -> &AccessToken {
+        self.access_token.as_ref().unwrap_or_else(|| AccessToken::default_instance())
+    }
+
+    fn get_access_token_for_reflect(&self) -> &::protobuf::SingularPtrField<AccessToken> {
+        &self.access_token
+    }
+
+    fn mut_access_token_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<AccessToken> {
+        &mut self.access_token
+    }
+}
+
+impl ::protobuf::Message for Session {
+    fn is_initialized(&self) -> bool {
+        for v in &self.access_token {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.email)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.flags = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.oauth_token)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.access_token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.email.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(ref v) = self.token.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(v) = self.flags {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.oauth_token.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
+        if let Some(ref v) = self.access_token.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.email.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(ref v) = self.token.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        if let Some(v) = self.flags {
+            os.write_uint32(5, v)?;
+        }
+        if let Some(ref v) = self.oauth_token.as_ref() {
+            os.write_string(6, &v)?;
+        }
+        if let Some(ref v) = self.access_token.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Session {
+    fn new() -> Session {
+        Session::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Session>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    Session::get_id_for_reflect,
+                    Session::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "email",
+                    Session::get_email_for_reflect,
+                    Session::mut_email_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    Session::get_name_for_reflect,
+                    Session::mut_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "token",
+                    Session::get_token_for_reflect,
+                    Session::mut_token_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "flags",
+                    Session::get_flags_for_reflect,
+                    Session::mut_flags_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "oauth_token",
+                    Session::get_oauth_token_for_reflect,
+                    Session::mut_oauth_token_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AccessToken>>(
+                    "access_token",
+                    Session::get_access_token_for_reflect,
+                    Session::mut_access_token_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Session>(
+                    "Session",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Session {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_email();
+        self.clear_name();
+        self.clear_token();
+        self.clear_flags();
+        self.clear_oauth_token();
+        self.clear_access_token();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Session {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Session {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SessionCreate {
+    // message fields
+    token: ::protobuf::SingularField<::std::string::String>,
+    extern_id: ::std::option::Option<u32>,
+    email: ::protobuf::SingularField<::std::string::String>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    provider: ::std::option::Option<OAuthProvider>,
+    app_token: ::protobuf::SingularField<::std::string::String>,
+    subject: ::protobuf::SingularField<::std::string::String>,
+    email_verified: ::std::option::Option<bool>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SessionCreate {}
+
+impl SessionCreate {
+    pub fn new() -> SessionCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SessionCreate {
+        static mut instance: ::protobuf::lazy::Lazy<SessionCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SessionCreate,
+        };
+        unsafe {
+            instance.get(SessionCreate::new)
+        }
+    }
+
+    // optional string token = 1;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        if self.token.is_none() {
+            self.token.set_default();
+        }
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        match self.token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_token_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.token
+    }
+
+    fn mut_token_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.token
+    }
+
+    // optional uint32 extern_id = 2;
+
+    pub fn clear_extern_id(&mut self) {
+        self.extern_id = ::std::option::Option::None;
+    }
+
+    pub fn has_extern_id(&self) -> bool {
+        self.extern_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_extern_id(&mut self, v: u32) {
+        self.extern_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_extern_id(&self) -> u32 {
+        self.extern_id.unwrap_or(0)
+    }
+
+    fn get_extern_id_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.extern_id
+    }
+
+    fn mut_extern_id_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.extern_id
+    }
+
+    // optional string email = 3;
+
+    pub fn clear_email(&mut self) {
+        self.email.clear();
+    }
+
+    pub fn has_email(&self) -> bool {
+        self.email.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.email = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        if self.email.is_none() {
+            self.email.set_default();
+        }
+        self.email.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        self.email.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_email(&self) -> &str {
+        match self.email.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_email_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.email
+    }
+
+    fn mut_email_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.email
+    }
+
+    // optional string name = 4;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
+    }
+
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
+    }
+
+    // optional .sessionsrv.OAuthProvider provider = 5;
+
+    pub fn clear_provider(&mut self) {
+        self.provider = ::std::option::Option::None;
+    }
+
+    pub fn has_provider(&self) -> bool {
+        self.provider.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_provider(&mut self, v: OAuthProvider) {
+        self.provider = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_provider(&self) -> OAuthProvider {
+        self.provider.unwrap_or(OAuthProvider::GitHub)
+    }
+
+    fn get_provider_for_reflect(&self) -> &::std::option::Option<OAuthProvider> {
+        &self.provider
+    }
+
+    fn mut_provider_for_reflect(&mut self) -> &mut ::std::option::Option<OAuthProvider> {
+        &mut self.provider
+    }
+
+    // optional string app_token = 6;
+
+    pub fn clear_app_token(&mut self) {
+        self.app_token.clear();
+    }
+
+    pub fn has_app_token(&self) -> bool {
+        self.app_token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_app_token(&mut self, v: ::std::string::String) {
+        self.app_token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_app_token(&mut self) -> &mut ::std::string::String {
+        if self.app_token.is_none() {
+            self.app_token.set_default();
+        }
+        self.app_token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_app_token(&mut self) -> ::std::string::String {
+        self.app_token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_app_token(&self) -> &str {
+        match self.app_token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_app_token_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.app_token
+    }
+
+    fn mut_app_token_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.app_token
+    }
+
+    // optional string subject = 7;
+
+    pub fn clear_subject(&mut self) {
+        self.subject.clear();
+    }
+
+    pub fn has_subject(&self) -> bool {
+        self.subject.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subject(&mut self, v: ::std::string::String) {
+        self.subject = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_subject(&mut self) -> &mut ::std::string::String {
+        if self.subject.is_none() {
+            self.subject.set_default();
+        }
+        self.subject.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_subject(&mut self) -> ::std::string::String {
+        self.subject.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_subject(&self) -> &str {
+        match self.subject.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_subject_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.subject
+    }
+
+    fn mut_subject_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.subject
+    }
+
+    // optional bool email_verified = 8;
+
+    pub fn clear_email_verified(&mut self) {
+        self.email_verified = ::std::option::Option::None;
+    }
+
+    pub fn has_email_verified(&self) -> bool {
+        self.email_verified.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email_verified(&mut self, v: bool) {
+        self.email_verified = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_email_verified(&self) -> bool {
+        self.email_verified.unwrap_or(false)
+    }
+
+    fn get_email_verified_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.email_verified
+    }
+
+    fn mut_email_verified_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.email_verified
+    }
+}
+
+impl ::protobuf::Message for SessionCreate {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.extern_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.email)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.provider = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.app_token)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.subject)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.email_verified = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.token.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.extern_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.email.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(v) = self.provider {
+            my_size += ::protobuf::rt::enum_size(5, v);
+        }
+        if let Some(ref v) = self.app_token.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
+        if let Some(ref v) = self.subject.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        }
+        if let Some(v) = self.email_verified {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.token.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.extern_id {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(ref v) = self.email.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        if let Some(v) = self.provider {
+            os.write_enum(5, v.value())?;
+        }
+        if let Some(ref v) = self.app_token.as_ref() {
+            os.write_string(6, &v)?;
+        }
+        if let Some(ref v) = self.subject.as_ref() {
+            os.write_string(7, &v)?;
+        }
+        if let Some(v) = self.email_verified {
+            os.write_bool(8, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SessionCreate {
+    fn new() -> SessionCreate {
+        SessionCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SessionCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "token",
+                    SessionCreate::get_token_for_reflect,
+                    SessionCreate::mut_token_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "extern_id",
+                    SessionCreate::get_extern_id_for_reflect,
+                    SessionCreate::mut_extern_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "email",
+                    SessionCreate::get_email_for_reflect,
+                    SessionCreate::mut_email_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    SessionCreate::get_name_for_reflect,
+                    SessionCreate::mut_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<OAuthProvider>>(
+                    "provider",
+                    SessionCreate::get_provider_for_reflect,
+                    SessionCreate::mut_provider_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "app_token",
+                    SessionCreate::get_app_token_for_reflect,
+                    SessionCreate::mut_app_token_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "subject",
+                    SessionCreate::get_subject_for_reflect,
+                    SessionCreate::mut_subject_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "email_verified",
+                    SessionCreate::get_email_verified_for_reflect,
+                    SessionCreate::mut_email_verified_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SessionCreate>(
+                    "SessionCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SessionCreate {
+    fn clear(&mut self) {
+        self.clear_token();
+        self.clear_extern_id();
+        self.clear_email();
+        self.clear_name();
+        self.clear_provider();
+        self.clear_app_token();
+        self.clear_subject();
+        self.clear_email_verified();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SessionCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SessionCreate {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SessionGet {
+    // message fields
+    token: ::protobuf::SingularPtrField<SessionToken>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SessionGet {}
+
+impl SessionGet {
+    pub fn new() -> SessionGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SessionGet {
+        static mut instance: ::protobuf::lazy::Lazy<SessionGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SessionGet,
+        };
+        unsafe {
+            instance.get(SessionGet::new)
+        }
+    }
+
+    // optional .sessionsrv.SessionToken token = 1;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: SessionToken) {
+        self.token = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut SessionToken {
+        if self.token.is_none() {
+            self.token.set_default();
+        }
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> SessionToken {
+        self.token.take().unwrap_or_else(|| SessionToken::new())
+    }
+
+    pub fn get_token(&self) -> &SessionToken {
+        self.token.as_ref().unwrap_or_else(|| SessionToken::default_instance())
+    }
+
+    fn get_token_for_reflect(&self) -> &::protobuf::SingularPtrField<SessionToken> {
+        &self.token
+    }
+
+    fn mut_token_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<SessionToken> {
+        &mut self.token
+    }
+}
+
+impl ::protobuf::Message for SessionGet {
+    fn is_initialized(&self) -> bool {
+        for v in &self.token {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.token.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.token.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SessionGet {
+    fn new() -> SessionGet {
+        SessionGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SessionGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SessionToken>>(
+                    "token",
+                    SessionGet::get_token_for_reflect,
+                    SessionGet::mut_token_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SessionGet>(
+                    "SessionGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SessionGet {
+    fn clear(&mut self) {
+        self.clear_token();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SessionGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SessionGet {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SessionToken {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    extern_id: ::std::option::Option<u32>,
+    provider: ::std::option::Option<OAuthProvider>,
+    token: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SessionToken {}
+
+impl SessionToken {
+    pub fn new() -> SessionToken {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SessionToken {
+        static mut instance: ::protobuf::lazy::Lazy<SessionToken> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SessionToken,
+        };
+        unsafe {
+            instance.get(SessionToken::new)
+        }
+    }
+
+    // optional uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
+    }
+
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
+    }
+
+    // optional uint32 extern_id = 2;
+
+    pub fn clear_extern_id(&mut self) {
+        self.extern_id = ::std::option::Option::None;
+    }
+
+    pub fn has_extern_id(&self) -> bool {
+        self.extern_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_extern_id(&mut self, v: u32) {
+        self.extern_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_extern_id(&self) -> u32 {
+        self.extern_id.unwrap_or(0)
+    }
+
+    fn get_extern_id_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.extern_id
+    }
+
+    fn mut_extern_id_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.extern_id
+    }
+
+    // optional .sessionsrv.OAuthProvider provider = 3;
+
+    pub fn clear_provider(&mut self) {
+        self.provider = ::std::option::Option::None;
+    }
+
+    pub fn has_provider(&self) -> bool {
+        self.provider.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_provider(&mut self, v: OAuthProvider) {
+        self.provider = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_provider(&self) -> OAuthProvider {
+        self.provider.unwrap_or(OAuthProvider::GitHub)
+    }
+
+    fn get_provider_for_reflect(&self) -> &::std::option::Option<OAuthProvider> {
+        &self.provider
+    }
+
+    fn mut_provider_for_reflect(&mut self) -> &mut ::std::option::Option<OAuthProvider> {
+        &mut self.provider
+    }
+
+    // optional bytes token = 4;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::vec::Vec<u8>) {
+        self.token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.token.is_none() {
+            self.token.set_default();
+        }
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::vec::Vec<u8> {
+        self.token.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_token(&self) -> &[u8] {
+        match self.token.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    fn get_token_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.token
+    }
+
+    fn mut_token_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.token
+    }
+}
+
+impl ::protobuf::Message for SessionToken {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.extern_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.provider = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.extern_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.provider {
+            my_size += ::protobuf::rt::enum_size(3, v);
+        }
+        if let Some(ref v) = self.token.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(4, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.extern_id {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(v) = self.provider {
+            os.write_enum(3, v.value())?;
+        }
+        if let Some(ref v) = self.token.as_ref() {
+            os.write_bytes(4, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SessionToken {
+    fn new() -> SessionToken {
+        SessionToken::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SessionToken>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "account_id",
+                    SessionToken::get_account_id_for_reflect,
+                    SessionToken::mut_account_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "extern_id",
+                    SessionToken::get_extern_id_for_reflect,
+                    SessionToken::mut_extern_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<OAuthProvider>>(
+                    "provider",
+                    SessionToken::get_provider_for_reflect,
+                    SessionToken::mut_provider_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "token",
+                    SessionToken::get_token_for_reflect,
+                    SessionToken::mut_token_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SessionToken>(
+                    "SessionToken",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SessionToken {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_extern_id();
+        self.clear_provider();
+        self.clear_token();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SessionToken {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SessionToken {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AccessToken {
+    // message fields
+    id: ::std::option::Option<u64>,
+    account_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    origins: ::protobuf::RepeatedField<::std::string::String>,
+    scopes: ::std::vec::Vec<AccessTokenScope>,
+    expires_at: ::std::option::Option<u64>,
+    created_at: ::std::option::Option<u64>,
+    token: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessToken {}
+
+impl AccessToken {
+    pub fn new() -> AccessToken {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccessToken {
+        static mut instance: ::protobuf::lazy::Lazy<AccessToken> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessToken,
+        };
+        unsafe {
+            instance.get(AccessToken::new)
+        }
+    }
+
+    // optional uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+
+    // optional uint64 account_id = 2;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
+    }
+
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
+    }
+
+    // optional string name = 3;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
+    }
+
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
+    }
+
+    // repeated string origins = 4;
+
+    pub fn clear_origins(&mut self) {
+        self.origins.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origins(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.origins = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_origins(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.origins
+    }
+
+    // Take field
+    pub fn take_origins(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.origins, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_origins(&self) -> &[::std::string::String] {
+        &self.origins
+    }
+
+    fn get_origins_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.origins
+    }
+
+    fn mut_origins_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.origins
+    }
+
+    // repeated .sessionsrv.AccessTokenScope scopes = 5;
+
+    pub fn clear_scopes(&mut self) {
+        self.scopes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scopes(&mut self, v: ::std::vec::Vec<AccessTokenScope>) {
+        self.scopes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scopes(&mut self) -> &mut ::std::vec::Vec<AccessTokenScope> {
+        &mut self.scopes
+    }
+
+    // Take field
+    pub fn take_scopes(&mut self) -> ::std::vec::Vec<AccessTokenScope> {
+        ::std::mem::replace(&mut self.scopes, ::std::vec::Vec::new())
+    }
+
+    pub fn get_scopes(&self) -> &[AccessTokenScope] {
+        &self.scopes
+    }
+
+    fn get_scopes_for_reflect(&self) -> &::std::vec::Vec<AccessTokenScope> {
+        &self.scopes
+    }
+
+    fn mut_scopes_for_reflect(&mut self) -> &mut ::std::vec::Vec<AccessTokenScope> {
+        &mut self.scopes
+    }
+
+    // optional uint64 expires_at = 6;
+
+    pub fn clear_expires_at(&mut self) {
+        self.expires_at = ::std::option::Option::None;
+    }
+
+    pub fn has_expires_at(&self) -> bool {
+        self.expires_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expires_at(&mut self, v: u64) {
+        self.expires_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_expires_at(&self) -> u64 {
+        self.expires_at.unwrap_or(0)
+    }
+
+    fn get_expires_at_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.expires_at
+    }
+
+    fn mut_expires_at_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.expires_at
+    }
+
+    // optional uint64 created_at = 7;
+
+    pub fn clear_created_at(&mut self) {
+        self.created_at = ::std::option::Option::None;
+    }
+
+    pub fn has_created_at(&self) -> bool {
+        self.created_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_at(&mut self, v: u64) {
+        self.created_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_created_at(&self) -> u64 {
+        self.created_at.unwrap_or(0)
+    }
+
+    fn get_created_at_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.created_at
+    }
+
+    fn mut_created_at_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.created_at
+    }
+
+    // optional string token = 8;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        if self.token.is_none() {
+            self.token.set_default();
+        }
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        match self.token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_token_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.token
+    }
+
+    fn mut_token_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.token
+    }
+}
+
+impl ::protobuf::Message for AccessToken {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.origins)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_enum_into(wire_type, is, &mut self.scopes)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.expires_at = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.created_at = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        for value in &self.origins {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in &self.scopes {
+            my_size += ::protobuf::rt::enum_size(5, *value);
+        };
+        if let Some(v) = self.expires_at {
+            my_size += ::protobuf::rt::value_size(6, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.created_at {
+            my_size += ::protobuf::rt::value_size(7, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.token.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.account_id {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        for v in &self.origins {
+            os.write_string(4, &v)?;
+        };
+        for v in &self.scopes {
+            os.write_enum(5, v.value())?;
+        };
+        if let Some(v) = self.expires_at {
+            os.write_uint64(6, v)?;
+        }
+        if let Some(v) = self.created_at {
+            os.write_uint64(7, v)?;
+        }
+        if let Some(ref v) = self.token.as_ref() {
+            os.write_string(8, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccessToken {
+    fn new() -> AccessToken {
+        AccessToken::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccessToken>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    AccessToken::get_id_for_reflect,
+                    AccessToken::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "account_id",
+                    AccessToken::get_account_id_for_reflect,
+                    AccessToken::mut_account_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    AccessToken::get_name_for_reflect,
+                    AccessToken::mut_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origins",
+                    AccessToken::get_origins_for_reflect,
+                    AccessToken::mut_origins_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeEnum<AccessTokenScope>>(
+                    "scopes",
+                    AccessToken::get_scopes_for_reflect,
+                    AccessToken::mut_scopes_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "expires_at",
+                    AccessToken::get_expires_at_for_reflect,
+                    AccessToken::mut_expires_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "created_at",
+                    AccessToken::get_created_at_for_reflect,
+                    AccessToken::mut_created_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "token",
+                    AccessToken::get_token_for_reflect,
+                    AccessToken::mut_token_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessToken>(
+                    "AccessToken",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccessToken {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_account_id();
+        self.clear_name();
+        self.clear_origins();
+        self.clear_scopes();
+        self.clear_expires_at();
+        self.clear_created_at();
+        self.clear_token();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccessToken {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AccessTokenCreate {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    origins: ::protobuf::RepeatedField<::std::string::String>,
+    scopes: ::std::vec::Vec<AccessTokenScope>,
+    expires_at: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessTokenCreate {}
+
+impl AccessTokenCreate {
+    pub fn new() -> AccessTokenCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccessTokenCreate {
+        static mut instance: ::protobuf::lazy::Lazy<AccessTokenCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessTokenCreate,
+        };
+        unsafe {
+            instance.get(AccessTokenCreate::new)
+        }
+    }
+
+    // optional uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
+    }
+
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
+    }
+
+    // optional string name = 2;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
+    }
+
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
+    }
+
+    // repeated string origins = 3;
+
+    pub fn clear_origins(&mut self) {
+        self.origins.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origins(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.origins = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_origins(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.origins
+    }
+
+    // Take field
+    pub fn take_origins(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.origins, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_origins(&self) -> &[::std::string::String] {
+        &self.origins
+    }
+
+    fn get_origins_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.origins
+    }
+
+    fn mut_origins_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.origins
+    }
+
+    // repeated .sessionsrv.AccessTokenScope scopes = 4;
+
+    pub fn clear_scopes(&mut self) {
+        self.scopes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scopes(&mut self, v: ::std::vec::Vec<AccessTokenScope>) {
+        self.scopes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scopes(&mut self) -> &mut ::std::vec::Vec<AccessTokenScope> {
+        &mut self.scopes
+    }
+
+    // Take field
+    pub fn take_scopes(&mut self) -> ::std::vec::Vec<AccessTokenScope> {
+        ::std::mem::replace(&mut self.scopes, ::std::vec::Vec::new())
+    }
+
+    pub fn get_scopes(&self) -> &[AccessTokenScope] {
+        &self.scopes
+    }
+
+    fn get_scopes_for_reflect(&self) -> &::std::vec::Vec<AccessTokenScope> {
+        &self.scopes
+    }
+
+    fn mut_scopes_for_reflect(&mut self) -> &mut ::std::vec::Vec<AccessTokenScope> {
+        &mut self.scopes
+    }
+
+    // optional uint64 expires_at = 5;
+
+    pub fn clear_expires_at(&mut self) {
+        self.expires_at = ::std::option::Option::None;
+    }
+
+    pub fn has_expires_at(&self) -> bool {
+        self.expires_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expires_at(&mut self, v: u64) {
+        self.expires_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_expires_at(&self) -> u64 {
+        self.expires_at.unwrap_or(0)
+    }
+
+    fn get_expires_at_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.expires_at
+    }
+
+    fn mut_expires_at_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.expires_at
+    }
+}
+
+impl ::protobuf::Message for AccessTokenCreate {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.origins)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_enum_into(wire_type, is, &mut self.scopes)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.expires_at = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        for value in &self.origins {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in &self.scopes {
+            my_size += ::protobuf::rt::enum_size(4, *value);
+        };
+        if let Some(v) = self.expires_at {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
 
-    fn get_token_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.token
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        for v in &self.origins {
+            os.write_string(3, &v)?;
+        };
+        for v in &self.scopes {
+            os.write_enum(4, v.value())?;
+        };
+        if let Some(v) = self.expires_at {
+            os.write_uint64(5, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccessTokenCreate {
+    fn new() -> AccessTokenCreate {
+        AccessTokenCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccessTokenCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "account_id",
+                    AccessTokenCreate::get_account_id_for_reflect,
+                    AccessTokenCreate::mut_account_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    AccessTokenCreate::get_name_for_reflect,
+                    AccessTokenCreate::mut_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origins",
+                    AccessTokenCreate::get_origins_for_reflect,
+                    AccessTokenCreate::mut_origins_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeEnum<AccessTokenScope>>(
+                    "scopes",
+                    AccessTokenCreate::get_scopes_for_reflect,
+                    AccessTokenCreate::mut_scopes_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "expires_at",
+                    AccessTokenCreate::get_expires_at_for_reflect,
+                    AccessTokenCreate::mut_expires_at_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessTokenCreate>(
+                    "AccessTokenCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccessTokenCreate {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_name();
+        self.clear_origins();
+        self.clear_scopes();
+        self.clear_expires_at();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AccessTokenCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccessTokenCreate {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AccessTokenListRequest {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessTokenListRequest {}
+
+impl AccessTokenListRequest {
+    pub fn new() -> AccessTokenListRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccessTokenListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<AccessTokenListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessTokenListRequest,
+        };
+        unsafe {
+            instance.get(AccessTokenListRequest::new)
+        }
+    }
+
+    // optional uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
+    }
+
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
+    }
+}
+
+impl ::protobuf::Message for AccessTokenListRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccessTokenListRequest {
+    fn new() -> AccessTokenListRequest {
+        AccessTokenListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccessTokenListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "account_id",
+                    AccessTokenListRequest::get_account_id_for_reflect,
+                    AccessTokenListRequest::mut_account_id_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessTokenListRequest>(
+                    "AccessTokenListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccessTokenListRequest {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AccessTokenListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccessTokenListRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AccessTokenListResponse {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    tokens: ::protobuf::RepeatedField<AccessToken>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessTokenListResponse {}
+
+impl AccessTokenListResponse {
+    pub fn new() -> AccessTokenListResponse {
+        ::std::default::Default::default()
     }
 
-    fn mut_token_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.token
+    pub fn default_instance() -> &'static AccessTokenListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<AccessTokenListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessTokenListResponse,
+        };
+        unsafe {
+            instance.get(AccessTokenListResponse::new)
+        }
     }
 
-    // optional uint32 extern_id = 2;
+    // optional uint64 account_id = 1;
 
-    pub fn clear_extern_id(&mut self) {
-        self.extern_id = ::std::option::Option::None;
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
     }
 
-    pub fn has_extern_id(&self) -> bool {
-        self.extern_id.is_some()
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_extern_id(&mut self, v: u32) {
-        self.extern_id = ::std::option::Option::Some(v);
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_extern_id(&self) -> u32 {
-        self.extern_id.unwrap_or(0)
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
     }
 
-    fn get_extern_id_for_reflect(&self) -> &::std::option::Option<u32> {
-        &self.extern_id
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
     }
 
-    fn mut_extern_id_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
-        &mut self.extern_id
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
     }
 
-    // optional string email = 3;
-
-    pub fn clear_email(&mut self) {
-        self.email.clear();
-    }
+    // repeated .sessionsrv.AccessToken tokens = 2;
 
-    pub fn has_email(&self) -> bool {
-        self.email.is_some()
+    pub fn clear_tokens(&mut self) {
+        self.tokens.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_email(&mut self, v: ::std::string::String) {
-        self.email = ::protobuf::SingularField::some(v);
+    pub fn set_tokens(&mut self, v: ::protobuf::RepeatedField<AccessToken>) {
+        self.tokens = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_email(&mut self) -> &mut ::std::string::String {
-        if self.email.is_none() {
-            self.email.set_default();
-        }
-        self.email.as_mut().unwrap()
+    pub fn mut_tokens(&mut self) -> &mut ::protobuf::RepeatedField<AccessToken> {
+        &mut self.tokens
     }
 
     // Take field
-    pub fn take_email(&mut self) -> ::std::string::String {
-        self.email.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_tokens(&mut self) -> ::protobuf::RepeatedField<AccessToken> {
+        ::std::mem::replace(&mut self.tokens, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_email(&self) -> &str {
-        match self.email.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_tokens(&self) -> &[AccessToken] {
+        &self.tokens
     }
 
-    fn get_email_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.email
+    fn get_tokens_for_reflect(&self) -> &::protobuf::RepeatedField<AccessToken> {
+        &self.tokens
     }
 
-    fn mut_email_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.email
+    fn mut_tokens_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<AccessToken> {
+        &mut self.tokens
     }
+}
 
-    // optional string name = 4;
-
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+impl ::protobuf::Message for AccessTokenListResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.tokens {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
     }
 
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.tokens)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.tokens {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            os.write_uint64(1, v)?;
         }
-        self.name.as_mut().unwrap()
+        for v in &self.tokens {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
     }
 
-    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.name
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
 
-    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.name
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
     }
 
-    // optional .sessionsrv.OAuthProvider provider = 5;
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
 
-    pub fn clear_provider(&mut self) {
-        self.provider = ::std::option::Option::None;
+impl ::protobuf::MessageStatic for AccessTokenListResponse {
+    fn new() -> AccessTokenListResponse {
+        AccessTokenListResponse::new()
     }
 
-    pub fn has_provider(&self) -> bool {
-        self.provider.is_some()
+    fn descriptor_static(_: ::std::option::Option<AccessTokenListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "account_id",
+                    AccessTokenListResponse::get_account_id_for_reflect,
+                    AccessTokenListResponse::mut_account_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AccessToken>>(
+                    "tokens",
+                    AccessTokenListResponse::get_tokens_for_reflect,
+                    AccessTokenListResponse::mut_tokens_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessTokenListResponse>(
+                    "AccessTokenListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_provider(&mut self, v: OAuthProvider) {
-        self.provider = ::std::option::Option::Some(v);
+impl ::protobuf::Clear for AccessTokenListResponse {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_tokens();
+        self.unknown_fields.clear();
     }
+}
 
-    pub fn get_provider(&self) -> OAuthProvider {
-        self.provider.unwrap_or(OAuthProvider::GitHub)
+impl ::std::fmt::Debug for AccessTokenListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    fn get_provider_for_reflect(&self) -> &::std::option::Option<OAuthProvider> {
-        &self.provider
+impl ::protobuf::reflect::ProtobufValue for AccessTokenListResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AccessTokenRevoke {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessTokenRevoke {}
+
+impl AccessTokenRevoke {
+    pub fn new() -> AccessTokenRevoke {
+        ::std::default::Default::default()
     }
 
-    fn mut_provider_for_reflect(&mut self) -> &mut ::std::option::Option<OAuthProvider> {
-        &mut self.provider
+    pub fn default_instance() -> &'static AccessTokenRevoke {
+        static mut instance: ::protobuf::lazy::Lazy<AccessTokenRevoke> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessTokenRevoke,
+        };
+        unsafe {
+            instance.get(AccessTokenRevoke::new)
+        }
     }
 
-    // optional string app_token = 6;
+    // optional uint64 account_id = 1;
 
-    pub fn clear_app_token(&mut self) {
-        self.app_token.clear();
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
     }
 
-    pub fn has_app_token(&self) -> bool {
-        self.app_token.is_some()
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_app_token(&mut self, v: ::std::string::String) {
-        self.app_token = ::protobuf::SingularField::some(v);
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_app_token(&mut self) -> &mut ::std::string::String {
-        if self.app_token.is_none() {
-            self.app_token.set_default();
-        }
-        self.app_token.as_mut().unwrap()
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
     }
 
-    // Take field
-    pub fn take_app_token(&mut self) -> ::std::string::String {
-        self.app_token.take().unwrap_or_else(|| ::std::string::String::new())
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
+    }
+
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
+    }
+
+    // optional uint64 id = 2;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_app_token(&self) -> &str {
-        match self.app_token.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
     }
 
-    fn get_app_token_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.app_token
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
     }
 
-    fn mut_app_token_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.app_token
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
     }
 }
 
-impl ::protobuf::Message for SessionCreate {
+impl ::protobuf::Message for AccessTokenRevoke {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -5184,30 +8260,18 @@ impl ::protobuf::Message for SessionCreate {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token)?;
-                },
-                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_uint32()?;
-                    self.extern_id = ::std::option::Option::Some(tmp);
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.email)?;
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
                 },
-                5 => {
+                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_enum()?;
-                    self.provider = ::std::option::Option::Some(tmp);
-                },
-                6 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.app_token)?;
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -5221,47 +8285,23 @@ impl ::protobuf::Message for SessionCreate {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.token.as_ref() {
-            my_size += ::protobuf::rt::string_size(1, &v);
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(v) = self.extern_id {
+        if let Some(v) = self.id {
             my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(ref v) = self.email.as_ref() {
-            my_size += ::protobuf::rt::string_size(3, &v);
-        }
-        if let Some(ref v) = self.name.as_ref() {
-            my_size += ::protobuf::rt::string_size(4, &v);
-        }
-        if let Some(v) = self.provider {
-            my_size += ::protobuf::rt::enum_size(5, v);
-        }
-        if let Some(ref v) = self.app_token.as_ref() {
-            my_size += ::protobuf::rt::string_size(6, &v);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.token.as_ref() {
-            os.write_string(1, &v)?;
-        }
-        if let Some(v) = self.extern_id {
-            os.write_uint32(2, v)?;
-        }
-        if let Some(ref v) = self.email.as_ref() {
-            os.write_string(3, &v)?;
-        }
-        if let Some(ref v) = self.name.as_ref() {
-            os.write_string(4, &v)?;
-        }
-        if let Some(v) = self.provider {
-            os.write_enum(5, v.value())?;
+        if let Some(v) = self.account_id {
+            os.write_uint64(1, v)?;
         }
-        if let Some(ref v) = self.app_token.as_ref() {
-            os.write_string(6, &v)?;
+        if let Some(v) = self.id {
+            os.write_uint64(2, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -5294,12 +8334,12 @@ impl ::protobuf::Message for SessionCreate {
     }
 }
 
-impl ::protobuf::MessageStatic for SessionCreate {
-    fn new() -> SessionCreate {
-        SessionCreate::new()
+impl ::protobuf::MessageStatic for AccessTokenRevoke {
+    fn new() -> AccessTokenRevoke {
+        AccessTokenRevoke::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<SessionCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccessTokenRevoke>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -5307,38 +8347,18 @@ impl ::protobuf::MessageStatic for SessionCreate {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "token",
-                    SessionCreate::get_token_for_reflect,
-                    SessionCreate::mut_token_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "extern_id",
-                    SessionCreate::get_extern_id_for_reflect,
-                    SessionCreate::mut_extern_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "email",
-                    SessionCreate::get_email_for_reflect,
-                    SessionCreate::mut_email_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    SessionCreate::get_name_for_reflect,
-                    SessionCreate::mut_name_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<OAuthProvider>>(
-                    "provider",
-                    SessionCreate::get_provider_for_reflect,
-                    SessionCreate::mut_provider_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "account_id",
+                    AccessTokenRevoke::get_account_id_for_reflect,
+                    AccessTokenRevoke::mut_account_id_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "app_token",
-                    SessionCreate::get_app_token_for_reflect,
-                    SessionCreate::mut_app_token_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    AccessTokenRevoke::get_id_for_reflect,
+                    AccessTokenRevoke::mut_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SessionCreate>(
-                    "SessionCreate",
+                ::protobuf::reflect::MessageDescriptor::new::<AccessTokenRevoke>(
+                    "AccessTokenRevoke",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5347,106 +8367,156 @@ impl ::protobuf::MessageStatic for SessionCreate {
     }
 }
 
-impl ::protobuf::Clear for SessionCreate {
+impl ::protobuf::Clear for AccessTokenRevoke {
     fn clear(&mut self) {
-        self.clear_token();
-        self.clear_extern_id();
-        self.clear_email();
-        self.clear_name();
-        self.clear_provider();
-        self.clear_app_token();
+        self.clear_account_id();
+        self.clear_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for SessionCreate {
+impl ::std::fmt::Debug for AccessTokenRevoke {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SessionCreate {
+impl ::protobuf::reflect::ProtobufValue for AccessTokenRevoke {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct SessionGet {
+pub struct AccessTokenKey {
     // message fields
-    token: ::protobuf::SingularPtrField<SessionToken>,
+    account_id: ::std::option::Option<u64>,
+    id: ::std::option::Option<u64>,
+    secret: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for SessionGet {}
+unsafe impl ::std::marker::Sync for AccessTokenKey {}
 
-impl SessionGet {
-    pub fn new() -> SessionGet {
+impl AccessTokenKey {
+    pub fn new() -> AccessTokenKey {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static SessionGet {
-        static mut instance: ::protobuf::lazy::Lazy<SessionGet> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccessTokenKey {
+        static mut instance: ::protobuf::lazy::Lazy<AccessTokenKey> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SessionGet,
+            ptr: 0 as *const AccessTokenKey,
         };
         unsafe {
-            instance.get(SessionGet::new)
+            instance.get(AccessTokenKey::new)
         }
     }
 
-    // optional .sessionsrv.SessionToken token = 1;
+    // optional uint64 account_id = 1;
 
-    pub fn clear_token(&mut self) {
-        self.token.clear();
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
     }
 
-    pub fn has_token(&self) -> bool {
-        self.token.is_some()
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_token(&mut self, v: SessionToken) {
-        self.token = ::protobuf::SingularPtrField::some(v);
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
+    }
+
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
+    }
+
+    // optional uint64 id = 2;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+
+    // optional bytes secret = 3;
+
+    pub fn clear_secret(&mut self) {
+        self.secret.clear();
+    }
+
+    pub fn has_secret(&self) -> bool {
+        self.secret.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secret(&mut self, v: ::std::vec::Vec<u8>) {
+        self.secret = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_token(&mut self) -> &mut SessionToken {
-        if self.token.is_none() {
-            self.token.set_default();
+    pub fn mut_secret(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.secret.is_none() {
+            self.secret.set_default();
         }
-        self.token.as_mut().unwrap()
+        self.secret.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_token(&mut self) -> SessionToken {
-        self.token.take().unwrap_or_else(|| SessionToken::new())
+    pub fn take_secret(&mut self) -> ::std::vec::Vec<u8> {
+        self.secret.take().unwrap_or_else(|| ::std::vec::Vec::new())
     }
 
-    pub fn get_token(&self) -> &SessionToken {
-        self.token.as_ref().unwrap_or_else(|| SessionToken::default_instance())
+    pub fn get_secret(&self) -> &[u8] {
+        match self.secret.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
     }
 
-    fn get_token_for_reflect(&self) -> &::protobuf::SingularPtrField<SessionToken> {
-        &self.token
+    fn get_secret_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &self.secret
     }
 
-    fn mut_token_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<SessionToken> {
-        &mut self.token
+    fn mut_secret_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
+        &mut self.secret
     }
 }
 
-impl ::protobuf::Message for SessionGet {
+impl ::protobuf::Message for AccessTokenKey {
     fn is_initialized(&self) -> bool {
-        for v in &self.token {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -5455,7 +8525,21 @@ impl ::protobuf::Message for SessionGet {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.token)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.secret)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -5469,9 +8553,14 @@ impl ::protobuf::Message for SessionGet {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.token.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.secret.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(3, &v);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -5479,10 +8568,14 @@ impl ::protobuf::Message for SessionGet {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.token.as_ref() {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if let Some(v) = self.account_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.id {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(ref v) = self.secret.as_ref() {
+            os.write_bytes(3, &v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -5515,12 +8608,12 @@ impl ::protobuf::Message for SessionGet {
     }
 }
 
-impl ::protobuf::MessageStatic for SessionGet {
-    fn new() -> SessionGet {
-        SessionGet::new()
+impl ::protobuf::MessageStatic for AccessTokenKey {
+    fn new() -> AccessTokenKey {
+        AccessTokenKey::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<SessionGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccessTokenKey>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -5528,13 +8621,23 @@ impl ::protobuf::MessageStatic for SessionGet {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SessionToken>>(
-                    "token",
-                    SessionGet::get_token_for_reflect,
-                    SessionGet::mut_token_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "account_id",
+                    AccessTokenKey::get_account_id_for_reflect,
+                    AccessTokenKey::mut_account_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SessionGet>(
-                    "SessionGet",
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    AccessTokenKey::get_id_for_reflect,
+                    AccessTokenKey::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "secret",
+                    AccessTokenKey::get_secret_for_reflect,
+                    AccessTokenKey::mut_secret_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessTokenKey>(
+                    "AccessTokenKey",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5543,183 +8646,103 @@ impl ::protobuf::MessageStatic for SessionGet {
     }
 }
 
-impl ::protobuf::Clear for SessionGet {
+impl ::protobuf::Clear for AccessTokenKey {
     fn clear(&mut self) {
-        self.clear_token();
+        self.clear_account_id();
+        self.clear_id();
+        self.clear_secret();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for SessionGet {
+impl ::std::fmt::Debug for AccessTokenKey {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SessionGet {
+impl ::protobuf::reflect::ProtobufValue for AccessTokenKey {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct SessionToken {
+pub struct AccessTokenValidate {
     // message fields
-    account_id: ::std::option::Option<u64>,
-    extern_id: ::std::option::Option<u32>,
-    provider: ::std::option::Option<OAuthProvider>,
-    token: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    key: ::protobuf::SingularPtrField<AccessTokenKey>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for SessionToken {}
+unsafe impl ::std::marker::Sync for AccessTokenValidate {}
 
-impl SessionToken {
-    pub fn new() -> SessionToken {
+impl AccessTokenValidate {
+    pub fn new() -> AccessTokenValidate {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static SessionToken {
-        static mut instance: ::protobuf::lazy::Lazy<SessionToken> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccessTokenValidate {
+        static mut instance: ::protobuf::lazy::Lazy<AccessTokenValidate> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SessionToken,
+            ptr: 0 as *const AccessTokenValidate,
         };
         unsafe {
-            instance.get(SessionToken::new)
+            instance.get(AccessTokenValidate::new)
         }
     }
 
-    // optional uint64 account_id = 1;
-
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
-    }
-
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
-    }
-
-    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.account_id
-    }
-
-    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.account_id
-    }
-
-    // optional uint32 extern_id = 2;
-
-    pub fn clear_extern_id(&mut self) {
-        self.extern_id = ::std::option::Option::None;
-    }
-
-    pub fn has_extern_id(&self) -> bool {
-        self.extern_id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_extern_id(&mut self, v: u32) {
-        self.extern_id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_extern_id(&self) -> u32 {
-        self.extern_id.unwrap_or(0)
-    }
-
-    fn get_extern_id_for_reflect(&self) -> &::std::option::Option<u32> {
-        &self.extern_id
-    }
-
-    fn mut_extern_id_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
-        &mut self.extern_id
-    }
-
-    // optional .sessionsrv.OAuthProvider provider = 3;
-
-    pub fn clear_provider(&mut self) {
-        self.provider = ::std::option::Option::None;
-    }
-
-    pub fn has_provider(&self) -> bool {
-        self.provider.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_provider(&mut self, v: OAuthProvider) {
-        self.provider = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_provider(&self) -> OAuthProvider {
-        self.provider.unwrap_or(OAuthProvider::GitHub)
-    }
-
-    fn get_provider_for_reflect(&self) -> &::std::option::Option<OAuthProvider> {
-        &self.provider
-    }
-
-    fn mut_provider_for_reflect(&mut self) -> &mut ::std::option::Option<OAuthProvider> {
-        &mut self.provider
-    }
+    // optional .sessionsrv.AccessTokenKey key = 1;
 
-    // optional bytes token = 4;
-
-    pub fn clear_token(&mut self) {
-        self.token.clear();
+    pub fn clear_key(&mut self) {
+        self.key.clear();
     }
 
-    pub fn has_token(&self) -> bool {
-        self.token.is_some()
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_token(&mut self, v: ::std::vec::Vec<u8>) {
-        self.token = ::protobuf::SingularField::some(v);
+    pub fn set_key(&mut self, v: AccessTokenKey) {
+        self.key = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_token(&mut self) -> &mut ::std::vec::Vec<u8> {
-        if self.token.is_none() {
-            self.token.set_default();
+    pub fn mut_key(&mut self) -> &mut AccessTokenKey {
+        if self.key.is_none() {
+            self.key.set_default();
         }
-        self.token.as_mut().unwrap()
+        self.key.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_token(&mut self) -> ::std::vec::Vec<u8> {
-        self.token.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    pub fn take_key(&mut self) -> AccessTokenKey {
+        self.key.take().unwrap_or_else(|| AccessTokenKey::new())
     }
 
-    pub fn get_token(&self) -> &[u8] {
-        match self.token.as_ref() {
-            Some(v) => &v,
-            None => &[],
-        }
+    pub fn get_key(&self) -> &AccessTokenKey {
+        self.key.as_ref().unwrap_or_else(|| AccessTokenKey::default_instance())
     }
 
-    fn get_token_for_reflect(&self) -> &::protobuf::SingularField<::std::vec::Vec<u8>> {
-        &self.token
+    fn get_key_for_reflect(&self) -> &::protobuf::SingularPtrField<AccessTokenKey> {
+        &self.key
     }
 
-    fn mut_token_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
-        &mut self.token
+    fn mut_key_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<AccessTokenKey> {
+        &mut self.key
     }
 }
 
-impl ::protobuf::Message for SessionToken {
+impl ::protobuf::Message for AccessTokenValidate {
     fn is_initialized(&self) -> bool {
+        for v in &self.key {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -5728,28 +8751,7 @@ impl ::protobuf::Message for SessionToken {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.account_id = ::std::option::Option::Some(tmp);
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.extern_id = ::std::option::Option::Some(tmp);
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_enum()?;
-                    self.provider = ::std::option::Option::Some(tmp);
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.token)?;
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.key)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -5763,17 +8765,9 @@ impl ::protobuf::Message for SessionToken {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.account_id {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(v) = self.extern_id {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(v) = self.provider {
-            my_size += ::protobuf::rt::enum_size(3, v);
-        }
-        if let Some(ref v) = self.token.as_ref() {
-            my_size += ::protobuf::rt::bytes_size(4, &v);
+        if let Some(ref v) = self.key.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -5781,17 +8775,10 @@ impl ::protobuf::Message for SessionToken {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_id {
-            os.write_uint64(1, v)?;
-        }
-        if let Some(v) = self.extern_id {
-            os.write_uint32(2, v)?;
-        }
-        if let Some(v) = self.provider {
-            os.write_enum(3, v.value())?;
-        }
-        if let Some(ref v) = self.token.as_ref() {
-            os.write_bytes(4, &v)?;
+        if let Some(ref v) = self.key.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -5824,12 +8811,12 @@ impl ::protobuf::Message for SessionToken {
     }
 }
 
-impl ::protobuf::MessageStatic for SessionToken {
-    fn new() -> SessionToken {
-        SessionToken::new()
+impl ::protobuf::MessageStatic for AccessTokenValidate {
+    fn new() -> AccessTokenValidate {
+        AccessTokenValidate::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<SessionToken>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccessTokenValidate>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -5837,28 +8824,13 @@ impl ::protobuf::MessageStatic for SessionToken {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "account_id",
-                    SessionToken::get_account_id_for_reflect,
-                    SessionToken::mut_account_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "extern_id",
-                    SessionToken::get_extern_id_for_reflect,
-                    SessionToken::mut_extern_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<OAuthProvider>>(
-                    "provider",
-                    SessionToken::get_provider_for_reflect,
-                    SessionToken::mut_provider_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "token",
-                    SessionToken::get_token_for_reflect,
-                    SessionToken::mut_token_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AccessTokenKey>>(
+                    "key",
+                    AccessTokenValidate::get_key_for_reflect,
+                    AccessTokenValidate::mut_key_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SessionToken>(
-                    "SessionToken",
+                ::protobuf::reflect::MessageDescriptor::new::<AccessTokenValidate>(
+                    "AccessTokenValidate",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5867,23 +8839,20 @@ impl ::protobuf::MessageStatic for SessionToken {
     }
 }
 
-impl ::protobuf::Clear for SessionToken {
+impl ::protobuf::Clear for AccessTokenValidate {
     fn clear(&mut self) {
-        self.clear_account_id();
-        self.clear_extern_id();
-        self.clear_provider();
-        self.clear_token();
+        self.clear_key();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for SessionToken {
+impl ::std::fmt::Debug for AccessTokenValidate {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SessionToken {
+impl ::protobuf::reflect::ProtobufValue for AccessTokenValidate {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -5892,6 +8861,7 @@ impl ::protobuf::reflect::ProtobufValue for SessionToken {
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum OAuthProvider {
     GitHub = 0,
+    OIDC = 1,
 }
 
 impl ::protobuf::ProtobufEnum for OAuthProvider {
@@ -5902,6 +8872,7 @@ impl ::protobuf::ProtobufEnum for OAuthProvider {
     fn from_i32(value: i32) -> ::std::option::Option<OAuthProvider> {
         match value {
             0 => ::std::option::Option::Some(OAuthProvider::GitHub),
+            1 => ::std::option::Option::Some(OAuthProvider::OIDC),
             _ => ::std::option::Option::None
         }
     }
@@ -5909,6 +8880,7 @@ impl ::protobuf::ProtobufEnum for OAuthProvider {
     fn values() -> &'static [Self] {
         static values: &'static [OAuthProvider] = &[
             OAuthProvider::GitHub,
+            OAuthProvider::OIDC,
         ];
         values
     }
@@ -5935,6 +8907,58 @@ impl ::protobuf::reflect::ProtobufValue for OAuthProvider {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum AccessTokenScope {
+    Upload = 0,
+    Promote = 1,
+    KeyDownload = 2,
+}
+
+impl ::protobuf::ProtobufEnum for AccessTokenScope {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<AccessTokenScope> {
+        match value {
+            0 => ::std::option::Option::Some(AccessTokenScope::Upload),
+            1 => ::std::option::Option::Some(AccessTokenScope::Promote),
+            2 => ::std::option::Option::Some(AccessTokenScope::KeyDownload),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [AccessTokenScope] = &[
+            AccessTokenScope::Upload,
+            AccessTokenScope::Promote,
+            AccessTokenScope::KeyDownload,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: ::std::option::Option<AccessTokenScope>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("AccessTokenScope", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for AccessTokenScope {
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccessTokenScope {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x1aprotocols/sessionsrv.proto\x12\nsessionsrv\"C\n\x07Account\x12\x0e\
     \n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\x14\n\x05email\x18\x02\x20\x01(\
@@ -5945,286 +8969,472 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x18\x01\x20\x01(\x04R\x02id\"9\n\rAccountCreate\x12\x12\n\x04name\x18\
     \x01\x20\x01(\tR\x04name\x12\x14\n\x05email\x18\x02\x20\x01(\tR\x05email\
     \"5\n\rAccountUpdate\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\x14\
-    \n\x05email\x18\x02\x20\x01(\tR\x05email\"\xf6\x01\n\x17AccountOriginInv\
-    itation\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x120\n\x14origin_inv\
-    itation_id\x18\x02\x20\x01(\x04R\x12originInvitationId\x12\x1d\n\naccoun\
-    t_id\x18\x03\x20\x01(\x04R\taccountId\x12!\n\x0caccount_name\x18\x04\x20\
-    \x01(\tR\x0baccountName\x12\x1b\n\torigin_id\x18\x05\x20\x01(\x04R\x08or\
-    iginId\x12\x1f\n\x0borigin_name\x18\x06\x20\x01(\tR\noriginName\x12\x19\
-    \n\x08owner_id\x18\x07\x20\x01(\x04R\x07ownerId\"\xec\x01\n\x1dAccountOr\
-    iginInvitationCreate\x120\n\x14origin_invitation_id\x18\x01\x20\x01(\x04\
-    R\x12originInvitationId\x12\x1d\n\naccount_id\x18\x02\x20\x01(\x04R\tacc\
-    ountId\x12!\n\x0caccount_name\x18\x03\x20\x01(\tR\x0baccountName\x12\x1b\
-    \n\torigin_id\x18\x04\x20\x01(\x04R\x08originId\x12\x1f\n\x0borigin_name\
-    \x18\x05\x20\x01(\tR\noriginName\x12\x19\n\x08owner_id\x18\x06\x20\x01(\
-    \x04R\x07ownerId\"\x9b\x01\n$AccountOriginInvitationAcceptRequest\x12\
-    \x1d\n\naccount_id\x18\x01\x20\x01(\x04R\taccountId\x12\x1b\n\tinvite_id\
-    \x18\x02\x20\x01(\x04R\x08inviteId\x12\x1f\n\x0borigin_name\x18\x03\x20\
-    \x01(\tR\noriginName\x12\x16\n\x06ignore\x18\x04\x20\x01(\x08R\x06ignore\
-    \"j\n$AccountOriginInvitationIgnoreRequest\x12\x1d\n\naccount_id\x18\x01\
-    \x20\x01(\x04R\taccountId\x12#\n\rinvitation_id\x18\x02\x20\x01(\x04R\
-    \x0cinvitationId\"k\n%AccountOriginInvitationRescindRequest\x12\x1d\n\na\
-    ccount_id\x18\x01\x20\x01(\x04R\taccountId\x12#\n\rinvitation_id\x18\x02\
-    \x20\x01(\x04R\x0cinvitationId\"=\n\x1cAccountInvitationListRequest\x12\
-    \x1d\n\naccount_id\x18\x01\x20\x01(\x04R\taccountId\"\x85\x01\n\x1dAccou\
-    ntInvitationListResponse\x12\x1d\n\naccount_id\x18\x01\x20\x01(\x04R\tac\
-    countId\x12E\n\x0binvitations\x18\x02\x20\x03(\x0b2#.sessionsrv.AccountO\
-    riginInvitationR\x0binvitations\"\x95\x01\n\x13AccountOriginCreate\x12\
-    \x1d\n\naccount_id\x18\x01\x20\x01(\x04R\taccountId\x12!\n\x0caccount_na\
-    me\x18\x02\x20\x01(\tR\x0baccountName\x12\x1b\n\torigin_id\x18\x03\x20\
-    \x01(\x04R\x08originId\x12\x1f\n\x0borigin_name\x18\x04\x20\x01(\tR\nori\
-    ginName\"9\n\x18AccountOriginListRequest\x12\x1d\n\naccount_id\x18\x01\
-    \x20\x01(\x04R\taccountId\"T\n\x19AccountOriginListResponse\x12\x1d\n\na\
-    ccount_id\x18\x01\x20\x01(\x04R\taccountId\x12\x18\n\x07origins\x18\x02\
-    \x20\x03(\tR\x07origins\"U\n\x13AccountOriginRemove\x12!\n\x0caccount_na\
-    me\x18\x01\x20\x01(\tR\x0baccountName\x12\x1b\n\torigin_id\x18\x02\x20\
-    \x01(\x04R\x08originId\"\x90\x01\n\x07Session\x12\x0e\n\x02id\x18\x01\
-    \x20\x01(\x04R\x02id\x12\x14\n\x05email\x18\x02\x20\x01(\tR\x05email\x12\
-    \x12\n\x04name\x18\x03\x20\x01(\tR\x04name\x12\x14\n\x05token\x18\x04\
-    \x20\x01(\tR\x05token\x12\x14\n\x05flags\x18\x05\x20\x01(\rR\x05flags\
-    \x12\x1f\n\x0boauth_token\x18\x06\x20\x01(\tR\noauthToken\"\xc0\x01\n\rS\
-    essionCreate\x12\x14\n\x05token\x18\x01\x20\x01(\tR\x05token\x12\x1b\n\t\
-    extern_id\x18\x02\x20\x01(\rR\x08externId\x12\x14\n\x05email\x18\x03\x20\
-    \x01(\tR\x05email\x12\x12\n\x04name\x18\x04\x20\x01(\tR\x04name\x125\n\
-    \x08provider\x18\x05\x20\x01(\x0e2\x19.sessionsrv.OAuthProviderR\x08prov\
-    ider\x12\x1b\n\tapp_token\x18\x06\x20\x01(\tR\x08appToken\"<\n\nSessionG\
-    et\x12.\n\x05token\x18\x01\x20\x01(\x0b2\x18.sessionsrv.SessionTokenR\
-    \x05token\"\x97\x01\n\x0cSessionToken\x12\x1d\n\naccount_id\x18\x01\x20\
-    \x01(\x04R\taccountId\x12\x1b\n\textern_id\x18\x02\x20\x01(\rR\x08extern\
-    Id\x125\n\x08provider\x18\x03\x20\x01(\x0e2\x19.sessionsrv.OAuthProvider\
-    R\x08provider\x12\x14\n\x05token\x18\x04\x20\x01(\x0cR\x05token*\x1b\n\r\
-    OAuthProvider\x12\n\n\x06GitHub\x10\0J\xb0&\n\x07\x12\x05\0\0\x83\x01\
-    \x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\x08\x12\
-    \n\n\n\x02\x05\0\x12\x04\x03\0\x05\x01\n\n\n\x03\x05\0\x01\x12\x03\x03\
-    \x05\x12\n\x0b\n\x04\x05\0\x02\0\x12\x03\x04\x02\r\n\x0c\n\x05\x05\0\x02\
-    \0\x01\x12\x03\x04\x02\x08\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x04\x0b\
-    \x0c\n\n\n\x02\x04\0\x12\x04\x07\0\x0b\x01\n\n\n\x03\x04\0\x01\x12\x03\
-    \x07\x08\x0f\n\x0b\n\x04\x04\0\x02\0\x12\x03\x08\x02\x19\n\x0c\n\x05\x04\
-    \0\x02\0\x04\x12\x03\x08\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x08\
-    \x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x08\x12\x14\n\x0c\n\x05\x04\
-    \0\x02\0\x03\x12\x03\x08\x17\x18\n\x0b\n\x04\x04\0\x02\x01\x12\x03\t\x02\
-    \x1c\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\t\x02\n\n\x0c\n\x05\x04\0\x02\
-    \x01\x05\x12\x03\t\x0b\x11\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\t\x12\
-    \x17\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\t\x1a\x1b\n\x0b\n\x04\x04\0\
-    \x02\x02\x12\x03\n\x02\x1b\n\x0c\n\x05\x04\0\x02\x02\x04\x12\x03\n\x02\n\
-    \n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\n\x0b\x11\n\x0c\n\x05\x04\0\x02\
-    \x02\x01\x12\x03\n\x12\x16\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\n\x19\
-    \x1a\n\n\n\x02\x04\x01\x12\x04\r\0\x10\x01\n\n\n\x03\x04\x01\x01\x12\x03\
-    \r\x08\x1b\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0e\x02\x1b\n\x0c\n\x05\x04\
-    \x01\x02\0\x04\x12\x03\x0e\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\
-    \x0e\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0e\x12\x16\n\x0c\n\
-    \x05\x04\x01\x02\0\x03\x12\x03\x0e\x19\x1a\n\x0b\n\x04\x04\x01\x02\x01\
-    \x12\x03\x0f\x02\x1c\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03\x0f\x02\n\n\
-    \x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\x0f\x0b\x11\n\x0c\n\x05\x04\x01\
-    \x02\x01\x01\x12\x03\x0f\x12\x17\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\
-    \x0f\x1a\x1b\n+\n\x02\x04\x02\x12\x04\x13\0\x15\x01\x1a\x1f\x20get\x20an\
-    \x20account\x20by\x20GH\x20username\n\n\n\n\x03\x04\x02\x01\x12\x03\x13\
-    \x08\x12\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x14\x02\x1b\n\x0c\n\x05\x04\
-    \x02\x02\0\x04\x12\x03\x14\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\
-    \x14\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x14\x12\x16\n\x0c\n\
-    \x05\x04\x02\x02\0\x03\x12\x03\x14\x19\x1a\n\n\n\x02\x04\x03\x12\x04\x17\
-    \0\x19\x01\n\n\n\x03\x04\x03\x01\x12\x03\x17\x08\x14\n\x0b\n\x04\x04\x03\
-    \x02\0\x12\x03\x18\x02\x19\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x18\x02\
-    \n\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x18\x0b\x11\n\x0c\n\x05\x04\x03\
-    \x02\0\x01\x12\x03\x18\x12\x14\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x18\
-    \x17\x18\n\n\n\x02\x04\x04\x12\x04\x1b\0\x1e\x01\n\n\n\x03\x04\x04\x01\
-    \x12\x03\x1b\x08\x15\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1c\x02\x1b\n\x0c\
-    \n\x05\x04\x04\x02\0\x04\x12\x03\x1c\x02\n\n\x0c\n\x05\x04\x04\x02\0\x05\
-    \x12\x03\x1c\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1c\x12\x16\n\
-    \x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1c\x19\x1a\n\x0b\n\x04\x04\x04\x02\
-    \x01\x12\x03\x1d\x02\x1c\n\x0c\n\x05\x04\x04\x02\x01\x04\x12\x03\x1d\x02\
-    \n\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\x1d\x0b\x11\n\x0c\n\x05\x04\
-    \x04\x02\x01\x01\x12\x03\x1d\x12\x17\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\
-    \x03\x1d\x1a\x1b\n\n\n\x02\x04\x05\x12\x04\x20\0#\x01\n\n\n\x03\x04\x05\
-    \x01\x12\x03\x20\x08\x15\n\x0b\n\x04\x04\x05\x02\0\x12\x03!\x02\x19\n\
-    \x0c\n\x05\x04\x05\x02\0\x04\x12\x03!\x02\n\n\x0c\n\x05\x04\x05\x02\0\
-    \x05\x12\x03!\x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03!\x12\x14\n\
-    \x0c\n\x05\x04\x05\x02\0\x03\x12\x03!\x17\x18\n\x0b\n\x04\x04\x05\x02\
-    \x01\x12\x03\"\x02\x1c\n\x0c\n\x05\x04\x05\x02\x01\x04\x12\x03\"\x02\n\n\
-    \x0c\n\x05\x04\x05\x02\x01\x05\x12\x03\"\x0b\x11\n\x0c\n\x05\x04\x05\x02\
-    \x01\x01\x12\x03\"\x12\x17\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03\"\x1a\
-    \x1b\n\n\n\x02\x04\x06\x12\x04%\0-\x01\n\n\n\x03\x04\x06\x01\x12\x03%\
-    \x08\x1f\n\x0b\n\x04\x04\x06\x02\0\x12\x03&\x02\x19\n\x0c\n\x05\x04\x06\
-    \x02\0\x04\x12\x03&\x02\n\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03&\x0b\x11\
-    \n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03&\x12\x14\n\x0c\n\x05\x04\x06\x02\
-    \0\x03\x12\x03&\x17\x18\n\x0b\n\x04\x04\x06\x02\x01\x12\x03'\x02+\n\x0c\
-    \n\x05\x04\x06\x02\x01\x04\x12\x03'\x02\n\n\x0c\n\x05\x04\x06\x02\x01\
-    \x05\x12\x03'\x0b\x11\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03'\x12&\n\
-    \x0c\n\x05\x04\x06\x02\x01\x03\x12\x03')*\n\x0b\n\x04\x04\x06\x02\x02\
-    \x12\x03(\x02!\n\x0c\n\x05\x04\x06\x02\x02\x04\x12\x03(\x02\n\n\x0c\n\
-    \x05\x04\x06\x02\x02\x05\x12\x03(\x0b\x11\n\x0c\n\x05\x04\x06\x02\x02\
-    \x01\x12\x03(\x12\x1c\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03(\x1f\x20\n\
-    \x0b\n\x04\x04\x06\x02\x03\x12\x03)\x02#\n\x0c\n\x05\x04\x06\x02\x03\x04\
-    \x12\x03)\x02\n\n\x0c\n\x05\x04\x06\x02\x03\x05\x12\x03)\x0b\x11\n\x0c\n\
-    \x05\x04\x06\x02\x03\x01\x12\x03)\x12\x1e\n\x0c\n\x05\x04\x06\x02\x03\
-    \x03\x12\x03)!\"\n\x0b\n\x04\x04\x06\x02\x04\x12\x03*\x02\x20\n\x0c\n\
-    \x05\x04\x06\x02\x04\x04\x12\x03*\x02\n\n\x0c\n\x05\x04\x06\x02\x04\x05\
-    \x12\x03*\x0b\x11\n\x0c\n\x05\x04\x06\x02\x04\x01\x12\x03*\x12\x1b\n\x0c\
-    \n\x05\x04\x06\x02\x04\x03\x12\x03*\x1e\x1f\n\x0b\n\x04\x04\x06\x02\x05\
-    \x12\x03+\x02\"\n\x0c\n\x05\x04\x06\x02\x05\x04\x12\x03+\x02\n\n\x0c\n\
-    \x05\x04\x06\x02\x05\x05\x12\x03+\x0b\x11\n\x0c\n\x05\x04\x06\x02\x05\
-    \x01\x12\x03+\x12\x1d\n\x0c\n\x05\x04\x06\x02\x05\x03\x12\x03+\x20!\n\
-    \x0b\n\x04\x04\x06\x02\x06\x12\x03,\x02\x1f\n\x0c\n\x05\x04\x06\x02\x06\
-    \x04\x12\x03,\x02\n\n\x0c\n\x05\x04\x06\x02\x06\x05\x12\x03,\x0b\x11\n\
-    \x0c\n\x05\x04\x06\x02\x06\x01\x12\x03,\x12\x1a\n\x0c\n\x05\x04\x06\x02\
-    \x06\x03\x12\x03,\x1d\x1e\n\n\n\x02\x04\x07\x12\x04/\06\x01\n\n\n\x03\
-    \x04\x07\x01\x12\x03/\x08%\n\x0b\n\x04\x04\x07\x02\0\x12\x030\x02+\n\x0c\
-    \n\x05\x04\x07\x02\0\x04\x12\x030\x02\n\n\x0c\n\x05\x04\x07\x02\0\x05\
-    \x12\x030\x0b\x11\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x030\x12&\n\x0c\n\
-    \x05\x04\x07\x02\0\x03\x12\x030)*\n\x0b\n\x04\x04\x07\x02\x01\x12\x031\
-    \x02!\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x031\x02\n\n\x0c\n\x05\x04\x07\
-    \x02\x01\x05\x12\x031\x0b\x11\n\x0c\n\x05\x04\x07\x02\x01\x01\x12\x031\
-    \x12\x1c\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x031\x1f\x20\n\x0b\n\x04\
-    \x04\x07\x02\x02\x12\x032\x02#\n\x0c\n\x05\x04\x07\x02\x02\x04\x12\x032\
-    \x02\n\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\x032\x0b\x11\n\x0c\n\x05\x04\
-    \x07\x02\x02\x01\x12\x032\x12\x1e\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\
-    \x032!\"\n\x0b\n\x04\x04\x07\x02\x03\x12\x033\x02\x20\n\x0c\n\x05\x04\
-    \x07\x02\x03\x04\x12\x033\x02\n\n\x0c\n\x05\x04\x07\x02\x03\x05\x12\x033\
-    \x0b\x11\n\x0c\n\x05\x04\x07\x02\x03\x01\x12\x033\x12\x1b\n\x0c\n\x05\
-    \x04\x07\x02\x03\x03\x12\x033\x1e\x1f\n\x0b\n\x04\x04\x07\x02\x04\x12\
-    \x034\x02\"\n\x0c\n\x05\x04\x07\x02\x04\x04\x12\x034\x02\n\n\x0c\n\x05\
-    \x04\x07\x02\x04\x05\x12\x034\x0b\x11\n\x0c\n\x05\x04\x07\x02\x04\x01\
-    \x12\x034\x12\x1d\n\x0c\n\x05\x04\x07\x02\x04\x03\x12\x034\x20!\n\x0b\n\
-    \x04\x04\x07\x02\x05\x12\x035\x02\x1f\n\x0c\n\x05\x04\x07\x02\x05\x04\
-    \x12\x035\x02\n\n\x0c\n\x05\x04\x07\x02\x05\x05\x12\x035\x0b\x11\n\x0c\n\
-    \x05\x04\x07\x02\x05\x01\x12\x035\x12\x1a\n\x0c\n\x05\x04\x07\x02\x05\
-    \x03\x12\x035\x1d\x1e\n\n\n\x02\x04\x08\x12\x048\0=\x01\n\n\n\x03\x04\
-    \x08\x01\x12\x038\x08,\n\x0b\n\x04\x04\x08\x02\0\x12\x039\x02!\n\x0c\n\
-    \x05\x04\x08\x02\0\x04\x12\x039\x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\
-    \x039\x0b\x11\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x039\x12\x1c\n\x0c\n\x05\
-    \x04\x08\x02\0\x03\x12\x039\x1f\x20\n\x0b\n\x04\x04\x08\x02\x01\x12\x03:\
-    \x02\x20\n\x0c\n\x05\x04\x08\x02\x01\x04\x12\x03:\x02\n\n\x0c\n\x05\x04\
-    \x08\x02\x01\x05\x12\x03:\x0b\x11\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\
-    \x03:\x12\x1b\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x03:\x1e\x1f\n\x0b\n\
-    \x04\x04\x08\x02\x02\x12\x03;\x02\"\n\x0c\n\x05\x04\x08\x02\x02\x04\x12\
-    \x03;\x02\n\n\x0c\n\x05\x04\x08\x02\x02\x05\x12\x03;\x0b\x11\n\x0c\n\x05\
-    \x04\x08\x02\x02\x01\x12\x03;\x12\x1d\n\x0c\n\x05\x04\x08\x02\x02\x03\
-    \x12\x03;\x20!\n\x0b\n\x04\x04\x08\x02\x03\x12\x03<\x02\x1b\n\x0c\n\x05\
-    \x04\x08\x02\x03\x04\x12\x03<\x02\n\n\x0c\n\x05\x04\x08\x02\x03\x05\x12\
-    \x03<\x0b\x0f\n\x0c\n\x05\x04\x08\x02\x03\x01\x12\x03<\x10\x16\n\x0c\n\
-    \x05\x04\x08\x02\x03\x03\x12\x03<\x19\x1a\n\n\n\x02\x04\t\x12\x04?\0B\
-    \x01\n\n\n\x03\x04\t\x01\x12\x03?\x08,\n\x0b\n\x04\x04\t\x02\0\x12\x03@\
-    \x02!\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03@\x02\n\n\x0c\n\x05\x04\t\x02\0\
-    \x05\x12\x03@\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03@\x12\x1c\n\x0c\
-    \n\x05\x04\t\x02\0\x03\x12\x03@\x1f\x20\n\x0b\n\x04\x04\t\x02\x01\x12\
-    \x03A\x02$\n\x0c\n\x05\x04\t\x02\x01\x04\x12\x03A\x02\n\n\x0c\n\x05\x04\
-    \t\x02\x01\x05\x12\x03A\x0b\x11\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x03A\
-    \x12\x1f\n\x0c\n\x05\x04\t\x02\x01\x03\x12\x03A\"#\n\n\n\x02\x04\n\x12\
-    \x04D\0G\x01\n\n\n\x03\x04\n\x01\x12\x03D\x08-\n\x0b\n\x04\x04\n\x02\0\
-    \x12\x03E\x02!\n\x0c\n\x05\x04\n\x02\0\x04\x12\x03E\x02\n\n\x0c\n\x05\
-    \x04\n\x02\0\x05\x12\x03E\x0b\x11\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03E\
-    \x12\x1c\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03E\x1f\x20\n\x0b\n\x04\x04\n\
-    \x02\x01\x12\x03F\x02$\n\x0c\n\x05\x04\n\x02\x01\x04\x12\x03F\x02\n\n\
-    \x0c\n\x05\x04\n\x02\x01\x05\x12\x03F\x0b\x11\n\x0c\n\x05\x04\n\x02\x01\
-    \x01\x12\x03F\x12\x1f\n\x0c\n\x05\x04\n\x02\x01\x03\x12\x03F\"#\n\n\n\
-    \x02\x04\x0b\x12\x04I\0K\x01\n\n\n\x03\x04\x0b\x01\x12\x03I\x08$\n\x0b\n\
-    \x04\x04\x0b\x02\0\x12\x03J\x02!\n\x0c\n\x05\x04\x0b\x02\0\x04\x12\x03J\
-    \x02\n\n\x0c\n\x05\x04\x0b\x02\0\x05\x12\x03J\x0b\x11\n\x0c\n\x05\x04\
-    \x0b\x02\0\x01\x12\x03J\x12\x1c\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03J\
-    \x1f\x20\n\n\n\x02\x04\x0c\x12\x04M\0P\x01\n\n\n\x03\x04\x0c\x01\x12\x03\
-    M\x08%\n\x0b\n\x04\x04\x0c\x02\0\x12\x03N\x02!\n\x0c\n\x05\x04\x0c\x02\0\
-    \x04\x12\x03N\x02\n\n\x0c\n\x05\x04\x0c\x02\0\x05\x12\x03N\x0b\x11\n\x0c\
-    \n\x05\x04\x0c\x02\0\x01\x12\x03N\x12\x1c\n\x0c\n\x05\x04\x0c\x02\0\x03\
-    \x12\x03N\x1f\x20\n\x0b\n\x04\x04\x0c\x02\x01\x12\x03O\x023\n\x0c\n\x05\
-    \x04\x0c\x02\x01\x04\x12\x03O\x02\n\n\x0c\n\x05\x04\x0c\x02\x01\x06\x12\
-    \x03O\x0b\"\n\x0c\n\x05\x04\x0c\x02\x01\x01\x12\x03O#.\n\x0c\n\x05\x04\
-    \x0c\x02\x01\x03\x12\x03O12\n\n\n\x02\x04\r\x12\x04R\0W\x01\n\n\n\x03\
-    \x04\r\x01\x12\x03R\x08\x1b\n\x0b\n\x04\x04\r\x02\0\x12\x03S\x02!\n\x0c\
-    \n\x05\x04\r\x02\0\x04\x12\x03S\x02\n\n\x0c\n\x05\x04\r\x02\0\x05\x12\
-    \x03S\x0b\x11\n\x0c\n\x05\x04\r\x02\0\x01\x12\x03S\x12\x1c\n\x0c\n\x05\
-    \x04\r\x02\0\x03\x12\x03S\x1f\x20\n\x0b\n\x04\x04\r\x02\x01\x12\x03T\x02\
-    #\n\x0c\n\x05\x04\r\x02\x01\x04\x12\x03T\x02\n\n\x0c\n\x05\x04\r\x02\x01\
-    \x05\x12\x03T\x0b\x11\n\x0c\n\x05\x04\r\x02\x01\x01\x12\x03T\x12\x1e\n\
-    \x0c\n\x05\x04\r\x02\x01\x03\x12\x03T!\"\n\x0b\n\x04\x04\r\x02\x02\x12\
-    \x03U\x02\x20\n\x0c\n\x05\x04\r\x02\x02\x04\x12\x03U\x02\n\n\x0c\n\x05\
-    \x04\r\x02\x02\x05\x12\x03U\x0b\x11\n\x0c\n\x05\x04\r\x02\x02\x01\x12\
-    \x03U\x12\x1b\n\x0c\n\x05\x04\r\x02\x02\x03\x12\x03U\x1e\x1f\n\x0b\n\x04\
-    \x04\r\x02\x03\x12\x03V\x02\"\n\x0c\n\x05\x04\r\x02\x03\x04\x12\x03V\x02\
-    \n\n\x0c\n\x05\x04\r\x02\x03\x05\x12\x03V\x0b\x11\n\x0c\n\x05\x04\r\x02\
-    \x03\x01\x12\x03V\x12\x1d\n\x0c\n\x05\x04\r\x02\x03\x03\x12\x03V\x20!\n\
-    \n\n\x02\x04\x0e\x12\x04Y\0[\x01\n\n\n\x03\x04\x0e\x01\x12\x03Y\x08\x20\
-    \n\x0b\n\x04\x04\x0e\x02\0\x12\x03Z\x02!\n\x0c\n\x05\x04\x0e\x02\0\x04\
-    \x12\x03Z\x02\n\n\x0c\n\x05\x04\x0e\x02\0\x05\x12\x03Z\x0b\x11\n\x0c\n\
-    \x05\x04\x0e\x02\0\x01\x12\x03Z\x12\x1c\n\x0c\n\x05\x04\x0e\x02\0\x03\
-    \x12\x03Z\x1f\x20\n\n\n\x02\x04\x0f\x12\x04]\0`\x01\n\n\n\x03\x04\x0f\
-    \x01\x12\x03]\x08!\n\x0b\n\x04\x04\x0f\x02\0\x12\x03^\x02!\n\x0c\n\x05\
-    \x04\x0f\x02\0\x04\x12\x03^\x02\n\n\x0c\n\x05\x04\x0f\x02\0\x05\x12\x03^\
-    \x0b\x11\n\x0c\n\x05\x04\x0f\x02\0\x01\x12\x03^\x12\x1c\n\x0c\n\x05\x04\
-    \x0f\x02\0\x03\x12\x03^\x1f\x20\n\x0b\n\x04\x04\x0f\x02\x01\x12\x03_\x02\
-    \x1e\n\x0c\n\x05\x04\x0f\x02\x01\x04\x12\x03_\x02\n\n\x0c\n\x05\x04\x0f\
-    \x02\x01\x05\x12\x03_\x0b\x11\n\x0c\n\x05\x04\x0f\x02\x01\x01\x12\x03_\
-    \x12\x19\n\x0c\n\x05\x04\x0f\x02\x01\x03\x12\x03_\x1c\x1d\n\n\n\x02\x04\
-    \x10\x12\x04b\0e\x01\n\n\n\x03\x04\x10\x01\x12\x03b\x08\x1b\n\x0b\n\x04\
-    \x04\x10\x02\0\x12\x03c\x02#\n\x0c\n\x05\x04\x10\x02\0\x04\x12\x03c\x02\
-    \n\n\x0c\n\x05\x04\x10\x02\0\x05\x12\x03c\x0b\x11\n\x0c\n\x05\x04\x10\
-    \x02\0\x01\x12\x03c\x12\x1e\n\x0c\n\x05\x04\x10\x02\0\x03\x12\x03c!\"\n\
-    \x0b\n\x04\x04\x10\x02\x01\x12\x03d\x02\x20\n\x0c\n\x05\x04\x10\x02\x01\
-    \x04\x12\x03d\x02\n\n\x0c\n\x05\x04\x10\x02\x01\x05\x12\x03d\x0b\x11\n\
-    \x0c\n\x05\x04\x10\x02\x01\x01\x12\x03d\x12\x1b\n\x0c\n\x05\x04\x10\x02\
-    \x01\x03\x12\x03d\x1e\x1f\n\n\n\x02\x04\x11\x12\x04g\0o\x01\n\n\n\x03\
-    \x04\x11\x01\x12\x03g\x08\x0f\n\x0b\n\x04\x04\x11\x02\0\x12\x03h\x02\x19\
-    \n\x0c\n\x05\x04\x11\x02\0\x04\x12\x03h\x02\n\n\x0c\n\x05\x04\x11\x02\0\
-    \x05\x12\x03h\x0b\x11\n\x0c\n\x05\x04\x11\x02\0\x01\x12\x03h\x12\x14\n\
-    \x0c\n\x05\x04\x11\x02\0\x03\x12\x03h\x17\x18\n\x0b\n\x04\x04\x11\x02\
-    \x01\x12\x03i\x02\x1c\n\x0c\n\x05\x04\x11\x02\x01\x04\x12\x03i\x02\n\n\
-    \x0c\n\x05\x04\x11\x02\x01\x05\x12\x03i\x0b\x11\n\x0c\n\x05\x04\x11\x02\
-    \x01\x01\x12\x03i\x12\x17\n\x0c\n\x05\x04\x11\x02\x01\x03\x12\x03i\x1a\
-    \x1b\n\x0b\n\x04\x04\x11\x02\x02\x12\x03j\x02\x1b\n\x0c\n\x05\x04\x11\
-    \x02\x02\x04\x12\x03j\x02\n\n\x0c\n\x05\x04\x11\x02\x02\x05\x12\x03j\x0b\
-    \x11\n\x0c\n\x05\x04\x11\x02\x02\x01\x12\x03j\x12\x16\n\x0c\n\x05\x04\
-    \x11\x02\x02\x03\x12\x03j\x19\x1a\nC\n\x04\x04\x11\x02\x03\x12\x03l\x02\
-    \x1c\x1a6\x20base64\x20encoded\x20binary\x20representation\x20of\x20Sess\
-    ionToken\n\n\x0c\n\x05\x04\x11\x02\x03\x04\x12\x03l\x02\n\n\x0c\n\x05\
-    \x04\x11\x02\x03\x05\x12\x03l\x0b\x11\n\x0c\n\x05\x04\x11\x02\x03\x01\
-    \x12\x03l\x12\x17\n\x0c\n\x05\x04\x11\x02\x03\x03\x12\x03l\x1a\x1b\n\x0b\
-    \n\x04\x04\x11\x02\x04\x12\x03m\x02\x1c\n\x0c\n\x05\x04\x11\x02\x04\x04\
-    \x12\x03m\x02\n\n\x0c\n\x05\x04\x11\x02\x04\x05\x12\x03m\x0b\x11\n\x0c\n\
-    \x05\x04\x11\x02\x04\x01\x12\x03m\x12\x17\n\x0c\n\x05\x04\x11\x02\x04\
-    \x03\x12\x03m\x1a\x1b\n\x0b\n\x04\x04\x11\x02\x05\x12\x03n\x02\"\n\x0c\n\
-    \x05\x04\x11\x02\x05\x04\x12\x03n\x02\n\n\x0c\n\x05\x04\x11\x02\x05\x05\
-    \x12\x03n\x0b\x11\n\x0c\n\x05\x04\x11\x02\x05\x01\x12\x03n\x12\x1d\n\x0c\
-    \n\x05\x04\x11\x02\x05\x03\x12\x03n\x20!\n\n\n\x02\x04\x12\x12\x04q\0x\
-    \x01\n\n\n\x03\x04\x12\x01\x12\x03q\x08\x15\n\x0b\n\x04\x04\x12\x02\0\
-    \x12\x03r\x02\x1c\n\x0c\n\x05\x04\x12\x02\0\x04\x12\x03r\x02\n\n\x0c\n\
-    \x05\x04\x12\x02\0\x05\x12\x03r\x0b\x11\n\x0c\n\x05\x04\x12\x02\0\x01\
-    \x12\x03r\x12\x17\n\x0c\n\x05\x04\x12\x02\0\x03\x12\x03r\x1a\x1b\n\x0b\n\
-    \x04\x04\x12\x02\x01\x12\x03s\x02\x20\n\x0c\n\x05\x04\x12\x02\x01\x04\
-    \x12\x03s\x02\n\n\x0c\n\x05\x04\x12\x02\x01\x05\x12\x03s\x0b\x11\n\x0c\n\
-    \x05\x04\x12\x02\x01\x01\x12\x03s\x12\x1b\n\x0c\n\x05\x04\x12\x02\x01\
-    \x03\x12\x03s\x1e\x1f\n\x0b\n\x04\x04\x12\x02\x02\x12\x03t\x02\x1c\n\x0c\
-    \n\x05\x04\x12\x02\x02\x04\x12\x03t\x02\n\n\x0c\n\x05\x04\x12\x02\x02\
-    \x05\x12\x03t\x0b\x11\n\x0c\n\x05\x04\x12\x02\x02\x01\x12\x03t\x12\x17\n\
-    \x0c\n\x05\x04\x12\x02\x02\x03\x12\x03t\x1a\x1b\n\x0b\n\x04\x04\x12\x02\
-    \x03\x12\x03u\x02\x1b\n\x0c\n\x05\x04\x12\x02\x03\x04\x12\x03u\x02\n\n\
-    \x0c\n\x05\x04\x12\x02\x03\x05\x12\x03u\x0b\x11\n\x0c\n\x05\x04\x12\x02\
-    \x03\x01\x12\x03u\x12\x16\n\x0c\n\x05\x04\x12\x02\x03\x03\x12\x03u\x19\
-    \x1a\n\x0b\n\x04\x04\x12\x02\x04\x12\x03v\x02&\n\x0c\n\x05\x04\x12\x02\
-    \x04\x04\x12\x03v\x02\n\n\x0c\n\x05\x04\x12\x02\x04\x06\x12\x03v\x0b\x18\
-    \n\x0c\n\x05\x04\x12\x02\x04\x01\x12\x03v\x19!\n\x0c\n\x05\x04\x12\x02\
-    \x04\x03\x12\x03v$%\n\x0b\n\x04\x04\x12\x02\x05\x12\x03w\x02\x20\n\x0c\n\
-    \x05\x04\x12\x02\x05\x04\x12\x03w\x02\n\n\x0c\n\x05\x04\x12\x02\x05\x05\
-    \x12\x03w\x0b\x11\n\x0c\n\x05\x04\x12\x02\x05\x01\x12\x03w\x12\x1b\n\x0c\
-    \n\x05\x04\x12\x02\x05\x03\x12\x03w\x1e\x1f\n\n\n\x02\x04\x13\x12\x04z\0\
-    |\x01\n\n\n\x03\x04\x13\x01\x12\x03z\x08\x12\n\x0b\n\x04\x04\x13\x02\0\
-    \x12\x03{\x02\"\n\x0c\n\x05\x04\x13\x02\0\x04\x12\x03{\x02\n\n\x0c\n\x05\
-    \x04\x13\x02\0\x06\x12\x03{\x0b\x17\n\x0c\n\x05\x04\x13\x02\0\x01\x12\
-    \x03{\x18\x1d\n\x0c\n\x05\x04\x13\x02\0\x03\x12\x03{\x20!\n\x0b\n\x02\
-    \x04\x14\x12\x05~\0\x83\x01\x01\n\n\n\x03\x04\x14\x01\x12\x03~\x08\x14\n\
-    \x0b\n\x04\x04\x14\x02\0\x12\x03\x7f\x02!\n\x0c\n\x05\x04\x14\x02\0\x04\
-    \x12\x03\x7f\x02\n\n\x0c\n\x05\x04\x14\x02\0\x05\x12\x03\x7f\x0b\x11\n\
-    \x0c\n\x05\x04\x14\x02\0\x01\x12\x03\x7f\x12\x1c\n\x0c\n\x05\x04\x14\x02\
-    \0\x03\x12\x03\x7f\x1f\x20\n\x0c\n\x04\x04\x14\x02\x01\x12\x04\x80\x01\
-    \x02\x20\n\r\n\x05\x04\x14\x02\x01\x04\x12\x04\x80\x01\x02\n\n\r\n\x05\
-    \x04\x14\x02\x01\x05\x12\x04\x80\x01\x0b\x11\n\r\n\x05\x04\x14\x02\x01\
-    \x01\x12\x04\x80\x01\x12\x1b\n\r\n\x05\x04\x14\x02\x01\x03\x12\x04\x80\
-    \x01\x1e\x1f\n\x0c\n\x04\x04\x14\x02\x02\x12\x04\x81\x01\x02&\n\r\n\x05\
-    \x04\x14\x02\x02\x04\x12\x04\x81\x01\x02\n\n\r\n\x05\x04\x14\x02\x02\x06\
-    \x12\x04\x81\x01\x0b\x18\n\r\n\x05\x04\x14\x02\x02\x01\x12\x04\x81\x01\
-    \x19!\n\r\n\x05\x04\x14\x02\x02\x03\x12\x04\x81\x01$%\n\x0c\n\x04\x04\
-    \x14\x02\x03\x12\x04\x82\x01\x02\x1b\n\r\n\x05\x04\x14\x02\x03\x04\x12\
-    \x04\x82\x01\x02\n\n\r\n\x05\x04\x14\x02\x03\x05\x12\x04\x82\x01\x0b\x10\
-    \n\r\n\x05\x04\x14\x02\x03\x01\x12\x04\x82\x01\x11\x16\n\r\n\x05\x04\x14\
-    \x02\x03\x03\x12\x04\x82\x01\x19\x1a\
+    \n\x05email\x18\x02\x20\x01(\tR\x05email\"\x81\x01\n\x0fAccountIdentity\
+    \x125\n\x08provider\x18\x01\x20\x01(\x0e2\x19.sessionsrv.OAuthProviderR\
+    \x08provider\x12\x18\n\x07subject\x18\x02\x20\x01(\tR\x07subject\x12\x1d\
+    \n\naccount_id\x18\x03\x20\x01(\x04R\taccountId\"\x87\x01\n\x15AccountId\
+    entityCreate\x125\n\x08provider\x18\x01\x20\x01(\x0e2\x19.sessionsrv.OAu\
+    thProviderR\x08provider\x12\x18\n\x07subject\x18\x02\x20\x01(\tR\x07subj\
+    ect\x12\x1d\n\naccount_id\x18\x03\x20\x01(\x04R\taccountId\"e\n\x12Accou\
+    ntIdentityGet\x125\n\x08provider\x18\x01\x20\x01(\x0e2\x19.sessionsrv.OA\
+    uthProviderR\x08provider\x12\x18\n\x07subject\x18\x02\x20\x01(\tR\x07sub\
+    ject\"\xf6\x01\n\x17AccountOriginInvitation\x12\x0e\n\x02id\x18\x01\x20\
+    \x01(\x04R\x02id\x120\n\x14origin_invitation_id\x18\x02\x20\x01(\x04R\
+    \x12originInvitationId\x12\x1d\n\naccount_id\x18\x03\x20\x01(\x04R\tacco\
+    untId\x12!\n\x0caccount_name\x18\x04\x20\x01(\tR\x0baccountName\x12\x1b\
+    \n\torigin_id\x18\x05\x20\x01(\x04R\x08originId\x12\x1f\n\x0borigin_name\
+    \x18\x06\x20\x01(\tR\noriginName\x12\x19\n\x08owner_id\x18\x07\x20\x01(\
+    \x04R\x07ownerId\"\xec\x01\n\x1dAccountOriginInvitationCreate\x120\n\x14\
+    origin_invitation_id\x18\x01\x20\x01(\x04R\x12originInvitationId\x12\x1d\
+    \n\naccount_id\x18\x02\x20\x01(\x04R\taccountId\x12!\n\x0caccount_name\
+    \x18\x03\x20\x01(\tR\x0baccountName\x12\x1b\n\torigin_id\x18\x04\x20\x01\
+    (\x04R\x08originId\x12\x1f\n\x0borigin_name\x18\x05\x20\x01(\tR\noriginN\
+    ame\x12\x19\n\x08owner_id\x18\x06\x20\x01(\x04R\x07ownerId\"\x9b\x01\n$A\
+    ccountOriginInvitationAcceptRequest\x12\x1d\n\naccount_id\x18\x01\x20\
+    \x01(\x04R\taccountId\x12\x1b\n\tinvite_id\x18\x02\x20\x01(\x04R\x08invi\
+    teId\x12\x1f\n\x0borigin_name\x18\x03\x20\x01(\tR\noriginName\x12\x16\n\
+    \x06ignore\x18\x04\x20\x01(\x08R\x06ignore\"j\n$AccountOriginInvitationI\
+    gnoreRequest\x12\x1d\n\naccount_id\x18\x01\x20\x01(\x04R\taccountId\x12#\
+    \n\rinvitation_id\x18\x02\x20\x01(\x04R\x0cinvitationId\"k\n%AccountOrig\
+    inInvitationRescindRequest\x12\x1d\n\naccount_id\x18\x01\x20\x01(\x04R\t\
+    accountId\x12#\n\rinvitation_id\x18\x02\x20\x01(\x04R\x0cinvitationId\"=\
+    \n\x1cAccountInvitationListRequest\x12\x1d\n\naccount_id\x18\x01\x20\x01\
+    (\x04R\taccountId\"\x85\x01\n\x1dAccountInvitationListResponse\x12\x1d\n\
+    \naccount_id\x18\x01\x20\x01(\x04R\taccountId\x12E\n\x0binvitations\x18\
+    \x02\x20\x03(\x0b2#.sessionsrv.AccountOriginInvitationR\x0binvitations\"\
+    \x95\x01\n\x13AccountOriginCreate\x12\x1d\n\naccount_id\x18\x01\x20\x01(\
+    \x04R\taccountId\x12!\n\x0caccount_name\x18\x02\x20\x01(\tR\x0baccountNa\
+    me\x12\x1b\n\torigin_id\x18\x03\x20\x01(\x04R\x08originId\x12\x1f\n\x0bo\
+    rigin_name\x18\x04\x20\x01(\tR\noriginName\"9\n\x18AccountOriginListRequ\
+    est\x12\x1d\n\naccount_id\x18\x01\x20\x01(\x04R\taccountId\"T\n\x19Accou\
+    ntOriginListResponse\x12\x1d\n\naccount_id\x18\x01\x20\x01(\x04R\taccoun\
+    tId\x12\x18\n\x07origins\x18\x02\x20\x03(\tR\x07origins\"U\n\x13AccountO\
+    riginRemove\x12!\n\x0caccount_name\x18\x01\x20\x01(\tR\x0baccountName\
+    \x12\x1b\n\torigin_id\x18\x02\x20\x01(\x04R\x08originId\"\xcc\x01\n\x07S\
+    ession\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\x14\n\x05email\
+    \x18\x02\x20\x01(\tR\x05email\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04n\
+    ame\x12\x14\n\x05token\x18\x04\x20\x01(\tR\x05token\x12\x14\n\x05flags\
+    \x18\x05\x20\x01(\rR\x05flags\x12\x1f\n\x0boauth_token\x18\x06\x20\x01(\
+    \tR\noauthToken\x12:\n\x0caccess_token\x18\x07\x20\x01(\x0b2\x17.session\
+    srv.AccessTokenR\x0baccessToken\"\x81\x02\n\rSessionCreate\x12\x14\n\x05\
+    token\x18\x01\x20\x01(\tR\x05token\x12\x1b\n\textern_id\x18\x02\x20\x01(\
+    \rR\x08externId\x12\x14\n\x05email\x18\x03\x20\x01(\tR\x05email\x12\x12\
+    \n\x04name\x18\x04\x20\x01(\tR\x04name\x125\n\x08provider\x18\x05\x20\
+    \x01(\x0e2\x19.sessionsrv.OAuthProviderR\x08provider\x12\x1b\n\tapp_toke\
+    n\x18\x06\x20\x01(\tR\x08appToken\x12\x18\n\x07subject\x18\x07\x20\x01(\
+    \tR\x07subject\x12%\n\x0eemail_verified\x18\x08\x20\x01(\x08R\remailVeri\
+    fied\"<\n\nSessionGet\x12.\n\x05token\x18\x01\x20\x01(\x0b2\x18.sessions\
+    rv.SessionTokenR\x05token\"\x97\x01\n\x0cSessionToken\x12\x1d\n\naccount\
+    _id\x18\x01\x20\x01(\x04R\taccountId\x12\x1b\n\textern_id\x18\x02\x20\
+    \x01(\rR\x08externId\x125\n\x08provider\x18\x03\x20\x01(\x0e2\x19.sessio\
+    nsrv.OAuthProviderR\x08provider\x12\x14\n\x05token\x18\x04\x20\x01(\x0cR\
+    \x05token\"\xf4\x01\n\x0bAccessToken\x12\x0e\n\x02id\x18\x01\x20\x01(\
+    \x04R\x02id\x12\x1d\n\naccount_id\x18\x02\x20\x01(\x04R\taccountId\x12\
+    \x12\n\x04name\x18\x03\x20\x01(\tR\x04name\x12\x18\n\x07origins\x18\x04\
+    \x20\x03(\tR\x07origins\x124\n\x06scopes\x18\x05\x20\x03(\x0e2\x1c.sessi\
+    onsrv.AccessTokenScopeR\x06scopes\x12\x1d\n\nexpires_at\x18\x06\x20\x01(\
+    \x04R\texpiresAt\x12\x1d\n\ncreated_at\x18\x07\x20\x01(\x04R\tcreatedAt\
+    \x12\x14\n\x05token\x18\x08\x20\x01(\tR\x05token\"\xb5\x01\n\x11AccessTo\
+    kenCreate\x12\x1d\n\naccount_id\x18\x01\x20\x01(\x04R\taccountId\x12\x12\
+    \n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x18\n\x07origins\x18\x03\x20\
+    \x03(\tR\x07origins\x124\n\x06scopes\x18\x04\x20\x03(\x0e2\x1c.sessionsr\
+    v.AccessTokenScopeR\x06scopes\x12\x1d\n\nexpires_at\x18\x05\x20\x01(\x04\
+    R\texpiresAt\"7\n\x16AccessTokenListRequest\x12\x1d\n\naccount_id\x18\
+    \x01\x20\x01(\x04R\taccountId\"i\n\x17AccessTokenListResponse\x12\x1d\n\
+    \naccount_id\x18\x01\x20\x01(\x04R\taccountId\x12/\n\x06tokens\x18\x02\
+    \x20\x03(\x0b2\x17.sessionsrv.AccessTokenR\x06tokens\"B\n\x11AccessToken\
+    Revoke\x12\x1d\n\naccount_id\x18\x01\x20\x01(\x04R\taccountId\x12\x0e\n\
+    \x02id\x18\x02\x20\x01(\x04R\x02id\"W\n\x0eAccessTokenKey\x12\x1d\n\nacc\
+    ount_id\x18\x01\x20\x01(\x04R\taccountId\x12\x0e\n\x02id\x18\x02\x20\x01\
+    (\x04R\x02id\x12\x16\n\x06secret\x18\x03\x20\x01(\x0cR\x06secret\"C\n\
+    \x13AccessTokenValidate\x12,\n\x03key\x18\x01\x20\x01(\x0b2\x1a.sessions\
+    rv.AccessTokenKeyR\x03key*%\n\rOAuthProvider\x12\n\n\x06GitHub\x10\0\x12\
+    \x08\n\x04OIDC\x10\x01*<\n\x10AccessTokenScope\x12\n\n\x06Upload\x10\0\
+    \x12\x0b\n\x07Promote\x10\x01\x12\x0f\n\x0bKeyDownload\x10\x02J\xa6@\n\
+    \x07\x12\x05\0\0\xcf\x01\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\
+    \x02\x12\x03\x01\x08\x12\n\n\n\x02\x05\0\x12\x04\x03\0\x06\x01\n\n\n\x03\
+    \x05\0\x01\x12\x03\x03\x05\x12\n\x0b\n\x04\x05\0\x02\0\x12\x03\x04\x02\r\
+    \n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x04\x02\x08\n\x0c\n\x05\x05\0\x02\0\
+    \x02\x12\x03\x04\x0b\x0c\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x05\x02\x0b\n\
+    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x05\x02\x06\n\x0c\n\x05\x05\0\x02\
+    \x01\x02\x12\x03\x05\t\n\n\n\n\x02\x04\0\x12\x04\x08\0\x0c\x01\n\n\n\x03\
+    \x04\0\x01\x12\x03\x08\x08\x0f\n\x0b\n\x04\x04\0\x02\0\x12\x03\t\x02\x19\
+    \n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\t\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\
+    \x12\x03\t\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\t\x12\x14\n\x0c\n\
+    \x05\x04\0\x02\0\x03\x12\x03\t\x17\x18\n\x0b\n\x04\x04\0\x02\x01\x12\x03\
+    \n\x02\x1c\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\n\x02\n\n\x0c\n\x05\x04\
+    \0\x02\x01\x05\x12\x03\n\x0b\x11\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\n\
+    \x12\x17\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\n\x1a\x1b\n\x0b\n\x04\x04\
+    \0\x02\x02\x12\x03\x0b\x02\x1b\n\x0c\n\x05\x04\0\x02\x02\x04\x12\x03\x0b\
+    \x02\n\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x0b\x0b\x11\n\x0c\n\x05\x04\
+    \0\x02\x02\x01\x12\x03\x0b\x12\x16\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\
+    \x0b\x19\x1a\n\n\n\x02\x04\x01\x12\x04\x0e\0\x11\x01\n\n\n\x03\x04\x01\
+    \x01\x12\x03\x0e\x08\x1b\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0f\x02\x1b\n\
+    \x0c\n\x05\x04\x01\x02\0\x04\x12\x03\x0f\x02\n\n\x0c\n\x05\x04\x01\x02\0\
+    \x05\x12\x03\x0f\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0f\x12\
+    \x16\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0f\x19\x1a\n\x0b\n\x04\x04\
+    \x01\x02\x01\x12\x03\x10\x02\x1c\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03\
+    \x10\x02\n\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\x10\x0b\x11\n\x0c\n\
+    \x05\x04\x01\x02\x01\x01\x12\x03\x10\x12\x17\n\x0c\n\x05\x04\x01\x02\x01\
+    \x03\x12\x03\x10\x1a\x1b\n+\n\x02\x04\x02\x12\x04\x14\0\x16\x01\x1a\x1f\
+    \x20get\x20an\x20account\x20by\x20GH\x20username\n\n\n\n\x03\x04\x02\x01\
+    \x12\x03\x14\x08\x12\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x15\x02\x1b\n\x0c\
+    \n\x05\x04\x02\x02\0\x04\x12\x03\x15\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\
+    \x12\x03\x15\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x15\x12\x16\n\
+    \x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x15\x19\x1a\n\n\n\x02\x04\x03\x12\
+    \x04\x18\0\x1a\x01\n\n\n\x03\x04\x03\x01\x12\x03\x18\x08\x14\n\x0b\n\x04\
+    \x04\x03\x02\0\x12\x03\x19\x02\x19\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\
+    \x19\x02\n\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x19\x0b\x11\n\x0c\n\x05\
+    \x04\x03\x02\0\x01\x12\x03\x19\x12\x14\n\x0c\n\x05\x04\x03\x02\0\x03\x12\
+    \x03\x19\x17\x18\n\n\n\x02\x04\x04\x12\x04\x1c\0\x1f\x01\n\n\n\x03\x04\
+    \x04\x01\x12\x03\x1c\x08\x15\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1d\x02\
+    \x1b\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x03\x1d\x02\n\n\x0c\n\x05\x04\x04\
+    \x02\0\x05\x12\x03\x1d\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1d\
+    \x12\x16\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1d\x19\x1a\n\x0b\n\x04\
+    \x04\x04\x02\x01\x12\x03\x1e\x02\x1c\n\x0c\n\x05\x04\x04\x02\x01\x04\x12\
+    \x03\x1e\x02\n\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\x1e\x0b\x11\n\x0c\
+    \n\x05\x04\x04\x02\x01\x01\x12\x03\x1e\x12\x17\n\x0c\n\x05\x04\x04\x02\
+    \x01\x03\x12\x03\x1e\x1a\x1b\n\n\n\x02\x04\x05\x12\x04!\0$\x01\n\n\n\x03\
+    \x04\x05\x01\x12\x03!\x08\x15\n\x0b\n\x04\x04\x05\x02\0\x12\x03\"\x02\
+    \x19\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03\"\x02\n\n\x0c\n\x05\x04\x05\
+    \x02\0\x05\x12\x03\"\x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\"\x12\
+    \x14\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\"\x17\x18\n\x0b\n\x04\x04\x05\
+    \x02\x01\x12\x03#\x02\x1c\n\x0c\n\x05\x04\x05\x02\x01\x04\x12\x03#\x02\n\
+    \n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x03#\x0b\x11\n\x0c\n\x05\x04\x05\
+    \x02\x01\x01\x12\x03#\x12\x17\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03#\
+    \x1a\x1b\nQ\n\x02\x04\x06\x12\x04'\0+\x01\x1aE\x20links\x20a\x20user\x20\
+    at\x20an\x20identity\x20provider\x20to\x20the\x20account\x20they\x20sign\
+    \x20in\x20as\n\n\n\n\x03\x04\x06\x01\x12\x03'\x08\x17\n\x0b\n\x04\x04\
+    \x06\x02\0\x12\x03(\x02&\n\x0c\n\x05\x04\x06\x02\0\x04\x12\x03(\x02\n\n\
+    \x0c\n\x05\x04\x06\x02\0\x06\x12\x03(\x0b\x18\n\x0c\n\x05\x04\x06\x02\0\
+    \x01\x12\x03(\x19!\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03($%\n\x0b\n\x04\
+    \x04\x06\x02\x01\x12\x03)\x02\x1e\n\x0c\n\x05\x04\x06\x02\x01\x04\x12\
+    \x03)\x02\n\n\x0c\n\x05\x04\x06\x02\x01\x05\x12\x03)\x0b\x11\n\x0c\n\x05\
+    \x04\x06\x02\x01\x01\x12\x03)\x12\x19\n\x0c\n\x05\x04\x06\x02\x01\x03\
+    \x12\x03)\x1c\x1d\n\x0b\n\x04\x04\x06\x02\x02\x12\x03*\x02!\n\x0c\n\x05\
+    \x04\x06\x02\x02\x04\x12\x03*\x02\n\n\x0c\n\x05\x04\x06\x02\x02\x05\x12\
+    \x03*\x0b\x11\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03*\x12\x1c\n\x0c\n\
+    \x05\x04\x06\x02\x02\x03\x12\x03*\x1f\x20\n\n\n\x02\x04\x07\x12\x04-\01\
+    \x01\n\n\n\x03\x04\x07\x01\x12\x03-\x08\x1d\n\x0b\n\x04\x04\x07\x02\0\
+    \x12\x03.\x02&\n\x0c\n\x05\x04\x07\x02\0\x04\x12\x03.\x02\n\n\x0c\n\x05\
+    \x04\x07\x02\0\x06\x12\x03.\x0b\x18\n\x0c\n\x05\x04\x07\x02\0\x01\x12\
+    \x03.\x19!\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03.$%\n\x0b\n\x04\x04\x07\
+    \x02\x01\x12\x03/\x02\x1e\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x03/\x02\n\
+    \n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03/\x0b\x11\n\x0c\n\x05\x04\x07\
+    \x02\x01\x01\x12\x03/\x12\x19\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x03/\
+    \x1c\x1d\n\x0b\n\x04\x04\x07\x02\x02\x12\x030\x02!\n\x0c\n\x05\x04\x07\
+    \x02\x02\x04\x12\x030\x02\n\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\x030\x0b\
+    \x11\n\x0c\n\x05\x04\x07\x02\x02\x01\x12\x030\x12\x1c\n\x0c\n\x05\x04\
+    \x07\x02\x02\x03\x12\x030\x1f\x20\n\n\n\x02\x04\x08\x12\x043\06\x01\n\n\
+    \n\x03\x04\x08\x01\x12\x033\x08\x1a\n\x0b\n\x04\x04\x08\x02\0\x12\x034\
+    \x02&\n\x0c\n\x05\x04\x08\x02\0\x04\x12\x034\x02\n\n\x0c\n\x05\x04\x08\
+    \x02\0\x06\x12\x034\x0b\x18\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x034\x19!\
+    \n\x0c\n\x05\x04\x08\x02\0\x03\x12\x034$%\n\x0b\n\x04\x04\x08\x02\x01\
+    \x12\x035\x02\x1e\n\x0c\n\x05\x04\x08\x02\x01\x04\x12\x035\x02\n\n\x0c\n\
+    \x05\x04\x08\x02\x01\x05\x12\x035\x0b\x11\n\x0c\n\x05\x04\x08\x02\x01\
+    \x01\x12\x035\x12\x19\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x035\x1c\x1d\n\
+    \n\n\x02\x04\t\x12\x048\0@\x01\n\n\n\x03\x04\t\x01\x12\x038\x08\x1f\n\
+    \x0b\n\x04\x04\t\x02\0\x12\x039\x02\x19\n\x0c\n\x05\x04\t\x02\0\x04\x12\
+    \x039\x02\n\n\x0c\n\x05\x04\t\x02\0\x05\x12\x039\x0b\x11\n\x0c\n\x05\x04\
+    \t\x02\0\x01\x12\x039\x12\x14\n\x0c\n\x05\x04\t\x02\0\x03\x12\x039\x17\
+    \x18\n\x0b\n\x04\x04\t\x02\x01\x12\x03:\x02+\n\x0c\n\x05\x04\t\x02\x01\
+    \x04\x12\x03:\x02\n\n\x0c\n\x05\x04\t\x02\x01\x05\x12\x03:\x0b\x11\n\x0c\
+    \n\x05\x04\t\x02\x01\x01\x12\x03:\x12&\n\x0c\n\x05\x04\t\x02\x01\x03\x12\
+    \x03:)*\n\x0b\n\x04\x04\t\x02\x02\x12\x03;\x02!\n\x0c\n\x05\x04\t\x02\
+    \x02\x04\x12\x03;\x02\n\n\x0c\n\x05\x04\t\x02\x02\x05\x12\x03;\x0b\x11\n\
+    \x0c\n\x05\x04\t\x02\x02\x01\x12\x03;\x12\x1c\n\x0c\n\x05\x04\t\x02\x02\
+    \x03\x12\x03;\x1f\x20\n\x0b\n\x04\x04\t\x02\x03\x12\x03<\x02#\n\x0c\n\
+    \x05\x04\t\x02\x03\x04\x12\x03<\x02\n\n\x0c\n\x05\x04\t\x02\x03\x05\x12\
+    \x03<\x0b\x11\n\x0c\n\x05\x04\t\x02\x03\x01\x12\x03<\x12\x1e\n\x0c\n\x05\
+    \x04\t\x02\x03\x03\x12\x03<!\"\n\x0b\n\x04\x04\t\x02\x04\x12\x03=\x02\
+    \x20\n\x0c\n\x05\x04\t\x02\x04\x04\x12\x03=\x02\n\n\x0c\n\x05\x04\t\x02\
+    \x04\x05\x12\x03=\x0b\x11\n\x0c\n\x05\x04\t\x02\x04\x01\x12\x03=\x12\x1b\
+    \n\x0c\n\x05\x04\t\x02\x04\x03\x12\x03=\x1e\x1f\n\x0b\n\x04\x04\t\x02\
+    \x05\x12\x03>\x02\"\n\x0c\n\x05\x04\t\x02\x05\x04\x12\x03>\x02\n\n\x0c\n\
+    \x05\x04\t\x02\x05\x05\x12\x03>\x0b\x11\n\x0c\n\x05\x04\t\x02\x05\x01\
+    \x12\x03>\x12\x1d\n\x0c\n\x05\x04\t\x02\x05\x03\x12\x03>\x20!\n\x0b\n\
+    \x04\x04\t\x02\x06\x12\x03?\x02\x1f\n\x0c\n\x05\x04\t\x02\x06\x04\x12\
+    \x03?\x02\n\n\x0c\n\x05\x04\t\x02\x06\x05\x12\x03?\x0b\x11\n\x0c\n\x05\
+    \x04\t\x02\x06\x01\x12\x03?\x12\x1a\n\x0c\n\x05\x04\t\x02\x06\x03\x12\
+    \x03?\x1d\x1e\n\n\n\x02\x04\n\x12\x04B\0I\x01\n\n\n\x03\x04\n\x01\x12\
+    \x03B\x08%\n\x0b\n\x04\x04\n\x02\0\x12\x03C\x02+\n\x0c\n\x05\x04\n\x02\0\
+    \x04\x12\x03C\x02\n\n\x0c\n\x05\x04\n\x02\0\x05\x12\x03C\x0b\x11\n\x0c\n\
+    \x05\x04\n\x02\0\x01\x12\x03C\x12&\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03C)\
+    *\n\x0b\n\x04\x04\n\x02\x01\x12\x03D\x02!\n\x0c\n\x05\x04\n\x02\x01\x04\
+    \x12\x03D\x02\n\n\x0c\n\x05\x04\n\x02\x01\x05\x12\x03D\x0b\x11\n\x0c\n\
+    \x05\x04\n\x02\x01\x01\x12\x03D\x12\x1c\n\x0c\n\x05\x04\n\x02\x01\x03\
+    \x12\x03D\x1f\x20\n\x0b\n\x04\x04\n\x02\x02\x12\x03E\x02#\n\x0c\n\x05\
+    \x04\n\x02\x02\x04\x12\x03E\x02\n\n\x0c\n\x05\x04\n\x02\x02\x05\x12\x03E\
+    \x0b\x11\n\x0c\n\x05\x04\n\x02\x02\x01\x12\x03E\x12\x1e\n\x0c\n\x05\x04\
+    \n\x02\x02\x03\x12\x03E!\"\n\x0b\n\x04\x04\n\x02\x03\x12\x03F\x02\x20\n\
+    \x0c\n\x05\x04\n\x02\x03\x04\x12\x03F\x02\n\n\x0c\n\x05\x04\n\x02\x03\
+    \x05\x12\x03F\x0b\x11\n\x0c\n\x05\x04\n\x02\x03\x01\x12\x03F\x12\x1b\n\
+    \x0c\n\x05\x04\n\x02\x03\x03\x12\x03F\x1e\x1f\n\x0b\n\x04\x04\n\x02\x04\
+    \x12\x03G\x02\"\n\x0c\n\x05\x04\n\x02\x04\x04\x12\x03G\x02\n\n\x0c\n\x05\
+    \x04\n\x02\x04\x05\x12\x03G\x0b\x11\n\x0c\n\x05\x04\n\x02\x04\x01\x12\
+    \x03G\x12\x1d\n\x0c\n\x05\x04\n\x02\x04\x03\x12\x03G\x20!\n\x0b\n\x04\
+    \x04\n\x02\x05\x12\x03H\x02\x1f\n\x0c\n\x05\x04\n\x02\x05\x04\x12\x03H\
+    \x02\n\n\x0c\n\x05\x04\n\x02\x05\x05\x12\x03H\x0b\x11\n\x0c\n\x05\x04\n\
+    \x02\x05\x01\x12\x03H\x12\x1a\n\x0c\n\x05\x04\n\x02\x05\x03\x12\x03H\x1d\
+    \x1e\n\n\n\x02\x04\x0b\x12\x04K\0P\x01\n\n\n\x03\x04\x0b\x01\x12\x03K\
+    \x08,\n\x0b\n\x04\x04\x0b\x02\0\x12\x03L\x02!\n\x0c\n\x05\x04\x0b\x02\0\
+    \x04\x12\x03L\x02\n\n\x0c\n\x05\x04\x0b\x02\0\x05\x12\x03L\x0b\x11\n\x0c\
+    \n\x05\x04\x0b\x02\0\x01\x12\x03L\x12\x1c\n\x0c\n\x05\x04\x0b\x02\0\x03\
+    \x12\x03L\x1f\x20\n\x0b\n\x04\x04\x0b\x02\x01\x12\x03M\x02\x20\n\x0c\n\
+    \x05\x04\x0b\x02\x01\x04\x12\x03M\x02\n\n\x0c\n\x05\x04\x0b\x02\x01\x05\
+    \x12\x03M\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03M\x12\x1b\n\x0c\
+    \n\x05\x04\x0b\x02\x01\x03\x12\x03M\x1e\x1f\n\x0b\n\x04\x04\x0b\x02\x02\
+    \x12\x03N\x02\"\n\x0c\n\x05\x04\x0b\x02\x02\x04\x12\x03N\x02\n\n\x0c\n\
+    \x05\x04\x0b\x02\x02\x05\x12\x03N\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x02\
+    \x01\x12\x03N\x12\x1d\n\x0c\n\x05\x04\x0b\x02\x02\x03\x12\x03N\x20!\n\
+    \x0b\n\x04\x04\x0b\x02\x03\x12\x03O\x02\x1b\n\x0c\n\x05\x04\x0b\x02\x03\
+    \x04\x12\x03O\x02\n\n\x0c\n\x05\x04\x0b\x02\x03\x05\x12\x03O\x0b\x0f\n\
+    \x0c\n\x05\x04\x0b\x02\x03\x01\x12\x03O\x10\x16\n\x0c\n\x05\x04\x0b\x02\
+    \x03\x03\x12\x03O\x19\x1a\n\n\n\x02\x04\x0c\x12\x04R\0U\x01\n\n\n\x03\
+    \x04\x0c\x01\x12\x03R\x08,\n\x0b\n\x04\x04\x0c\x02\0\x12\x03S\x02!\n\x0c\
+    \n\x05\x04\x0c\x02\0\x04\x12\x03S\x02\n\n\x0c\n\x05\x04\x0c\x02\0\x05\
+    \x12\x03S\x0b\x11\n\x0c\n\x05\x04\x0c\x02\0\x01\x12\x03S\x12\x1c\n\x0c\n\
+    \x05\x04\x0c\x02\0\x03\x12\x03S\x1f\x20\n\x0b\n\x04\x04\x0c\x02\x01\x12\
+    \x03T\x02$\n\x0c\n\x05\x04\x0c\x02\x01\x04\x12\x03T\x02\n\n\x0c\n\x05\
+    \x04\x0c\x02\x01\x05\x12\x03T\x0b\x11\n\x0c\n\x05\x04\x0c\x02\x01\x01\
+    \x12\x03T\x12\x1f\n\x0c\n\x05\x04\x0c\x02\x01\x03\x12\x03T\"#\n\n\n\x02\
+    \x04\r\x12\x04W\0Z\x01\n\n\n\x03\x04\r\x01\x12\x03W\x08-\n\x0b\n\x04\x04\
+    \r\x02\0\x12\x03X\x02!\n\x0c\n\x05\x04\r\x02\0\x04\x12\x03X\x02\n\n\x0c\
+    \n\x05\x04\r\x02\0\x05\x12\x03X\x0b\x11\n\x0c\n\x05\x04\r\x02\0\x01\x12\
+    \x03X\x12\x1c\n\x0c\n\x05\x04\r\x02\0\x03\x12\x03X\x1f\x20\n\x0b\n\x04\
+    \x04\r\x02\x01\x12\x03Y\x02$\n\x0c\n\x05\x04\r\x02\x01\x04\x12\x03Y\x02\
+    \n\n\x0c\n\x05\x04\r\x02\x01\x05\x12\x03Y\x0b\x11\n\x0c\n\x05\x04\r\x02\
+    \x01\x01\x12\x03Y\x12\x1f\n\x0c\n\x05\x04\r\x02\x01\x03\x12\x03Y\"#\n\n\
+    \n\x02\x04\x0e\x12\x04\\\0^\x01\n\n\n\x03\x04\x0e\x01\x12\x03\\\x08$\n\
+    \x0b\n\x04\x04\x0e\x02\0\x12\x03]\x02!\n\x0c\n\x05\x04\x0e\x02\0\x04\x12\
+    \x03]\x02\n\n\x0c\n\x05\x04\x0e\x02\0\x05\x12\x03]\x0b\x11\n\x0c\n\x05\
+    \x04\x0e\x02\0\x01\x12\x03]\x12\x1c\n\x0c\n\x05\x04\x0e\x02\0\x03\x12\
+    \x03]\x1f\x20\n\n\n\x02\x04\x0f\x12\x04`\0c\x01\n\n\n\x03\x04\x0f\x01\
+    \x12\x03`\x08%\n\x0b\n\x04\x04\x0f\x02\0\x12\x03a\x02!\n\x0c\n\x05\x04\
+    \x0f\x02\0\x04\x12\x03a\x02\n\n\x0c\n\x05\x04\x0f\x02\0\x05\x12\x03a\x0b\
+    \x11\n\x0c\n\x05\x04\x0f\x02\0\x01\x12\x03a\x12\x1c\n\x0c\n\x05\x04\x0f\
+    \x02\0\x03\x12\x03a\x1f\x20\n\x0b\n\x04\x04\x0f\x02\x01\x12\x03b\x023\n\
+    \x0c\n\x05\x04\x0f\x02\x01\x04\x12\x03b\x02\n\n\x0c\n\x05\x04\x0f\x02\
+    \x01\x06\x12\x03b\x0b\"\n\x0c\n\x05\x04\x0f\x02\x01\x01\x12\x03b#.\n\x0c\
+    \n\x05\x04\x0f\x02\x01\x03\x12\x03b12\n\n\n\x02\x04\x10\x12\x04e\0j\x01\
+    \n\n\n\x03\x04\x10\x01\x12\x03e\x08\x1b\n\x0b\n\x04\x04\x10\x02\0\x12\
+    \x03f\x02!\n\x0c\n\x05\x04\x10\x02\0\x04\x12\x03f\x02\n\n\x0c\n\x05\x04\
+    \x10\x02\0\x05\x12\x03f\x0b\x11\n\x0c\n\x05\x04\x10\x02\0\x01\x12\x03f\
+    \x12\x1c\n\x0c\n\x05\x04\x10\x02\0\x03\x12\x03f\x1f\x20\n\x0b\n\x04\x04\
+    \x10\x02\x01\x12\x03g\x02#\n\x0c\n\x05\x04\x10\x02\x01\x04\x12\x03g\x02\
+    \n\n\x0c\n\x05\x04\x10\x02\x01\x05\x12\x03g\x0b\x11\n\x0c\n\x05\x04\x10\
+    \x02\x01\x01\x12\x03g\x12\x1e\n\x0c\n\x05\x04\x10\x02\x01\x03\x12\x03g!\
+    \"\n\x0b\n\x04\x04\x10\x02\x02\x12\x03h\x02\x20\n\x0c\n\x05\x04\x10\x02\
+    \x02\x04\x12\x03h\x02\n\n\x0c\n\x05\x04\x10\x02\x02\x05\x12\x03h\x0b\x11\
+    \n\x0c\n\x05\x04\x10\x02\x02\x01\x12\x03h\x12\x1b\n\x0c\n\x05\x04\x10\
+    \x02\x02\x03\x12\x03h\x1e\x1f\n\x0b\n\x04\x04\x10\x02\x03\x12\x03i\x02\"\
+    \n\x0c\n\x05\x04\x10\x02\x03\x04\x12\x03i\x02\n\n\x0c\n\x05\x04\x10\x02\
+    \x03\x05\x12\x03i\x0b\x11\n\x0c\n\x05\x04\x10\x02\x03\x01\x12\x03i\x12\
+    \x1d\n\x0c\n\x05\x04\x10\x02\x03\x03\x12\x03i\x20!\n\n\n\x02\x04\x11\x12\
+    \x04l\0n\x01\n\n\n\x03\x04\x11\x01\x12\x03l\x08\x20\n\x0b\n\x04\x04\x11\
+    \x02\0\x12\x03m\x02!\n\x0c\n\x05\x04\x11\x02\0\x04\x12\x03m\x02\n\n\x0c\
+    \n\x05\x04\x11\x02\0\x05\x12\x03m\x0b\x11\n\x0c\n\x05\x04\x11\x02\0\x01\
+    \x12\x03m\x12\x1c\n\x0c\n\x05\x04\x11\x02\0\x03\x12\x03m\x1f\x20\n\n\n\
+    \x02\x04\x12\x12\x04p\0s\x01\n\n\n\x03\x04\x12\x01\x12\x03p\x08!\n\x0b\n\
+    \x04\x04\x12\x02\0\x12\x03q\x02!\n\x0c\n\x05\x04\x12\x02\0\x04\x12\x03q\
+    \x02\n\n\x0c\n\x05\x04\x12\x02\0\x05\x12\x03q\x0b\x11\n\x0c\n\x05\x04\
+    \x12\x02\0\x01\x12\x03q\x12\x1c\n\x0c\n\x05\x04\x12\x02\0\x03\x12\x03q\
+    \x1f\x20\n\x0b\n\x04\x04\x12\x02\x01\x12\x03r\x02\x1e\n\x0c\n\x05\x04\
+    \x12\x02\x01\x04\x12\x03r\x02\n\n\x0c\n\x05\x04\x12\x02\x01\x05\x12\x03r\
+    \x0b\x11\n\x0c\n\x05\x04\x12\x02\x01\x01\x12\x03r\x12\x19\n\x0c\n\x05\
+    \x04\x12\x02\x01\x03\x12\x03r\x1c\x1d\n\n\n\x02\x04\x13\x12\x04u\0x\x01\
+    \n\n\n\x03\x04\x13\x01\x12\x03u\x08\x1b\n\x0b\n\x04\x04\x13\x02\0\x12\
+    \x03v\x02#\n\x0c\n\x05\x04\x13\x02\0\x04\x12\x03v\x02\n\n\x0c\n\x05\x04\
+    \x13\x02\0\x05\x12\x03v\x0b\x11\n\x0c\n\x05\x04\x13\x02\0\x01\x12\x03v\
+    \x12\x1e\n\x0c\n\x05\x04\x13\x02\0\x03\x12\x03v!\"\n\x0b\n\x04\x04\x13\
+    \x02\x01\x12\x03w\x02\x20\n\x0c\n\x05\x04\x13\x02\x01\x04\x12\x03w\x02\n\
+    \n\x0c\n\x05\x04\x13\x02\x01\x05\x12\x03w\x0b\x11\n\x0c\n\x05\x04\x13\
+    \x02\x01\x01\x12\x03w\x12\x1b\n\x0c\n\x05\x04\x13\x02\x01\x03\x12\x03w\
+    \x1e\x1f\n\x0b\n\x02\x04\x14\x12\x05z\0\x84\x01\x01\n\n\n\x03\x04\x14\
+    \x01\x12\x03z\x08\x0f\n\x0b\n\x04\x04\x14\x02\0\x12\x03{\x02\x19\n\x0c\n\
+    \x05\x04\x14\x02\0\x04\x12\x03{\x02\n\n\x0c\n\x05\x04\x14\x02\0\x05\x12\
+    \x03{\x0b\x11\n\x0c\n\x05\x04\x14\x02\0\x01\x12\x03{\x12\x14\n\x0c\n\x05\
+    \x04\x14\x02\0\x03\x12\x03{\x17\x18\n\x0b\n\x04\x04\x14\x02\x01\x12\x03|\
+    \x02\x1c\n\x0c\n\x05\x04\x14\x02\x01\x04\x12\x03|\x02\n\n\x0c\n\x05\x04\
+    \x14\x02\x01\x05\x12\x03|\x0b\x11\n\x0c\n\x05\x04\x14\x02\x01\x01\x12\
+    \x03|\x12\x17\n\x0c\n\x05\x04\x14\x02\x01\x03\x12\x03|\x1a\x1b\n\x0b\n\
+    \x04\x04\x14\x02\x02\x12\x03}\x02\x1b\n\x0c\n\x05\x04\x14\x02\x02\x04\
+    \x12\x03}\x02\n\n\x0c\n\x05\x04\x14\x02\x02\x05\x12\x03}\x0b\x11\n\x0c\n\
+    \x05\x04\x14\x02\x02\x01\x12\x03}\x12\x16\n\x0c\n\x05\x04\x14\x02\x02\
+    \x03\x12\x03}\x19\x1a\nC\n\x04\x04\x14\x02\x03\x12\x03\x7f\x02\x1c\x1a6\
+    \x20base64\x20encoded\x20binary\x20representation\x20of\x20SessionToken\
+    \n\n\x0c\n\x05\x04\x14\x02\x03\x04\x12\x03\x7f\x02\n\n\x0c\n\x05\x04\x14\
+    \x02\x03\x05\x12\x03\x7f\x0b\x11\n\x0c\n\x05\x04\x14\x02\x03\x01\x12\x03\
+    \x7f\x12\x17\n\x0c\n\x05\x04\x14\x02\x03\x03\x12\x03\x7f\x1a\x1b\n\x0c\n\
+    \x04\x04\x14\x02\x04\x12\x04\x80\x01\x02\x1c\n\r\n\x05\x04\x14\x02\x04\
+    \x04\x12\x04\x80\x01\x02\n\n\r\n\x05\x04\x14\x02\x04\x05\x12\x04\x80\x01\
+    \x0b\x11\n\r\n\x05\x04\x14\x02\x04\x01\x12\x04\x80\x01\x12\x17\n\r\n\x05\
+    \x04\x14\x02\x04\x03\x12\x04\x80\x01\x1a\x1b\n\x0c\n\x04\x04\x14\x02\x05\
+    \x12\x04\x81\x01\x02\"\n\r\n\x05\x04\x14\x02\x05\x04\x12\x04\x81\x01\x02\
+    \n\n\r\n\x05\x04\x14\x02\x05\x05\x12\x04\x81\x01\x0b\x11\n\r\n\x05\x04\
+    \x14\x02\x05\x01\x12\x04\x81\x01\x12\x1d\n\r\n\x05\x04\x14\x02\x05\x03\
+    \x12\x04\x81\x01\x20!\nQ\n\x04\x04\x14\x02\x06\x12\x04\x83\x01\x02(\x1aC\
+    \x20set\x20when\x20the\x20session\x20was\x20established\x20with\x20a\x20\
+    personal\x20access\x20token\n\n\r\n\x05\x04\x14\x02\x06\x04\x12\x04\x83\
+    \x01\x02\n\n\r\n\x05\x04\x14\x02\x06\x06\x12\x04\x83\x01\x0b\x16\n\r\n\
+    \x05\x04\x14\x02\x06\x01\x12\x04\x83\x01\x17#\n\r\n\x05\x04\x14\x02\x06\
+    \x03\x12\x04\x83\x01&'\n\x0c\n\x02\x04\x15\x12\x06\x86\x01\0\x90\x01\x01\
+    \n\x0b\n\x03\x04\x15\x01\x12\x04\x86\x01\x08\x15\n\x0c\n\x04\x04\x15\x02\
+    \0\x12\x04\x87\x01\x02\x1c\n\r\n\x05\x04\x15\x02\0\x04\x12\x04\x87\x01\
+    \x02\n\n\r\n\x05\x04\x15\x02\0\x05\x12\x04\x87\x01\x0b\x11\n\r\n\x05\x04\
+    \x15\x02\0\x01\x12\x04\x87\x01\x12\x17\n\r\n\x05\x04\x15\x02\0\x03\x12\
+    \x04\x87\x01\x1a\x1b\n\x0c\n\x04\x04\x15\x02\x01\x12\x04\x88\x01\x02\x20\
+    \n\r\n\x05\x04\x15\x02\x01\x04\x12\x04\x88\x01\x02\n\n\r\n\x05\x04\x15\
+    \x02\x01\x05\x12\x04\x88\x01\x0b\x11\n\r\n\x05\x04\x15\x02\x01\x01\x12\
+    \x04\x88\x01\x12\x1b\n\r\n\x05\x04\x15\x02\x01\x03\x12\x04\x88\x01\x1e\
+    \x1f\n\x0c\n\x04\x04\x15\x02\x02\x12\x04\x89\x01\x02\x1c\n\r\n\x05\x04\
+    \x15\x02\x02\x04\x12\x04\x89\x01\x02\n\n\r\n\x05\x04\x15\x02\x02\x05\x12\
+    \x04\x89\x01\x0b\x11\n\r\n\x05\x04\x15\x02\x02\x01\x12\x04\x89\x01\x12\
+    \x17\n\r\n\x05\x04\x15\x02\x02\x03\x12\x04\x89\x01\x1a\x1b\n\x0c\n\x04\
+    \x04\x15\x02\x03\x12\x04\x8a\x01\x02\x1b\n\r\n\x05\x04\x15\x02\x03\x04\
+    \x12\x04\x8a\x01\x02\n\n\r\n\x05\x04\x15\x02\x03\x05\x12\x04\x8a\x01\x0b\
+    \x11\n\r\n\x05\x04\x15\x02\x03\x01\x12\x04\x8a\x01\x12\x16\n\r\n\x05\x04\
+    \x15\x02\x03\x03\x12\x04\x8a\x01\x19\x1a\n\x0c\n\x04\x04\x15\x02\x04\x12\
+    \x04\x8b\x01\x02&\n\r\n\x05\x04\x15\x02\x04\x04\x12\x04\x8b\x01\x02\n\n\
+    \r\n\x05\x04\x15\x02\x04\x06\x12\x04\x8b\x01\x0b\x18\n\r\n\x05\x04\x15\
+    \x02\x04\x01\x12\x04\x8b\x01\x19!\n\r\n\x05\x04\x15\x02\x04\x03\x12\x04\
+    \x8b\x01$%\n\x0c\n\x04\x04\x15\x02\x05\x12\x04\x8c\x01\x02\x20\n\r\n\x05\
+    \x04\x15\x02\x05\x04\x12\x04\x8c\x01\x02\n\n\r\n\x05\x04\x15\x02\x05\x05\
+    \x12\x04\x8c\x01\x0b\x11\n\r\n\x05\x04\x15\x02\x05\x01\x12\x04\x8c\x01\
+    \x12\x1b\n\r\n\x05\x04\x15\x02\x05\x03\x12\x04\x8c\x01\x1e\x1f\n=\n\x04\
+    \x04\x15\x02\x06\x12\x04\x8e\x01\x02\x1e\x1a/\x20stable\x20identifier\
+    \x20of\x20the\x20user\x20at\x20the\x20provider\n\n\r\n\x05\x04\x15\x02\
+    \x06\x04\x12\x04\x8e\x01\x02\n\n\r\n\x05\x04\x15\x02\x06\x05\x12\x04\x8e\
+    \x01\x0b\x11\n\r\n\x05\x04\x15\x02\x06\x01\x12\x04\x8e\x01\x12\x19\n\r\n\
+    \x05\x04\x15\x02\x06\x03\x12\x04\x8e\x01\x1c\x1d\n\x0c\n\x04\x04\x15\x02\
+    \x07\x12\x04\x8f\x01\x02#\n\r\n\x05\x04\x15\x02\x07\x04\x12\x04\x8f\x01\
+    \x02\n\n\r\n\x05\x04\x15\x02\x07\x05\x12\x04\x8f\x01\x0b\x0f\n\r\n\x05\
+    \x04\x15\x02\x07\x01\x12\x04\x8f\x01\x10\x1e\n\r\n\x05\x04\x15\x02\x07\
+    \x03\x12\x04\x8f\x01!\"\n\x0c\n\x02\x04\x16\x12\x06\x92\x01\0\x94\x01\
+    \x01\n\x0b\n\x03\x04\x16\x01\x12\x04\x92\x01\x08\x12\n\x0c\n\x04\x04\x16\
+    \x02\0\x12\x04\x93\x01\x02\"\n\r\n\x05\x04\x16\x02\0\x04\x12\x04\x93\x01\
+    \x02\n\n\r\n\x05\x04\x16\x02\0\x06\x12\x04\x93\x01\x0b\x17\n\r\n\x05\x04\
+    \x16\x02\0\x01\x12\x04\x93\x01\x18\x1d\n\r\n\x05\x04\x16\x02\0\x03\x12\
+    \x04\x93\x01\x20!\n\x0c\n\x02\x04\x17\x12\x06\x96\x01\0\x9b\x01\x01\n\
+    \x0b\n\x03\x04\x17\x01\x12\x04\x96\x01\x08\x14\n\x0c\n\x04\x04\x17\x02\0\
+    \x12\x04\x97\x01\x02!\n\r\n\x05\x04\x17\x02\0\x04\x12\x04\x97\x01\x02\n\
+    \n\r\n\x05\x04\x17\x02\0\x05\x12\x04\x97\x01\x0b\x11\n\r\n\x05\x04\x17\
+    \x02\0\x01\x12\x04\x97\x01\x12\x1c\n\r\n\x05\x04\x17\x02\0\x03\x12\x04\
+    \x97\x01\x1f\x20\n\x0c\n\x04\x04\x17\x02\x01\x12\x04\x98\x01\x02\x20\n\r\
+    \n\x05\x04\x17\x02\x01\x04\x12\x04\x98\x01\x02\n\n\r\n\x05\x04\x17\x02\
+    \x01\x05\x12\x04\x98\x01\x0b\x11\n\r\n\x05\x04\x17\x02\x01\x01\x12\x04\
+    \x98\x01\x12\x1b\n\r\n\x05\x04\x17\x02\x01\x03\x12\x04\x98\x01\x1e\x1f\n\
+    \x0c\n\x04\x04\x17\x02\x02\x12\x04\x99\x01\x02&\n\r\n\x05\x04\x17\x02\
+    \x02\x04\x12\x04\x99\x01\x02\n\n\r\n\x05\x04\x17\x02\x02\x06\x12\x04\x99\
+    \x01\x0b\x18\n\r\n\x05\x04\x17\x02\x02\x01\x12\x04\x99\x01\x19!\n\r\n\
+    \x05\x04\x17\x02\x02\x03\x12\x04\x99\x01$%\n\x0c\n\x04\x04\x17\x02\x03\
+    \x12\x04\x9a\x01\x02\x1b\n\r\n\x05\x04\x17\x02\x03\x04\x12\x04\x9a\x01\
+    \x02\n\n\r\n\x05\x04\x17\x02\x03\x05\x12\x04\x9a\x01\x0b\x10\n\r\n\x05\
+    \x04\x17\x02\x03\x01\x12\x04\x9a\x01\x11\x16\n\r\n\x05\x04\x17\x02\x03\
+    \x03\x12\x04\x9a\x01\x19\x1a\n\x0c\n\x02\x05\x01\x12\x06\x9d\x01\0\xa1\
+    \x01\x01\n\x0b\n\x03\x05\x01\x01\x12\x04\x9d\x01\x05\x15\n\x0c\n\x04\x05\
+    \x01\x02\0\x12\x04\x9e\x01\x02\r\n\r\n\x05\x05\x01\x02\0\x01\x12\x04\x9e\
+    \x01\x02\x08\n\r\n\x05\x05\x01\x02\0\x02\x12\x04\x9e\x01\x0b\x0c\n\x0c\n\
+    \x04\x05\x01\x02\x01\x12\x04\x9f\x01\x02\x0e\n\r\n\x05\x05\x01\x02\x01\
+    \x01\x12\x04\x9f\x01\x02\t\n\r\n\x05\x05\x01\x02\x01\x02\x12\x04\x9f\x01\
+    \x0c\r\n\x0c\n\x04\x05\x01\x02\x02\x12\x04\xa0\x01\x02\x12\n\r\n\x05\x05\
+    \x01\x02\x02\x01\x12\x04\xa0\x01\x02\r\n\r\n\x05\x05\x01\x02\x02\x02\x12\
+    \x04\xa0\x01\x10\x11\n\x0c\n\x02\x04\x18\x12\x06\xa3\x01\0\xae\x01\x01\n\
+    \x0b\n\x03\x04\x18\x01\x12\x04\xa3\x01\x08\x13\n\x0c\n\x04\x04\x18\x02\0\
+    \x12\x04\xa4\x01\x02\x19\n\r\n\x05\x04\x18\x02\0\x04\x12\x04\xa4\x01\x02\
+    \n\n\r\n\x05\x04\x18\x02\0\x05\x12\x04\xa4\x01\x0b\x11\n\r\n\x05\x04\x18\
+    \x02\0\x01\x12\x04\xa4\x01\x12\x14\n\r\n\x05\x04\x18\x02\0\x03\x12\x04\
+    \xa4\x01\x17\x18\n\x0c\n\x04\x04\x18\x02\x01\x12\x04\xa5\x01\x02!\n\r\n\
+    \x05\x04\x18\x02\x01\x04\x12\x04\xa5\x01\x02\n\n\r\n\x05\x04\x18\x02\x01\
+    \x05\x12\x04\xa5\x01\x0b\x11\n\r\n\x05\x04\x18\x02\x01\x01\x12\x04\xa5\
+    \x01\x12\x1c\n\r\n\x05\x04\x18\x02\x01\x03\x12\x04\xa5\x01\x1f\x20\n\x0c\
+    \n\x04\x04\x18\x02\x02\x12\x04\xa6\x01\x02\x1b\n\r\n\x05\x04\x18\x02\x02\
+    \x04\x12\x04\xa6\x01\x02\n\n\r\n\x05\x04\x18\x02\x02\x05\x12\x04\xa6\x01\
+    \x0b\x11\n\r\n\x05\x04\x18\x02\x02\x01\x12\x04\xa6\x01\x12\x16\n\r\n\x05\
+    \x04\x18\x02\x02\x03\x12\x04\xa6\x01\x19\x1a\n\x0c\n\x04\x04\x18\x02\x03\
+    \x12\x04\xa7\x01\x02\x1e\n\r\n\x05\x04\x18\x02\x03\x04\x12\x04\xa7\x01\
+    \x02\n\n\r\n\x05\x04\x18\x02\x03\x05\x12\x04\xa7\x01\x0b\x11\n\r\n\x05\
+    \x04\x18\x02\x03\x01\x12\x04\xa7\x01\x12\x19\n\r\n\x05\x04\x18\x02\x03\
+    \x03\x12\x04\xa7\x01\x1c\x1d\n\x0c\n\x04\x04\x18\x02\x04\x12\x04\xa8\x01\
+    \x02'\n\r\n\x05\x04\x18\x02\x04\x04\x12\x04\xa8\x01\x02\n\n\r\n\x05\x04\
+    \x18\x02\x04\x06\x12\x04\xa8\x01\x0b\x1b\n\r\n\x05\x04\x18\x02\x04\x01\
+    \x12\x04\xa8\x01\x1c\"\n\r\n\x05\x04\x18\x02\x04\x03\x12\x04\xa8\x01%&\n\
+    E\n\x04\x04\x18\x02\x05\x12\x04\xaa\x01\x02!\x1a7\x20seconds\x20since\
+    \x20the\x20epoch,\x200\x20if\x20the\x20token\x20never\x20expires\n\n\r\n\
+    \x05\x04\x18\x02\x05\x04\x12\x04\xaa\x01\x02\n\n\r\n\x05\x04\x18\x02\x05\
+    \x05\x12\x04\xaa\x01\x0b\x11\n\r\n\x05\x04\x18\x02\x05\x01\x12\x04\xaa\
+    \x01\x12\x1c\n\r\n\x05\x04\x18\x02\x05\x03\x12\x04\xaa\x01\x1f\x20\n\x0c\
+    \n\x04\x04\x18\x02\x06\x12\x04\xab\x01\x02!\n\r\n\x05\x04\x18\x02\x06\
+    \x04\x12\x04\xab\x01\x02\n\n\r\n\x05\x04\x18\x02\x06\x05\x12\x04\xab\x01\
+    \x0b\x11\n\r\n\x05\x04\x18\x02\x06\x01\x12\x04\xab\x01\x12\x1c\n\r\n\x05\
+    \x04\x18\x02\x06\x03\x12\x04\xab\x01\x1f\x20\nH\n\x04\x04\x18\x02\x07\
+    \x12\x04\xad\x01\x02\x1c\x1a:\x20only\x20ever\x20populated\x20in\x20the\
+    \x20reply\x20to\x20an\x20AccessTokenCreate\n\n\r\n\x05\x04\x18\x02\x07\
+    \x04\x12\x04\xad\x01\x02\n\n\r\n\x05\x04\x18\x02\x07\x05\x12\x04\xad\x01\
+    \x0b\x11\n\r\n\x05\x04\x18\x02\x07\x01\x12\x04\xad\x01\x12\x17\n\r\n\x05\
+    \x04\x18\x02\x07\x03\x12\x04\xad\x01\x1a\x1b\n\x0c\n\x02\x04\x19\x12\x06\
+    \xb0\x01\0\xb6\x01\x01\n\x0b\n\x03\x04\x19\x01\x12\x04\xb0\x01\x08\x19\n\
+    \x0c\n\x04\x04\x19\x02\0\x12\x04\xb1\x01\x02!\n\r\n\x05\x04\x19\x02\0\
+    \x04\x12\x04\xb1\x01\x02\n\n\r\n\x05\x04\x19\x02\0\x05\x12\x04\xb1\x01\
+    \x0b\x11\n\r\n\x05\x04\x19\x02\0\x01\x12\x04\xb1\x01\x12\x1c\n\r\n\x05\
+    \x04\x19\x02\0\x03\x12\x04\xb1\x01\x1f\x20\n\x0c\n\x04\x04\x19\x02\x01\
+    \x12\x04\xb2\x01\x02\x1b\n\r\n\x05\x04\x19\x02\x01\x04\x12\x04\xb2\x01\
+    \x02\n\n\r\n\x05\x04\x19\x02\x01\x05\x12\x04\xb2\x01\x0b\x11\n\r\n\x05\
+    \x04\x19\x02\x01\x01\x12\x04\xb2\x01\x12\x16\n\r\n\x05\x04\x19\x02\x01\
+    \x03\x12\x04\xb2\x01\x19\x1a\n\x0c\n\x04\x04\x19\x02\x02\x12\x04\xb3\x01\
+    \x02\x1e\n\r\n\x05\x04\x19\x02\x02\x04\x12\x04\xb3\x01\x02\n\n\r\n\x05\
+    \x04\x19\x02\x02\x05\x12\x04\xb3\x01\x0b\x11\n\r\n\x05\x04\x19\x02\x02\
+    \x01\x12\x04\xb3\x01\x12\x19\n\r\n\x05\x04\x19\x02\x02\x03\x12\x04\xb3\
+    \x01\x1c\x1d\n\x0c\n\x04\x04\x19\x02\x03\x12\x04\xb4\x01\x02'\n\r\n\x05\
+    \x04\x19\x02\x03\x04\x12\x04\xb4\x01\x02\n\n\r\n\x05\x04\x19\x02\x03\x06\
+    \x12\x04\xb4\x01\x0b\x1b\n\r\n\x05\x04\x19\x02\x03\x01\x12\x04\xb4\x01\
+    \x1c\"\n\r\n\x05\x04\x19\x02\x03\x03\x12\x04\xb4\x01%&\n\x0c\n\x04\x04\
+    \x19\x02\x04\x12\x04\xb5\x01\x02!\n\r\n\x05\x04\x19\x02\x04\x04\x12\x04\
+    \xb5\x01\x02\n\n\r\n\x05\x04\x19\x02\x04\x05\x12\x04\xb5\x01\x0b\x11\n\r\
+    \n\x05\x04\x19\x02\x04\x01\x12\x04\xb5\x01\x12\x1c\n\r\n\x05\x04\x19\x02\
+    \x04\x03\x12\x04\xb5\x01\x1f\x20\n\x0c\n\x02\x04\x1a\x12\x06\xb8\x01\0\
+    \xba\x01\x01\n\x0b\n\x03\x04\x1a\x01\x12\x04\xb8\x01\x08\x1e\n\x0c\n\x04\
+    \x04\x1a\x02\0\x12\x04\xb9\x01\x02!\n\r\n\x05\x04\x1a\x02\0\x04\x12\x04\
+    \xb9\x01\x02\n\n\r\n\x05\x04\x1a\x02\0\x05\x12\x04\xb9\x01\x0b\x11\n\r\n\
+    \x05\x04\x1a\x02\0\x01\x12\x04\xb9\x01\x12\x1c\n\r\n\x05\x04\x1a\x02\0\
+    \x03\x12\x04\xb9\x01\x1f\x20\n\x0c\n\x02\x04\x1b\x12\x06\xbc\x01\0\xbf\
+    \x01\x01\n\x0b\n\x03\x04\x1b\x01\x12\x04\xbc\x01\x08\x1f\n\x0c\n\x04\x04\
+    \x1b\x02\0\x12\x04\xbd\x01\x02!\n\r\n\x05\x04\x1b\x02\0\x04\x12\x04\xbd\
+    \x01\x02\n\n\r\n\x05\x04\x1b\x02\0\x05\x12\x04\xbd\x01\x0b\x11\n\r\n\x05\
+    \x04\x1b\x02\0\x01\x12\x04\xbd\x01\x12\x1c\n\r\n\x05\x04\x1b\x02\0\x03\
+    \x12\x04\xbd\x01\x1f\x20\n\x0c\n\x04\x04\x1b\x02\x01\x12\x04\xbe\x01\x02\
+    \"\n\r\n\x05\x04\x1b\x02\x01\x04\x12\x04\xbe\x01\x02\n\n\r\n\x05\x04\x1b\
+    \x02\x01\x06\x12\x04\xbe\x01\x0b\x16\n\r\n\x05\x04\x1b\x02\x01\x01\x12\
+    \x04\xbe\x01\x17\x1d\n\r\n\x05\x04\x1b\x02\x01\x03\x12\x04\xbe\x01\x20!\
+    \n\x0c\n\x02\x04\x1c\x12\x06\xc1\x01\0\xc4\x01\x01\n\x0b\n\x03\x04\x1c\
+    \x01\x12\x04\xc1\x01\x08\x19\n\x0c\n\x04\x04\x1c\x02\0\x12\x04\xc2\x01\
+    \x02!\n\r\n\x05\x04\x1c\x02\0\x04\x12\x04\xc2\x01\x02\n\n\r\n\x05\x04\
+    \x1c\x02\0\x05\x12\x04\xc2\x01\x0b\x11\n\r\n\x05\x04\x1c\x02\0\x01\x12\
+    \x04\xc2\x01\x12\x1c\n\r\n\x05\x04\x1c\x02\0\x03\x12\x04\xc2\x01\x1f\x20\
+    \n\x0c\n\x04\x04\x1c\x02\x01\x12\x04\xc3\x01\x02\x19\n\r\n\x05\x04\x1c\
+    \x02\x01\x04\x12\x04\xc3\x01\x02\n\n\r\n\x05\x04\x1c\x02\x01\x05\x12\x04\
+    \xc3\x01\x0b\x11\n\r\n\x05\x04\x1c\x02\x01\x01\x12\x04\xc3\x01\x12\x14\n\
+    \r\n\x05\x04\x1c\x02\x01\x03\x12\x04\xc3\x01\x17\x18\nf\n\x02\x04\x1d\
+    \x12\x06\xc7\x01\0\xcb\x01\x01\x1aX\x20binary\x20representation\x20of\
+    \x20the\x20secret\x20handed\x20out\x20to\x20the\x20owner\x20of\x20a\x20p\
+    ersonal\x20access\x20token\n\n\x0b\n\x03\x04\x1d\x01\x12\x04\xc7\x01\x08\
+    \x16\n\x0c\n\x04\x04\x1d\x02\0\x12\x04\xc8\x01\x02!\n\r\n\x05\x04\x1d\
+    \x02\0\x04\x12\x04\xc8\x01\x02\n\n\r\n\x05\x04\x1d\x02\0\x05\x12\x04\xc8\
+    \x01\x0b\x11\n\r\n\x05\x04\x1d\x02\0\x01\x12\x04\xc8\x01\x12\x1c\n\r\n\
+    \x05\x04\x1d\x02\0\x03\x12\x04\xc8\x01\x1f\x20\n\x0c\n\x04\x04\x1d\x02\
+    \x01\x12\x04\xc9\x01\x02\x19\n\r\n\x05\x04\x1d\x02\x01\x04\x12\x04\xc9\
+    \x01\x02\n\n\r\n\x05\x04\x1d\x02\x01\x05\x12\x04\xc9\x01\x0b\x11\n\r\n\
+    \x05\x04\x1d\x02\x01\x01\x12\x04\xc9\x01\x12\x14\n\r\n\x05\x04\x1d\x02\
+    \x01\x03\x12\x04\xc9\x01\x17\x18\n\x0c\n\x04\x04\x1d\x02\x02\x12\x04\xca\
+    \x01\x02\x1c\n\r\n\x05\x04\x1d\x02\x02\x04\x12\x04\xca\x01\x02\n\n\r\n\
+    \x05\x04\x1d\x02\x02\x05\x12\x04\xca\x01\x0b\x10\n\r\n\x05\x04\x1d\x02\
+    \x02\x01\x12\x04\xca\x01\x11\x17\n\r\n\x05\x04\x1d\x02\x02\x03\x12\x04\
+    \xca\x01\x1a\x1b\n\x0c\n\x02\x04\x1e\x12\x06\xcd\x01\0\xcf\x01\x01\n\x0b\
+    \n\x03\x04\x1e\x01\x12\x04\xcd\x01\x08\x1b\n\x0c\n\x04\x04\x1e\x02\0\x12\
+    \x04\xce\x01\x02\"\n\r\n\x05\x04\x1e\x02\0\x04\x12\x04\xce\x01\x02\n\n\r\
+    \n\x05\x04\x1e\x02\0\x06\x12\x04\xce\x01\x0b\x19\n\r\n\x05\x04\x1e\x02\0\
+    \x01\x12\x04\xce\x01\x1a\x1d\n\r\n\x05\x04\x1e\x02\0\x03\x12\x04\xce\x01\
+    \x20!\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
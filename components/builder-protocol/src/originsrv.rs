@@ -23,13 +23,21 @@ use sharding::InstaId;
 
 #[derive(Debug)]
 pub enum Error {
+    BadOriginAuditAction,
+    BadOriginMemberRole,
     BadOriginPackageVisibility,
+    BadOriginWebhookDeliveryState,
+    BadOriginWebhookEvent,
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::BadOriginAuditAction => "Bad Origin Audit Action",
+            Error::BadOriginMemberRole => "Bad Origin Member Role",
             Error::BadOriginPackageVisibility => "Bad Origin Package Visibility",
+            Error::BadOriginWebhookDeliveryState => "Bad Origin Webhook Delivery State",
+            Error::BadOriginWebhookEvent => "Bad Origin Webhook Event",
         };
         write!(f, "{}", msg)
     }
@@ -38,7 +46,13 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::BadOriginAuditAction => "Origin audit action cannot be parsed",
+            Error::BadOriginMemberRole => "Origin member role cannot be parsed",
             Error::BadOriginPackageVisibility => "Origin package visibility cannot be parsed",
+            Error::BadOriginWebhookDeliveryState => {
+                "Origin webhook delivery state cannot be parsed"
+            }
+            Error::BadOriginWebhookEvent => "Origin webhook event cannot be parsed",
         }
     }
 }
@@ -112,6 +126,147 @@ impl Routable for CheckOriginOwnerRequest {
     }
 }
 
+const AUDIT_ACTIONS: &'static [(OriginAuditAction, &'static str)] = &[
+    (OriginAuditAction::OriginRegister, "origin-create"),
+    (OriginAuditAction::OriginSettingsUpdate, "origin-update"),
+    (OriginAuditAction::PackageUpload, "package-upload"),
+    (OriginAuditAction::PackagePromote, "package-promote"),
+    (OriginAuditAction::PackageDemote, "package-demote"),
+    (
+        OriginAuditAction::PackageVisibilityUpdate,
+        "package-visibility-update",
+    ),
+    (OriginAuditAction::PackageYank, "package-yank"),
+    (OriginAuditAction::PackageUnyank, "package-unyank"),
+    (OriginAuditAction::PackageDelete, "package-delete"),
+    (OriginAuditAction::ChannelCreate, "channel-create"),
+    (OriginAuditAction::ChannelDelete, "channel-delete"),
+    (OriginAuditAction::KeyGenerate, "key-generate"),
+    (OriginAuditAction::PublicKeyUpload, "public-key-upload"),
+    (OriginAuditAction::SecretKeyUpload, "secret-key-upload"),
+    (OriginAuditAction::SecretKeyDownload, "secret-key-download"),
+    (OriginAuditAction::MemberInvite, "member-invite"),
+    (OriginAuditAction::MemberRemove, "member-remove"),
+    (OriginAuditAction::MemberRoleUpdate, "member-role-update"),
+    (OriginAuditAction::IntegrationCreate, "integration-create"),
+    (OriginAuditAction::IntegrationDelete, "integration-delete"),
+    (OriginAuditAction::ProjectCreate, "project-create"),
+    (OriginAuditAction::ProjectUpdate, "project-update"),
+    (OriginAuditAction::ProjectDelete, "project-delete"),
+    (
+        OriginAuditAction::ProjectVisibilityUpdate,
+        "project-visibility-update",
+    ),
+    (
+        OriginAuditAction::ProjectIntegrationCreate,
+        "project-integration-create",
+    ),
+    (
+        OriginAuditAction::ProjectIntegrationDelete,
+        "project-integration-delete",
+    ),
+    (OriginAuditAction::JobGroupPromote, "job-group-promote"),
+    (OriginAuditAction::JobGroupDemote, "job-group-demote"),
+    (OriginAuditAction::WebhookCreate, "webhook-create"),
+    (OriginAuditAction::WebhookDelete, "webhook-delete"),
+    (
+        OriginAuditAction::ChannelPolicyUpdate,
+        "channel-policy-update",
+    ),
+    (OriginAuditAction::InvitationAccept, "invitation-accept"),
+    (OriginAuditAction::InvitationIgnore, "invitation-ignore"),
+    (OriginAuditAction::InvitationRescind, "invitation-rescind"),
+    (OriginAuditAction::JobSchedule, "job-schedule"),
+    (OriginAuditAction::JobGroupCancel, "job-group-cancel"),
+    (OriginAuditAction::AccessTokenCreate, "access-token-create"),
+    (OriginAuditAction::AccessTokenRevoke, "access-token-revoke"),
+];
+
+impl Serialize for OriginAuditAction {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl FromStr for OriginAuditAction {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        let value = value.to_lowercase();
+        AUDIT_ACTIONS
+            .iter()
+            .find(|&&(_, name)| name == value)
+            .map(|&(action, _)| action)
+            .ok_or(Error::BadOriginAuditAction)
+    }
+}
+
+impl fmt::Display for OriginAuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = AUDIT_ACTIONS
+            .iter()
+            .find(|&&(action, _)| action == *self)
+            .map(|&(_, name)| name)
+            .expect("Every audit action has a name");
+        write!(f, "{}", value)
+    }
+}
+
+impl Serialize for OriginAuditEvent {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_audit_event", 12)?;
+        strukt.serialize_field("id", &self.get_id().to_string())?;
+        strukt.serialize_field("origin", self.get_origin_name())?;
+        strukt.serialize_field(
+            "account_id",
+            &self.get_account_id().to_string(),
+        )?;
+        strukt.serialize_field("account_name", self.get_account_name())?;
+        strukt.serialize_field("action", &self.get_action())?;
+        strukt.serialize_field("target", self.get_target())?;
+        strukt.serialize_field("detail", self.get_detail())?;
+        strukt.serialize_field("remote_addr", self.get_remote_addr())?;
+        strukt.serialize_field("forwarded_for", self.get_forwarded_for())?;
+        strukt.serialize_field("user_agent", self.get_user_agent())?;
+        if self.has_access_token_id() {
+            strukt.serialize_field(
+                "access_token_id",
+                &self.get_access_token_id().to_string(),
+            )?;
+        }
+        strukt.serialize_field("created_at", self.get_created_at())?;
+        strukt.end()
+    }
+}
+
+impl Routable for OriginAuditEventCreate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_event().get_origin_name().to_string())
+    }
+}
+
+impl Pageable for OriginAuditEventListRequest {
+    fn get_range(&self) -> [u64; 2] {
+        [self.get_start(), self.get_stop()]
+    }
+}
+
+impl Routable for OriginAuditEventListRequest {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin_name().to_string())
+    }
+}
+
 impl Default for OriginPackageVisibility {
     fn default() -> OriginPackageVisibility {
         OriginPackageVisibility::Public
@@ -538,17 +693,74 @@ impl Serialize for OriginMemberListResponse {
     {
         let mut strukt = serializer.serialize_struct(
             "origin_member_list_response",
-            2,
+            3,
         )?;
         strukt.serialize_field(
             "origin_id",
             &self.get_origin_id().to_string(),
         )?;
         strukt.serialize_field("members", self.get_members())?;
+        strukt.serialize_field("roles", self.get_roles())?;
         strukt.end()
     }
 }
 
+impl Serialize for OriginMember {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_member", 3)?;
+        strukt.serialize_field(
+            "account_id",
+            &self.get_account_id().to_string(),
+        )?;
+        strukt.serialize_field("account_name", self.get_account_name())?;
+        strukt.serialize_field("role", &self.get_role())?;
+        strukt.end()
+    }
+}
+
+impl OriginMemberRole {
+    /// Whether a member holding this role may do everything the given role may.
+    pub fn includes(&self, role: OriginMemberRole) -> bool {
+        *self as i32 >= role as i32
+    }
+}
+
+impl Serialize for OriginMemberRole {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl FromStr for OriginMemberRole {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "developer" => Ok(OriginMemberRole::Developer),
+            "maintainer" => Ok(OriginMemberRole::Maintainer),
+            "owner" => Ok(OriginMemberRole::Owner),
+            _ => Err(Error::BadOriginMemberRole),
+        }
+    }
+}
+
+impl fmt::Display for OriginMemberRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            OriginMemberRole::Developer => "developer",
+            OriginMemberRole::Maintainer => "maintainer",
+            OriginMemberRole::Owner => "owner",
+        };
+        write!(f, "{}", value)
+    }
+}
+
 impl Routable for OriginMemberRemove {
     type H = InstaId;
 
@@ -557,6 +769,14 @@ impl Routable for OriginMemberRemove {
     }
 }
 
+impl Routable for OriginMemberRoleUpdate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
 impl fmt::Display for Origin {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.get_name().fmt(f)
@@ -596,6 +816,7 @@ impl FromArchive for OriginPackage {
             package.set_config(cfg);
         }
         package.set_checksum(checksum);
+        package.set_is_service(archive.is_a_service());
         Ok(package)
     }
 }
@@ -611,7 +832,7 @@ impl Serialize for OriginPackage {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("origin_package", 8)?;
+        let mut strukt = serializer.serialize_struct("origin_package", 10)?;
         strukt.serialize_field("ident", self.get_ident())?;
         strukt.serialize_field("checksum", self.get_checksum())?;
         strukt.serialize_field("manifest", self.get_manifest())?;
@@ -621,6 +842,7 @@ impl Serialize for OriginPackage {
         strukt.serialize_field("exposes", self.get_exposes())?;
         strukt.serialize_field("config", self.get_config())?;
         strukt.serialize_field("visibility", &self.get_visibility())?;
+        strukt.serialize_field("yanked", &self.get_yanked())?;
         strukt.end()
     }
 }
@@ -975,6 +1197,38 @@ impl Routable for OriginPackageUpdate {
     }
 }
 
+impl Routable for OriginPackageDelete {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_ident().get_origin()))
+    }
+}
+
+// Dependents can be in any origin, so this is sent to every shard in turn rather than to the
+// shard of the release's origin.
+impl Routable for OriginPackageDependentListRequest {
+    type H = u32;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_shard())
+    }
+}
+
+impl Serialize for OriginPackageDependentListResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct(
+            "origin_package_dependent_list_response",
+            1,
+        )?;
+        strukt.serialize_field("dependents", self.get_idents())?;
+        strukt.end()
+    }
+}
+
 impl Routable for OriginPackagePromote {
     type H = String;
 
@@ -1243,6 +1497,262 @@ impl Routable for OriginSecretKeyGet {
     }
 }
 
+impl Serialize for OriginWebhookEvent {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl FromStr for OriginWebhookEvent {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "package-uploaded" => Ok(OriginWebhookEvent::PackageUploaded),
+            "package-promoted" => Ok(OriginWebhookEvent::PackagePromoted),
+            "package-demoted" => Ok(OriginWebhookEvent::PackageDemoted),
+            "job-group-completed" => Ok(OriginWebhookEvent::JobGroupCompleted),
+            "job-failed" => Ok(OriginWebhookEvent::JobFailed),
+            _ => Err(Error::BadOriginWebhookEvent),
+        }
+    }
+}
+
+impl fmt::Display for OriginWebhookEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            OriginWebhookEvent::PackageUploaded => "package-uploaded",
+            OriginWebhookEvent::PackagePromoted => "package-promoted",
+            OriginWebhookEvent::PackageDemoted => "package-demoted",
+            OriginWebhookEvent::JobGroupCompleted => "job-group-completed",
+            OriginWebhookEvent::JobFailed => "job-failed",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl Serialize for OriginWebhook {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_webhook", 6)?;
+        strukt.serialize_field("id", &self.get_id().to_string())?;
+        strukt.serialize_field("origin", self.get_origin_name())?;
+        strukt.serialize_field("url", self.get_url())?;
+        if self.has_secret() {
+            strukt.serialize_field("secret", self.get_secret())?;
+        }
+        strukt.serialize_field("events", self.get_events())?;
+        strukt.serialize_field("created_at", self.get_created_at())?;
+        strukt.end()
+    }
+}
+
+impl Routable for OriginWebhookCreate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_webhook().get_origin_name().to_string())
+    }
+}
+
+impl Routable for OriginWebhookDelete {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin_name().to_string())
+    }
+}
+
+impl Routable for OriginWebhookListRequest {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin_name().to_string())
+    }
+}
+
+impl Serialize for OriginWebhookListResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_webhook_list_response", 2)?;
+        strukt.serialize_field("origin", self.get_origin_name())?;
+        strukt.serialize_field("webhooks", self.get_webhooks())?;
+        strukt.end()
+    }
+}
+
+impl OriginWebhookTrigger {
+    pub fn for_package(event: OriginWebhookEvent, ident: &OriginPackageIdent) -> Self {
+        let mut trigger = OriginWebhookTrigger::new();
+        trigger.set_origin_name(ident.get_origin().to_string());
+        trigger.set_event(event);
+        trigger.set_ident(ident.to_string());
+        trigger
+    }
+}
+
+// This is the payload delivered to the webhooks, so only the fields relevant to the event are
+// included.
+impl Serialize for OriginWebhookTrigger {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_webhook_trigger", 8)?;
+        strukt.serialize_field("event", &self.get_event())?;
+        strukt.serialize_field("origin", self.get_origin_name())?;
+        if self.has_ident() {
+            strukt.serialize_field("ident", self.get_ident())?;
+        }
+        if self.has_target() {
+            strukt.serialize_field("target", self.get_target())?;
+        }
+        if self.has_channel() {
+            strukt.serialize_field("channel", self.get_channel())?;
+        }
+        if self.has_group_id() {
+            strukt.serialize_field("group_id", &self.get_group_id().to_string())?;
+        }
+        if self.has_job_id() {
+            strukt.serialize_field("job_id", &self.get_job_id().to_string())?;
+        }
+        if self.has_state() {
+            strukt.serialize_field("state", self.get_state())?;
+        }
+        strukt.end()
+    }
+}
+
+impl Routable for OriginWebhookTrigger {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin_name().to_string())
+    }
+}
+
+impl Serialize for OriginWebhookDeliveryState {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl FromStr for OriginWebhookDeliveryState {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "pending" => Ok(OriginWebhookDeliveryState::Pending),
+            "delivered" => Ok(OriginWebhookDeliveryState::Delivered),
+            "failed" => Ok(OriginWebhookDeliveryState::Failed),
+            _ => Err(Error::BadOriginWebhookDeliveryState),
+        }
+    }
+}
+
+impl fmt::Display for OriginWebhookDeliveryState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            OriginWebhookDeliveryState::Pending => "pending",
+            OriginWebhookDeliveryState::Delivered => "delivered",
+            OriginWebhookDeliveryState::Failed => "failed",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl Serialize for OriginWebhookDelivery {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_webhook_delivery", 10)?;
+        strukt.serialize_field("id", &self.get_id().to_string())?;
+        strukt.serialize_field(
+            "webhook_id",
+            &self.get_webhook_id().to_string(),
+        )?;
+        strukt.serialize_field("event", &self.get_event())?;
+        strukt.serialize_field("payload", self.get_payload())?;
+        strukt.serialize_field("state", &self.get_state())?;
+        strukt.serialize_field("attempts", &self.get_attempts())?;
+        strukt.serialize_field("response_code", &self.get_response_code())?;
+        strukt.serialize_field("error", self.get_error())?;
+        strukt.serialize_field("created_at", self.get_created_at())?;
+        strukt.serialize_field("updated_at", self.get_updated_at())?;
+        strukt.end()
+    }
+}
+
+impl Pageable for OriginWebhookDeliveryListRequest {
+    fn get_range(&self) -> [u64; 2] {
+        [self.get_start(), self.get_stop()]
+    }
+}
+
+impl Routable for OriginWebhookDeliveryListRequest {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin_name().to_string())
+    }
+}
+
+impl Serialize for OriginChannelPolicy {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_channel_policy", 5)?;
+        strukt.serialize_field("origin", self.get_origin_name())?;
+        strukt.serialize_field("channel", self.get_channel_name())?;
+        if self.has_promote_role() {
+            strukt.serialize_field("promote_role", &self.get_promote_role())?;
+        } else {
+            strukt.serialize_field("promote_role", &None::<OriginMemberRole>)?;
+        }
+        if self.has_prerequisite_channel() {
+            strukt.serialize_field(
+                "prerequisite_channel",
+                self.get_prerequisite_channel(),
+            )?;
+        } else {
+            strukt.serialize_field("prerequisite_channel", &None::<String>)?;
+        }
+        strukt.serialize_field(
+            "require_job_success",
+            &self.get_require_job_success(),
+        )?;
+        strukt.end()
+    }
+}
+
+impl Routable for OriginChannelPolicyGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin_name().to_string())
+    }
+}
+
+impl Routable for OriginChannelPolicyUpdate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_policy().get_origin_name().to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1286,4 +1796,19 @@ mod tests {
         );
         assert_eq!(vec!["3.6.12", "3.6.10", "3.6.6", "3.6.5"], r);
     }
+
+    #[test]
+    fn origin_webhook_event_names() {
+        let events = vec![
+            OriginWebhookEvent::PackageUploaded,
+            OriginWebhookEvent::PackagePromoted,
+            OriginWebhookEvent::PackageDemoted,
+            OriginWebhookEvent::JobGroupCompleted,
+            OriginWebhookEvent::JobFailed,
+        ];
+        for event in events {
+            assert_eq!(event.to_string().parse::<OriginWebhookEvent>().unwrap(), event);
+        }
+        assert!("package-yanked".parse::<OriginWebhookEvent>().is_err());
+    }
 }
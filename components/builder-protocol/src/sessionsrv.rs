@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::error;
+use std::fmt;
 use std::result;
+use std::str::FromStr;
 
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
@@ -22,6 +25,31 @@ use message::{Persistable, Routable};
 use sharding::InstaId;
 pub use message::sessionsrv::*;
 
+/// Marks a bearer token as a personal access token rather than an encoded `SessionToken`.
+pub const ACCESS_TOKEN_PREFIX: &'static str = "hab_pat_";
+
+#[derive(Debug)]
+pub enum Error {
+    BadAccessTokenScope,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match *self {
+            Error::BadAccessTokenScope => "Bad Access Token Scope",
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::BadAccessTokenScope => "Access token scope cannot be parsed",
+        }
+    }
+}
+
 impl Routable for SessionCreate {
     type H = u32;
 
@@ -113,6 +141,22 @@ impl Routable for AccountUpdate {
     }
 }
 
+impl Routable for AccountIdentityCreate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_subject().to_string())
+    }
+}
+
+impl Routable for AccountIdentityGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_subject().to_string())
+    }
+}
+
 
 impl Routable for AccountOriginInvitationCreate {
     type H = InstaId;
@@ -270,3 +314,157 @@ impl Serialize for Session {
         strukt.end()
     }
 }
+
+impl FromStr for AccessTokenScope {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "upload" => Ok(AccessTokenScope::Upload),
+            "promote" => Ok(AccessTokenScope::Promote),
+            "key-download" => Ok(AccessTokenScope::KeyDownload),
+            _ => Err(Error::BadAccessTokenScope),
+        }
+    }
+}
+
+impl fmt::Display for AccessTokenScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            AccessTokenScope::Upload => "upload",
+            AccessTokenScope::Promote => "promote",
+            AccessTokenScope::KeyDownload => "key-download",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl Serialize for AccessTokenScope {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl AccessToken {
+    /// Whether this token may be used for the given operation against the given origin.
+    pub fn permits(&self, scope: AccessTokenScope, origin: Option<&str>) -> bool {
+        self.get_scopes().contains(&scope) && self.permits_origin(origin)
+    }
+
+    /// Whether this token may be used against the given origin. A token without any origins is
+    /// valid for every origin its owner has access to, while one restricted to some origins is
+    /// never valid for requests which aren't about a single origin.
+    pub fn permits_origin(&self, origin: Option<&str>) -> bool {
+        if self.get_origins().is_empty() {
+            return true;
+        }
+        match origin {
+            Some(origin) => self.get_origins().iter().any(|o| o == origin),
+            None => false,
+        }
+    }
+}
+
+impl Serialize for AccessToken {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("access_token", 7)?;
+        strukt.serialize_field("id", &self.get_id().to_string())?;
+        strukt.serialize_field("name", self.get_name())?;
+        strukt.serialize_field("origins", self.get_origins())?;
+        strukt.serialize_field("scopes", self.get_scopes())?;
+        strukt.serialize_field("expires_at", &self.get_expires_at())?;
+        strukt.serialize_field("created_at", &self.get_created_at())?;
+        if self.has_token() {
+            strukt.serialize_field("token", self.get_token())?;
+        }
+        strukt.end()
+    }
+}
+
+impl Routable for AccessTokenCreate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_account_id()))
+    }
+}
+
+impl Routable for AccessTokenListRequest {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_account_id()))
+    }
+}
+
+impl Serialize for AccessTokenListResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("access_token_list_response", 2)?;
+        strukt.serialize_field(
+            "account_id",
+            &self.get_account_id().to_string(),
+        )?;
+        strukt.serialize_field("tokens", self.get_tokens())?;
+        strukt.end()
+    }
+}
+
+impl Routable for AccessTokenRevoke {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_account_id()))
+    }
+}
+
+impl Routable for AccessTokenValidate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_key().get_account_id()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn access_token_permits() {
+        let mut token = AccessToken::new();
+        token.set_scopes(vec![AccessTokenScope::Upload]);
+        assert!(token.permits(AccessTokenScope::Upload, Some("core")));
+        assert!(!token.permits(AccessTokenScope::Promote, Some("core")));
+        assert!(token.permits(AccessTokenScope::Upload, None));
+
+        token.set_origins(::protobuf::RepeatedField::from_vec(vec!["core".to_string()]));
+        assert!(token.permits(AccessTokenScope::Upload, Some("core")));
+        assert!(!token.permits(AccessTokenScope::Upload, Some("acme")));
+        assert!(!token.permits(AccessTokenScope::Upload, None));
+        assert!(token.permits_origin(Some("core")));
+        assert!(!token.permits_origin(None));
+    }
+
+    #[test]
+    fn access_token_scope_round_trip() {
+        for scope in &[
+            AccessTokenScope::Upload,
+            AccessTokenScope::Promote,
+            AccessTokenScope::KeyDownload,
+        ]
+        {
+            let parsed: AccessTokenScope = scope.to_string().parse().unwrap();
+            assert_eq!(&parsed, scope);
+        }
+        assert!("bogus".parse::<AccessTokenScope>().is_err());
+    }
+}
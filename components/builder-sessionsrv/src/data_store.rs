@@ -16,12 +16,14 @@
 
 use std::sync::Arc;
 
+use chrono::{DateTime, TimeZone, UTC};
 use db::config::{DataStoreCfg, ShardId};
 use db::pool::Pool;
 use db::migration::Migrator;
 use protocol::sessionsrv;
 use postgres;
 use protobuf;
+use protobuf::ProtobufEnum;
 
 use error::{SrvError, SrvResult};
 use migrations;
@@ -51,6 +53,7 @@ impl DataStore {
         migrations::accounts::migrate(&mut migrator)?;
         migrations::sessions::migrate(&mut migrator)?;
         migrations::invitations::migrate(&mut migrator)?;
+        migrations::access_tokens::migrate(&mut migrator)?;
 
         migrator.finish()?;
 
@@ -130,6 +133,40 @@ impl DataStore {
         }
     }
 
+    pub fn create_account_identity(
+        &self,
+        request: &sessionsrv::AccountIdentityCreate,
+    ) -> SrvResult<sessionsrv::AccountIdentity> {
+        let conn = self.pool.get(request)?;
+        let rows = conn.query(
+            "SELECT * FROM insert_account_identity_v1($1, $2, $3)",
+            &[
+                &(request.get_provider() as i16),
+                &request.get_subject(),
+                &(request.get_account_id() as i64),
+            ],
+        ).map_err(SrvError::AccountIdentityCreate)?;
+        let row = rows.get(0);
+        Ok(self.row_to_account_identity(&row))
+    }
+
+    pub fn get_account_identity(
+        &self,
+        request: &sessionsrv::AccountIdentityGet,
+    ) -> SrvResult<Option<sessionsrv::AccountIdentity>> {
+        let conn = self.pool.get(request)?;
+        let rows = conn.query(
+            "SELECT * FROM get_account_identity_v1($1, $2)",
+            &[&(request.get_provider() as i16), &request.get_subject()],
+        ).map_err(SrvError::AccountIdentityGet)?;
+        if rows.len() != 0 {
+            let row = rows.get(0);
+            Ok(Some(self.row_to_account_identity(&row)))
+        } else {
+            Ok(None)
+        }
+    }
+
     pub fn get_origins_by_account(
         &self,
         request: &sessionsrv::AccountOriginListRequest,
@@ -278,6 +315,112 @@ impl DataStore {
         Ok(response)
     }
 
+    pub fn create_access_token(
+        &self,
+        msg: &sessionsrv::AccessTokenCreate,
+        token_hash: &str,
+    ) -> SrvResult<sessionsrv::AccessToken> {
+        let conn = self.pool.get(msg)?;
+        let scopes: Vec<String> = msg.get_scopes().iter().map(|s| s.to_string()).collect();
+        let expires_at = match msg.get_expires_at() {
+            0 => None,
+            secs => Some(UTC.timestamp(secs as i64, 0)),
+        };
+        let rows = conn.query(
+            "SELECT * FROM insert_account_token_v1($1, $2, $3, $4, $5, $6)",
+            &[
+                &(msg.get_account_id() as i64),
+                &msg.get_name(),
+                &token_hash,
+                &msg.get_origins().to_vec(),
+                &scopes,
+                &expires_at,
+            ],
+        ).map_err(SrvError::AccessTokenCreate)?;
+        let row = rows.get(0);
+        Ok(self.row_to_access_token(&row))
+    }
+
+    /// Returns a token which is neither expired nor revoked along with the hash of its secret.
+    pub fn get_access_token(
+        &self,
+        msg: &sessionsrv::AccessTokenValidate,
+    ) -> SrvResult<Option<(sessionsrv::AccessToken, String)>> {
+        let conn = self.pool.get(msg)?;
+        let rows = conn.query(
+            "SELECT * FROM get_account_token_v1($1, $2)",
+            &[
+                &(msg.get_key().get_account_id() as i64),
+                &(msg.get_key().get_id() as i64),
+            ],
+        ).map_err(SrvError::AccessTokenGet)?;
+        if rows.len() != 0 {
+            let row = rows.get(0);
+            Ok(Some((self.row_to_access_token(&row), row.get("token_hash"))))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn list_access_tokens(
+        &self,
+        msg: &sessionsrv::AccessTokenListRequest,
+    ) -> SrvResult<sessionsrv::AccessTokenListResponse> {
+        let conn = self.pool.get(msg)?;
+        let rows = conn.query(
+            "SELECT * FROM get_account_tokens_v1($1)",
+            &[&(msg.get_account_id() as i64)],
+        ).map_err(SrvError::AccessTokenList)?;
+        let mut response = sessionsrv::AccessTokenListResponse::new();
+        response.set_account_id(msg.get_account_id());
+        let mut tokens = protobuf::RepeatedField::new();
+        for row in rows.iter() {
+            tokens.push(self.row_to_access_token(&row));
+        }
+        response.set_tokens(tokens);
+        Ok(response)
+    }
+
+    /// Returns false if the account has no live token with the given id.
+    pub fn revoke_access_token(&self, msg: &sessionsrv::AccessTokenRevoke) -> SrvResult<bool> {
+        let conn = self.pool.get(msg)?;
+        let rows = conn.query(
+            "SELECT * FROM revoke_account_token_v1($1, $2)",
+            &[&(msg.get_account_id() as i64), &(msg.get_id() as i64)],
+        ).map_err(SrvError::AccessTokenRevoke)?;
+        Ok(rows.len() != 0)
+    }
+
+    fn row_to_access_token(&self, row: &postgres::rows::Row) -> sessionsrv::AccessToken {
+        let mut token = sessionsrv::AccessToken::new();
+        let id: i64 = row.get("id");
+        token.set_id(id as u64);
+        let account_id: i64 = row.get("account_id");
+        token.set_account_id(account_id as u64);
+        token.set_name(row.get("name"));
+        let origins: Vec<String> = row.get("origins");
+        token.set_origins(protobuf::RepeatedField::from_vec(origins));
+        let scopes: Vec<String> = row.get("scopes");
+        token.set_scopes(scopes.iter().filter_map(|s| s.parse().ok()).collect());
+        let expires_at: Option<DateTime<UTC>> = row.get("expires_at");
+        token.set_expires_at(expires_at.map_or(0, |t| t.timestamp() as u64));
+        let created_at: DateTime<UTC> = row.get("created_at");
+        token.set_created_at(created_at.timestamp() as u64);
+        token
+    }
+
+    fn row_to_account_identity(&self, row: &postgres::rows::Row) -> sessionsrv::AccountIdentity {
+        let mut identity = sessionsrv::AccountIdentity::new();
+        let provider: i16 = row.get("provider");
+        if let Some(provider) = sessionsrv::OAuthProvider::from_i32(provider as i32) {
+            identity.set_provider(provider);
+        }
+        identity.set_subject(row.get("subject"));
+        let account_id: i64 = row.get("account_id");
+        identity.set_account_id(account_id as u64);
+        identity
+    }
+
     fn row_to_account(&self, row: postgres::rows::Row) -> sessionsrv::Account {
         let mut account = sessionsrv::Account::new();
         let id: i64 = row.get("id");
@@ -14,6 +14,7 @@
 
 use std::error;
 use std::fmt;
+use std::io;
 use std::num;
 
 use db;
@@ -27,10 +28,17 @@ pub type SrvResult<T> = Result<T, SrvError>;
 
 #[derive(Debug)]
 pub enum SrvError {
+    AccessTokenCreate(postgres::error::Error),
+    AccessTokenGet(postgres::error::Error),
+    AccessTokenList(postgres::error::Error),
+    AccessTokenRevoke(postgres::error::Error),
+    AccessTokenSecret(io::Error),
     AccountCreate(postgres::error::Error),
     AccountGet(postgres::error::Error),
     AccountGetById(postgres::error::Error),
     AccountIdFromString(num::ParseIntError),
+    AccountIdentityCreate(postgres::error::Error),
+    AccountIdentityGet(postgres::error::Error),
     AccountOriginInvitationAccept(postgres::error::Error),
     AccountOriginInvitationCreate(postgres::error::Error),
     AccountOriginInvitationIgnore(postgres::error::Error),
@@ -57,6 +65,21 @@ pub enum SrvError {
 impl fmt::Display for SrvError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            SrvError::AccessTokenCreate(ref e) => {
+                format!("Error creating access token in database, {}", e)
+            }
+            SrvError::AccessTokenGet(ref e) => {
+                format!("Error getting access token from database, {}", e)
+            }
+            SrvError::AccessTokenList(ref e) => {
+                format!("Error listing access tokens in database, {}", e)
+            }
+            SrvError::AccessTokenRevoke(ref e) => {
+                format!("Error revoking access token in database, {}", e)
+            }
+            SrvError::AccessTokenSecret(ref e) => {
+                format!("Unable to generate access token secret, {}", e)
+            }
             SrvError::AccountCreate(ref e) => format!("Error creating account in database, {}", e),
             SrvError::AccountGet(ref e) => format!("Error getting account from database, {}", e),
             SrvError::AccountGetById(ref e) => {
@@ -65,6 +88,12 @@ impl fmt::Display for SrvError {
             SrvError::AccountIdFromString(ref e) => {
                 format!("Cannot convert from string to Account ID, {}", e)
             }
+            SrvError::AccountIdentityCreate(ref e) => {
+                format!("Error linking identity to account in database, {}", e)
+            }
+            SrvError::AccountIdentityGet(ref e) => {
+                format!("Error getting identity from database, {}", e)
+            }
             SrvError::AccountOriginInvitationAccept(ref e) => {
                 format!("Error accepting invitation in database, {}", e)
             }
@@ -114,10 +143,17 @@ impl fmt::Display for SrvError {
 impl error::Error for SrvError {
     fn description(&self) -> &str {
         match *self {
+            SrvError::AccessTokenCreate(ref err) => err.description(),
+            SrvError::AccessTokenGet(ref err) => err.description(),
+            SrvError::AccessTokenList(ref err) => err.description(),
+            SrvError::AccessTokenRevoke(ref err) => err.description(),
+            SrvError::AccessTokenSecret(ref err) => err.description(),
             SrvError::AccountCreate(ref err) => err.description(),
             SrvError::AccountGet(ref err) => err.description(),
             SrvError::AccountGetById(ref err) => err.description(),
             SrvError::AccountIdFromString(ref err) => err.description(),
+            SrvError::AccountIdentityCreate(ref err) => err.description(),
+            SrvError::AccountIdentityGet(ref err) => err.description(),
             SrvError::AccountOriginInvitationAccept(ref err) => err.description(),
             SrvError::AccountOriginInvitationCreate(ref err) => err.description(),
             SrvError::AccountOriginInvitationIgnore(ref err) => err.description(),
@@ -16,6 +16,7 @@
 #![cfg_attr(feature="clippy", plugin(clippy))]
 
 extern crate base64;
+extern crate chrono;
 extern crate github_api_client;
 extern crate habitat_builder_db as db;
 extern crate habitat_builder_protocol as protocol;
@@ -26,9 +27,11 @@ extern crate hyper;
 extern crate lazy_static;
 #[macro_use]
 extern crate log;
+extern crate openssl;
 extern crate postgres;
 extern crate protobuf;
 extern crate r2d2;
+extern crate rand;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
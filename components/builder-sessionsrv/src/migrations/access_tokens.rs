@@ -0,0 +1,106 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::SrvResult;
+
+pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE SEQUENCE IF NOT EXISTS account_tokens_id_seq;"#,
+    )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE TABLE IF NOT EXISTS account_tokens (
+                        id bigint PRIMARY KEY DEFAULT next_id_v1('account_tokens_id_seq'),
+                        account_id bigint NOT NULL,
+                        name text NOT NULL,
+                        token_hash text NOT NULL,
+                        origins text[] NOT NULL DEFAULT '{}',
+                        scopes text[] NOT NULL DEFAULT '{}',
+                        expires_at timestamptz,
+                        revoked_at timestamptz,
+                        created_at timestamptz DEFAULT now()
+                        )"#,
+    )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE INDEX IF NOT EXISTS account_tokens_account_id ON account_tokens(account_id)"#,
+    )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_account_token_v1 (
+                    at_account_id bigint,
+                    at_name text,
+                    at_token_hash text,
+                    at_origins text[],
+                    at_scopes text[],
+                    at_expires_at timestamptz
+                 ) RETURNS SETOF account_tokens
+                 LANGUAGE SQL
+                 VOLATILE AS $$
+                    INSERT INTO account_tokens (account_id, name, token_hash, origins, scopes, expires_at)
+                    VALUES (at_account_id, at_name, at_token_hash, at_origins, at_scopes, at_expires_at)
+                    RETURNING *;
+                 $$"#,
+    )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE OR REPLACE FUNCTION get_account_tokens_v1 (
+                    at_account_id bigint
+                 ) RETURNS SETOF account_tokens
+                 LANGUAGE SQL
+                 STABLE AS $$
+                    SELECT *
+                    FROM account_tokens
+                    WHERE account_id = at_account_id
+                    AND revoked_at IS NULL
+                    ORDER BY created_at DESC;
+                 $$"#,
+    )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE OR REPLACE FUNCTION get_account_token_v1 (
+                    at_account_id bigint,
+                    at_id bigint
+                 ) RETURNS SETOF account_tokens
+                 LANGUAGE SQL
+                 STABLE AS $$
+                    SELECT *
+                    FROM account_tokens
+                    WHERE id = at_id
+                    AND account_id = at_account_id
+                    AND revoked_at IS NULL
+                    AND (expires_at IS NULL OR expires_at > now());
+                 $$"#,
+    )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE OR REPLACE FUNCTION revoke_account_token_v1 (
+                    at_account_id bigint,
+                    at_id bigint
+                 ) RETURNS SETOF account_tokens
+                 LANGUAGE SQL
+                 VOLATILE AS $$
+                    UPDATE account_tokens
+                    SET revoked_at = now()
+                    WHERE id = at_id
+                    AND account_id = at_account_id
+                    AND revoked_at IS NULL
+                    RETURNING *;
+                 $$"#,
+    )?;
+    Ok(())
+}
@@ -147,5 +147,42 @@ pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
                             UPDATE accounts SET email = op_email WHERE id = op_id;
                           $$ LANGUAGE SQL VOLATILE"#,
     )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE TABLE IF NOT EXISTS account_identities (
+                        provider smallint NOT NULL,
+                        subject text NOT NULL,
+                        account_id bigint NOT NULL,
+                        created_at timestamptz DEFAULT now(),
+                        PRIMARY KEY (provider, subject)
+                        )"#,
+    )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE OR REPLACE FUNCTION get_account_identity_v1 (
+                          ai_provider smallint,
+                          ai_subject text
+                        ) RETURNS SETOF account_identities AS $$
+                            SELECT * FROM account_identities
+                            WHERE provider = ai_provider AND subject = ai_subject;
+                          $$ LANGUAGE SQL STABLE"#,
+    )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_account_identity_v1 (
+                          ai_provider smallint,
+                          ai_subject text,
+                          ai_account_id bigint
+                        ) RETURNS SETOF account_identities AS $$
+                          BEGIN
+                            INSERT INTO account_identities (provider, subject, account_id)
+                            VALUES (ai_provider, ai_subject, ai_account_id)
+                            ON CONFLICT DO NOTHING;
+                            RETURN QUERY SELECT * FROM account_identities
+                            WHERE provider = ai_provider AND subject = ai_subject;
+                            RETURN;
+                          END
+                        $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
     Ok(())
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod access_tokens;
 pub mod accounts;
 pub mod invitations;
 pub mod sessions;
@@ -14,13 +14,17 @@
 
 use std::env;
 
+use hab_core::crypto::hash;
 use hab_net::app::prelude::*;
+use hab_net::NetResult;
 use hab_net::privilege::{self, FeatureFlags};
+use openssl::memcmp;
 
 use protocol::net;
 use protocol::sessionsrv as proto;
 
-use super::{encode_token, ServerState, Session};
+use super::{encode_access_token, encode_token, generate_access_token_secret, ServerState,
+            Session};
 use error::SrvResult;
 
 pub fn account_get_id(
@@ -116,6 +120,44 @@ pub fn account_find_or_create(
     Ok(())
 }
 
+pub fn account_identity_get(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::AccountIdentityGet>()?;
+    match state.datastore.get_account_identity(&msg) {
+        Ok(Some(identity)) => conn.route_reply(req, &identity)?,
+        Ok(None) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "ss:account-identity-get:0");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "ss:account-identity-get:1");
+            error!("{}, {}", e, err);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn account_identity_create(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::AccountIdentityCreate>()?;
+    match state.datastore.create_account_identity(&msg) {
+        Ok(identity) => conn.route_reply(req, &identity)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "ss:account-identity-create:0");
+            error!("{}, {}", e, err);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn session_create(
     req: &mut Message,
     conn: &mut RouteConn,
@@ -126,15 +168,22 @@ pub fn session_create(
     let mut flags = FeatureFlags::default();
     if env::var_os("HAB_FUNC_TEST").is_some() {
         flags = FeatureFlags::all();
-    } else {
+    } else if msg.get_provider() == proto::OAuthProvider::GitHub {
+        // Permissions are granted through GitHub team membership, which is looked up by login.
+        // Account names coming from any other provider can't be trusted to match a GitHub login.
         assign_permissions(msg.get_name(), &mut flags, state)
     }
 
-    let mut account_req = proto::AccountFindOrCreate::default();
-    account_req.set_name(msg.take_name());
-    account_req.set_email(msg.take_email());
+    let account = if msg.get_provider() == proto::OAuthProvider::OIDC {
+        oidc_account(&msg, conn)
+    } else {
+        let mut account_req = proto::AccountFindOrCreate::default();
+        account_req.set_name(msg.take_name());
+        account_req.set_email(msg.take_email());
+        conn.route::<proto::AccountFindOrCreate, proto::Account>(&account_req)
+    };
 
-    match conn.route::<proto::AccountFindOrCreate, proto::Account>(&account_req) {
+    match account {
         Ok(account) => {
             let session = Session::build(msg, account, flags)?;
             {
@@ -143,6 +192,10 @@ pub fn session_create(
             }
             conn.route_reply(req, &*session)?;
         }
+        Err(ref err) if err.get_code() == ErrCode::ENTITY_CONFLICT => {
+            warn!("{}", err);
+            conn.route_reply(req, &**err)?;
+        }
         Err(e) => {
             let err = NetError::new(ErrCode::DATA_STORE, "ss:session-create:5");
             error!("{}, {}", e, err);
@@ -152,6 +205,66 @@ pub fn session_create(
     Ok(())
 }
 
+/// Users of an OpenID Connect provider are recognized by their `sub` claim alone, since the
+/// name and email a provider reports may change or be chosen by the user. The first time a user
+/// signs in they're linked to the account carrying their name, but only if the provider has
+/// verified that the user owns that account's email.
+fn oidc_account(msg: &proto::SessionCreate, conn: &mut RouteConn) -> NetResult<proto::Account> {
+    if msg.get_subject().is_empty() {
+        return Err(NetError::new(ErrCode::BUG, "ss:session-create:6"));
+    }
+    let mut identity_get = proto::AccountIdentityGet::new();
+    identity_get.set_provider(msg.get_provider());
+    identity_get.set_subject(msg.get_subject().to_string());
+    match conn.route::<proto::AccountIdentityGet, proto::AccountIdentity>(&identity_get) {
+        Ok(identity) => return account_by_id(identity.get_account_id(), conn),
+        Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => (),
+        Err(err) => return Err(err),
+    }
+
+    let mut account_get = proto::AccountGet::new();
+    account_get.set_name(msg.get_name().to_string());
+    let account = match conn.route::<proto::AccountGet, proto::Account>(&account_get) {
+        Ok(account) => {
+            if !msg.get_email_verified() || msg.get_email().is_empty() ||
+                !account.get_email().eq_ignore_ascii_case(msg.get_email())
+            {
+                return Err(NetError::new(
+                    ErrCode::ENTITY_CONFLICT,
+                    "ss:session-create:7",
+                ));
+            }
+            account
+        }
+        Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => {
+            let mut account_create = proto::AccountCreate::new();
+            account_create.set_name(msg.get_name().to_string());
+            account_create.set_email(msg.get_email().to_string());
+            conn.route::<proto::AccountCreate, proto::Account>(&account_create)?
+        }
+        Err(err) => return Err(err),
+    };
+
+    let mut identity_create = proto::AccountIdentityCreate::new();
+    identity_create.set_provider(msg.get_provider());
+    identity_create.set_subject(msg.get_subject().to_string());
+    identity_create.set_account_id(account.get_id());
+    let identity = conn.route::<proto::AccountIdentityCreate, proto::AccountIdentity>(
+        &identity_create,
+    )?;
+    // A concurrent first sign-in of the same user may have linked them first.
+    if identity.get_account_id() != account.get_id() {
+        return account_by_id(identity.get_account_id(), conn);
+    }
+    Ok(account)
+}
+
+fn account_by_id(id: u64, conn: &mut RouteConn) -> NetResult<proto::Account> {
+    let mut account_get = proto::AccountGetId::new();
+    account_get.set_id(id);
+    conn.route::<proto::AccountGetId, proto::Account>(&account_get)
+}
+
 pub fn session_get(
     req: &mut Message,
     conn: &mut RouteConn,
@@ -323,6 +436,133 @@ pub fn account_invitation_list(
     Ok(())
 }
 
+pub fn access_token_create(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::AccessTokenCreate>()?;
+    let secret = match generate_access_token_secret() {
+        Ok(secret) => secret,
+        Err(e) => {
+            let err = NetError::new(ErrCode::SYS, "ss:access-token-create:0");
+            error!("{}, {}", e, err);
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+    };
+    match state.datastore.create_access_token(
+        &msg,
+        &hash::hash_bytes(&secret),
+    ) {
+        Ok(mut token) => {
+            let mut key = proto::AccessTokenKey::new();
+            key.set_account_id(token.get_account_id());
+            key.set_id(token.get_id());
+            key.set_secret(secret);
+            token.set_token(encode_access_token(&key)?);
+            conn.route_reply(req, &token)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "ss:access-token-create:1");
+            error!("{}, {}", e, err);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn access_token_list(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::AccessTokenListRequest>()?;
+    match state.datastore.list_access_tokens(&msg) {
+        Ok(response) => conn.route_reply(req, &response)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "ss:access-token-list:0");
+            error!("{}, {}", e, err);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn access_token_revoke(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::AccessTokenRevoke>()?;
+    match state.datastore.revoke_access_token(&msg) {
+        Ok(true) => conn.route_reply(req, &net::NetOk::new())?,
+        Ok(false) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "ss:access-token-revoke:0");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "ss:access-token-revoke:1");
+            error!("{}, {}", e, err);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+// The comparison takes the same time however much of the hash matches, so a caller can't find
+// the hash of a secret one byte at a time.
+fn secret_matches(token_hash: &str, secret: &[u8]) -> bool {
+    let secret_hash = hash::hash_bytes(secret);
+    token_hash.len() == secret_hash.len() &&
+        memcmp::eq(token_hash.as_bytes(), secret_hash.as_bytes())
+}
+
+/// Exchange a personal access token for a session. Sessions established this way never carry
+/// any feature flags and are restricted to the origins and scopes of their token.
+pub fn access_token_validate(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::AccessTokenValidate>()?;
+    let token = match state.datastore.get_access_token(&msg) {
+        Ok(Some((ref token, ref token_hash)))
+            if secret_matches(token_hash, msg.get_key().get_secret()) => token.clone(),
+        Ok(_) => {
+            let err = NetError::new(ErrCode::ACCESS_DENIED, "ss:access-token-validate:0");
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "ss:access-token-validate:1");
+            error!("{}, {}", e, err);
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+    };
+    let mut account_get = proto::AccountGetId::new();
+    account_get.set_id(token.get_account_id());
+    match state.datastore.get_account_by_id(&account_get) {
+        Ok(Some(account)) => {
+            let mut session: proto::Session = account.into();
+            session.set_flags(FeatureFlags::default().bits());
+            session.set_access_token(token);
+            conn.route_reply(req, &session)?;
+        }
+        Ok(None) => {
+            let err = NetError::new(ErrCode::ACCESS_DENIED, "ss:access-token-validate:2");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "ss:access-token-validate:3");
+            error!("{}, {}", e, err);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 fn assign_permissions(name: &str, flags: &mut FeatureFlags, state: &ServerState) {
     match state.github.app_installation_token(
         state.permissions.app_install_id,
@@ -26,11 +26,14 @@ use github_api_client::GitHubClient;
 use hab_net::app::prelude::*;
 use hab_net::privilege::FeatureFlags;
 use protocol::{message, sessionsrv as proto};
+use rand::{OsRng, Rng};
 
 use config::{Config, PermissionsCfg};
 use data_store::DataStore;
 use error::{SrvError, SrvResult};
 
+const ACCESS_TOKEN_SECRET_LEN: usize = 32;
+
 lazy_static! {
     static ref DISPATCH_TABLE: DispatchTable<SessionSrv> = {
         let mut map = DispatchTable::new();
@@ -40,6 +43,10 @@ lazy_static! {
         map.register(proto::AccountUpdate::descriptor_static(None), handlers::account_update);
         map.register(proto::AccountFindOrCreate::descriptor_static(None),
             handlers::account_find_or_create);
+        map.register(proto::AccountIdentityGet::descriptor_static(None),
+            handlers::account_identity_get);
+        map.register(proto::AccountIdentityCreate::descriptor_static(None),
+            handlers::account_identity_create);
         map.register(proto::SessionCreate::descriptor_static(None), handlers::session_create);
         map.register(proto::SessionGet::descriptor_static(None), handlers::session_get);
         map.register(proto::AccountInvitationListRequest::descriptor_static(None),
@@ -61,6 +68,14 @@ lazy_static! {
             handlers::account_origin_create);
         map.register(proto::AccountOriginRemove::descriptor_static(None),
             handlers::account_origin_remove);
+        map.register(proto::AccessTokenCreate::descriptor_static(None),
+            handlers::access_token_create);
+        map.register(proto::AccessTokenListRequest::descriptor_static(None),
+            handlers::access_token_list);
+        map.register(proto::AccessTokenRevoke::descriptor_static(None),
+            handlers::access_token_revoke);
+        map.register(proto::AccessTokenValidate::descriptor_static(None),
+            handlers::access_token_validate);
         map
     };
 
@@ -206,6 +221,23 @@ pub fn decode_token(value: &str) -> SrvResult<proto::SessionToken> {
     Ok(token)
 }
 
+pub fn encode_access_token(key: &proto::AccessTokenKey) -> SrvResult<String> {
+    let bytes = message::encode(key)?;
+    Ok(format!(
+        "{}{}",
+        proto::ACCESS_TOKEN_PREFIX,
+        base64::encode(&bytes)
+    ))
+}
+
+/// Only a hash of the secret is persisted, the owner of the token is shown the secret once.
+pub fn generate_access_token_secret() -> SrvResult<Vec<u8>> {
+    let mut rng = OsRng::new().map_err(SrvError::AccessTokenSecret)?;
+    let mut secret = vec![0; ACCESS_TOKEN_SECRET_LEN];
+    rng.fill_bytes(&mut secret);
+    Ok(secret)
+}
+
 pub fn run(config: Config) -> AppResult<(), SrvError> {
     app_start::<SessionSrv>(config)
 }
@@ -250,4 +282,26 @@ mod test {
             "CL3Ag7z4tvaAChCUpgMYACIoZDFmODI3NDc3YTk4ODUyM2E0ZGUyY2JmZjgwNWEyN2ZmOTZkNmIzNQ=="
         );
     }
+
+    #[test]
+    fn encode_access_token_key() {
+        let mut key = proto::AccessTokenKey::new();
+        key.set_account_id(721096797631602749);
+        key.set_id(42);
+        key.set_secret(vec![1, 2, 3]);
+        let encoded = encode_access_token(&key).unwrap();
+        assert!(encoded.starts_with(proto::ACCESS_TOKEN_PREFIX));
+
+        let bytes = base64::decode(&encoded[proto::ACCESS_TOKEN_PREFIX.len()..]).unwrap();
+        let decoded: proto::AccessTokenKey = message::decode(&bytes).unwrap();
+        assert_eq!(decoded, key);
+    }
+
+    #[test]
+    fn access_token_secrets_differ() {
+        let first = generate_access_token_secret().unwrap();
+        let second = generate_access_token_secret().unwrap();
+        assert_eq!(first.len(), ACCESS_TOKEN_SECRET_LEN);
+        assert!(first != second);
+    }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use protobuf;
 use protocol::sessionsrv;
 use sessionsrv::data_store::DataStore;
 
@@ -67,6 +68,51 @@ fn get_account_by_id() {
     assert_eq!(bobo.get_name(), bobo2.get_name());
 }
 
+#[test]
+fn account_identities() {
+    let ds = datastore_test!(DataStore);
+    let bobo = create_bobo_account(&ds);
+
+    let mut ig = sessionsrv::AccountIdentityGet::new();
+    ig.set_provider(sessionsrv::OAuthProvider::OIDC);
+    ig.set_subject(String::from("abc123"));
+    assert!(
+        ds.get_account_identity(&ig)
+            .expect("Should run without error")
+            .is_none()
+    );
+
+    let mut ic = sessionsrv::AccountIdentityCreate::new();
+    ic.set_provider(sessionsrv::OAuthProvider::OIDC);
+    ic.set_subject(String::from("abc123"));
+    ic.set_account_id(bobo.get_id());
+    let identity = ds.create_account_identity(&ic).expect(
+        "Should link identity",
+    );
+    assert_eq!(identity.get_account_id(), bobo.get_id());
+
+    let identity = ds.get_account_identity(&ig)
+        .expect("Should run without error")
+        .expect("Identity should exist");
+    assert_eq!(identity.get_provider(), sessionsrv::OAuthProvider::OIDC);
+    assert_eq!(identity.get_account_id(), bobo.get_id());
+
+    // An identity stays linked to the account it was first linked to
+    ic.set_account_id(bobo.get_id() + 1);
+    let identity = ds.create_account_identity(&ic).expect(
+        "Should run without error",
+    );
+    assert_eq!(identity.get_account_id(), bobo.get_id());
+
+    // The same subject at another provider is someone else
+    ig.set_provider(sessionsrv::OAuthProvider::GitHub);
+    assert!(
+        ds.get_account_identity(&ig)
+            .expect("Should run without error")
+            .is_none()
+    );
+}
+
 #[test]
 fn delete_origin() {
     let ds = datastore_test!(DataStore);
@@ -98,3 +144,74 @@ fn delete_origin() {
 
     assert_eq!(0, accounts2.get_origins().len());
 }
+
+#[test]
+fn access_tokens() {
+    let ds = datastore_test!(DataStore);
+    let bobo = create_bobo_account(&ds);
+
+    let mut atc = sessionsrv::AccessTokenCreate::new();
+    atc.set_account_id(bobo.get_id());
+    atc.set_name(String::from("ci"));
+    atc.set_origins(protobuf::RepeatedField::from_vec(vec![String::from("core")]));
+    atc.set_scopes(vec![sessionsrv::AccessTokenScope::Upload]);
+    let token = ds.create_access_token(&atc, "hash")
+        .expect("Should create access token");
+    assert_eq!(token.get_name(), "ci");
+    assert_eq!(token.get_origins(), &[String::from("core")]);
+    assert_eq!(token.get_scopes(), &[sessionsrv::AccessTokenScope::Upload]);
+    assert_eq!(token.get_expires_at(), 0);
+
+    let mut key = sessionsrv::AccessTokenKey::new();
+    key.set_account_id(bobo.get_id());
+    key.set_id(token.get_id());
+    let mut atv = sessionsrv::AccessTokenValidate::new();
+    atv.set_key(key);
+    let (found, hash) = ds.get_access_token(&atv)
+        .expect("Should run without error")
+        .expect("Token should exist");
+    assert_eq!(found.get_id(), token.get_id());
+    assert_eq!(hash, "hash");
+
+    let mut atl = sessionsrv::AccessTokenListRequest::new();
+    atl.set_account_id(bobo.get_id());
+    let list = ds.list_access_tokens(&atl).expect("Should list tokens");
+    assert_eq!(list.get_tokens().len(), 1);
+
+    let mut atr = sessionsrv::AccessTokenRevoke::new();
+    atr.set_account_id(bobo.get_id());
+    atr.set_id(token.get_id());
+    assert!(ds.revoke_access_token(&atr).expect("Should revoke token"));
+    assert!(!ds.revoke_access_token(&atr).expect("Should run without error"));
+    assert!(
+        ds.get_access_token(&atv)
+            .expect("Should run without error")
+            .is_none()
+    );
+    let list = ds.list_access_tokens(&atl).expect("Should list tokens");
+    assert!(list.get_tokens().is_empty());
+}
+
+#[test]
+fn expired_access_token() {
+    let ds = datastore_test!(DataStore);
+    let bobo = create_bobo_account(&ds);
+
+    let mut atc = sessionsrv::AccessTokenCreate::new();
+    atc.set_account_id(bobo.get_id());
+    atc.set_name(String::from("expired"));
+    atc.set_scopes(vec![sessionsrv::AccessTokenScope::Promote]);
+    atc.set_expires_at(1);
+    let token = ds.create_access_token(&atc, "hash")
+        .expect("Should create access token");
+    assert_eq!(token.get_expires_at(), 1);
+
+    let mut atv = sessionsrv::AccessTokenValidate::new();
+    atv.mut_key().set_account_id(bobo.get_id());
+    atv.mut_key().set_id(token.get_id());
+    assert!(
+        ds.get_access_token(&atv)
+            .expect("Should run without error")
+            .is_none()
+    );
+}
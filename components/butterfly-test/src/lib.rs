@@ -465,17 +465,27 @@ impl SwimNet {
     }
 
     pub fn add_service(&mut self, member: usize, package: &str) {
+        self.insert_service(member, package, true);
+    }
+
+    /// Add a service which hasn't been initialized yet, such as one still waiting on its binds.
+    pub fn add_starting_service(&mut self, member: usize, package: &str) {
+        self.insert_service(member, package, false);
+    }
+
+    fn insert_service(&mut self, member: usize, package: &str, initialized: bool) {
         let ident = PackageIdent::from_str(package).expect(
             "package needs to be a fully qualified package identifier",
         );
         let sg = ServiceGroup::new(None, ident.name(), "prod", None).unwrap();
-        let s = Service::new(
+        let mut s = Service::new(
             self[member].member_id().to_string(),
             &ident,
             &sg,
             &SysInfo::default(),
             None,
         );
+        s.set_initialized(initialized);
         self[member].insert_service(s);
     }
 
@@ -143,7 +143,7 @@ impl Serialize for swim::Service {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("service", 7)?;
+        let mut strukt = serializer.serialize_struct("service", 8)?;
         let cfg = toml::from_slice(self.get_cfg()).unwrap_or(toml::value::Table::default());
         strukt.serialize_field("member_id", self.get_member_id())?;
         strukt.serialize_field(
@@ -161,6 +161,22 @@ impl Serialize for swim::Service {
             "initialized",
             &self.get_initialized(),
         )?;
+        if self.has_roles() {
+            strukt.serialize_field("roles", self.get_roles())?;
+        }
+        strukt.end()
+    }
+}
+
+impl Serialize for swim::RoleAssignment {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("role_assignment", 3)?;
+        strukt.serialize_field("version", &self.get_version())?;
+        strukt.serialize_field("active", self.get_active())?;
+        strukt.serialize_field("shards", self.get_shards())?;
         strukt.end()
     }
 }
@@ -2061,6 +2061,7 @@ pub struct Service {
     pkg: ::protobuf::SingularField<::std::string::String>,
     cfg: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     sys: ::protobuf::SingularPtrField<SysInfo>,
+    roles: ::protobuf::SingularPtrField<RoleAssignment>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -2354,6 +2355,47 @@ impl Service {
     fn mut_sys_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<SysInfo> {
         &mut self.sys
     }
+
+    // optional .RoleAssignment roles = 13;
+
+    pub fn clear_roles(&mut self) {
+        self.roles.clear();
+    }
+
+    pub fn has_roles(&self) -> bool {
+        self.roles.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_roles(&mut self, v: RoleAssignment) {
+        self.roles = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_roles(&mut self) -> &mut RoleAssignment {
+        if self.roles.is_none() {
+            self.roles.set_default();
+        }
+        self.roles.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_roles(&mut self) -> RoleAssignment {
+        self.roles.take().unwrap_or_else(|| RoleAssignment::new())
+    }
+
+    pub fn get_roles(&self) -> &RoleAssignment {
+        self.roles.as_ref().unwrap_or_else(|| RoleAssignment::default_instance())
+    }
+
+    fn get_roles_for_reflect(&self) -> &::protobuf::SingularPtrField<RoleAssignment> {
+        &self.roles
+    }
+
+    fn mut_roles_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<RoleAssignment> {
+        &mut self.roles
+    }
 }
 
 impl ::protobuf::Message for Service {
@@ -2363,6 +2405,11 @@ impl ::protobuf::Message for Service {
                 return false;
             }
         };
+        for v in &self.roles {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -2399,6 +2446,9 @@ impl ::protobuf::Message for Service {
                 12 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.sys)?;
                 },
+                13 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.roles)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2433,6 +2483,10 @@ impl ::protobuf::Message for Service {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if let Some(ref v) = self.roles.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2462,6 +2516,11 @@ impl ::protobuf::Message for Service {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if let Some(ref v) = self.roles.as_ref() {
+            os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2541,6 +2600,11 @@ impl ::protobuf::MessageStatic for Service {
                     Service::get_sys_for_reflect,
                     Service::mut_sys_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RoleAssignment>>(
+                    "roles",
+                    Service::get_roles_for_reflect,
+                    Service::mut_roles_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Service>(
                     "Service",
                     fields,
@@ -2560,6 +2624,7 @@ impl ::protobuf::Clear for Service {
         self.clear_pkg();
         self.clear_cfg();
         self.clear_sys();
+        self.clear_roles();
         self.unknown_fields.clear();
     }
 }
@@ -5607,6 +5672,278 @@ impl ::protobuf::reflect::ProtobufValue for Wire {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct RoleAssignment {
+    // message fields
+    version: ::std::option::Option<u64>,
+    active: ::protobuf::RepeatedField<::std::string::String>,
+    shards: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for RoleAssignment {}
+
+impl RoleAssignment {
+    pub fn new() -> RoleAssignment {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static RoleAssignment {
+        static mut instance: ::protobuf::lazy::Lazy<RoleAssignment> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RoleAssignment,
+        };
+        unsafe {
+            instance.get(RoleAssignment::new)
+        }
+    }
+
+    // optional uint64 version = 1;
+
+    pub fn clear_version(&mut self) {
+        self.version = ::std::option::Option::None;
+    }
+
+    pub fn has_version(&self) -> bool {
+        self.version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u64) {
+        self.version = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_version(&self) -> u64 {
+        self.version.unwrap_or(0)
+    }
+
+    fn get_version_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.version
+    }
+
+    fn mut_version_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.version
+    }
+
+    // repeated string active = 2;
+
+    pub fn clear_active(&mut self) {
+        self.active.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_active(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.active = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_active(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.active
+    }
+
+    // Take field
+    pub fn take_active(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.active, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_active(&self) -> &[::std::string::String] {
+        &self.active
+    }
+
+    fn get_active_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.active
+    }
+
+    fn mut_active_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.active
+    }
+
+    // repeated string shards = 3;
+
+    pub fn clear_shards(&mut self) {
+        self.shards.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shards(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.shards = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_shards(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.shards
+    }
+
+    // Take field
+    pub fn take_shards(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.shards, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_shards(&self) -> &[::std::string::String] {
+        &self.shards
+    }
+
+    fn get_shards_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.shards
+    }
+
+    fn mut_shards_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.shards
+    }
+}
+
+impl ::protobuf::Message for RoleAssignment {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.version = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.active)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.shards)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.version {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.active {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.shards {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.version {
+            os.write_uint64(1, v)?;
+        }
+        for v in &self.active {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.shards {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for RoleAssignment {
+    fn new() -> RoleAssignment {
+        RoleAssignment::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<RoleAssignment>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "version",
+                    RoleAssignment::get_version_for_reflect,
+                    RoleAssignment::mut_version_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "active",
+                    RoleAssignment::get_active_for_reflect,
+                    RoleAssignment::mut_active_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "shards",
+                    RoleAssignment::get_shards_for_reflect,
+                    RoleAssignment::mut_shards_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RoleAssignment>(
+                    "RoleAssignment",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for RoleAssignment {
+    fn clear(&mut self) {
+        self.clear_version();
+        self.clear_active();
+        self.clear_shards();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RoleAssignment {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RoleAssignment {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x14protocols/swim.proto\"\xdc\x01\n\x06Member\x12\x0e\n\x02id\x18\x01\
     \x20\x01(\tR\x02id\x12\x20\n\x0bincarnation\x18\x02\x20\x01(\x04R\x0binc\
@@ -5630,22 +5967,23 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x20\x01(\x04R\x0bsuitability\x12(\n\x06status\x18\x05\x20\x01(\x0e2\x10\
     .Election.StatusR\x06status\x12\x14\n\x05votes\x18\x06\x20\x03(\tR\x05vo\
     tes\"1\n\x06Status\x12\x0b\n\x07Running\x10\x01\x12\x0c\n\x08NoQuorum\
-    \x10\x02\x12\x0c\n\x08Finished\x10\x03\"\xcf\x01\n\x07Service\x12\x1b\n\
+    \x10\x02\x12\x0c\n\x08Finished\x10\x03\"\xf6\x01\n\x07Service\x12\x1b\n\
     \tmember_id\x18\x01\x20\x01(\tR\x08memberId\x12#\n\rservice_group\x18\
     \x02\x20\x01(\tR\x0cserviceGroup\x12\x20\n\x0bincarnation\x18\x03\x20\
     \x01(\x04R\x0bincarnation\x12\x20\n\x0binitialized\x18\x08\x20\x01(\x08R\
     \x0binitialized\x12\x10\n\x03pkg\x18\t\x20\x01(\tR\x03pkg\x12\x10\n\x03c\
     fg\x18\n\x20\x01(\x0cR\x03cfg\x12\x1a\n\x03sys\x18\x0c\x20\x01(\x0b2\x08\
-    .SysInfoR\x03sys\"\x8c\x01\n\rServiceConfig\x12#\n\rservice_group\x18\
-    \x01\x20\x01(\tR\x0cserviceGroup\x12\x20\n\x0bincarnation\x18\x02\x20\
-    \x01(\x04R\x0bincarnation\x12\x1c\n\tencrypted\x18\x03\x20\x01(\x08R\ten\
-    crypted\x12\x16\n\x06config\x18\x04\x20\x01(\x0cR\x06config\"\xa2\x01\n\
-    \x0bServiceFile\x12#\n\rservice_group\x18\x01\x20\x01(\tR\x0cserviceGrou\
-    p\x12\x20\n\x0bincarnation\x18\x02\x20\x01(\x04R\x0bincarnation\x12\x1c\
-    \n\tencrypted\x18\x03\x20\x01(\x08R\tencrypted\x12\x1a\n\x08filename\x18\
-    \x04\x20\x01(\tR\x08filename\x12\x12\n\x04body\x18\x05\x20\x01(\x0cR\x04\
-    body\"\xf3\x01\n\x07SysInfo\x12\x19\n\x02ip\x18\x01\x20\x01(\t:\t127.0.0\
-    .1R\x02ip\x12%\n\x08hostname\x18\x02\x20\x01(\t:\tlocalhostR\x08hostname\
+    .SysInfoR\x03sys\x12%\n\x05roles\x18\r\x20\x01(\x0b2\x0f.RoleAssignmentR\
+    \x05roles\"\x8c\x01\n\rServiceConfig\x12#\n\rservice_group\x18\x01\x20\
+    \x01(\tR\x0cserviceGroup\x12\x20\n\x0bincarnation\x18\x02\x20\x01(\x04R\
+    \x0bincarnation\x12\x1c\n\tencrypted\x18\x03\x20\x01(\x08R\tencrypted\
+    \x12\x16\n\x06config\x18\x04\x20\x01(\x0cR\x06config\"\xa2\x01\n\x0bServ\
+    iceFile\x12#\n\rservice_group\x18\x01\x20\x01(\tR\x0cserviceGroup\x12\
+    \x20\n\x0bincarnation\x18\x02\x20\x01(\x04R\x0bincarnation\x12\x1c\n\ten\
+    crypted\x18\x03\x20\x01(\x08R\tencrypted\x12\x1a\n\x08filename\x18\x04\
+    \x20\x01(\tR\x08filename\x12\x12\n\x04body\x18\x05\x20\x01(\x0cR\x04body\
+    \"\xf3\x01\n\x07SysInfo\x12\x19\n\x02ip\x18\x01\x20\x01(\t:\t127.0.0.1R\
+    \x02ip\x12%\n\x08hostname\x18\x02\x20\x01(\t:\tlocalhostR\x08hostname\
     \x12&\n\tgossip_ip\x18\x03\x20\x01(\t:\t127.0.0.1R\x08gossipIp\x12\x1f\n\
     \x0bgossip_port\x18\x04\x20\x01(\rR\ngossipPort\x121\n\x0fhttp_gateway_i\
     p\x18\x05\x20\x01(\t:\t127.0.0.1R\rhttpGatewayIp\x12*\n\x11http_gateway_\
@@ -5672,276 +6010,305 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x10\x08\x12\r\n\tDeparture\x10\tB\t\n\x07payload\"T\n\x04Wire\x12\x1c\n\
     \tencrypted\x18\x01\x20\x01(\x08R\tencrypted\x12\x14\n\x05nonce\x18\x02\
     \x20\x01(\x0cR\x05nonce\x12\x18\n\x07payload\x18\x03\x20\x01(\x0cR\x07pa\
-    yloadJ\xc7,\n\x06\x12\x04\0\0~\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\
-    \x02\x04\0\x12\x04\x02\0\n\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x0e\n\
-    \x0b\n\x04\x04\0\x02\0\x12\x03\x03\x02\x19\n\x0c\n\x05\x04\0\x02\0\x04\
-    \x12\x03\x03\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x0b\x11\n\x0c\
-    \n\x05\x04\0\x02\0\x01\x12\x03\x03\x12\x14\n\x0c\n\x05\x04\0\x02\0\x03\
-    \x12\x03\x03\x17\x18\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x02\"\n\x0c\n\
-    \x05\x04\0\x02\x01\x04\x12\x03\x04\x02\n\n\x0c\n\x05\x04\0\x02\x01\x05\
-    \x12\x03\x04\x0b\x11\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x12\x1d\n\
-    \x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x20!\n\x0b\n\x04\x04\0\x02\x02\
-    \x12\x03\x05\x02\x1e\n\x0c\n\x05\x04\0\x02\x02\x04\x12\x03\x05\x02\n\n\
-    \x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x0b\x11\n\x0c\n\x05\x04\0\x02\
-    \x02\x01\x12\x03\x05\x12\x19\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\
-    \x1c\x1d\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x02\x1f\n\x0c\n\x05\x04\0\
-    \x02\x03\x04\x12\x03\x06\x02\n\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\
-    \x0b\x10\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06\x11\x1a\n\x0c\n\x05\
-    \x04\0\x02\x03\x03\x12\x03\x06\x1d\x1e\n\x0b\n\x04\x04\0\x02\x04\x12\x03\
-    \x07\x02!\n\x0c\n\x05\x04\0\x02\x04\x04\x12\x03\x07\x02\n\n\x0c\n\x05\
-    \x04\0\x02\x04\x05\x12\x03\x07\x0b\x10\n\x0c\n\x05\x04\0\x02\x04\x01\x12\
-    \x03\x07\x11\x1c\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x1f\x20\n\x0b\
-    \n\x04\x04\0\x02\x05\x12\x03\x08\x021\n\x0c\n\x05\x04\0\x02\x05\x04\x12\
-    \x03\x08\x02\n\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x08\x0b\x0f\n\x0c\n\
-    \x05\x04\0\x02\x05\x01\x12\x03\x08\x10\x1a\n\x0c\n\x05\x04\0\x02\x05\x03\
-    \x12\x03\x08\x1d\x1e\n\x0c\n\x05\x04\0\x02\x05\x08\x12\x03\x08\x1f0\n\
-    \x0c\n\x05\x04\0\x02\x05\x07\x12\x03\x08*/\n\x0b\n\x04\x04\0\x02\x06\x12\
-    \x03\t\x02/\n\x0c\n\x05\x04\0\x02\x06\x04\x12\x03\t\x02\n\n\x0c\n\x05\
-    \x04\0\x02\x06\x05\x12\x03\t\x0b\x0f\n\x0c\n\x05\x04\0\x02\x06\x01\x12\
-    \x03\t\x10\x18\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03\t\x1b\x1c\n\x0c\n\
-    \x05\x04\0\x02\x06\x08\x12\x03\t\x1d.\n\x0c\n\x05\x04\0\x02\x06\x07\x12\
-    \x03\t(-\n\n\n\x02\x04\x01\x12\x04\x0c\0\x0f\x01\n\n\n\x03\x04\x01\x01\
-    \x12\x03\x0c\x08\x0c\n\x0b\n\x04\x04\x01\x02\0\x12\x03\r\x02\x1b\n\x0c\n\
-    \x05\x04\x01\x02\0\x04\x12\x03\r\x02\n\n\x0c\n\x05\x04\x01\x02\0\x06\x12\
-    \x03\r\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\r\x12\x16\n\x0c\n\
-    \x05\x04\x01\x02\0\x03\x12\x03\r\x19\x1a\n\x0b\n\x04\x04\x01\x02\x01\x12\
-    \x03\x0e\x02!\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03\x0e\x02\n\n\x0c\n\
-    \x05\x04\x01\x02\x01\x06\x12\x03\x0e\x0b\x11\n\x0c\n\x05\x04\x01\x02\x01\
-    \x01\x12\x03\x0e\x12\x1c\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0e\x1f\
-    \x20\n\n\n\x02\x04\x02\x12\x04\x11\0\x14\x01\n\n\n\x03\x04\x02\x01\x12\
-    \x03\x11\x08\x0b\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x12\x02\x1b\n\x0c\n\
-    \x05\x04\x02\x02\0\x04\x12\x03\x12\x02\n\n\x0c\n\x05\x04\x02\x02\0\x06\
-    \x12\x03\x12\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x12\x12\x16\n\
-    \x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x12\x19\x1a\n\x0b\n\x04\x04\x02\x02\
-    \x01\x12\x03\x13\x02!\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\x03\x13\x02\n\
-    \n\x0c\n\x05\x04\x02\x02\x01\x06\x12\x03\x13\x0b\x11\n\x0c\n\x05\x04\x02\
-    \x02\x01\x01\x12\x03\x13\x12\x1c\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\
-    \x13\x1f\x20\n\n\n\x02\x04\x03\x12\x04\x16\0\x19\x01\n\n\n\x03\x04\x03\
-    \x01\x12\x03\x16\x08\x0f\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x17\x02\x1b\n\
-    \x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x17\x02\n\n\x0c\n\x05\x04\x03\x02\0\
-    \x06\x12\x03\x17\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x17\x12\
-    \x16\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x17\x19\x1a\n\x0b\n\x04\x04\
-    \x03\x02\x01\x12\x03\x18\x02\x1d\n\x0c\n\x05\x04\x03\x02\x01\x04\x12\x03\
-    \x18\x02\n\n\x0c\n\x05\x04\x03\x02\x01\x06\x12\x03\x18\x0b\x11\n\x0c\n\
-    \x05\x04\x03\x02\x01\x01\x12\x03\x18\x12\x18\n\x0c\n\x05\x04\x03\x02\x01\
-    \x03\x12\x03\x18\x1b\x1c\n\n\n\x02\x04\x04\x12\x04\x1b\0\x20\x01\n\n\n\
-    \x03\x04\x04\x01\x12\x03\x1b\x08\x12\n\x0b\n\x04\x04\x04\x04\0\x12\x03\
-    \x1c\x02F\n\x0c\n\x05\x04\x04\x04\0\x01\x12\x03\x1c\x07\r\n\r\n\x06\x04\
-    \x04\x04\0\x02\0\x12\x03\x1c\x10\x1a\n\x0e\n\x07\x04\x04\x04\0\x02\0\x01\
-    \x12\x03\x1c\x10\x15\n\x0e\n\x07\x04\x04\x04\0\x02\0\x02\x12\x03\x1c\x18\
-    \x19\n\r\n\x06\x04\x04\x04\0\x02\x01\x12\x03\x1c\x1b'\n\x0e\n\x07\x04\
-    \x04\x04\0\x02\x01\x01\x12\x03\x1c\x1b\"\n\x0e\n\x07\x04\x04\x04\0\x02\
-    \x01\x02\x12\x03\x1c%&\n\r\n\x06\x04\x04\x04\0\x02\x02\x12\x03\x1c(6\n\
-    \x0e\n\x07\x04\x04\x04\0\x02\x02\x01\x12\x03\x1c(1\n\x0e\n\x07\x04\x04\
-    \x04\0\x02\x02\x02\x12\x03\x1c45\n\r\n\x06\x04\x04\x04\0\x02\x03\x12\x03\
-    \x1c7D\n\x0e\n\x07\x04\x04\x04\0\x02\x03\x01\x12\x03\x1c7?\n\x0e\n\x07\
-    \x04\x04\x04\0\x02\x03\x02\x12\x03\x1cBC\n\x0b\n\x04\x04\x04\x02\0\x12\
-    \x03\x1e\x02\x1d\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x03\x1e\x02\n\n\x0c\n\
-    \x05\x04\x04\x02\0\x06\x12\x03\x1e\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\
-    \x12\x03\x1e\x12\x18\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1e\x1b\x1c\n\
-    \x0b\n\x04\x04\x04\x02\x01\x12\x03\x1f\x02\x1d\n\x0c\n\x05\x04\x04\x02\
-    \x01\x04\x12\x03\x1f\x02\n\n\x0c\n\x05\x04\x04\x02\x01\x06\x12\x03\x1f\
-    \x0b\x11\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x1f\x12\x18\n\x0c\n\x05\
-    \x04\x04\x02\x01\x03\x12\x03\x1f\x1b\x1c\n\n\n\x02\x04\x05\x12\x04\"\0+\
-    \x01\n\n\n\x03\x04\x05\x01\x12\x03\"\x08\x10\n\x0b\n\x04\x04\x05\x04\0\
-    \x12\x03#\x02:\n\x0c\n\x05\x04\x05\x04\0\x01\x12\x03#\x07\r\n\r\n\x06\
-    \x04\x05\x04\0\x02\0\x12\x03#\x10\x1c\n\x0e\n\x07\x04\x05\x04\0\x02\0\
-    \x01\x12\x03#\x10\x17\n\x0e\n\x07\x04\x05\x04\0\x02\0\x02\x12\x03#\x1a\
-    \x1b\n\r\n\x06\x04\x05\x04\0\x02\x01\x12\x03#\x1d*\n\x0e\n\x07\x04\x05\
-    \x04\0\x02\x01\x01\x12\x03#\x1d%\n\x0e\n\x07\x04\x05\x04\0\x02\x01\x02\
-    \x12\x03#()\n\r\n\x06\x04\x05\x04\0\x02\x02\x12\x03#+8\n\x0e\n\x07\x04\
-    \x05\x04\0\x02\x02\x01\x12\x03#+3\n\x0e\n\x07\x04\x05\x04\0\x02\x02\x02\
-    \x12\x03#67\n\x0b\n\x04\x04\x05\x02\0\x12\x03%\x02\x20\n\x0c\n\x05\x04\
-    \x05\x02\0\x04\x12\x03%\x02\n\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03%\x0b\
-    \x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03%\x12\x1b\n\x0c\n\x05\x04\x05\
-    \x02\0\x03\x12\x03%\x1e\x1f\n\x0b\n\x04\x04\x05\x02\x01\x12\x03&\x02$\n\
-    \x0c\n\x05\x04\x05\x02\x01\x04\x12\x03&\x02\n\n\x0c\n\x05\x04\x05\x02\
-    \x01\x05\x12\x03&\x0b\x11\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03&\x12\
-    \x1f\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03&\"#\n\x0b\n\x04\x04\x05\x02\
-    \x02\x12\x03'\x02\x1b\n\x0c\n\x05\x04\x05\x02\x02\x04\x12\x03'\x02\n\n\
-    \x0c\n\x05\x04\x05\x02\x02\x05\x12\x03'\x0b\x11\n\x0c\n\x05\x04\x05\x02\
-    \x02\x01\x12\x03'\x12\x16\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03'\x19\
-    \x1a\n\x0b\n\x04\x04\x05\x02\x03\x12\x03(\x02\"\n\x0c\n\x05\x04\x05\x02\
-    \x03\x04\x12\x03(\x02\n\n\x0c\n\x05\x04\x05\x02\x03\x05\x12\x03(\x0b\x11\
-    \n\x0c\n\x05\x04\x05\x02\x03\x01\x12\x03(\x12\x1d\n\x0c\n\x05\x04\x05\
-    \x02\x03\x03\x12\x03(\x20!\n\x0b\n\x04\x04\x05\x02\x04\x12\x03)\x02\x1d\
-    \n\x0c\n\x05\x04\x05\x02\x04\x04\x12\x03)\x02\n\n\x0c\n\x05\x04\x05\x02\
-    \x04\x06\x12\x03)\x0b\x11\n\x0c\n\x05\x04\x05\x02\x04\x01\x12\x03)\x12\
-    \x18\n\x0c\n\x05\x04\x05\x02\x04\x03\x12\x03)\x1b\x1c\n\x0b\n\x04\x04\
-    \x05\x02\x05\x12\x03*\x02\x1c\n\x0c\n\x05\x04\x05\x02\x05\x04\x12\x03*\
-    \x02\n\n\x0c\n\x05\x04\x05\x02\x05\x05\x12\x03*\x0b\x11\n\x0c\n\x05\x04\
-    \x05\x02\x05\x01\x12\x03*\x12\x17\n\x0c\n\x05\x04\x05\x02\x05\x03\x12\
-    \x03*\x1a\x1b\n\n\n\x02\x04\x06\x12\x04-\05\x01\n\n\n\x03\x04\x06\x01\
-    \x12\x03-\x08\x0f\n\x0b\n\x04\x04\x06\x02\0\x12\x03.\x02\x20\n\x0c\n\x05\
-    \x04\x06\x02\0\x04\x12\x03.\x02\n\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03.\
-    \x0b\x11\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03.\x12\x1b\n\x0c\n\x05\x04\
-    \x06\x02\0\x03\x12\x03.\x1e\x1f\n\x0b\n\x04\x04\x06\x02\x01\x12\x03/\x02\
-    $\n\x0c\n\x05\x04\x06\x02\x01\x04\x12\x03/\x02\n\n\x0c\n\x05\x04\x06\x02\
-    \x01\x05\x12\x03/\x0b\x11\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03/\x12\
-    \x1f\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03/\"#\n\x0b\n\x04\x04\x06\x02\
-    \x02\x12\x030\x02\"\n\x0c\n\x05\x04\x06\x02\x02\x04\x12\x030\x02\n\n\x0c\
-    \n\x05\x04\x06\x02\x02\x05\x12\x030\x0b\x11\n\x0c\n\x05\x04\x06\x02\x02\
-    \x01\x12\x030\x12\x1d\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x030\x20!\n\
-    \x0b\n\x04\x04\x06\x02\x03\x12\x031\x02\x20\n\x0c\n\x05\x04\x06\x02\x03\
-    \x04\x12\x031\x02\n\n\x0c\n\x05\x04\x06\x02\x03\x05\x12\x031\x0b\x0f\n\
-    \x0c\n\x05\x04\x06\x02\x03\x01\x12\x031\x10\x1b\n\x0c\n\x05\x04\x06\x02\
-    \x03\x03\x12\x031\x1e\x1f\n\x0b\n\x04\x04\x06\x02\x04\x12\x032\x02\x1a\n\
-    \x0c\n\x05\x04\x06\x02\x04\x04\x12\x032\x02\n\n\x0c\n\x05\x04\x06\x02\
-    \x04\x05\x12\x032\x0b\x11\n\x0c\n\x05\x04\x06\x02\x04\x01\x12\x032\x12\
-    \x15\n\x0c\n\x05\x04\x06\x02\x04\x03\x12\x032\x18\x19\n\x0b\n\x04\x04\
-    \x06\x02\x05\x12\x033\x02\x1a\n\x0c\n\x05\x04\x06\x02\x05\x04\x12\x033\
-    \x02\n\n\x0c\n\x05\x04\x06\x02\x05\x05\x12\x033\x0b\x10\n\x0c\n\x05\x04\
-    \x06\x02\x05\x01\x12\x033\x11\x14\n\x0c\n\x05\x04\x06\x02\x05\x03\x12\
-    \x033\x17\x19\n\x0b\n\x04\x04\x06\x02\x06\x12\x034\x02\x1c\n\x0c\n\x05\
-    \x04\x06\x02\x06\x04\x12\x034\x02\n\n\x0c\n\x05\x04\x06\x02\x06\x06\x12\
-    \x034\x0b\x12\n\x0c\n\x05\x04\x06\x02\x06\x01\x12\x034\x13\x16\n\x0c\n\
-    \x05\x04\x06\x02\x06\x03\x12\x034\x19\x1b\n\n\n\x02\x04\x07\x12\x047\0<\
-    \x01\n\n\n\x03\x04\x07\x01\x12\x037\x08\x15\n\x0b\n\x04\x04\x07\x02\0\
-    \x12\x038\x02$\n\x0c\n\x05\x04\x07\x02\0\x04\x12\x038\x02\n\n\x0c\n\x05\
-    \x04\x07\x02\0\x05\x12\x038\x0b\x11\n\x0c\n\x05\x04\x07\x02\0\x01\x12\
-    \x038\x12\x1f\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x038\"#\n\x0b\n\x04\x04\
-    \x07\x02\x01\x12\x039\x02\"\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x039\x02\
-    \n\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x039\x0b\x11\n\x0c\n\x05\x04\x07\
-    \x02\x01\x01\x12\x039\x12\x1d\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x039\
-    \x20!\n\x0b\n\x04\x04\x07\x02\x02\x12\x03:\x02\x1e\n\x0c\n\x05\x04\x07\
-    \x02\x02\x04\x12\x03:\x02\n\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\x03:\x0b\
-    \x0f\n\x0c\n\x05\x04\x07\x02\x02\x01\x12\x03:\x10\x19\n\x0c\n\x05\x04\
-    \x07\x02\x02\x03\x12\x03:\x1c\x1d\n\x0b\n\x04\x04\x07\x02\x03\x12\x03;\
-    \x02\x1c\n\x0c\n\x05\x04\x07\x02\x03\x04\x12\x03;\x02\n\n\x0c\n\x05\x04\
-    \x07\x02\x03\x05\x12\x03;\x0b\x10\n\x0c\n\x05\x04\x07\x02\x03\x01\x12\
-    \x03;\x11\x17\n\x0c\n\x05\x04\x07\x02\x03\x03\x12\x03;\x1a\x1b\n\n\n\x02\
-    \x04\x08\x12\x04>\0D\x01\n\n\n\x03\x04\x08\x01\x12\x03>\x08\x13\n\x0b\n\
-    \x04\x04\x08\x02\0\x12\x03?\x02$\n\x0c\n\x05\x04\x08\x02\0\x04\x12\x03?\
-    \x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03?\x0b\x11\n\x0c\n\x05\x04\
-    \x08\x02\0\x01\x12\x03?\x12\x1f\n\x0c\n\x05\x04\x08\x02\0\x03\x12\x03?\"\
-    #\n\x0b\n\x04\x04\x08\x02\x01\x12\x03@\x02\"\n\x0c\n\x05\x04\x08\x02\x01\
-    \x04\x12\x03@\x02\n\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03@\x0b\x11\n\
-    \x0c\n\x05\x04\x08\x02\x01\x01\x12\x03@\x12\x1d\n\x0c\n\x05\x04\x08\x02\
-    \x01\x03\x12\x03@\x20!\n\x0b\n\x04\x04\x08\x02\x02\x12\x03A\x02\x1e\n\
-    \x0c\n\x05\x04\x08\x02\x02\x04\x12\x03A\x02\n\n\x0c\n\x05\x04\x08\x02\
-    \x02\x05\x12\x03A\x0b\x0f\n\x0c\n\x05\x04\x08\x02\x02\x01\x12\x03A\x10\
-    \x19\n\x0c\n\x05\x04\x08\x02\x02\x03\x12\x03A\x1c\x1d\n\x0b\n\x04\x04\
-    \x08\x02\x03\x12\x03B\x02\x1f\n\x0c\n\x05\x04\x08\x02\x03\x04\x12\x03B\
-    \x02\n\n\x0c\n\x05\x04\x08\x02\x03\x05\x12\x03B\x0b\x11\n\x0c\n\x05\x04\
-    \x08\x02\x03\x01\x12\x03B\x12\x1a\n\x0c\n\x05\x04\x08\x02\x03\x03\x12\
-    \x03B\x1d\x1e\n\x0b\n\x04\x04\x08\x02\x04\x12\x03C\x02\x1a\n\x0c\n\x05\
-    \x04\x08\x02\x04\x04\x12\x03C\x02\n\n\x0c\n\x05\x04\x08\x02\x04\x05\x12\
-    \x03C\x0b\x10\n\x0c\n\x05\x04\x08\x02\x04\x01\x12\x03C\x11\x15\n\x0c\n\
-    \x05\x04\x08\x02\x04\x03\x12\x03C\x18\x19\n\n\n\x02\x04\t\x12\x04F\0M\
-    \x01\n\n\n\x03\x04\t\x01\x12\x03F\x08\x0f\n\x0b\n\x04\x04\t\x02\0\x12\
-    \x03G\x021\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03G\x02\n\n\x0c\n\x05\x04\t\
-    \x02\0\x05\x12\x03G\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03G\x12\x14\
-    \n\x0c\n\x05\x04\t\x02\0\x03\x12\x03G\x17\x18\n\x0c\n\x05\x04\t\x02\0\
-    \x08\x12\x03G\x190\n\x0c\n\x05\x04\t\x02\0\x07\x12\x03G$/\n\x0b\n\x04\
-    \x04\t\x02\x01\x12\x03H\x027\n\x0c\n\x05\x04\t\x02\x01\x04\x12\x03H\x02\
-    \n\n\x0c\n\x05\x04\t\x02\x01\x05\x12\x03H\x0b\x11\n\x0c\n\x05\x04\t\x02\
-    \x01\x01\x12\x03H\x12\x1a\n\x0c\n\x05\x04\t\x02\x01\x03\x12\x03H\x1d\x1e\
-    \n\x0c\n\x05\x04\t\x02\x01\x08\x12\x03H\x1f6\n\x0c\n\x05\x04\t\x02\x01\
-    \x07\x12\x03H*5\n\x0b\n\x04\x04\t\x02\x02\x12\x03I\x028\n\x0c\n\x05\x04\
-    \t\x02\x02\x04\x12\x03I\x02\n\n\x0c\n\x05\x04\t\x02\x02\x05\x12\x03I\x0b\
-    \x11\n\x0c\n\x05\x04\t\x02\x02\x01\x12\x03I\x12\x1b\n\x0c\n\x05\x04\t\
-    \x02\x02\x03\x12\x03I\x1e\x1f\n\x0c\n\x05\x04\t\x02\x02\x08\x12\x03I\x20\
-    7\n\x0c\n\x05\x04\t\x02\x02\x07\x12\x03I+6\n\x0b\n\x04\x04\t\x02\x03\x12\
-    \x03J\x02\"\n\x0c\n\x05\x04\t\x02\x03\x04\x12\x03J\x02\n\n\x0c\n\x05\x04\
-    \t\x02\x03\x05\x12\x03J\x0b\x11\n\x0c\n\x05\x04\t\x02\x03\x01\x12\x03J\
-    \x12\x1d\n\x0c\n\x05\x04\t\x02\x03\x03\x12\x03J\x20!\n\x0b\n\x04\x04\t\
-    \x02\x04\x12\x03K\x02>\n\x0c\n\x05\x04\t\x02\x04\x04\x12\x03K\x02\n\n\
-    \x0c\n\x05\x04\t\x02\x04\x05\x12\x03K\x0b\x11\n\x0c\n\x05\x04\t\x02\x04\
-    \x01\x12\x03K\x12!\n\x0c\n\x05\x04\t\x02\x04\x03\x12\x03K$%\n\x0c\n\x05\
-    \x04\t\x02\x04\x08\x12\x03K&=\n\x0c\n\x05\x04\t\x02\x04\x07\x12\x03K1<\n\
-    \x0b\n\x04\x04\t\x02\x05\x12\x03L\x02(\n\x0c\n\x05\x04\t\x02\x05\x04\x12\
-    \x03L\x02\n\n\x0c\n\x05\x04\t\x02\x05\x05\x12\x03L\x0b\x11\n\x0c\n\x05\
-    \x04\t\x02\x05\x01\x12\x03L\x12#\n\x0c\n\x05\x04\t\x02\x05\x03\x12\x03L&\
-    '\n\n\n\x02\x04\n\x12\x04O\0Q\x01\n\n\n\x03\x04\n\x01\x12\x03O\x08\x11\n\
-    \x0b\n\x04\x04\n\x02\0\x12\x03P\x02\x20\n\x0c\n\x05\x04\n\x02\0\x04\x12\
-    \x03P\x02\n\n\x0c\n\x05\x04\n\x02\0\x05\x12\x03P\x0b\x11\n\x0c\n\x05\x04\
-    \n\x02\0\x01\x12\x03P\x12\x1b\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03P\x1e\
-    \x1f\n\n\n\x02\x04\x0b\x12\x04S\0^\x01\n\n\n\x03\x04\x0b\x01\x12\x03S\
-    \x08\x0c\n\x0b\n\x04\x04\x0b\x04\0\x12\x03T\x02/\n\x0c\n\x05\x04\x0b\x04\
-    \0\x01\x12\x03T\x07\x0b\n\r\n\x06\x04\x0b\x04\0\x02\0\x12\x03T\x0e\x17\n\
-    \x0e\n\x07\x04\x0b\x04\0\x02\0\x01\x12\x03T\x0e\x12\n\x0e\n\x07\x04\x0b\
-    \x04\0\x02\0\x02\x12\x03T\x15\x16\n\r\n\x06\x04\x0b\x04\0\x02\x01\x12\
-    \x03T\x18\x20\n\x0e\n\x07\x04\x0b\x04\0\x02\x01\x01\x12\x03T\x18\x1b\n\
-    \x0e\n\x07\x04\x0b\x04\0\x02\x01\x02\x12\x03T\x1e\x1f\n\r\n\x06\x04\x0b\
-    \x04\0\x02\x02\x12\x03T!-\n\x0e\n\x07\x04\x0b\x04\0\x02\x02\x01\x12\x03T\
-    !(\n\x0e\n\x07\x04\x0b\x04\0\x02\x02\x02\x12\x03T+,\n3\n\x04\x04\x0b\x02\
-    \0\x12\x03W\x02\x19\x1a&\x20Identifies\x20which\x20field\x20is\x20filled\
-    \x20in.\n\n\x0c\n\x05\x04\x0b\x02\0\x04\x12\x03W\x02\n\n\x0c\n\x05\x04\
-    \x0b\x02\0\x06\x12\x03W\x0b\x0f\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03W\
-    \x10\x14\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03W\x17\x18\n\x0c\n\x04\x04\
-    \x0b\x08\0\x12\x04X\x02\\\x03\n\x0c\n\x05\x04\x0b\x08\0\x01\x12\x03X\x08\
-    \x0f\n\x0b\n\x04\x04\x0b\x02\x01\x12\x03Y\x04\x12\n\x0c\n\x05\x04\x0b\
-    \x02\x01\x06\x12\x03Y\x04\x08\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03Y\t\
-    \r\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\x03Y\x10\x11\n\x0b\n\x04\x04\x0b\
-    \x02\x02\x12\x03Z\x04\x10\n\x0c\n\x05\x04\x0b\x02\x02\x06\x12\x03Z\x04\
-    \x07\n\x0c\n\x05\x04\x0b\x02\x02\x01\x12\x03Z\x08\x0b\n\x0c\n\x05\x04\
-    \x0b\x02\x02\x03\x12\x03Z\x0e\x0f\n\x0b\n\x04\x04\x0b\x02\x03\x12\x03[\
-    \x04\x18\n\x0c\n\x05\x04\x0b\x02\x03\x06\x12\x03[\x04\x0b\n\x0c\n\x05\
-    \x04\x0b\x02\x03\x01\x12\x03[\x0c\x13\n\x0c\n\x05\x04\x0b\x02\x03\x03\
-    \x12\x03[\x16\x17\n\x0b\n\x04\x04\x0b\x02\x04\x12\x03]\x02%\n\x0c\n\x05\
-    \x04\x0b\x02\x04\x04\x12\x03]\x02\n\n\x0c\n\x05\x04\x0b\x02\x04\x06\x12\
-    \x03]\x0b\x15\n\x0c\n\x05\x04\x0b\x02\x04\x01\x12\x03]\x16\x20\n\x0c\n\
-    \x05\x04\x0b\x02\x04\x03\x12\x03]#$\n\n\n\x02\x04\x0c\x12\x04`\0x\x01\n\
-    \n\n\x03\x04\x0c\x01\x12\x03`\x08\r\n\x0c\n\x04\x04\x0c\x04\0\x12\x04a\
-    \x02k\x03\n\x0c\n\x05\x04\x0c\x04\0\x01\x12\x03a\x07\x0b\n\r\n\x06\x04\
-    \x0c\x04\0\x02\0\x12\x03b\x04\x0f\n\x0e\n\x07\x04\x0c\x04\0\x02\0\x01\
-    \x12\x03b\x04\n\n\x0e\n\x07\x04\x0c\x04\0\x02\0\x02\x12\x03b\r\x0e\n\r\n\
-    \x06\x04\x0c\x04\0\x02\x01\x12\x03c\x04\x10\n\x0e\n\x07\x04\x0c\x04\0\
-    \x02\x01\x01\x12\x03c\x04\x0b\n\x0e\n\x07\x04\x0c\x04\0\x02\x01\x02\x12\
-    \x03c\x0e\x0f\n\r\n\x06\x04\x0c\x04\0\x02\x02\x12\x03d\x04\x11\n\x0e\n\
-    \x07\x04\x0c\x04\0\x02\x02\x01\x12\x03d\x04\x0c\n\x0e\n\x07\x04\x0c\x04\
-    \0\x02\x02\x02\x12\x03d\x0f\x10\n\r\n\x06\x04\x0c\x04\0\x02\x03\x12\x03e\
-    \x04\x16\n\x0e\n\x07\x04\x0c\x04\0\x02\x03\x01\x12\x03e\x04\x11\n\x0e\n\
-    \x07\x04\x0c\x04\0\x02\x03\x02\x12\x03e\x14\x15\n\r\n\x06\x04\x0c\x04\0\
-    \x02\x04\x12\x03f\x04\x14\n\x0e\n\x07\x04\x0c\x04\0\x02\x04\x01\x12\x03f\
-    \x04\x0f\n\x0e\n\x07\x04\x0c\x04\0\x02\x04\x02\x12\x03f\x12\x13\n\r\n\
-    \x06\x04\x0c\x04\0\x02\x05\x12\x03g\x04\r\n\x0e\n\x07\x04\x0c\x04\0\x02\
-    \x05\x01\x12\x03g\x04\x08\n\x0e\n\x07\x04\x0c\x04\0\x02\x05\x02\x12\x03g\
-    \x0b\x0c\n\r\n\x06\x04\x0c\x04\0\x02\x06\x12\x03h\x04\x0e\n\x0e\n\x07\
-    \x04\x0c\x04\0\x02\x06\x01\x12\x03h\x04\t\n\x0e\n\x07\x04\x0c\x04\0\x02\
-    \x06\x02\x12\x03h\x0c\r\n\r\n\x06\x04\x0c\x04\0\x02\x07\x12\x03i\x04\x17\
-    \n\x0e\n\x07\x04\x0c\x04\0\x02\x07\x01\x12\x03i\x04\x12\n\x0e\n\x07\x04\
-    \x0c\x04\0\x02\x07\x02\x12\x03i\x15\x16\n\r\n\x06\x04\x0c\x04\0\x02\x08\
-    \x12\x03j\x04\x12\n\x0e\n\x07\x04\x0c\x04\0\x02\x08\x01\x12\x03j\x04\r\n\
-    \x0e\n\x07\x04\x0c\x04\0\x02\x08\x02\x12\x03j\x10\x11\n\x0b\n\x04\x04\
-    \x0c\x02\0\x12\x03m\x02\x19\n\x0c\n\x05\x04\x0c\x02\0\x04\x12\x03m\x02\n\
-    \n\x0c\n\x05\x04\x0c\x02\0\x06\x12\x03m\x0b\x0f\n\x0c\n\x05\x04\x0c\x02\
-    \0\x01\x12\x03m\x10\x14\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03m\x17\x18\n\
-    \x0b\n\x04\x04\x0c\x02\x01\x12\x03n\x02\x1a\n\x0c\n\x05\x04\x0c\x02\x01\
-    \x04\x12\x03n\x02\n\n\x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03n\x0b\x11\n\
-    \x0c\n\x05\x04\x0c\x02\x01\x01\x12\x03n\x12\x15\n\x0c\n\x05\x04\x0c\x02\
-    \x01\x03\x12\x03n\x18\x19\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03o\x02\x1e\n\
-    \x0c\n\x05\x04\x0c\x02\x02\x04\x12\x03o\x02\n\n\x0c\n\x05\x04\x0c\x02\
-    \x02\x05\x12\x03o\x0b\x11\n\x0c\n\x05\x04\x0c\x02\x02\x01\x12\x03o\x12\
-    \x19\n\x0c\n\x05\x04\x0c\x02\x02\x03\x12\x03o\x1c\x1d\n\x0c\n\x04\x04\
-    \x0c\x08\0\x12\x04p\x02w\x03\n\x0c\n\x05\x04\x0c\x08\0\x01\x12\x03p\x08\
-    \x0f\n\x0b\n\x04\x04\x0c\x02\x03\x12\x03q\x04\x1a\n\x0c\n\x05\x04\x0c\
-    \x02\x03\x06\x12\x03q\x04\x0e\n\x0c\n\x05\x04\x0c\x02\x03\x01\x12\x03q\
-    \x0f\x15\n\x0c\n\x05\x04\x0c\x02\x03\x03\x12\x03q\x18\x19\n\x0b\n\x04\
-    \x04\x0c\x02\x04\x12\x03r\x04\x18\n\x0c\n\x05\x04\x0c\x02\x04\x06\x12\
-    \x03r\x04\x0b\n\x0c\n\x05\x04\x0c\x02\x04\x01\x12\x03r\x0c\x13\n\x0c\n\
-    \x05\x04\x0c\x02\x04\x03\x12\x03r\x16\x17\n\x0b\n\x04\x04\x0c\x02\x05\
-    \x12\x03s\x04%\n\x0c\n\x05\x04\x0c\x02\x05\x06\x12\x03s\x04\x11\n\x0c\n\
-    \x05\x04\x0c\x02\x05\x01\x12\x03s\x12\x20\n\x0c\n\x05\x04\x0c\x02\x05\
-    \x03\x12\x03s#$\n\x0b\n\x04\x04\x0c\x02\x06\x12\x03t\x04!\n\x0c\n\x05\
-    \x04\x0c\x02\x06\x06\x12\x03t\x04\x0f\n\x0c\n\x05\x04\x0c\x02\x06\x01\
-    \x12\x03t\x10\x1c\n\x0c\n\x05\x04\x0c\x02\x06\x03\x12\x03t\x1f\x20\n\x0b\
-    \n\x04\x04\x0c\x02\x07\x12\x03u\x04\x1a\n\x0c\n\x05\x04\x0c\x02\x07\x06\
-    \x12\x03u\x04\x0c\n\x0c\n\x05\x04\x0c\x02\x07\x01\x12\x03u\r\x15\n\x0c\n\
-    \x05\x04\x0c\x02\x07\x03\x12\x03u\x18\x19\n\x0b\n\x04\x04\x0c\x02\x08\
-    \x12\x03v\x04\x1c\n\x0c\n\x05\x04\x0c\x02\x08\x06\x12\x03v\x04\r\n\x0c\n\
-    \x05\x04\x0c\x02\x08\x01\x12\x03v\x0e\x17\n\x0c\n\x05\x04\x0c\x02\x08\
-    \x03\x12\x03v\x1a\x1b\n\n\n\x02\x04\r\x12\x04z\0~\x01\n\n\n\x03\x04\r\
-    \x01\x12\x03z\x08\x0c\n\x0b\n\x04\x04\r\x02\0\x12\x03{\x02\x1e\n\x0c\n\
-    \x05\x04\r\x02\0\x04\x12\x03{\x02\n\n\x0c\n\x05\x04\r\x02\0\x05\x12\x03{\
-    \x0b\x0f\n\x0c\n\x05\x04\r\x02\0\x01\x12\x03{\x10\x19\n\x0c\n\x05\x04\r\
-    \x02\0\x03\x12\x03{\x1c\x1d\n\x0b\n\x04\x04\r\x02\x01\x12\x03|\x02\x1b\n\
-    \x0c\n\x05\x04\r\x02\x01\x04\x12\x03|\x02\n\n\x0c\n\x05\x04\r\x02\x01\
-    \x05\x12\x03|\x0b\x10\n\x0c\n\x05\x04\r\x02\x01\x01\x12\x03|\x11\x16\n\
-    \x0c\n\x05\x04\r\x02\x01\x03\x12\x03|\x19\x1a\n\x0b\n\x04\x04\r\x02\x02\
-    \x12\x03}\x02\x1d\n\x0c\n\x05\x04\r\x02\x02\x04\x12\x03}\x02\n\n\x0c\n\
-    \x05\x04\r\x02\x02\x05\x12\x03}\x0b\x10\n\x0c\n\x05\x04\r\x02\x02\x01\
-    \x12\x03}\x11\x18\n\x0c\n\x05\x04\r\x02\x02\x03\x12\x03}\x1b\x1c\
+    yload\"Z\n\x0eRoleAssignment\x12\x18\n\x07version\x18\x01\x20\x01(\x04R\
+    \x07version\x12\x16\n\x06active\x18\x02\x20\x03(\tR\x06active\x12\x16\n\
+    \x06shards\x18\x03\x20\x03(\tR\x06shardsJ\xea1\n\x07\x12\x05\0\0\x88\x01\
+    \x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\n\x01\
+    \n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x0e\n\x0b\n\x04\x04\0\x02\0\x12\x03\
+    \x03\x02\x19\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\x03\x02\n\n\x0c\n\x05\
+    \x04\0\x02\0\x05\x12\x03\x03\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\
+    \x03\x12\x14\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x17\x18\n\x0b\n\x04\
+    \x04\0\x02\x01\x12\x03\x04\x02\"\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\
+    \x04\x02\n\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x0b\x11\n\x0c\n\x05\
+    \x04\0\x02\x01\x01\x12\x03\x04\x12\x1d\n\x0c\n\x05\x04\0\x02\x01\x03\x12\
+    \x03\x04\x20!\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x02\x1e\n\x0c\n\x05\
+    \x04\0\x02\x02\x04\x12\x03\x05\x02\n\n\x0c\n\x05\x04\0\x02\x02\x05\x12\
+    \x03\x05\x0b\x11\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x12\x19\n\x0c\
+    \n\x05\x04\0\x02\x02\x03\x12\x03\x05\x1c\x1d\n\x0b\n\x04\x04\0\x02\x03\
+    \x12\x03\x06\x02\x1f\n\x0c\n\x05\x04\0\x02\x03\x04\x12\x03\x06\x02\n\n\
+    \x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x0b\x10\n\x0c\n\x05\x04\0\x02\
+    \x03\x01\x12\x03\x06\x11\x1a\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x06\
+    \x1d\x1e\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x02!\n\x0c\n\x05\x04\0\
+    \x02\x04\x04\x12\x03\x07\x02\n\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\x07\
+    \x0b\x10\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\x11\x1c\n\x0c\n\x05\
+    \x04\0\x02\x04\x03\x12\x03\x07\x1f\x20\n\x0b\n\x04\x04\0\x02\x05\x12\x03\
+    \x08\x021\n\x0c\n\x05\x04\0\x02\x05\x04\x12\x03\x08\x02\n\n\x0c\n\x05\
+    \x04\0\x02\x05\x05\x12\x03\x08\x0b\x0f\n\x0c\n\x05\x04\0\x02\x05\x01\x12\
+    \x03\x08\x10\x1a\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\x08\x1d\x1e\n\x0c\
+    \n\x05\x04\0\x02\x05\x08\x12\x03\x08\x1f0\n\x0c\n\x05\x04\0\x02\x05\x07\
+    \x12\x03\x08*/\n\x0b\n\x04\x04\0\x02\x06\x12\x03\t\x02/\n\x0c\n\x05\x04\
+    \0\x02\x06\x04\x12\x03\t\x02\n\n\x0c\n\x05\x04\0\x02\x06\x05\x12\x03\t\
+    \x0b\x0f\n\x0c\n\x05\x04\0\x02\x06\x01\x12\x03\t\x10\x18\n\x0c\n\x05\x04\
+    \0\x02\x06\x03\x12\x03\t\x1b\x1c\n\x0c\n\x05\x04\0\x02\x06\x08\x12\x03\t\
+    \x1d.\n\x0c\n\x05\x04\0\x02\x06\x07\x12\x03\t(-\n\n\n\x02\x04\x01\x12\
+    \x04\x0c\0\x0f\x01\n\n\n\x03\x04\x01\x01\x12\x03\x0c\x08\x0c\n\x0b\n\x04\
+    \x04\x01\x02\0\x12\x03\r\x02\x1b\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\r\
+    \x02\n\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\r\x0b\x11\n\x0c\n\x05\x04\
+    \x01\x02\0\x01\x12\x03\r\x12\x16\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\r\
+    \x19\x1a\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0e\x02!\n\x0c\n\x05\x04\
+    \x01\x02\x01\x04\x12\x03\x0e\x02\n\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\
+    \x03\x0e\x0b\x11\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x0e\x12\x1c\n\
+    \x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0e\x1f\x20\n\n\n\x02\x04\x02\x12\
+    \x04\x11\0\x14\x01\n\n\n\x03\x04\x02\x01\x12\x03\x11\x08\x0b\n\x0b\n\x04\
+    \x04\x02\x02\0\x12\x03\x12\x02\x1b\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03\
+    \x12\x02\n\n\x0c\n\x05\x04\x02\x02\0\x06\x12\x03\x12\x0b\x11\n\x0c\n\x05\
+    \x04\x02\x02\0\x01\x12\x03\x12\x12\x16\n\x0c\n\x05\x04\x02\x02\0\x03\x12\
+    \x03\x12\x19\x1a\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x13\x02!\n\x0c\n\
+    \x05\x04\x02\x02\x01\x04\x12\x03\x13\x02\n\n\x0c\n\x05\x04\x02\x02\x01\
+    \x06\x12\x03\x13\x0b\x11\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x13\x12\
+    \x1c\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x13\x1f\x20\n\n\n\x02\x04\
+    \x03\x12\x04\x16\0\x19\x01\n\n\n\x03\x04\x03\x01\x12\x03\x16\x08\x0f\n\
+    \x0b\n\x04\x04\x03\x02\0\x12\x03\x17\x02\x1b\n\x0c\n\x05\x04\x03\x02\0\
+    \x04\x12\x03\x17\x02\n\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03\x17\x0b\x11\
+    \n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x17\x12\x16\n\x0c\n\x05\x04\x03\
+    \x02\0\x03\x12\x03\x17\x19\x1a\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x18\
+    \x02\x1d\n\x0c\n\x05\x04\x03\x02\x01\x04\x12\x03\x18\x02\n\n\x0c\n\x05\
+    \x04\x03\x02\x01\x06\x12\x03\x18\x0b\x11\n\x0c\n\x05\x04\x03\x02\x01\x01\
+    \x12\x03\x18\x12\x18\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x18\x1b\x1c\
+    \n\n\n\x02\x04\x04\x12\x04\x1b\0\x20\x01\n\n\n\x03\x04\x04\x01\x12\x03\
+    \x1b\x08\x12\n\x0b\n\x04\x04\x04\x04\0\x12\x03\x1c\x02F\n\x0c\n\x05\x04\
+    \x04\x04\0\x01\x12\x03\x1c\x07\r\n\r\n\x06\x04\x04\x04\0\x02\0\x12\x03\
+    \x1c\x10\x1a\n\x0e\n\x07\x04\x04\x04\0\x02\0\x01\x12\x03\x1c\x10\x15\n\
+    \x0e\n\x07\x04\x04\x04\0\x02\0\x02\x12\x03\x1c\x18\x19\n\r\n\x06\x04\x04\
+    \x04\0\x02\x01\x12\x03\x1c\x1b'\n\x0e\n\x07\x04\x04\x04\0\x02\x01\x01\
+    \x12\x03\x1c\x1b\"\n\x0e\n\x07\x04\x04\x04\0\x02\x01\x02\x12\x03\x1c%&\n\
+    \r\n\x06\x04\x04\x04\0\x02\x02\x12\x03\x1c(6\n\x0e\n\x07\x04\x04\x04\0\
+    \x02\x02\x01\x12\x03\x1c(1\n\x0e\n\x07\x04\x04\x04\0\x02\x02\x02\x12\x03\
+    \x1c45\n\r\n\x06\x04\x04\x04\0\x02\x03\x12\x03\x1c7D\n\x0e\n\x07\x04\x04\
+    \x04\0\x02\x03\x01\x12\x03\x1c7?\n\x0e\n\x07\x04\x04\x04\0\x02\x03\x02\
+    \x12\x03\x1cBC\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1e\x02\x1d\n\x0c\n\x05\
+    \x04\x04\x02\0\x04\x12\x03\x1e\x02\n\n\x0c\n\x05\x04\x04\x02\0\x06\x12\
+    \x03\x1e\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1e\x12\x18\n\x0c\
+    \n\x05\x04\x04\x02\0\x03\x12\x03\x1e\x1b\x1c\n\x0b\n\x04\x04\x04\x02\x01\
+    \x12\x03\x1f\x02\x1d\n\x0c\n\x05\x04\x04\x02\x01\x04\x12\x03\x1f\x02\n\n\
+    \x0c\n\x05\x04\x04\x02\x01\x06\x12\x03\x1f\x0b\x11\n\x0c\n\x05\x04\x04\
+    \x02\x01\x01\x12\x03\x1f\x12\x18\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\
+    \x1f\x1b\x1c\n\n\n\x02\x04\x05\x12\x04\"\0+\x01\n\n\n\x03\x04\x05\x01\
+    \x12\x03\"\x08\x10\n\x0b\n\x04\x04\x05\x04\0\x12\x03#\x02:\n\x0c\n\x05\
+    \x04\x05\x04\0\x01\x12\x03#\x07\r\n\r\n\x06\x04\x05\x04\0\x02\0\x12\x03#\
+    \x10\x1c\n\x0e\n\x07\x04\x05\x04\0\x02\0\x01\x12\x03#\x10\x17\n\x0e\n\
+    \x07\x04\x05\x04\0\x02\0\x02\x12\x03#\x1a\x1b\n\r\n\x06\x04\x05\x04\0\
+    \x02\x01\x12\x03#\x1d*\n\x0e\n\x07\x04\x05\x04\0\x02\x01\x01\x12\x03#\
+    \x1d%\n\x0e\n\x07\x04\x05\x04\0\x02\x01\x02\x12\x03#()\n\r\n\x06\x04\x05\
+    \x04\0\x02\x02\x12\x03#+8\n\x0e\n\x07\x04\x05\x04\0\x02\x02\x01\x12\x03#\
+    +3\n\x0e\n\x07\x04\x05\x04\0\x02\x02\x02\x12\x03#67\n\x0b\n\x04\x04\x05\
+    \x02\0\x12\x03%\x02\x20\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03%\x02\n\n\
+    \x0c\n\x05\x04\x05\x02\0\x05\x12\x03%\x0b\x11\n\x0c\n\x05\x04\x05\x02\0\
+    \x01\x12\x03%\x12\x1b\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03%\x1e\x1f\n\
+    \x0b\n\x04\x04\x05\x02\x01\x12\x03&\x02$\n\x0c\n\x05\x04\x05\x02\x01\x04\
+    \x12\x03&\x02\n\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x03&\x0b\x11\n\x0c\n\
+    \x05\x04\x05\x02\x01\x01\x12\x03&\x12\x1f\n\x0c\n\x05\x04\x05\x02\x01\
+    \x03\x12\x03&\"#\n\x0b\n\x04\x04\x05\x02\x02\x12\x03'\x02\x1b\n\x0c\n\
+    \x05\x04\x05\x02\x02\x04\x12\x03'\x02\n\n\x0c\n\x05\x04\x05\x02\x02\x05\
+    \x12\x03'\x0b\x11\n\x0c\n\x05\x04\x05\x02\x02\x01\x12\x03'\x12\x16\n\x0c\
+    \n\x05\x04\x05\x02\x02\x03\x12\x03'\x19\x1a\n\x0b\n\x04\x04\x05\x02\x03\
+    \x12\x03(\x02\"\n\x0c\n\x05\x04\x05\x02\x03\x04\x12\x03(\x02\n\n\x0c\n\
+    \x05\x04\x05\x02\x03\x05\x12\x03(\x0b\x11\n\x0c\n\x05\x04\x05\x02\x03\
+    \x01\x12\x03(\x12\x1d\n\x0c\n\x05\x04\x05\x02\x03\x03\x12\x03(\x20!\n\
+    \x0b\n\x04\x04\x05\x02\x04\x12\x03)\x02\x1d\n\x0c\n\x05\x04\x05\x02\x04\
+    \x04\x12\x03)\x02\n\n\x0c\n\x05\x04\x05\x02\x04\x06\x12\x03)\x0b\x11\n\
+    \x0c\n\x05\x04\x05\x02\x04\x01\x12\x03)\x12\x18\n\x0c\n\x05\x04\x05\x02\
+    \x04\x03\x12\x03)\x1b\x1c\n\x0b\n\x04\x04\x05\x02\x05\x12\x03*\x02\x1c\n\
+    \x0c\n\x05\x04\x05\x02\x05\x04\x12\x03*\x02\n\n\x0c\n\x05\x04\x05\x02\
+    \x05\x05\x12\x03*\x0b\x11\n\x0c\n\x05\x04\x05\x02\x05\x01\x12\x03*\x12\
+    \x17\n\x0c\n\x05\x04\x05\x02\x05\x03\x12\x03*\x1a\x1b\n\n\n\x02\x04\x06\
+    \x12\x04-\06\x01\n\n\n\x03\x04\x06\x01\x12\x03-\x08\x0f\n\x0b\n\x04\x04\
+    \x06\x02\0\x12\x03.\x02\x20\n\x0c\n\x05\x04\x06\x02\0\x04\x12\x03.\x02\n\
+    \n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03.\x0b\x11\n\x0c\n\x05\x04\x06\x02\
+    \0\x01\x12\x03.\x12\x1b\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03.\x1e\x1f\n\
+    \x0b\n\x04\x04\x06\x02\x01\x12\x03/\x02$\n\x0c\n\x05\x04\x06\x02\x01\x04\
+    \x12\x03/\x02\n\n\x0c\n\x05\x04\x06\x02\x01\x05\x12\x03/\x0b\x11\n\x0c\n\
+    \x05\x04\x06\x02\x01\x01\x12\x03/\x12\x1f\n\x0c\n\x05\x04\x06\x02\x01\
+    \x03\x12\x03/\"#\n\x0b\n\x04\x04\x06\x02\x02\x12\x030\x02\"\n\x0c\n\x05\
+    \x04\x06\x02\x02\x04\x12\x030\x02\n\n\x0c\n\x05\x04\x06\x02\x02\x05\x12\
+    \x030\x0b\x11\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x030\x12\x1d\n\x0c\n\
+    \x05\x04\x06\x02\x02\x03\x12\x030\x20!\n\x0b\n\x04\x04\x06\x02\x03\x12\
+    \x031\x02\x20\n\x0c\n\x05\x04\x06\x02\x03\x04\x12\x031\x02\n\n\x0c\n\x05\
+    \x04\x06\x02\x03\x05\x12\x031\x0b\x0f\n\x0c\n\x05\x04\x06\x02\x03\x01\
+    \x12\x031\x10\x1b\n\x0c\n\x05\x04\x06\x02\x03\x03\x12\x031\x1e\x1f\n\x0b\
+    \n\x04\x04\x06\x02\x04\x12\x032\x02\x1a\n\x0c\n\x05\x04\x06\x02\x04\x04\
+    \x12\x032\x02\n\n\x0c\n\x05\x04\x06\x02\x04\x05\x12\x032\x0b\x11\n\x0c\n\
+    \x05\x04\x06\x02\x04\x01\x12\x032\x12\x15\n\x0c\n\x05\x04\x06\x02\x04\
+    \x03\x12\x032\x18\x19\n\x0b\n\x04\x04\x06\x02\x05\x12\x033\x02\x1a\n\x0c\
+    \n\x05\x04\x06\x02\x05\x04\x12\x033\x02\n\n\x0c\n\x05\x04\x06\x02\x05\
+    \x05\x12\x033\x0b\x10\n\x0c\n\x05\x04\x06\x02\x05\x01\x12\x033\x11\x14\n\
+    \x0c\n\x05\x04\x06\x02\x05\x03\x12\x033\x17\x19\n\x0b\n\x04\x04\x06\x02\
+    \x06\x12\x034\x02\x1c\n\x0c\n\x05\x04\x06\x02\x06\x04\x12\x034\x02\n\n\
+    \x0c\n\x05\x04\x06\x02\x06\x06\x12\x034\x0b\x12\n\x0c\n\x05\x04\x06\x02\
+    \x06\x01\x12\x034\x13\x16\n\x0c\n\x05\x04\x06\x02\x06\x03\x12\x034\x19\
+    \x1b\n\x0b\n\x04\x04\x06\x02\x07\x12\x035\x02%\n\x0c\n\x05\x04\x06\x02\
+    \x07\x04\x12\x035\x02\n\n\x0c\n\x05\x04\x06\x02\x07\x06\x12\x035\x0b\x19\
+    \n\x0c\n\x05\x04\x06\x02\x07\x01\x12\x035\x1a\x1f\n\x0c\n\x05\x04\x06\
+    \x02\x07\x03\x12\x035\"$\n\n\n\x02\x04\x07\x12\x048\0=\x01\n\n\n\x03\x04\
+    \x07\x01\x12\x038\x08\x15\n\x0b\n\x04\x04\x07\x02\0\x12\x039\x02$\n\x0c\
+    \n\x05\x04\x07\x02\0\x04\x12\x039\x02\n\n\x0c\n\x05\x04\x07\x02\0\x05\
+    \x12\x039\x0b\x11\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x039\x12\x1f\n\x0c\n\
+    \x05\x04\x07\x02\0\x03\x12\x039\"#\n\x0b\n\x04\x04\x07\x02\x01\x12\x03:\
+    \x02\"\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x03:\x02\n\n\x0c\n\x05\x04\
+    \x07\x02\x01\x05\x12\x03:\x0b\x11\n\x0c\n\x05\x04\x07\x02\x01\x01\x12\
+    \x03:\x12\x1d\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x03:\x20!\n\x0b\n\x04\
+    \x04\x07\x02\x02\x12\x03;\x02\x1e\n\x0c\n\x05\x04\x07\x02\x02\x04\x12\
+    \x03;\x02\n\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\x03;\x0b\x0f\n\x0c\n\x05\
+    \x04\x07\x02\x02\x01\x12\x03;\x10\x19\n\x0c\n\x05\x04\x07\x02\x02\x03\
+    \x12\x03;\x1c\x1d\n\x0b\n\x04\x04\x07\x02\x03\x12\x03<\x02\x1c\n\x0c\n\
+    \x05\x04\x07\x02\x03\x04\x12\x03<\x02\n\n\x0c\n\x05\x04\x07\x02\x03\x05\
+    \x12\x03<\x0b\x10\n\x0c\n\x05\x04\x07\x02\x03\x01\x12\x03<\x11\x17\n\x0c\
+    \n\x05\x04\x07\x02\x03\x03\x12\x03<\x1a\x1b\n\n\n\x02\x04\x08\x12\x04?\0\
+    E\x01\n\n\n\x03\x04\x08\x01\x12\x03?\x08\x13\n\x0b\n\x04\x04\x08\x02\0\
+    \x12\x03@\x02$\n\x0c\n\x05\x04\x08\x02\0\x04\x12\x03@\x02\n\n\x0c\n\x05\
+    \x04\x08\x02\0\x05\x12\x03@\x0b\x11\n\x0c\n\x05\x04\x08\x02\0\x01\x12\
+    \x03@\x12\x1f\n\x0c\n\x05\x04\x08\x02\0\x03\x12\x03@\"#\n\x0b\n\x04\x04\
+    \x08\x02\x01\x12\x03A\x02\"\n\x0c\n\x05\x04\x08\x02\x01\x04\x12\x03A\x02\
+    \n\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03A\x0b\x11\n\x0c\n\x05\x04\x08\
+    \x02\x01\x01\x12\x03A\x12\x1d\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x03A\
+    \x20!\n\x0b\n\x04\x04\x08\x02\x02\x12\x03B\x02\x1e\n\x0c\n\x05\x04\x08\
+    \x02\x02\x04\x12\x03B\x02\n\n\x0c\n\x05\x04\x08\x02\x02\x05\x12\x03B\x0b\
+    \x0f\n\x0c\n\x05\x04\x08\x02\x02\x01\x12\x03B\x10\x19\n\x0c\n\x05\x04\
+    \x08\x02\x02\x03\x12\x03B\x1c\x1d\n\x0b\n\x04\x04\x08\x02\x03\x12\x03C\
+    \x02\x1f\n\x0c\n\x05\x04\x08\x02\x03\x04\x12\x03C\x02\n\n\x0c\n\x05\x04\
+    \x08\x02\x03\x05\x12\x03C\x0b\x11\n\x0c\n\x05\x04\x08\x02\x03\x01\x12\
+    \x03C\x12\x1a\n\x0c\n\x05\x04\x08\x02\x03\x03\x12\x03C\x1d\x1e\n\x0b\n\
+    \x04\x04\x08\x02\x04\x12\x03D\x02\x1a\n\x0c\n\x05\x04\x08\x02\x04\x04\
+    \x12\x03D\x02\n\n\x0c\n\x05\x04\x08\x02\x04\x05\x12\x03D\x0b\x10\n\x0c\n\
+    \x05\x04\x08\x02\x04\x01\x12\x03D\x11\x15\n\x0c\n\x05\x04\x08\x02\x04\
+    \x03\x12\x03D\x18\x19\n\n\n\x02\x04\t\x12\x04G\0N\x01\n\n\n\x03\x04\t\
+    \x01\x12\x03G\x08\x0f\n\x0b\n\x04\x04\t\x02\0\x12\x03H\x021\n\x0c\n\x05\
+    \x04\t\x02\0\x04\x12\x03H\x02\n\n\x0c\n\x05\x04\t\x02\0\x05\x12\x03H\x0b\
+    \x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03H\x12\x14\n\x0c\n\x05\x04\t\x02\
+    \0\x03\x12\x03H\x17\x18\n\x0c\n\x05\x04\t\x02\0\x08\x12\x03H\x190\n\x0c\
+    \n\x05\x04\t\x02\0\x07\x12\x03H$/\n\x0b\n\x04\x04\t\x02\x01\x12\x03I\x02\
+    7\n\x0c\n\x05\x04\t\x02\x01\x04\x12\x03I\x02\n\n\x0c\n\x05\x04\t\x02\x01\
+    \x05\x12\x03I\x0b\x11\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x03I\x12\x1a\n\
+    \x0c\n\x05\x04\t\x02\x01\x03\x12\x03I\x1d\x1e\n\x0c\n\x05\x04\t\x02\x01\
+    \x08\x12\x03I\x1f6\n\x0c\n\x05\x04\t\x02\x01\x07\x12\x03I*5\n\x0b\n\x04\
+    \x04\t\x02\x02\x12\x03J\x028\n\x0c\n\x05\x04\t\x02\x02\x04\x12\x03J\x02\
+    \n\n\x0c\n\x05\x04\t\x02\x02\x05\x12\x03J\x0b\x11\n\x0c\n\x05\x04\t\x02\
+    \x02\x01\x12\x03J\x12\x1b\n\x0c\n\x05\x04\t\x02\x02\x03\x12\x03J\x1e\x1f\
+    \n\x0c\n\x05\x04\t\x02\x02\x08\x12\x03J\x207\n\x0c\n\x05\x04\t\x02\x02\
+    \x07\x12\x03J+6\n\x0b\n\x04\x04\t\x02\x03\x12\x03K\x02\"\n\x0c\n\x05\x04\
+    \t\x02\x03\x04\x12\x03K\x02\n\n\x0c\n\x05\x04\t\x02\x03\x05\x12\x03K\x0b\
+    \x11\n\x0c\n\x05\x04\t\x02\x03\x01\x12\x03K\x12\x1d\n\x0c\n\x05\x04\t\
+    \x02\x03\x03\x12\x03K\x20!\n\x0b\n\x04\x04\t\x02\x04\x12\x03L\x02>\n\x0c\
+    \n\x05\x04\t\x02\x04\x04\x12\x03L\x02\n\n\x0c\n\x05\x04\t\x02\x04\x05\
+    \x12\x03L\x0b\x11\n\x0c\n\x05\x04\t\x02\x04\x01\x12\x03L\x12!\n\x0c\n\
+    \x05\x04\t\x02\x04\x03\x12\x03L$%\n\x0c\n\x05\x04\t\x02\x04\x08\x12\x03L\
+    &=\n\x0c\n\x05\x04\t\x02\x04\x07\x12\x03L1<\n\x0b\n\x04\x04\t\x02\x05\
+    \x12\x03M\x02(\n\x0c\n\x05\x04\t\x02\x05\x04\x12\x03M\x02\n\n\x0c\n\x05\
+    \x04\t\x02\x05\x05\x12\x03M\x0b\x11\n\x0c\n\x05\x04\t\x02\x05\x01\x12\
+    \x03M\x12#\n\x0c\n\x05\x04\t\x02\x05\x03\x12\x03M&'\n\n\n\x02\x04\n\x12\
+    \x04P\0R\x01\n\n\n\x03\x04\n\x01\x12\x03P\x08\x11\n\x0b\n\x04\x04\n\x02\
+    \0\x12\x03Q\x02\x20\n\x0c\n\x05\x04\n\x02\0\x04\x12\x03Q\x02\n\n\x0c\n\
+    \x05\x04\n\x02\0\x05\x12\x03Q\x0b\x11\n\x0c\n\x05\x04\n\x02\0\x01\x12\
+    \x03Q\x12\x1b\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03Q\x1e\x1f\n\n\n\x02\x04\
+    \x0b\x12\x04T\0_\x01\n\n\n\x03\x04\x0b\x01\x12\x03T\x08\x0c\n\x0b\n\x04\
+    \x04\x0b\x04\0\x12\x03U\x02/\n\x0c\n\x05\x04\x0b\x04\0\x01\x12\x03U\x07\
+    \x0b\n\r\n\x06\x04\x0b\x04\0\x02\0\x12\x03U\x0e\x17\n\x0e\n\x07\x04\x0b\
+    \x04\0\x02\0\x01\x12\x03U\x0e\x12\n\x0e\n\x07\x04\x0b\x04\0\x02\0\x02\
+    \x12\x03U\x15\x16\n\r\n\x06\x04\x0b\x04\0\x02\x01\x12\x03U\x18\x20\n\x0e\
+    \n\x07\x04\x0b\x04\0\x02\x01\x01\x12\x03U\x18\x1b\n\x0e\n\x07\x04\x0b\
+    \x04\0\x02\x01\x02\x12\x03U\x1e\x1f\n\r\n\x06\x04\x0b\x04\0\x02\x02\x12\
+    \x03U!-\n\x0e\n\x07\x04\x0b\x04\0\x02\x02\x01\x12\x03U!(\n\x0e\n\x07\x04\
+    \x0b\x04\0\x02\x02\x02\x12\x03U+,\n3\n\x04\x04\x0b\x02\0\x12\x03X\x02\
+    \x19\x1a&\x20Identifies\x20which\x20field\x20is\x20filled\x20in.\n\n\x0c\
+    \n\x05\x04\x0b\x02\0\x04\x12\x03X\x02\n\n\x0c\n\x05\x04\x0b\x02\0\x06\
+    \x12\x03X\x0b\x0f\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03X\x10\x14\n\x0c\n\
+    \x05\x04\x0b\x02\0\x03\x12\x03X\x17\x18\n\x0c\n\x04\x04\x0b\x08\0\x12\
+    \x04Y\x02]\x03\n\x0c\n\x05\x04\x0b\x08\0\x01\x12\x03Y\x08\x0f\n\x0b\n\
+    \x04\x04\x0b\x02\x01\x12\x03Z\x04\x12\n\x0c\n\x05\x04\x0b\x02\x01\x06\
+    \x12\x03Z\x04\x08\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03Z\t\r\n\x0c\n\
+    \x05\x04\x0b\x02\x01\x03\x12\x03Z\x10\x11\n\x0b\n\x04\x04\x0b\x02\x02\
+    \x12\x03[\x04\x10\n\x0c\n\x05\x04\x0b\x02\x02\x06\x12\x03[\x04\x07\n\x0c\
+    \n\x05\x04\x0b\x02\x02\x01\x12\x03[\x08\x0b\n\x0c\n\x05\x04\x0b\x02\x02\
+    \x03\x12\x03[\x0e\x0f\n\x0b\n\x04\x04\x0b\x02\x03\x12\x03\\\x04\x18\n\
+    \x0c\n\x05\x04\x0b\x02\x03\x06\x12\x03\\\x04\x0b\n\x0c\n\x05\x04\x0b\x02\
+    \x03\x01\x12\x03\\\x0c\x13\n\x0c\n\x05\x04\x0b\x02\x03\x03\x12\x03\\\x16\
+    \x17\n\x0b\n\x04\x04\x0b\x02\x04\x12\x03^\x02%\n\x0c\n\x05\x04\x0b\x02\
+    \x04\x04\x12\x03^\x02\n\n\x0c\n\x05\x04\x0b\x02\x04\x06\x12\x03^\x0b\x15\
+    \n\x0c\n\x05\x04\x0b\x02\x04\x01\x12\x03^\x16\x20\n\x0c\n\x05\x04\x0b\
+    \x02\x04\x03\x12\x03^#$\n\n\n\x02\x04\x0c\x12\x04a\0y\x01\n\n\n\x03\x04\
+    \x0c\x01\x12\x03a\x08\r\n\x0c\n\x04\x04\x0c\x04\0\x12\x04b\x02l\x03\n\
+    \x0c\n\x05\x04\x0c\x04\0\x01\x12\x03b\x07\x0b\n\r\n\x06\x04\x0c\x04\0\
+    \x02\0\x12\x03c\x04\x0f\n\x0e\n\x07\x04\x0c\x04\0\x02\0\x01\x12\x03c\x04\
+    \n\n\x0e\n\x07\x04\x0c\x04\0\x02\0\x02\x12\x03c\r\x0e\n\r\n\x06\x04\x0c\
+    \x04\0\x02\x01\x12\x03d\x04\x10\n\x0e\n\x07\x04\x0c\x04\0\x02\x01\x01\
+    \x12\x03d\x04\x0b\n\x0e\n\x07\x04\x0c\x04\0\x02\x01\x02\x12\x03d\x0e\x0f\
+    \n\r\n\x06\x04\x0c\x04\0\x02\x02\x12\x03e\x04\x11\n\x0e\n\x07\x04\x0c\
+    \x04\0\x02\x02\x01\x12\x03e\x04\x0c\n\x0e\n\x07\x04\x0c\x04\0\x02\x02\
+    \x02\x12\x03e\x0f\x10\n\r\n\x06\x04\x0c\x04\0\x02\x03\x12\x03f\x04\x16\n\
+    \x0e\n\x07\x04\x0c\x04\0\x02\x03\x01\x12\x03f\x04\x11\n\x0e\n\x07\x04\
+    \x0c\x04\0\x02\x03\x02\x12\x03f\x14\x15\n\r\n\x06\x04\x0c\x04\0\x02\x04\
+    \x12\x03g\x04\x14\n\x0e\n\x07\x04\x0c\x04\0\x02\x04\x01\x12\x03g\x04\x0f\
+    \n\x0e\n\x07\x04\x0c\x04\0\x02\x04\x02\x12\x03g\x12\x13\n\r\n\x06\x04\
+    \x0c\x04\0\x02\x05\x12\x03h\x04\r\n\x0e\n\x07\x04\x0c\x04\0\x02\x05\x01\
+    \x12\x03h\x04\x08\n\x0e\n\x07\x04\x0c\x04\0\x02\x05\x02\x12\x03h\x0b\x0c\
+    \n\r\n\x06\x04\x0c\x04\0\x02\x06\x12\x03i\x04\x0e\n\x0e\n\x07\x04\x0c\
+    \x04\0\x02\x06\x01\x12\x03i\x04\t\n\x0e\n\x07\x04\x0c\x04\0\x02\x06\x02\
+    \x12\x03i\x0c\r\n\r\n\x06\x04\x0c\x04\0\x02\x07\x12\x03j\x04\x17\n\x0e\n\
+    \x07\x04\x0c\x04\0\x02\x07\x01\x12\x03j\x04\x12\n\x0e\n\x07\x04\x0c\x04\
+    \0\x02\x07\x02\x12\x03j\x15\x16\n\r\n\x06\x04\x0c\x04\0\x02\x08\x12\x03k\
+    \x04\x12\n\x0e\n\x07\x04\x0c\x04\0\x02\x08\x01\x12\x03k\x04\r\n\x10\n\t\
+    \x04\x0c\x04\0\x02\x08\xe7\x07\0\x12\x03k\x10\x11\n\x11\n\n\x04\x0c\x04\
+    \0\x02\x08\xe7\x07\0\x02\x12\x03k\x10\x16\n\x12\n\x0b\x04\x0c\x04\0\x02\
+    \x08\xe7\x07\0\x02\0\x12\x03k\x10\x16\n\x13\n\x0c\x04\x0c\x04\0\x02\x08\
+    \xe7\x07\0\x02\0\x01\x12\x03k\x10\x16\n\x11\n\n\x04\x0c\x04\0\x02\x08\
+    \xe7\x07\0\x03\x12\x03k\x17\x11\n\x0b\n\x04\x04\x0c\x02\0\x12\x03n\x02\
+    \x19\n\x0c\n\x05\x04\x0c\x02\0\x04\x12\x03n\x02\n\n\x0c\n\x05\x04\x0c\
+    \x02\0\x06\x12\x03n\x0b\x0f\n\x0c\n\x05\x04\x0c\x02\0\x01\x12\x03n\x10\
+    \x14\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03n\x17\x18\n\x0b\n\x04\x04\x0c\
+    \x02\x01\x12\x03o\x02\x1a\n\x0c\n\x05\x04\x0c\x02\x01\x04\x12\x03o\x02\n\
+    \n\x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03o\x0b\x11\n\x0c\n\x05\x04\x0c\
+    \x02\x01\x01\x12\x03o\x12\x15\n\x0c\n\x05\x04\x0c\x02\x01\x03\x12\x03o\
+    \x18\x19\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03p\x02\x1e\n\x0c\n\x05\x04\
+    \x0c\x02\x02\x04\x12\x03p\x02\n\n\x0c\n\x05\x04\x0c\x02\x02\x05\x12\x03p\
+    \x0b\x11\n\x0c\n\x05\x04\x0c\x02\x02\x01\x12\x03p\x12\x19\n\x0c\n\x05\
+    \x04\x0c\x02\x02\x03\x12\x03p\x1c\x1d\n\x0c\n\x04\x04\x0c\x08\0\x12\x04q\
+    \x02x\x03\n\x0c\n\x05\x04\x0c\x08\0\x01\x12\x03q\x08\x0f\n\x0b\n\x04\x04\
+    \x0c\x02\x03\x12\x03r\x04\x1a\n\x0c\n\x05\x04\x0c\x02\x03\x06\x12\x03r\
+    \x04\x0e\n\x0c\n\x05\x04\x0c\x02\x03\x01\x12\x03r\x0f\x15\n\x0c\n\x05\
+    \x04\x0c\x02\x03\x03\x12\x03r\x18\x19\n\x0b\n\x04\x04\x0c\x02\x04\x12\
+    \x03s\x04\x18\n\x0c\n\x05\x04\x0c\x02\x04\x06\x12\x03s\x04\x0b\n\x0c\n\
+    \x05\x04\x0c\x02\x04\x01\x12\x03s\x0c\x13\n\x0c\n\x05\x04\x0c\x02\x04\
+    \x03\x12\x03s\x16\x17\n\x0b\n\x04\x04\x0c\x02\x05\x12\x03t\x04%\n\x0c\n\
+    \x05\x04\x0c\x02\x05\x06\x12\x03t\x04\x11\n\x0c\n\x05\x04\x0c\x02\x05\
+    \x01\x12\x03t\x12\x20\n\x0c\n\x05\x04\x0c\x02\x05\x03\x12\x03t#$\n\x0b\n\
+    \x04\x04\x0c\x02\x06\x12\x03u\x04!\n\x0c\n\x05\x04\x0c\x02\x06\x06\x12\
+    \x03u\x04\x0f\n\x0c\n\x05\x04\x0c\x02\x06\x01\x12\x03u\x10\x1c\n\x0c\n\
+    \x05\x04\x0c\x02\x06\x03\x12\x03u\x1f\x20\n\x0b\n\x04\x04\x0c\x02\x07\
+    \x12\x03v\x04\x1a\n\x0c\n\x05\x04\x0c\x02\x07\x06\x12\x03v\x04\x0c\n\x0c\
+    \n\x05\x04\x0c\x02\x07\x01\x12\x03v\r\x15\n\x0c\n\x05\x04\x0c\x02\x07\
+    \x03\x12\x03v\x18\x19\n\x0b\n\x04\x04\x0c\x02\x08\x12\x03w\x04\x1c\n\x0c\
+    \n\x05\x04\x0c\x02\x08\x06\x12\x03w\x04\r\n\x0c\n\x05\x04\x0c\x02\x08\
+    \x01\x12\x03w\x0e\x17\n\x0c\n\x05\x04\x0c\x02\x08\x03\x12\x03w\x1a\x1b\n\
+    \n\n\x02\x04\r\x12\x04{\0\x7f\x01\n\n\n\x03\x04\r\x01\x12\x03{\x08\x0c\n\
+    \x0b\n\x04\x04\r\x02\0\x12\x03|\x02\x1e\n\x0c\n\x05\x04\r\x02\0\x04\x12\
+    \x03|\x02\n\n\x0c\n\x05\x04\r\x02\0\x05\x12\x03|\x0b\x0f\n\x0c\n\x05\x04\
+    \r\x02\0\x01\x12\x03|\x10\x19\n\x0c\n\x05\x04\r\x02\0\x03\x12\x03|\x1c\
+    \x1d\n\x0b\n\x04\x04\r\x02\x01\x12\x03}\x02\x1b\n\x0c\n\x05\x04\r\x02\
+    \x01\x04\x12\x03}\x02\n\n\x0c\n\x05\x04\r\x02\x01\x05\x12\x03}\x0b\x10\n\
+    \x0c\n\x05\x04\r\x02\x01\x01\x12\x03}\x11\x16\n\x0c\n\x05\x04\r\x02\x01\
+    \x03\x12\x03}\x19\x1a\n\x0b\n\x04\x04\r\x02\x02\x12\x03~\x02\x1d\n\x0c\n\
+    \x05\x04\r\x02\x02\x04\x12\x03~\x02\n\n\x0c\n\x05\x04\r\x02\x02\x05\x12\
+    \x03~\x0b\x10\n\x0c\n\x05\x04\r\x02\x02\x01\x12\x03~\x11\x18\n\x0c\n\x05\
+    \x04\r\x02\x02\x03\x12\x03~\x1b\x1c\n\xc9\x01\n\x02\x04\x0e\x12\x06\x83\
+    \x01\0\x88\x01\x01\x1a\xba\x01\x20The\x20roles\x20the\x20leader\x20of\
+    \x20a\x20service\x20group\x20assigned\x20to\x20the\x20group's\x20members\
+    .\x20A\x20leader\x20publishes\n\x20its\x20assignment\x20in\x20its\x20own\
+    \x20service\x20rumor,\x20and\x20the\x20one\x20with\x20the\x20highest\x20\
+    version\x20is\x20in\x20effect.\n\n\x0b\n\x03\x04\x0e\x01\x12\x04\x83\x01\
+    \x08\x16\n\x0c\n\x04\x04\x0e\x02\0\x12\x04\x84\x01\x02\x1e\n\r\n\x05\x04\
+    \x0e\x02\0\x04\x12\x04\x84\x01\x02\n\n\r\n\x05\x04\x0e\x02\0\x05\x12\x04\
+    \x84\x01\x0b\x11\n\r\n\x05\x04\x0e\x02\0\x01\x12\x04\x84\x01\x12\x19\n\r\
+    \n\x05\x04\x0e\x02\0\x03\x12\x04\x84\x01\x1c\x1d\n\x0c\n\x04\x04\x0e\x02\
+    \x01\x12\x04\x85\x01\x02\x1d\n\r\n\x05\x04\x0e\x02\x01\x04\x12\x04\x85\
+    \x01\x02\n\n\r\n\x05\x04\x0e\x02\x01\x05\x12\x04\x85\x01\x0b\x11\n\r\n\
+    \x05\x04\x0e\x02\x01\x01\x12\x04\x85\x01\x12\x18\n\r\n\x05\x04\x0e\x02\
+    \x01\x03\x12\x04\x85\x01\x1b\x1c\na\n\x04\x04\x0e\x02\x02\x12\x04\x87\
+    \x01\x02\x1d\x1aS\x20The\x20member\x20holding\x20each\x20shard\x20ordina\
+    l,\x20or\x20an\x20empty\x20string\x20while\x20it\x20has\x20no\x20holder.\
+    \n\n\r\n\x05\x04\x0e\x02\x02\x04\x12\x04\x87\x01\x02\n\n\r\n\x05\x04\x0e\
+    \x02\x02\x05\x12\x04\x87\x01\x0b\x11\n\r\n\x05\x04\x0e\x02\x02\x01\x12\
+    \x04\x87\x01\x12\x18\n\r\n\x05\x04\x0e\x02\x02\x03\x12\x04\x87\x01\x1b\
+    \x1c\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
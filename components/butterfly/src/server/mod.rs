@@ -91,6 +91,7 @@ pub struct Server {
     swim_rounds: Arc<AtomicIsize>,
     gossip_rounds: Arc<AtomicIsize>,
     blacklist: Arc<RwLock<HashSet<String>>>,
+    abdications: Arc<RwLock<HashSet<String>>>,
 }
 
 impl Clone for Server {
@@ -119,6 +120,7 @@ impl Clone for Server {
             swim_rounds: self.swim_rounds.clone(),
             gossip_rounds: self.gossip_rounds.clone(),
             blacklist: self.blacklist.clone(),
+            abdications: self.abdications.clone(),
             socket: None,
         }
     }
@@ -173,6 +175,7 @@ impl Server {
                     swim_rounds: Arc::new(AtomicIsize::new(0)),
                     gossip_rounds: Arc::new(AtomicIsize::new(0)),
                     blacklist: Arc::new(RwLock::new(HashSet::new())),
+                    abdications: Arc::new(RwLock::new(HashSet::new())),
                     socket: None,
                 })
             }
@@ -681,11 +684,52 @@ impl Server {
         alive_population >= ((total_population / 2) + 1)
     }
 
+    /// Pick the member this server nominates when it gives up leadership of a service group: an
+    /// alive member whose service rumor says its service is up, so leadership is never handed to
+    /// a member that is still starting or failed to initialize. The choice is deterministic, so
+    /// that every abdication in a group settles on the same candidate. The election itself still
+    /// goes to the most suitable member.
+    fn get_successor(&self, key: &str) -> Option<String> {
+        let mut candidates = vec![];
+        self.service_store.with_rumors(key, |s| {
+            if s.get_member_id() != self.member_id() && s.get_initialized() &&
+                self.member_list.check_health_of_by_id(s.get_member_id(), Health::Alive)
+            {
+                candidates.push(String::from(s.get_member_id()));
+            }
+        });
+        candidates.into_iter().max()
+    }
+
     /// Start an election for the given service group, declaring this members suitability and the
     /// term for the election.
+    ///
+    /// If this server has abdicated the service group, it nominates a successor rather than
+    /// standing itself.
     pub fn start_election(&self, sg: ServiceGroup, term: u64) {
-        let suitability = self.suitability_lookup.get(&sg);
-        let mut e = Election::new(self.member_id(), sg, suitability);
+        let key = format!("{}", sg);
+        let mut e = if self.is_abdicating(&key) {
+            match self.get_successor(&key) {
+                Some(successor) => {
+                    // A nomination carries no suitability of its own; any member that stands for
+                    // itself will out-bid it.
+                    let mut e = Election::new(successor, sg, 0);
+                    e.insert_vote(self.member_id());
+                    e
+                }
+                None => {
+                    warn!(
+                        "Cannot start an election for {}; abdicated, and there is no one to \
+                           nominate",
+                        key
+                    );
+                    return;
+                }
+            }
+        } else {
+            let suitability = self.suitability_lookup.get(&sg);
+            Election::new(self.member_id(), sg, suitability)
+        };
         e.set_term(term);
         let ek = RumorKey::from(&e);
         if !self.check_quorum(e.key()) {
@@ -695,6 +739,69 @@ impl Server {
         self.rumor_heat.start_hot_rumor(ek);
     }
 
+    /// Give up leadership of the given service group, handing it to another alive member.
+    ///
+    /// Only the leader of a finished election can abdicate, and only when the group has quorum and
+    /// at least one other alive member to take over. On success a new election is started with
+    /// the next term, in which this server never stands as a candidate, and that term is
+    /// returned; use `elected_leader` to learn when it has finished.
+    pub fn abdicate(&self, sg: &ServiceGroup) -> Option<u64> {
+        let key = format!("{}", sg);
+        let mut current_term = None;
+        self.election_store.with_rumor(&key, "election", |e| if let Some(e) = e {
+            if e.is_finished() && e.get_member_id() == self.member_id() {
+                current_term = Some(e.get_term());
+            }
+        });
+        let term = match current_term {
+            Some(term) => term + 1,
+            None => return None,
+        };
+        if !self.check_quorum(&key) || self.get_successor(&key).is_none() {
+            return None;
+        }
+        self.abdications
+            .write()
+            .expect("Abdications lock is poisoned")
+            .insert(key.clone());
+        warn!("Abdicating leadership of {}; starting term {}", key, term);
+        self.election_store.remove(&key, "election");
+        self.start_election(sg.clone(), term);
+        Some(term)
+    }
+
+    /// Stand in elections for the given service group again, after a previous `abdicate`.
+    pub fn end_abdication(&self, sg: &ServiceGroup) {
+        self.abdications
+            .write()
+            .expect("Abdications lock is poisoned")
+            .remove(&format!("{}", sg));
+    }
+
+    /// Returns true if this server has abdicated leadership of the given service group.
+    pub fn is_abdicating(&self, key: &str) -> bool {
+        self.abdications
+            .read()
+            .expect("Abdications lock is poisoned")
+            .contains(key)
+    }
+
+    /// Returns the member elected leader of the given service group, once an election for `term`
+    /// or a later one has finished.
+    pub fn elected_leader(&self, sg: &ServiceGroup, term: u64) -> Option<String> {
+        let mut leader = None;
+        self.election_store.with_rumor(
+            &format!("{}", sg),
+            "election",
+            |e| if let Some(e) = e {
+                if e.is_finished() && e.get_term() >= term {
+                    leader = Some(String::from(e.get_member_id()));
+                }
+            },
+        );
+        leader
+    }
+
     pub fn start_update_election(&self, sg: ServiceGroup, suitability: u64, term: u64) {
         let mut e = ElectionUpdate::new(self.member_id(), sg, suitability);
         e.set_term(term);
@@ -14,6 +14,7 @@
 
 use habitat_butterfly::member::Health;
 use habitat_butterfly::message::swim::Election_Status;
+use habitat_core::service::ServiceGroup;
 
 use btest;
 
@@ -239,3 +240,84 @@ fn five_members_elect_a_new_leader_when_they_are_quorum_partitioned() {
         },
     );
 }
+
+#[test]
+fn three_members_elect_a_new_leader_when_the_old_one_abdicates() {
+    let mut net = btest::SwimNet::new(3);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_service(1, "core/witcher/1.2.3/20161208121212");
+    net.add_service(2, "core/witcher/1.2.3/20161208121212");
+    net.add_election(0, "witcher");
+    assert_wait_for_election_status!(net, [0..3], "witcher.prod", Election_Status::Finished);
+    assert_wait_for_equal_election!(net, [0..3, 0..3], "witcher.prod");
+
+    let mut leader_id = String::from("");
+    net[0].election_store.with_rumor(
+        "witcher.prod",
+        "election",
+        |e| {
+            leader_id = String::from(e.unwrap().get_member_id());
+        },
+    );
+    let mut leader = 0;
+    for (index, server) in net.iter_mut().enumerate() {
+        if server.member_id() == &leader_id[..] {
+            leader = index;
+        }
+    }
+
+    let sg = ServiceGroup::new(None, "witcher", "prod", None).unwrap();
+    let term = net[leader].abdicate(&sg).expect(
+        "Leader with quorum failed to abdicate",
+    );
+    assert_eq!(term, 1);
+    assert!(net[leader].elected_leader(&sg, term).is_none());
+
+    assert_wait_for_election_status!(net, leader, "witcher.prod", Election_Status::Finished);
+    assert_wait_for_equal_election!(net, [0..3, 0..3], "witcher.prod");
+    let new_leader_id = net[leader].elected_leader(&sg, term).expect(
+        "No leader elected after abdication",
+    );
+    assert!(new_leader_id != leader_id);
+}
+
+#[test]
+fn followers_cannot_abdicate() {
+    let mut net = btest::SwimNet::new(3);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_service(1, "core/witcher/1.2.3/20161208121212");
+    net.add_service(2, "core/witcher/1.2.3/20161208121212");
+    net.add_election(0, "witcher");
+    assert_wait_for_election_status!(net, [0..3], "witcher.prod", Election_Status::Finished);
+    assert_wait_for_equal_election!(net, [0..3, 0..3], "witcher.prod");
+
+    let sg = ServiceGroup::new(None, "witcher", "prod", None).unwrap();
+    let leader_id = net[0].elected_leader(&sg, 0).expect("No leader elected");
+    for server in net.iter_mut() {
+        if server.member_id() != &leader_id[..] {
+            assert!(server.abdicate(&sg).is_none());
+        }
+    }
+}
+
+#[test]
+fn leaders_cannot_abdicate_to_members_which_are_still_starting() {
+    let mut net = btest::SwimNet::new(3);
+    net.mesh();
+    net.add_starting_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_starting_service(1, "core/witcher/1.2.3/20161208121212");
+    net.add_starting_service(2, "core/witcher/1.2.3/20161208121212");
+    net.add_election(0, "witcher");
+    assert_wait_for_election_status!(net, [0..3], "witcher.prod", Election_Status::Finished);
+    assert_wait_for_equal_election!(net, [0..3, 0..3], "witcher.prod");
+
+    let sg = ServiceGroup::new(None, "witcher", "prod", None).unwrap();
+    let leader_id = net[0].elected_leader(&sg, 0).expect("No leader elected");
+    for server in net.iter_mut() {
+        if server.member_id() == &leader_id[..] {
+            assert!(server.abdicate(&sg).is_none());
+        }
+    }
+}
@@ -28,36 +28,54 @@
 //!
 //! This would install the `3.0.1` version of redis.
 //!
+//! Hosts which can't reach Builder can install everything from local directories of artifacts
+//! and public origin keys, or from a bundle made by `hab pkg bundle`:
+//!
+//! ```bash
+//! $ hab pkg install core/redis --offline /mnt/redis.bundle
+//! ```
+//!
 //! # Internals
 //!
-//! * Download the artifact
-//! * Verify it is un-altered
-//! * Unpack it
+//! * Check the package and its missing dependencies against the install policy
+//! * Download the artifact, and any missing dependencies, several at a time
+//! * Verify they are un-altered
+//! * Unpack them in dependency order
 //!
 
+use std::cmp;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::result::Result as StdResult;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
 
 use depot_client::{self, Client};
 use depot_client::Error::APIError;
 use hcore;
 use hcore::fs::{am_i_root, cache_key_path};
-use hcore::crypto::{artifact, SigKeyPair};
+use hcore::url::default_bldr_url;
+use hcore::crypto::{artifact, revocation, SigKeyPair};
 use hcore::crypto::keys::parse_name_with_rev;
-use hcore::package::{Identifiable, PackageArchive, PackageIdent, Target, PackageInstall};
+use hcore::package::{Identifiable, PackageArchive, PackageBundle, PackageIdent, Target,
+                     PackageInstall};
 use hcore::package::metadata::PackageType;
 use hyper::status::StatusCode;
 
+use command::package::policy::InstallPolicy;
 use error::{Error, Result};
-use ui::{Status, UI};
+use ui::{ProgressBar, Status, UI};
 
 use retry::retry;
 
 pub const RETRIES: u64 = 5;
 pub const RETRY_WAIT: u64 = 3000;
+/// How many artifacts are downloaded and verified at once, unless overridden by
+/// `FETCH_WORKERS_ENVVAR`.
+pub const DEFAULT_FETCH_WORKERS: usize = 4;
+pub const FETCH_WORKERS_ENVVAR: &'static str = "HAB_INSTALL_FETCH_WORKERS";
 
 /// Represents a locally-available `.hart` file for package
 /// installation purposes only.
@@ -172,6 +190,9 @@ impl AsRef<PackageIdent> for InstallSource {
 ///
 /// At the end of this function, the specified package and all its
 /// dependencies will be installed on the system.
+///
+/// Anything outside the install policy under `fs_root_path` is
+/// refused before it is downloaded.
 
 // TODO (CM): Consider passing in a configured depot client instead of
 // product / version... That might make it easier to share with the
@@ -191,15 +212,7 @@ where
     P1: AsRef<Path>,
     P2: AsRef<Path>,
 {
-    if env::var_os("HAB_NON_ROOT").is_none() && !am_i_root() {
-        ui.warn(
-            "Installing a package requires root or administrator privileges. Please retry \
-                   this command as a super user or use a privilege-granting facility such as \
-                   sudo.",
-        )?;
-        ui.br()?;
-        return Err(Error::RootRequired);
-    }
+    ensure_root(ui)?;
 
     // TODO (CM): rename fs::cache_key_path so the naming is
     // consistent and flows better.
@@ -213,6 +226,8 @@ where
         fs_root_path.as_ref(),
         artifact_cache_path.as_ref(),
         &key_cache_path,
+        vec![],
+        InstallPolicy::load(fs_root_path.as_ref())?,
     )?;
 
     match *install_source {
@@ -221,31 +236,168 @@ where
     }
 }
 
+/// Install a Habitat package without contacting Builder.
+///
+/// The package, all of its dependencies and the public origin keys
+/// they are signed with are taken from `local_sources`, each of which
+/// is either a directory of `.hart` files and public keys, or a bundle
+/// made by `hab pkg bundle`. A partially-qualified identifier resolves
+/// to the newest matching release found in any of them.
+pub fn start_offline<P1, P2>(
+    ui: &mut UI,
+    install_source: &InstallSource,
+    local_sources: &[PathBuf],
+    product: &str,
+    version: &str,
+    fs_root_path: P1,
+    artifact_cache_path: P2,
+) -> Result<PackageInstall>
+where
+    P1: AsRef<Path>,
+    P2: AsRef<Path>,
+{
+    ensure_root(ui)?;
+
+    let key_cache_path = cache_key_path(Some(fs_root_path.as_ref()));
+    let mut sources = Vec::with_capacity(local_sources.len());
+    for path in local_sources {
+        sources.push(LocalSource::open(path)?);
+    }
+    // The depot client is never used, but the task always has one.
+    let url = default_bldr_url();
+    let task = InstallTask::new(
+        &url,
+        product,
+        version,
+        fs_root_path.as_ref(),
+        artifact_cache_path.as_ref(),
+        &key_cache_path,
+        sources,
+        InstallPolicy::load(fs_root_path.as_ref())?,
+    )?;
+
+    match *install_source {
+        InstallSource::Ident(ref ident) => task.from_ident(ui, ident.clone(), None, None),
+        InstallSource::Archive(ref local_archive) => task.from_archive(ui, local_archive),
+    }
+}
+
+fn ensure_root(ui: &mut UI) -> Result<()> {
+    if env::var_os("HAB_NON_ROOT").is_none() && !am_i_root() {
+        ui.warn(
+            "Installing a package requires root or administrator privileges. Please retry \
+                   this command as a super user or use a privilege-granting facility such as \
+                   sudo.",
+        )?;
+        ui.br()?;
+        return Err(Error::RootRequired);
+    }
+    Ok(())
+}
+
+/// Somewhere artifacts and public origin keys can be installed from without going to Builder.
+enum LocalSource {
+    /// A directory holding `.hart` files and public key files.
+    Directory(PathBuf),
+    /// A bundle made by `hab pkg bundle`.
+    Bundle(PackageBundle),
+}
+
+impl LocalSource {
+    fn open(path: &Path) -> Result<Self> {
+        if path.is_dir() {
+            Ok(LocalSource::Directory(path.to_path_buf()))
+        } else {
+            Ok(LocalSource::Bundle(PackageBundle::open(path)?))
+        }
+    }
+
+    /// Returns the idents of every artifact this source holds.
+    fn idents(&self) -> Vec<PackageIdent> {
+        match *self {
+            LocalSource::Directory(ref dir) => {
+                let entries = match fs::read_dir(dir) {
+                    Ok(entries) => entries,
+                    Err(_) => return vec![],
+                };
+                entries
+                    .filter_map(|e| e.ok())
+                    .map(|e| e.path())
+                    .filter(|p| p.extension().map_or(false, |ext| ext == "hart"))
+                    .filter_map(|p| PackageArchive::new(p).ident().ok())
+                    .collect()
+            }
+            LocalSource::Bundle(ref bundle) => bundle.idents(),
+        }
+    }
+
+    /// Copies the named file to `dst` if this source holds it, returning whether it did.
+    fn copy_file(&self, name: &str, dst: &Path) -> Result<bool> {
+        match *self {
+            LocalSource::Directory(ref dir) => {
+                let src = dir.join(name);
+                if !src.is_file() {
+                    return Ok(false);
+                }
+                if src != dst {
+                    fs::copy(&src, dst)?;
+                }
+                Ok(true)
+            }
+            LocalSource::Bundle(ref bundle) => {
+                if !bundle.contains(name) {
+                    return Ok(false);
+                }
+                bundle.extract(name, dst)?;
+                Ok(true)
+            }
+        }
+    }
+}
+
 struct InstallTask<'a> {
     depot_client: Client,
+    // Kept around so that every fetch worker can make a client of its own.
+    url: &'a str,
+    product: &'a str,
+    version: &'a str,
     fs_root_path: &'a Path,
     /// The path to the local artifact cache (e.g., /hab/cache/artifacts)
     artifact_cache_path: &'a Path,
     key_cache_path: &'a Path,
+    /// When there are any, everything is installed from these instead of from Builder.
+    local_sources: Vec<LocalSource>,
+    policy: InstallPolicy,
 }
 
 impl<'a> InstallTask<'a> {
     fn new(
-        url: &str,
-        product: &str,
-        version: &str,
+        url: &'a str,
+        product: &'a str,
+        version: &'a str,
         fs_root_path: &'a Path,
         artifact_cache_path: &'a Path,
         key_cache_path: &'a Path,
+        local_sources: Vec<LocalSource>,
+        policy: InstallPolicy,
     ) -> Result<Self> {
         Ok(InstallTask {
             depot_client: Client::new(url, product, version, Some(fs_root_path))?,
+            url: url,
+            product: product,
+            version: version,
             fs_root_path: fs_root_path,
             artifact_cache_path: artifact_cache_path,
             key_cache_path: key_cache_path,
+            local_sources: local_sources,
+            policy: policy,
         })
     }
 
+    fn is_offline(&self) -> bool {
+        !self.local_sources.is_empty()
+    }
+
     /// Install a package from the Depot, based on a given identifier.
     ///
     /// If the identifier is fully-qualified, that specific package
@@ -266,6 +418,13 @@ impl<'a> InstallTask<'a> {
         channel: Option<&str>,
         token: Option<&str>,
     ) -> Result<PackageInstall> {
+        // A pin narrows down which release we go looking for.
+        let ident = self.policy.constrain(&ident)?;
+        if let Some(channel) = channel {
+            if !self.is_offline() {
+                self.policy.check_channel(channel)?;
+            }
+        }
         if channel.is_some() {
             ui.begin(format!(
                 "Installing {} from channel '{}'",
@@ -279,7 +438,9 @@ impl<'a> InstallTask<'a> {
         // The "target_ident" will be the fully-qualified identifier
         // of the package we will ultimately install, once we
         // determine if we need to get a more recent version or not.
-        let target_ident = if !ident.fully_qualified() {
+        let target_ident = if self.is_offline() {
+            self.latest_local_ident(&ident)?
+        } else if !ident.fully_qualified() {
             match self.fetch_latest_pkg_ident_for(&ident, channel, token) {
                 Ok(latest_ident) => latest_ident,
                 Err(Error::DepotClient(APIError(StatusCode::NotFound, _))) => {
@@ -345,6 +506,7 @@ impl<'a> InstallTask<'a> {
             }
             None => {
                 // No installed package was found
+                self.check_policy(&target_ident, token)?;
                 self.install_package(ui, &target_ident, token)
             }
         }
@@ -360,6 +522,9 @@ impl<'a> InstallTask<'a> {
         token: Option<&str>,
     ) -> Result<Vec<(String, String)>> {
         let mut res = Vec::new();
+        if self.is_offline() {
+            return Ok(res);
+        }
 
         let channels = match self.depot_client.list_channels(ident.origin(), false) {
             Ok(channels) => channels,
@@ -394,6 +559,8 @@ impl<'a> InstallTask<'a> {
                 Ok(package_install)
             }
             None => {
+                // A local archive doesn't come from a channel, so only the origin and pins apply.
+                self.policy.check(ident)?;
                 self.store_artifact_in_cache(ident, &local_archive.path)?;
                 self.install_package(ui, ident, None)
             }
@@ -422,14 +589,18 @@ impl<'a> InstallTask<'a> {
                 // Ensure that all transitive dependencies, as well as the
                 // original package itself, are cached locally.
                 let dependencies = artifact.tdeps()?;
-                let mut artifacts_to_install = Vec::with_capacity(dependencies.len() + 1);
-                for dependency in dependencies.iter() {
-                    if self.installed_package(dependency).is_some() {
-                        ui.status(Status::Using, dependency)?;
+                let mut missing = Vec::with_capacity(dependencies.len());
+                for dependency in dependencies.into_iter() {
+                    if self.installed_package(&dependency).is_some() {
+                        ui.status(Status::Using, &dependency)?;
                     } else {
-                        artifacts_to_install.push(self.get_cached_artifact(ui, dependency, token)?);
+                        self.check_policy(&dependency, token)?;
+                        missing.push(dependency);
                     }
                 }
+                // The dependencies come back in the same order they went in, which is the
+                // order they have to be unpacked in.
+                let mut artifacts_to_install = self.get_cached_artifacts(ui, &missing, token)?;
                 // The package we're actually trying to install goes last; we
                 // want to ensure that its dependencies get installed before
                 // it does.
@@ -482,6 +653,8 @@ impl<'a> InstallTask<'a> {
                 "Found {} in artifact cache, skipping remote download",
                 ident
             );
+        } else if self.is_offline() {
+            self.copy_local_artifact(ui, ident)?;
         } else {
             if retry(
                 RETRIES,
@@ -504,6 +677,62 @@ impl<'a> InstallTask<'a> {
         Ok(artifact)
     }
 
+    /// Ensures every identified package is in the local cache and verified, downloading the
+    /// missing ones on a bounded pool of worker threads. The archives are returned in the same
+    /// order as `idents`.
+    fn get_cached_artifacts(
+        &self,
+        ui: &mut UI,
+        idents: &[PackageIdent],
+        token: Option<&str>,
+    ) -> Result<Vec<PackageArchive>> {
+        // Local copies are quick enough that a pool of workers isn't worth it.
+        if self.is_offline() {
+            return idents
+                .iter()
+                .map(|ident| self.get_cached_artifact(ui, ident, token))
+                .collect();
+        }
+        let workers = cmp::min(fetch_workers(), idents.len());
+        if workers == 0 {
+            return Ok(vec![]);
+        }
+
+        for ident in idents {
+            if self.is_artifact_cached(ident)? {
+                debug!(
+                    "Found {} in artifact cache, skipping remote download",
+                    ident
+                );
+            } else {
+                ui.status(Status::Downloading, ident)?;
+            }
+        }
+        let mut fetchers = Vec::with_capacity(workers);
+        for _ in 0..workers {
+            fetchers.push(ArtifactFetcher {
+                depot_client: Client::new(
+                    self.url,
+                    self.product,
+                    self.version,
+                    Some(self.fs_root_path),
+                )?,
+                artifact_cache_path: self.artifact_cache_path.to_path_buf(),
+                key_cache_path: self.key_cache_path.to_path_buf(),
+                token: token.map(String::from),
+            });
+        }
+        let fetched = fetch_in_parallel(idents, fetchers, |ident| {
+            ui.status(Status::Verified, ident)?;
+            Ok(())
+        })?;
+
+        fetched
+            .iter()
+            .map(|ident| Ok(PackageArchive::new(self.cached_artifact_path(ident)?)))
+            .collect()
+    }
+
     /// Adapter function wrapping `PackageArchive::unpack`
     fn unpack_artifact(&self, ui: &mut UI, artifact: &mut PackageArchive) -> Result<()> {
         artifact.unpack(Some(self.fs_root_path))?;
@@ -511,6 +740,52 @@ impl<'a> InstallTask<'a> {
         Ok(())
     }
 
+    /// Returns the newest release matching `ident` held by any of the local sources.
+    fn latest_local_ident(&self, ident: &PackageIdent) -> Result<PackageIdent> {
+        // It may already be installed, so don't insist on finding it here.
+        if ident.fully_qualified() {
+            return Ok(ident.clone());
+        }
+        self.local_sources
+            .iter()
+            .flat_map(|source| source.idents())
+            .filter(|candidate| candidate.satisfies(ident))
+            .max()
+            .ok_or(Error::OfflineArtifactNotFound(ident.to_string()))
+    }
+
+    /// Copies the identified artifact from the first local source which holds it into the
+    /// artifact cache.
+    fn copy_local_artifact(&self, ui: &mut UI, ident: &PackageIdent) -> Result<()> {
+        ui.status(Status::Using, format!("{} from local source", ident))?;
+        fs::create_dir_all(self.artifact_cache_path)?;
+        let name = fully_qualified_archive_name(ident)?;
+        let dst = self.cached_artifact_path(ident)?;
+        for source in self.local_sources.iter() {
+            if source.copy_file(&name, &dst)? {
+                return Ok(());
+            }
+        }
+        Err(Error::OfflineArtifactNotFound(ident.to_string()))
+    }
+
+    /// Copies a public origin key from the first local source which holds it into the key cache.
+    fn copy_local_origin_key(&self, ui: &mut UI, name_with_rev: &str) -> Result<()> {
+        fs::create_dir_all(self.key_cache_path)?;
+        let name = format!("{}.pub", name_with_rev);
+        let dst = self.key_cache_path.join(&name);
+        for source in self.local_sources.iter() {
+            if source.copy_file(&name, &dst)? {
+                ui.status(
+                    Status::Cached,
+                    format!("{} public origin key", &name_with_rev),
+                )?;
+                return Ok(());
+            }
+        }
+        Err(Error::OfflineOriginKeyNotFound(name_with_rev.to_string()))
+    }
+
     /// Adapter function to retrieve an installed package given an
     /// identifier, if it exists.
     fn installed_package(&self, ident: &PackageIdent) -> Option<PackageInstall> {
@@ -531,6 +806,17 @@ impl<'a> InstallTask<'a> {
         Ok(self.artifact_cache_path.join(name))
     }
 
+    /// Refuses a package the install policy doesn't allow. When installing from Builder and the
+    /// policy restricts channels, Builder is asked which channels the package is in.
+    fn check_policy(&self, ident: &PackageIdent, token: Option<&str>) -> Result<()> {
+        self.policy.check(ident)?;
+        if self.is_offline() || !self.policy.restricts_channels() {
+            return Ok(());
+        }
+        let channels = self.depot_client.package_channels(ident, token)?;
+        self.policy.check_channels(ident, &channels)
+    }
+
     fn fetch_latest_pkg_ident_for(
         &self,
         ident: &PackageIdent,
@@ -548,22 +834,13 @@ impl<'a> InstallTask<'a> {
     /// the artifact is cached locally.
     fn fetch_artifact(&self, ui: &mut UI, ident: &PackageIdent, token: Option<&str>) -> Result<()> {
         ui.status(Status::Downloading, ident)?;
-        match self.depot_client.fetch_package(
+        download_artifact(
+            &self.depot_client,
             ident,
             token,
             self.artifact_cache_path,
             ui.progress(),
-        ) {
-            Ok(_) => Ok(()),
-            Err(depot_client::Error::APIError(StatusCode::NotImplemented, _)) => {
-                println!(
-                    "Host platform or architecture not supported by the targted depot; \
-                          skipping."
-                );
-                Ok(())
-            }
-            Err(e) => Err(Error::from(e)),
-        }
+        )
     }
 
     fn fetch_origin_key(&self, ui: &mut UI, name_with_rev: &str) -> Result<()> {
@@ -607,26 +884,221 @@ impl<'a> InstallTask<'a> {
         ident: &PackageIdent,
         artifact: &mut PackageArchive,
     ) -> Result<()> {
-        let artifact_ident = artifact.ident()?;
-        if ident != &artifact_ident {
-            return Err(Error::ArtifactIdentMismatch((
-                artifact.file_name(),
-                artifact_ident.to_string(),
-                ident.to_string(),
-            )));
+        verify_with_keys(ident, artifact, self.key_cache_path, |nwr| if self.is_offline() {
+            self.copy_local_origin_key(ui, nwr)
+        } else {
+            self.fetch_origin_key(ui, nwr)
+        })
+    }
+}
+
+/// Downloads the identified package into the artifact cache. Depots which don't serve packages
+/// for this platform are reported and skipped rather than treated as failing.
+fn download_artifact(
+    depot_client: &Client,
+    ident: &PackageIdent,
+    token: Option<&str>,
+    artifact_cache_path: &Path,
+    progress: Option<ProgressBar>,
+) -> Result<()> {
+    match depot_client.fetch_package(ident, token, artifact_cache_path, progress) {
+        Ok(_) => Ok(()),
+        Err(depot_client::Error::APIError(StatusCode::NotImplemented, _)) => {
+            println!(
+                "Host platform or architecture not supported by the targted depot; \
+                      skipping."
+            );
+            Ok(())
         }
+        Err(e) => Err(Error::from(e)),
+    }
+}
+
+/// Checks and verifies an artifact, calling `fetch_key` for each of its signers whose public key
+/// isn't in the key cache yet.
+fn verify_with_keys<F>(
+    ident: &PackageIdent,
+    artifact: &mut PackageArchive,
+    key_cache_path: &Path,
+    mut fetch_key: F,
+) -> Result<()>
+where
+    F: FnMut(&str) -> Result<()>,
+{
+    let signers = check_artifact(ident, artifact, key_cache_path)?;
+    for nwr in signers.iter() {
+        if let Err(_) = SigKeyPair::get_public_key_path(nwr, key_cache_path) {
+            fetch_key(nwr)?;
+        }
+    }
+
+    artifact.verify(&key_cache_path)?;
+    debug!("Verified {} signed by {}", ident, signers.join(", "));
+    Ok(())
+}
 
-        let artifact_target = artifact.target()?;
-        artifact_target.validate()?;
+/// Puts a single package in the artifact cache. Each fetch worker owns one.
+trait Fetch: Send + 'static {
+    fn fetch(&self, ident: &PackageIdent) -> Result<()>;
+}
 
-        let nwr = artifact::artifact_signer(&artifact.path)?;
-        if let Err(_) = SigKeyPair::get_public_key_path(&nwr, self.key_cache_path) {
-            self.fetch_origin_key(ui, &nwr)?;
+/// Runs a fetch for every ident on a pool of worker threads, one per fetcher, calling
+/// `on_fetched` as each one succeeds. Fetches finish in any order, but the idents are returned in
+/// the order they were given, which is the order they must be installed in. After a fetch fails
+/// no new ones are started, and the failure is returned once the running ones are done.
+fn fetch_in_parallel<F, C>(
+    idents: &[PackageIdent],
+    fetchers: Vec<F>,
+    mut on_fetched: C,
+) -> Result<Vec<PackageIdent>>
+where
+    F: Fetch,
+    C: FnMut(&PackageIdent) -> Result<()>,
+{
+    let (job_tx, job_rx) = mpsc::channel();
+    for ident in idents {
+        job_tx.send(ident.clone()).expect("Fetch job queue closed");
+    }
+    // With the queue closed, idle workers stop as soon as it is empty.
+    drop(job_tx);
+    let jobs = Arc::new(Mutex::new(job_rx));
+
+    let (result_tx, result_rx) = mpsc::channel();
+    for (n, fetcher) in fetchers.into_iter().enumerate() {
+        let jobs = jobs.clone();
+        let results = result_tx.clone();
+        thread::Builder::new()
+            .name(format!("install-fetch-{}", n))
+            .spawn(move || loop {
+                let next = jobs.lock().expect("Fetch job queue lock poisoned").recv();
+                let ident = match next {
+                    Ok(ident) => ident,
+                    Err(_) => break,
+                };
+                let result = fetcher.fetch(&ident);
+                if results.send((ident, result)).is_err() {
+                    break;
+                }
+            })?;
+    }
+    drop(result_tx);
+
+    let mut failure = None;
+    for (ident, result) in result_rx.iter() {
+        match result {
+            Ok(()) => on_fetched(&ident)?,
+            Err(e) => {
+                debug!("Failed to fetch {}: {:?}", ident, e);
+                // Let the workers finish what they have started, but give them nothing new.
+                jobs.lock()
+                    .expect("Fetch job queue lock poisoned")
+                    .try_iter()
+                    .count();
+                if failure.is_none() {
+                    failure = Some(e);
+                }
+            }
         }
+    }
+    match failure {
+        Some(e) => Err(e),
+        None => Ok(idents.to_vec()),
+    }
+}
 
-        artifact.verify(&self.key_cache_path)?;
-        debug!("Verified {} signed by {}", ident, &nwr);
-        Ok(())
+/// Downloads and verifies artifacts on a fetch worker thread. It owns everything it needs so that
+/// it can be moved onto the thread, and leaves all output to the thread collecting the results.
+struct ArtifactFetcher {
+    depot_client: Client,
+    artifact_cache_path: PathBuf,
+    key_cache_path: PathBuf,
+    token: Option<String>,
+}
+
+impl Fetch for ArtifactFetcher {
+    /// Ensures the identified package is in the local cache, along with the public key of the
+    /// origin which signed it, and verifies it.
+    fn fetch(&self, ident: &PackageIdent) -> Result<()> {
+        let cache_path = self.artifact_cache_path.join(
+            fully_qualified_archive_name(ident)?,
+        );
+        if !cache_path.is_file() {
+            let token = self.token.as_ref().map(String::as_str);
+            if retry(
+                RETRIES,
+                RETRY_WAIT,
+                || {
+                    download_artifact(
+                        &self.depot_client,
+                        ident,
+                        token,
+                        &self.artifact_cache_path,
+                        None,
+                    )
+                },
+                |res| res.is_ok(),
+            ).is_err()
+            {
+                return Err(Error::from(depot_client::Error::DownloadFailed(format!(
+                    "We tried {} times but could not download {}. Giving up.",
+                    RETRIES,
+                    ident
+                ))));
+            }
+        }
+
+        let mut artifact = PackageArchive::new(cache_path);
+        verify_with_keys(ident, &mut artifact, &self.key_cache_path, |nwr| {
+            let (name, rev) = parse_name_with_rev(nwr)?;
+            self.depot_client.fetch_origin_key(
+                &name,
+                &rev,
+                &self.key_cache_path,
+                None::<ProgressBar>,
+            )?;
+            Ok(())
+        })
+    }
+}
+
+/// Checks that an artifact is the package it is expected to be, that it was built for this
+/// platform and that none of its signatures were made with a revoked key, returning the names
+/// with revision of the keys which signed it.
+fn check_artifact(
+    ident: &PackageIdent,
+    artifact: &mut PackageArchive,
+    key_cache_path: &Path,
+) -> Result<Vec<String>> {
+    let artifact_ident = artifact.ident()?;
+    if ident != &artifact_ident {
+        return Err(Error::ArtifactIdentMismatch((
+            artifact.file_name(),
+            artifact_ident.to_string(),
+            ident.to_string(),
+        )));
+    }
+
+    let artifact_target = artifact.target()?;
+    artifact_target.validate()?;
+
+    let signers = artifact::artifact_signers(&artifact.path)?;
+    // Refuse before fetching anything, rather than downloading a key we'll never trust.
+    for nwr in signers.iter() {
+        revocation::check_not_revoked(nwr, key_cache_path)?;
+    }
+    Ok(signers)
+}
+
+/// Returns how many artifacts to download and verify at once.
+fn fetch_workers() -> usize {
+    match env::var(FETCH_WORKERS_ENVVAR) {
+        Ok(val) => {
+            match val.parse() {
+                Ok(n) if n > 0 => n,
+                _ => DEFAULT_FETCH_WORKERS,
+            }
+        }
+        Err(_) => DEFAULT_FETCH_WORKERS,
     }
 }
 
@@ -640,3 +1112,123 @@ fn fully_qualified_archive_name(ident: &PackageIdent) -> Result<String> {
         ),
     ))
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Condvar;
+
+    use super::*;
+
+    fn ident(s: &str) -> PackageIdent {
+        PackageIdent::from_str(s).unwrap()
+    }
+
+    fn idents() -> Vec<PackageIdent> {
+        vec![
+            ident("core/glibc/2.22/20170513201042"),
+            ident("core/zlib/1.2.8/20170513201911"),
+            ident("core/openssl/1.0.2l/20171014213633"),
+            ident("core/redis/3.2.4/20170514150022"),
+        ]
+    }
+
+    // The packages verified so far, which fetches can wait on.
+    #[derive(Clone, Default)]
+    struct Verified(Arc<(Mutex<Vec<PackageIdent>>, Condvar)>);
+
+    impl Verified {
+        fn push(&self, ident: &PackageIdent) {
+            let &(ref verified, ref changed) = &*self.0;
+            verified.lock().unwrap().push(ident.clone());
+            changed.notify_all();
+        }
+
+        fn wait_for(&self, ident: &PackageIdent) {
+            let &(ref verified, ref changed) = &*self.0;
+            let mut verified = verified.lock().unwrap();
+            while !verified.contains(ident) {
+                verified = changed.wait(verified).unwrap();
+            }
+        }
+
+        fn get(&self) -> Vec<PackageIdent> {
+            (self.0).0.lock().unwrap().clone()
+        }
+    }
+
+    // Records every fetch it's asked for. If given what's been verified, a fetch doesn't finish
+    // until the package after it in the list has been verified, so fetches finish in the reverse
+    // of the install order.
+    struct FakeFetcher {
+        started: Arc<Mutex<Vec<PackageIdent>>>,
+        fail: Option<PackageIdent>,
+        reverse: Option<Verified>,
+    }
+
+    impl Fetch for FakeFetcher {
+        fn fetch(&self, ident: &PackageIdent) -> Result<()> {
+            self.started.lock().unwrap().push(ident.clone());
+            if let Some(ref verified) = self.reverse {
+                let position = idents().iter().position(|i| i == ident).unwrap();
+                if let Some(next) = idents().get(position + 1) {
+                    verified.wait_for(next);
+                }
+            }
+            if self.fail.as_ref() == Some(ident) {
+                return Err(Error::from(depot_client::Error::DownloadFailed(
+                    format!("{} is broken", ident),
+                )));
+            }
+            Ok(())
+        }
+    }
+
+    fn fetchers(
+        workers: usize,
+        fail: Option<PackageIdent>,
+        reverse: Option<Verified>,
+    ) -> (Vec<FakeFetcher>, Arc<Mutex<Vec<PackageIdent>>>) {
+        let started = Arc::new(Mutex::new(vec![]));
+        let fetchers = (0..workers)
+            .map(|_| {
+                FakeFetcher {
+                    started: started.clone(),
+                    fail: fail.clone(),
+                    reverse: reverse.clone(),
+                }
+            })
+            .collect();
+        (fetchers, started)
+    }
+
+    #[test]
+    fn fetches_keep_the_install_order() {
+        let verified = Verified::default();
+        let (fetchers, started) = fetchers(4, None, Some(verified.clone()));
+        let fetched = fetch_in_parallel(&idents(), fetchers, |ident| {
+            verified.push(ident);
+            Ok(())
+        }).unwrap();
+        assert_eq!(fetched, idents());
+        assert_eq!(started.lock().unwrap().len(), 4);
+        // They were verified as they arrived, which was the reverse of the order they're
+        // installed in.
+        let mut reversed = idents();
+        reversed.reverse();
+        assert_eq!(verified.get(), reversed);
+    }
+
+    #[test]
+    fn fetches_stop_after_a_failure() {
+        let (fetchers, started) =
+            fetchers(1, Some(ident("core/zlib/1.2.8/20170513201911")), None);
+        let mut verified = vec![];
+        let result = fetch_in_parallel(&idents(), fetchers, |ident| {
+            verified.push(ident.clone());
+            Ok(())
+        });
+        assert!(result.is_err());
+        assert_eq!(verified, vec![ident("core/glibc/2.22/20170513201042")]);
+        assert_eq!(*started.lock().unwrap(), idents()[..2].to_vec());
+    }
+}
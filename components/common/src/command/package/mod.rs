@@ -15,3 +15,4 @@
 pub mod binds;
 pub mod config;
 pub mod install;
+pub mod policy;
@@ -0,0 +1,248 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The install policy limits what the installer will put on a host.
+//!
+//! It is read from `/hab/etc/install-policy.toml`, and every rule is optional; without the file
+//! anything can be installed:
+//!
+//! ```toml
+//! # Only packages from these origins can be installed.
+//! origins = ["core", "acme"]
+//! # Packages downloaded from Builder must be in at least one of these channels.
+//! channels = ["stable"]
+//! # These packages can only be installed at the given version or release.
+//! pinned = ["core/redis/3.2.4", "acme/app/1.0.0/20170601120000"]
+//! ```
+//!
+//! The rules apply to the package being installed and to every dependency installed along with
+//! it, whether by `hab pkg install`, the Supervisor when loading a service, or its updaters.
+
+use std::path::Path;
+use std::str::FromStr;
+
+use hcore::config::ConfigFile;
+use hcore::package::{Identifiable, PackageIdent};
+
+use error::{Error, Result};
+
+/// Where the policy lives, relative to the filesystem root.
+pub const INSTALL_POLICY_PATH: &'static str = "hab/etc/install-policy.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PolicyFile {
+    origins: Vec<String>,
+    channels: Vec<String>,
+    pinned: Vec<String>,
+}
+
+impl ConfigFile for PolicyFile {
+    type Error = Error;
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InstallPolicy {
+    /// Origins packages may come from. Empty means any origin.
+    pub origins: Vec<String>,
+    /// Channels packages from Builder must be in at least one of. Empty means any channel.
+    pub channels: Vec<String>,
+    /// Packages which may only be installed at a particular version or release.
+    pub pinned: Vec<PackageIdent>,
+}
+
+impl InstallPolicy {
+    /// Load the policy under the given filesystem root. A missing file allows everything.
+    pub fn load<P: AsRef<Path>>(fs_root_path: P) -> Result<Self> {
+        let path = fs_root_path.as_ref().join(INSTALL_POLICY_PATH);
+        if !path.is_file() {
+            debug!("No install policy found at {}", path.display());
+            return Ok(Self::default());
+        }
+        debug!("Loading install policy from {}", path.display());
+        Self::from_policy_file(PolicyFile::from_file(&path)?)
+    }
+
+    fn from_policy_file(file: PolicyFile) -> Result<Self> {
+        let mut pinned = Vec::with_capacity(file.pinned.len());
+        for pin in file.pinned.iter() {
+            let ident = PackageIdent::from_str(pin)?;
+            if ident.version().is_none() {
+                return Err(Error::InstallPolicyViolation(format!(
+                    "pinned package {} must include a version",
+                    pin
+                )));
+            }
+            pinned.push(ident);
+        }
+        Ok(InstallPolicy {
+            origins: file.origins,
+            channels: file.channels,
+            pinned: pinned,
+        })
+    }
+
+    /// Narrow an identifier down to the release it is pinned to, returning an error if it asks
+    /// for a different version or release than the pin allows.
+    pub fn constrain(&self, ident: &PackageIdent) -> Result<PackageIdent> {
+        self.check_origin(ident)?;
+        let pin = match self.pin_for(ident) {
+            Some(pin) => pin,
+            None => return Ok(ident.clone()),
+        };
+        if !agrees(ident.version(), pin.version()) || !agrees(ident.release(), pin.release()) {
+            return Err(Error::InstallPolicyViolation(
+                format!("{} is pinned to {}", ident, pin),
+            ));
+        }
+        Ok(PackageIdent::new(
+            ident.origin(),
+            ident.name(),
+            ident.version().or(pin.version()),
+            ident.release().or(pin.release()),
+        ))
+    }
+
+    /// Check the origin and pin rules for a package about to be installed.
+    pub fn check(&self, ident: &PackageIdent) -> Result<()> {
+        self.constrain(ident).map(|_| ())
+    }
+
+    /// Whether packages must be in particular channels, and so need looking up on Builder.
+    pub fn restricts_channels(&self) -> bool {
+        !self.channels.is_empty()
+    }
+
+    /// Check that a channel may be installed from.
+    pub fn check_channel(&self, channel: &str) -> Result<()> {
+        if self.restricts_channels() && !self.channels.iter().any(|c| c == channel) {
+            return Err(Error::InstallPolicyViolation(format!(
+                "channel {} is not one of the allowed channels ({})",
+                channel,
+                self.channels.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check that a package is in at least one allowed channel, given all the channels it is in.
+    pub fn check_channels(&self, ident: &PackageIdent, channels: &[String]) -> Result<()> {
+        if self.restricts_channels() && !channels.iter().any(|c| self.channels.contains(c)) {
+            return Err(Error::InstallPolicyViolation(format!(
+                "{} is not in any of the allowed channels ({})",
+                ident,
+                self.channels.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    fn check_origin(&self, ident: &PackageIdent) -> Result<()> {
+        if !self.origins.is_empty() && !self.origins.iter().any(|o| o == ident.origin()) {
+            return Err(Error::InstallPolicyViolation(format!(
+                "origin {} of {} is not one of the allowed origins ({})",
+                ident.origin(),
+                ident,
+                self.origins.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    fn pin_for(&self, ident: &PackageIdent) -> Option<&PackageIdent> {
+        self.pinned.iter().find(|pin| {
+            pin.origin() == ident.origin() && pin.name() == ident.name()
+        })
+    }
+}
+
+/// Two optional ident components agree unless both are given and differ.
+fn agrees(a: Option<&str>, b: Option<&str>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a == b,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn policy(raw: &str) -> InstallPolicy {
+        InstallPolicy::from_policy_file(PolicyFile::from_raw(raw).unwrap()).unwrap()
+    }
+
+    fn ident(s: &str) -> PackageIdent {
+        PackageIdent::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn an_empty_policy_allows_everything() {
+        let policy = policy("");
+        assert_eq!(
+            policy.constrain(&ident("acme/app")).unwrap(),
+            ident("acme/app")
+        );
+        policy.check_channel("unstable").unwrap();
+        policy
+            .check_channels(&ident("acme/app/1.0.0/20170601120000"), &[])
+            .unwrap();
+    }
+
+    #[test]
+    fn origins_are_restricted() {
+        let policy = policy("origins = [\"core\"]");
+        policy.check(&ident("core/redis")).unwrap();
+        assert!(policy.check(&ident("acme/app")).is_err());
+    }
+
+    #[test]
+    fn pins_narrow_and_reject_idents() {
+        let policy = policy("pinned = [\"core/redis/3.2.4\"]");
+        assert_eq!(
+            policy.constrain(&ident("core/redis")).unwrap(),
+            ident("core/redis/3.2.4")
+        );
+        assert_eq!(
+            policy
+                .constrain(&ident("core/redis/3.2.4/20170514150022"))
+                .unwrap(),
+            ident("core/redis/3.2.4/20170514150022")
+        );
+        assert!(policy.constrain(&ident("core/redis/3.2.5")).is_err());
+        policy.check(&ident("core/openssl/1.0.2l")).unwrap();
+    }
+
+    #[test]
+    fn pins_must_include_a_version() {
+        let file = PolicyFile::from_raw("pinned = [\"core/redis\"]").unwrap();
+        assert!(InstallPolicy::from_policy_file(file).is_err());
+    }
+
+    #[test]
+    fn channels_are_restricted() {
+        let policy = policy("channels = [\"stable\"]");
+        policy.check_channel("stable").unwrap();
+        assert!(policy.check_channel("unstable").is_err());
+        let redis = ident("core/redis/3.2.4/20170514150022");
+        policy
+            .check_channels(&redis, &["unstable".to_string(), "stable".to_string()])
+            .unwrap();
+        assert!(
+            policy
+                .check_channels(&redis, &["unstable".to_string()])
+                .is_err()
+        );
+    }
+}
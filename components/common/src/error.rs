@@ -46,6 +46,12 @@ pub enum Error {
     WireDecode(String),
     EditorEnv(env::VarError),
     PackageNotFound,
+    /// Occurs when an offline install can't find an artifact in any of its local sources.
+    OfflineArtifactNotFound(String),
+    /// Occurs when an offline install can't find a public origin key in any of its local sources.
+    OfflineOriginKeyNotFound(String),
+    /// Occurs when a package falls outside the install policy.
+    InstallPolicyViolation(String),
 }
 
 impl fmt::Display for Error {
@@ -84,6 +90,15 @@ impl fmt::Display for Error {
             Error::WireDecode(ref m) => format!("Failed to decode wire message: {}", m),
             Error::EditorEnv(ref e) => format!("Missing EDITOR environment variable: {}", e),
             Error::PackageNotFound => format!("Package not found"),
+            Error::OfflineArtifactNotFound(ref ident) => {
+                format!("Offline install: no local artifact found for {}", ident)
+            }
+            Error::OfflineOriginKeyNotFound(ref key) => {
+                format!("Offline install: no local public origin key found for {}", key)
+            }
+            Error::InstallPolicyViolation(ref e) => {
+                format!("Refusing to install, outside the install policy: {}", e)
+            }
         };
         write!(f, "{}", msg)
     }
@@ -115,6 +130,9 @@ impl error::Error for Error {
             Error::WireDecode(_) => "Failed to decode wire message",
             Error::EditorEnv(_) => "Missing EDITOR environment variable",
             Error::PackageNotFound => "Package not found",
+            Error::OfflineArtifactNotFound(_) => "Artifact not found in local sources",
+            Error::OfflineOriginKeyNotFound(_) => "Public origin key not found in local sources",
+            Error::InstallPolicyViolation(_) => "Package is outside the install policy",
         }
     }
 }
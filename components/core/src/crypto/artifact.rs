@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fs::File;
+use std::fs::{self, File};
 use std::io;
 use std::io::prelude::*;
 use std::io::{BufReader, BufWriter};
@@ -22,9 +22,10 @@ use base64;
 use sodiumoxide::crypto::sign;
 
 use error::{Error, Result};
-use super::{HART_FORMAT_VERSION, SIG_HASH_TYPE, SigKeyPair};
+use super::{HART_FORMAT_VERSION, MULTI_SIG_HART_FORMAT_VERSION, SIG_HASH_TYPE, SigKeyPair};
 use super::hash;
 use super::keys::parse_name_with_rev;
+use super::revocation;
 
 /// Generate and sign a package
 pub fn sign<P1: ?Sized, P2: ?Sized>(src: &P1, dst: &P2, pair: &SigKeyPair) -> Result<()>
@@ -49,39 +50,93 @@ where
     Ok(())
 }
 
+/// Add a signature to an already signed artifact, keeping its existing signatures. The
+/// countersigned artifact is written to `dst` in the `HART-2` format, and `dst` may be `src`.
+pub fn countersign<P1: ?Sized, P2: ?Sized>(src: &P1, dst: &P2, pair: &SigKeyPair) -> Result<()>
+where
+    P1: AsRef<Path>,
+    P2: AsRef<Path>,
+{
+    let mut reader = BufReader::new(File::open(src)?);
+    let header = read_header(&mut reader)?;
+    if header.signatures.iter().any(
+        |s| s.key_name == pair.name_with_rev(),
+    )
+    {
+        return Err(Error::CryptoError(format!(
+            "Artifact is already signed with {}",
+            pair.name_with_rev()
+        )));
+    }
+    let hash = hash::hash_reader(&mut reader)?;
+    debug!("Payload hash for {} = {}", src.as_ref().display(), &hash);
+    let signature = sign::sign(&hash.as_bytes(), pair.secret()?);
+
+    let mut signatures = header.signatures;
+    signatures.push(ArtifactSignature::new(
+        pair.name_with_rev(),
+        base64::encode(&signature),
+    ));
+    // Write next to the destination first, so that countersigning an artifact in place never
+    // truncates the payload we're copying from.
+    let tmp_dst = dst.as_ref().with_extension("countersign.tmp");
+    {
+        let output_file = File::create(&tmp_dst)?;
+        let mut writer = BufWriter::new(&output_file);
+        write!(
+            writer,
+            "{}\n{}\n",
+            MULTI_SIG_HART_FORMAT_VERSION,
+            SIG_HASH_TYPE
+        )?;
+        for signature in signatures.iter() {
+            write!(
+                writer,
+                "{} {}\n",
+                signature.key_name,
+                signature.signature_raw
+            )?;
+        }
+        write!(writer, "\n")?;
+        io::copy(&mut get_archive_reader(&src)?, &mut writer)?;
+        writer.flush()?;
+    }
+    fs::rename(&tmp_dst, dst)?;
+    Ok(())
+}
+
 /// return a BufReader to the .tar bytestream, skipping the signed header
-pub fn get_archive_reader<P: AsRef<Path>>(src: &P) -> Result<BufReader<File>> {
+pub fn get_archive_reader<P: AsRef<Path> + ?Sized>(src: &P) -> Result<BufReader<File>> {
     let f = File::open(src)?;
-    let mut your_format_version = String::new();
-    let mut your_key_name = String::new();
-    let mut your_hash_type = String::new();
-    let mut your_signature_raw = String::new();
-    let mut empty_line = String::new();
-
     let mut reader = BufReader::new(f);
-    if reader.read_line(&mut your_format_version)? <= 0 {
-        return Err(Error::CryptoError("Can't read format version".to_string()));
-    }
-    if reader.read_line(&mut your_key_name)? <= 0 {
-        return Err(Error::CryptoError("Can't read keyname".to_string()));
-    }
-    if reader.read_line(&mut your_hash_type)? <= 0 {
-        return Err(Error::CryptoError("Can't read hash type".to_string()));
-    }
-    if reader.read_line(&mut your_signature_raw)? <= 0 {
-        return Err(Error::CryptoError("Can't read signature".to_string()));
-    }
-    if reader.read_line(&mut empty_line)? <= 0 {
-        return Err(Error::CryptoError("Can't end of header".to_string()));
-    }
+    read_header(&mut reader)?;
     Ok(reader)
 }
 
+/// One signature in an artifact header.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArtifactSignature {
+    pub key_name: String,
+    pub signature_raw: String,
+}
+
+impl ArtifactSignature {
+    pub fn new(key_name: String, signature_raw: String) -> ArtifactSignature {
+        ArtifactSignature {
+            key_name: key_name,
+            signature_raw: signature_raw,
+        }
+    }
+}
+
+/// The header of an artifact. `key_name` and `signature_raw` describe the first signature, which
+/// is the only one a `HART-1` artifact has; `signatures` holds every signature, in order.
 pub struct ArtifactHeader {
     pub format_version: String,
     pub key_name: String,
     pub hash_type: String,
     pub signature_raw: String,
+    pub signatures: Vec<ArtifactSignature>,
 }
 
 impl ArtifactHeader {
@@ -91,11 +146,13 @@ impl ArtifactHeader {
         hash_type: String,
         signature_raw: String,
     ) -> ArtifactHeader {
+        let signatures = vec![ArtifactSignature::new(key_name.clone(), signature_raw.clone())];
         ArtifactHeader {
             format_version: format_version,
             key_name: key_name,
             hash_type: hash_type,
             signature_raw: signature_raw,
+            signatures: signatures,
         }
     }
 }
@@ -108,51 +165,13 @@ where
     P: AsRef<Path>,
 {
     let f = File::open(src)?;
-    let mut your_format_version = String::new();
-    let mut your_key_name = String::new();
-    let mut your_hash_type = String::new();
-    let mut your_signature_raw = String::new();
-    let mut empty_line = String::new();
-
     let mut reader = BufReader::new(f);
-    if reader.read_line(&mut your_format_version)? <= 0 {
-        return Err(Error::CryptoError("Can't read format version".to_string()));
-    }
-    if reader.read_line(&mut your_key_name)? <= 0 {
-        return Err(Error::CryptoError("Can't read keyname".to_string()));
-    }
-    if reader.read_line(&mut your_hash_type)? <= 0 {
-        return Err(Error::CryptoError("Can't read hash type".to_string()));
-    }
-    if reader.read_line(&mut your_signature_raw)? <= 0 {
-        return Err(Error::CryptoError("Can't read signature".to_string()));
-    }
-    if reader.read_line(&mut empty_line)? <= 0 {
-        return Err(Error::CryptoError("Can't end of header".to_string()));
-    }
-    let your_format_version = your_format_version.trim().to_string();
-    let your_key_name = your_key_name.trim().to_string();
-    let your_hash_type = your_hash_type.trim().to_string();
-    let your_signature_raw = your_signature_raw.trim().to_string();
-
-    Ok(ArtifactHeader::new(
-        your_format_version,
-        your_key_name,
-        your_hash_type,
-        your_signature_raw,
-    ))
+    read_header(&mut reader)
 }
 
-/// verify the crypto signature of a .hart file
-pub fn verify<P1: ?Sized, P2: ?Sized>(src: &P1, cache_key_path: &P2) -> Result<(String, String)>
-where
-    P1: AsRef<Path>,
-    P2: AsRef<Path>,
-{
-    let f = File::open(src)?;
-    let mut reader = BufReader::new(f);
-
-    let _ = {
+/// Read the header of an artifact in either format, leaving `reader` at the start of the payload.
+pub fn read_header<R: BufRead>(reader: &mut R) -> Result<ArtifactHeader> {
+    let format_version = {
         let mut buffer = String::new();
         match reader.read_line(&mut buffer) {
             Ok(0) => {
@@ -161,77 +180,175 @@ where
                 ))
             }
             Ok(_) => {
-                if buffer.trim() != HART_FORMAT_VERSION {
-                    let msg = format!("Unsupported format version: {}", &buffer.trim());
+                let version = buffer.trim();
+                if version != HART_FORMAT_VERSION && version != MULTI_SIG_HART_FORMAT_VERSION {
+                    let msg = format!("Unsupported format version: {}", version);
                     return Err(Error::CryptoError(msg));
                 }
             }
             Err(e) => return Err(Error::from(e)),
-
         };
         buffer.trim().to_string()
     };
-    let pair = {
-        let mut buffer = String::new();
-        if reader.read_line(&mut buffer)? <= 0 {
-            return Err(Error::CryptoError(
-                "Corrupt payload, can't read origin key name".to_string(),
-            ));
-        }
-        SigKeyPair::get_pair_for(buffer.trim(), cache_key_path)?
-    };
-    let _ = {
-        let mut buffer = String::new();
-        match reader.read_line(&mut buffer) {
-            Ok(0) => {
+    if format_version == HART_FORMAT_VERSION {
+        let key_name = {
+            let mut buffer = String::new();
+            if reader.read_line(&mut buffer)? <= 0 {
                 return Err(Error::CryptoError(
-                    "Corrupt payload, can't read hash type".to_string(),
-                ))
-            }
-            Ok(_) => {
-                if buffer.trim() != SIG_HASH_TYPE {
-                    let msg = format!("Unsupported signature type: {}", &buffer.trim());
-                    return Err(Error::CryptoError(msg));
-                }
+                    "Corrupt payload, can't read origin key name".to_string(),
+                ));
             }
-            Err(e) => return Err(Error::from(e)),
+            parse_name_with_rev(buffer.trim())?;
+            buffer.trim().to_string()
         };
-    };
-    let signature = {
-        let mut buffer = String::new();
-        match reader.read_line(&mut buffer) {
-            Ok(0) => {
+        let hash_type = read_hash_type(reader)?;
+        let signature_raw = {
+            let mut buffer = String::new();
+            if reader.read_line(&mut buffer)? <= 0 {
                 return Err(Error::CryptoError(
                     "Corrupt payload, can't read signature".to_string(),
-                ))
+                ));
             }
-            Ok(_) => {
-                base64::decode(buffer.trim()).map_err(|e| {
-                    Error::CryptoError(format!("Can't decode signature: {}", e))
-                })?
+            decode_signature(buffer.trim())?;
+            buffer.trim().to_string()
+        };
+        read_end_of_header(reader)?;
+        Ok(ArtifactHeader::new(
+            format_version,
+            key_name,
+            hash_type,
+            signature_raw,
+        ))
+    } else {
+        let hash_type = read_hash_type(reader)?;
+        let mut signatures = vec![];
+        loop {
+            let mut buffer = String::new();
+            if reader.read_line(&mut buffer)? <= 0 {
+                return Err(Error::CryptoError(
+                    "Corrupt payload, can't find end of header".to_string(),
+                ));
             }
-            Err(e) => return Err(Error::from(e)),
+            let line = buffer.trim();
+            if line.is_empty() {
+                break;
+            }
+            let mut fields = line.splitn(2, ' ');
+            let key_name = fields.next().unwrap_or("");
+            parse_name_with_rev(key_name)?;
+            let signature_raw = match fields.next() {
+                Some(signature_raw) => signature_raw.trim(),
+                None => {
+                    return Err(Error::CryptoError(
+                        "Corrupt payload, can't read signature".to_string(),
+                    ))
+                }
+            };
+            decode_signature(signature_raw)?;
+            signatures.push(ArtifactSignature::new(
+                key_name.to_string(),
+                signature_raw.to_string(),
+            ));
         }
-    };
-    let _ = {
-        let mut buffer = String::new();
-        if reader.read_line(&mut buffer)? <= 0 {
+        if signatures.is_empty() {
             return Err(Error::CryptoError(
-                "Corrupt payload, can't find end of header".to_string(),
+                "Corrupt payload, can't read signature".to_string(),
             ));
         }
-    };
-    let expected_hash = match sign::verify(signature.as_slice(), pair.public()?) {
-        Ok(signed_data) => {
-            String::from_utf8(signed_data).map_err(|_| {
-                Error::CryptoError("Error parsing artifact signature".to_string())
-            })?
+        let mut header = ArtifactHeader::new(
+            format_version,
+            signatures[0].key_name.clone(),
+            hash_type,
+            signatures[0].signature_raw.clone(),
+        );
+        header.signatures = signatures;
+        Ok(header)
+    }
+}
+
+fn read_hash_type<R: BufRead>(reader: &mut R) -> Result<String> {
+    let mut buffer = String::new();
+    match reader.read_line(&mut buffer) {
+        Ok(0) => {
+            return Err(Error::CryptoError(
+                "Corrupt payload, can't read hash type".to_string(),
+            ))
+        }
+        Ok(_) => {
+            if buffer.trim() != SIG_HASH_TYPE {
+                let msg = format!("Unsupported signature type: {}", &buffer.trim());
+                return Err(Error::CryptoError(msg));
+            }
         }
-        Err(_) => return Err(Error::CryptoError("Verification failed".to_string())),
+        Err(e) => return Err(Error::from(e)),
     };
+    Ok(buffer.trim().to_string())
+}
+
+fn read_end_of_header<R: BufRead>(reader: &mut R) -> Result<()> {
+    let mut buffer = String::new();
+    if reader.read_line(&mut buffer)? <= 0 {
+        return Err(Error::CryptoError(
+            "Corrupt payload, can't find end of header".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn decode_signature(signature_raw: &str) -> Result<Vec<u8>> {
+    base64::decode(signature_raw).map_err(|e| {
+        Error::CryptoError(format!("Can't decode signature: {}", e))
+    })
+}
+
+/// verify the crypto signatures of a .hart file, returning the name with revision of the key
+/// which made the first signature, and the verified hash.
+///
+/// Every signature must be good, and none may be made with a key on the revocation list kept in
+/// `cache_key_path`.
+pub fn verify<P1: ?Sized, P2: ?Sized>(src: &P1, cache_key_path: &P2) -> Result<(String, String)>
+where
+    P1: AsRef<Path>,
+    P2: AsRef<Path>,
+{
+    let f = File::open(src)?;
+    let mut reader = BufReader::new(f);
+    let header = read_header(&mut reader)?;
+
+    let revoked = revocation::revoked_keys(cache_key_path)?;
+    for signature in header.signatures.iter() {
+        if revoked.contains(&signature.key_name) {
+            return Err(Error::RevokedKey(signature.key_name.clone()));
+        }
+    }
+
+    let mut expected_hash: Option<String> = None;
+    for signature in header.signatures.iter() {
+        let pair = SigKeyPair::get_pair_for(&signature.key_name, cache_key_path)?;
+        let signed_data = decode_signature(&signature.signature_raw)?;
+        let signed_hash = match sign::verify(signed_data.as_slice(), pair.public()?) {
+            Ok(signed_data) => {
+                String::from_utf8(signed_data).map_err(|_| {
+                    Error::CryptoError("Error parsing artifact signature".to_string())
+                })?
+            }
+            Err(_) => return Err(Error::CryptoError("Verification failed".to_string())),
+        };
+        if let Some(ref hash) = expected_hash {
+            if *hash != signed_hash {
+                let msg = format!(
+                    "Habitat artifact is invalid, signature by {} is for a different payload",
+                    &signature.key_name
+                );
+                return Err(Error::CryptoError(msg));
+            }
+        }
+        expected_hash = Some(signed_hash);
+    }
+    let expected_hash = expected_hash.unwrap();
     let computed_hash = hash::hash_reader(&mut reader)?;
     if computed_hash == expected_hash {
-        Ok((pair.name_with_rev(), expected_hash))
+        Ok((header.key_name, expected_hash))
     } else {
         let msg = format!(
             "Habitat artifact is invalid, \
@@ -243,40 +360,20 @@ where
     }
 }
 
+/// The name with revision of the key which made the first signature on an artifact.
 pub fn artifact_signer<P: AsRef<Path>>(src: &P) -> Result<String> {
-    let f = File::open(src)?;
-    let mut reader = BufReader::new(f);
-
-    let _ = {
-        let mut buffer = String::new();
-        match reader.read_line(&mut buffer) {
-            Ok(0) => {
-                return Err(Error::CryptoError(
-                    "Corrupt payload, can't read format version".to_string(),
-                ))
-            }
-            Ok(_) => {
-                if buffer.trim() != HART_FORMAT_VERSION {
-                    let msg = format!("Unsupported format version: {}", &buffer.trim());
-                    return Err(Error::CryptoError(msg));
-                }
-            }
-            Err(e) => return Err(Error::from(e)),
+    Ok(get_artifact_header(src)?.key_name)
+}
 
-        };
-        buffer.trim().to_string()
-    };
-    let name_with_rev = {
-        let mut buffer = String::new();
-        if reader.read_line(&mut buffer)? <= 0 {
-            return Err(Error::CryptoError(
-                "Corrupt payload, can't read origin key name".to_string(),
-            ));
-        }
-        parse_name_with_rev(buffer.trim())?;
-        buffer.trim().to_string()
-    };
-    Ok(name_with_rev)
+/// The names with revision of every key which signed an artifact, in order.
+pub fn artifact_signers<P: AsRef<Path>>(src: &P) -> Result<Vec<String>> {
+    Ok(
+        get_artifact_header(src)?
+            .signatures
+            .into_iter()
+            .map(|s| s.key_name)
+            .collect(),
+    )
 }
 
 #[cfg(test)]
@@ -287,9 +384,11 @@ mod test {
     use tempdir::TempDir;
 
     use super::*;
-    use super::super::{HART_FORMAT_VERSION, SIG_HASH_TYPE, SigKeyPair};
+    use super::super::{HART_FORMAT_VERSION, MULTI_SIG_HART_FORMAT_VERSION, SIG_HASH_TYPE,
+                       SigKeyPair};
     use super::super::test_support::*;
     use super::super::keys::parse_name_with_rev;
+    use super::super::revocation;
 
     #[test]
     fn sign_and_verify() {
@@ -533,4 +632,75 @@ mod test {
         assert_eq!(SIG_HASH_TYPE, hart_header.hash_type);
         assert!(hart_header.signature_raw.len() > 0);
     }
+
+    #[test]
+    fn countersign_and_verify() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn").unwrap();
+        pair.to_pair_files(cache.path()).unwrap();
+        let approver = SigKeyPair::generate_pair_for_origin("approvals").unwrap();
+        approver.to_pair_files(cache.path()).unwrap();
+        let src = cache.path().join("src.in");
+        let dst = cache.path().join("src.signed");
+        let mut f = File::create(&src).unwrap();
+        f.write_all("hearty goodness".as_bytes()).unwrap();
+        sign(&src, &dst, &pair).unwrap();
+
+        countersign(&dst, &dst, &approver).unwrap();
+        assert!(countersign(&dst, &dst, &approver).is_err());
+
+        let (signer, _hash) = verify(&dst, cache.path()).unwrap();
+        assert_eq!(signer, pair.name_with_rev());
+        let hart_header = get_artifact_header(&dst).unwrap();
+        assert_eq!(MULTI_SIG_HART_FORMAT_VERSION, hart_header.format_version);
+        assert_eq!(
+            artifact_signers(&dst).unwrap(),
+            vec![pair.name_with_rev(), approver.name_with_rev()]
+        );
+        let mut buffer = String::new();
+        get_archive_reader(&dst)
+            .unwrap()
+            .read_to_string(&mut buffer)
+            .unwrap();
+        assert_eq!(buffer, "hearty goodness");
+    }
+
+    #[test]
+    #[should_panic(expected = "Public key is required but not present for")]
+    fn verify_countersigned_missing_public_key() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn").unwrap();
+        pair.to_pair_files(cache.path()).unwrap();
+        let approver = SigKeyPair::generate_pair_for_origin("approvals").unwrap();
+        approver.to_pair_files(cache.path()).unwrap();
+        let dst = cache.path().join("signed.dat");
+        sign(&fixture("signme.dat"), &dst, &pair).unwrap();
+        countersign(&dst, &dst, &approver).unwrap();
+
+        // Every signature has to be checked, not just the first one
+        fs::remove_file(
+            SigKeyPair::get_public_key_path(&approver.name_with_rev(), cache.path()).unwrap(),
+        ).unwrap();
+
+        verify(&dst, cache.path()).unwrap();
+    }
+
+    #[test]
+    fn verify_refuses_revoked_keys() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn").unwrap();
+        pair.to_pair_files(cache.path()).unwrap();
+        let approver = SigKeyPair::generate_pair_for_origin("approvals").unwrap();
+        approver.to_pair_files(cache.path()).unwrap();
+        let dst = cache.path().join("signed.dat");
+        sign(&fixture("signme.dat"), &dst, &pair).unwrap();
+        countersign(&dst, &dst, &approver).unwrap();
+
+        revocation::revoke(&approver.name_with_rev(), cache.path()).unwrap();
+        match verify(&dst, cache.path()) {
+            Err(Error::RevokedKey(ref key)) => assert_eq!(*key, approver.name_with_rev()),
+            Err(e) => panic!("Expected a revoked key error, got {}", e),
+            Ok(_) => panic!("Verified an artifact signed with a revoked key"),
+        }
+    }
 }
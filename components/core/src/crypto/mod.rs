@@ -189,6 +189,27 @@
 //! is **not** a supported workflow for working with Habitat artifacts--they are signed for very
 //! important reasons.
 //!
+//! ### Countersigned artifacts
+//!
+//! An artifact can carry more than one signature, for example one from the origin key which built
+//! it and one from a release-approval key. Such an artifact uses the `HART-2` format, whose header
+//! names the hashing algorithm once and then has one line per signature, holding the name with
+//! revision of the signing key and the Base64 signed hash separated by a space. As before, an empty
+//! line separates the header from the payload:
+//!
+//! ```text
+//! HART-2
+//! BLAKE2b
+//! habitat-20160405144945 signed BLAKE2b signature
+//! approvals-20170602110000 signed BLAKE2b signature
+//!
+//! <binary-blob>
+//! ```
+//!
+//! Every signature must verify against its public key for the artifact to be trusted, and none of
+//! the keys may be on the local revocation list (see the `revocation` module). Since the header
+//! length varies, the `tail(1)` examples above only apply to `HART-1` artifacts.
+//!
 //! ## Encrypted payloads
 //!
 //! The first 4 lines of an encrypted payload are as follows:
@@ -249,6 +270,10 @@ pub static SIG_HASH_TYPE: &'static str = "BLAKE2b";
 /// at runtime. This is useful for testing.
 pub static CACHE_KEY_PATH_ENV_VAR: &'static str = "HAB_CACHE_KEY_PATH";
 pub static HART_FORMAT_VERSION: &'static str = "HART-1";
+/// The format version of artifacts which carry more than one signature
+pub static MULTI_SIG_HART_FORMAT_VERSION: &'static str = "HART-2";
+/// The name of the revocation list file in the key cache
+pub static REVOKED_KEYS_FILE: &'static str = "revoked-keys";
 pub static BOX_FORMAT_VERSION: &'static str = "BOX-1";
 pub static ANONYMOUS_BOX_FORMAT_VERSION: &'static str = "ANONYMOUS-BOX-1";
 /// Create secret key files with these permissions
@@ -266,6 +291,7 @@ pub mod artifact;
 pub mod dpapi;
 pub mod hash;
 pub mod keys;
+pub mod revocation;
 
 pub fn default_cache_key_path(fs_root_path: Option<&Path>) -> PathBuf {
     match henv::var(CACHE_KEY_PATH_ENV_VAR) {
@@ -0,0 +1,120 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The local list of revoked origin keys.
+//!
+//! The list lives next to the keys themselves, in a file named `revoked-keys` in the key cache,
+//! and holds one key name with revision per line. Blank lines and lines starting with `#` are
+//! ignored, so the file can be managed across a fleet like any other configuration file:
+//!
+//! ```text
+//! # leaked on 2017-06-01
+//! acme-20170101000000
+//! ```
+//!
+//! An artifact which carries a signature from a revoked key fails verification, even if its other
+//! signatures are good.
+
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use error::{Error, Result};
+use super::REVOKED_KEYS_FILE;
+use super::keys::parse_name_with_rev;
+
+/// The path to the revocation list in the given key cache.
+pub fn revoked_keys_path<P: AsRef<Path>>(cache_key_path: P) -> PathBuf {
+    cache_key_path.as_ref().join(REVOKED_KEYS_FILE)
+}
+
+/// The names with revision of every revoked key. A missing list means nothing is revoked.
+pub fn revoked_keys<P: AsRef<Path>>(cache_key_path: P) -> Result<HashSet<String>> {
+    let path = revoked_keys_path(cache_key_path);
+    let mut keys = HashSet::new();
+    if !path.is_file() {
+        return Ok(keys);
+    }
+    let reader = BufReader::new(File::open(&path)?);
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        keys.insert(line.to_string());
+    }
+    Ok(keys)
+}
+
+/// Returns an error if the named key is on the revocation list.
+pub fn check_not_revoked<P: AsRef<Path>>(name_with_rev: &str, cache_key_path: P) -> Result<()> {
+    if revoked_keys(cache_key_path)?.contains(name_with_rev) {
+        return Err(Error::RevokedKey(name_with_rev.to_string()));
+    }
+    Ok(())
+}
+
+/// Add a key to the revocation list, returning `false` if it was already on it.
+pub fn revoke<P: AsRef<Path>>(name_with_rev: &str, cache_key_path: P) -> Result<bool> {
+    parse_name_with_rev(name_with_rev)?;
+    if revoked_keys(cache_key_path.as_ref())?.contains(name_with_rev) {
+        return Ok(false);
+    }
+    fs::create_dir_all(cache_key_path.as_ref())?;
+    let mut file = OpenOptions::new().create(true).append(true).open(
+        revoked_keys_path(cache_key_path),
+    )?;
+    writeln!(file, "{}", name_with_rev)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::Write;
+
+    use tempdir::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn nothing_is_revoked_without_a_list() {
+        let cache = TempDir::new("key_cache").unwrap();
+        assert!(revoked_keys(cache.path()).unwrap().is_empty());
+        check_not_revoked("unicorn-20160517220007", cache.path()).unwrap();
+    }
+
+    #[test]
+    fn revoke_adds_each_key_once() {
+        let cache = TempDir::new("key_cache").unwrap();
+        File::create(revoked_keys_path(cache.path()))
+            .unwrap()
+            .write_all(b"# leaked\n\nacme-20170101000000\n")
+            .unwrap();
+
+        assert!(!revoke("acme-20170101000000", cache.path()).unwrap());
+        assert!(revoke("unicorn-20160517220007", cache.path()).unwrap());
+        assert!(revoke("not a key", cache.path()).is_err());
+
+        let keys = revoked_keys(cache.path()).unwrap();
+        assert_eq!(keys.len(), 2);
+        assert!(keys.contains("acme-20170101000000"));
+        match check_not_revoked("unicorn-20160517220007", cache.path()) {
+            Err(Error::RevokedKey(ref key)) => assert_eq!(key, "unicorn-20160517220007"),
+            r => panic!("Expected a revoked key error, got {:?}", r),
+        }
+    }
+}
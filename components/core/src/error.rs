@@ -95,6 +95,8 @@ pub enum Error {
     FullyQualifiedPackageIdentRequired(String),
     /// Occurs when an application environment string cannot be successfully parsed.
     InvalidApplicationEnvironment(String),
+    /// Occurs when a package bundle is malformed.
+    InvalidBundle(String),
     /// Occurs when a package identifier string cannot be successfully parsed.
     InvalidPackageIdent(String),
     /// Occurs when a package target string cannot be successfully parsed.
@@ -134,6 +136,8 @@ pub enum Error {
     OpenDesktopFailed(String),
     /// Occurs when a suitable installed package cannot be found.
     PackageNotFound(package::PackageIdent),
+    /// Occurs when an unpacked package is incomplete or doesn't match its checksums.
+    PackageUnpackFailed(String),
     /// When an error occurs parsing an integer.
     ParseIntError(num::ParseIntError),
     /// Occurs when setting ownership or permissions on a file or directory fails.
@@ -144,6 +148,8 @@ pub enum Error {
     PrivilegeNotHeld,
     /// When an error occurs parsing or compiling a regular expression.
     RegexParse(regex::Error),
+    /// Occurs when an artifact is signed with a key that is on the local revocation list.
+    RevokedKey(String),
     /// When an error occurs converting a `String` from a UTF-8 byte vector.
     StringFromUtf8Error(string::FromUtf8Error),
     /// When the system target (platform and architecture) do not match the package target.
@@ -296,6 +302,7 @@ impl fmt::Display for Error {
                     e
                 )
             }
+            Error::InvalidBundle(ref e) => format!("Invalid package bundle: {}", e),
             Error::InvalidPackageIdent(ref e) => {
                 format!(
                     "Invalid package identifier: {:?}. A valid identifier is in the form \
@@ -357,6 +364,7 @@ impl fmt::Display for Error {
                     format!("Cannot find a release of package: {}", pkg)
                 }
             }
+            Error::PackageUnpackFailed(ref e) => format!("Failed to unpack package: {}", e),
             Error::ParseIntError(ref e) => format!("{}", e),
             Error::PlanMalformed => format!("Failed to read or parse contents of Plan file"),
             Error::PermissionFailed(ref e) => format!("{}", e),
@@ -368,6 +376,12 @@ impl fmt::Display for Error {
                 )
             }
             Error::RegexParse(ref e) => format!("{}", e),
+            Error::RevokedKey(ref e) => {
+                format!(
+                    "Origin key {} has been revoked, refusing to trust artifacts signed with it",
+                    e
+                )
+            }
             Error::StringFromUtf8Error(ref e) => format!("{}", e),
             Error::TargetMatchError(ref e) => format!("{}", e),
             Error::UnameFailed(ref e) => format!("{}", e),
@@ -462,6 +476,7 @@ impl error::Error for Error {
                 "Application environment strings must be in \
                  application.environment format (example: twitter.prod)"
             }
+            Error::InvalidBundle(_) => "Package bundle is malformed",
             Error::InvalidPackageIdent(_) => {
                 "Package identifiers must be in origin/name format (example: acme/redis)"
             }
@@ -494,11 +509,13 @@ impl error::Error for Error {
             Error::NoOutboundAddr => "Failed to discover the outbound IP address",
             Error::OpenDesktopFailed(_) => "OpenDesktopW failed",
             Error::PackageNotFound(_) => "Cannot find a package",
+            Error::PackageUnpackFailed(_) => "Unpacked package failed validation",
             Error::ParseIntError(_) => "Failed to parse an integer from a string!",
             Error::PermissionFailed(_) => "Failed to set permissions",
             Error::PlanMalformed => "Failed to read or parse contents of Plan file",
             Error::PrivilegeNotHeld => "Privilege not held to spawn process as different user",
             Error::RegexParse(_) => "Failed to parse a regular expression",
+            Error::RevokedKey(_) => "Artifact is signed with a revoked origin key",
             Error::StringFromUtf8Error(_) => "Failed to convert a string from a Vec<u8> as UTF-8",
             Error::TargetMatchError(_) => "System target does not match package target",
             Error::UnameFailed(_) => "uname failed",
@@ -12,21 +12,34 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::error;
+use std::fs::{self, File};
+use std::io::Read;
 use std::path::{Path, PathBuf};
 use std::result;
 use std::str::{self, FromStr};
 
 use libarchive::writer;
 use libarchive::reader::{self, Reader};
-use libarchive::archive::{Entry, ReadFilter, ReadFormat, ExtractOption, ExtractOptions};
+use libarchive::archive::{Entry, FileType, ReadFilter, ReadFormat, ExtractOption,
+                          ExtractOptions};
+use rand::{self, Rng};
 use regex::Regex;
 
-use super::{Identifiable, PackageIdent, PackageTarget};
-use super::metadata::{MetaFile, PackageType};
+use super::{Identifiable, PackageIdent, PackageInstall, PackageTarget};
+use super::metadata::{parse_files_metafile, read_files_metafile, MetaFile, PackageType};
 use error::{Error, Result};
 use crypto::{artifact, hash};
+use fs::{pkg_install_path, pkg_root_path};
+use os::process::{self, Pid};
+
+/// Packages are unpacked into this directory under the package root first, so that they are on
+/// the same filesystem as their final location and can be renamed into it in one step.
+pub const STAGING_DIR: &'static str = ".staging";
+
+/// Every package has these metafiles, however it was built.
+const REQUIRED_METAFILES: [MetaFile; 3] = [MetaFile::Ident, MetaFile::Manifest, MetaFile::Target];
 
 lazy_static! {
     static ref METAFILE_REGXS: HashMap<MetaFile, Regex> = {
@@ -82,6 +95,19 @@ lazy_static! {
 
 type Metadata = HashMap<MetaFile, String>;
 
+/// A file or symbolic link inside a package archive.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ArchiveFile {
+    /// Path relative to the package's install directory, such as `hooks/run`
+    pub path: String,
+    pub size: u64,
+    /// Where the file points, if it's a symbolic link
+    pub link: Option<String>,
+    /// Checksum recorded for the file at build time, or computed from its contents for files
+    /// the `FILES` metafile doesn't list. Symbolic links have none.
+    pub checksum: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct PackageArchive {
     pub path: PathBuf,
@@ -236,6 +262,141 @@ impl PackageArchive {
         }
     }
 
+    /// Lists the files and symbolic links in the archive, ordered by path, with a checksum for
+    /// every file. Directories are left out.
+    ///
+    /// # Failures
+    ///
+    /// * If the archive cannot be read
+    pub fn files(&self) -> Result<Vec<ArchiveFile>> {
+        let mut files = vec![];
+        let mut checksums = HashMap::new();
+        let mut reader = self.reader()?;
+        loop {
+            let (pathname, filetype, size, link) = match reader.next_header() {
+                Some(entry) => {
+                    (
+                        entry.pathname().to_string(),
+                        entry.filetype(),
+                        entry.size(),
+                        entry.symlink().to_string(),
+                    )
+                }
+                None => break,
+            };
+            let path = match install_relative_path(&pathname) {
+                Some(path) => path,
+                None => continue,
+            };
+            match filetype {
+                FileType::RegularFile => {
+                    let body = read_entry(&mut reader)?;
+                    if path == MetaFile::Files.to_string() {
+                        checksums = parse_files_metafile(&String::from_utf8_lossy(&body))?
+                            .into_iter()
+                            .filter_map(|(checksum, path)| {
+                                install_relative_path(&path).map(|path| (path, checksum))
+                            })
+                            .collect();
+                    }
+                    files.push(ArchiveFile {
+                        path: path,
+                        size: size as u64,
+                        link: None,
+                        checksum: Some(hash::hash_bytes(&body)),
+                    });
+                }
+                FileType::SymbolicLink => {
+                    files.push(ArchiveFile {
+                        path: path,
+                        size: 0,
+                        link: Some(link),
+                        checksum: None,
+                    });
+                }
+                _ => (),
+            }
+        }
+        // Prefer the checksums recorded at build time; the ones computed above cover packages
+        // built before the `FILES` metafile existed, and the metafiles `FILES` can't list.
+        for file in files.iter_mut() {
+            if let Some(checksum) = checksums.remove(&file.path) {
+                file.checksum = Some(checksum);
+            }
+        }
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(files)
+    }
+
+    /// Reads a single file out of the archive, by its path relative to the package's install
+    /// directory. Returns `None` if the archive has no such file.
+    ///
+    /// # Failures
+    ///
+    /// * If the archive cannot be read
+    pub fn read_file(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        let mut reader = self.reader()?;
+        loop {
+            let matched = match reader.next_header() {
+                Some(entry) => {
+                    match entry.filetype() {
+                        FileType::RegularFile => {
+                            install_relative_path(entry.pathname()).map_or(false, |p| p == path)
+                        }
+                        _ => false,
+                    }
+                }
+                None => return Ok(None),
+            };
+            if matched {
+                return Ok(Some(read_entry(&mut reader)?));
+            }
+        }
+    }
+
+    /// Reads every file in the archive whose path, relative to the package's install directory,
+    /// is accepted by `filter`, in a single pass over the archive.
+    ///
+    /// # Failures
+    ///
+    /// * If the archive cannot be read
+    pub fn read_files<F>(&self, mut filter: F) -> Result<BTreeMap<String, Vec<u8>>>
+    where
+        F: FnMut(&str) -> bool,
+    {
+        let mut files = BTreeMap::new();
+        let mut reader = self.reader()?;
+        loop {
+            let path = match reader.next_header() {
+                Some(entry) => {
+                    match entry.filetype() {
+                        FileType::RegularFile => {
+                            install_relative_path(entry.pathname()).and_then(|p| if filter(&p) {
+                                Some(p)
+                            } else {
+                                None
+                            })
+                        }
+                        _ => None,
+                    }
+                }
+                None => return Ok(files),
+            };
+            if let Some(path) = path {
+                let body = read_entry(&mut reader)?;
+                files.insert(path, body);
+            }
+        }
+    }
+
+    fn reader(&self) -> Result<reader::StreamReader> {
+        let tar_reader = artifact::get_archive_reader(&self.path)?;
+        let mut builder = reader::Builder::new();
+        builder.support_format(ReadFormat::Gnutar)?;
+        builder.support_filter(ReadFilter::Xz)?;
+        Ok(builder.open_stream(tar_reader)?)
+    }
+
     /// A plain string representation of the archive's file name.
     pub fn file_name(&self) -> String {
         self.path
@@ -258,11 +419,42 @@ impl PackageArchive {
     /// Given a package name and a path to a file as an `&str`, unpack
     /// the package.
     ///
+    /// The package is extracted into a staging directory, checked against the checksums in its
+    /// `FILES` metafile, and only then moved into the package root. A crash or a full disk part
+    /// way through therefore never leaves a partly unpacked release behind; staging directories
+    /// left over by a process which has since died are removed the next time around.
+    ///
     /// # Failures
     ///
     /// * If the package cannot be unpacked
+    /// * If the unpacked package is missing a required metafile or fails its checksums
     pub fn unpack(&self, fs_root_path: Option<&Path>) -> Result<()> {
         let root = fs_root_path.unwrap_or(Path::new("/"));
+        let staging_root = pkg_root_path(Some(root)).join(STAGING_DIR);
+        remove_stale_staging_dirs(&staging_root);
+        let staging_path = staging_root.join(format!(
+            "{}-{}",
+            process::current_pid(),
+            rand::thread_rng()
+                .gen_ascii_chars()
+                .take(8)
+                .collect::<String>()
+        ));
+        fs::create_dir_all(&staging_path)?;
+        let result = self.extract(&staging_path).and_then(|_| {
+            self.install_staged(&staging_path, root)
+        });
+        if let Err(e) = fs::remove_dir_all(&staging_path) {
+            debug!(
+                "Failed to remove staging directory {}, {}",
+                staging_path.display(),
+                e
+            );
+        }
+        result
+    }
+
+    fn extract(&self, root: &Path) -> Result<()> {
         let tar_reader = artifact::get_archive_reader(&self.path)?;
         let mut builder = reader::Builder::new();
         builder.support_format(ReadFormat::Gnutar)?;
@@ -282,6 +474,42 @@ impl PackageArchive {
         Ok(())
     }
 
+    /// Validates the release extracted into `staging_path` and moves it into place, unless the
+    /// same release is already installed and intact.
+    fn install_staged(&self, staging_path: &Path, fs_root_path: &Path) -> Result<()> {
+        let mut idents = PackageInstall::all(Some(staging_path))?;
+        if idents.len() != 1 {
+            return Err(Error::PackageUnpackFailed(format!(
+                "{} contains {} package releases, expected 1",
+                self.file_name(),
+                idents.len()
+            )));
+        }
+        let ident = idents.remove(0);
+        validate_release(&ident, staging_path)?;
+
+        let install_path = pkg_install_path(&ident, Some(fs_root_path));
+        if let Some(parent) = install_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        if install_path.exists() {
+            // Services may be running from a release which is already installed, so it's kept
+            // as it is unless it's been damaged, and the staged copy is thrown away.
+            if validate_release(&ident, fs_root_path).is_ok() {
+                debug!("{} is already installed and intact, keeping it", ident);
+                return Ok(());
+            }
+            // A directory can't be renamed over one which has anything in it, so the damaged
+            // release is moved aside first and cleaned up along with the staging directory.
+            fs::rename(&install_path, staging_path.join("previous"))?;
+        }
+        fs::rename(
+            pkg_install_path(&ident, Some(staging_path)),
+            &install_path,
+        )?;
+        Ok(())
+    }
+
     fn read_deps(&mut self, file: MetaFile) -> Result<Vec<PackageIdent>> {
         let mut deps: Vec<PackageIdent> = vec![];
 
@@ -373,15 +601,111 @@ impl PackageArchive {
     }
 }
 
+/// Returns the path of an archive entry relative to the package's install directory, or `None` for
+/// the directories leading up to it.
+fn install_relative_path(pathname: &str) -> Option<String> {
+    let parts: Vec<&str> = pathname.trim_left_matches('/').splitn(7, '/').collect();
+    if parts.len() == 7 && parts[0] == "hab" && parts[1] == "pkgs" {
+        let path = parts[6].trim_right_matches('/');
+        if !path.is_empty() {
+            return Some(path.to_string());
+        }
+    }
+    None
+}
+
+/// Reads the body of the entry the reader is positioned at.
+fn read_entry(reader: &mut reader::StreamReader) -> Result<Vec<u8>> {
+    let mut body = vec![];
+    while let Some(bytes) = reader.read_block()? {
+        body.extend_from_slice(bytes);
+    }
+    Ok(body)
+}
+
 pub trait FromArchive: Sized {
     type Error: error::Error;
 
     fn from_archive(archive: &mut PackageArchive) -> result::Result<Self, Self::Error>;
 }
 
+/// Checks that a release unpacked under `staging_path`, either a staging directory or the root an
+/// installed release lives in, has every required metafile, that it is the release it says it
+/// is, and that every file listed in its `FILES` metafile is there with the expected checksum.
+fn validate_release(ident: &PackageIdent, staging_path: &Path) -> Result<()> {
+    let staged = pkg_install_path(ident, Some(staging_path));
+    for metafile in REQUIRED_METAFILES.iter() {
+        if !staged.join(metafile.to_string()).is_file() {
+            return Err(Error::PackageUnpackFailed(
+                format!("{} is missing its {} metafile", ident, metafile),
+            ));
+        }
+    }
+
+    let mut body = String::new();
+    File::open(staged.join(MetaFile::Ident.to_string()))?
+        .read_to_string(&mut body)?;
+    if body.trim() != ident.to_string() {
+        return Err(Error::PackageUnpackFailed(format!(
+            "{} was unpacked to the install path of {}",
+            body.trim(),
+            ident
+        )));
+    }
+
+    // Packages built before the `FILES` metafile existed have nothing to check against.
+    let files = staged.join(MetaFile::Files.to_string());
+    if !files.is_file() {
+        return Ok(());
+    }
+    for (checksum, path) in read_files_metafile(&files)? {
+        // Paths are listed where the file was at build time, which is where it will be once
+        // it's installed.
+        let staged_file = staging_path.join(path.trim_left_matches('/'));
+        match hash::hash_file(&staged_file) {
+            Ok(ref actual) if *actual == checksum => (),
+            Ok(_) => {
+                return Err(Error::PackageUnpackFailed(
+                    format!("checksum mismatch for {}", path),
+                ))
+            }
+            Err(_) => {
+                return Err(Error::PackageUnpackFailed(
+                    format!("{} is missing {}", ident, path),
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Removes staging directories left behind by processes which are no longer running.
+fn remove_stale_staging_dirs(staging_root: &Path) {
+    let entries = match fs::read_dir(staging_root) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.filter_map(|e| e.ok()) {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let alive = match name.split('-').next().map(|pid| pid.parse::<Pid>()) {
+            Some(Ok(pid)) => process::is_alive(pid),
+            _ => false,
+        };
+        if !alive {
+            debug!("Removing stale staging directory {}", entry.path().display());
+            let _ = fs::remove_dir_all(entry.path());
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use std::path::PathBuf;
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+
+    use tempdir::TempDir;
+
     use os::system::{Architecture, Platform};
     use super::*;
 
@@ -432,4 +756,147 @@ mod test {
         assert_eq!(target.platform, Platform::Linux);
         assert_eq!(target.architecture, Architecture::X86_64);
     }
+
+    #[test]
+    fn reading_artifact_files() {
+        let hart = PackageArchive::new(fixtures().join(
+            "happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart",
+        ));
+        let files = hart.files().unwrap();
+        assert!(files.iter().any(|f| f.path == "IDENT" && f.link.is_none()));
+        assert!(files.iter().all(|f| !f.path.starts_with("hab/")));
+
+        let ident = hart.read_file("IDENT").unwrap().unwrap();
+        // The fixture predates the `FILES` metafile, so the checksums come from the contents.
+        assert_eq!(
+            files.iter().find(|f| f.path == "IDENT").unwrap().checksum,
+            Some(hash::hash_bytes(&ident))
+        );
+        assert_eq!(
+            String::from_utf8(ident).unwrap().trim(),
+            "happyhumans/possums/8.1.4/20160427165340"
+        );
+        assert!(hart.read_file("nope.toml").unwrap().is_none());
+
+        let metafiles = hart.read_files(|p| p == "IDENT" || p == "TARGET").unwrap();
+        assert_eq!(
+            metafiles.keys().collect::<Vec<_>>(),
+            vec!["IDENT", "TARGET"]
+        );
+    }
+
+    #[test]
+    fn install_relative_paths() {
+        assert_eq!(
+            install_relative_path("hab/pkgs/core/redis/3.2.4/20170101000000/hooks/run"),
+            Some("hooks/run".to_string())
+        );
+        assert_eq!(
+            install_relative_path("/hab/pkgs/core/redis/3.2.4/20170101000000/config/"),
+            Some("config".to_string())
+        );
+        assert_eq!(
+            install_relative_path("hab/pkgs/core/redis/3.2.4/20170101000000/"),
+            None
+        );
+        assert_eq!(install_relative_path("hab/pkgs/core/redis"), None);
+    }
+
+    fn stage_release(staging_path: &Path, ident: &PackageIdent, files: &str) {
+        let staged = pkg_install_path(ident, Some(staging_path));
+        fs::create_dir_all(staged.join("bin")).unwrap();
+        for &(name, ref body) in [
+            ("IDENT", ident.to_string()),
+            ("MANIFEST", "# possums".to_string()),
+            ("TARGET", "x86_64-linux".to_string()),
+            ("bin/possums", "#!/bin/sh".to_string()),
+        ].iter()
+        {
+            File::create(staged.join(name))
+                .unwrap()
+                .write_all(body.as_bytes())
+                .unwrap();
+        }
+        let mut signed = File::create(staged.join("FILES")).unwrap();
+        write!(
+            signed,
+            "HART-1\nhappyhumans-20160424223347\nBLAKE2b\nc2lnbmF0dXJl\n\n{}",
+            files
+        ).unwrap();
+    }
+
+    #[test]
+    fn validate_release_checks_files_checksums() {
+        let staging = TempDir::new("staging").unwrap();
+        let ident: PackageIdent = "happyhumans/possums/8.1.4/20160427165340".parse().unwrap();
+        let bin = "/hab/pkgs/happyhumans/possums/8.1.4/20160427165340/bin/possums";
+        stage_release(
+            staging.path(),
+            &ident,
+            &format!("{}  {}\n", hash::hash_string("#!/bin/sh"), bin),
+        );
+        assert!(validate_release(&ident, staging.path()).is_ok());
+
+        stage_release(
+            staging.path(),
+            &ident,
+            &format!("{}  {}\n", hash::hash_string("#!/bin/bash"), bin),
+        );
+        match validate_release(&ident, staging.path()) {
+            Err(Error::PackageUnpackFailed(_)) => (),
+            other => panic!("Expected a checksum mismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn installed_releases_are_kept_unless_damaged() {
+        let fs_root = TempDir::new("fs_root").unwrap();
+        let staging = TempDir::new("staging").unwrap();
+        let ident: PackageIdent = "happyhumans/possums/8.1.4/20160427165340".parse().unwrap();
+        let bin = "/hab/pkgs/happyhumans/possums/8.1.4/20160427165340/bin/possums";
+        let files = format!("{}  {}\n", hash::hash_string("#!/bin/sh"), bin);
+        let installed = pkg_install_path(&ident, Some(fs_root.path()));
+        let hart = PackageArchive::new(fixtures().join(
+            "happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart",
+        ));
+
+        // Something only the running copy has, such as a file a service wrote next to it.
+        stage_release(fs_root.path(), &ident, &files);
+        File::create(installed.join("running")).unwrap();
+        stage_release(staging.path(), &ident, &files);
+        hart.install_staged(staging.path(), fs_root.path()).unwrap();
+        assert!(installed.join("running").is_file());
+
+        File::create(installed.join("bin/possums"))
+            .unwrap()
+            .write_all(b"tampered")
+            .unwrap();
+        hart.install_staged(staging.path(), fs_root.path()).unwrap();
+        assert!(!installed.join("running").exists());
+        assert!(validate_release(&ident, fs_root.path()).is_ok());
+    }
+
+    #[test]
+    fn validate_release_requires_metafiles() {
+        let staging = TempDir::new("staging").unwrap();
+        let ident: PackageIdent = "happyhumans/possums/8.1.4/20160427165340".parse().unwrap();
+        stage_release(staging.path(), &ident, "");
+        fs::remove_file(pkg_install_path(&ident, Some(staging.path())).join("TARGET")).unwrap();
+        match validate_release(&ident, staging.path()) {
+            Err(Error::PackageUnpackFailed(_)) => (),
+            other => panic!("Expected a missing metafile, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn stale_staging_dirs_are_removed() {
+        let staging = TempDir::new("staging").unwrap();
+        let ours = staging.path().join(format!("{}-abcdefgh", process::current_pid()));
+        let stale = staging.path().join("not-a-pid");
+        fs::create_dir_all(&ours).unwrap();
+        fs::create_dir_all(&stale).unwrap();
+        remove_stale_staging_dirs(staging.path());
+        assert!(ours.is_dir());
+        assert!(!stale.exists());
+    }
 }
@@ -0,0 +1,221 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A single file holding package artifacts and the public origin keys they are signed with, so
+//! that packages can be installed on hosts which can't reach Builder.
+//!
+//! A bundle starts with a `HAB-BUNDLE-1` line, followed by one entry per file. Each entry is a
+//! header line, then the contents of the file:
+//!
+//! ```text
+//! artifact <ident> <file name> <length>
+//! key <file name> <length>
+//! ```
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use super::{PackageArchive, PackageIdent};
+use error::{Error, Result};
+
+pub const BUNDLE_FORMAT_VERSION: &'static str = "HAB-BUNDLE-1";
+
+/// Where a file is in a bundle.
+#[derive(Clone, Copy, Debug)]
+struct Entry {
+    offset: u64,
+    len: u64,
+}
+
+#[derive(Debug)]
+pub struct PackageBundle {
+    pub path: PathBuf,
+    artifacts: HashMap<PackageIdent, String>,
+    entries: HashMap<String, Entry>,
+}
+
+impl PackageBundle {
+    /// Write a new bundle to `dst` holding the given artifacts and public key files.
+    pub fn create<P>(dst: P, artifacts: &[PathBuf], keys: &[PathBuf]) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let mut writer = BufWriter::new(File::create(dst.as_ref())?);
+        writeln!(writer, "{}", BUNDLE_FORMAT_VERSION)?;
+        for path in artifacts {
+            let ident = PackageArchive::new(path.clone()).ident()?;
+            let header = format!("artifact {} {}", ident, file_name(path)?);
+            write_entry(&mut writer, &header, path)?;
+        }
+        for path in keys {
+            let header = format!("key {}", file_name(path)?);
+            write_entry(&mut writer, &header, path)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Read the index of an existing bundle. File contents are only read once extracted.
+    pub fn open<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let mut reader = BufReader::new(File::open(path.as_ref())?);
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        if line.trim() != BUNDLE_FORMAT_VERSION {
+            return Err(bad_bundle(path.as_ref(), "unknown format version"));
+        }
+        let mut bundle = PackageBundle {
+            path: path.as_ref().to_path_buf(),
+            artifacts: HashMap::new(),
+            entries: HashMap::new(),
+        };
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+            let fields: Vec<&str> = line.trim().split(' ').collect();
+            let (ident, name, len) = match (fields[0], fields.len()) {
+                ("artifact", 4) => (Some(fields[1]), fields[2], fields[3]),
+                ("key", 3) => (None, fields[1], fields[2]),
+                _ => return Err(bad_bundle(path.as_ref(), "malformed entry header")),
+            };
+            if !is_plain_file_name(name) {
+                return Err(bad_bundle(path.as_ref(), "entry names must be plain file names"));
+            }
+            let len: u64 = match len.parse() {
+                Ok(len) => len,
+                Err(_) => return Err(bad_bundle(path.as_ref(), "malformed entry length")),
+            };
+            if let Some(ident) = ident {
+                bundle.artifacts.insert(
+                    PackageIdent::from_str(ident)?,
+                    name.to_string(),
+                );
+            }
+            let offset = reader.seek(SeekFrom::Current(0))?;
+            bundle.entries.insert(
+                name.to_string(),
+                Entry {
+                    offset: offset,
+                    len: len,
+                },
+            );
+            reader.seek(SeekFrom::Current(len as i64))?;
+        }
+        Ok(bundle)
+    }
+
+    /// The idents of every artifact in the bundle.
+    pub fn idents(&self) -> Vec<PackageIdent> {
+        self.artifacts.keys().cloned().collect()
+    }
+
+    /// Whether the bundle holds a file with the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries.contains_key(name)
+    }
+
+    /// Copy the named file out of the bundle to `dst`.
+    pub fn extract<P>(&self, name: &str, dst: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let entry = match self.entries.get(name) {
+            Some(entry) => *entry,
+            None => return Err(Error::FileNotFound(format!("{}", name))),
+        };
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start(entry.offset))?;
+        let mut out = File::create(dst.as_ref())?;
+        let copied = io::copy(&mut file.take(entry.len), &mut out)?;
+        if copied != entry.len {
+            return Err(bad_bundle(&self.path, "truncated entry"));
+        }
+        Ok(())
+    }
+}
+
+fn write_entry<W: Write>(writer: &mut W, header: &str, path: &Path) -> Result<()> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    writeln!(writer, "{} {}", header, len)?;
+    io::copy(&mut file, writer)?;
+    Ok(())
+}
+
+fn file_name(path: &Path) -> Result<String> {
+    match path.file_name() {
+        Some(name) => Ok(name.to_string_lossy().into_owned()),
+        None => Err(Error::FileNotFound(path.display().to_string())),
+    }
+}
+
+/// Entries are extracted by name, so they must not be able to point anywhere else.
+fn is_plain_file_name(name: &str) -> bool {
+    !name.is_empty() && !name.starts_with('.') && !name.contains('/') && !name.contains('\\')
+}
+
+fn bad_bundle(path: &Path, reason: &str) -> Error {
+    Error::InvalidBundle(format!("{}, {}", path.display(), reason))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::{Read, Write};
+
+    use tempdir::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn keys_round_trip_through_a_bundle() {
+        let dir = TempDir::new("bundle").unwrap();
+        let key = dir.path().join("happyhumans-20160424223347.pub");
+        File::create(&key)
+            .unwrap()
+            .write_all(b"SIG-PUB-1\nhappyhumans-20160424223347\n\nkey")
+            .unwrap();
+        let bundle_path = dir.path().join("test.bundle");
+        PackageBundle::create(&bundle_path, &[], &[key]).unwrap();
+
+        let bundle = PackageBundle::open(&bundle_path).unwrap();
+        assert!(bundle.idents().is_empty());
+        assert!(bundle.contains("happyhumans-20160424223347.pub"));
+        let out = dir.path().join("out.pub");
+        bundle
+            .extract("happyhumans-20160424223347.pub", &out)
+            .unwrap();
+        let mut body = String::new();
+        File::open(&out).unwrap().read_to_string(&mut body).unwrap();
+        assert_eq!(body, "SIG-PUB-1\nhappyhumans-20160424223347\n\nkey");
+    }
+
+    #[test]
+    fn entries_must_be_plain_file_names() {
+        let dir = TempDir::new("bundle").unwrap();
+        let bundle_path = dir.path().join("evil.bundle");
+        File::create(&bundle_path)
+            .unwrap()
+            .write_all(b"HAB-BUNDLE-1\nkey ../../etc/passwd 4\nroot")
+            .unwrap();
+        assert!(PackageBundle::open(&bundle_path).is_err());
+    }
+}
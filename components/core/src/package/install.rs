@@ -26,7 +26,9 @@ use toml;
 use toml::Value;
 
 use super::{Identifiable, PackageIdent, Target, PackageTarget};
-use super::metadata::{Bind, BindMapping, MetaFile, PackageType, parse_key_value};
+use super::metadata::{Bind, BindMapping, MetaFile, PackageType, PeriodicHook, parse_key_value,
+                      read_files_metafile};
+use crypto::hash;
 use error::{Error, Result};
 use fs;
 
@@ -40,6 +42,24 @@ pub struct PackageInstall {
     pub installed_path: PathBuf,
 }
 
+/// How the files of an installed release differ from the checksums recorded in its `FILES`
+/// metafile when it was built.
+#[derive(Debug, Default, PartialEq)]
+pub struct FileVerification {
+    /// Files whose contents no longer match their recorded checksum.
+    pub modified: Vec<PathBuf>,
+    /// Files which were recorded but are gone.
+    pub missing: Vec<PathBuf>,
+    /// Files which weren't recorded at all.
+    pub extra: Vec<PathBuf>,
+}
+
+impl FileVerification {
+    pub fn is_ok(&self) -> bool {
+        self.modified.is_empty() && self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
 // The docs recommend implementing `From` instead, but that feels a
 // bit odd here.
 impl Into<PackageIdent> for PackageInstall {
@@ -195,6 +215,16 @@ impl PackageInstall {
         }
     }
 
+    /// Returns the identifiers of every package installed under the given filesystem root, in no
+    /// particular order. An empty list is returned if no packages have been installed yet.
+    pub fn all(fs_root_path: Option<&Path>) -> Result<Vec<PackageIdent>> {
+        let package_root_path = fs::pkg_root_path(fs_root_path);
+        if !package_root_path.exists() {
+            return Ok(vec![]);
+        }
+        Self::package_list(&package_root_path)
+    }
+
     pub fn new_from_parts(
         ident: PackageIdent,
         fs_root_path: PathBuf,
@@ -273,6 +303,29 @@ impl PackageInstall {
         }
     }
 
+    /// Returns the periodic hooks declared by the `pkg_periodic_hooks` plan variable, along with
+    /// whether each of them was marked as leader-only by `pkg_periodic_hooks_leader`.
+    pub fn periodic_hooks(&self) -> Result<Vec<PeriodicHook>> {
+        let leader_only: Vec<String> = match self.read_metafile(MetaFile::PeriodicHooksLeader) {
+            Ok(body) => body.split_whitespace().map(|n| n.to_string()).collect(),
+            Err(Error::MetaFileNotFound(MetaFile::PeriodicHooksLeader)) => Vec::new(),
+            Err(e) => return Err(e),
+        };
+        match self.read_metafile(MetaFile::PeriodicHooks) {
+            Ok(body) => {
+                let mut hooks = Vec::new();
+                for line in body.lines() {
+                    let mut hook = PeriodicHook::from_str(line)?;
+                    hook.leader_only = leader_only.contains(&hook.name);
+                    hooks.push(hook);
+                }
+                Ok(hooks)
+            }
+            Err(Error::MetaFileNotFound(MetaFile::PeriodicHooks)) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Returns the bind mappings for a composite package.
     pub fn bind_map(&self) -> Result<HashMap<PackageIdent, Vec<BindMapping>>> {
         match self.read_metafile(MetaFile::BindMap) {
@@ -318,7 +371,7 @@ impl PackageInstall {
         }
     }
 
-    fn deps(&self) -> Result<Vec<PackageIdent>> {
+    pub fn deps(&self) -> Result<Vec<PackageIdent>> {
         self.read_deps(MetaFile::Deps)
     }
 
@@ -326,6 +379,45 @@ impl PackageInstall {
         self.read_deps(MetaFile::TDeps)
     }
 
+    /// Rehashes every file of this release and compares it with the checksums recorded in its
+    /// `FILES` metafile when it was built.
+    ///
+    /// # Failures
+    ///
+    /// * The release was built before `FILES` metafiles existed
+    /// * The `FILES` metafile or the release directory can't be read
+    pub fn verify_files(&self) -> Result<FileVerification> {
+        let files_path = self.installed_path.join(MetaFile::Files.to_string());
+        if !files_path.is_file() {
+            return Err(Error::MetaFileNotFound(MetaFile::Files));
+        }
+        let mut report = FileVerification::default();
+        let mut recorded = HashSet::new();
+        // `FILES` can't list itself, and `MANIFEST` is written after it.
+        recorded.insert(files_path.clone());
+        recorded.insert(self.installed_path.join(MetaFile::Manifest.to_string()));
+        for (checksum, path) in read_files_metafile(&files_path)? {
+            // Paths are recorded where they were at build time, so line them up with our root.
+            let on_disk = self.fs_root_path.join(path.trim_left_matches('/'));
+            match hash::hash_file(&on_disk) {
+                Ok(ref actual) if *actual == checksum => (),
+                Ok(_) => report.modified.push(on_disk.clone()),
+                Err(_) => report.missing.push(on_disk.clone()),
+            }
+            recorded.insert(on_disk);
+        }
+        let mut on_disk = vec![];
+        Self::walk_files(&self.installed_path, &mut on_disk)?;
+        report.extra = on_disk
+            .into_iter()
+            .filter(|path| !recorded.contains(path))
+            .collect();
+        report.modified.sort();
+        report.missing.sort();
+        report.extra.sort();
+        Ok(report)
+    }
+
     /// Returns a Rust representation of the mappings defined by the `pkg_exports` plan variable.
     ///
     /// These mappings are used as a filter-map to generate a public configuration when the package
@@ -635,6 +727,21 @@ impl PackageInstall {
         }
     }
 
+    /// Collects every regular file below `path`. Symlinks aren't followed, since the build only
+    /// records regular files either.
+    fn walk_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+        for entry in std::fs::read_dir(path)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                Self::walk_files(&entry.path(), files)?;
+            } else if file_type.is_file() {
+                files.push(entry.path());
+            }
+        }
+        Ok(())
+    }
+
     /// Returns a list of package structs built from the contents of the given directory.
     fn package_list(path: &Path) -> Result<Vec<PackageIdent>> {
         let mut package_list: Vec<PackageIdent> = vec![];
@@ -650,6 +757,10 @@ impl PackageInstall {
     fn walk_origins(path: &Path, packages: &mut Vec<PackageIdent>) -> Result<()> {
         for entry in std::fs::read_dir(path)? {
             let origin = entry?;
+            // Packages which are still being unpacked are staged in hidden directories.
+            if origin.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
             if std::fs::metadata(origin.path())?.is_dir() {
                 Self::walk_names(&origin, packages)?;
             }
@@ -730,10 +841,11 @@ impl fmt::Display for PackageInstall {
 mod test {
     use package::metadata::{BindMapping, MetaFile};
     use std::collections::HashMap;
-    use std::fs::File;
+    use std::fs::{self, File};
     use std::io::Write;
     use std::path::PathBuf;
     use std::str::FromStr;
+    use crypto::hash;
     use super::PackageInstall;
     use super::super::PackageIdent;
     use super::super::test_support::*;
@@ -759,6 +871,29 @@ mod test {
         }
     }
 
+    #[test]
+    fn all_lists_every_installed_release() {
+        let fs_root = TempDir::new("all_installed").expect(
+            "Could not create fs_root temporary directory",
+        );
+        assert!(PackageInstall::all(Some(fs_root.path())).unwrap().is_empty());
+
+        let idents = vec!["core/redis/3.2.4/20170514150022", "core/redis/3.2.4/20170622210423"];
+        for ident in &idents {
+            let ident = PackageIdent::from_str(ident).unwrap();
+            fs::create_dir_all(::fs::pkg_install_path(&ident, Some(fs_root.path())))
+                .expect("Could not create package directory");
+        }
+
+        let mut installed: Vec<String> = PackageInstall::all(Some(fs_root.path()))
+            .unwrap()
+            .iter()
+            .map(|i| i.to_string())
+            .collect();
+        installed.sort();
+        assert_eq!(installed, idents);
+    }
+
     /// Create a `PackageInstall` struct for the explicit purpose of
     /// testing metadata file interpretation. This exists to point to
     /// a directory of metadata files, and that's it.
@@ -857,5 +992,78 @@ core/bar=pub:core/publish sub:core/subscribe
 
     }
 
+    #[test]
+    fn reading_periodic_hooks_marks_leader_only_hooks() {
+        let installed_path = TempDir::new("periodic_hooks").expect(
+            "Could not create installed_path temporary directory",
+        );
+        let package_install =
+            fake_package_install("core/database", installed_path.path().to_path_buf());
+
+        write_metadata_file(
+            &package_install,
+            MetaFile::PeriodicHooks,
+            "backup=0 3 * * *\nvacuum=@hourly\n",
+        );
+        write_metadata_file(&package_install, MetaFile::PeriodicHooksLeader, "backup\n");
+
+        let mut hooks = package_install.periodic_hooks().unwrap();
+        hooks.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(hooks.len(), 2);
+        assert_eq!(hooks[0].name, "backup");
+        assert_eq!(hooks[0].schedule, "0 3 * * *");
+        assert!(hooks[0].leader_only);
+        assert_eq!(hooks[1].name, "vacuum");
+        assert_eq!(hooks[1].schedule, "@hourly");
+        assert!(!hooks[1].leader_only);
+    }
+
+    #[test]
+    fn verify_files_reports_modified_missing_and_extra_files() {
+        let fs_root = TempDir::new("verify_files").expect(
+            "Could not create fs_root temporary directory",
+        );
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+        let installed_path = ::fs::pkg_install_path(&ident, Some(fs_root.path()));
+        fs::create_dir_all(installed_path.join("bin")).unwrap();
+        let package_install = PackageInstall::new_from_parts(
+            ident,
+            fs_root.path().to_path_buf(),
+            ::fs::pkg_root_path(Some(fs_root.path())),
+            installed_path.clone(),
+        );
+        for &(name, content) in [
+            ("bin/intact", "intact"),
+            ("bin/changed", "changed"),
+            ("bin/extra", "extra"),
+            ("MANIFEST", "# redis"),
+        ].iter()
+        {
+            File::create(installed_path.join(name))
+                .unwrap()
+                .write_all(content.as_bytes())
+                .unwrap();
+        }
+        let prefix = "/hab/pkgs/core/redis/3.2.4/20170514150022";
+        write_metadata_file(
+            &package_install,
+            MetaFile::Files,
+            &format!(
+                "HART-1\ncore-20160810182414\nBLAKE2b\nc2lnbmF0dXJl\n\n\
+                 {}  {}/bin/intact\n{}  {}/bin/changed\n{}  {}/bin/gone\n",
+                hash::hash_string("intact"),
+                prefix,
+                hash::hash_string("original"),
+                prefix,
+                hash::hash_string("gone"),
+                prefix
+            ),
+        );
 
+        let report = package_install.verify_files().unwrap();
+        assert!(!report.is_ok());
+        assert_eq!(report.modified, vec![installed_path.join("bin/changed")]);
+        assert_eq!(report.missing, vec![installed_path.join("bin/gone")]);
+        assert_eq!(report.extra, vec![installed_path.join("bin/extra")]);
+    }
 }
@@ -15,11 +15,14 @@
 use std::collections::HashMap;
 use std::env;
 use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, Read};
 use std::iter::{FromIterator, IntoIterator};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::vec::IntoIter;
 
+use crypto::artifact;
 use error::{Error, Result};
 use package::PackageIdent;
 
@@ -29,6 +32,29 @@ const ENV_PATH_SEPARATOR: char = ':';
 #[cfg(windows)]
 const ENV_PATH_SEPARATOR: char = ';';
 
+/// Reads the checksum and build time path of every file listed in a `FILES` metafile. The file is
+/// signed like an artifact, so its body comes after the same header.
+pub fn read_files_metafile<P: AsRef<Path>>(path: P) -> Result<Vec<(String, String)>> {
+    let mut body = String::new();
+    File::open(path)?.read_to_string(&mut body)?;
+    parse_files_metafile(&body)
+}
+
+/// Parses the contents of a `FILES` metafile like `read_files_metafile`.
+pub fn parse_files_metafile(body: &str) -> Result<Vec<(String, String)>> {
+    let mut reader = body.as_bytes();
+    artifact::read_header(&mut reader)?;
+    let mut files = vec![];
+    for line in reader.lines() {
+        let line = line?;
+        let mut parts = line.splitn(2, "  ");
+        if let (Some(checksum), Some(path)) = (parts.next(), parts.next()) {
+            files.push((checksum.to_string(), path.to_string()));
+        }
+    }
+    Ok(files)
+}
+
 pub fn parse_key_value(s: &str) -> Result<HashMap<String, String>> {
     Ok(HashMap::from_iter(
         s.lines()
@@ -99,6 +125,39 @@ impl FromStr for BindMapping {
     }
 }
 
+/// Describes a hook which the Supervisor runs on a schedule rather than in response to a
+/// lifecycle event.
+#[derive(Debug, PartialEq)]
+pub struct PeriodicHook {
+    /// The name of the hook, which is also its file name under `hooks/periodic`.
+    pub name: String,
+    /// A cron-style schedule expression describing when to run the hook.
+    pub schedule: String,
+    /// Whether the hook should only be run by the elected leader of a service group.
+    pub leader_only: bool,
+}
+
+impl FromStr for PeriodicHook {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self> {
+        let mut parts = line.splitn(2, '=');
+        let name = match parts.next() {
+            Some(name) if !name.trim().is_empty() => name.trim().to_string(),
+            _ => return Err(Error::MetaFileMalformed(MetaFile::PeriodicHooks)),
+        };
+        let schedule = match parts.next() {
+            Some(schedule) if !schedule.trim().is_empty() => schedule.trim().to_string(),
+            _ => return Err(Error::MetaFileMalformed(MetaFile::PeriodicHooks)),
+        };
+        Ok(PeriodicHook {
+            name: name,
+            schedule: schedule,
+            leader_only: false,
+        })
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct EnvVar {
     pub key: String,
@@ -174,11 +233,14 @@ pub enum MetaFile {
     EnvironmentSep,
     Exports,
     Exposes,
+    Files,
     Ident,
     LdFlags,
     LdRunPath,
     Manifest,
     Path,
+    PeriodicHooks,
+    PeriodicHooksLeader,
     ResolvedServices, // Composite-only
     RuntimeEnvironment,
     Services, // Composite-only
@@ -202,11 +264,14 @@ impl fmt::Display for MetaFile {
             MetaFile::EnvironmentSep => "ENVIRONMENT_SEP",
             MetaFile::Exports => "EXPORTS",
             MetaFile::Exposes => "EXPOSES",
+            MetaFile::Files => "FILES",
             MetaFile::Ident => "IDENT",
             MetaFile::LdFlags => "LDFLAGS",
             MetaFile::LdRunPath => "LD_RUN_PATH",
             MetaFile::Manifest => "MANIFEST",
             MetaFile::Path => "PATH",
+            MetaFile::PeriodicHooks => "PERIODIC_HOOKS",
+            MetaFile::PeriodicHooksLeader => "PERIODIC_HOOKS_LEADER",
             MetaFile::ResolvedServices => "RESOLVED_SERVICES",
             MetaFile::RuntimeEnvironment => "RUNTIME_ENVIRONMENT",
             MetaFile::Services => "SERVICES",
@@ -262,6 +327,47 @@ port=front-end.port
 "#;
     static PATH: &str = "/hab/pkgs/python/setuptools/35.0.1/20170424072606/bin";
 
+    #[test]
+    fn parsing_signed_files_metafile() {
+        let files = parse_files_metafile(
+            "HART-1\nhappyhumans-20160424223347\nBLAKE2b\nc2lnbmF0dXJl\n\n\
+             abc  /hab/pkgs/happyhumans/possums/8.1.4/20160427165340/bin/possums\n\
+             def  /hab/pkgs/happyhumans/possums/8.1.4/20160427165340/hooks/run\n",
+        ).unwrap();
+        assert_eq!(
+            files,
+            vec![
+                (
+                    "abc".to_string(),
+                    "/hab/pkgs/happyhumans/possums/8.1.4/20160427165340/bin/possums".to_string(),
+                ),
+                (
+                    "def".to_string(),
+                    "/hab/pkgs/happyhumans/possums/8.1.4/20160427165340/hooks/run".to_string(),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn unsigned_files_metafile_is_rejected() {
+        assert!(parse_files_metafile("abc  /hab/pkgs/core/redis/bin/redis\n").is_err());
+    }
+
+    #[test]
+    fn can_parse_periodic_hook_line() {
+        let hook = PeriodicHook::from_str("backup=0 3 * * *").unwrap();
+        assert_eq!(hook.name, "backup");
+        assert_eq!(hook.schedule, "0 3 * * *");
+        assert!(!hook.leader_only);
+    }
+
+    #[test]
+    fn periodic_hook_line_without_schedule_is_malformed() {
+        assert!(PeriodicHook::from_str("backup").is_err());
+        assert!(PeriodicHook::from_str("backup=").is_err());
+    }
+
     #[test]
     #[should_panic]
     fn malformed_file() {
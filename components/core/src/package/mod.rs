@@ -13,15 +13,17 @@
 // limitations under the License.
 
 pub mod archive;
+pub mod bundle;
 pub mod ident;
 pub mod install;
 pub mod metadata;
 pub mod plan;
 pub mod target;
 
-pub use self::archive::{FromArchive, PackageArchive};
+pub use self::archive::{ArchiveFile, FromArchive, PackageArchive};
+pub use self::bundle::PackageBundle;
 pub use self::ident::{Identifiable, PackageIdent};
-pub use self::install::PackageInstall;
+pub use self::install::{FileVerification, PackageInstall};
 pub use self::plan::Plan;
 pub use self::target::{Target, PackageTarget};
 
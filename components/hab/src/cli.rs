@@ -18,9 +18,13 @@ use std::str::FromStr;
 
 use clap::{App, AppSettings, Arg};
 use hcore::crypto::keys::PairType;
+use hcore::package::PackageTarget;
 use regex::Regex;
 use url::Url;
 
+use command::origin::member;
+use command::pkg::search::SortBy;
+
 pub fn get() -> App<'static, 'static> {
     let alias_apply = sub_config_apply()
         .about("Alias for 'config apply'")
@@ -205,7 +209,7 @@ pub fn get() -> App<'static, 'static> {
             )
         )
         (@subcommand origin =>
-            (about: "Commands relating to Habitat origin keys")
+            (about: "Commands relating to Habitat origins")
             (aliases: &["o", "or", "ori", "orig", "origi"])
             (@setting ArgRequiredElseHelp)
             (@subcommand key =>
@@ -242,6 +246,13 @@ pub fn get() -> App<'static, 'static> {
                         contents and writes the key to disk")
                     (aliases: &["i", "im", "imp", "impo", "impor"])
                 )
+                (@subcommand revoke =>
+                    (about: "Adds an origin key to the local revocation list, so that artifacts \
+                        signed with it are no longer trusted")
+                    (aliases: &["r", "re", "rev", "revo", "revok"])
+                    (@arg KEY_NAME: +required +takes_value
+                        "The name with revision of the key to revoke (ex: acme-20170101000000)")
+                )
                 (@subcommand upload =>
                     (@group upload =>
                         (@attributes +required)
@@ -262,6 +273,44 @@ pub fn get() -> App<'static, 'static> {
                     (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
                 )
             )
+            (@subcommand member =>
+                (about: "Commands relating to the members of a Habitat origin")
+                (aliases: &["m", "me", "mem", "memb", "membe"])
+                (@setting ArgRequiredElseHelp)
+                (@subcommand list =>
+                    (about: "Lists the members of an origin and their roles")
+                    (aliases: &["l", "li", "lis"])
+                    (@arg ORIGIN: -o --origin +takes_value
+                        "The origin whose members will be listed. Default is from 'HAB_ORIGIN' or cli.toml")
+                    (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                        "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+                )
+                (@subcommand remove =>
+                    (about: "Removes a member from an origin")
+                    (aliases: &["rm", "rem", "remo", "remov"])
+                    (@arg MEMBER: +required +takes_value "The name of the member")
+                    (@arg ORIGIN: -o --origin +takes_value
+                        "The origin the member belongs to. Default is from 'HAB_ORIGIN' or cli.toml")
+                    (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                        "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+                )
+                (@subcommand role =>
+                    (about: "Sets the role of an origin member. Developers may upload packages, \
+                        maintainers may also promote and demote them, and owners may also manage \
+                        keys, integrations and members")
+                    (aliases: &["ro", "rol"])
+                    (@arg MEMBER: +required +takes_value "The name of the member")
+                    (@arg ROLE: +required +takes_value {valid_member_role}
+                        "The role to grant (developer, maintainer or owner)")
+                    (@arg ORIGIN: -o --origin +takes_value
+                        "The origin the member belongs to. Default is from 'HAB_ORIGIN' or cli.toml")
+                    (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                        "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+                )
+            )
         )
         (@subcommand pkg =>
             (about: "Commands relating to Habitat packages")
@@ -290,10 +339,40 @@ pub fn get() -> App<'static, 'static> {
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
             )
             (subcommand: sub_pkg_build())
+            (@subcommand bundle =>
+                (about: "Gathers a package, its dependencies and their public origin keys into a \
+                    bundle for offline installs")
+                (aliases: &["bun", "bund", "bundl"])
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg OUTPUT: -o --output +takes_value
+                    "Path of the bundle to write (default: <origin>-<name>-<version>-<release>.bundle)")
+                (@arg BLDR_URL: --url -u +takes_value {valid_url}
+                    "Specify an alternate Builder endpoint. If not specified, the value will \
+                         be taken from the HAB_BLDR_URL environment variable if defined. (default: \
+                         https://bldr.habitat.sh)")
+                (@arg CHANNEL: --channel -c +takes_value
+                    "Bundle from the specified release channel (default: stable)")
+                (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+            )
+            (@subcommand dependencies =>
+                (about: "Shows the dependencies of an installed package, or what depends on it")
+                (aliases: &["dep", "deps"])
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg TRANSITIVE: -t --transitive
+                    "Show the whole tree rather than only direct dependencies")
+                (@arg REVERSE: -r --reverse
+                    "Show the installed packages which depend on this one instead")
+                (@arg JSON: -j --json "Output as JSON")
+            )
             (@subcommand env =>
                 (about: "Prints the runtime environment of a specific installed package")
-                (@arg PKG_IDENT: +required +takes_value
+                (@arg PKG_IDENT: required_unless[DIFF] +takes_value
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg DIFF: --diff +takes_value number_of_values(2) conflicts_with[PKG_IDENT]
+                    "Compare the runtime environments, PATH, exports, exposes and binds of two \
+                    installed packages (ex: --diff core/redis/3.2.4 core/redis/3.2.10)")
             )
             (@subcommand exec =>
                 (about: "Executes a command using the 'PATH' context of an installed package")
@@ -327,6 +406,14 @@ pub fn get() -> App<'static, 'static> {
             )
             (subcommand: sub_pkg_install().aliases(
                 &["i", "in", "ins", "inst", "insta", "instal"]))
+            (@subcommand list =>
+                (about: "Lists installed packages")
+                (aliases: &["l", "li", "lis"])
+                (@arg PKG_IDENT: +takes_value
+                    "Only list releases of this package (ex: core/redis, core/redis/3.2.4)")
+                (@arg ORIGIN: -o --origin +takes_value "Only list packages from this origin")
+                (@arg JSON: -j --json "Output as JSON")
+            )
             (@subcommand path =>
                 (about: "Prints the path to a specific installed release of a package")
                 (aliases: &["p", "pa", "pat"])
@@ -345,6 +432,17 @@ pub fn get() -> App<'static, 'static> {
             (@subcommand search =>
                 (about: "Search for a package in Builder")
                 (@arg SEARCH_TERM: +required +takes_value "Search term")
+                (@arg ORIGIN: -o --origin +takes_value "Only show packages from this origin")
+                (@arg CHANNEL: -c --channel +takes_value
+                    "Only show releases in this channel, and the latest release in it")
+                (@arg TARGET: -t --target +takes_value {valid_target}
+                    "Only show releases built for this platform (ex: x86_64-linux)")
+                (@arg SORT: -s --sort +takes_value {valid_search_sort}
+                    "Sort by name, version (newest first) or recent (most recently built first) \
+                    (default: name)")
+                (@arg JSON: -j --json "Output as JSON")
+                (@arg LIMIT: -l --limit +takes_value {valid_search_limit}
+                    "Search at most this many results (default: 250)")
                 (@arg BLDR_URL: -u --url +takes_value {valid_url}
                     "Specify an alternate Builder endpoint. If not specified, the value will \
                          be taken from the HAB_BLDR_URL environment variable if defined. (default: \
@@ -355,6 +453,9 @@ pub fn get() -> App<'static, 'static> {
                 (about: "Signs an archive with an origin key, generating a Habitat Artifact")
                 (aliases: &["s", "si", "sig"])
                 (@arg ORIGIN: --origin +takes_value "Origin key used to create signature")
+                (@arg COUNTERSIGN: --countersign
+                    "Add a signature to an already signed Habitat Artifact given as the source, \
+                    keeping its existing signatures")
                 (@arg SOURCE: +required {file_exists}
                     "A path to a source archive file \
                     (ex: /home/acme-redis-3.0.7-21120102031201.tar.xz)")
@@ -362,6 +463,18 @@ pub fn get() -> App<'static, 'static> {
                     "The destination path to the signed Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
             )
+            (@subcommand uninstall =>
+                (about: "Removes an installed package release, refusing if other installed \
+                    packages or loaded services still need it")
+                (aliases: &["un", "uni", "unin", "unins", "uninst"])
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg DEST_DIR: -d --dest +takes_value
+                    "The directory to remove binlinks from (default: /bin)")
+                (@arg REMOVE_ORPHANS: --("remove-orphans")
+                    "Also remove dependencies no other package or loaded service needs")
+                (@arg DRY_RUN: --("dry-run") "Show what would be removed without removing it")
+            )
             (@subcommand upload =>
                 (about: "Uploads a local Habitat Artifact to Builder")
                 (aliases: &["u", "up", "upl", "uplo", "uploa"])
@@ -418,9 +531,12 @@ pub fn get() -> App<'static, 'static> {
             (@subcommand verify =>
                 (about: "Verifies a Habitat Artifact with an origin key")
                 (aliases: &["v", "ve", "ver", "veri", "verif"])
-                (@arg SOURCE: +required {file_exists}
+                (@arg SOURCE: required_unless[INSTALLED] {file_exists}
                     "A path to a Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+                (@arg INSTALLED: --installed +takes_value conflicts_with[SOURCE]
+                    "Instead, check the files of this installed package against the checksums \
+                    recorded when it was built (ex: core/redis, core/redis/3.2.4/20170514150022)")
             )
             (@subcommand header =>
                 (about: "Returns the Habitat Artifact header")
@@ -706,6 +822,9 @@ fn sub_pkg_install() -> App<'static, 'static> {
         (@arg BINLINK: -b --binlink "Binlink all binaries from installed package(s)")
         (@arg FORCE: -f --force "Overwrite existing binlinks")
         (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+        (@arg OFFLINE: --offline +takes_value +multiple number_of_values(1) {dir_or_file_exists}
+            "Install everything from this directory of artifacts and public origin keys, or \
+            bundle made by 'hab pkg bundle', instead of from Builder. May be given more than once")
     )
 }
 
@@ -717,10 +836,29 @@ fn file_exists(val: String) -> result::Result<(), String> {
     }
 }
 
+fn dir_or_file_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).exists() {
+        Ok(())
+    } else {
+        Err(format!("'{}' cannot be found", &val))
+    }
+}
+
 fn file_exists_or_stdin(val: String) -> result::Result<(), String> {
     if val == "-" { Ok(()) } else { file_exists(val) }
 }
 
+fn valid_member_role(val: String) -> result::Result<(), String> {
+    if member::ROLES.contains(&val.as_str()) {
+        Ok(())
+    } else {
+        Err(format!(
+            "ROLE: {} is invalid, must be one of (developer, maintainer, owner)",
+            &val
+        ))
+    }
+}
+
 fn valid_pair_type(val: String) -> result::Result<(), String> {
     match PairType::from_str(&val) {
         Ok(_) => Ok(()),
@@ -742,6 +880,30 @@ fn valid_service_group(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_search_sort(val: String) -> result::Result<(), String> {
+    match SortBy::from_name(&val) {
+        Some(_) => Ok(()),
+        None => Err(format!(
+            "SORT: {} is invalid, must be one of (name, version, recent)",
+            &val
+        )),
+    }
+}
+
+fn valid_search_limit(val: String) -> result::Result<(), String> {
+    match val.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(()),
+        _ => Err(format!("LIMIT: {} is invalid, must be a positive number", &val)),
+    }
+}
+
+fn valid_target(val: String) -> result::Result<(), String> {
+    match PackageTarget::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("TARGET: '{}' is not a supported platform", &val)),
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
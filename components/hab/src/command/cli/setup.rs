@@ -120,6 +120,10 @@ pub fn start(ui: &mut UI, cache_path: &Path, analytics_path: &Path) -> Result<()
         "If you would like to share your packages on the depot, please enter your GitHub \
                access token. Otherwise, just enter No.",
     )?;
+    ui.para(
+        "Automated systems such as CI servers should instead use a Builder personal access \
+               token, provided through the HAB_AUTH_TOKEN environment variable.",
+    )?;
     ui.para(
         "For more information on sharing packages on the depot, please read the \
           documentation at https://www.habitat.sh/docs/share-packages-overview/",
@@ -16,6 +16,7 @@ pub mod download;
 pub mod export;
 pub mod generate;
 pub mod import;
+pub mod revoke;
 pub mod upload_latest;
 pub mod upload;
 
@@ -0,0 +1,33 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use common::ui::UI;
+use hcore::crypto::revocation;
+
+use error::Result;
+
+pub fn start(ui: &mut UI, key_name: &str, cache: &Path) -> Result<()> {
+    ui.begin(format!("Revoking origin key {}", key_name))?;
+    if revocation::revoke(key_name, cache)? {
+        ui.end(format!(
+            "Revoked origin key {}. Artifacts signed with it will no longer be trusted.",
+            key_name
+        ))?;
+    } else {
+        ui.end(format!("Origin key {} was already revoked.", key_name))?;
+    }
+    Ok(())
+}
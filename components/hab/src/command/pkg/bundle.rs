@@ -0,0 +1,161 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Gathers a package, its transitive dependencies and the public origin keys they are signed with
+//! into a single bundle file, which `hab pkg install --offline` can install from on a host that
+//! can't reach Builder. Nothing is installed: the artifacts are downloaded to the artifact cache
+//! and their dependencies read from the archives, so bundling doesn't need root.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg bundle core/redis -o redis.bundle
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use common::ui::{Status, UI};
+use depot_client::Client;
+use hcore;
+use hcore::crypto::{artifact, SigKeyPair};
+use hcore::crypto::keys::parse_name_with_rev;
+use hcore::fs::{cache_artifact_path, cache_key_path};
+use hcore::package::{Identifiable, PackageArchive, PackageBundle, PackageIdent};
+use hcore::package::metadata::PackageType;
+
+use {PRODUCT, VERSION};
+use error::Result;
+
+pub fn start(
+    ui: &mut UI,
+    url: &str,
+    channel: &str,
+    ident: &PackageIdent,
+    output: Option<&Path>,
+    fs_root_path: &Path,
+    token: Option<&str>,
+) -> Result<()> {
+    let artifact_cache_path = cache_artifact_path(Some(fs_root_path));
+    let key_cache_path = cache_key_path(Some(fs_root_path));
+    let depot_client = Client::new(url, PRODUCT, VERSION, Some(fs_root_path))?;
+    let target: PackageIdent = if ident.fully_qualified() {
+        ident.clone()
+    } else {
+        depot_client.show_package(ident, Some(channel), token)?.into()
+    };
+
+    ui.begin(format!("Bundling {}", &target))?;
+    let path = cached_artifact(ui, &depot_client, &target, &artifact_cache_path, token)?;
+    let mut archive = PackageArchive::new(path);
+    let mut idents = vec![target.clone()];
+    idents.extend(archive.tdeps()?);
+    if let PackageType::Composite = archive.package_type()? {
+        for service in archive.resolved_services()? {
+            let path = cached_artifact(ui, &depot_client, &service, &artifact_cache_path, token)?;
+            idents.extend(PackageArchive::new(path).tdeps()?);
+            idents.push(service);
+        }
+    }
+
+    let mut artifacts: Vec<PathBuf> = vec![];
+    let mut keys: Vec<PathBuf> = vec![];
+    for ident in idents.iter() {
+        let path = cached_artifact(ui, &depot_client, ident, &artifact_cache_path, token)?;
+        if artifacts.contains(&path) {
+            continue;
+        }
+        for key in public_keys(ui, &depot_client, &path, &key_cache_path)? {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+        // Nothing was installed, so nothing has checked the signatures yet.
+        PackageArchive::new(path.clone()).verify(&key_cache_path)?;
+        ui.status(Status::Using, ident)?;
+        artifacts.push(path);
+    }
+
+    let output = match output {
+        Some(output) => output.to_path_buf(),
+        None => PathBuf::from(format!("{}.bundle", target.to_string().replace("/", "-"))),
+    };
+    PackageBundle::create(&output, &artifacts, &keys)?;
+    ui.end(format!(
+        "Bundled {} package(s) and {} public origin key(s) into {}",
+        artifacts.len(),
+        keys.len(),
+        output.display()
+    ))?;
+    Ok(())
+}
+
+/// Returns the path to the identified artifact in the local cache, downloading it first if it
+/// isn't there.
+fn cached_artifact(
+    ui: &mut UI,
+    depot_client: &Client,
+    ident: &PackageIdent,
+    artifact_cache_path: &Path,
+    token: Option<&str>,
+) -> Result<PathBuf> {
+    let name = match ident.archive_name() {
+        Some(name) => name,
+        None => {
+            return Err(
+                hcore::Error::FullyQualifiedPackageIdentRequired(ident.to_string()).into(),
+            )
+        }
+    };
+    let path = artifact_cache_path.join(name);
+    if !path.is_file() {
+        ui.status(Status::Downloading, ident)?;
+        depot_client.fetch_package(
+            ident,
+            token,
+            artifact_cache_path,
+            ui.progress(),
+        )?;
+    }
+    Ok(path)
+}
+
+/// Returns the paths to the public keys which signed an artifact, downloading any that aren't in
+/// the local cache first.
+fn public_keys(
+    ui: &mut UI,
+    depot_client: &Client,
+    artifact_path: &Path,
+    key_cache_path: &Path,
+) -> Result<Vec<PathBuf>> {
+    let mut paths = vec![];
+    for nwr in artifact::artifact_signers(&artifact_path)? {
+        if let Ok(path) = SigKeyPair::get_public_key_path(&nwr, key_cache_path) {
+            paths.push(path);
+            continue;
+        }
+        ui.status(
+            Status::Downloading,
+            format!("{} public origin key", &nwr),
+        )?;
+        let (name, rev) = parse_name_with_rev(&nwr)?;
+        depot_client.fetch_origin_key(
+            &name,
+            &rev,
+            key_cache_path,
+            ui.progress(),
+        )?;
+        paths.push(SigKeyPair::get_public_key_path(&nwr, key_cache_path)?);
+    }
+    Ok(paths)
+}
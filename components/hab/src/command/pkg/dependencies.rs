@@ -0,0 +1,225 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shows what an installed package depends on, or what depends on it, as read from the `DEPS`
+//! metafile of each installed release.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use hcore::package::{PackageIdent, PackageInstall};
+use serde_json;
+
+use error::Result;
+
+/// One package in a dependency tree. Only one of `dependencies` and `dependents` is ever filled
+/// in, depending on which way the tree goes.
+#[derive(Debug, PartialEq, Serialize)]
+struct Node {
+    ident: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dependencies: Option<Vec<Node>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dependents: Option<Vec<Node>>,
+}
+
+impl Node {
+    fn children(&self) -> &[Node] {
+        self.dependencies
+            .as_ref()
+            .or(self.dependents.as_ref())
+            .map_or(&[][..], |c| c.as_slice())
+    }
+}
+
+pub fn start(
+    ident: &PackageIdent,
+    fs_root_path: &Path,
+    transitive: bool,
+    reverse: bool,
+    json: bool,
+) -> Result<()> {
+    let pkg_install = PackageInstall::load(ident, Some(fs_root_path))?;
+    let deps = installed_deps(fs_root_path)?;
+    let edges = if reverse { reverse_edges(&deps) } else { deps };
+    let tree = build_tree(pkg_install.ident(), &edges, transitive, reverse, &mut vec![]);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&tree).unwrap());
+    } else {
+        println!("{}", tree.ident);
+        print_children(&tree, "");
+    }
+    Ok(())
+}
+
+/// Returns the direct dependencies of every installed release.
+fn installed_deps(fs_root_path: &Path) -> Result<HashMap<PackageIdent, Vec<PackageIdent>>> {
+    let mut deps = HashMap::new();
+    for ident in PackageInstall::all(Some(fs_root_path))? {
+        let pkg_install = PackageInstall::load(&ident, Some(fs_root_path))?;
+        deps.insert(ident, pkg_install.deps()?);
+    }
+    Ok(deps)
+}
+
+/// Turns a map of packages to their dependencies into a map of packages to their dependents.
+fn reverse_edges(
+    deps: &HashMap<PackageIdent, Vec<PackageIdent>>,
+) -> HashMap<PackageIdent, Vec<PackageIdent>> {
+    let mut dependents: HashMap<PackageIdent, Vec<PackageIdent>> = HashMap::new();
+    for (ident, ident_deps) in deps {
+        for dep in ident_deps {
+            dependents.entry(dep.clone()).or_insert(vec![]).push(
+                ident.clone(),
+            );
+        }
+    }
+    dependents
+}
+
+fn build_tree(
+    ident: &PackageIdent,
+    edges: &HashMap<PackageIdent, Vec<PackageIdent>>,
+    transitive: bool,
+    reverse: bool,
+    path: &mut Vec<PackageIdent>,
+) -> Node {
+    let mut children = vec![];
+    // Dependencies can't be circular, but don't loop forever if a broken install says they are.
+    if path.is_empty() || (transitive && !path.contains(ident)) {
+        let mut next = edges.get(ident).cloned().unwrap_or(vec![]);
+        next.sort_by(|a, b| a.to_string().cmp(&b.to_string()));
+        path.push(ident.clone());
+        for child in next {
+            children.push(build_tree(&child, edges, transitive, reverse, path));
+        }
+        path.pop();
+    }
+    let (dependencies, dependents) = if reverse {
+        (None, Some(children))
+    } else {
+        (Some(children), None)
+    };
+    Node {
+        ident: ident.to_string(),
+        dependencies: dependencies,
+        dependents: dependents,
+    }
+}
+
+fn print_children(node: &Node, prefix: &str) {
+    let children = node.children();
+    for (i, child) in children.iter().enumerate() {
+        let last = i == children.len() - 1;
+        println!(
+            "{}{}{}",
+            prefix,
+            if last { "└── " } else { "├── " },
+            child.ident
+        );
+        print_children(
+            child,
+            &format!("{}{}", prefix, if last { "    " } else { "│   " }),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use hcore::package::PackageIdent;
+
+    use super::{build_tree, reverse_edges, Node};
+
+    fn ident(ident: &str) -> PackageIdent {
+        ident.parse().unwrap()
+    }
+
+    fn leaf(ident: &str, reverse: bool) -> Node {
+        Node {
+            ident: ident.to_string(),
+            dependencies: if reverse { None } else { Some(vec![]) },
+            dependents: if reverse { Some(vec![]) } else { None },
+        }
+    }
+
+    fn fake_deps() -> HashMap<PackageIdent, Vec<PackageIdent>> {
+        let glibc = ident("core/glibc/2.22/20170513201042");
+        let openssl = ident("core/openssl/1.0.2l/20171014213633");
+        let nginx = ident("core/nginx/1.11.10/20170513215034");
+        let mut deps = HashMap::new();
+        deps.insert(glibc.clone(), vec![]);
+        deps.insert(openssl.clone(), vec![glibc.clone()]);
+        deps.insert(nginx, vec![openssl, glibc]);
+        deps
+    }
+
+    #[test]
+    fn direct_dependencies_are_one_level_deep() {
+        let deps = fake_deps();
+        let tree = build_tree(
+            &ident("core/nginx/1.11.10/20170513215034"),
+            &deps,
+            false,
+            false,
+            &mut vec![],
+        );
+        assert_eq!(
+            tree.dependencies.unwrap(),
+            vec![
+                leaf("core/glibc/2.22/20170513201042", false),
+                leaf("core/openssl/1.0.2l/20171014213633", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn transitive_dependencies_are_a_tree() {
+        let deps = fake_deps();
+        let tree = build_tree(
+            &ident("core/nginx/1.11.10/20170513215034"),
+            &deps,
+            true,
+            false,
+            &mut vec![],
+        );
+        let openssl = &tree.dependencies.as_ref().unwrap()[1];
+        assert_eq!(openssl.ident, "core/openssl/1.0.2l/20171014213633");
+        assert_eq!(
+            openssl.dependencies,
+            Some(vec![leaf("core/glibc/2.22/20170513201042", false)])
+        );
+    }
+
+    #[test]
+    fn reverse_dependencies_list_dependents() {
+        let dependents = reverse_edges(&fake_deps());
+        let tree = build_tree(
+            &ident("core/glibc/2.22/20170513201042"),
+            &dependents,
+            true,
+            true,
+            &mut vec![],
+        );
+        assert!(tree.dependencies.is_none());
+        let direct = tree.dependents.unwrap();
+        assert_eq!(direct[0], leaf("core/nginx/1.11.10/20170513215034", true));
+        assert_eq!(direct[1].ident, "core/openssl/1.0.2l/20171014213633");
+        assert_eq!(
+            direct[1].dependents,
+            Some(vec![leaf("core/nginx/1.11.10/20170513215034", true)])
+        );
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 use hcore::package::{PackageIdent, PackageInstall};
@@ -27,3 +28,173 @@ pub fn start(ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
     }
     Ok(())
 }
+
+/// Prints what differs between the runtime environments, `PATH` entries, exports, exposed ports
+/// and binds of two installed releases.
+pub fn diff(a: &PackageIdent, b: &PackageIdent, fs_root_path: &Path) -> Result<()> {
+    let a_install = PackageInstall::load(a, Some(fs_root_path))?;
+    let b_install = PackageInstall::load(b, Some(fs_root_path))?;
+    let a_env = Environment::load(&a_install)?;
+    let b_env = Environment::load(&b_install)?;
+
+    println!("--- {}", a_install.ident());
+    println!("+++ {}", b_install.ident());
+    let sections = a_env.diff(&b_env);
+    if sections.is_empty() {
+        println!("No differences");
+    }
+    for (title, changes) in sections {
+        println!("");
+        println!("{}:", title);
+        for change in changes {
+            println!("{}", change);
+        }
+    }
+    Ok(())
+}
+
+/// The parts of an installed release which make up the environment it runs in.
+#[derive(Debug, Default)]
+struct Environment {
+    runtime: BTreeMap<String, String>,
+    path: Vec<String>,
+    exports: BTreeMap<String, String>,
+    exposes: Vec<String>,
+    binds: Vec<String>,
+}
+
+impl Environment {
+    fn load(pkg_install: &PackageInstall) -> Result<Self> {
+        let mut runtime = sorted(pkg_install.runtime_environment()?);
+        // PATH is compared entry by entry on its own.
+        runtime.remove("PATH");
+        let mut binds: Vec<String> = pkg_install
+            .binds()?
+            .iter()
+            .map(|b| b.to_string())
+            .collect();
+        binds.extend(pkg_install.binds_optional()?.iter().map(
+            |b| format!("{} (optional)", b),
+        ));
+        Ok(Environment {
+            runtime: runtime,
+            path: pkg_install
+                .paths()?
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect(),
+            exports: sorted(pkg_install.exports()?),
+            exposes: pkg_install.exposes()?,
+            binds: binds,
+        })
+    }
+
+    /// Returns a titled list of `-` and `+` lines for every section which changed.
+    fn diff(&self, other: &Environment) -> Vec<(&'static str, Vec<String>)> {
+        let sections = vec![
+            ("Runtime environment", diff_maps(&self.runtime, &other.runtime)),
+            ("PATH", diff_lists(&self.path, &other.path)),
+            ("Exports", diff_maps(&self.exports, &other.exports)),
+            ("Exposes", diff_lists(&self.exposes, &other.exposes)),
+            ("Binds", diff_lists(&self.binds, &other.binds)),
+        ];
+        sections
+            .into_iter()
+            .filter(|&(_, ref changes)| !changes.is_empty())
+            .collect()
+    }
+}
+
+fn sorted(map: HashMap<String, String>) -> BTreeMap<String, String> {
+    map.into_iter().collect()
+}
+
+fn diff_maps(a: &BTreeMap<String, String>, b: &BTreeMap<String, String>) -> Vec<String> {
+    let mut changes = Vec::new();
+    for (key, value) in a.iter() {
+        match b.get(key) {
+            Some(other) if other == value => {}
+            Some(other) => {
+                changes.push(format!("- {}=\"{}\"", key, value));
+                changes.push(format!("+ {}=\"{}\"", key, other));
+            }
+            None => changes.push(format!("- {}=\"{}\"", key, value)),
+        }
+    }
+    for (key, value) in b.iter() {
+        if !a.contains_key(key) {
+            changes.push(format!("+ {}=\"{}\"", key, value));
+        }
+    }
+    changes
+}
+
+fn diff_lists(a: &[String], b: &[String]) -> Vec<String> {
+    let removed = a.iter().filter(|x| !b.contains(x)).map(
+        |x| format!("- {}", x),
+    );
+    let added = b.iter().filter(|x| !a.contains(x)).map(
+        |x| format!("+ {}", x),
+    );
+    removed.chain(added).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use super::{diff_lists, diff_maps, Environment};
+
+    fn map(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|&(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn list(items: &[&str]) -> Vec<String> {
+        items.iter().map(|i| i.to_string()).collect()
+    }
+
+    #[test]
+    fn maps_show_changed_removed_and_added_keys() {
+        let a = map(&[("LANG", "C"), ("SSL_CERT_FILE", "/a/cert.pem"), ("OLD", "1")]);
+        let b = map(&[("LANG", "C"), ("SSL_CERT_FILE", "/b/cert.pem"), ("NEW", "2")]);
+        assert_eq!(
+            diff_maps(&a, &b),
+            vec![
+                "- OLD=\"1\"",
+                "- SSL_CERT_FILE=\"/a/cert.pem\"",
+                "+ SSL_CERT_FILE=\"/b/cert.pem\"",
+                "+ NEW=\"2\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn lists_show_removed_then_added_entries() {
+        let a = list(&["/hab/pkgs/core/redis/3.2.4/20170514150022/bin", "/usr/bin"]);
+        let b = list(&["/hab/pkgs/core/redis/3.2.10/20170810120000/bin", "/usr/bin"]);
+        assert_eq!(
+            diff_lists(&a, &b),
+            vec![
+                "- /hab/pkgs/core/redis/3.2.4/20170514150022/bin",
+                "+ /hab/pkgs/core/redis/3.2.10/20170810120000/bin",
+            ]
+        );
+    }
+
+    #[test]
+    fn only_changed_sections_are_reported() {
+        let a = Environment {
+            exposes: list(&["6379"]),
+            ..Default::default()
+        };
+        let b = Environment {
+            exposes: list(&["6379", "16379"]),
+            ..Default::default()
+        };
+        assert_eq!(a.diff(&b), vec![("Exposes", list(&["+ 16379"]))]);
+        assert!(a.diff(&a).is_empty());
+    }
+}
@@ -36,24 +36,26 @@ pub fn start(ui: &mut UI, src: &Path) -> Result<()> {
                 header.format_version
             ).as_bytes(),
         )?;
-        io::stdout().write(
-            format!(
-                "Key Name       : {}\n",
-                header.key_name
-            ).as_bytes(),
-        )?;
         io::stdout().write(
             format!(
                 "Hash Type      : {}\n",
                 header.hash_type
             ).as_bytes(),
         )?;
-        io::stdout().write(
-            format!(
-                "Raw Signature  : {}\n",
-                header.signature_raw
-            ).as_bytes(),
-        )?;
+        for signature in header.signatures.iter() {
+            io::stdout().write(
+                format!(
+                    "Key Name       : {}\n",
+                    signature.key_name
+                ).as_bytes(),
+            )?;
+            io::stdout().write(
+                format!(
+                    "Raw Signature  : {}\n",
+                    signature.signature_raw
+                ).as_bytes(),
+            )?;
+        }
     } else {
         ui.warn("Failed to read package header.")?;
     }
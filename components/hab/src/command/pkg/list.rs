@@ -0,0 +1,114 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use hcore::package::{Identifiable, PackageIdent, PackageInstall};
+use serde_json;
+
+use error::Result;
+
+pub fn start(
+    ident: Option<&PackageIdent>,
+    origin: Option<&str>,
+    fs_root_path: &Path,
+    json: bool,
+) -> Result<()> {
+    let installed = installed(ident, origin, fs_root_path)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&installed).unwrap());
+    } else {
+        for ident in installed {
+            println!("{}", ident);
+        }
+    }
+    Ok(())
+}
+
+/// Returns every installed release which matches the given ident and origin, sorted by origin and
+/// name and then from oldest to newest.
+pub fn installed(
+    ident: Option<&PackageIdent>,
+    origin: Option<&str>,
+    fs_root_path: &Path,
+) -> Result<Vec<PackageIdent>> {
+    let mut installed: Vec<PackageIdent> = PackageInstall::all(Some(fs_root_path))?
+        .into_iter()
+        .filter(|p| ident.map_or(true, |i| p.satisfies(i)))
+        .filter(|p| origin.map_or(true, |o| p.origin == o))
+        .collect();
+    installed.sort_by(|a, b| {
+        (&a.origin, &a.name).cmp(&(&b.origin, &b.name)).then(a.cmp(b))
+    });
+    Ok(installed)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::Path;
+
+    use hcore;
+    use hcore::package::PackageIdent;
+    use tempdir::TempDir;
+
+    use super::installed;
+
+    fn fake_installs(rootfs: &Path, idents: &[&str]) {
+        for ident in idents {
+            let ident: PackageIdent = ident.parse().unwrap();
+            fs::create_dir_all(hcore::fs::pkg_install_path(&ident, Some(rootfs))).unwrap();
+        }
+    }
+
+    fn strings(idents: Vec<PackageIdent>) -> Vec<String> {
+        idents.iter().map(|i| i.to_string()).collect()
+    }
+
+    #[test]
+    fn installed_is_sorted_and_filtered() {
+        let rootfs = TempDir::new("rootfs").unwrap();
+        fake_installs(
+            rootfs.path(),
+            &[
+                "core/redis/3.2.10/20170810120000",
+                "core/redis/3.2.4/20170514150022",
+                "acme/redis/1.0.0/20170101000000",
+                "core/glibc/2.22/20170513201042",
+            ],
+        );
+
+        assert_eq!(
+            strings(installed(None, None, rootfs.path()).unwrap()),
+            vec![
+                "acme/redis/1.0.0/20170101000000",
+                "core/glibc/2.22/20170513201042",
+                "core/redis/3.2.4/20170514150022",
+                "core/redis/3.2.10/20170810120000",
+            ]
+        );
+        assert_eq!(
+            strings(installed(None, Some("acme"), rootfs.path()).unwrap()),
+            vec!["acme/redis/1.0.0/20170101000000"]
+        );
+        let redis: PackageIdent = "core/redis".parse().unwrap();
+        assert_eq!(
+            strings(installed(Some(&redis), None, rootfs.path()).unwrap()),
+            vec![
+                "core/redis/3.2.4/20170514150022",
+                "core/redis/3.2.10/20170810120000",
+            ]
+        );
+    }
+}
@@ -14,17 +14,21 @@
 
 pub mod binlink;
 pub mod build;
+pub mod bundle;
 pub mod channels;
 pub mod demote;
+pub mod dependencies;
 pub mod env;
 pub mod exec;
 pub mod export;
 pub mod hash;
 pub mod header;
+pub mod list;
 pub mod path;
 pub mod promote;
 pub mod provides;
 pub mod search;
 pub mod sign;
+pub mod uninstall;
 pub mod upload;
 pub mod verify;
@@ -12,30 +12,292 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use hcore::package::{PackageIdent, PackageTarget};
+use hcore::package::ident::version_sort;
+use hyper::status::StatusCode;
+use serde_json;
+
+use depot_client::{self, Client};
 use error::Result;
-use depot_client::Client;
 use {PRODUCT, VERSION};
 
-pub fn start(st: &str, url: &str, token: Option<&str>) -> Result<()> {
+/// How many search results are fetched from Builder unless asked for more. Builder returns them
+/// 50 to a page.
+pub const DEFAULT_LIMIT: usize = 250;
+
+/// How search results are ordered.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SortBy {
+    /// Alphabetically by origin and name.
+    Name,
+    /// Newest version first.
+    Version,
+    /// Most recently built first.
+    Recent,
+}
+
+impl SortBy {
+    pub fn from_name(value: &str) -> Option<SortBy> {
+        match value {
+            "name" => Some(SortBy::Name),
+            "version" => Some(SortBy::Version),
+            "recent" => Some(SortBy::Recent),
+            _ => None,
+        }
+    }
+}
+
+pub fn start(
+    st: &str,
+    url: &str,
+    origin: Option<&str>,
+    channel: Option<&str>,
+    target: Option<&PackageTarget>,
+    sort: SortBy,
+    json: bool,
+    limit: usize,
+    token: Option<&str>,
+) -> Result<()> {
     let depot_client = Client::new(url, PRODUCT, VERSION, None)?;
-    let (packages, more) = depot_client.search_package(st, token)?;
-    match packages.len() {
-        0 => println!("No packages found that match '{}'", st),
-        _ => {
-            for p in &packages {
-                if let (&Some(ref version), &Some(ref release)) = (&p.version, &p.release) {
-                    println!("{}/{}/{}/{}", p.origin, p.name, version, release);
-                } else {
-                    println!("{}/{}", p.origin, p.name);
-                }
+    // Builder treats a term of the form `origin/name` as a search within that origin.
+    let term = match origin {
+        Some(origin) => format!("{}/{}", origin, st),
+        None => st.to_string(),
+    };
+    let (found, truncated) = fetch_results(
+        limit,
+        |range| Ok(depot_client.search_package(&term, range, token)?),
+    )?;
+    let mut packages = latest(found, origin);
+    if channel.is_some() || target.is_some() {
+        packages = resolve(&depot_client, packages, channel, target, token)?;
+    }
+    sort_idents(&mut packages, sort);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&packages).unwrap());
+    } else if packages.is_empty() {
+        println!("No packages found that match '{}'", st);
+    } else {
+        for p in &packages {
+            println!("{}", p);
+        }
+    }
+    if truncated && !json {
+        println!(
+            "Only the first {} results were searched; use --limit to search more",
+            limit
+        );
+    }
+    Ok(())
+}
+
+/// Fetch pages of search results with `fetch_page`, starting from the first one, until there are
+/// no more or `limit` results have been fetched. Returns the results, and whether there were more
+/// than `limit` of them.
+fn fetch_results<F>(limit: usize, mut fetch_page: F) -> Result<(Vec<PackageIdent>, bool)>
+where
+    F: FnMut(usize) -> Result<(Vec<PackageIdent>, Option<usize>)>,
+{
+    let mut found = Vec::new();
+    let mut range = 0;
+    loop {
+        let (packages, next) = fetch_page(range)?;
+        found.extend(packages);
+        match next {
+            Some(next) if found.len() < limit => range = next,
+            next => {
+                let truncated = next.is_some() || found.len() > limit;
+                found.truncate(limit);
+                return Ok((found, truncated));
             }
-            if more {
-                println!(
-                    "Search returned too many items, only showing the first {}",
-                    packages.len()
-                );
+        }
+    }
+}
+
+/// Keep only the newest release of each package, dropping any from other origins than `origin`.
+pub fn latest(idents: Vec<PackageIdent>, origin: Option<&str>) -> Vec<PackageIdent> {
+    let mut newest: BTreeMap<(String, String), PackageIdent> = BTreeMap::new();
+    for ident in idents {
+        if origin.map_or(false, |o| ident.origin != o) {
+            continue;
+        }
+        let key = (ident.origin.clone(), ident.name.clone());
+        let replace = match newest.get(&key) {
+            Some(current) => ident > *current,
+            None => true,
+        };
+        if replace {
+            newest.insert(key, ident);
+        }
+    }
+    newest.into_iter().map(|(_, ident)| ident).collect()
+}
+
+/// Replace each package with its latest release in `channel` and for `target`, dropping those
+/// with no such release.
+fn resolve(
+    depot_client: &Client,
+    packages: Vec<PackageIdent>,
+    channel: Option<&str>,
+    target: Option<&PackageTarget>,
+    token: Option<&str>,
+) -> Result<Vec<PackageIdent>> {
+    let mut resolved = Vec::with_capacity(packages.len());
+    for ident in packages {
+        let name = PackageIdent::new(ident.origin, ident.name, None, None);
+        let result = match target {
+            Some(target) => depot_client.show_package_for_target(&name, channel, target, token),
+            None => depot_client.show_package(&name, channel, token),
+        };
+        match result {
+            Ok(pkg) => resolved.push(pkg.get_ident().clone().into()),
+            Err(depot_client::Error::APIError(StatusCode::NotFound, _)) => {
+                debug!("No release of {} matches the channel and target", name)
             }
+            Err(e) => return Err(e.into()),
         }
     }
-    Ok(())
+    Ok(resolved)
+}
+
+/// Order packages as asked, falling back to origin and name for ties.
+pub fn sort_idents(idents: &mut Vec<PackageIdent>, sort: SortBy) {
+    idents.sort_by(|a, b| {
+        let by_name = (&a.origin, &a.name).cmp(&(&b.origin, &b.name));
+        let ordering = match sort {
+            SortBy::Name => Ordering::Equal,
+            SortBy::Version => compare_versions(b, a),
+            SortBy::Recent => b.release.cmp(&a.release),
+        };
+        ordering.then(by_name)
+    });
+}
+
+fn compare_versions(a: &PackageIdent, b: &PackageIdent) -> Ordering {
+    match (&a.version, &b.version) {
+        (&Some(ref a), &Some(ref b)) => version_sort(a, b).unwrap_or(Ordering::Equal),
+        (a, b) => a.cmp(b),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cmp;
+
+    use hcore::package::PackageIdent;
+
+    use super::{fetch_results, latest, sort_idents, SortBy};
+
+    fn idents(idents: &[&str]) -> Vec<PackageIdent> {
+        idents.iter().map(|i| i.parse().unwrap()).collect()
+    }
+
+    fn strings(idents: Vec<PackageIdent>) -> Vec<String> {
+        idents.iter().map(|i| i.to_string()).collect()
+    }
+
+    #[test]
+    fn latest_keeps_the_newest_release_of_each_package() {
+        let found = idents(
+            &[
+                "core/redis/3.2.4/20170514150022",
+                "core/redis/3.2.10/20170810120000",
+                "acme/redis/1.0.0/20170101000000",
+                "core/openssl/1.0.2l/20170513215923",
+            ],
+        );
+        assert_eq!(
+            strings(latest(found.clone(), None)),
+            vec![
+                "acme/redis/1.0.0/20170101000000",
+                "core/openssl/1.0.2l/20170513215923",
+                "core/redis/3.2.10/20170810120000",
+            ]
+        );
+        assert_eq!(
+            strings(latest(found, Some("acme"))),
+            vec!["acme/redis/1.0.0/20170101000000"]
+        );
+    }
+
+    #[test]
+    fn sort_by_version_and_recency() {
+        let mut found = idents(
+            &[
+                "core/openssl/1.0.2l/20170513215923",
+                "core/redis/3.2.10/20170810120000",
+                "acme/redis/3.2.9/20170901000000",
+            ],
+        );
+        sort_idents(&mut found, SortBy::Version);
+        assert_eq!(
+            strings(found.clone()),
+            vec![
+                "core/redis/3.2.10/20170810120000",
+                "acme/redis/3.2.9/20170901000000",
+                "core/openssl/1.0.2l/20170513215923",
+            ]
+        );
+        sort_idents(&mut found, SortBy::Recent);
+        assert_eq!(
+            strings(found.clone()),
+            vec![
+                "acme/redis/3.2.9/20170901000000",
+                "core/redis/3.2.10/20170810120000",
+                "core/openssl/1.0.2l/20170513215923",
+            ]
+        );
+        sort_idents(&mut found, SortBy::Name);
+        assert_eq!(
+            strings(found),
+            vec![
+                "acme/redis/3.2.9/20170901000000",
+                "core/openssl/1.0.2l/20170513215923",
+                "core/redis/3.2.10/20170810120000",
+            ]
+        );
+    }
+
+    fn packages(total: usize) -> Vec<PackageIdent> {
+        (0..total)
+            .map(|n| format!("core/pkg{}/1.0.0/20170101000000", n).parse().unwrap())
+            .collect()
+    }
+
+    // Serves the page starting at `range` the way Builder does, 2 results at a time.
+    fn page(all: &[PackageIdent], range: usize) -> (Vec<PackageIdent>, Option<usize>) {
+        let end = cmp::min(range + 2, all.len());
+        let next = if end < all.len() { Some(end) } else { None };
+        (all[range..end].to_vec(), next)
+    }
+
+    #[test]
+    fn fetch_results_follows_pages_up_to_the_limit() {
+        let all = packages(7);
+        let mut requested = vec![];
+        let (found, truncated) = fetch_results(4, |range| {
+            requested.push(range);
+            Ok(page(&all, range))
+        }).unwrap();
+        assert_eq!(requested, vec![0, 2]);
+        assert_eq!(found, all[..4].to_vec());
+        assert!(truncated);
+    }
+
+    #[test]
+    fn fetch_results_stops_at_the_last_page() {
+        let all = packages(5);
+        let mut requested = vec![];
+        let (found, truncated) = fetch_results(10, |range| {
+            requested.push(range);
+            Ok(page(&all, range))
+        }).unwrap();
+        assert_eq!(requested, vec![0, 2, 4]);
+        assert_eq!(found, all);
+        assert!(!truncated);
+    }
 }
@@ -34,3 +34,19 @@ pub fn start(ui: &mut UI, origin: &SigKeyPair, src: &Path, dst: &Path) -> Result
     ui.end(format!("Signed artifact {}.", dst.display()))?;
     Ok(())
 }
+
+pub fn countersign(ui: &mut UI, origin: &SigKeyPair, src: &Path, dst: &Path) -> Result<()> {
+    ui.begin(format!("Countersigning {}", src.display()))?;
+    ui.status(
+        Status::Signing,
+        format!(
+            "{} with {} to create {}",
+            src.display(),
+            &origin.name_with_rev(),
+            dst.display()
+        ),
+    )?;
+    artifact::countersign(src, dst, origin)?;
+    ui.end(format!("Countersigned artifact {}.", dst.display()))?;
+    Ok(())
+}
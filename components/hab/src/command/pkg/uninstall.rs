@@ -0,0 +1,387 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Removes an installed package release.
+//!
+//! A release is only removed if no other installed package depends on it, and no service loaded
+//! by a Supervisor, whether running or stopped, runs from it or depends on it. Its dependencies
+//! can optionally be removed too, once nothing else needs them.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use common::ui::{Status, UI};
+use hcore::fs as hfs;
+use hcore::package::{PackageIdent, PackageInstall};
+use serde_json::{self, Value as Json};
+use toml;
+
+use error::{Error, Result};
+
+/// The state directory of every Supervisor on the host, relative to the filesystem root.
+const SUP_STATE_ROOT: &'static str = "hab/sup";
+
+pub fn start(
+    ui: &mut UI,
+    ident: &PackageIdent,
+    fs_root_path: &Path,
+    binlink_dir: &Path,
+    remove_orphans: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let pkg_install = PackageInstall::load(ident, Some(fs_root_path))?;
+    let target = pkg_install.ident().clone();
+    ui.begin(format!(
+        "{}Uninstalling {}",
+        if dry_run { "[Dry run] " } else { "" },
+        &target
+    ))?;
+
+    let tdeps = installed_tdeps(fs_root_path)?;
+    let in_use = loaded_service_packages(fs_root_path);
+    if in_use.contains(&target) {
+        return Err(Error::PackageInUse(target.to_string()));
+    }
+    let dependents = dependents_of(&target, &tdeps);
+    if !dependents.is_empty() {
+        return Err(Error::PackageHasDependents(
+            target.to_string(),
+            dependents.iter().map(|d| d.to_string()).collect(),
+        ));
+    }
+
+    let binlinks = binlinks(fs_root_path, binlink_dir);
+    let mut removals = vec![target.clone()];
+    if remove_orphans {
+        // Binlinked packages were most likely installed on purpose, so they are never orphans.
+        let mut keep = in_use.clone();
+        keep.extend(binlinks.iter().map(|&(_, ref ident)| ident.clone()));
+        removals.extend(orphans(&target, &tdeps, &keep));
+    }
+
+    for removal in removals.iter() {
+        let install_path = hfs::pkg_install_path(removal, Some(fs_root_path));
+        for &(ref link, _) in binlinks.iter().filter(|&&(_, ref i)| i == removal) {
+            ui.status(Status::Deleting, format!("binlink {}", link.display()))?;
+            if !dry_run {
+                fs::remove_file(link)?;
+            }
+        }
+        ui.status(Status::Deleting, format!("{}", removal))?;
+        if !dry_run {
+            fs::remove_dir_all(&install_path)?;
+            remove_empty_parents(&install_path, &hfs::pkg_root_path(Some(fs_root_path)));
+        }
+    }
+    ui.end(format!(
+        "{} {} package(s)",
+        if dry_run { "Would uninstall" } else { "Uninstalled" },
+        removals.len()
+    ))?;
+    Ok(())
+}
+
+/// Returns the transitive dependencies of every installed release.
+fn installed_tdeps(fs_root_path: &Path) -> Result<HashMap<PackageIdent, Vec<PackageIdent>>> {
+    let mut tdeps = HashMap::new();
+    for ident in PackageInstall::all(Some(fs_root_path))? {
+        let pkg_install = PackageInstall::load(&ident, Some(fs_root_path))?;
+        tdeps.insert(ident, pkg_install.tdeps()?);
+    }
+    Ok(tdeps)
+}
+
+/// Returns the installed releases which depend on the given one, directly or transitively.
+fn dependents_of(
+    ident: &PackageIdent,
+    tdeps: &HashMap<PackageIdent, Vec<PackageIdent>>,
+) -> Vec<PackageIdent> {
+    let mut dependents: Vec<PackageIdent> = tdeps
+        .iter()
+        .filter(|&(_, deps)| deps.contains(ident))
+        .map(|(dependent, _)| dependent.clone())
+        .collect();
+    dependents.sort();
+    dependents
+}
+
+/// Returns the dependencies of `target` which nothing else would need once it was removed,
+/// leaving out anything in `keep`.
+fn orphans(
+    target: &PackageIdent,
+    tdeps: &HashMap<PackageIdent, Vec<PackageIdent>>,
+    keep: &HashSet<PackageIdent>,
+) -> Vec<PackageIdent> {
+    let mut removed: HashSet<PackageIdent> = HashSet::new();
+    removed.insert(target.clone());
+    let candidates = tdeps.get(target).cloned().unwrap_or(vec![]);
+    let mut orphans = vec![];
+    // A dependency may only become an orphan once the other candidates which need it have been
+    // found to be orphans themselves, so keep going until nothing changes.
+    loop {
+        let found: Vec<PackageIdent> = candidates
+            .iter()
+            .filter(|dep| !removed.contains(*dep) && !keep.contains(*dep))
+            .filter(|dep| {
+                tdeps.contains_key(*dep) &&
+                    dependents_of(dep, tdeps).iter().all(|d| removed.contains(d))
+            })
+            .cloned()
+            .collect();
+        if found.is_empty() {
+            break;
+        }
+        for dep in found {
+            removed.insert(dep.clone());
+            orphans.push(dep);
+        }
+    }
+    orphans
+}
+
+/// Returns every release a service loaded by a Supervisor on this host runs from or depends on,
+/// as recorded in each Supervisor's services state file. Stopped services are only in there
+/// while they run, so the release each service spec would start from is included too.
+fn loaded_service_packages(fs_root_path: &Path) -> HashSet<PackageIdent> {
+    let mut idents = HashSet::new();
+    let sup_dirs = match fs::read_dir(fs_root_path.join(SUP_STATE_ROOT)) {
+        Ok(dirs) => dirs,
+        Err(_) => return idents,
+    };
+    for sup_dir in sup_dirs.filter_map(|d| d.ok()) {
+        for ident in spec_idents(&sup_dir.path().join("specs")) {
+            if let Ok(pkg_install) = PackageInstall::load(&ident, Some(fs_root_path)) {
+                idents.extend(pkg_install.tdeps().unwrap_or(vec![]));
+                idents.insert(pkg_install.ident().clone());
+            }
+        }
+        let services_path = sup_dir.path().join("data").join("services.dat");
+        let services: Json = match File::open(&services_path) {
+            Ok(file) => {
+                match serde_json::from_reader(file) {
+                    Ok(services) => services,
+                    Err(_) => continue,
+                }
+            }
+            Err(_) => continue,
+        };
+        for service in services.as_array().unwrap_or(&vec![]) {
+            let pkg = &service["pkg"];
+            let deps = pkg["deps"].as_array().cloned().unwrap_or(vec![]);
+            for ident in Some(&pkg["ident"]).into_iter().chain(deps.iter()) {
+                if let Some(ident) = ident_from_json(ident) {
+                    idents.insert(ident);
+                }
+            }
+        }
+    }
+    idents
+}
+
+/// Returns the package idents of the service specs in a Supervisor's specs directory. They may not
+/// be fully qualified.
+fn spec_idents(specs_path: &Path) -> Vec<PackageIdent> {
+    let mut idents = vec![];
+    let entries = match fs::read_dir(specs_path) {
+        Ok(entries) => entries,
+        Err(_) => return idents,
+    };
+    for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+        if path.extension().and_then(|e| e.to_str()) != Some("spec") {
+            continue;
+        }
+        let mut body = String::new();
+        if File::open(&path)
+            .and_then(|mut f| f.read_to_string(&mut body))
+            .is_err()
+        {
+            continue;
+        }
+        let spec: toml::Value = match body.parse() {
+            Ok(spec) => spec,
+            Err(_) => continue,
+        };
+        if let Some(ident) = spec.get("ident").and_then(|i| i.as_str()) {
+            if let Ok(ident) = ident.parse() {
+                idents.push(ident);
+            }
+        }
+    }
+    idents
+}
+
+/// A service's own ident is written as a string, but its dependencies are written as structs.
+fn ident_from_json(value: &Json) -> Option<PackageIdent> {
+    if let Some(ident) = value.as_str() {
+        return ident.parse().ok();
+    }
+    match (value["origin"].as_str(), value["name"].as_str()) {
+        (Some(origin), Some(name)) => {
+            Some(PackageIdent::new(
+                origin,
+                name,
+                value["version"].as_str(),
+                value["release"].as_str(),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Returns every binlink in `binlink_dir`, along with the release it points into.
+fn binlinks(fs_root_path: &Path, binlink_dir: &Path) -> Vec<(PathBuf, PackageIdent)> {
+    let binlink_dir = match binlink_dir.strip_prefix("/") {
+        Ok(relative) => fs_root_path.join(relative),
+        Err(_) => binlink_dir.to_path_buf(),
+    };
+    let pkg_root = hfs::pkg_root_path(Some(fs_root_path));
+    let mut links = vec![];
+    let entries = match fs::read_dir(&binlink_dir) {
+        Ok(entries) => entries,
+        Err(_) => return links,
+    };
+    for entry in entries.filter_map(|e| e.ok()) {
+        let link = entry.path();
+        let src = match fs::read_link(&link) {
+            Ok(src) => src,
+            Err(_) => continue,
+        };
+        // Binlinks are made with paths outside of the filesystem root, so line them up first.
+        let src = match src.strip_prefix("/") {
+            Ok(relative) if !src.starts_with(&pkg_root) => fs_root_path.join(relative),
+            _ => src.clone(),
+        };
+        let parts: Vec<String> = match src.strip_prefix(&pkg_root) {
+            Ok(relative) => {
+                relative
+                    .iter()
+                    .take(4)
+                    .map(|p| p.to_string_lossy().into_owned())
+                    .collect()
+            }
+            Err(_) => continue,
+        };
+        if parts.len() == 4 {
+            let ident = PackageIdent::new(
+                parts[0].clone(),
+                parts[1].clone(),
+                Some(parts[2].clone()),
+                Some(parts[3].clone()),
+            );
+            links.push((link, ident));
+        }
+    }
+    links
+}
+
+/// Remove the version, name and origin directories above a removed release, if they are empty.
+fn remove_empty_parents(install_path: &Path, pkg_root: &Path) {
+    let mut dir = install_path.parent();
+    while let Some(path) = dir {
+        if path == pkg_root || fs::remove_dir(path).is_err() {
+            break;
+        }
+        dir = path.parent();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{HashMap, HashSet};
+    use std::fs::File;
+    use std::io::Write;
+
+    use hcore::package::PackageIdent;
+    use tempdir::TempDir;
+
+    use super::{dependents_of, orphans, spec_idents};
+
+    fn ident(ident: &str) -> PackageIdent {
+        ident.parse().unwrap()
+    }
+
+    fn fake_tdeps() -> HashMap<PackageIdent, Vec<PackageIdent>> {
+        let glibc = ident("core/glibc/2.22/20170513201042");
+        let openssl = ident("core/openssl/1.0.2l/20171014213633");
+        let redis = ident("core/redis/3.2.4/20170514150022");
+        let nginx = ident("core/nginx/1.11.10/20170513215034");
+        let mut tdeps = HashMap::new();
+        tdeps.insert(glibc.clone(), vec![]);
+        tdeps.insert(openssl.clone(), vec![glibc.clone()]);
+        tdeps.insert(redis, vec![glibc.clone()]);
+        tdeps.insert(nginx, vec![openssl, glibc]);
+        tdeps
+    }
+
+    #[test]
+    fn dependents_of_finds_transitive_dependents() {
+        let tdeps = fake_tdeps();
+        assert_eq!(
+            dependents_of(&ident("core/glibc/2.22/20170513201042"), &tdeps),
+            vec![
+                ident("core/nginx/1.11.10/20170513215034"),
+                ident("core/openssl/1.0.2l/20171014213633"),
+                ident("core/redis/3.2.4/20170514150022"),
+            ]
+        );
+        assert!(dependents_of(&ident("core/redis/3.2.4/20170514150022"), &tdeps).is_empty());
+    }
+
+    #[test]
+    fn orphans_are_only_deps_nothing_else_needs() {
+        let tdeps = fake_tdeps();
+        // core/redis still needs core/glibc, so only core/openssl is left behind by core/nginx.
+        assert_eq!(
+            orphans(
+                &ident("core/nginx/1.11.10/20170513215034"),
+                &tdeps,
+                &HashSet::new(),
+            ),
+            vec![ident("core/openssl/1.0.2l/20171014213633")]
+        );
+    }
+
+    #[test]
+    fn orphans_leave_kept_packages() {
+        let tdeps = fake_tdeps();
+        let mut keep = HashSet::new();
+        keep.insert(ident("core/openssl/1.0.2l/20171014213633"));
+        assert!(
+            orphans(&ident("core/nginx/1.11.10/20170513215034"), &tdeps, &keep).is_empty()
+        );
+    }
+
+    #[test]
+    fn spec_idents_reads_every_spec() {
+        let specs = TempDir::new("specs").unwrap();
+        File::create(specs.path().join("redis.spec"))
+            .unwrap()
+            .write_all(b"ident = \"core/redis\"\ndesired_state = \"down\"\n")
+            .unwrap();
+        File::create(specs.path().join("nginx.spec"))
+            .unwrap()
+            .write_all(b"ident = \"core/nginx/1.11.10\"\n")
+            .unwrap();
+        File::create(specs.path().join("notes.txt"))
+            .unwrap()
+            .write_all(b"ident = \"core/glibc\"\n")
+            .unwrap();
+        let mut idents = spec_idents(specs.path());
+        idents.sort();
+        assert_eq!(idents, vec![ident("core/nginx/1.11.10"), ident("core/redis")]);
+        assert!(spec_idents(&specs.path().join("nope")).is_empty());
+    }
+}
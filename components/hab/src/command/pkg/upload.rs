@@ -42,7 +42,7 @@ use common::ui::{Status, UI};
 use depot_client::{self, Client};
 use error::{Error, Result};
 use hcore::channel::{STABLE_CHANNEL, UNSTABLE_CHANNEL};
-use hcore::crypto::artifact::get_artifact_header;
+use hcore::crypto::artifact::artifact_signers;
 use hcore::crypto::keys::parse_name_with_rev;
 use hcore::package::{PackageArchive, PackageIdent, PackageTarget};
 use {PRODUCT, VERSION};
@@ -68,39 +68,12 @@ where
     U: AsRef<Path>,
 {
     let mut archive = PackageArchive::new(PathBuf::from(archive_path.as_ref()));
-    let hart_header = get_artifact_header(&archive_path.as_ref())?;
-    let public_keyfile_name = format!("{}.pub", &hart_header.key_name);
-    let public_keyfile = key_path.as_ref().join(&public_keyfile_name);
-
-    ui.status(
-        Status::Signed,
-        format!("artifact with {}", &public_keyfile_name),
-    )?;
-
-    let (name, rev) = parse_name_with_rev(&hart_header.key_name)?;
     let depot_client = Client::new(url, PRODUCT, VERSION, None)?;
-
-    ui.begin(format!(
-        "Uploading public origin key {}",
-        &public_keyfile_name
-    ))?;
-
-    match depot_client.put_origin_key(&name, &rev, &public_keyfile, token, ui.progress()) {
-        Ok(()) => {
-            ui.status(Status::Uploaded,
-                      format!("public origin key {}", &public_keyfile_name))?;
-        }
-        Err(depot_client::Error::APIError(StatusCode::Conflict, _)) => {
-            ui.status(
-                Status::Using,
-                format!(
-                    "existing public origin key {}",
-                    &public_keyfile_name
-                ),
-            )?;
-        }
-        Err(err) => return Err(Error::from(err)),
-    };
+    // A countersigned artifact can only be installed by someone who can get every signer's key,
+    // so they all go up with it.
+    for key_name in artifact_signers(&archive_path.as_ref())? {
+        upload_public_key(ui, &depot_client, token, &key_name, key_path.as_ref())?;
+    }
 
     ui.begin(
         format!("Uploading {}", archive_path.as_ref().display()),
@@ -185,6 +158,49 @@ where
     }
 }
 
+/// Uploads the public key with the given name with revision from the key cache, unless Builder
+/// already has it.
+fn upload_public_key(
+    ui: &mut UI,
+    depot_client: &Client,
+    token: &str,
+    key_name: &str,
+    key_path: &Path,
+) -> Result<()> {
+    let public_keyfile_name = format!("{}.pub", key_name);
+    let public_keyfile = key_path.join(&public_keyfile_name);
+
+    ui.status(
+        Status::Signed,
+        format!("artifact with {}", &public_keyfile_name),
+    )?;
+
+    let (name, rev) = parse_name_with_rev(key_name)?;
+
+    ui.begin(format!(
+        "Uploading public origin key {}",
+        &public_keyfile_name
+    ))?;
+
+    match depot_client.put_origin_key(&name, &rev, &public_keyfile, token, ui.progress()) {
+        Ok(()) => {
+            ui.status(Status::Uploaded,
+                      format!("public origin key {}", &public_keyfile_name))?;
+        }
+        Err(depot_client::Error::APIError(StatusCode::Conflict, _)) => {
+            ui.status(
+                Status::Using,
+                format!(
+                    "existing public origin key {}",
+                    &public_keyfile_name
+                ),
+            )?;
+        }
+        Err(err) => return Err(Error::from(err)),
+    };
+    Ok(())
+}
+
 /// Uploads a package to the depot. All packages are always
 /// automatically put into the `unstable` channel, but if
 /// `additional_release_channel` is provided, packages will be
@@ -16,20 +16,53 @@ use std::path::Path;
 
 use common::ui::{Status, UI};
 use hcore::crypto::artifact;
+use hcore::package::{PackageIdent, PackageInstall};
 
-use error::Result;
+use error::{Error, Result};
 
 pub fn start(ui: &mut UI, src: &Path, cache: &Path) -> Result<()> {
     ui.begin(format!("Verifying artifact {}", &src.display()))?;
-    let (name_with_rev, hash) = artifact::verify(src, cache)?;
-    ui.status(
-        Status::Verified,
-        format!(
-            "checksum {} signed with {}",
-            &hash,
-            &name_with_rev
-        ),
-    )?;
+    let (_, hash) = artifact::verify(src, cache)?;
+    for name_with_rev in artifact::artifact_signers(&src)? {
+        ui.status(
+            Status::Verified,
+            format!(
+                "checksum {} signed with {}",
+                &hash,
+                &name_with_rev
+            ),
+        )?;
+    }
     ui.end(format!("Verified artifact {}.", &src.display()))?;
     Ok(())
 }
+
+/// Check the files of an installed release against the checksums recorded when it was built.
+pub fn start_installed(ui: &mut UI, ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
+    let pkg_install = PackageInstall::load(ident, Some(fs_root_path))?;
+    ui.begin(format!("Verifying installed files of {}", pkg_install.ident()))?;
+    let report = pkg_install.verify_files()?;
+    for path in report.modified.iter() {
+        ui.status(
+            Status::Custom('✗', "Modified".to_string()),
+            path.display(),
+        )?;
+    }
+    for path in report.missing.iter() {
+        ui.status(Status::Missing, path.display())?;
+    }
+    for path in report.extra.iter() {
+        ui.status(Status::Custom('+', "Extra".to_string()), path.display())?;
+    }
+    if !report.is_ok() {
+        return Err(Error::PackageFilesChanged(
+            pkg_install.ident().to_string(),
+            report.modified.len() + report.missing.len() + report.extra.len(),
+        ));
+    }
+    ui.end(format!(
+        "Verified installed files of {}.",
+        pkg_install.ident()
+    ))?;
+    Ok(())
+}
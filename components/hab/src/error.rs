@@ -56,6 +56,9 @@ pub enum Error {
     JobGroupCancel(api_client::Error),
     JobGroupPromoteOrDemoteUnprocessable(bool /* promote */),
     PackageArchiveMalformed(String),
+    PackageFilesChanged(String, usize),
+    PackageHasDependents(String, Vec<String>),
+    PackageInUse(String),
     ParseIntError(num::ParseIntError),
     PathPrefixError(path::StripPrefixError),
     ProvidesError(String),
@@ -154,6 +157,27 @@ impl fmt::Display for Error {
                     e
                 )
             }
+            Error::PackageHasDependents(ref p, ref d) => {
+                format!(
+                    "{} is needed by {} other installed package(s): {}",
+                    p,
+                    d.len(),
+                    d.join(", ")
+                )
+            }
+            Error::PackageFilesChanged(ref p, n) => {
+                format!(
+                    "{} has {} file(s) which differ from when it was built",
+                    p,
+                    n
+                )
+            }
+            Error::PackageInUse(ref p) => {
+                format!(
+                    "{} is used by a service loaded by a Supervisor, unload the service first",
+                    p
+                )
+            }
             Error::ParseIntError(ref err) => format!("{}", err),
             Error::PathPrefixError(ref err) => format!("{}", err),
             Error::ProvidesError(ref err) => format!("Can't find {}", err),
@@ -210,6 +234,9 @@ impl error::Error for Error {
             Error::PackageArchiveMalformed(_) => {
                 "Package archive was unreadable or had unexpected contents"
             }
+            Error::PackageFilesChanged(_, _) => "Installed package files differ from the build",
+            Error::PackageHasDependents(_, _) => "Package is needed by other installed packages",
+            Error::PackageInUse(_) => "Package is used by a loaded service",
             Error::ParseIntError(ref err) => err.description(),
             Error::PathPrefixError(ref err) => err.description(),
             Error::ProvidesError(_) => {
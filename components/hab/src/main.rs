@@ -45,7 +45,7 @@ use hcore::crypto::{init, default_cache_key_path, SigKeyPair};
 use hcore::crypto::keys::PairType;
 use hcore::env as henv;
 use hcore::fs::{cache_artifact_path, cache_analytics_path, cache_key_path};
-use hcore::package::PackageIdent;
+use hcore::package::{PackageIdent, PackageTarget};
 use hcore::service::ServiceGroup;
 use hcore::url::default_bldr_url;
 use hcore::binlink::default_binlink_dir;
@@ -53,6 +53,7 @@ use hcore::binlink::default_binlink_dir;
 use hab::{analytics, cli, command, config, scaffolding, AUTH_TOKEN_ENVVAR, ORIGIN_ENVVAR, PRODUCT,
           VERSION};
 use hab::error::{Error, Result};
+use hab::command::pkg::search::{DEFAULT_LIMIT as DEFAULT_SEARCH_LIMIT, SortBy};
 
 /// Makes the --org CLI param optional when this env var is set
 const HABITAT_ORG_ENVVAR: &'static str = "HAB_ORG";
@@ -122,10 +123,19 @@ fn start(ui: &mut UI) -> Result<()> {
                         ("export", Some(sc)) => sub_origin_key_export(sc)?,
                         ("generate", Some(sc)) => sub_origin_key_generate(ui, sc)?,
                         ("import", Some(_)) => sub_origin_key_import(ui)?,
+                        ("revoke", Some(sc)) => sub_origin_key_revoke(ui, sc)?,
                         ("upload", Some(sc)) => sub_origin_key_upload(ui, sc)?,
                         _ => unreachable!(),
                     }
                 }
+                ("member", Some(m)) => {
+                    match m.subcommand() {
+                        ("list", Some(sc)) => sub_origin_member_list(ui, sc)?,
+                        ("remove", Some(sc)) => sub_origin_member_remove(ui, sc)?,
+                        ("role", Some(sc)) => sub_origin_member_role(ui, sc)?,
+                        _ => unreachable!(),
+                    }
+                }
                 _ => unreachable!(),
             }
         }
@@ -158,17 +168,21 @@ fn start(ui: &mut UI) -> Result<()> {
                 ("binds", Some(m)) => sub_pkg_binds(m)?,
                 ("binlink", Some(m)) => sub_pkg_binlink(ui, m)?,
                 ("build", Some(m)) => sub_pkg_build(ui, m)?,
+                ("bundle", Some(m)) => sub_pkg_bundle(ui, m)?,
                 ("channels", Some(m)) => sub_pkg_channels(ui, m)?,
                 ("config", Some(m)) => sub_pkg_config(m)?,
+                ("dependencies", Some(m)) => sub_pkg_dependencies(m)?,
                 ("env", Some(m)) => sub_pkg_env(m)?,
                 ("exec", Some(m)) => sub_pkg_exec(m, remaining_args)?,
                 ("export", Some(m)) => sub_pkg_export(ui, m)?,
                 ("hash", Some(m)) => sub_pkg_hash(m)?,
                 ("install", Some(m)) => sub_pkg_install(ui, m)?,
+                ("list", Some(m)) => sub_pkg_list(m)?,
                 ("path", Some(m)) => sub_pkg_path(m)?,
                 ("provides", Some(m)) => sub_pkg_provides(m)?,
                 ("search", Some(m)) => sub_pkg_search(m)?,
                 ("sign", Some(m)) => sub_pkg_sign(ui, m)?,
+                ("uninstall", Some(m)) => sub_pkg_uninstall(ui, m)?,
                 ("upload", Some(m)) => sub_pkg_upload(ui, m)?,
                 ("verify", Some(m)) => sub_pkg_verify(ui, m)?,
                 ("header", Some(m)) => sub_pkg_header(ui, m)?,
@@ -288,6 +302,12 @@ fn sub_origin_key_import(ui: &mut UI) -> Result<()> {
     )
 }
 
+fn sub_origin_key_revoke(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let key_name = m.value_of("KEY_NAME").unwrap(); // Required via clap
+
+    command::origin::key::revoke::start(ui, key_name, &default_cache_key_path(Some(&*FS_ROOT)))
+}
+
 fn sub_origin_key_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let url = bldr_url_from_matches(m);
     let token = auth_token_param_or_env(&m)?;
@@ -313,6 +333,30 @@ fn sub_origin_key_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     }
 }
 
+fn sub_origin_member_list(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let url = bldr_url_from_matches(m);
+    let origin = origin_param_or_env(&m)?;
+    let token = auth_token_param_or_env(&m)?;
+    command::origin::member::list::start(ui, &url, &token, &origin)
+}
+
+fn sub_origin_member_remove(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let url = bldr_url_from_matches(m);
+    let origin = origin_param_or_env(&m)?;
+    let member = m.value_of("MEMBER").unwrap(); // Required via clap
+    let token = auth_token_param_or_env(&m)?;
+    command::origin::member::remove::start(ui, &url, &token, &origin, &member)
+}
+
+fn sub_origin_member_role(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let url = bldr_url_from_matches(m);
+    let origin = origin_param_or_env(&m)?;
+    let member = m.value_of("MEMBER").unwrap(); // Required via clap
+    let role = m.value_of("ROLE").unwrap(); // Required via clap
+    let token = auth_token_param_or_env(&m)?;
+    command::origin::member::role::start(ui, &url, &token, &origin, &member, &role)
+}
+
 fn sub_pkg_binlink(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
     let dest_dir = binlink_dest_dir_from_matches(m);
@@ -325,6 +369,26 @@ fn sub_pkg_binlink(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     }
 }
 
+fn sub_pkg_bundle(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?; // Required via clap
+    let url = bldr_url_from_matches(m);
+    let channel = channel_from_matches(m);
+    let output = m.value_of("OUTPUT").map(Path::new);
+    let token = maybe_auth_token(&m);
+
+    init();
+
+    command::pkg::bundle::start(
+        ui,
+        &url,
+        &channel,
+        &ident,
+        output,
+        &*FS_ROOT,
+        token.as_ref().map(String::as_str),
+    )
+}
+
 fn sub_pkg_build(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let plan_context = m.value_of("PLAN_CONTEXT").unwrap(); // Required via clap
     let root = m.value_of("HAB_STUDIO_ROOT");
@@ -370,8 +434,23 @@ fn sub_pkg_binds(m: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+fn sub_pkg_dependencies(m: &ArgMatches) -> Result<()> {
+    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?; // Required via clap
+    let transitive = m.is_present("TRANSITIVE");
+    let reverse = m.is_present("REVERSE");
+    let json = m.is_present("JSON");
+
+    command::pkg::dependencies::start(&ident, &*FS_ROOT, transitive, reverse, json)
+}
+
 fn sub_pkg_env(m: &ArgMatches) -> Result<()> {
-    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
+    if let Some(mut idents) = m.values_of("DIFF") {
+        // Exactly two via clap
+        let a = PackageIdent::from_str(idents.next().unwrap())?;
+        let b = PackageIdent::from_str(idents.next().unwrap())?;
+        return command::pkg::env::diff(&a, &b, &*FS_ROOT);
+    }
+    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?; // Required via clap
 
     command::pkg::env::start(&ident, &*FS_ROOT)
 }
@@ -512,21 +591,37 @@ fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let channel = channel_from_matches(m);
     let install_sources = install_sources_from_matches(m)?;
     let token = maybe_auth_token(&m);
+    let offline_sources: Vec<PathBuf> = match m.values_of("OFFLINE") {
+        Some(sources) => sources.map(PathBuf::from).collect(),
+        None => vec![],
+    };
 
     init();
 
     for install_source in install_sources.iter() {
-        let pkg_install = common::command::package::install::start(
-            ui,
-            &url,
-            Some(&channel),
-            install_source,
-            PRODUCT,
-            VERSION,
-            &*FS_ROOT,
-            &cache_artifact_path(Some(&*FS_ROOT)),
-            token.as_ref().map(String::as_str),
-        )?;
+        let pkg_install = if offline_sources.is_empty() {
+            common::command::package::install::start(
+                ui,
+                &url,
+                Some(&channel),
+                install_source,
+                PRODUCT,
+                VERSION,
+                &*FS_ROOT,
+                &cache_artifact_path(Some(&*FS_ROOT)),
+                token.as_ref().map(String::as_str),
+            )?
+        } else {
+            common::command::package::install::start_offline(
+                ui,
+                install_source,
+                &offline_sources,
+                PRODUCT,
+                VERSION,
+                &*FS_ROOT,
+                &cache_artifact_path(Some(&*FS_ROOT)),
+            )?
+        };
 
         if m.is_present("BINLINK") {
             let dest_dir = binlink_dest_dir_from_matches(m);
@@ -543,6 +638,17 @@ fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+fn sub_pkg_list(m: &ArgMatches) -> Result<()> {
+    let ident = match m.value_of("PKG_IDENT") {
+        Some(ident) => Some(PackageIdent::from_str(ident)?),
+        None => None,
+    };
+    let origin = m.value_of("ORIGIN");
+    let json = m.is_present("JSON");
+
+    command::pkg::list::start(ident.as_ref(), origin, &*FS_ROOT, json)
+}
+
 fn sub_pkg_path(m: &ArgMatches) -> Result<()> {
     let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
 
@@ -562,7 +668,24 @@ fn sub_pkg_search(m: &ArgMatches) -> Result<()> {
     let url = bldr_url_from_matches(m);
     let search_term = m.value_of("SEARCH_TERM").unwrap(); // Required via clap
     let token = maybe_auth_token(&m);
-    command::pkg::search::start(&search_term, &url, token.as_ref().map(String::as_str))
+    let target = match m.value_of("TARGET") {
+        Some(target) => Some(PackageTarget::from_str(target)?),
+        None => None,
+    };
+    let sort = m.value_of("SORT")
+        .and_then(SortBy::from_name)
+        .unwrap_or(SortBy::Name);
+    command::pkg::search::start(
+        &search_term,
+        &url,
+        m.value_of("ORIGIN"),
+        m.value_of("CHANNEL"),
+        target.as_ref(),
+        sort,
+        m.is_present("JSON"),
+        m.value_of("LIMIT").map_or(DEFAULT_SEARCH_LIMIT, |l| l.parse().unwrap()),
+        token.as_ref().map(String::as_str),
+    )
 }
 
 fn sub_pkg_sign(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -575,7 +698,27 @@ fn sub_pkg_sign(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Some(&PairType::Secret),
     )?;
 
-    command::pkg::sign::start(ui, &pair, &src, &dst)
+    if m.is_present("COUNTERSIGN") {
+        command::pkg::sign::countersign(ui, &pair, &src, &dst)
+    } else {
+        command::pkg::sign::start(ui, &pair, &src, &dst)
+    }
+}
+
+fn sub_pkg_uninstall(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?; // Required via clap
+    let dest_dir = binlink_dest_dir_from_matches(m);
+    let remove_orphans = m.is_present("REMOVE_ORPHANS");
+    let dry_run = m.is_present("DRY_RUN");
+
+    command::pkg::uninstall::start(
+        ui,
+        &ident,
+        &*FS_ROOT,
+        &dest_dir,
+        remove_orphans,
+        dry_run,
+    )
 }
 
 fn sub_pkg_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -602,6 +745,10 @@ fn sub_pkg_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
 }
 
 fn sub_pkg_verify(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    if let Some(ident) = m.value_of("INSTALLED") {
+        let ident = PackageIdent::from_str(ident)?;
+        return command::pkg::verify::start_installed(ui, &ident, &*FS_ROOT);
+    }
     let src = Path::new(m.value_of("SOURCE").unwrap()); // Required via clap
     init();
 
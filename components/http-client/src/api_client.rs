@@ -12,22 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io;
+use std::net::SocketAddr;
 use std::path::Path;
 use std::time::Duration;
 
 use hab_core::util::sys;
-use hyper::client::{Client as HyperClient, IntoUrl, RequestBuilder};
+use hyper::client::{Client as HyperClient, IntoUrl, RedirectPolicy, RequestBuilder};
 use hyper::client::pool::{Config, Pool};
 use hyper::header::UserAgent;
 use hyper::http::h1::Http11Protocol;
-use hyper::net::HttpsConnector;
+use hyper::net::{HttpsConnector, NetworkConnector, NetworkStream};
 use hyper_openssl::OpensslClient;
 use openssl::ssl::{SslConnectorBuilder, SslConnector, SslMethod, SslOption, SSL_OP_NO_SSLV2,
                    SSL_OP_NO_SSLV3, SSL_OP_NO_COMPRESSION};
 use url::Url;
 
 use error::{Error, Result};
-use net::ProxyHttpsConnector;
+use net::{ProxyHttpsConnector, ResolvingHttpsConnector};
 use proxy::{ProxyInfo, proxy_unless_domain_exempted};
 use ssl;
 
@@ -54,6 +56,10 @@ pub struct ApiClient {
     target_scheme: String,
     /// The `User-Agent` header string to use for HTTP calls.
     user_agent_header: UserAgent,
+    /// The product and version named in the `User-Agent` header, such as `hab/0.30.0`.
+    product: String,
+    /// The kernel release named in the `User-Agent` header.
+    kernel_release: String,
 }
 
 impl ApiClient {
@@ -75,15 +81,83 @@ impl ApiClient {
         T: IntoUrl,
     {
         let endpoint = endpoint.into_url().map_err(Error::UrlParseError)?;
+        let inner = new_hyper_client(&endpoint, fs_root_path)?;
+        let proxy = proxy_unless_domain_exempted(Some(&endpoint))?;
+        Self::with_client(endpoint, product, version, inner, proxy)
+    }
+
+    /// Creates an `ApiClient` which only connects to the address `resolve` returns for a host.
+    /// HTTP proxies are never used, since a proxy would connect to whatever address it resolved
+    /// the host to itself.
+    ///
+    /// # Errors
+    ///
+    /// * If the underlying Hyper client cannot be created
+    /// * If a suitable SSL context cannot be established
+    /// * If a `User-Agent` HTTP header string cannot be constructed
+    pub fn with_resolver<T, R>(
+        endpoint: T,
+        product: &str,
+        version: &str,
+        fs_root_path: Option<&Path>,
+        resolve: R,
+    ) -> Result<Self>
+    where
+        T: IntoUrl,
+        R: Fn(&str, u16) -> io::Result<SocketAddr> + Send + Sync + 'static,
+    {
+        let endpoint = endpoint.into_url().map_err(Error::UrlParseError)?;
+        let ssl_client = OpensslClient::from(ssl_connector(fs_root_path)?);
+        let inner = client_with_connector(ResolvingHttpsConnector::new(resolve, ssl_client));
+        Self::with_client(endpoint, product, version, inner, None)
+    }
+
+    fn with_client(
+        endpoint: Url,
+        product: &str,
+        version: &str,
+        inner: HyperClient,
+        proxy: Option<ProxyInfo>,
+    ) -> Result<Self> {
+        let uname = sys::uname()?;
+        let product = format!("{}/{}", product.trim(), version.trim());
+        let target = format!(
+            "{}-{}",
+            uname.machine.trim().to_lowercase(),
+            uname.sys_name.trim().to_lowercase()
+        );
+        let kernel_release = uname.release.trim().to_lowercase();
+        let user_agent_header = user_agent(&product, &target, &kernel_release);
+        debug!("User-Agent: {}", &user_agent_header);
         Ok(ApiClient {
-            inner: new_hyper_client(&endpoint, fs_root_path)?,
-            proxy: proxy_unless_domain_exempted(Some(&endpoint))?,
+            inner: inner,
+            proxy: proxy,
             target_scheme: endpoint.scheme().to_string(),
             endpoint: endpoint,
-            user_agent_header: user_agent(product, version)?,
+            user_agent_header: user_agent_header,
+            product: product,
+            kernel_release: kernel_release,
         })
     }
 
+    /// Returns the `User-Agent` header this client sends, but naming another target platform, for
+    /// requests about packages built for a platform other than this one.
+    pub fn user_agent_for_target(&self, target: &str) -> UserAgent {
+        user_agent(&self.product, target, &self.kernel_release)
+    }
+
+    /// Sets the read and write timeout of the client's sockets, replacing the default of
+    /// `CLIENT_SOCKET_RW_TIMEOUT` seconds.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.inner.set_read_timeout(Some(timeout));
+        self.inner.set_write_timeout(Some(timeout));
+    }
+
+    /// Sets which redirects the client follows. By default every redirect is followed.
+    pub fn set_redirect_policy(&mut self, policy: RedirectPolicy) {
+        self.inner.set_redirect_policy(policy);
+    }
+
     /// Builds an HTTP GET request for a given path.
     pub fn get(&self, path: &str) -> RequestBuilder {
         self.get_with_custom_url(path, |_| {})
@@ -246,29 +320,30 @@ impl ApiClient {
 fn new_hyper_client(url: &Url, fs_root_path: Option<&Path>) -> Result<HyperClient> {
     let connector = ssl_connector(fs_root_path)?;
     let ssl_client = OpensslClient::from(connector);
-    let timeout = Some(Duration::from_secs(CLIENT_SOCKET_RW_TIMEOUT));
 
     match proxy_unless_domain_exempted(Some(url))? {
         Some(proxy) => {
             debug!("Using proxy {}:{}...", proxy.host(), proxy.port());
             let connector = ProxyHttpsConnector::new(proxy, ssl_client)?;
-            let pool = Pool::with_connector(Config::default(), connector);
-            let mut client = HyperClient::with_protocol(Http11Protocol::with_connector(pool));
-            client.set_read_timeout(timeout);
-            client.set_write_timeout(timeout);
-            Ok(client)
-        }
-        None => {
-            let connector = HttpsConnector::new(ssl_client);
-            let pool = Pool::with_connector(Config::default(), connector);
-            let mut client = HyperClient::with_protocol(Http11Protocol::with_connector(pool));
-            client.set_read_timeout(timeout);
-            client.set_write_timeout(timeout);
-            Ok(client)
+            Ok(client_with_connector(connector))
         }
+        None => Ok(client_with_connector(HttpsConnector::new(ssl_client))),
     }
 }
 
+fn client_with_connector<C, S>(connector: C) -> HyperClient
+where
+    C: NetworkConnector<Stream = S> + Send + Sync + 'static,
+    S: NetworkStream + Send,
+{
+    let timeout = Some(Duration::from_secs(CLIENT_SOCKET_RW_TIMEOUT));
+    let pool = Pool::with_connector(Config::default(), connector);
+    let mut client = HyperClient::with_protocol(Http11Protocol::with_connector(pool));
+    client.set_read_timeout(timeout);
+    client.set_write_timeout(timeout);
+    client
+}
+
 /// Returns an HTTP User-Agent string type for use by Hyper when making HTTP requests.
 ///
 /// The general form for Habitat-related clients are of the following form:
@@ -293,18 +368,8 @@ fn new_hyper_client(url: &Url, fs_root_path: Option<&Path>) -> Result<HyperClien
 /// # Errors
 ///
 /// * If system information cannot be obtained via `uname`
-fn user_agent(product: &str, version: &str) -> Result<UserAgent> {
-    let uname = sys::uname()?;
-    let ua = format!(
-        "{}/{} ({}-{}; {})",
-        product.trim(),
-        version.trim(),
-        uname.machine.trim().to_lowercase(),
-        uname.sys_name.trim().to_lowercase(),
-        uname.release.trim().to_lowercase()
-    );
-    debug!("User-Agent: {}", &ua);
-    Ok(UserAgent(ua))
+fn user_agent(product: &str, target: &str, kernel_release: &str) -> UserAgent {
+    UserAgent(format!("{} ({}; {})", product, target, kernel_release))
 }
 
 fn ssl_connector(fs_root_path: Option<&Path>) -> Result<SslConnector> {
@@ -12,13 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::io::{Read, Write};
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpStream};
 
 use httparse;
 use hyper;
 use hyper::method::Method;
 use hyper::version::HttpVersion;
-use hyper::net::{HttpConnector, HttpsStream, NetworkConnector, SslClient};
+use hyper::net::{HttpConnector, HttpStream, HttpsStream, NetworkConnector, SslClient};
 
 use proxy::ProxyInfo;
 
@@ -117,3 +118,43 @@ impl<S: SslClient> NetworkConnector for ProxyHttpsConnector<S> {
         }
     }
 }
+
+/// A connector that connects to whichever address `resolve` returns for a host, rather than to
+/// whatever the host resolves to at connection time. Requests and SSL sessions still name the
+/// host, so its certificate is verified as usual.
+pub struct ResolvingHttpsConnector<S: SslClient, R> {
+    resolve: R,
+    ssl_client: S,
+}
+
+impl<S, R> ResolvingHttpsConnector<S, R>
+where
+    S: SslClient,
+    R: Fn(&str, u16) -> io::Result<SocketAddr>,
+{
+    /// Creates a new connector using the provided resolver and SSL implementation.
+    pub fn new(resolve: R, ssl: S) -> Self {
+        ResolvingHttpsConnector {
+            resolve: resolve,
+            ssl_client: ssl,
+        }
+    }
+}
+
+impl<S, R> NetworkConnector for ResolvingHttpsConnector<S, R>
+where
+    S: SslClient,
+    R: Fn(&str, u16) -> io::Result<SocketAddr>,
+{
+    type Stream = HttpsStream<S::Stream>;
+
+    fn connect(&self, host: &str, port: u16, scheme: &str) -> hyper::Result<Self::Stream> {
+        let addr = (self.resolve)(host, port)?;
+        debug!("Connecting to {}:{} at {}", host, port, addr);
+        let stream = HttpStream(TcpStream::connect(addr)?);
+        match scheme {
+            "https" => self.ssl_client.wrap_client(stream, host).map(HttpsStream::Https),
+            _ => Ok(HttpsStream::Http(stream)),
+        }
+    }
+}
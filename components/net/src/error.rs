@@ -185,6 +185,15 @@ impl error::Error for NetError {
             ErrCode::PARTIAL_JOB_GROUP_PROMOTE => {
                 "Some packages failed to promote to the specified channel."
             }
+            ErrCode::CHANNEL_PROTECTED => {
+                "The channel is protected and requires a more privileged role to promote into."
+            }
+            ErrCode::CHANNEL_PREREQUISITE_UNMET => {
+                "The package must be in the channel's prerequisite channel before promotion."
+            }
+            ErrCode::CHANNEL_JOB_REQUIRED => {
+                "The channel only accepts packages built by a successful Builder job."
+            }
         }
     }
 }
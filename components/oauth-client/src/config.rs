@@ -0,0 +1,77 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use github_api_client::GitHubCfg;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum ProviderKind {
+    #[serde(rename = "github")]
+    GitHub,
+    #[serde(rename = "oidc")]
+    Oidc,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct OAuthCfg {
+    /// Identity provider users sign in with
+    pub provider: ProviderKind,
+    /// Configuration of the GitHub provider. Callers which already carry a `GitHubCfg` are
+    /// expected to keep the two in sync.
+    #[serde(skip)]
+    pub github: GitHubCfg,
+    pub oidc: OidcCfg,
+}
+
+impl Default for OAuthCfg {
+    fn default() -> Self {
+        OAuthCfg {
+            provider: ProviderKind::GitHub,
+            github: GitHubCfg::default(),
+            oidc: OidcCfg::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct OidcCfg {
+    /// URL of the issuer, used to discover any endpoint which isn't set explicitly
+    pub issuer: String,
+    /// URL of the token endpoint
+    pub token_url: String,
+    /// URL of the userinfo endpoint
+    pub userinfo_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    /// Redirect URI registered with the provider for Builder
+    pub redirect_url: String,
+    /// Claim naming the Builder account a user signs in as. Users of every provider share one
+    /// namespace of account names, so this should be unique across the deployment.
+    pub username_claim: String,
+}
+
+impl Default for OidcCfg {
+    fn default() -> Self {
+        OidcCfg {
+            issuer: String::new(),
+            token_url: String::new(),
+            userinfo_url: String::new(),
+            client_id: String::new(),
+            client_secret: String::new(),
+            redirect_url: String::new(),
+            username_claim: "preferred_username".to_string(),
+        }
+    }
+}
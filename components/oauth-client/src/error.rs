@@ -0,0 +1,103 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::fmt;
+use std::io;
+
+use github_api_client::HubError;
+use hyper;
+use openssl;
+use serde_json;
+
+pub type OAuthResult<T> = Result<T, OAuthError>;
+
+#[derive(Debug)]
+pub enum OAuthError {
+    Auth(String),
+    Discovery(String),
+    GitHub(HubError),
+    HttpClient(hyper::Error),
+    HttpResponse(hyper::status::StatusCode, String),
+    IO(io::Error),
+    MissingClaim(String),
+    Serialization(serde_json::Error),
+    SslErrorStack(openssl::error::ErrorStack),
+    Unauthorized,
+}
+
+impl fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match *self {
+            OAuthError::Auth(ref e) => format!("Authentication error, {}", e),
+            OAuthError::Discovery(ref e) => {
+                format!("Unable to discover identity provider endpoints, {}", e)
+            }
+            OAuthError::GitHub(ref e) => format!("{}", e),
+            OAuthError::HttpClient(ref e) => format!("{}", e),
+            OAuthError::HttpResponse(ref code, ref body) => {
+                format!("Received a non-200 response, status={}, body={}", code, body)
+            }
+            OAuthError::IO(ref e) => format!("{}", e),
+            OAuthError::MissingClaim(ref e) => {
+                format!("Identity provider did not return the {} claim", e)
+            }
+            OAuthError::Serialization(ref e) => format!("{}", e),
+            OAuthError::SslErrorStack(ref e) => format!("{}", e),
+            OAuthError::Unauthorized => format!("Access token was rejected"),
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+impl error::Error for OAuthError {
+    fn description(&self) -> &str {
+        match *self {
+            OAuthError::Auth(_) => "Authentication error.",
+            OAuthError::Discovery(_) => "Unable to discover identity provider endpoints.",
+            OAuthError::GitHub(ref err) => err.description(),
+            OAuthError::HttpClient(ref err) => err.description(),
+            OAuthError::HttpResponse(_, _) => "Non-200 HTTP response.",
+            OAuthError::IO(ref err) => err.description(),
+            OAuthError::MissingClaim(_) => "Identity provider did not return a required claim.",
+            OAuthError::Serialization(ref err) => err.description(),
+            OAuthError::SslErrorStack(ref err) => err.description(),
+            OAuthError::Unauthorized => "Access token was rejected.",
+        }
+    }
+}
+
+impl From<hyper::Error> for OAuthError {
+    fn from(err: hyper::Error) -> Self {
+        OAuthError::HttpClient(err)
+    }
+}
+
+impl From<io::Error> for OAuthError {
+    fn from(err: io::Error) -> Self {
+        OAuthError::IO(err)
+    }
+}
+
+impl From<openssl::error::ErrorStack> for OAuthError {
+    fn from(err: openssl::error::ErrorStack) -> Self {
+        OAuthError::SslErrorStack(err)
+    }
+}
+
+impl From<serde_json::Error> for OAuthError {
+    fn from(err: serde_json::Error) -> Self {
+        OAuthError::Serialization(err)
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use github_api_client::{GitHubCfg, GitHubClient, HubError};
+use hyper::status::StatusCode;
+
+use config::ProviderKind;
+use error::{OAuthError, OAuthResult};
+use {Identity, IdentityProvider};
+
+pub struct GitHubProvider {
+    client: GitHubClient,
+}
+
+impl GitHubProvider {
+    pub fn new(config: GitHubCfg) -> Self {
+        GitHubProvider { client: GitHubClient::new(config) }
+    }
+}
+
+impl IdentityProvider for GitHubProvider {
+    fn kind(&self) -> ProviderKind {
+        ProviderKind::GitHub
+    }
+
+    fn authenticate(&self, code: &str) -> OAuthResult<String> {
+        match self.client.authenticate(code) {
+            Ok(token) => Ok(token),
+            Err(HubError::Auth(e)) => Err(OAuthError::Auth(e.error)),
+            Err(HubError::Serialization(e)) => Err(OAuthError::Serialization(e)),
+            Err(e) => Err(OAuthError::GitHub(e)),
+        }
+    }
+
+    fn user(&self, token: &str) -> OAuthResult<Identity> {
+        match self.client.user(token) {
+            Ok(user) => {
+                Ok(Identity {
+                    id: user.id.to_string(),
+                    username: user.login,
+                    email: user.email,
+                    email_verified: false,
+                })
+            }
+            Err(HubError::ApiError(StatusCode::Unauthorized, _)) => Err(OAuthError::Unauthorized),
+            Err(HubError::ApiError(status, body)) => {
+                Err(OAuthError::HttpResponse(status, format!("{:?}", body)))
+            }
+            Err(HubError::Serialization(e)) => Err(OAuthError::Serialization(e)),
+            Err(e) => Err(OAuthError::GitHub(e)),
+        }
+    }
+}
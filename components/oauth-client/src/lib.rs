@@ -0,0 +1,115 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Identity providers which Builder users can sign in with.
+//!
+//! Every provider follows the OAuth2 authorization code flow: the user is sent to the provider,
+//! which redirects back to Builder with a code. The code is exchanged for an access token and the
+//! token is then used to look up who the user is at the provider.
+
+extern crate github_api_client;
+extern crate hyper;
+extern crate hyper_openssl;
+#[macro_use]
+extern crate log;
+extern crate openssl;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate url;
+
+pub mod config;
+pub mod error;
+pub mod github;
+pub mod oidc;
+
+pub use config::{OAuthCfg, OidcCfg, ProviderKind};
+pub use error::{OAuthError, OAuthResult};
+
+use github::GitHubProvider;
+use oidc::OidcProvider;
+
+/// A user as known by an identity provider.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Identity {
+    /// Stable identifier of the user at the provider
+    pub id: String,
+    /// Name of the Builder account the user signs in as
+    pub username: String,
+    pub email: Option<String>,
+    /// Whether the provider vouches that the user owns `email`
+    pub email_verified: bool,
+}
+
+impl Identity {
+    /// Numeric form of the user's identifier, used to route session requests. Identifiers which
+    /// aren't numeric are hashed with FNV-1a so every gateway routes the same user alike.
+    pub fn extern_id(&self) -> u32 {
+        match self.id.parse::<u32>() {
+            Ok(id) => id,
+            Err(_) => {
+                self.id.bytes().fold(2166136261, |hash, byte| {
+                    (hash ^ byte as u32).wrapping_mul(16777619)
+                })
+            }
+        }
+    }
+}
+
+pub trait IdentityProvider: Send + Sync {
+    fn kind(&self) -> ProviderKind;
+
+    /// Exchange an authorization code for an access token.
+    fn authenticate(&self, code: &str) -> OAuthResult<String>;
+
+    /// Look up the user an access token was issued to.
+    fn user(&self, token: &str) -> OAuthResult<Identity>;
+}
+
+pub fn new_provider(config: &OAuthCfg) -> Box<IdentityProvider> {
+    match config.provider {
+        ProviderKind::GitHub => Box::new(GitHubProvider::new(config.github.clone())),
+        ProviderKind::Oidc => Box::new(OidcProvider::new(config.oidc.clone())),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn identity(id: &str) -> Identity {
+        Identity {
+            id: id.to_string(),
+            username: "jdoe".to_string(),
+            email: None,
+            email_verified: false,
+        }
+    }
+
+    #[test]
+    fn numeric_extern_id() {
+        assert_eq!(identity("54036").extern_id(), 54036);
+    }
+
+    #[test]
+    fn hashed_extern_id() {
+        assert_eq!(identity("").extern_id(), 2166136261);
+        assert_eq!(identity("a").extern_id(), 0xe40c292c);
+        assert_eq!(
+            identity("abc123").extern_id(),
+            identity("abc123").extern_id()
+        );
+    }
+}
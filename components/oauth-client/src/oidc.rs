@@ -0,0 +1,338 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A provider for any OpenID Connect compliant identity provider. Plain OAuth2 providers work
+//! too, as long as they expose an endpoint returning the user's claims as JSON.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use hyper;
+use hyper::client::IntoUrl;
+use hyper::header::{Accept, Authorization, Bearer, ContentType, UserAgent, qitem};
+use hyper::mime::{Mime, SubLevel, TopLevel};
+use hyper::net::HttpsConnector;
+use hyper::status::StatusCode;
+use hyper_openssl::OpensslClient;
+use serde_json;
+use url::form_urlencoded;
+
+use config::{OidcCfg, ProviderKind};
+use error::{OAuthError, OAuthResult};
+use {Identity, IdentityProvider};
+
+const USER_AGENT: &'static str = "Habitat-Builder";
+const HTTP_TIMEOUT: u64 = 3_000;
+
+#[derive(Clone, Debug, Deserialize)]
+struct Discovery {
+    token_endpoint: String,
+    userinfo_endpoint: String,
+}
+
+#[derive(Deserialize)]
+struct TokenOk {
+    access_token: String,
+}
+
+#[derive(Deserialize)]
+struct TokenErr {
+    error: String,
+    #[serde(default)]
+    error_description: String,
+}
+
+pub struct OidcProvider {
+    config: OidcCfg,
+    endpoints: RwLock<Option<Discovery>>,
+}
+
+impl OidcProvider {
+    pub fn new(config: OidcCfg) -> Self {
+        OidcProvider {
+            config: config,
+            endpoints: RwLock::new(None),
+        }
+    }
+
+    /// Endpoints which aren't configured are looked up in the issuer's discovery document the
+    /// first time they're needed.
+    fn endpoints(&self) -> OAuthResult<Discovery> {
+        if let Some(ref endpoints) = *self.endpoints.read().unwrap() {
+            return Ok(endpoints.clone());
+        }
+        let mut endpoints = Discovery {
+            token_endpoint: self.config.token_url.clone(),
+            userinfo_endpoint: self.config.userinfo_url.clone(),
+        };
+        if endpoints.token_endpoint.is_empty() || endpoints.userinfo_endpoint.is_empty() {
+            if self.config.issuer.is_empty() {
+                return Err(OAuthError::Discovery(
+                    "no issuer or endpoint urls configured".to_string(),
+                ));
+            }
+            let url = format!(
+                "{}/.well-known/openid-configuration",
+                self.config.issuer.trim_right_matches('/')
+            );
+            let (status, body) = http_get(&url, None)?;
+            if status != StatusCode::Ok {
+                return Err(OAuthError::Discovery(format!("{} returned {}", url, status)));
+            }
+            let discovered: Discovery = serde_json::from_str(&body)?;
+            if endpoints.token_endpoint.is_empty() {
+                endpoints.token_endpoint = discovered.token_endpoint;
+            }
+            if endpoints.userinfo_endpoint.is_empty() {
+                endpoints.userinfo_endpoint = discovered.userinfo_endpoint;
+            }
+        }
+        *self.endpoints.write().unwrap() = Some(endpoints.clone());
+        Ok(endpoints)
+    }
+}
+
+impl IdentityProvider for OidcProvider {
+    fn kind(&self) -> ProviderKind {
+        ProviderKind::Oidc
+    }
+
+    fn authenticate(&self, code: &str) -> OAuthResult<String> {
+        let endpoints = self.endpoints()?;
+        let body = form_urlencoded::Serializer::new(String::new())
+            .append_pair("grant_type", "authorization_code")
+            .append_pair("code", code)
+            .append_pair("redirect_uri", &self.config.redirect_url)
+            .append_pair("client_id", &self.config.client_id)
+            .append_pair("client_secret", &self.config.client_secret)
+            .finish();
+        let client = hyper_client()?;
+        let mut rep = client
+            .post(&endpoints.token_endpoint)
+            .header(ContentType::form_url_encoded())
+            .header(json_accept())
+            .header(UserAgent(USER_AGENT.to_string()))
+            .body(body.as_str())
+            .send()?;
+        let mut body = String::new();
+        rep.read_to_string(&mut body)?;
+        // The body holds the access token, so it's kept out of the logs.
+        debug!("OIDC token response status, {}", rep.status);
+        match rep.status {
+            StatusCode::Ok => {
+                let msg: TokenOk = serde_json::from_str(&body)?;
+                Ok(msg.access_token)
+            }
+            StatusCode::BadRequest |
+            StatusCode::Unauthorized => {
+                let err: TokenErr = serde_json::from_str(&body)?;
+                Err(OAuthError::Auth(
+                    format!("{} {}", err.error, err.error_description)
+                        .trim()
+                        .to_string(),
+                ))
+            }
+            status => Err(OAuthError::HttpResponse(status, body)),
+        }
+    }
+
+    fn user(&self, token: &str) -> OAuthResult<Identity> {
+        let endpoints = self.endpoints()?;
+        let (status, body) = http_get(&endpoints.userinfo_endpoint, Some(token))?;
+        match status {
+            StatusCode::Ok => (),
+            StatusCode::Unauthorized => return Err(OAuthError::Unauthorized),
+            status => return Err(OAuthError::HttpResponse(status, body)),
+        }
+        let claims: HashMap<String, serde_json::Value> = serde_json::from_str(&body)?;
+        let claim = |name: &str| claims.get(name).and_then(|v| v.as_str()).map(String::from);
+        let id = claim("sub").ok_or(OAuthError::MissingClaim("sub".to_string()))?;
+        let username = claim(&self.config.username_claim).ok_or(
+            OAuthError::MissingClaim(
+                self.config.username_claim.clone(),
+            ),
+        )?;
+        // Some providers send the `email_verified` claim as a string.
+        let email_verified = match claims.get("email_verified") {
+            Some(&serde_json::Value::Bool(verified)) => verified,
+            Some(&serde_json::Value::String(ref verified)) => verified == "true",
+            _ => false,
+        };
+        Ok(Identity {
+            id: id,
+            username: username,
+            email: claim("email"),
+            email_verified: email_verified,
+        })
+    }
+}
+
+fn http_get<T>(url: T, token: Option<&str>) -> OAuthResult<(StatusCode, String)>
+where
+    T: IntoUrl,
+{
+    let client = hyper_client()?;
+    let req = client.get(url).header(json_accept()).header(UserAgent(
+        USER_AGENT.to_string(),
+    ));
+    let req = match token {
+        Some(token) => req.header(Authorization(Bearer { token: token.to_string() })),
+        None => req,
+    };
+    let mut rep = req.send()?;
+    let mut body = String::new();
+    rep.read_to_string(&mut body)?;
+    // Userinfo bodies hold personal details, so only the status is logged.
+    debug!("OIDC response status, {}", rep.status);
+    Ok((rep.status, body))
+}
+
+fn json_accept() -> Accept {
+    Accept(vec![
+        qitem(Mime(TopLevel::Application, SubLevel::Json, vec![])),
+    ])
+}
+
+fn hyper_client() -> OAuthResult<hyper::Client> {
+    let ssl = OpensslClient::new()?;
+    let connector = HttpsConnector::new(ssl);
+    let mut client = hyper::Client::with_connector(connector);
+    client.set_read_timeout(Some(Duration::from_millis(HTTP_TIMEOUT)));
+    client.set_write_timeout(Some(Duration::from_millis(HTTP_TIMEOUT)));
+    Ok(client)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{Read, Write};
+
+    use hyper::header::{Authorization, Bearer, Host};
+    use hyper::server::{Listening, Request, Response, Server};
+    use hyper::status::StatusCode;
+    use hyper::uri::RequestUri;
+
+    use super::*;
+
+    /// A minimal identity provider which knows about a single user.
+    fn mock(mut req: Request, mut res: Response) {
+        let path = match req.uri {
+            RequestUri::AbsolutePath(ref path) => path.clone(),
+            _ => String::new(),
+        };
+        let base = match req.headers.get::<Host>() {
+            Some(host) => format!("http://{}:{}", host.hostname, host.port.unwrap_or(80)),
+            None => String::new(),
+        };
+        let (status, body) = match path.as_str() {
+            "/.well-known/openid-configuration" => {
+                (
+                    StatusCode::Ok,
+                    format!(
+                        r#"{{"token_endpoint": "{0}/token", "userinfo_endpoint": "{0}/userinfo"}}"#,
+                        base
+                    ),
+                )
+            }
+            "/token" => {
+                let mut form = String::new();
+                req.read_to_string(&mut form).unwrap();
+                if form.contains("code=good-code") && form.contains("client_id=builder") {
+                    (StatusCode::Ok, r#"{"access_token": "good-token"}"#.to_string())
+                } else {
+                    (
+                        StatusCode::BadRequest,
+                        r#"{"error": "invalid_grant"}"#.to_string(),
+                    )
+                }
+            }
+            "/userinfo" => {
+                match req.headers.get::<Authorization<Bearer>>() {
+                    Some(&Authorization(Bearer { ref token })) if token == "good-token" => {
+                        (
+                            StatusCode::Ok,
+                            r#"{"sub": "abc123", "preferred_username": "jdoe", "email": "jdoe@example.com", "email_verified": true}"#
+                                .to_string(),
+                        )
+                    }
+                    _ => (StatusCode::Unauthorized, String::new()),
+                }
+            }
+            _ => (StatusCode::NotFound, String::new()),
+        };
+        *res.status_mut() = status;
+        res.start()
+            .unwrap()
+            .write_all(body.as_bytes())
+            .unwrap();
+    }
+
+    fn provider(username_claim: &str) -> (Listening, OidcProvider) {
+        let listening = Server::http("127.0.0.1:0").unwrap().handle(mock).unwrap();
+        let mut config = OidcCfg::default();
+        config.issuer = format!("http://{}", listening.socket);
+        config.client_id = "builder".to_string();
+        config.username_claim = username_claim.to_string();
+        (listening, OidcProvider::new(config))
+    }
+
+    #[test]
+    fn sign_in() {
+        let (mut listening, provider) = provider("preferred_username");
+        let token = provider.authenticate("good-code").unwrap();
+        assert_eq!(token, "good-token");
+        let identity = provider.user(&token).unwrap();
+        assert_eq!(
+            identity,
+            Identity {
+                id: "abc123".to_string(),
+                username: "jdoe".to_string(),
+                email: Some("jdoe@example.com".to_string()),
+                email_verified: true,
+            }
+        );
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn bad_code() {
+        let (mut listening, provider) = provider("preferred_username");
+        match provider.authenticate("bad-code") {
+            Err(OAuthError::Auth(ref e)) => assert_eq!(e, "invalid_grant"),
+            r => panic!("unexpected result {:?}", r),
+        }
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn bad_token() {
+        let (mut listening, provider) = provider("preferred_username");
+        match provider.user("bad-token") {
+            Err(OAuthError::Unauthorized) => (),
+            r => panic!("unexpected result {:?}", r),
+        }
+        listening.close().unwrap();
+    }
+
+    #[test]
+    fn missing_username_claim() {
+        let (mut listening, provider) = provider("nickname");
+        match provider.user("good-token") {
+            Err(OAuthError::MissingClaim(ref claim)) => assert_eq!(claim, "nickname"),
+            r => panic!("unexpected result {:?}", r),
+        }
+        listening.close().unwrap();
+    }
+}
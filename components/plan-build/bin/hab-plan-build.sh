@@ -211,6 +211,25 @@
 #   [storage]="port host"
 # )
 #
+# ### pkg_periodic_hooks
+# An associative array of hooks which the Supervisor runs on a cron-style schedule. Each key names
+# a hook template in `hooks/periodic/` and each value is its schedule, either five fields
+# (minute, hour, day of month, month, day of week) or one of `@hourly`, `@daily`, `@weekly`,
+# `@monthly` or `@yearly`.
+# ```
+# pkg_periodic_hooks=(
+#   [backup]="0 3 * * *"
+#   [vacuum]="@hourly"
+# )
+# ```
+#
+# ### pkg_periodic_hooks_leader
+# An array of `pkg_periodic_hooks` keys which should only be run by the elected leader when the
+# service is running in the leader topology.
+# ```
+# pkg_periodic_hooks_leader=(backup)
+# ```
+#
 # ### pkg_origin
 # A string to use for the origin. The origin is used to denote a particular upstream of a
 # package; when we resolve dependencies, we consider a version of a package to be equal
@@ -408,6 +427,8 @@ pkg_exposes=()
 declare -A pkg_exports
 declare -A pkg_binds
 declare -A pkg_binds_optional
+declare -A pkg_periodic_hooks
+pkg_periodic_hooks_leader=()
 # The user to run the service as
 pkg_svc_user=hab
 # The group to run the service as
@@ -1724,6 +1745,8 @@ do_default_install() {
 # * `$pkg_prefix/EXPOSES` - An array of `pkg_exports` for which ports that this package exposes
 # * `$pkg_prefix/BINDS` - A list of services you connect to and keys that you expect to be exported
 # * `$pkg_prefix/BINDS_OPTIONAL` - Same as `BINDS` but not required for the service to start
+# * `$pkg_prefix/PERIODIC_HOOKS` - A list of scheduled hooks and their cron-style schedules
+# * `$pkg_prefix/PERIODIC_HOOKS_LEADER` - The scheduled hooks which only run on the leader
 # * `$pkg_prefix/FILES` - blake2b checksums of all files in the package
 # * `$pkg_prefix/LDFLAGS` - Any LDFLAGS for things that link against us
 # * `$pkg_prefix/LD_RUN_PATH` - The LD_RUN_PATH for things that link against us
@@ -1743,6 +1766,8 @@ _build_metadata() {
   _render_metadata_EXPORTS
   _render_metadata_BINDS
   _render_metadata_BINDS_OPTIONAL
+  _render_metadata_PERIODIC_HOOKS
+  _render_metadata_PERIODIC_HOOKS_LEADER
   _render_metadata_EXPOSES
   _render_metadata_INTERPRETERS
   _render_metadata_BUILD_DEPS
@@ -113,6 +113,26 @@ _render_metadata_INTERPRETERS() {
     fi
 }
 
+_render_metadata_PERIODIC_HOOKS() {
+    _render_associative_array_file ${pkg_prefix} PERIODIC_HOOKS pkg_periodic_hooks
+}
+
+_render_metadata_PERIODIC_HOOKS_LEADER() {
+    local metadata_file_name="PERIODIC_HOOKS_LEADER"
+
+    if [[ ${#pkg_periodic_hooks_leader[@]} -gt 0 ]]; then
+        for hook in "${pkg_periodic_hooks_leader[@]}"; do
+            if [[ ! ${pkg_periodic_hooks[$hook]+abc} ]]; then
+                exit_with "Bad value in pkg_periodic_hooks_leader; No pkg_periodic_hooks found matching key: ${hook}"
+            fi
+        done
+        debug "Rendering ${metadata_file_name} metadata file"
+        echo "${pkg_periodic_hooks_leader[@]}" > $pkg_prefix/${metadata_file_name}
+    else
+        debug "Would have rendered ${metadata_file_name}, but there was no data for it"
+    fi
+}
+
 _render_metadata_LDFLAGS(){
     local metadata_file_name="LDFLAGS"
 
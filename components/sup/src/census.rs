@@ -18,7 +18,7 @@ use std::str::FromStr;
 
 use butterfly::member::{MemberList, Member, Health};
 use butterfly::rumor::RumorStore;
-use butterfly::rumor::service::Service as ServiceRumor;
+use butterfly::rumor::service::{RoleAssignment, Service as ServiceRumor};
 use butterfly::rumor::service_file::ServiceFile as ServiceFileRumor;
 use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
 use butterfly::rumor::election::Election as ElectionRumor;
@@ -28,9 +28,11 @@ use butterfly::rumor::service::SysInfo;
 use hcore;
 use hcore::service::ServiceGroup;
 use hcore::package::PackageIdent;
+use protobuf::RepeatedField;
 use toml;
 
 use error::{Error, SupError};
+use event_stream::{self, Event};
 
 static LOGKEY: &'static str = "CE";
 
@@ -41,6 +43,8 @@ pub struct CensusRing {
     changed: bool,
 
     census_groups: HashMap<ServiceGroup, CensusGroup>,
+    group_roles: HashMap<ServiceGroup, GroupRoles>,
+    member_health: HashMap<MemberId, Health>,
     local_member_id: MemberId,
     last_service_counter: usize,
     last_election_counter: usize,
@@ -64,6 +68,8 @@ impl CensusRing {
         CensusRing {
             changed: false,
             census_groups: HashMap::new(),
+            group_roles: HashMap::new(),
+            member_health: HashMap::new(),
             local_member_id: local_member_id.into(),
             last_service_counter: 0,
             last_election_counter: 0,
@@ -100,6 +106,7 @@ impl CensusRing {
             self.update_from_election_update_store(election_update_rumors);
             self.update_from_service_config(service_config_rumors);
             self.update_from_service_files(service_file_rumors);
+            self.assign_roles();
 
             // Update our counters to reflect current state.
             self.last_membership_counter = member_list.get_update_counter();
@@ -114,6 +121,19 @@ impl CensusRing {
         }
     }
 
+    /// Sets the roles the census assigns to members of the given service group, or clears them
+    /// when `roles` is `None`. Roles are (re)assigned whenever the census is rebuilt.
+    pub fn set_group_roles(&mut self, sg: &ServiceGroup, roles: Option<GroupRoles>) {
+        match roles {
+            Some(roles) => self.group_roles.insert(sg.clone(), roles),
+            None => self.group_roles.remove(sg),
+        };
+        if let Some(census_group) = self.census_groups.get_mut(sg) {
+            census_group.assign_roles(roles);
+            self.changed = true;
+        }
+    }
+
     pub fn census_group_for(&self, sg: &ServiceGroup) -> Option<&CensusGroup> {
         self.census_groups.get(sg)
     }
@@ -156,6 +176,12 @@ impl CensusRing {
 
         member_list.with_members(|member| {
             let health = member_list.health_of(&member).unwrap();
+            if self.member_health.insert(member.get_id().to_string(), health) != Some(health) {
+                event_stream::publish(Event::MemberHealthChanged {
+                    member_id: member.get_id().to_string(),
+                    health: health.to_string(),
+                });
+            }
             for group in self.census_groups.values_mut() {
                 if let Some(census_member) = group.find_member_mut(member.get_id()) {
                     census_member.update_from_member(&member);
@@ -165,6 +191,12 @@ impl CensusRing {
         });
     }
 
+    fn assign_roles(&mut self) {
+        for (sg, census_group) in self.census_groups.iter_mut() {
+            census_group.assign_roles(self.group_roles.get(sg).cloned());
+        }
+    }
+
     fn update_from_election_store(&mut self, election_rumors: &RumorStore<ElectionRumor>) {
         election_rumors.with_keys(|(service_group, rumors)| {
             let election = rumors.get("election").unwrap();
@@ -220,6 +252,26 @@ impl CensusRing {
     }
 }
 
+/// Roles the census assigns to the members of a service group, on top of the leader and
+/// followers its election produces.
+///
+/// The group's elected leader decides the roles and publishes them in its own service rumor, and
+/// every member adopts the published assignment with the highest version. That assignment
+/// outlives the leader that made it, and a new leader carries it on. A role only moves off a
+/// member once that member is confirmed dead or has departed, so members joining or a member
+/// being briefly suspect never reshuffle the roles of the others. Roles are only in effect once
+/// the group's election has finished, so that members on the minority side of a partition never
+/// claim them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum GroupRoles {
+    /// `count` members are active and the rest are on standby. The leader is preferred when an
+    /// active role needs filling.
+    ActiveStandby(u32),
+    /// Every member owns the shard with its ordinal. The ordinal of a member that is confirmed
+    /// gone is given to the next member to join, and stays unowned until then.
+    Sharded,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum ElectionStatus {
     None,
@@ -290,6 +342,8 @@ pub struct CensusGroup {
     pub update_election_status: ElectionStatus,
     pub leader_id: Option<MemberId>,
     pub service_config: Option<ServiceConfig>,
+    pub shard_count: Option<u32>,
+    pub role_assignment: Option<RoleAssignment>,
 
     local_member_id: MemberId,
     population: BTreeMap<MemberId, CensusMember>,
@@ -309,6 +363,8 @@ impl CensusGroup {
             leader_id: None,
             update_leader_id: None,
             service_config: None,
+            shard_count: None,
+            role_assignment: None,
             service_files: HashMap::new(),
             changed_service_files: Vec::new(),
         }
@@ -367,7 +423,127 @@ impl CensusGroup {
         }
     }
 
+    /// Returns the role assignment this member should publish for the group, if it's the group's
+    /// leader and the assignment in effect is missing or out of date.
+    pub fn next_role_assignment(&self, roles: GroupRoles) -> Option<RoleAssignment> {
+        if self.election_status != ElectionStatus::ElectionFinished ||
+            !self.me().map_or(false, |me| me.leader)
+        {
+            return None;
+        }
+        let current = self.role_assignment.clone().unwrap_or_default();
+        let mut next = RoleAssignment::new();
+        match roles {
+            GroupRoles::ActiveStandby(count) => {
+                let count = count as usize;
+                let mut active: Vec<String> = current
+                    .get_active()
+                    .iter()
+                    .filter(|id| self.holds_role(id))
+                    .cloned()
+                    .collect();
+                active.truncate(count);
+                let candidates = self.leader_id.iter().chain(
+                    self.population
+                        .values()
+                        .filter(|cm| cm.alive)
+                        .map(|cm| &cm.member_id),
+                );
+                for id in candidates {
+                    if active.len() >= count {
+                        break;
+                    }
+                    if !active.contains(id) {
+                        active.push(id.clone());
+                    }
+                }
+                next.set_active(RepeatedField::from_vec(active));
+            }
+            GroupRoles::Sharded => {
+                let mut shards: Vec<String> = current
+                    .get_shards()
+                    .iter()
+                    .map(|id| if self.holds_role(id) {
+                        id.clone()
+                    } else {
+                        String::new()
+                    })
+                    .collect();
+                for member in self.population.values().filter(|cm| cm.alive) {
+                    if shards.contains(&member.member_id) {
+                        continue;
+                    }
+                    match shards.iter().position(|id| id.is_empty()) {
+                        Some(idx) => shards[idx] = member.member_id.clone(),
+                        None => shards.push(member.member_id.clone()),
+                    }
+                }
+                while shards.last().map_or(false, |id| id.is_empty()) {
+                    shards.pop();
+                }
+                next.set_shards(RepeatedField::from_vec(shards));
+            }
+        }
+        if self.role_assignment.is_some() && next.get_active() == current.get_active() &&
+            next.get_shards() == current.get_shards()
+        {
+            return None;
+        }
+        next.set_version(current.get_version() + 1);
+        Some(next)
+    }
+
+    // A member keeps its roles until it's confirmed dead or has departed. One that is only
+    // suspect may well still be running and serving them.
+    fn holds_role(&self, member_id: &str) -> bool {
+        self.population.get(member_id).map_or(false, |cm| cm.alive || cm.suspect)
+    }
+
+    fn assign_roles(&mut self, roles: Option<GroupRoles>) {
+        self.shard_count = None;
+        for member in self.population.values_mut() {
+            member.active = false;
+            member.standby = false;
+            member.shard = None;
+        }
+        if self.election_status != ElectionStatus::ElectionFinished {
+            return;
+        }
+        let assignment = match self.role_assignment {
+            Some(ref assignment) => assignment,
+            None => return,
+        };
+        match roles {
+            Some(GroupRoles::ActiveStandby(_)) => {
+                for member in self.population.values_mut() {
+                    member.active = assignment.get_active().contains(&member.member_id);
+                    member.standby = member.alive && !member.active;
+                }
+            }
+            Some(GroupRoles::Sharded) => {
+                for (ordinal, id) in assignment.get_shards().iter().enumerate() {
+                    if let Some(member) = self.population.get_mut(id) {
+                        member.shard = Some(ordinal as u32);
+                    }
+                }
+                self.shard_count = Some(assignment.get_shards().len() as u32);
+            }
+            None => (),
+        }
+    }
+
     fn update_from_service_rumors(&mut self, rumors: &HashMap<String, ServiceRumor>) {
+        // Of the role assignments published by this group's leaders, past and present, the one
+        // with the highest version is in effect. Ties are broken by member ID, so every member
+        // settles on the same one.
+        self.role_assignment = rumors
+            .values()
+            .filter(|rumor| rumor.has_roles())
+            .max_by(|a, b| {
+                (a.get_roles().get_version(), a.get_member_id())
+                    .cmp(&(b.get_roles().get_version(), b.get_member_id()))
+            })
+            .map(|rumor| rumor.get_roles().clone());
         for (member_id, service_rumor) in rumors.iter() {
             // Yeah - we are ourself - we're alive.
             let is_self = member_id == &self.local_member_id;
@@ -383,7 +559,8 @@ impl CensusGroup {
     }
 
     fn update_from_election_rumor(&mut self, election: &ElectionRumor) {
-        self.leader_id = None;
+        let previous_leader_id = self.leader_id.take();
+        let previous_status = self.election_status;
         for census_member in self.population.values_mut() {
             if census_member.update_from_election_rumor(election) {
                 self.leader_id = Some(census_member.member_id.clone());
@@ -400,6 +577,16 @@ impl CensusGroup {
                 self.election_status = ElectionStatus::ElectionFinished;
             }
         }
+        if self.election_status == ElectionStatus::ElectionFinished &&
+            (previous_status != self.election_status || previous_leader_id != self.leader_id)
+        {
+            if let Some(ref leader_id) = self.leader_id {
+                event_stream::publish(Event::ElectionFinished {
+                    service_group: self.service_group.to_string(),
+                    leader: leader_id.clone(),
+                });
+            }
+        }
     }
 
     fn update_from_election_update_rumor(&mut self, election: &ElectionUpdateRumor) {
@@ -497,6 +684,9 @@ pub struct CensusMember {
     pub update_election_is_running: bool,
     pub update_election_is_no_quorum: bool,
     pub update_election_is_finished: bool,
+    pub active: bool,
+    pub standby: bool,
+    pub shard: Option<u32>,
     pub sys: SysInfo,
     alive: bool,
     suspect: bool,
@@ -601,15 +791,15 @@ fn service_group_from_str(sg: &str) -> Result<ServiceGroup, hcore::Error> {
 mod tests {
     use hcore::package::ident::PackageIdent;
     use hcore::service::ServiceGroup;
-    use butterfly::member::MemberList;
-    use butterfly::rumor::service::Service as ServiceRumor;
+    use butterfly::member::{Health, Member, MemberList};
+    use butterfly::rumor::service::{RoleAssignment, Service as ServiceRumor};
     use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
     use butterfly::rumor::service_file::ServiceFile as ServiceFileRumor;
     use butterfly::rumor::election::Election as ElectionRumor;
     use butterfly::rumor::election::ElectionUpdate as ElectionUpdateRumor;
     use butterfly::rumor::service::SysInfo;
     use butterfly::rumor::RumorStore;
-    use census::CensusRing;
+    use census::{CensusGroup, CensusRing, GroupRoles};
 
     #[test]
     fn update_from_rumors() {
@@ -683,4 +873,197 @@ mod tests {
         assert_eq!(members[0].member_id, "member-a");
         assert_eq!(members[1].member_id, "member-b");
     }
+
+    struct RolesFixture {
+        pg_id: PackageIdent,
+        sg: ServiceGroup,
+        service_store: RumorStore<ServiceRumor>,
+        election_store: RumorStore<ElectionRumor>,
+        member_list: MemberList,
+        ring: CensusRing,
+        incarnation: u64,
+    }
+
+    impl RolesFixture {
+        // A group led by member-c, which is also the local member.
+        fn new(roles: GroupRoles, members: &[(&str, Health)]) -> Self {
+            let sg = ServiceGroup::new(None, "redis", "default", None).unwrap();
+            let election_store: RumorStore<ElectionRumor> = RumorStore::default();
+            let mut election = ElectionRumor::new("member-c", sg.clone(), 0);
+            election.finish();
+            election_store.insert(election);
+            let mut ring = CensusRing::new("member-c".to_string());
+            ring.set_group_roles(&sg, Some(roles));
+            let mut fixture = RolesFixture {
+                pg_id: PackageIdent::new("core", "redis", Some("3.2.4"), Some("20170514150022")),
+                sg: sg,
+                service_store: RumorStore::default(),
+                election_store: election_store,
+                member_list: MemberList::new(),
+                ring: ring,
+                incarnation: 0,
+            };
+            for &(id, health) in members.iter() {
+                fixture.add_member(id, health);
+            }
+            fixture
+        }
+
+        fn add_member(&mut self, id: &str, health: Health) {
+            self.service_store.insert(ServiceRumor::new(
+                id.to_string(),
+                &self.pg_id,
+                &self.sg,
+                &SysInfo::new(),
+                None,
+            ));
+            let mut member = Member::default();
+            member.set_id(id.to_string());
+            self.member_list.insert(member, health);
+            self.update();
+        }
+
+        fn set_health(&mut self, id: &str, health: Health) {
+            self.member_list.insert_health_by_id(id, health);
+            self.update();
+        }
+
+        fn update(&mut self) {
+            self.ring.update_from_rumors(
+                &self.service_store,
+                &self.election_store,
+                &RumorStore::default(),
+                &self.member_list,
+                &RumorStore::default(),
+                &RumorStore::default(),
+            );
+        }
+
+        fn next(&self, roles: GroupRoles) -> Option<RoleAssignment> {
+            self.ring
+                .census_group_for(&self.sg)
+                .unwrap()
+                .next_role_assignment(roles)
+        }
+
+        // Publish the next assignment as the leader would, and return it.
+        fn publish(&mut self, roles: GroupRoles) -> RoleAssignment {
+            let assignment = self.next(roles).expect("the leader has an assignment to publish");
+            self.incarnation += 1;
+            let mut rumor = ServiceRumor::new(
+                "member-c".to_string(),
+                &self.pg_id,
+                &self.sg,
+                &SysInfo::new(),
+                None,
+            );
+            rumor.set_incarnation(self.incarnation);
+            rumor.set_roles(assignment.clone());
+            self.service_store.insert(rumor);
+            self.update();
+            assignment
+        }
+
+        fn group(&self) -> &CensusGroup {
+            self.ring.census_group_for(&self.sg).unwrap()
+        }
+    }
+
+    #[test]
+    fn active_standby_roles_are_published_by_the_leader() {
+        let roles = GroupRoles::ActiveStandby(2);
+        let mut fixture = RolesFixture::new(
+            roles,
+            &[
+                ("member-a", Health::Alive),
+                ("member-b", Health::Alive),
+                ("member-c", Health::Alive),
+                ("member-d", Health::Confirmed),
+            ],
+        );
+        // Nobody holds a role until the leader has published an assignment.
+        assert!(fixture.group().members().iter().all(|m| !m.active && !m.standby));
+
+        let assignment = fixture.publish(roles);
+        assert_eq!(assignment.get_version(), 1);
+        assert_eq!(assignment.get_active(), &["member-c", "member-a"]);
+        {
+            let members = fixture.group().members();
+            let held: Vec<(bool, bool)> = members.iter().map(|m| (m.active, m.standby)).collect();
+            assert_eq!(
+                held,
+                vec![(true, false), (false, true), (true, false), (false, false)]
+            );
+        }
+        assert!(fixture.next(roles).is_none());
+
+        // A member joining or being suspect doesn't move anyone's role.
+        fixture.add_member("member-0", Health::Alive);
+        fixture.set_health("member-a", Health::Suspect);
+        assert!(fixture.next(roles).is_none());
+
+        // Once it's confirmed gone, its role is handed to a standby member.
+        fixture.set_health("member-a", Health::Confirmed);
+        let assignment = fixture.publish(roles);
+        assert_eq!(assignment.get_version(), 2);
+        assert_eq!(assignment.get_active(), &["member-c", "member-0"]);
+    }
+
+    #[test]
+    fn shard_ordinals_stay_with_their_members() {
+        let roles = GroupRoles::Sharded;
+        let mut fixture = RolesFixture::new(
+            roles,
+            &[
+                ("member-b", Health::Alive),
+                ("member-c", Health::Alive),
+                ("member-d", Health::Alive),
+            ],
+        );
+        fixture.publish(roles);
+        // A member joining takes a new ordinal rather than shifting the others'.
+        fixture.add_member("member-a", Health::Alive);
+        let assignment = fixture.publish(roles);
+        assert_eq!(
+            assignment.get_shards(),
+            &["member-b", "member-c", "member-d", "member-a"]
+        );
+        {
+            let group = fixture.group();
+            let shards: Vec<Option<u32>> = group.members().iter().map(|m| m.shard).collect();
+            assert_eq!(shards, vec![Some(3), Some(0), Some(1), Some(2)]);
+            assert_eq!(group.shard_count, Some(4));
+            assert!(group.members().iter().all(|m| !m.active && !m.standby));
+        }
+
+        fixture.set_health("member-d", Health::Suspect);
+        assert!(fixture.next(roles).is_none());
+
+        // The ordinal of a member confirmed gone stays unowned until another one joins.
+        fixture.set_health("member-d", Health::Departed);
+        let assignment = fixture.publish(roles);
+        assert_eq!(
+            assignment.get_shards(),
+            &["member-b", "member-c", "", "member-a"]
+        );
+        assert_eq!(fixture.group().shard_count, Some(4));
+        fixture.add_member("member-e", Health::Alive);
+        let assignment = fixture.publish(roles);
+        assert_eq!(
+            assignment.get_shards(),
+            &["member-b", "member-c", "member-e", "member-a"]
+        );
+    }
+
+    #[test]
+    fn only_the_leader_publishes_roles() {
+        let mut fixture = RolesFixture::new(
+            GroupRoles::Sharded,
+            &[("member-a", Health::Alive), ("member-c", Health::Alive)],
+        );
+        fixture.ring = CensusRing::new("member-a".to_string());
+        fixture.ring.set_group_roles(&fixture.sg, Some(GroupRoles::Sharded));
+        fixture.update();
+        assert!(fixture.next(GroupRoles::Sharded).is_none());
+    }
 }
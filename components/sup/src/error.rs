@@ -128,6 +128,7 @@ pub enum Error {
     InvalidCompositeBinding(String),
     InvalidKeyParameter(String),
     InvalidPidFile,
+    InvalidSchedule(String),
     InvalidTopology(String),
     InvalidUpdateStrategy(String),
     Io(io::Error),
@@ -142,6 +143,7 @@ pub enum Error {
     NotifyError(notify::Error),
     NulError(ffi::NulError),
     PackageNotFound(package::PackageIdent),
+    PackageFilesChanged(package::PackageIdent, usize),
     Permissions(String),
     PidFileCorrupt(PathBuf),
     PidFileIO(PathBuf, io::Error),
@@ -249,6 +251,14 @@ impl fmt::Display for SupError {
                 format!("Invalid parameter for key generation: {:?}", e)
             }
             Error::InvalidPidFile => format!("Invalid child process PID file"),
+            Error::InvalidSchedule(ref s) => {
+                format!(
+                    "Invalid schedule \"{}\", must be five cron-style fields (minute, hour, day \
+                     of month, month, day of week) or one of @hourly, @daily, @weekly, \
+                     @monthly or @yearly",
+                    s
+                )
+            }
             Error::InvalidTopology(ref t) => format!("Invalid topology: {}", t),
             Error::InvalidUpdateStrategy(ref s) => format!("Invalid update strategy: {}", s),
             Error::Io(ref err) => format!("{}", err),
@@ -273,6 +283,13 @@ impl fmt::Display for SupError {
                     format!("Cannot find a release of package: {}", pkg)
                 }
             }
+            Error::PackageFilesChanged(ref pkg, n) => {
+                format!(
+                    "{} has {} file(s) which differ from when it was built",
+                    pkg,
+                    n
+                )
+            }
             Error::PidFileCorrupt(ref path) => {
                 format!("Unable to decode contents of PID file, {}", path.display())
             }
@@ -384,6 +401,7 @@ impl error::Error for SupError {
             Error::InvalidCompositeBinding(_) => "Invalid binding parameter",
             Error::InvalidKeyParameter(_) => "Key parameter error",
             Error::InvalidPidFile => "Invalid child process PID file",
+            Error::InvalidSchedule(_) => "Invalid periodic hook schedule",
             Error::InvalidTopology(_) => "Invalid topology",
             Error::InvalidUpdateStrategy(_) => "Invalid update strategy",
             Error::Io(ref err) => err.description(),
@@ -404,6 +422,7 @@ impl error::Error for SupError {
                 "An attempt was made to build a CString with a null byte inside it"
             }
             Error::PackageNotFound(_) => "Cannot find a package",
+            Error::PackageFilesChanged(_, _) => "Installed package files differ from the build",
             Error::Permissions(_) => "File system permissions error",
             Error::PidFileCorrupt(_) => "Unable to decode contents of PID file",
             Error::PidFileIO(_, _) => "Unable to read or write to PID file",
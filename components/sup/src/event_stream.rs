@@ -0,0 +1,163 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A live stream of the things happening inside the Supervisor, consumed by the HTTP gateway's
+//! `/events` endpoint.
+//!
+//! Events are published from wherever they happen with `publish`, and fanned out to every current
+//! subscriber already encoded as a Server-Sent Events frame. Each subscriber gets a bounded queue;
+//! a subscriber which falls too far behind is dropped rather than holding up the Supervisor, and
+//! is expected to reconnect.
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TrySendError};
+
+use serde_json;
+
+/// The number of events a subscriber may have waiting before it is dropped.
+const SUBSCRIBER_QUEUE_SIZE: usize = 256;
+
+static NEXT_EVENT_ID: AtomicUsize = ATOMIC_USIZE_INIT;
+
+lazy_static! {
+    static ref SUBSCRIBERS: Mutex<Vec<SyncSender<String>>> = Mutex::new(Vec::new());
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Event {
+    ServiceStateChanged {
+        service_group: String,
+        state: String,
+        pid: Option<u32>,
+    },
+    HealthCheckChanged {
+        service_group: String,
+        from: String,
+        to: String,
+    },
+    HookExecuted {
+        service_group: String,
+        hook: String,
+        exit_code: Option<i32>,
+    },
+    PackageUpdated {
+        service_group: String,
+        from: String,
+        to: String,
+    },
+    ElectionFinished {
+        service_group: String,
+        leader: String,
+    },
+    ConfigApplied {
+        service_group: String,
+        incarnation: u64,
+    },
+    FileApplied {
+        service_group: String,
+        filename: String,
+        incarnation: u64,
+    },
+    MemberHealthChanged { member_id: String, health: String },
+    PackagesCollected {
+        removed: Vec<String>,
+        bytes_reclaimed: u64,
+    },
+}
+
+impl Event {
+    /// The name of the event, as used for the SSE `event` field and the JSON `type` field.
+    pub fn kind(&self) -> &'static str {
+        match *self {
+            Event::ServiceStateChanged { .. } => "service_state_changed",
+            Event::HealthCheckChanged { .. } => "health_check_changed",
+            Event::HookExecuted { .. } => "hook_executed",
+            Event::PackageUpdated { .. } => "package_updated",
+            Event::ElectionFinished { .. } => "election_finished",
+            Event::ConfigApplied { .. } => "config_applied",
+            Event::FileApplied { .. } => "file_applied",
+            Event::MemberHealthChanged { .. } => "member_health_changed",
+            Event::PackagesCollected { .. } => "packages_collected",
+        }
+    }
+
+    /// Encode the event as a Server-Sent Events frame with the given id.
+    fn to_frame(&self, id: usize) -> String {
+        let data = serde_json::to_string(self).expect("Events always serialize");
+        format!("id: {}\nevent: {}\ndata: {}\n\n", id, self.kind(), data)
+    }
+}
+
+/// Send an event to every subscriber. Subscribers which have gone away, or whose queue is full,
+/// are dropped.
+pub fn publish(event: Event) {
+    let mut subscribers = SUBSCRIBERS.lock().expect("Event subscribers lock poisoned");
+    if subscribers.is_empty() {
+        return;
+    }
+    let frame = event.to_frame(NEXT_EVENT_ID.fetch_add(1, Ordering::Relaxed));
+    subscribers.retain(|tx| match tx.try_send(frame.clone()) {
+        Ok(()) => true,
+        Err(TrySendError::Full(_)) => {
+            warn!("Dropping event stream subscriber which has fallen behind");
+            false
+        }
+        Err(TrySendError::Disconnected(_)) => false,
+    });
+}
+
+/// Start receiving every event published from now on, as encoded Server-Sent Events frames.
+pub fn subscribe() -> Receiver<String> {
+    let (tx, rx) = sync_channel(SUBSCRIBER_QUEUE_SIZE);
+    SUBSCRIBERS
+        .lock()
+        .expect("Event subscribers lock poisoned")
+        .push(tx);
+    rx
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn events_are_encoded_as_typed_json() {
+        let event = Event::ElectionFinished {
+            service_group: "redis.default".to_string(),
+            leader: "abc123".to_string(),
+        };
+        assert_eq!(
+            event.to_frame(7),
+            "id: 7\nevent: election_finished\ndata: \
+             {\"type\":\"election_finished\",\"service_group\":\"redis.default\",\
+             \"leader\":\"abc123\"}\n\n"
+        );
+    }
+
+    #[test]
+    fn subscribers_receive_published_events() {
+        let rx = subscribe();
+        publish(Event::MemberHealthChanged {
+            member_id: "abc123".to_string(),
+            health: "confirmed".to_string(),
+        });
+        // Other tests may publish at the same time, so look for ours among whatever arrives.
+        let frame = rx.try_iter()
+            .find(|f| f.contains("member_health_changed"))
+            .expect("Published event was not received");
+        assert!(frame.contains("\"health\":\"confirmed\""));
+    }
+}
@@ -15,7 +15,7 @@
 use std::collections::HashMap;
 use std::fmt;
 use std::fs::File;
-use std::io::{self, Read};
+use std::io::{self, Read, Write};
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
 use std::ops::{Deref, DerefMut};
 use std::option;
@@ -23,24 +23,39 @@ use std::path::Path;
 use std::result;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
 use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
 use hcore::service::{ApplicationEnvironment, ServiceGroup};
 use iron::prelude::*;
 use iron::{headers, status, typemap};
+use iron::mime::Mime;
 use iron::modifiers::Header;
+use iron::response::WriteBody;
 use persistent;
 use prometheus::{self, CounterVec, HistogramVec, TextEncoder, Encoder};
 use router::Router;
 use serde_json::{self, Value as Json};
 
 use error::{Result, Error, SupError};
+use event_stream;
 use manager;
 use manager::service::HealthCheck;
 use manager::service::hooks::{self, HealthCheckHook};
 
 static LOGKEY: &'static str = "HG";
 const APIDOCS: &'static str = include_str!(concat!(env!("OUT_DIR"), "/api.html"));
+/// How often an idle event stream sends a comment, so that proxies and clients can tell the
+/// connection is still alive.
+const EVENT_STREAM_KEEPALIVE_SECS: u64 = 15;
+/// The most event stream clients served at once. Each one holds a worker thread for as long as
+/// it stays connected, so this is kept well below the size of the worker pool (8 threads per
+/// CPU) to leave workers free for the other endpoints.
+const EVENT_STREAM_MAX_CLIENTS: usize = 4;
+
+static EVENT_STREAM_CLIENTS: AtomicUsize = ATOMIC_USIZE_INIT;
 
 // Simple macro to encapsulate the HTTP metrics for each endpoint
 macro_rules! with_metrics {
@@ -150,6 +165,7 @@ impl Server {
             doc: get "/" => with_metrics!(doc, "doc"),
             butterfly: get "/butterfly" => with_metrics!(butterfly, "butterfly"),
             census: get "/census" => with_metrics!(census, "census"),
+            events: get "/events" => with_metrics!(events, "events"),
             metrics: get "/metrics" => with_metrics!(metrics, "metrics"),
             services: get "/services" => with_metrics!(services, "services"),
             service: get "/services/:svc/:group" => {
@@ -212,6 +228,51 @@ fn census(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// The body of an `/events` response, which writes each event as it is published until the
+/// client goes away. It holds one of the `EVENT_STREAM_MAX_CLIENTS` places until it's dropped.
+struct EventStream(Receiver<String>);
+
+impl Drop for EventStream {
+    fn drop(&mut self) {
+        EVENT_STREAM_CLIENTS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl WriteBody for EventStream {
+    fn write_body(&mut self, res: &mut Write) -> io::Result<()> {
+        let keepalive = Duration::from_secs(EVENT_STREAM_KEEPALIVE_SECS);
+        // Tell the client the stream is open before the first event arrives.
+        res.write_all(b": connected\n\n")?;
+        res.flush()?;
+        loop {
+            match self.0.recv_timeout(keepalive) {
+                Ok(frame) => res.write_all(frame.as_bytes())?,
+                Err(RecvTimeoutError::Timeout) => res.write_all(b": keepalive\n\n")?,
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+            res.flush()?;
+        }
+    }
+}
+
+fn events(_req: &mut Request) -> IronResult<Response> {
+    if EVENT_STREAM_CLIENTS.fetch_add(1, Ordering::SeqCst) >= EVENT_STREAM_MAX_CLIENTS {
+        EVENT_STREAM_CLIENTS.fetch_sub(1, Ordering::SeqCst);
+        return Ok(Response::with((
+            status::ServiceUnavailable,
+            "Too many event stream clients are connected, try again later\n",
+        )));
+    }
+    let mime: Mime = "text/event-stream".parse().unwrap();
+    let body: Box<WriteBody> = Box::new(EventStream(event_stream::subscribe()));
+    Ok(Response::with((
+        status::Ok,
+        Header(headers::ContentType(mime)),
+        Header(headers::CacheControl(vec![headers::CacheDirective::NoCache])),
+        body,
+    )))
+}
+
 fn config(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     let service_group = match build_service_group(req) {
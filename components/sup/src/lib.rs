@@ -98,6 +98,7 @@ pub mod command;
 pub mod config;
 pub mod census;
 pub mod error;
+pub mod event_stream;
 pub mod fs;
 pub mod http_gateway;
 pub mod manager;
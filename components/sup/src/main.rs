@@ -183,6 +183,8 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 [default: https://bldr.habitat.sh]")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
                 "Service topology; [default: none]")
+            (@arg ACTIVE_COUNT: --("active-count") +takes_value {valid_active_count}
+                "Number of active members in an active-standby topology [default: 1]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
                 "The update strategy; [default: none] [values: none, at-once, rolling]")
             (@arg BIND: --bind +takes_value +multiple
@@ -226,6 +228,12 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 [default: https://bldr.habitat.sh]")
             (@arg AUTO_UPDATE: --("auto-update") -A "Enable automatic updates for the Supervisor \
                 itself")
+            (@arg KEEP_RELEASES: --("keep-releases") +takes_value {valid_keep_releases}
+                "Periodically remove old package releases, keeping this many of each package \
+                [default: keep all]")
+            (@arg VERIFY_PACKAGES: --("verify-packages")
+                "Check the files of a service's packages against the checksums recorded when they \
+                were built, and refuse to start the service if any differ")
             (@arg EVENTS: --events -n +takes_value {valid_service_group} "Name of the service \
                 group running a Habitat EventSrv to forward Supervisor and service event data to")
         )
@@ -271,6 +279,8 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 [default: https://bldr.habitat.sh]")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
                 "Service topology; [default: none]")
+            (@arg ACTIVE_COUNT: --("active-count") +takes_value {valid_active_count}
+                "Number of active members in an active-standby topology [default: 1]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
                 "The update strategy; [default: none] [values: none, at-once, rolling]")
             (@arg BIND: --bind +takes_value +multiple
@@ -279,6 +289,12 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Use package config from this path, rather than the package itself")
             (@arg AUTO_UPDATE: --("auto-update") -A "Enable automatic updates for the Supervisor \
                 itself")
+            (@arg KEEP_RELEASES: --("keep-releases") +takes_value {valid_keep_releases}
+                "Periodically remove old package releases, keeping this many of each package \
+                [default: keep all]")
+            (@arg VERIFY_PACKAGES: --("verify-packages")
+                "Check the files of a service's packages against the checksums recorded when they \
+                were built, and refuse to start the service if any differ")
             (@arg EVENTS: --events -n +takes_value {valid_service_group} "Name of the service \
                 group running a Habitat EventSrv to forward Supervisor and service event data to")
         )
@@ -350,6 +366,8 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 [default: https://bldr.habitat.sh]")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
                 "Service topology; [default: none]")
+            (@arg ACTIVE_COUNT: --("active-count") +takes_value {valid_active_count}
+                "Number of active members in an active-standby topology [default: 1]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
                 "The update strategy; [default: none] [values: none, at-once, rolling]")
             (@arg BIND: --bind +takes_value +multiple
@@ -395,6 +413,12 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 [default: https://bldr.habitat.sh]")
             (@arg AUTO_UPDATE: --("auto-update") -A "Enable automatic updates for the Supervisor \
                 itself")
+            (@arg KEEP_RELEASES: --("keep-releases") +takes_value {valid_keep_releases}
+                "Periodically remove old package releases, keeping this many of each package \
+                [default: keep all]")
+            (@arg VERIFY_PACKAGES: --("verify-packages")
+                "Check the files of a service's packages against the checksums recorded when they \
+                were built, and refuse to start the service if any differ")
             (@arg EVENTS: --events -n +takes_value {valid_service_group} "Name of the service \
                 group running a Habitat EventSrv to forward Supervisor and service event data to")
         )
@@ -440,6 +464,8 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 [default: https://bldr.habitat.sh]")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
                 "Service topology; [default: none]")
+            (@arg ACTIVE_COUNT: --("active-count") +takes_value {valid_active_count}
+                "Number of active members in an active-standby topology [default: 1]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
                 "The update strategy; [default: none] [values: none, at-once, rolling]")
             (@arg BIND: --bind +takes_value +multiple
@@ -448,6 +474,12 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Use package config from this path, rather than the package itself")
             (@arg AUTO_UPDATE: --("auto-update") -A "Enable automatic updates for the Supervisor \
                 itself")
+            (@arg KEEP_RELEASES: --("keep-releases") +takes_value {valid_keep_releases}
+                "Periodically remove old package releases, keeping this many of each package \
+                [default: keep all]")
+            (@arg VERIFY_PACKAGES: --("verify-packages")
+                "Check the files of a service's packages against the checksums recorded when they \
+                were built, and refuse to start the service if any differ")
             (@arg EVENTS: --events -n +takes_value {valid_service_group} "Name of the service \
                 group running a Habitat EventSrv to forward Supervisor and service event data to")
             (@arg PASSWORD: --password +takes_value "Password of the service user")
@@ -963,6 +995,8 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
     let mut cfg = ManagerConfig::default();
 
     cfg.auto_update = m.is_present("AUTO_UPDATE");
+    cfg.keep_releases = m.value_of("KEEP_RELEASES").map(|k| k.parse().unwrap());
+    cfg.verify_packages = m.is_present("VERIFY_PACKAGES");
     cfg.update_url = bldr_url(m);
     cfg.update_channel = channel(m);
     if let Some(addr_str) = m.value_of("LISTEN_GOSSIP") {
@@ -1142,6 +1176,11 @@ fn set_topology_from_input(spec: &mut ServiceSpec, m: &ArgMatches) {
         // `valid_topology`
         spec.topology = Topology::from_str(t).unwrap();
     }
+    if let Some(c) = m.value_of("ACTIVE_COUNT") {
+        // unwrap() is safe, because the input is validated by
+        // `valid_active_count`
+        spec.active_count = c.parse().unwrap();
+    }
 }
 
 /// Set an update strategy only if specified by the user as a CLI
@@ -1434,6 +1473,20 @@ fn valid_topology(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_active_count(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(count) if count > 0 => Ok(()),
+        _ => Err(format!("Active count: '{}' is not a positive number", &val)),
+    }
+}
+
+fn valid_keep_releases(val: String) -> result::Result<(), String> {
+    match val.parse::<usize>() {
+        Ok(keep) if keep > 0 => Ok(()),
+        _ => Err(format!("Keep releases: '{}' is not a positive number", &val)),
+    }
+}
+
 fn valid_listen_gossip(val: String) -> result::Result<(), String> {
     match GossipListenAddr::from_str(&val) {
         Ok(_) => Ok(()),
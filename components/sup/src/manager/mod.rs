@@ -16,6 +16,7 @@ pub mod service;
 #[macro_use]
 mod debug;
 mod events;
+mod package_gc;
 mod periodic;
 mod self_updater;
 mod service_updater;
@@ -38,9 +39,11 @@ use std::time::Duration;
 
 use butterfly;
 use butterfly::member::Member;
+use butterfly::rumor::service::RoleAssignment;
 use butterfly::trace::Trace;
 use butterfly::server::timing::Timing;
 use butterfly::server::Suitability;
+use hcore;
 use hcore::crypto::{default_cache_key_path, SymKey};
 use hcore::env;
 use hcore::fs::FS_ROOT_PATH;
@@ -54,6 +57,7 @@ use time::{self, Timespec, Duration as TimeDuration};
 
 pub use self::service::{CompositeSpec, Service, ServiceBind, ServiceSpec, UpdateStrategy, Topology};
 pub use self::sys::Sys;
+use self::package_gc::PackageGc;
 use self::self_updater::{SUP_PKG_IDENT, SelfUpdater};
 use self::service::{DesiredState, Pkg, ProcessState, StartStyle};
 use self::service_updater::ServiceUpdater;
@@ -67,6 +71,8 @@ use http_gateway;
 
 const MEMBER_ID_FILE: &'static str = "MEMBER_ID";
 const PROC_LOCK_FILE: &'static str = "LOCK";
+const LEADER_HANDOFF_TIMEOUT_ENVVAR: &'static str = "HAB_LEADER_HANDOFF_TIMEOUT_MS";
+const DEFAULT_LEADER_HANDOFF_TIMEOUT: u64 = 30_000;
 
 static LOGKEY: &'static str = "MR";
 
@@ -134,6 +140,8 @@ pub struct ManagerConfig {
     pub name: Option<String>,
     pub organization: Option<String>,
     pub watch_peer_file: Option<String>,
+    pub keep_releases: Option<usize>,
+    pub verify_packages: bool,
 
     custom_state_path: Option<PathBuf>,
 }
@@ -149,9 +157,23 @@ pub struct Manager {
     watcher: SpecWatcher,
     organization: Option<String>,
     self_updater: Option<SelfUpdater>,
+    package_gc: Option<PackageGc>,
+    verify_packages: bool,
     service_states: HashMap<PackageIdent, Timespec>,
     sys: Arc<Sys>,
     peer_watcher: Option<PeerWatcher>,
+    leader_handoffs: HashMap<ServiceGroup, LeaderHandoff>,
+    stopping: bool,
+}
+
+/// A handoff of leadership which the main loop checks on until the group has elected a new
+/// leader or the handoff has timed out.
+struct LeaderHandoff {
+    term: u64,
+    started: time::SteadyTime,
+    /// Whether the service is stopped once the handoff is over, as it is when its spec was
+    /// unloaded. On a Supervisor shutdown, `shutdown` takes care of that.
+    remove: bool,
 }
 
 impl Manager {
@@ -312,6 +334,8 @@ impl Manager {
         };
         Ok(Manager {
             self_updater: self_updater,
+            package_gc: cfg.keep_releases.map(PackageGc::new),
+            verify_packages: cfg.verify_packages,
             updater: ServiceUpdater::new(server.clone()),
             census_ring: CensusRing::new(sys.member_id.clone()),
             butterfly: server,
@@ -324,6 +348,8 @@ impl Manager {
             service_states: HashMap::new(),
             sys: Arc::new(sys),
             peer_watcher: peer_watcher,
+            leader_handoffs: HashMap::new(),
+            stopping: false,
         })
     }
 
@@ -498,6 +524,16 @@ impl Manager {
             }
         };
 
+        if self.verify_packages {
+            if let Err(err) = verify_packages(&service.pkg) {
+                outputln!("Unable to start {}, {}", &spec.ident, err);
+                if spec.start_style == StartStyle::Transient {
+                    self.remove_spec(&spec);
+                }
+                return;
+            }
+        }
+
         if let Err(e) = service.create_svc_path() {
             outputln!(
                 "Can't create directory {}: {}",
@@ -514,12 +550,17 @@ impl Manager {
         }
 
         self.gossip_latest_service_rumor(&service);
-        if service.topology == Topology::Leader {
+        if service.topology.has_election() {
+            self.butterfly.end_abdication(&service.service_group);
             self.butterfly.start_election(
                 service.service_group.clone(),
                 0,
             );
         }
+        self.census_ring.set_group_roles(
+            &service.service_group,
+            service.topology.group_roles(service.active_count),
+        );
         self.updater.add(&service);
         self.services
             .write()
@@ -548,9 +589,17 @@ impl Manager {
         };
         loop {
             let next_check = time::get_time() + TimeDuration::milliseconds(1000);
-            if self.launcher.is_stopping() {
-                self.shutdown();
-                return Ok(());
+            // The launcher only tells us to stop once, but we keep running until every group we
+            // lead has a new leader.
+            if self.stopping || self.launcher.is_stopping() {
+                if !self.stopping {
+                    self.stopping = true;
+                    self.hand_off_all_leadership();
+                }
+                if self.leader_handoffs.is_empty() {
+                    self.shutdown();
+                    return Ok(());
+                }
             }
             if self.check_for_departure() {
                 self.shutdown();
@@ -564,10 +613,14 @@ impl Manager {
                 self.shutdown();
                 return Ok(());
             }
-            self.update_running_services_from_watcher()?;
+            if !self.stopping {
+                self.update_running_services_from_watcher()?;
+                self.check_for_updated_packages();
+                self.collect_package_garbage();
+            }
             self.update_peers_from_watch_file()?;
-            self.check_for_updated_packages();
             self.restart_elections();
+            self.check_leader_handoffs();
             self.census_ring.update_from_rumors(
                 &self.butterfly.service_store,
                 &self.butterfly.election_store,
@@ -577,6 +630,8 @@ impl Manager {
                 &self.butterfly.service_file_store,
             );
 
+            self.publish_role_assignments();
+
             if self.check_for_changed_services() {
                 self.persist_state();
             }
@@ -650,8 +705,60 @@ impl Manager {
         }
     }
 
+    /// Remove old releases of installed packages, if a retention policy was given and a run is
+    /// due. Nothing the loaded services or this Supervisor run from is ever removed, including
+    /// the releases of services which are loaded but stopped.
+    fn collect_package_garbage(&mut self) {
+        if !self.package_gc.as_ref().map_or(false, |gc| gc.is_due()) {
+            return;
+        }
+        let mut in_use: Vec<PackageIdent> = self.services
+            .read()
+            .expect("Services lock is poisoned!")
+            .iter()
+            .map(|service| service.pkg.ident.clone())
+            .collect();
+        // A stopped service has no `Service`, only its spec, and it needs its release to start
+        // again. If we can't tell which releases those are, it's safer not to remove anything.
+        let spec_files = match SpecWatcher::spec_files(&self.fs_cfg.specs_path) {
+            Ok(spec_files) => spec_files,
+            Err(err) => {
+                outputln!("Skipping package garbage collection, {}", err);
+                return;
+            }
+        };
+        for spec_file in spec_files {
+            match ServiceSpec::from_file(&spec_file) {
+                Ok(spec) => in_use.push(spec.ident),
+                Err(err) => {
+                    outputln!(
+                        "Skipping package garbage collection; unable to read service spec, {}, {}",
+                        spec_file.display(),
+                        err
+                    );
+                    return;
+                }
+            }
+        }
+        in_use.push(
+            PackageIdent::from_str(&format!("{}/{}", SUP_PKG_IDENT, VERSION)).unwrap(),
+        );
+        if let Some(ref mut gc) = self.package_gc {
+            if let Err(err) = gc.run(&in_use) {
+                outputln!("Package garbage collection failed, {}", err);
+            }
+        }
+    }
+
     fn gossip_latest_service_rumor(&self, service: &Service) {
+        self.gossip_service_rumor(service, None);
+    }
+
+    /// Gossip a new service rumor for the given service. Any role assignment we published for its
+    /// group before is carried over, unless a new one is given.
+    fn gossip_service_rumor(&self, service: &Service, roles: Option<RoleAssignment>) {
         let mut incarnation = 1;
+        let mut roles = roles;
         {
             let list = self.butterfly.service_store.list.read().expect(
                 "Rumor store lock poisoned",
@@ -661,9 +768,41 @@ impl Manager {
             })
             {
                 incarnation = rumor.clone().get_incarnation() + 1;
+                if roles.is_none() && rumor.has_roles() {
+                    roles = Some(rumor.get_roles().clone());
+                }
             }
         }
-        self.butterfly.insert_service(service.to_rumor(incarnation));
+        let mut rumor = service.to_rumor(incarnation);
+        if let Some(roles) = roles {
+            rumor.set_roles(roles);
+        }
+        self.butterfly.insert_service(rumor);
+    }
+
+    /// Publish a new role assignment for every group with roles that we lead, when the one in
+    /// effect is missing or out of date. It's carried in our own service rumor, which the rest
+    /// of the group reads it from.
+    fn publish_role_assignments(&self) {
+        for service in self.services
+            .read()
+            .expect("Services lock is poisoned!")
+            .iter()
+        {
+            let roles = match service.topology.group_roles(service.active_count) {
+                Some(roles) => roles,
+                None => continue,
+            };
+            let assignment = match self.census_ring
+                .census_group_for(&service.service_group)
+                .and_then(|census_group| census_group.next_role_assignment(roles)) {
+                Some(assignment) => assignment,
+                None => continue,
+            };
+            outputln!(preamble service.service_group,
+                "Publishing role assignment {}", assignment.get_version());
+            self.gossip_service_rumor(service, Some(assignment));
+        }
     }
 
     fn check_for_departure(&self) -> bool {
@@ -879,6 +1018,93 @@ impl Manager {
         self.butterfly.restart_elections();
     }
 
+    /// Start handing leadership of a service group this Supervisor leads to another member. The
+    /// main loop carries on while the group elects a new leader, and `check_leader_handoffs`
+    /// finishes the handoff once it has, or after `HAB_LEADER_HANDOFF_TIMEOUT_MS` so a
+    /// partitioned ring can't hold up a shutdown forever.
+    ///
+    /// Returns `true` if a handoff was started.
+    fn start_leader_handoff(&mut self, service_group: &ServiceGroup, remove: bool) -> bool {
+        let term = match self.butterfly.abdicate(service_group) {
+            Some(term) => term,
+            None => {
+                if self.butterfly.elected_leader(service_group, 0).as_ref().map(|l| &l[..]) ==
+                    Some(self.butterfly.member_id())
+                {
+                    outputln!(preamble service_group,
+                              "Unable to hand off leadership; no quorum or no other members");
+                }
+                return false;
+            }
+        };
+        outputln!(preamble service_group, "Handing off leadership");
+        self.leader_handoffs.insert(
+            service_group.clone(),
+            LeaderHandoff {
+                term: term,
+                started: time::SteadyTime::now(),
+                remove: remove,
+            },
+        );
+        true
+    }
+
+    /// Finish the leadership handoffs which are over, either because a new leader was elected or
+    /// because they timed out. The old leader's service is demoted if another member took over,
+    /// and stopped if its spec was unloaded.
+    fn check_leader_handoffs(&mut self) {
+        if self.leader_handoffs.is_empty() {
+            return;
+        }
+        let timeout = leader_handoff_timeout();
+        let mut finished = vec![];
+        for (service_group, handoff) in self.leader_handoffs.iter() {
+            if let Some(leader) = self.butterfly.elected_leader(service_group, handoff.term) {
+                outputln!(preamble service_group, "Handed off leadership to {}", leader);
+                finished.push((service_group.clone(), true));
+            } else if time::SteadyTime::now() - handoff.started >= timeout {
+                outputln!(preamble service_group,
+                          "Timed out waiting for a new leader to be elected; stopping anyway");
+                finished.push((service_group.clone(), false));
+            }
+        }
+        for (service_group, handed_off) in finished {
+            let remove = match self.leader_handoffs.remove(&service_group) {
+                Some(handoff) => handoff.remove,
+                None => continue,
+            };
+            let mut services = self.services.write().expect("Services lock is poisoned");
+            let idx = match services.iter().position(|s| s.service_group == service_group) {
+                Some(idx) => idx,
+                None => continue,
+            };
+            if handed_off {
+                services[idx].demote();
+            }
+            if remove {
+                let mut service = services.remove(idx);
+                self.remove_service(&mut service, true);
+            }
+        }
+    }
+
+    /// Start handing off leadership of every group this Supervisor leads, before its services
+    /// are stopped.
+    fn hand_off_all_leadership(&mut self) {
+        let service_groups: Vec<ServiceGroup> = self.services
+            .read()
+            .expect("Services lock is poisoned!")
+            .iter()
+            .filter(|s| {
+                s.topology.has_election() && !self.leader_handoffs.contains_key(&s.service_group)
+            })
+            .map(|s| s.service_group.clone())
+            .collect();
+        for service_group in service_groups {
+            self.start_leader_handoff(&service_group, false);
+        }
+    }
+
     fn shutdown(&self) {
         outputln!("Gracefully departing from butterfly network.");
         self.butterfly.set_departed();
@@ -948,6 +1174,21 @@ impl Manager {
     }
 
     fn remove_service_for_spec(&mut self, spec: &ServiceSpec) -> Result<()> {
+        // A leader hands off leadership before its service is stopped, which
+        // `check_leader_handoffs` does once the group has a new leader.
+        let leader_group = self.services
+            .read()
+            .expect("Services lock is poisoned")
+            .iter()
+            .find(|s| s.spec_ident == spec.ident && s.topology.has_election())
+            .map(|s| s.service_group.clone());
+        if let Some(service_group) = leader_group {
+            if self.leader_handoffs.contains_key(&service_group) ||
+                self.start_leader_handoff(&service_group, true)
+            {
+                return Ok(());
+            }
+        }
         let mut services = self.services.write().expect("Services lock is poisoned");
         // TODO fn: storing services as a `Vec` is a bit crazy when you have to do these
         // shenanigans--maybe we want to consider changing the data structure in the future?
@@ -978,6 +1219,62 @@ impl Manager {
     }
 }
 
+/// How long a leader waits for its successor to be elected before stopping anyway.
+fn leader_handoff_timeout() -> TimeDuration {
+    let millis = match env::var(LEADER_HANDOFF_TIMEOUT_ENVVAR) {
+        Ok(val) => {
+            match val.parse::<u64>() {
+                Ok(num) => num,
+                Err(_) => {
+                    outputln!(
+                        "Unable to parse '{}' from {} as a valid integer. Falling back to \
+                         default {} MS timeout.",
+                        val,
+                        LEADER_HANDOFF_TIMEOUT_ENVVAR,
+                        DEFAULT_LEADER_HANDOFF_TIMEOUT
+                    );
+                    DEFAULT_LEADER_HANDOFF_TIMEOUT
+                }
+            }
+        }
+        Err(_) => DEFAULT_LEADER_HANDOFF_TIMEOUT,
+    };
+    TimeDuration::milliseconds(millis as i64)
+}
+
+/// Check the files of a service's package and all of its dependencies against the checksums
+/// recorded when they were built. Packages built before those were recorded are let through.
+fn verify_packages(pkg: &Pkg) -> Result<()> {
+    let fs_root_path = Path::new(&*FS_ROOT_PATH);
+    for ident in Some(&pkg.ident).into_iter().chain(pkg.deps.iter()) {
+        let pkg_install = PackageInstall::load(ident, Some(fs_root_path))?;
+        let report = match pkg_install.verify_files() {
+            Ok(report) => report,
+            Err(hcore::Error::MetaFileNotFound(_)) => {
+                debug!("{} has no recorded file checksums, skipping verification", ident);
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+        for path in report.modified.iter() {
+            outputln!("Modified file {}", path.display());
+        }
+        for path in report.missing.iter() {
+            outputln!("Missing file {}", path.display());
+        }
+        for path in report.extra.iter() {
+            outputln!("Extra file {}", path.display());
+        }
+        if !report.is_ok() {
+            return Err(sup_error!(Error::PackageFilesChanged(
+                ident.clone(),
+                report.modified.len() + report.missing.len() + report.extra.len(),
+            )));
+        }
+    }
+    Ok(())
+}
+
 #[derive(Deserialize)]
 pub struct ProcessStatus {
     #[serde(deserialize_with = "deserialize_time", rename = "state_entered")]
@@ -0,0 +1,295 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Removes old releases of installed packages, so that a long running Supervisor which keeps
+//! updating its services doesn't fill up the disk.
+//!
+//! Releases are grouped by origin and name, and only the newest `keep` releases of each package
+//! are kept. A release is never removed if a loaded service, the Supervisor or a binlink still
+//! refers to it, whether directly or as a transitive dependency.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use hcore::binlink::default_binlink_dir;
+use hcore::fs::{cache_artifact_path, pkg_install_path, pkg_root_path, FS_ROOT_PATH};
+use hcore::package::{Identifiable, PackageIdent, PackageInstall};
+use time::SteadyTime;
+
+use env;
+use error::Result;
+use event_stream::{self, Event};
+use manager::periodic::Periodic;
+
+static LOGKEY: &'static str = "GC";
+const DEFAULT_FREQUENCY: i64 = 3_600_000;
+const FREQUENCY_ENVVAR: &'static str = "HAB_PKG_GC_MS";
+/// The launcher can't tell us which release it is running from, so none of its releases are ever
+/// removed.
+const LAUNCHER_PKG_IDENT: &'static str = "core/hab-launcher";
+
+/// What a garbage collection run removed.
+#[derive(Debug, Default)]
+pub struct GcReport {
+    pub removed: Vec<PackageIdent>,
+    pub bytes_reclaimed: u64,
+}
+
+pub struct PackageGc {
+    keep: usize,
+    next_run: SteadyTime,
+}
+
+impl Periodic for PackageGc {
+    fn update_period(&self) -> i64 {
+        match env::var(FREQUENCY_ENVVAR) {
+            Ok(val) => val.parse().unwrap_or(DEFAULT_FREQUENCY),
+            Err(_) => DEFAULT_FREQUENCY,
+        }
+    }
+}
+
+impl PackageGc {
+    /// Keep the newest `keep` releases of every installed package. At least one release of each
+    /// package is always kept.
+    pub fn new(keep: usize) -> Self {
+        let mut gc = PackageGc {
+            keep: keep.max(1),
+            next_run: SteadyTime::now(),
+        };
+        // Wait a full period before the first run, so that services have been loaded and had a
+        // chance to update before we decide what is still needed.
+        gc.next_run = gc.next_period_start();
+        gc
+    }
+
+    /// Whether enough time has passed since the last run to start a new one.
+    pub fn is_due(&self) -> bool {
+        SteadyTime::now() >= self.next_run
+    }
+
+    /// Remove every release that is neither among the newest `keep` releases of its package nor
+    /// referenced by one of the `in_use` packages or their transitive dependencies.
+    pub fn run(&mut self, in_use: &[PackageIdent]) -> Result<GcReport> {
+        self.next_run = self.next_period_start();
+        let fs_root_path = Path::new(&*FS_ROOT_PATH);
+        let binlinked = binlinked_packages(fs_root_path);
+        let mut pinned = HashSet::new();
+        for ident in in_use.iter().chain(binlinked.iter()) {
+            pin(ident, fs_root_path, &mut pinned);
+        }
+        let launcher: PackageIdent = LAUNCHER_PKG_IDENT.parse().unwrap();
+        let installed = PackageInstall::all(Some(fs_root_path))?;
+        let mut report = GcReport::default();
+        for ident in select_for_removal(installed, self.keep, &pinned) {
+            if ident.satisfies(&launcher) {
+                continue;
+            }
+            match remove_release(&ident, fs_root_path) {
+                Ok(bytes) => {
+                    report.bytes_reclaimed += bytes;
+                    report.removed.push(ident);
+                }
+                Err(err) => outputln!("Unable to remove {}, {}", ident, err),
+            }
+        }
+        if !report.removed.is_empty() {
+            outputln!(
+                "Removed {} old package release(s), reclaiming {} bytes",
+                report.removed.len(),
+                report.bytes_reclaimed
+            );
+            event_stream::publish(Event::PackagesCollected {
+                removed: report.removed.iter().map(|i| i.to_string()).collect(),
+                bytes_reclaimed: report.bytes_reclaimed,
+            });
+        }
+        Ok(report)
+    }
+}
+
+/// Add a package and all of its transitive dependencies to the set of pinned releases. An ident
+/// which isn't fully qualified, such as the one in a service spec, pins the newest installed
+/// release it matches. Packages which aren't installed pin nothing.
+fn pin(ident: &PackageIdent, fs_root_path: &Path, pinned: &mut HashSet<PackageIdent>) {
+    if let Ok(package) = PackageInstall::load(ident, Some(fs_root_path)) {
+        if let Ok(tdeps) = package.tdeps() {
+            pinned.extend(tdeps);
+        }
+        pinned.insert(package.ident().clone());
+    }
+}
+
+/// Returns the releases of every package which has a binlink pointing into it.
+fn binlinked_packages(fs_root_path: &Path) -> Vec<PackageIdent> {
+    let binlink_dir = fs_root_path.join(default_binlink_dir().trim_left_matches('/'));
+    let package_root = pkg_root_path(Some(fs_root_path));
+    let mut idents = vec![];
+    let entries = match fs::read_dir(&binlink_dir) {
+        Ok(entries) => entries,
+        Err(_) => return idents,
+    };
+    for entry in entries.filter_map(|e| e.ok()) {
+        if let Ok(target) = fs::read_link(entry.path()) {
+            if let Some(ident) = ident_from_install_path(&target, &package_root) {
+                idents.push(ident);
+            }
+        }
+    }
+    idents
+}
+
+/// Returns the ident of the package release a path inside the package root belongs to.
+fn ident_from_install_path(path: &Path, package_root: &Path) -> Option<PackageIdent> {
+    let relative = match path.strip_prefix(package_root) {
+        Ok(relative) => relative,
+        Err(_) => return None,
+    };
+    let parts: Vec<String> = relative
+        .iter()
+        .take(4)
+        .map(|part| part.to_string_lossy().into_owned())
+        .collect();
+    if parts.len() < 4 {
+        return None;
+    }
+    Some(PackageIdent::new(
+        parts[0].clone(),
+        parts[1].clone(),
+        Some(parts[2].clone()),
+        Some(parts[3].clone()),
+    ))
+}
+
+/// Given every installed release, returns the ones which may be removed: everything but the
+/// newest `keep` releases of each package, and anything pinned.
+fn select_for_removal(
+    installed: Vec<PackageIdent>,
+    keep: usize,
+    pinned: &HashSet<PackageIdent>,
+) -> Vec<PackageIdent> {
+    let mut by_package: HashMap<(String, String), Vec<PackageIdent>> = HashMap::new();
+    for ident in installed {
+        by_package
+            .entry((ident.origin.clone(), ident.name.clone()))
+            .or_insert(vec![])
+            .push(ident);
+    }
+    let mut removable = vec![];
+    for (_, mut releases) in by_package {
+        releases.sort_by(|a, b| b.cmp(a));
+        removable.extend(releases.into_iter().skip(keep).filter(
+            |ident| !pinned.contains(ident),
+        ));
+    }
+    removable.sort();
+    removable
+}
+
+/// Remove an installed release and its cached artifact, returning the number of bytes freed.
+fn remove_release(ident: &PackageIdent, fs_root_path: &Path) -> io::Result<u64> {
+    let install_path = pkg_install_path(ident, Some(fs_root_path));
+    let mut bytes = disk_usage(&install_path)?;
+    fs::remove_dir_all(&install_path)?;
+    // Leave no empty version directory behind once its last release is gone.
+    if let Some(version_path) = install_path.parent() {
+        let _ = fs::remove_dir(version_path);
+    }
+    if let Some(archive_name) = ident.archive_name() {
+        let archive: PathBuf = cache_artifact_path(Some(fs_root_path)).join(archive_name);
+        if let Ok(metadata) = fs::metadata(&archive) {
+            fs::remove_file(&archive)?;
+            bytes += metadata.len();
+        }
+    }
+    Ok(bytes)
+}
+
+fn disk_usage(path: &Path) -> io::Result<u64> {
+    let metadata = fs::symlink_metadata(path)?;
+    if !metadata.is_dir() {
+        return Ok(metadata.len());
+    }
+    let mut total = 0;
+    for entry in fs::read_dir(path)? {
+        total += disk_usage(&entry?.path())?;
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+    use std::path::Path;
+
+    use hcore::package::PackageIdent;
+
+    use super::*;
+
+    fn idents(idents: &[&str]) -> Vec<PackageIdent> {
+        idents.iter().map(|i| i.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn select_for_removal_keeps_newest_releases() {
+        let installed = idents(
+            &[
+                "core/redis/3.2.4/20170514150022",
+                "core/redis/3.2.4/20170622210423",
+                "core/redis/3.2.10/20170810120000",
+                "core/nginx/1.11.10/20170513215034",
+            ],
+        );
+        let removable = select_for_removal(installed, 2, &HashSet::new());
+        assert_eq!(removable, idents(&["core/redis/3.2.4/20170514150022"]));
+    }
+
+    #[test]
+    fn select_for_removal_never_removes_pinned_releases() {
+        let installed = idents(
+            &[
+                "core/redis/3.2.4/20170514150022",
+                "core/redis/3.2.4/20170622210423",
+                "core/redis/3.2.10/20170810120000",
+            ],
+        );
+        let pinned = idents(&["core/redis/3.2.4/20170514150022"])
+            .into_iter()
+            .collect();
+        let removable = select_for_removal(installed, 1, &pinned);
+        assert_eq!(removable, idents(&["core/redis/3.2.4/20170622210423"]));
+    }
+
+    #[test]
+    fn ident_from_install_path_finds_the_release() {
+        let root = Path::new("/hab/pkgs");
+        assert_eq!(
+            ident_from_install_path(
+                Path::new("/hab/pkgs/core/hab/0.30.2/20170822223054/bin/hab"),
+                root,
+            ),
+            Some("core/hab/0.30.2/20170822223054".parse().unwrap())
+        );
+        assert_eq!(
+            ident_from_install_path(Path::new("/usr/local/bin/hab"), root),
+            None
+        );
+        assert_eq!(
+            ident_from_install_path(Path::new("/hab/pkgs/core/hab"), root),
+            None
+        );
+    }
+}
@@ -13,11 +13,15 @@
 // limitations under the License.
 
 use std;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::io::BufReader;
 use std::io::prelude::*;
 use std::fs::File;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
 #[cfg(not(windows))]
 use std::process::{Child, ExitStatus};
 #[cfg(windows)]
@@ -28,14 +32,18 @@ use hcore;
 use hcore::crypto;
 use hcore::service::ServiceGroup;
 use serde::{Serialize, Serializer};
+use time::Tm;
 
-use super::{health, Pkg};
+use super::{health, PeriodicHookSpec, Pkg, Schedule};
 use error::{Result, SupError};
+use event_stream::{self, Event};
 use fs;
 use templating::{RenderContext, TemplateRenderer};
 use util::exec;
 
 pub const HOOK_PERMISSIONS: u32 = 0o755;
+/// Directory, relative to a package's hooks, in which periodic hook templates live.
+pub const PERIODIC_HOOKS_DIR: &'static str = "periodic";
 static LOGKEY: &'static str = "HK";
 
 pub fn stdout_log_path<T>(service_group: &ServiceGroup) -> PathBuf
@@ -136,7 +144,14 @@ pub trait Hook: fmt::Debug + Sized {
         let mut hook_output = HookOutput::new(self.stdout_log_path(), self.stderr_log_path());
         hook_output.stream_output::<Self>(service_group, &mut child);
         match child.wait() {
-            Ok(status) => self.handle_exit(service_group, &hook_output, &status),
+            Ok(status) => {
+                event_stream::publish(Event::HookExecuted {
+                    service_group: service_group.to_string(),
+                    hook: Self::file_name().to_string(),
+                    exit_code: status.code(),
+                });
+                self.handle_exit(service_group, &hook_output, &status)
+            }
             Err(err) => {
                 outputln!(preamble service_group,
                     "Hook failed to run, {}, {}", Self::file_name(), err);
@@ -768,6 +783,368 @@ impl Hook for PostStopHook {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct PromoteHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+}
+
+impl Hook for PromoteHook {
+    type ExitValue = bool;
+
+    fn file_name() -> &'static str {
+        "promote"
+    }
+
+    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+        PromoteHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+        }
+    }
+
+    fn handle_exit<'a>(
+        &self,
+        service_group: &ServiceGroup,
+        _: &'a HookOutput,
+        status: &ExitStatus,
+    ) -> Self::ExitValue {
+        match status.code() {
+            Some(0) => true,
+            Some(code) => {
+                outputln!(preamble service_group, "Promote failed! '{}' exited with \
+                    status code {}", Self::file_name(), code);
+                false
+            }
+            None => {
+                outputln!(preamble service_group, "Promote failed! '{}' exited without a \
+                    status code", Self::file_name());
+                false
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn renderer(&self) -> &TemplateRenderer {
+        &self.render_pair.renderer
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DemoteHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+}
+
+impl Hook for DemoteHook {
+    type ExitValue = bool;
+
+    fn file_name() -> &'static str {
+        "demote"
+    }
+
+    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+        DemoteHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+        }
+    }
+
+    fn handle_exit<'a>(
+        &self,
+        service_group: &ServiceGroup,
+        _: &'a HookOutput,
+        status: &ExitStatus,
+    ) -> Self::ExitValue {
+        match status.code() {
+            Some(0) => true,
+            Some(code) => {
+                outputln!(preamble service_group, "Demote failed! '{}' exited with \
+                    status code {}", Self::file_name(), code);
+                false
+            }
+            None => {
+                outputln!(preamble service_group, "Demote failed! '{}' exited without a \
+                    status code", Self::file_name());
+                false
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn renderer(&self) -> &TemplateRenderer {
+        &self.render_pair.renderer
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReshardHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+}
+
+impl Hook for ReshardHook {
+    type ExitValue = bool;
+
+    fn file_name() -> &'static str {
+        "reshard"
+    }
+
+    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+        ReshardHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+        }
+    }
+
+    fn handle_exit<'a>(
+        &self,
+        service_group: &ServiceGroup,
+        _: &'a HookOutput,
+        status: &ExitStatus,
+    ) -> Self::ExitValue {
+        match status.code() {
+            Some(0) => true,
+            Some(code) => {
+                outputln!(preamble service_group, "Reshard failed! '{}' exited with \
+                    status code {}", Self::file_name(), code);
+                false
+            }
+            None => {
+                outputln!(preamble service_group, "Reshard failed! '{}' exited without a \
+                    status code", Self::file_name());
+                false
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn renderer(&self) -> &TemplateRenderer {
+        &self.render_pair.renderer
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+}
+
+/// A hook which is run on a cron-style schedule rather than in response to a lifecycle event.
+///
+/// Unlike the lifecycle hooks, there can be any number of periodic hooks for a service, so they
+/// are identified by name rather than by type. Runs happen on their own thread so a long running
+/// backup doesn't hold up the rest of the Supervisor, and a hook is never run again while a
+/// previous run of it is still in progress.
+#[derive(Debug, Serialize)]
+pub struct PeriodicHook {
+    name: String,
+    schedule: Schedule,
+    leader_only: bool,
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+    #[serde(skip_serializing)]
+    last_run: Option<i64>,
+    #[serde(skip_serializing)]
+    running: Arc<AtomicBool>,
+}
+
+impl PeriodicHook {
+    pub fn load<C, T>(
+        service_group: &ServiceGroup,
+        name: &str,
+        spec: &PeriodicHookSpec,
+        concrete_path: C,
+        template_path: T,
+    ) -> Option<Self>
+    where
+        C: AsRef<Path>,
+        T: AsRef<Path>,
+    {
+        let template = template_path.as_ref().join(PERIODIC_HOOKS_DIR).join(name);
+        if std::fs::metadata(&template).is_err() {
+            outputln!(preamble service_group,
+                      "Periodic hook {} has a schedule but no template at {}, not loading",
+                      name, template.display());
+            return None;
+        }
+        let schedule = match spec.schedule.parse::<Schedule>() {
+            Ok(schedule) => schedule,
+            Err(err) => {
+                outputln!(preamble service_group,
+                          "Failed to load periodic hook {}: {}", name, err);
+                return None;
+            }
+        };
+        let concrete = concrete_path.as_ref().join(PERIODIC_HOOKS_DIR).join(name);
+        let pair = match RenderPair::new(concrete, &template) {
+            Ok(pair) => pair,
+            Err(err) => {
+                outputln!(preamble service_group, "Failed to load hook: {}", err);
+                return None;
+            }
+        };
+        let logs_path = fs::svc_logs_path(service_group.service());
+        Some(PeriodicHook {
+            name: name.to_string(),
+            schedule: schedule,
+            leader_only: spec.leader_only,
+            render_pair: pair,
+            stdout_log_path: logs_path.join(format!("{}-{}.stdout.log", PERIODIC_HOOKS_DIR, name)),
+            stderr_log_path: logs_path.join(format!("{}-{}.stderr.log", PERIODIC_HOOKS_DIR, name)),
+            last_run: None,
+            running: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn leader_only(&self) -> bool {
+        self.leader_only
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    /// Compile a hook into its destination service directory.
+    pub fn compile(&self, service_group: &ServiceGroup, ctx: &RenderContext) -> Result<bool> {
+        let content = self.render_pair.renderer.render(&self.name, ctx)?;
+        if let Some(parent) = self.path().parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        if write_hook(&content, self.path())? {
+            outputln!(preamble service_group, "{} {}, compiled to {}", PERIODIC_HOOKS_DIR,
+                self.name, self.path().display());
+            hcore::util::perm::set_permissions(self.path(), HOOK_PERMISSIONS)?;
+            Ok(true)
+        } else {
+            debug!(
+                "{} {}, already compiled to {}",
+                PERIODIC_HOOKS_DIR,
+                self.name,
+                self.path().display()
+            );
+            Ok(false)
+        }
+    }
+
+    /// Returns true if the hook is scheduled for the minute containing `now` and hasn't already
+    /// been run during it. Marks the minute as handled, so a hook is considered due at most once
+    /// per scheduled minute.
+    pub fn due(&mut self, now: &Tm) -> bool {
+        let minute = now.to_timespec().sec / 60;
+        if self.last_run == Some(minute) || !self.schedule.matches(now) {
+            return false;
+        }
+        self.last_run = Some(minute);
+        true
+    }
+
+    /// Run the compiled hook on a separate thread, unless a previous run is still in progress.
+    pub fn run<T>(&self, service_group: &ServiceGroup, pkg: &Pkg, svc_encrypted_password: Option<T>)
+    where
+        T: ToString,
+    {
+        if self.running.compare_and_swap(false, true, Ordering::SeqCst) {
+            outputln!(preamble service_group,
+                      "Skipping periodic hook {}; the previous run is still in progress",
+                      self.name);
+            return;
+        }
+        let service_group = service_group.clone();
+        let pkg = pkg.clone();
+        let password = svc_encrypted_password.map(|p| p.to_string());
+        let name = self.name.clone();
+        let path = self.path().to_path_buf();
+        let stdout_log_path = self.stdout_log_path.clone();
+        let stderr_log_path = self.stderr_log_path.clone();
+        let running = self.running.clone();
+        let spawned = thread::Builder::new()
+            .name(format!("{}-{}", PERIODIC_HOOKS_DIR, name))
+            .spawn(move || {
+                let preamble = format!("{} hook[{}/{}]:", service_group, PERIODIC_HOOKS_DIR, name);
+                match exec::run(&path, &pkg, password) {
+                    Ok(mut child) => {
+                        let mut hook_output = HookOutput::new(&stdout_log_path, &stderr_log_path);
+                        hook_output.stream_output_with_preamble(&preamble, &mut child);
+                        match child.wait() {
+                            Ok(status) => {
+                                event_stream::publish(Event::HookExecuted {
+                                    service_group: service_group.to_string(),
+                                    hook: format!("{}/{}", PERIODIC_HOOKS_DIR, name),
+                                    exit_code: status.code(),
+                                });
+                                match status.code() {
+                                    Some(0) => (),
+                                    Some(code) => {
+                                        outputln!(preamble service_group,
+                                                  "Periodic hook {} exited with status code {}",
+                                                  name, code)
+                                    }
+                                    None => {
+                                        outputln!(preamble service_group,
+                                                  "Periodic hook {} exited without a status code",
+                                                  name)
+                                    }
+                                }
+                            }
+                            Err(err) => {
+                                outputln!(preamble service_group,
+                                          "Periodic hook {} failed to run, {}", name, err)
+                            }
+                        }
+                    }
+                    Err(err) => {
+                        outputln!(preamble service_group,
+                                  "Periodic hook {} failed to run, {}", name, err)
+                    }
+                }
+                running.store(false, Ordering::SeqCst);
+            });
+        if let Err(err) = spawned {
+            outputln!(preamble service_group,
+                      "Unable to start periodic hook {}, {}", self.name, err);
+            self.running.store(false, Ordering::SeqCst);
+        }
+    }
+}
 
 /// Cryptographically hash the contents of the compiled hook
 /// file.
@@ -812,11 +1189,23 @@ pub struct HookTable {
     pub post_run: Option<PostRunHook>,
     pub smoke_test: Option<SmokeTestHook>,
     pub post_stop: Option<PostStopHook>,
+    pub promote: Option<PromoteHook>,
+    pub demote: Option<DemoteHook>,
+    pub reshard: Option<ReshardHook>,
+    pub periodic: Vec<PeriodicHook>,
 }
 
 impl HookTable {
     /// Read all available hook templates from the table's package directory into the table.
-    pub fn load<P, T>(service_group: &ServiceGroup, templates: T, hooks_path: P) -> Self
+    ///
+    /// Periodic hooks are only loaded if they appear in `periodic`, which maps hook names to
+    /// their schedules.
+    pub fn load<P, T>(
+        service_group: &ServiceGroup,
+        templates: T,
+        hooks_path: P,
+        periodic: &BTreeMap<String, PeriodicHookSpec>,
+    ) -> Self
     where
         P: AsRef<Path>,
         T: AsRef<Path>,
@@ -834,6 +1223,15 @@ impl HookTable {
                 table.post_run = PostRunHook::load(service_group, &hooks_path, &templates);
                 table.smoke_test = SmokeTestHook::load(service_group, &hooks_path, &templates);
                 table.post_stop = PostStopHook::load(service_group, &hooks_path, &templates);
+                table.promote = PromoteHook::load(service_group, &hooks_path, &templates);
+                table.demote = DemoteHook::load(service_group, &hooks_path, &templates);
+                table.reshard = ReshardHook::load(service_group, &hooks_path, &templates);
+                table.periodic = periodic
+                    .iter()
+                    .filter_map(|(name, spec)| {
+                        PeriodicHook::load(service_group, name, spec, &hooks_path, &templates)
+                    })
+                    .collect();
             }
         }
         debug!(
@@ -882,6 +1280,26 @@ impl HookTable {
         if let Some(ref hook) = self.post_stop {
             changed = self.compile_one(hook, service_group, ctx) || changed;
         }
+        if let Some(ref hook) = self.promote {
+            changed = self.compile_one(hook, service_group, ctx) || changed;
+        }
+        if let Some(ref hook) = self.demote {
+            changed = self.compile_one(hook, service_group, ctx) || changed;
+        }
+        if let Some(ref hook) = self.reshard {
+            changed = self.compile_one(hook, service_group, ctx) || changed;
+        }
+        for hook in self.periodic.iter() {
+            changed = match hook.compile(service_group, ctx) {
+                Ok(status) => status,
+                Err(e) => {
+                    outputln!(preamble service_group,
+                              "Failed to compile {} {} hook: {}", PERIODIC_HOOKS_DIR,
+                              hook.name(), e);
+                    false
+                }
+            } || changed;
+        }
         outputln!(preamble service_group, "Hooks compiled");
         changed
     }
@@ -974,12 +1392,16 @@ impl<'a> HookOutput<'a> {
     }
 
     fn stream_output<H: Hook>(&mut self, service_group: &ServiceGroup, process: &mut Child) {
+        let preamble_str = self.stream_preamble::<H>(service_group);
+        self.stream_output_with_preamble(&preamble_str, process);
+    }
+
+    fn stream_output_with_preamble(&mut self, preamble_str: &str, process: &mut Child) {
         let mut stdout_log =
             File::create(&self.stdout_log_file).expect("couldn't create log output file");
         let mut stderr_log =
             File::create(&self.stderr_log_file).expect("couldn't create log output file");
 
-        let preamble_str = self.stream_preamble::<H>(service_group);
         if let Some(ref mut stdout) = process.stdout {
             for line in BufReader::new(stdout).lines() {
                 if let Some(ref l) = line.ok() {
@@ -1059,7 +1481,10 @@ mod tests {
                       RunHook
                       SmokeTestHook
                       SuitabilityHook
-                      PostStopHook);
+                      PostStopHook
+                      PromoteHook
+                      DemoteHook
+                      ReshardHook);
 
     fn hook_fixtures_path() -> PathBuf {
         PathBuf::from(env!("CARGO_MANIFEST_DIR"))
@@ -1440,7 +1865,8 @@ echo "The message is Hello"
         // END RENDER CONTEXT SETUP
         ////////////////////////////////////////////////////////////////////////
 
-        let hook_table = HookTable::load(&service_group, &template_path, &hooks_path);
+        let hook_table =
+            HookTable::load(&service_group, &template_path, &hooks_path, &BTreeMap::new());
         assert_eq!(hook_table.compile(&service_group, &ctx), true);
 
         // Verify init hook
@@ -17,10 +17,12 @@ mod composite_spec;
 mod config;
 mod health;
 mod package;
+mod schedule;
 mod spec;
 mod supervisor;
 
 use std;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fs::File;
 use std::io::BufWriter;
@@ -40,16 +42,17 @@ use hcore::util::deserialize_using_from_str;
 use hcore::util::perm::{set_owner, set_permissions};
 use launcher_client::LauncherCli;
 use serde;
-use time::Timespec;
+use time::{self, Timespec};
 
 use super::Sys;
 use self::config::CfgRenderer;
 use self::hooks::{HOOK_PERMISSIONS, Hook, HookTable};
 use self::supervisor::Supervisor;
 use error::{Error, Result, SupError};
+use event_stream::{self, Event};
 use fs;
 use manager;
-use census::{ServiceFile, CensusRing, ElectionStatus};
+use census::{CensusGroup, CensusRing, ElectionStatus, GroupRoles, ServiceFile};
 use templating::RenderContext;
 use util;
 
@@ -57,7 +60,8 @@ pub use self::config::Cfg;
 pub use self::health::{HealthCheck, SmokeCheck};
 pub use self::package::Pkg;
 pub use self::composite_spec::CompositeSpec;
-pub use self::spec::{DesiredState, ServiceBind, ServiceSpec, StartStyle};
+pub use self::schedule::Schedule;
+pub use self::spec::{DesiredState, PeriodicHookSpec, ServiceBind, ServiceSpec, StartStyle};
 pub use self::supervisor::ProcessState;
 
 static LOGKEY: &'static str = "SR";
@@ -77,6 +81,7 @@ pub struct Service {
     pub spec_ident: PackageIdent,
     pub start_style: StartStyle,
     pub topology: Topology,
+    pub active_count: u32,
     pub update_strategy: UpdateStrategy,
     pub cfg: Cfg,
     pub pkg: Pkg,
@@ -87,6 +92,10 @@ pub struct Service {
     config_renderer: CfgRenderer,
     health_check: HealthCheck,
     last_election_status: ElectionStatus,
+    #[serde(skip_serializing)]
+    last_promoted: Option<bool>,
+    #[serde(skip_serializing)]
+    last_shard: Option<(u32, u32)>,
     needs_reload: bool,
     needs_reconfiguration: bool,
     smoke_check: SmokeCheck,
@@ -100,6 +109,7 @@ pub struct Service {
     supervisor: Supervisor,
     svc_encrypted_password: Option<String>,
     composite: Option<String>,
+    periodic_hooks: BTreeMap<String, PeriodicHookSpec>,
 }
 
 impl Service {
@@ -111,7 +121,7 @@ impl Service {
         organization: Option<&str>,
     ) -> Result<Service> {
         spec.validate(&package)?;
-        let pkg = Pkg::from_install(package)?;
+        let pkg = Pkg::from_install(package.clone())?;
         let spec_file = manager_fs_cfg.specs_path.join(spec.file_name());
         let service_group = ServiceGroup::new(
             spec.application_environment.as_ref(),
@@ -121,6 +131,7 @@ impl Service {
         )?;
         let config_root = Self::config_root(&pkg, spec.config_from.as_ref());
         let hooks_root = Self::hooks_root(&pkg, spec.config_from.as_ref());
+        let periodic_schedules = Self::periodic_schedules(&package, &spec.periodic_hooks);
         Ok(Service {
             sys: sys,
             cfg: Cfg::new(&pkg, spec.config_from.as_ref())?,
@@ -132,9 +143,12 @@ impl Service {
                 &service_group,
                 &hooks_root,
                 fs::svc_hooks_path(&service_group.service()),
+                &periodic_schedules,
             ),
             initialized: false,
             last_election_status: ElectionStatus::None,
+            last_promoted: None,
+            last_shard: None,
             needs_reload: false,
             needs_reconfiguration: false,
             manager_fs_cfg: manager_fs_cfg,
@@ -147,11 +161,13 @@ impl Service {
             spec_file: spec_file,
             start_style: spec.start_style,
             topology: spec.topology,
+            active_count: spec.active_count,
             update_strategy: spec.update_strategy,
             config_from: spec.config_from,
             last_health_check: None,
             svc_encrypted_password: spec.svc_encrypted_password,
             composite: spec.composite,
+            periodic_hooks: spec.periodic_hooks,
         })
     }
 
@@ -171,6 +187,35 @@ impl Service {
             .join("hooks")
     }
 
+    /// Returns the periodic hook schedules for the package, with any schedules from the spec
+    /// taking precedence over the ones the package declares.
+    fn periodic_schedules(
+        package: &PackageInstall,
+        overrides: &BTreeMap<String, PeriodicHookSpec>,
+    ) -> BTreeMap<String, PeriodicHookSpec> {
+        let mut schedules = BTreeMap::new();
+        match package.periodic_hooks() {
+            Ok(hooks) => {
+                for hook in hooks {
+                    schedules.insert(
+                        hook.name,
+                        PeriodicHookSpec {
+                            schedule: hook.schedule,
+                            leader_only: hook.leader_only,
+                        },
+                    );
+                }
+            }
+            Err(err) => {
+                outputln!("Failed to read periodic hooks for {}, {}", package.ident(), err);
+            }
+        }
+        for (name, spec) in overrides.iter() {
+            schedules.insert(name.clone(), spec.clone());
+        }
+        schedules
+    }
+
     pub fn load(
         sys: Arc<Sys>,
         spec: ServiceSpec,
@@ -192,6 +237,9 @@ impl Service {
 
         // Create Supervisor writable directories
         Self::create_dir_all(fs::svc_hooks_path(&self.pkg.name))?;
+        Self::create_dir_all(
+            fs::svc_hooks_path(&self.pkg.name).join(hooks::PERIODIC_HOOKS_DIR),
+        )?;
         Self::create_dir_all(fs::svc_logs_path(&self.pkg.name))?;
 
         // Create service writable directories
@@ -294,7 +342,8 @@ impl Service {
             }
         }
 
-        let svc_updated = self.update_templates(census_ring);
+        let was_initialized = self.initialized;
+        let mut svc_updated = self.update_templates(census_ring);
         if self.update_service_files(census_ring) {
             self.file_updated();
         }
@@ -302,8 +351,11 @@ impl Service {
         match self.topology {
             Topology::Standalone => {
                 self.execute_hooks(launcher);
+                // With no election a standalone service is its own leader, so its `leader_only`
+                // hooks run too.
+                self.run_periodic_hooks(true);
             }
-            Topology::Leader => {
+            Topology::Leader | Topology::ActiveStandby | Topology::Sharded => {
                 let census_group = census_ring.census_group_for(&self.service_group).expect(
                     "Service Group's census entry missing from list!",
                 );
@@ -340,11 +392,19 @@ impl Service {
                                       leader_id.to_string());
                             self.last_election_status = census_group.election_status;
                         }
-                        self.execute_hooks(launcher)
+                        self.execute_hooks(launcher);
+                        let is_leader = census_group.me().map_or(false, |me| me.leader);
+                        self.update_roles(census_group);
+                        self.run_periodic_hooks(is_leader);
                     }
                 }
             }
         }
+        // Other members read whether we're up from our service rumor, when picking a successor
+        // to hand leadership to.
+        if self.initialized != was_initialized {
+            svc_updated = true;
+        }
         svc_updated
     }
 
@@ -360,6 +420,7 @@ impl Service {
         spec.bldr_url = self.bldr_url.clone();
         spec.channel = self.channel.clone();
         spec.topology = self.topology;
+        spec.active_count = self.active_count;
         spec.update_strategy = self.update_strategy;
         spec.binds = self.binds.clone();
         spec.start_style = self.start_style;
@@ -367,6 +428,7 @@ impl Service {
         if let Some(ref password) = self.svc_encrypted_password {
             spec.svc_encrypted_password = Some(password.clone())
         }
+        spec.periodic_hooks = self.periodic_hooks.clone();
         spec
     }
 
@@ -413,6 +475,12 @@ impl Service {
             "Service update failed; unable to find own service group",
         );
         let cfg_updated = self.cfg.update(census_group);
+        if cfg_updated {
+            event_stream::publish(Event::ConfigApplied {
+                service_group: self.service_group.to_string(),
+                incarnation: census_group.service_config.as_ref().map_or(0, |c| c.incarnation),
+            });
+        }
         if cfg_updated || census_ring.changed() {
             let (reload, reconfigure) = {
                 let ctx = self.render_context(census_ring);
@@ -428,10 +496,16 @@ impl Service {
 
     /// Replace the package of the running service and restart it's system process.
     pub fn update_package(&mut self, package: PackageInstall, launcher: &LauncherCli) {
+        let periodic_schedules = Self::periodic_schedules(&package, &self.periodic_hooks);
         match Pkg::from_install(package) {
             Ok(pkg) => {
                 outputln!(preamble self.service_group,
                             "Updating service {} to {}", self.pkg.ident, pkg.ident);
+                event_stream::publish(Event::PackageUpdated {
+                    service_group: self.service_group.to_string(),
+                    from: self.pkg.ident.to_string(),
+                    to: pkg.ident.to_string(),
+                });
                 match CfgRenderer::new(&Self::config_root(&pkg, self.config_from.as_ref())) {
                     Ok(renderer) => self.config_renderer = renderer,
                     Err(e) => {
@@ -444,6 +518,7 @@ impl Service {
                     &self.service_group,
                     &Self::hooks_root(&pkg, self.config_from.as_ref()),
                     fs::svc_hooks_path(self.service_group.service()),
+                    &periodic_schedules,
                 );
                 self.pkg = pkg;
             }
@@ -478,6 +553,7 @@ impl Service {
             exported.as_ref(),
         );
         rumor.set_incarnation(incarnation);
+        rumor.set_initialized(self.initialized);
         rumor
     }
 
@@ -530,6 +606,86 @@ impl Service {
         }
     }
 
+    /// Run the demote hook, if present, because this member is handing leadership of its group
+    /// to another. Nothing is run if the service already saw the new leader take over.
+    pub fn demote(&mut self) {
+        if self.last_promoted == Some(false) {
+            return;
+        }
+        self.last_promoted = Some(false);
+        if let Some(ref hook) = self.hooks.demote {
+            hook.run(
+                &self.service_group,
+                &self.pkg,
+                self.svc_encrypted_password.as_ref(),
+            );
+        }
+    }
+
+    /// Run the hooks which tell the service its role in the group has changed: promote or demote
+    /// when this member becomes or stops being the leader (or, in an active-standby topology, an
+    /// active member), and reshard when its shard or the number of shards changes. The roles a
+    /// member holds when its service first comes up are not a change, so no hooks run for them.
+    fn update_roles(&mut self, census_group: &CensusGroup) {
+        if !self.initialized {
+            return;
+        }
+        let me = match census_group.me() {
+            Some(me) => me,
+            None => return,
+        };
+        let (promoted, promoted_role, demoted_role) = match self.topology {
+            Topology::ActiveStandby => (me.active, "active", "standby"),
+            _ => (me.leader, "leader", "follower"),
+        };
+        let shard = match (me.shard, census_group.shard_count) {
+            (Some(shard), Some(count)) => Some((shard, count)),
+            _ => None,
+        };
+        let was_promoted = self.last_promoted;
+        let last_shard = self.last_shard;
+        self.last_promoted = Some(promoted);
+        self.last_shard = shard;
+        match (was_promoted, promoted) {
+            (Some(false), true) => {
+                outputln!(preamble self.service_group, "Promoted to {}", promoted_role);
+                if let Some(ref hook) = self.hooks.promote {
+                    hook.run(
+                        &self.service_group,
+                        &self.pkg,
+                        self.svc_encrypted_password.as_ref(),
+                    );
+                }
+            }
+            (Some(true), false) => {
+                outputln!(preamble self.service_group, "Demoted to {}", demoted_role);
+                if let Some(ref hook) = self.hooks.demote {
+                    hook.run(
+                        &self.service_group,
+                        &self.pkg,
+                        self.svc_encrypted_password.as_ref(),
+                    );
+                }
+            }
+            _ => (),
+        }
+        if was_promoted.is_some() && self.topology == Topology::Sharded && shard != last_shard {
+            match shard {
+                Some((shard, count)) => {
+                    outputln!(preamble self.service_group, "Now owns shard {} of {}", shard, count)
+                }
+                None => outputln!(preamble self.service_group, "No longer owns a shard"),
+            }
+            if let Some(ref hook) = self.hooks.reshard {
+                hook.run(
+                    &self.service_group,
+                    &self.pkg,
+                    self.svc_encrypted_password.as_ref(),
+                );
+            }
+        }
+    }
+
     pub fn suitability(&self) -> Option<u64> {
         if !self.initialized {
             return None;
@@ -679,6 +835,26 @@ impl Service {
         }
     }
 
+    /// Run any periodic hooks which are due. Leader-only hooks are skipped unless `is_leader` is
+    /// set, and nothing runs until the service has been initialized.
+    fn run_periodic_hooks(&mut self, is_leader: bool) {
+        if !self.initialized || self.hooks.periodic.is_empty() {
+            return;
+        }
+        let now = time::now();
+        for hook in self.hooks.periodic.iter_mut() {
+            if !hook.due(&now) || (hook.leader_only() && !is_leader) {
+                continue;
+            }
+            outputln!(preamble self.service_group, "Running periodic hook {}", hook.name());
+            hook.run(
+                &self.service_group,
+                &self.pkg,
+                self.svc_encrypted_password.as_ref(),
+            );
+        }
+    }
+
     /// Run file_updated hook if present
     fn file_updated(&self) -> bool {
         if self.initialized {
@@ -705,6 +881,11 @@ impl Service {
             if self.cache_service_file(&service_file) {
                 outputln!(preamble self.service_group, "Service file updated, {}",
                     service_file.filename);
+                event_stream::publish(Event::FileApplied {
+                    service_group: self.service_group.to_string(),
+                    filename: service_file.filename.clone(),
+                    incarnation: service_file.incarnation,
+                });
                 updated = true;
             }
         }
@@ -753,6 +934,14 @@ impl Service {
             }
         };
         self.last_health_check = Some(Instant::now());
+        if check_result != self.health_check {
+            event_stream::publish(Event::HealthCheckChanged {
+                service_group: self.service_group.to_string(),
+                from: self.health_check.to_string(),
+                to: check_result.to_string(),
+            });
+            self.health_check = check_result;
+        }
         self.cache_health_check(check_result);
     }
 
@@ -826,6 +1015,8 @@ impl fmt::Display for Service {
 pub enum Topology {
     Standalone,
     Leader,
+    ActiveStandby,
+    Sharded,
 }
 
 impl Topology {
@@ -833,6 +1024,24 @@ impl Topology {
         match *self {
             Topology::Leader => "leader",
             Topology::Standalone => "standalone",
+            Topology::ActiveStandby => "active-standby",
+            Topology::Sharded => "sharded",
+        }
+    }
+
+    /// Returns true if members of a service group with this topology elect a leader, and so need
+    /// quorum before their service is started.
+    pub fn has_election(&self) -> bool {
+        *self != Topology::Standalone
+    }
+
+    /// Returns the roles the census assigns to members of a service group with this topology, on
+    /// top of leader and follower.
+    pub fn group_roles(&self, active_count: u32) -> Option<GroupRoles> {
+        match *self {
+            Topology::ActiveStandby => Some(GroupRoles::ActiveStandby(active_count)),
+            Topology::Sharded => Some(GroupRoles::Sharded),
+            Topology::Standalone | Topology::Leader => None,
         }
     }
 }
@@ -844,6 +1053,8 @@ impl FromStr for Topology {
         match topology {
             "leader" => Ok(Topology::Leader),
             "standalone" => Ok(Topology::Standalone),
+            "active-standby" => Ok(Topology::ActiveStandby),
+            "sharded" => Ok(Topology::Sharded),
             _ => Err(sup_error!(Error::InvalidTopology(String::from(topology)))),
         }
     }
@@ -965,6 +1176,17 @@ mod test {
         assert_eq!(topology, Topology::Leader);
     }
 
+    #[test]
+    fn topology_from_str_with_roles() {
+        let topology = Topology::from_str("active-standby").unwrap();
+        assert_eq!(topology, Topology::ActiveStandby);
+        assert_eq!(topology.to_string(), "active-standby");
+
+        let topology = Topology::from_str("sharded").unwrap();
+        assert_eq!(topology, Topology::Sharded);
+        assert_eq!(topology.to_string(), "sharded");
+    }
+
     #[test]
     fn topology_from_str_invalid() {
         let topology_str = "dope";
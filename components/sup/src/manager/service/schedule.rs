@@ -0,0 +1,278 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cron-style schedules for periodic hooks.
+//!
+//! A schedule is either five whitespace separated fields (minute, hour, day of month, month and
+//! day of week) or one of the `@hourly`, `@daily`, `@midnight`, `@weekly`, `@monthly`, `@yearly`
+//! and `@annually` shorthands. Each field accepts `*`, single values, ranges (`1-5`), steps
+//! (`*/15`, `0-30/10`) and comma separated lists of any of those. As with cron, when both the day
+//! of month and day of week are restricted, a time matches if either of them does, and a field
+//! starting with `*` (such as `*/2`) doesn't count as restricted.
+
+use std::fmt;
+use std::result;
+use std::str::FromStr;
+
+use serde;
+use time::Tm;
+
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "SC";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schedule {
+    source: String,
+    minutes: Field,
+    hours: Field,
+    days_of_month: Field,
+    months: Field,
+    days_of_week: Field,
+}
+
+impl Schedule {
+    /// Returns true if the given time falls within this schedule, to the minute.
+    pub fn matches(&self, tm: &Tm) -> bool {
+        if !self.minutes.contains(tm.tm_min as u32) || !self.hours.contains(tm.tm_hour as u32) ||
+            !self.months.contains(tm.tm_mon as u32 + 1)
+        {
+            return false;
+        }
+        let dom = self.days_of_month.contains(tm.tm_mday as u32);
+        let dow = self.days_of_week.contains(tm.tm_wday as u32);
+        if self.days_of_month.any || self.days_of_week.any {
+            dom && dow
+        } else {
+            dom || dow
+        }
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        let expanded = match value.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            other => other,
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(invalid(value));
+        }
+        let mut days_of_week = Field::parse(fields[4], 0, 7).map_err(|_| invalid(value))?;
+        // Both 0 and 7 mean Sunday.
+        if days_of_week.contains(7) {
+            days_of_week.values[0] = true;
+        }
+        Ok(Schedule {
+            source: value.trim().to_string(),
+            minutes: Field::parse(fields[0], 0, 59).map_err(|_| invalid(value))?,
+            hours: Field::parse(fields[1], 0, 23).map_err(|_| invalid(value))?,
+            days_of_month: Field::parse(fields[2], 1, 31).map_err(|_| invalid(value))?,
+            months: Field::parse(fields[3], 1, 12).map_err(|_| invalid(value))?,
+            days_of_week: days_of_week,
+        })
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+impl serde::Serialize for Schedule {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+fn invalid(value: &str) -> SupError {
+    sup_error!(Error::InvalidSchedule(value.to_string()))
+}
+
+/// The set of values a single schedule field matches.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Field {
+    values: Vec<bool>,
+    /// Whether the field starts with `*`, which cron counts as unrestricted when deciding how
+    /// day of month and day of week combine, even when it's stepped like `*/2`.
+    any: bool,
+}
+
+impl Field {
+    fn parse(field: &str, min: u32, max: u32) -> Result<Field> {
+        let mut values = vec![false; max as usize + 1];
+        for part in field.split(',') {
+            let (range, step) = match part.find('/') {
+                Some(idx) => {
+                    let step = part[idx + 1..].parse::<u32>().map_err(|_| invalid(field))?;
+                    if step == 0 {
+                        return Err(invalid(field));
+                    }
+                    (&part[..idx], step)
+                }
+                None => (part, 1),
+            };
+            let (start, end) = if range == "*" {
+                (min, max)
+            } else {
+                match range.find('-') {
+                    Some(idx) => {
+                        (
+                            range[..idx].parse::<u32>().map_err(|_| invalid(field))?,
+                            range[idx + 1..].parse::<u32>().map_err(|_| invalid(field))?,
+                        )
+                    }
+                    None => {
+                        let value = range.parse::<u32>().map_err(|_| invalid(field))?;
+                        // A step on a single value runs to the end of the field, as in cron.
+                        if step > 1 { (value, max) } else { (value, value) }
+                    }
+                }
+            };
+            if start < min || end > max || start > end {
+                return Err(invalid(field));
+            }
+            let mut value = start;
+            while value <= end {
+                values[value as usize] = true;
+                value += step;
+            }
+        }
+        Ok(Field {
+            values: values,
+            any: field.starts_with('*'),
+        })
+    }
+
+    fn contains(&self, value: u32) -> bool {
+        self.values.get(value as usize).cloned().unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use time::{self, Tm};
+
+    use super::Schedule;
+    use error::Error::*;
+
+    /// Builds a `Tm` for the given date and time; `wday` is 0 for Sunday.
+    fn tm(mon: i32, mday: i32, wday: i32, hour: i32, min: i32) -> Tm {
+        let mut tm = time::empty_tm();
+        tm.tm_year = 117;
+        tm.tm_mon = mon - 1;
+        tm.tm_mday = mday;
+        tm.tm_wday = wday;
+        tm.tm_hour = hour;
+        tm.tm_min = min;
+        tm
+    }
+
+    #[test]
+    fn every_minute() {
+        let schedule = Schedule::from_str("* * * * *").unwrap();
+        assert!(schedule.matches(&tm(1, 1, 0, 0, 0)));
+        assert!(schedule.matches(&tm(12, 31, 6, 23, 59)));
+    }
+
+    #[test]
+    fn fixed_time_of_day() {
+        let schedule = Schedule::from_str("30 3 * * *").unwrap();
+        assert!(schedule.matches(&tm(7, 12, 3, 3, 30)));
+        assert!(!schedule.matches(&tm(7, 12, 3, 3, 31)));
+        assert!(!schedule.matches(&tm(7, 12, 3, 4, 30)));
+    }
+
+    #[test]
+    fn steps_ranges_and_lists() {
+        let schedule = Schedule::from_str("*/15 9-17 * * 1-5").unwrap();
+        assert!(schedule.matches(&tm(7, 12, 3, 9, 45)));
+        assert!(!schedule.matches(&tm(7, 12, 3, 9, 50)));
+        assert!(!schedule.matches(&tm(7, 12, 3, 18, 0)));
+        assert!(!schedule.matches(&tm(7, 16, 0, 10, 0)));
+
+        let schedule = Schedule::from_str("0,30 0 1,15 * *").unwrap();
+        assert!(schedule.matches(&tm(7, 15, 6, 0, 30)));
+        assert!(!schedule.matches(&tm(7, 14, 5, 0, 30)));
+    }
+
+    #[test]
+    fn restricted_day_of_month_and_week_match_either() {
+        let schedule = Schedule::from_str("0 0 1 * 0").unwrap();
+        // The first of the month, a Saturday
+        assert!(schedule.matches(&tm(7, 1, 6, 0, 0)));
+        // Not the first, but a Sunday
+        assert!(schedule.matches(&tm(7, 2, 0, 0, 0)));
+        assert!(!schedule.matches(&tm(7, 3, 1, 0, 0)));
+    }
+
+    #[test]
+    fn stepped_star_day_of_month_must_match_with_day_of_week() {
+        let schedule = Schedule::from_str("0 0 */2 * 1").unwrap();
+        // The 3rd, a Monday
+        assert!(schedule.matches(&tm(7, 3, 1, 0, 0)));
+        // The 10th, a Monday, isn't one of the days `*/2` picks
+        assert!(!schedule.matches(&tm(7, 10, 1, 0, 0)));
+        // The 1st, a Saturday, isn't a Monday
+        assert!(!schedule.matches(&tm(7, 1, 6, 0, 0)));
+    }
+
+    #[test]
+    fn sunday_can_be_seven() {
+        let schedule = Schedule::from_str("0 0 * * 7").unwrap();
+        assert!(schedule.matches(&tm(7, 2, 0, 0, 0)));
+    }
+
+    #[test]
+    fn shorthands() {
+        let schedule = Schedule::from_str("@daily").unwrap();
+        assert!(schedule.matches(&tm(7, 2, 0, 0, 0)));
+        assert!(!schedule.matches(&tm(7, 2, 0, 1, 0)));
+        assert_eq!(schedule.to_string(), "@daily");
+
+        let schedule = Schedule::from_str("@monthly").unwrap();
+        assert!(schedule.matches(&tm(8, 1, 2, 0, 0)));
+        assert!(!schedule.matches(&tm(8, 2, 3, 0, 0)));
+    }
+
+    #[test]
+    fn invalid_schedules() {
+        for bad in &["", "* * * *", "60 * * * *", "* 24 * * *", "*/0 * * * *", "5-1 * * * *",
+                     "@sometimes", "a b c d e"]
+        {
+            match Schedule::from_str(bad) {
+                Err(e) => {
+                    match e.err {
+                        InvalidSchedule(_) => (),
+                        wrong => panic!("Unexpected error returned: {:?}", wrong),
+                    }
+                }
+                Ok(_) => panic!("{:?} should fail to parse", bad),
+            }
+        }
+    }
+}
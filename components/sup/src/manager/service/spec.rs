@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fmt;
 use std::fs::{self, File};
 use std::io::{BufReader, Read, Write};
@@ -30,12 +30,13 @@ use rand::{Rng, thread_rng};
 use serde::{self, Deserialize};
 use toml;
 
-use super::{Topology, UpdateStrategy};
+use super::{Schedule, Topology, UpdateStrategy};
 use error::{Error, Result, SupError};
 
 static LOGKEY: &'static str = "SS";
 static DEFAULT_GROUP: &'static str = "default";
 const SPEC_FILE_EXT: &'static str = "spec";
+const DEFAULT_ACTIVE_COUNT: u32 = 1;
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum DesiredState {
@@ -71,6 +72,10 @@ impl FromStr for DesiredState {
     }
 }
 
+fn is_default_active_count(count: &u32) -> bool {
+    *count == DEFAULT_ACTIVE_COUNT
+}
+
 pub fn deserialize_application_environment<'de, D>(
     d: D,
 ) -> result::Result<Option<ApplicationEnvironment>, D::Error>
@@ -100,6 +105,9 @@ pub struct ServiceSpec {
     pub bldr_url: String,
     pub channel: String,
     pub topology: Topology,
+    // The number of members which are active at once in an active-standby topology
+    #[serde(skip_serializing_if = "is_default_active_count")]
+    pub active_count: u32,
     pub update_strategy: UpdateStrategy,
     pub binds: Vec<ServiceBind>,
     pub config_from: Option<PathBuf>,
@@ -112,6 +120,11 @@ pub struct ServiceSpec {
     pub svc_encrypted_password: Option<String>,
     // The name of the composite this service is a part of
     pub composite: Option<String>,
+    // Periodic hook schedules which override or extend the ones declared by the package. Maps
+    // must be represented last in a serializable struct for the current version of the toml
+    // crate.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub periodic_hooks: BTreeMap<String, PeriodicHookSpec>,
 }
 
 impl ServiceSpec {
@@ -181,6 +194,26 @@ impl ServiceSpec {
 
     pub fn validate(&self, package: &PackageInstall) -> Result<()> {
         self.validate_binds(package)?;
+        self.validate_periodic_hooks()?;
+        self.validate_topology()?;
+        Ok(())
+    }
+
+    /// Validates that an active-standby topology has at least one active member.
+    fn validate_topology(&self) -> Result<()> {
+        if self.topology == Topology::ActiveStandby && self.active_count == 0 {
+            return Err(sup_error!(Error::InvalidTopology(
+                format!("{} with no active members", self.topology),
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validates that every periodic hook schedule given in the spec can be parsed.
+    fn validate_periodic_hooks(&self) -> Result<()> {
+        for hook in self.periodic_hooks.values() {
+            Schedule::from_str(&hook.schedule)?;
+        }
         Ok(())
     }
 
@@ -237,6 +270,7 @@ impl Default for ServiceSpec {
             bldr_url: DEFAULT_BLDR_URL.to_string(),
             channel: STABLE_CHANNEL.to_string(),
             topology: Topology::default(),
+            active_count: DEFAULT_ACTIVE_COUNT,
             update_strategy: UpdateStrategy::default(),
             binds: Vec::default(),
             config_from: None,
@@ -244,6 +278,7 @@ impl Default for ServiceSpec {
             start_style: StartStyle::default(),
             svc_encrypted_password: None,
             composite: None,
+            periodic_hooks: BTreeMap::new(),
         }
     }
 }
@@ -308,6 +343,14 @@ impl serde::Serialize for ServiceBind {
     }
 }
 
+/// The schedule of a periodic hook, as given in a service spec.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PeriodicHookSpec {
+    pub schedule: String,
+    #[serde(default)]
+    pub leader_only: bool,
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum StartStyle {
     Persistent,
@@ -423,6 +466,60 @@ mod test {
         assert_eq!(spec.start_style, StartStyle::Persistent);
     }
 
+    #[test]
+    fn service_spec_from_str_with_periodic_hooks() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+
+            [periodic_hooks.backup]
+            schedule = "0 3 * * *"
+            leader_only = true
+
+            [periodic_hooks.vacuum]
+            schedule = "@hourly"
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.periodic_hooks.len(), 2);
+        assert_eq!(spec.periodic_hooks["backup"].schedule, "0 3 * * *");
+        assert!(spec.periodic_hooks["backup"].leader_only);
+        assert_eq!(spec.periodic_hooks["vacuum"].schedule, "@hourly");
+        assert!(!spec.periodic_hooks["vacuum"].leader_only);
+    }
+
+    #[test]
+    fn service_spec_to_toml_string_with_periodic_hooks() {
+        let mut spec =
+            ServiceSpec::default_for(PackageIdent::from_str("origin/name").unwrap());
+        spec.periodic_hooks.insert(
+            "backup".to_string(),
+            PeriodicHookSpec {
+                schedule: "0 3 * * *".to_string(),
+                leader_only: true,
+            },
+        );
+        let toml = spec.to_toml_string().unwrap();
+        let parsed = ServiceSpec::from_str(&toml).unwrap();
+
+        assert_eq!(parsed.periodic_hooks, spec.periodic_hooks);
+    }
+
+    #[test]
+    fn service_spec_active_count() {
+        let spec = ServiceSpec::default_for(PackageIdent::from_str("origin/name").unwrap());
+        assert_eq!(spec.active_count, 1);
+        assert!(!spec.to_toml_string().unwrap().contains("active_count"));
+
+        let toml = r#"
+            ident = "origin/name"
+            topology = "active-standby"
+            active_count = 2
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+        assert_eq!(spec.topology, Topology::ActiveStandby);
+        assert_eq!(spec.active_count, 2);
+    }
+
     #[test]
     fn service_spec_from_str_missing_ident() {
         let toml = r#""#;
@@ -487,6 +584,7 @@ mod test {
             bldr_url: String::from("http://example.com/depot"),
             channel: String::from("unstable"),
             topology: Topology::Leader,
+            active_count: 1,
             update_strategy: UpdateStrategy::AtOnce,
             binds: vec![
                 ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
@@ -497,6 +595,7 @@ mod test {
             start_style: StartStyle::Persistent,
             svc_encrypted_password: None,
             composite: None,
+            periodic_hooks: BTreeMap::new(),
         };
         let toml = spec.to_toml_string().unwrap();
 
@@ -646,6 +745,7 @@ mod test {
             bldr_url: String::from("http://example.com/depot"),
             channel: String::from("unstable"),
             topology: Topology::Leader,
+            active_count: 1,
             update_strategy: UpdateStrategy::AtOnce,
             binds: vec![
                 ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
@@ -656,6 +756,7 @@ mod test {
             start_style: StartStyle::Persistent,
             svc_encrypted_password: None,
             composite: None,
+            periodic_hooks: BTreeMap::new(),
         };
         spec.to_file(&path).unwrap();
         let toml = string_from_file(path);
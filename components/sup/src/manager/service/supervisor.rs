@@ -34,6 +34,7 @@ use serde::ser::SerializeStruct;
 use time::{self, Timespec};
 
 use error::{Result, Error};
+use event_stream::{self, Event};
 use fs;
 use manager::service::Pkg;
 
@@ -89,8 +90,8 @@ impl Supervisor {
         };
         if let Some(pid) = pid {
             if process::is_alive(pid) {
-                self.change_state(ProcessState::Up);
                 self.pid = Some(pid);
+                self.change_state(ProcessState::Up);
                 return true;
             }
         }
@@ -217,6 +218,11 @@ impl Supervisor {
         }
         self.state = state;
         self.state_entered = time::get_time();
+        event_stream::publish(Event::ServiceStateChanged {
+            service_group: self.preamble.clone(),
+            state: self.state.to_string(),
+            pid: self.pid.map(|pid| pid as u32),
+        });
     }
 }
 
@@ -17,6 +17,8 @@ use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TryRecvError};
 use std::thread;
 
 use butterfly;
+use common;
+use common::command::package::install::InstallSource;
 use common::ui::UI;
 use env;
 use hcore::package::{PackageIdent, PackageInstall};
@@ -24,8 +26,9 @@ use hcore::service::ServiceGroup;
 use launcher_client::LauncherCli;
 
 use census::CensusRing;
+use error::{Error, SupError};
 use manager::periodic::Periodic;
-use manager::service::{Service, Topology, UpdateStrategy};
+use manager::service::{Service, UpdateStrategy};
 use util;
 
 static LOGKEY: &'static str = "SU";
@@ -129,10 +132,10 @@ impl ServiceUpdater {
 
             Some(&mut UpdaterState::Rolling(ref mut st @ RollingState::AwaitingElection)) => {
                 if let Some(census_group) = census_ring.census_group_for(&service.service_group) {
-                    if service.topology == Topology::Leader {
+                    if service.topology.has_election() {
                         debug!(
                             "Rolling Update, determining proper suitability because we're in \
-                                a leader topology"
+                                a topology with a leader"
                         );
                         match (census_group.me(), census_group.leader()) {
                             (Some(me), Some(leader)) => {
@@ -412,7 +415,8 @@ impl Worker {
         // Fairly certain that this only gets called in a rolling update
         // scenario, where `ident` is always a fully-qualified identifier
         outputln!("Updating from {} to {}", self.current, ident);
-        let install_source = ident.into();
+        let install_source: InstallSource = ident.into();
+        let mut refused = false;
         loop {
             let next_time = self.next_period_start();
 
@@ -428,6 +432,12 @@ impl Worker {
                     sender.send(package).expect("Main thread has gone away!");
                     break;
                 }
+                Err(ref e) if refused_by_policy(e) => {
+                    if !refused {
+                        outputln!("Not updating to {}: {}", install_source.as_ref(), e.err);
+                        refused = true;
+                    }
+                }
                 Err(e) => warn!("Failed to install updated package: {:?}", e),
             }
 
@@ -439,6 +449,7 @@ impl Worker {
     /// when found.
     fn run_poll(&mut self, sender: SyncSender<PackageInstall>) {
         let install_source = self.spec_ident.clone().into(); // UGH clone
+        let mut refused = false;
         loop {
             let next_time = self.next_period_start();
 
@@ -465,6 +476,13 @@ impl Worker {
                         debug!("Package found is not newer than ours");
                     }
                 }
+                Err(ref e) if refused_by_policy(e) => {
+                    // Say so once, rather than every time we poll.
+                    if !refused {
+                        outputln!("Not updating {}: {}", self.spec_ident, e.err);
+                        refused = true;
+                    }
+                }
                 Err(e) => warn!("Updater failed to get latest package: {:?}", e),
             }
 
@@ -472,3 +490,12 @@ impl Worker {
         }
     }
 }
+
+/// Whether an update failed because the install policy refused the package, which won't change
+/// until the policy does.
+fn refused_by_policy(err: &SupError) -> bool {
+    match err.err {
+        Error::HabitatCommon(common::Error::InstallPolicyViolation(_)) => true,
+        _ => false,
+    }
+}
@@ -103,6 +103,7 @@ pub struct Svc<'a> {
     pub members: Vec<SvcMember<'a>>,
     pub leader: Option<SvcMember<'a>>,
     pub update_leader: Option<SvcMember<'a>>,
+    pub shard_count: Option<u32>,
 }
 
 impl<'a> Svc<'a> {
@@ -129,6 +130,7 @@ impl<'a> Svc<'a> {
             leader: census_group.leader().map(|m| SvcMember(m)),
             first: select_first(census_group).expect("First should always be present on svc"),
             update_leader: census_group.update_leader().map(|m| SvcMember(m)),
+            shard_count: census_group.shard_count,
         }
     }
 }